@@ -0,0 +1,1042 @@
+//! Test impact selection: symbols changed since a git ref, walked backwards
+//! over the dependency graph and file-level `tested_by` links to the tests
+//! that exercise them, with filters for each test runner.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use aether_core::{EdgeKind, Symbol, diff_symbols, file_source_id, normalize_path};
+use aether_parse::{SymbolExtractor, language_for_path};
+use aether_store::{
+    LocatedEdgeRecord, SqliteStore, SurrealGraphStore, TestIntentRecord, TestedByRecord,
+    block_on_store_future,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::api_surface::{GitTree, git_stdout, package_name, resolve_commit};
+use crate::coupling::AnalysisError;
+use crate::dead_code::is_test_path;
+
+/// Edges walked from a changed symbol back to its dependents.
+pub const AFFECTED_TESTS_EDGE_KINDS: &[EdgeKind] = &[
+    EdgeKind::Calls,
+    EdgeKind::DependsOn,
+    EdgeKind::TypeRef,
+    EdgeKind::Implements,
+    EdgeKind::References,
+    EdgeKind::Endpoint,
+];
+pub const DEFAULT_AFFECTED_TESTS_MAX_DEPTH: usize = 4;
+const AFFECTED_TESTS_SCHEMA_VERSION: &str = "1.0";
+/// Kinds whose changes always show up on their parent as well.
+const CONTAINED_KINDS: &[&str] = &["field", "enum_variant", "module"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AffectedTestsRequest {
+    pub base_ref: String,
+    /// `None` compares against the working tree.
+    pub head_ref: Option<String>,
+    /// Dependency hops walked from each changed symbol.
+    pub max_depth: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolChangeType {
+    Added,
+    Modified,
+    Removed,
+}
+
+impl SymbolChangeType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Modified => "modified",
+            Self::Removed => "removed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedSymbol {
+    pub symbol_id: String,
+    pub qualified_name: String,
+    pub file_path: String,
+    pub language: String,
+    pub kind: String,
+    pub change: SymbolChangeType,
+    /// Selected tests reached from this symbol.
+    pub covering_tests: usize,
+    /// Added or modified outside test code with no covering test.
+    pub uncovered: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestRunner {
+    Cargo,
+    Pytest,
+    Jest,
+}
+
+impl TestRunner {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cargo => "cargo",
+            Self::Pytest => "pytest",
+            Self::Jest => "jest",
+        }
+    }
+
+    fn for_language(language: &str) -> Option<Self> {
+        match language {
+            "rust" => Some(Self::Cargo),
+            "python" => Some(Self::Pytest),
+            "typescript" | "tsx" | "javascript" | "jsx" => Some(Self::Jest),
+            _ => None,
+        }
+    }
+}
+
+/// Why a test was selected, strongest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestSelectionReason {
+    /// The test itself changed.
+    Changed,
+    /// The test reaches a changed symbol over the dependency graph.
+    Graph,
+    /// The test file is linked to a changed file by `tested_by`.
+    TestedBy,
+}
+
+impl TestSelectionReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Changed => "changed",
+            Self::Graph => "graph",
+            Self::TestedBy => "tested_by",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AffectedTest {
+    pub file_path: String,
+    pub test_name: String,
+    pub intent_text: String,
+    pub group_label: Option<String>,
+    pub language: String,
+    pub runner: TestRunner,
+    /// Cargo package for Rust tests.
+    pub package: Option<String>,
+    /// `cargo test` filter, pytest node id or jest `-t` name.
+    pub selector: String,
+    pub reason: TestSelectionReason,
+    /// Dependency hops to the nearest changed symbol, for graph selections.
+    pub depth: Option<usize>,
+    /// `tested_by` confidence, for file-level selections.
+    pub confidence: Option<f32>,
+    /// Changed symbols (or files) that selected this test.
+    pub triggered_by: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunnerCommand {
+    /// `cargo-test`, `cargo-nextest`, `pytest` or `jest`.
+    pub runner: String,
+    pub package: Option<String>,
+    pub command: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AffectedTestsReport {
+    pub schema_version: String,
+    pub base_ref: String,
+    pub base_commit: String,
+    pub head_ref: String,
+    pub head_commit: Option<String>,
+    pub max_depth: usize,
+    pub changed_files: usize,
+    pub changed_symbols: Vec<ChangedSymbol>,
+    pub uncovered_symbols: usize,
+    pub tests: Vec<AffectedTest>,
+    pub commands: Vec<RunnerCommand>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Selection {
+    reason: TestSelectionReason,
+    depth: Option<usize>,
+    confidence: Option<f32>,
+    triggered_by: BTreeSet<String>,
+}
+
+impl Selection {
+    fn merge(
+        &mut self,
+        reason: TestSelectionReason,
+        depth: Option<usize>,
+        confidence: Option<f32>,
+    ) {
+        self.reason = self.reason.min(reason);
+        self.depth = match (self.depth, depth) {
+            (Some(left), Some(right)) => Some(left.min(right)),
+            (left, right) => left.or(right),
+        };
+        self.confidence = match (self.confidence, confidence) {
+            (Some(left), Some(right)) => Some(left.max(right)),
+            (left, right) => left.or(right),
+        };
+    }
+}
+
+pub struct AffectedTestsAnalyzer {
+    workspace: PathBuf,
+}
+
+impl AffectedTestsAnalyzer {
+    pub fn new(workspace: impl AsRef<Path>) -> Result<Self, AnalysisError> {
+        Ok(Self {
+            workspace: workspace.as_ref().to_path_buf(),
+        })
+    }
+
+    /// `graph` supplies `tested_by` links; without it only the symbol graph
+    /// is used.
+    pub fn analyze(
+        &self,
+        store: &SqliteStore,
+        graph: Option<&SurrealGraphStore>,
+        request: &AffectedTestsRequest,
+    ) -> Result<AffectedTestsReport, AnalysisError> {
+        let workspace = self.workspace.as_path();
+        let base_commit = resolve_commit(workspace, request.base_ref.as_str())?;
+        let head_commit = request
+            .head_ref
+            .as_deref()
+            .map(|head_ref| resolve_commit(workspace, head_ref))
+            .transpose()?;
+        let changed_files = self.changed_files(&base_commit, head_commit.as_deref())?;
+
+        let mut notes = Vec::new();
+        let mut changed = changed_symbols(
+            &GitTree {
+                workspace,
+                commit: Some(base_commit.clone()),
+            },
+            &GitTree {
+                workspace,
+                commit: head_commit.clone(),
+            },
+            &changed_files,
+            &mut notes,
+        )?;
+
+        let intents = store.list_all_test_intents()?;
+        let edges = store.list_located_edges(AFFECTED_TESTS_EDGE_KINDS)?;
+        let mut tested_by = Vec::new();
+        match graph {
+            Some(graph) => {
+                for file in &changed_files {
+                    if is_test_path(file) {
+                        continue;
+                    }
+                    tested_by.extend(block_on_store_future(
+                        graph.list_tested_by_for_target_file(file),
+                    )??);
+                }
+            }
+            None => notes.push(
+                "tested_by links unavailable; tests were selected from the symbol graph only"
+                    .to_owned(),
+            ),
+        }
+
+        let selections = select_tests(
+            &mut changed,
+            &changed_files,
+            &intents,
+            &edges,
+            &tested_by,
+            request.max_depth,
+        );
+
+        let qualified_names = store
+            .list_all_symbol_records()?
+            .into_iter()
+            .map(|record| (record.id, record.qualified_name))
+            .collect::<HashMap<_, _>>();
+        let mut packages = HashMap::new();
+        let mut tests = Vec::new();
+        let mut unsupported = 0usize;
+        for (index, selection) in selections {
+            let intent = &intents[index];
+            let Some(runner) = TestRunner::for_language(intent.language.as_str()) else {
+                unsupported += 1;
+                continue;
+            };
+            let qualified_name = intent
+                .symbol_id
+                .as_ref()
+                .and_then(|symbol_id| qualified_names.get(symbol_id))
+                .map(String::as_str);
+            let package = (runner == TestRunner::Cargo)
+                .then(|| self.cargo_package(&mut packages, intent.file_path.as_str()))
+                .flatten();
+            tests.push(AffectedTest {
+                file_path: intent.file_path.clone(),
+                test_name: intent.test_name.clone(),
+                intent_text: intent.intent_text.clone(),
+                group_label: intent.group_label.clone(),
+                language: intent.language.clone(),
+                runner,
+                package,
+                selector: test_selector(runner, intent, qualified_name),
+                reason: selection.reason,
+                depth: selection.depth,
+                confidence: selection.confidence,
+                triggered_by: selection.triggered_by.into_iter().collect(),
+            });
+        }
+        if unsupported > 0 {
+            notes.push(format!(
+                "{unsupported} affected test(s) are in languages without a known runner"
+            ));
+        }
+        tests.sort_by(|left, right| {
+            left.reason
+                .cmp(&right.reason)
+                .then_with(|| left.depth.cmp(&right.depth))
+                .then_with(|| left.file_path.cmp(&right.file_path))
+                .then_with(|| left.selector.cmp(&right.selector))
+        });
+
+        changed.sort_by(|left, right| {
+            left.file_path
+                .cmp(&right.file_path)
+                .then_with(|| left.qualified_name.cmp(&right.qualified_name))
+                .then_with(|| left.change.cmp(&right.change))
+        });
+        for symbol in &mut changed {
+            symbol.uncovered = is_uncovered(symbol);
+        }
+        let uncovered_symbols = changed.iter().filter(|symbol| symbol.uncovered).count();
+        if intents.is_empty() {
+            notes.push(
+                "no test intents are indexed; run `aetherd --index-once` to extract them"
+                    .to_owned(),
+            );
+        }
+
+        Ok(AffectedTestsReport {
+            schema_version: AFFECTED_TESTS_SCHEMA_VERSION.to_owned(),
+            base_ref: request.base_ref.clone(),
+            base_commit,
+            head_ref: request
+                .head_ref
+                .clone()
+                .unwrap_or_else(|| "working tree".to_owned()),
+            head_commit,
+            max_depth: request.max_depth,
+            changed_files: changed_files.len(),
+            changed_symbols: changed,
+            uncovered_symbols,
+            commands: runner_commands(&tests),
+            tests,
+            notes,
+        })
+    }
+
+    /// Files that differ between the refs; the working tree also counts
+    /// untracked files.
+    fn changed_files(
+        &self,
+        base_commit: &str,
+        head_commit: Option<&str>,
+    ) -> Result<BTreeSet<String>, AnalysisError> {
+        let workspace = self.workspace.as_path();
+        let mut listing = match head_commit {
+            Some(head) => git_stdout(
+                workspace,
+                &["diff", "--name-only", "--no-renames", base_commit, head],
+            )?,
+            None => git_stdout(
+                workspace,
+                &["diff", "--name-only", "--no-renames", base_commit],
+            )?,
+        };
+        if head_commit.is_none() {
+            listing.push_str(&git_stdout(
+                workspace,
+                &["ls-files", "--others", "--exclude-standard"],
+            )?);
+        }
+        Ok(listing
+            .lines()
+            .map(|line| normalize_path(line.trim()))
+            .filter(|line| !line.is_empty())
+            .collect())
+    }
+
+    /// Name of the nearest `Cargo.toml` package above `file_path`.
+    fn cargo_package(
+        &self,
+        cache: &mut HashMap<String, Option<String>>,
+        file_path: &str,
+    ) -> Option<String> {
+        let mut dir = Path::new(file_path).parent();
+        while let Some(current) = dir {
+            let key = normalize_path(&current.to_string_lossy());
+            if let Some(name) = cache.get(&key) {
+                if name.is_some() {
+                    return name.clone();
+                }
+            } else {
+                let manifest = self.workspace.join(&key).join("Cargo.toml");
+                let name = std::fs::read_to_string(manifest)
+                    .ok()
+                    .and_then(|manifest| package_name("Cargo.toml", &manifest, &key));
+                cache.insert(key, name.clone());
+                if name.is_some() {
+                    return name;
+                }
+            }
+            dir = current.parent();
+        }
+        None
+    }
+}
+
+fn changed_symbols(
+    base: &GitTree<'_>,
+    head: &GitTree<'_>,
+    changed_files: &BTreeSet<String>,
+    notes: &mut Vec<String>,
+) -> Result<Vec<ChangedSymbol>, AnalysisError> {
+    let mut extractor =
+        SymbolExtractor::new().map_err(|err| AnalysisError::Message(err.to_string()))?;
+    let mut changed = Vec::new();
+    for file in changed_files {
+        let Some(language) = language_for_path(Path::new(file)) else {
+            continue;
+        };
+        let mut extract = |tree: &GitTree<'_>| -> Result<Vec<Symbol>, AnalysisError> {
+            let Some(source) = tree.read(file)? else {
+                return Ok(Vec::new());
+            };
+            Ok(extractor
+                .extract_from_source(language, file, &source)
+                .unwrap_or_else(|err| {
+                    notes.push(format!("skipped {file}: {err}"));
+                    Vec::new()
+                }))
+        };
+        let before = extract(base)?;
+        let after = extract(head)?;
+        changed.extend(classify_changes(&diff_symbols(
+            file, language, &before, &after,
+        )));
+    }
+    Ok(changed)
+}
+
+/// A signature change re-keys the symbol id, so an added and a removed
+/// symbol with the same name and kind are one modification.
+fn classify_changes(event: &aether_core::SymbolChangeEvent) -> Vec<ChangedSymbol> {
+    let entry = |symbol: &Symbol, change| ChangedSymbol {
+        symbol_id: symbol.id.clone(),
+        qualified_name: symbol.qualified_name.clone(),
+        file_path: symbol.file_path.clone(),
+        language: symbol.language.as_str().to_owned(),
+        kind: symbol.kind.as_str().to_owned(),
+        change,
+        covering_tests: 0,
+        uncovered: false,
+    };
+    let key = |symbol: &Symbol| (symbol.qualified_name.clone(), symbol.kind);
+    let added = event.added.iter().map(key).collect::<HashSet<_>>();
+    let removed = event.removed.iter().map(key).collect::<HashSet<_>>();
+
+    event
+        .updated
+        .iter()
+        .map(|symbol| entry(symbol, SymbolChangeType::Modified))
+        .chain(event.added.iter().map(|symbol| {
+            let change = if removed.contains(&key(symbol)) {
+                SymbolChangeType::Modified
+            } else {
+                SymbolChangeType::Added
+            };
+            entry(symbol, change)
+        }))
+        .chain(
+            event
+                .removed
+                .iter()
+                .filter(|symbol| !added.contains(&key(symbol)))
+                .map(|symbol| entry(symbol, SymbolChangeType::Removed)),
+        )
+        .filter(|symbol| !CONTAINED_KINDS.contains(&symbol.kind.as_str()))
+        .collect()
+}
+
+/// Selected intents by index into `intents`. Updates `covering_tests` on
+/// every changed symbol.
+fn select_tests(
+    changed: &mut [ChangedSymbol],
+    changed_files: &BTreeSet<String>,
+    intents: &[TestIntentRecord],
+    edges: &[LocatedEdgeRecord],
+    tested_by: &[TestedByRecord],
+    max_depth: usize,
+) -> BTreeMap<usize, Selection> {
+    // Tests with a symbol are matched by id; the rest (jest `it` blocks)
+    // by the file node their top-level calls hang off.
+    let mut intents_by_node = HashMap::<String, Vec<usize>>::new();
+    let mut intents_by_file = HashMap::<&str, Vec<usize>>::new();
+    for (index, intent) in intents.iter().enumerate() {
+        let node = intent
+            .symbol_id
+            .clone()
+            .unwrap_or_else(|| file_source_id(&intent.file_path));
+        intents_by_node.entry(node).or_default().push(index);
+        intents_by_file
+            .entry(intent.file_path.as_str())
+            .or_default()
+            .push(index);
+    }
+
+    let mut incoming = HashMap::<&str, Vec<&str>>::new();
+    for edge in edges {
+        let key = edge
+            .target_id
+            .as_deref()
+            .unwrap_or(edge.target_qualified_name.as_str());
+        if key != edge.source_id {
+            incoming
+                .entry(key)
+                .or_default()
+                .push(edge.source_id.as_str());
+        }
+    }
+
+    let mut selections = BTreeMap::<usize, Selection>::new();
+    let mut select = |index: usize,
+                      reason: TestSelectionReason,
+                      depth: Option<usize>,
+                      confidence: Option<f32>,
+                      trigger: &str| {
+        selections
+            .entry(index)
+            .and_modify(|selection| selection.merge(reason, depth, confidence))
+            .or_insert_with(|| Selection {
+                reason,
+                depth,
+                confidence,
+                triggered_by: BTreeSet::new(),
+            })
+            .triggered_by
+            .insert(trigger.to_owned());
+    };
+
+    for file in changed_files {
+        for &index in intents_by_node
+            .get(&file_source_id(file))
+            .into_iter()
+            .flatten()
+        {
+            select(index, TestSelectionReason::Changed, None, None, file);
+        }
+    }
+
+    for symbol in changed.iter_mut() {
+        let mut covering = HashSet::new();
+        if symbol.change != SymbolChangeType::Removed {
+            for &index in intents_by_node.get(&symbol.symbol_id).into_iter().flatten() {
+                select(
+                    index,
+                    TestSelectionReason::Changed,
+                    Some(0),
+                    None,
+                    &symbol.qualified_name,
+                );
+                covering.insert(index);
+            }
+        }
+
+        let mut visited = HashSet::from([symbol.symbol_id.as_str()]);
+        let mut queue = VecDeque::new();
+        for seed in [symbol.symbol_id.as_str(), symbol.qualified_name.as_str()] {
+            for &caller in incoming.get(seed).into_iter().flatten() {
+                if visited.insert(caller) {
+                    queue.push_back((caller, 1));
+                }
+            }
+        }
+        while let Some((node, depth)) = queue.pop_front() {
+            for &index in intents_by_node.get(node).into_iter().flatten() {
+                select(
+                    index,
+                    TestSelectionReason::Graph,
+                    Some(depth),
+                    None,
+                    &symbol.qualified_name,
+                );
+                covering.insert(index);
+            }
+            if depth >= max_depth {
+                continue;
+            }
+            for &caller in incoming.get(node).into_iter().flatten() {
+                if visited.insert(caller) {
+                    queue.push_back((caller, depth + 1));
+                }
+            }
+        }
+
+        for link in tested_by
+            .iter()
+            .filter(|link| link.target_file == symbol.file_path)
+        {
+            for &index in intents_by_file
+                .get(link.test_file.as_str())
+                .into_iter()
+                .flatten()
+            {
+                select(
+                    index,
+                    TestSelectionReason::TestedBy,
+                    None,
+                    Some(link.confidence.clamp(0.0, 1.0)),
+                    &symbol.qualified_name,
+                );
+                covering.insert(index);
+            }
+        }
+        symbol.covering_tests = covering.len();
+    }
+    selections
+}
+
+fn is_uncovered(symbol: &ChangedSymbol) -> bool {
+    symbol.change != SymbolChangeType::Removed
+        && symbol.covering_tests == 0
+        && !is_test_path(&symbol.file_path)
+}
+
+/// `cargo test` substring filter, pytest node id or jest full test name.
+fn test_selector(
+    runner: TestRunner,
+    intent: &TestIntentRecord,
+    qualified_name: Option<&str>,
+) -> String {
+    let test_name = intent.test_name.as_str();
+    match runner {
+        TestRunner::Cargo => qualified_name
+            .filter(|name| name.ends_with(test_name))
+            .unwrap_or(test_name)
+            .to_owned(),
+        TestRunner::Pytest => {
+            let path = qualified_name
+                .and_then(|name| name.split_once("::"))
+                .map(|(_, path)| path)
+                .filter(|path| path.ends_with(test_name))
+                .unwrap_or(test_name);
+            format!("{}::{path}", intent.file_path)
+        }
+        TestRunner::Jest => match intent.group_label.as_deref() {
+            Some(group) if test_name != "describe" => {
+                format!("{group} {}", intent.intent_text)
+            }
+            _ => intent.intent_text.clone(),
+        },
+    }
+}
+
+/// One command per runner (and Cargo package) that runs only `tests`.
+fn runner_commands(tests: &[AffectedTest]) -> Vec<RunnerCommand> {
+    let mut cargo = BTreeMap::<Option<&str>, BTreeSet<&str>>::new();
+    let mut pytest = BTreeSet::new();
+    let mut jest_files = BTreeSet::new();
+    let mut jest_names = BTreeSet::new();
+    for test in tests {
+        match test.runner {
+            TestRunner::Cargo => {
+                cargo
+                    .entry(test.package.as_deref())
+                    .or_default()
+                    .insert(test.selector.as_str());
+            }
+            TestRunner::Pytest => {
+                pytest.insert(shell_quote(&test.selector));
+            }
+            TestRunner::Jest => {
+                jest_files.insert(shell_quote(&test.file_path));
+                jest_names.insert(regex_escape(&test.selector));
+            }
+        }
+    }
+
+    let mut commands = Vec::new();
+    for (package, filters) in cargo {
+        let scope = match package {
+            Some(package) => format!("-p {}", shell_quote(package)),
+            None => "--workspace".to_owned(),
+        };
+        commands.push(RunnerCommand {
+            runner: "cargo-test".to_owned(),
+            package: package.map(str::to_owned),
+            command: format!(
+                "cargo test {scope} -- {}",
+                filters
+                    .iter()
+                    .map(|filter| shell_quote(filter))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+        });
+        let expression = filters
+            .iter()
+            .map(|filter| format!("test({filter})"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        commands.push(RunnerCommand {
+            runner: "cargo-nextest".to_owned(),
+            package: package.map(str::to_owned),
+            command: format!("cargo nextest run {scope} -E {}", shell_quote(&expression)),
+        });
+    }
+    if !pytest.is_empty() {
+        commands.push(RunnerCommand {
+            runner: "pytest".to_owned(),
+            package: None,
+            command: format!(
+                "pytest {}",
+                pytest.into_iter().collect::<Vec<_>>().join(" ")
+            ),
+        });
+    }
+    if !jest_files.is_empty() {
+        let pattern = format!("({})", jest_names.into_iter().collect::<Vec<_>>().join("|"));
+        commands.push(RunnerCommand {
+            runner: "jest".to_owned(),
+            package: None,
+            command: format!(
+                "npx jest {} -t {}",
+                jest_files.into_iter().collect::<Vec<_>>().join(" "),
+                shell_quote(&pattern)
+            ),
+        });
+    }
+    commands
+}
+
+fn shell_quote(value: &str) -> String {
+    let safe = !value.is_empty()
+        && value
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || "_-./:=@".contains(ch));
+    if safe {
+        value.to_owned()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
+
+fn regex_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if r"\^$.|?*+()[]{}/".contains(ch) {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_core::{Language, Position, SourceRange, SymbolChangeEvent, SymbolKind};
+
+    use super::*;
+
+    fn changed(id: &str, qualified_name: &str, file_path: &str) -> ChangedSymbol {
+        ChangedSymbol {
+            symbol_id: id.to_owned(),
+            qualified_name: qualified_name.to_owned(),
+            file_path: file_path.to_owned(),
+            language: "rust".to_owned(),
+            kind: "function".to_owned(),
+            change: SymbolChangeType::Modified,
+            covering_tests: 0,
+            uncovered: false,
+        }
+    }
+
+    fn intent(file_path: &str, test_name: &str, symbol_id: Option<&str>) -> TestIntentRecord {
+        TestIntentRecord {
+            intent_id: format!("{file_path}:{test_name}"),
+            file_path: file_path.to_owned(),
+            test_name: test_name.to_owned(),
+            intent_text: test_name.replace('_', " "),
+            group_label: None,
+            language: "rust".to_owned(),
+            symbol_id: symbol_id.map(str::to_owned),
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn edge(source: &str, target_name: &str, target: Option<&str>) -> LocatedEdgeRecord {
+        LocatedEdgeRecord {
+            source_id: source.to_owned(),
+            source_qualified_name: None,
+            source_file: "src/lib.rs".to_owned(),
+            target_qualified_name: target_name.to_owned(),
+            target_id: target.map(str::to_owned),
+            target_file: None,
+            edge_kind: "calls".to_owned(),
+        }
+    }
+
+    fn affected(
+        runner: TestRunner,
+        package: Option<&str>,
+        file: &str,
+        selector: &str,
+    ) -> AffectedTest {
+        AffectedTest {
+            file_path: file.to_owned(),
+            test_name: selector.to_owned(),
+            intent_text: selector.to_owned(),
+            group_label: None,
+            language: String::new(),
+            runner,
+            package: package.map(str::to_owned),
+            selector: selector.to_owned(),
+            reason: TestSelectionReason::Graph,
+            depth: Some(1),
+            confidence: None,
+            triggered_by: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn tests_are_selected_over_reverse_edges_within_depth() {
+        let mut symbols = vec![
+            changed("parse", "parser::parse", "src/parser.rs"),
+            changed("orphan", "util::orphan", "src/util.rs"),
+        ];
+        let intents = vec![
+            intent("src/parser.rs", "parses_input", Some("t-direct")),
+            intent("tests/cli.rs", "runs_cli", Some("t-deep")),
+            intent("tests/far.rs", "too_far", Some("t-far")),
+            intent("tests/guard.rs", "guards_parser", Some("t-guard")),
+        ];
+        let edges = vec![
+            edge("t-direct", "parser::parse", Some("parse")),
+            edge("cli", "parser::parse", Some("parse")),
+            edge("t-deep", "cli::run", Some("cli")),
+            edge("far-1", "cli::run", Some("cli")),
+            edge("far-2", "far_1", Some("far-1")),
+            edge("t-far", "far_2", Some("far-2")),
+        ];
+        let tested_by = vec![TestedByRecord {
+            target_file: "src/parser.rs".to_owned(),
+            test_file: "tests/guard.rs".to_owned(),
+            intent_count: 1,
+            confidence: 0.9,
+            inference_method: "naming_convention".to_owned(),
+        }];
+
+        let selections = select_tests(
+            &mut symbols,
+            &BTreeSet::from(["src/parser.rs".to_owned(), "src/util.rs".to_owned()]),
+            &intents,
+            &edges,
+            &tested_by,
+            3,
+        );
+
+        assert_eq!(
+            selections.keys().copied().collect::<Vec<_>>(),
+            vec![0, 1, 3]
+        );
+        assert_eq!(selections[&0].reason, TestSelectionReason::Graph);
+        assert_eq!(selections[&0].depth, Some(1));
+        assert_eq!(selections[&1].depth, Some(2));
+        assert_eq!(selections[&3].reason, TestSelectionReason::TestedBy);
+        assert_eq!(selections[&3].confidence, Some(0.9));
+        assert_eq!(symbols[0].covering_tests, 3);
+        assert!(is_uncovered(&symbols[1]));
+    }
+
+    #[test]
+    fn changed_tests_and_removed_symbols_are_matched_by_id_and_name() {
+        let mut symbols = vec![
+            changed("t-changed", "tests::checks", "tests/checks.rs"),
+            ChangedSymbol {
+                change: SymbolChangeType::Removed,
+                ..changed("gone", "legacy::gone", "src/legacy.rs")
+            },
+        ];
+        let mut jest = intent("web/app.test.ts", "it", None);
+        jest.language = "typescript".to_owned();
+        let intents = vec![
+            intent("tests/checks.rs", "checks", Some("t-changed")),
+            intent("tests/legacy.rs", "still_calls_gone", Some("t-caller")),
+            jest,
+        ];
+        let edges = vec![edge("t-caller", "legacy::gone", None)];
+
+        let selections = select_tests(
+            &mut symbols,
+            &BTreeSet::from(["web/app.test.ts".to_owned()]),
+            &intents,
+            &edges,
+            &[],
+            2,
+        );
+
+        assert_eq!(selections[&0].reason, TestSelectionReason::Changed);
+        assert_eq!(selections[&1].reason, TestSelectionReason::Graph);
+        assert_eq!(
+            selections[&1].triggered_by,
+            BTreeSet::from(["legacy::gone".to_owned()])
+        );
+        assert_eq!(selections[&2].reason, TestSelectionReason::Changed);
+        assert!(!is_uncovered(&symbols[1]));
+    }
+
+    #[test]
+    fn signature_changes_are_reported_as_modifications() {
+        let symbol = |id: &str, name: &str, kind| Symbol {
+            id: id.to_owned(),
+            language: Language::Rust,
+            file_path: "src/lib.rs".to_owned(),
+            kind,
+            name: name.to_owned(),
+            qualified_name: name.to_owned(),
+            signature_fingerprint: id.to_owned(),
+            content_hash: id.to_owned(),
+            range: SourceRange {
+                start: Position { line: 1, column: 0 },
+                end: Position { line: 1, column: 0 },
+                start_byte: None,
+                end_byte: None,
+            },
+        };
+        let event = SymbolChangeEvent {
+            file_path: "src/lib.rs".to_owned(),
+            language: Language::Rust,
+            added: vec![
+                symbol("open-v2", "open", SymbolKind::Function),
+                symbol("sync", "sync", SymbolKind::Function),
+            ],
+            removed: vec![
+                symbol("open-v1", "open", SymbolKind::Function),
+                symbol("close", "close", SymbolKind::Function),
+                symbol("field", "Config::path", SymbolKind::Field),
+            ],
+            updated: vec![symbol("read", "read", SymbolKind::Function)],
+        };
+
+        let changes = classify_changes(&event)
+            .into_iter()
+            .map(|symbol| (symbol.symbol_id, symbol.change))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            changes,
+            vec![
+                ("read".to_owned(), SymbolChangeType::Modified),
+                ("open-v2".to_owned(), SymbolChangeType::Modified),
+                ("sync".to_owned(), SymbolChangeType::Added),
+                ("close".to_owned(), SymbolChangeType::Removed),
+            ]
+        );
+    }
+
+    #[test]
+    fn runner_commands_filter_each_runner_to_the_selected_tests() {
+        let mut jest = affected(
+            TestRunner::Jest,
+            None,
+            "web/app.test.ts",
+            "App renders (empty)",
+        );
+        jest.group_label = Some("App".to_owned());
+        let tests = vec![
+            affected(
+                TestRunner::Cargo,
+                Some("aether-core"),
+                "crates/core/src/lib.rs",
+                "tests::parses",
+            ),
+            affected(
+                TestRunner::Cargo,
+                Some("aether-core"),
+                "crates/core/tests/it.rs",
+                "round_trip",
+            ),
+            affected(
+                TestRunner::Pytest,
+                None,
+                "tests/test_api.py",
+                "tests/test_api.py::TestApi::test_load",
+            ),
+            jest,
+        ];
+
+        let commands = runner_commands(&tests)
+            .into_iter()
+            .map(|command| command.command)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            commands,
+            vec![
+                "cargo test -p aether-core -- round_trip tests::parses",
+                "cargo nextest run -p aether-core -E 'test(round_trip) | test(tests::parses)'",
+                "pytest tests/test_api.py::TestApi::test_load",
+                "npx jest web/app.test.ts -t '(App renders \\(empty\\))'",
+            ]
+        );
+    }
+
+    #[test]
+    fn selectors_follow_runner_naming() {
+        let mut rust = intent("src/lib.rs", "parses", Some("id"));
+        assert_eq!(
+            test_selector(TestRunner::Cargo, &rust, Some("tests::parses")),
+            "tests::parses"
+        );
+        rust.test_name = "other".to_owned();
+        assert_eq!(
+            test_selector(TestRunner::Cargo, &rust, Some("tests::parses")),
+            "other"
+        );
+
+        let python = intent("tests/test_api.py", "test_load", Some("id"));
+        assert_eq!(
+            test_selector(
+                TestRunner::Pytest,
+                &python,
+                Some("tests.test_api::TestApi::test_load")
+            ),
+            "tests/test_api.py::TestApi::test_load"
+        );
+        assert_eq!(
+            test_selector(TestRunner::Pytest, &python, None),
+            "tests/test_api.py::test_load"
+        );
+
+        let mut jest = intent("web/app.test.ts", "it", None);
+        jest.intent_text = "renders".to_owned();
+        jest.group_label = Some("App".to_owned());
+        assert_eq!(test_selector(TestRunner::Jest, &jest, None), "App renders");
+    }
+}
@@ -0,0 +1,927 @@
+//! Exported API surface per package compared across git refs: added, removed
+//! and signature-changed items, plus silent behavior changes where the
+//! signature held but the SIR shifted below the verify-intent threshold.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
+use std::process::Command;
+
+use aether_core::{Language, Symbol, SymbolKind, normalize_path};
+use aether_parse::{SymbolExtractor, language_for_path};
+use aether_store::{SirHistoryBaselineSelector, SirHistoryStore, SirStateStore, SqliteStore};
+use serde::{Deserialize, Serialize};
+
+use crate::coupling::AnalysisError;
+use crate::refactor::SimilarityEngine;
+
+/// Same bar `verify-intent` uses for "meaning preserved".
+pub const DEFAULT_RELEASE_CHECK_THRESHOLD: f64 = 0.85;
+const RELEASE_CHECK_SCHEMA_VERSION: &str = "1.0";
+const ROOT_PACKAGE: &str = ".";
+const PACKAGE_MANIFESTS: &[&str] = &["Cargo.toml", "package.json", "pyproject.toml", "setup.py"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReleaseCheckRequest {
+    pub base_ref: String,
+    /// Compared ref; the working tree when `None`.
+    pub head_ref: Option<String>,
+    pub threshold: f64,
+    /// Only report these packages when non-empty.
+    pub packages: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiItem {
+    pub package: String,
+    pub language: String,
+    pub kind: String,
+    pub qualified_name: String,
+    pub file_path: String,
+    pub symbol_id: String,
+    pub signature_fingerprint: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiChangeKind {
+    Removed,
+    SignatureChanged,
+    SilentBehaviorChange,
+    Added,
+}
+
+impl ApiChangeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Removed => "removed",
+            Self::SignatureChanged => "signature_changed",
+            Self::SilentBehaviorChange => "silent_behavior_change",
+            Self::Added => "added",
+        }
+    }
+
+    pub fn is_breaking(self) -> bool {
+        matches!(self, Self::Removed | Self::SignatureChanged)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiChange {
+    pub kind: ApiChangeKind,
+    pub package: String,
+    pub qualified_name: String,
+    pub symbol_kind: String,
+    pub language: String,
+    pub file_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before_signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after_signature: Option<String>,
+    /// SIR similarity between the refs, for silent behavior changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub similarity: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageApiSummary {
+    pub package: String,
+    pub exported_before: usize,
+    pub exported_after: usize,
+    pub added: usize,
+    pub removed: usize,
+    pub signature_changed: usize,
+    pub silent_behavior_changes: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReleaseCheckReport {
+    pub schema_version: String,
+    pub base_ref: String,
+    pub base_commit: String,
+    pub head_ref: String,
+    pub head_commit: Option<String>,
+    pub threshold: f64,
+    /// Any removed or signature-changed export.
+    pub breaking: bool,
+    pub packages: Vec<PackageApiSummary>,
+    pub changes: Vec<ApiChange>,
+    /// Unchanged signatures whose SIR was missing at one of the refs.
+    pub unchecked_behavior: usize,
+    pub used_embeddings: bool,
+    pub notes: Vec<String>,
+}
+
+pub fn check_release(
+    workspace: &Path,
+    store: &SqliteStore,
+    request: &ReleaseCheckRequest,
+) -> Result<ReleaseCheckReport, AnalysisError> {
+    let base_commit = resolve_commit(workspace, request.base_ref.as_str())?;
+    let head_commit = request
+        .head_ref
+        .as_deref()
+        .map(|head_ref| resolve_commit(workspace, head_ref))
+        .transpose()?;
+
+    let mut notes = Vec::new();
+    let mut extractor =
+        SymbolExtractor::new().map_err(|err| AnalysisError::Message(err.to_string()))?;
+    let base_tree = GitTree {
+        workspace,
+        commit: Some(base_commit.clone()),
+    };
+    let head_tree = GitTree {
+        workspace,
+        commit: head_commit.clone(),
+    };
+    let package_filter = request.packages.iter().cloned().collect::<HashSet<_>>();
+    let keep = |items: Vec<ApiItem>| {
+        items
+            .into_iter()
+            .filter(|item| package_filter.is_empty() || package_filter.contains(&item.package))
+            .collect::<Vec<_>>()
+    };
+    let before = keep(collect_api_surface(&base_tree, &mut extractor, &mut notes)?);
+    let after = keep(collect_api_surface(&head_tree, &mut extractor, &mut notes)?);
+
+    let (mut changes, unchanged) = diff_api_surfaces(&before, &after);
+
+    let mut unchecked_behavior = 0;
+    let mut used_embeddings = false;
+    if !unchanged.is_empty() {
+        let base_time = commit_time(workspace, base_commit.as_str())?;
+        let head_time = head_commit
+            .as_deref()
+            .map(|commit| commit_time(workspace, commit))
+            .transpose()?;
+        let mut engine = SimilarityEngine::new(workspace)?;
+        for (base_item, head_item) in unchanged {
+            let base_sir = sir_at_commit(store, &base_item.symbol_id, &base_commit, base_time)?;
+            let head_sir = match (head_commit.as_deref(), head_time) {
+                (Some(commit), Some(time)) => {
+                    sir_at_commit(store, &head_item.symbol_id, commit, time)?
+                }
+                _ => store.read_sir_blob(&head_item.symbol_id)?,
+            };
+            let (Some(base_sir), Some(head_sir)) = (base_sir, head_sir) else {
+                unchecked_behavior += 1;
+                continue;
+            };
+            if base_sir == head_sir {
+                continue;
+            }
+            let (similarity, method, _) = engine.compare(&base_sir, &head_sir);
+            if similarity < request.threshold {
+                changes.push(ApiChange {
+                    kind: ApiChangeKind::SilentBehaviorChange,
+                    package: head_item.package.clone(),
+                    qualified_name: head_item.qualified_name.clone(),
+                    symbol_kind: head_item.kind.clone(),
+                    language: head_item.language.clone(),
+                    file_path: head_item.file_path.clone(),
+                    before_signature: None,
+                    after_signature: None,
+                    similarity: Some(similarity),
+                    method: Some(method),
+                });
+            }
+        }
+        used_embeddings = engine.used_embeddings;
+        notes.append(&mut engine.notes);
+    }
+    if unchecked_behavior > 0 {
+        notes.push(format!(
+            "{unchecked_behavior} export(s) with unchanged signatures had no SIR at one of the refs; \
+             behavior was not compared"
+        ));
+    }
+
+    changes.sort_by(|left, right| {
+        left.package
+            .cmp(&right.package)
+            .then_with(|| left.kind.cmp(&right.kind))
+            .then_with(|| left.qualified_name.cmp(&right.qualified_name))
+            .then_with(|| left.file_path.cmp(&right.file_path))
+    });
+
+    Ok(ReleaseCheckReport {
+        schema_version: RELEASE_CHECK_SCHEMA_VERSION.to_owned(),
+        base_ref: request.base_ref.clone(),
+        base_commit,
+        head_ref: request
+            .head_ref
+            .clone()
+            .unwrap_or_else(|| "working tree".to_owned()),
+        head_commit,
+        threshold: request.threshold,
+        breaking: changes.iter().any(|change| change.kind.is_breaking()),
+        packages: summarize_packages(&before, &after, &changes),
+        changes,
+        unchecked_behavior,
+        used_embeddings,
+        notes,
+    })
+}
+
+/// Symbols of one file that are part of its public API: `pub` items in Rust
+/// (trait methods and enum variants follow their parent), `export`ed
+/// declarations and members of exported classes in TypeScript/JavaScript,
+/// and `__all__` or non-underscore names in Python.
+pub fn exported_symbols<'a>(
+    language: Language,
+    source: &str,
+    symbols: &'a [Symbol],
+) -> Vec<&'a Symbol> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let python_all = (language == Language::Python)
+        .then(|| python_dunder_all(source))
+        .flatten();
+    let ts_exports = matches!(
+        language,
+        Language::TypeScript | Language::Tsx | Language::JavaScript | Language::Jsx
+    )
+    .then(|| ts_export_list_names(source))
+    .unwrap_or_default();
+    let context = ExportContext {
+        language,
+        lines: &lines,
+        symbols,
+        python_all: python_all.as_ref(),
+        ts_exports: &ts_exports,
+    };
+
+    symbols
+        .iter()
+        .filter(|symbol| context.is_exported(symbol))
+        .collect()
+}
+
+struct ExportContext<'a> {
+    language: Language,
+    lines: &'a [&'a str],
+    symbols: &'a [Symbol],
+    python_all: Option<&'a BTreeSet<String>>,
+    ts_exports: &'a BTreeSet<String>,
+}
+
+impl ExportContext<'_> {
+    fn is_exported(&self, symbol: &Symbol) -> bool {
+        let line = self.declaration_line(symbol);
+        let container = self.container(symbol);
+        match self.language {
+            Language::Rust => {
+                if line.starts_with("pub(") {
+                    return false;
+                }
+                if line.starts_with("pub ") {
+                    return container.is_none_or(|parent| self.is_exported(parent));
+                }
+                match (symbol.kind, container) {
+                    (SymbolKind::EnumVariant, Some(parent)) => {
+                        parent.kind == SymbolKind::Enum && self.is_exported(parent)
+                    }
+                    (SymbolKind::Method, Some(parent)) if parent.kind == SymbolKind::Trait => {
+                        self.is_exported(parent)
+                    }
+                    // Trait impl methods are reachable wherever the trait is.
+                    (SymbolKind::Method, _) => {
+                        self.enclosing_header(symbol).is_some_and(|header| {
+                            header.starts_with("impl") && header.contains(" for ")
+                        })
+                    }
+                    _ => false,
+                }
+            }
+            Language::TypeScript | Language::Tsx | Language::JavaScript | Language::Jsx => {
+                match container {
+                    Some(parent) => {
+                        !(line.starts_with("private ")
+                            || line.starts_with("protected ")
+                            || line.starts_with('#'))
+                            && self.is_exported(parent)
+                    }
+                    None => line.starts_with("export ") || self.ts_exports.contains(&symbol.name),
+                }
+            }
+            Language::Python => {
+                let dunder = symbol.name.starts_with("__") && symbol.name.ends_with("__");
+                if symbol.name.starts_with('_') && !dunder {
+                    return false;
+                }
+                match container {
+                    Some(parent) => self.is_exported(parent),
+                    None => self
+                        .python_all
+                        .is_none_or(|names| names.contains(&symbol.name)),
+                }
+            }
+            Language::Plugin(_) => false,
+        }
+    }
+
+    /// First line of the declaration, skipping attributes and decorators.
+    fn declaration_line(&self, symbol: &Symbol) -> &str {
+        let start = symbol.range.start.line.saturating_sub(1);
+        self.lines
+            .iter()
+            .skip(start)
+            .take(symbol.range.end.line.saturating_sub(start).max(1))
+            .map(|line| line.trim())
+            .find(|line| !(line.is_empty() || line.starts_with("#[") || line.starts_with('@')))
+            .unwrap_or_default()
+    }
+
+    /// Innermost symbol whose range strictly contains `symbol`.
+    fn container(&self, symbol: &Symbol) -> Option<&Symbol> {
+        let start = (symbol.range.start.line, symbol.range.start.column);
+        let end = (symbol.range.end.line, symbol.range.end.column);
+        self.symbols
+            .iter()
+            .filter(|candidate| candidate.id != symbol.id)
+            .filter(|candidate| {
+                let candidate_start = (candidate.range.start.line, candidate.range.start.column);
+                let candidate_end = (candidate.range.end.line, candidate.range.end.column);
+                candidate_start <= start
+                    && end <= candidate_end
+                    && (candidate_start, candidate_end) != (start, end)
+            })
+            .min_by_key(|candidate| {
+                (
+                    candidate.range.end.line - candidate.range.start.line,
+                    candidate.range.end.column,
+                )
+            })
+    }
+
+    /// Nearest less-indented line above the declaration, e.g. an `impl` header.
+    fn enclosing_header(&self, symbol: &Symbol) -> Option<&str> {
+        let start = symbol.range.start.line.saturating_sub(1);
+        let indent = |line: &str| line.len() - line.trim_start().len();
+        let own_indent = indent(self.lines.get(start)?);
+        self.lines[..start]
+            .iter()
+            .rev()
+            .find(|line| !line.trim().is_empty() && indent(line) < own_indent)
+            .map(|line| line.trim())
+    }
+}
+
+/// Names listed in a top-level `__all__ = [...]`, if the module declares one.
+fn python_dunder_all(source: &str) -> Option<BTreeSet<String>> {
+    let start = source
+        .lines()
+        .scan(0, |offset, line| {
+            let line_start = *offset;
+            *offset += line.len() + 1;
+            Some((line_start, line))
+        })
+        .find(|(_, line)| line.starts_with("__all__") && line.contains('='))
+        .map(|(offset, _)| offset)?;
+    let rest = &source[start..];
+    let open = rest.find(['[', '('])?;
+    let close = rest[open..].find([']', ')'])? + open;
+    Some(quoted_strings(&rest[open + 1..close]))
+}
+
+fn quoted_strings(text: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '"' || ch == '\'' {
+            let name = chars
+                .by_ref()
+                .take_while(|next| *next != ch)
+                .collect::<String>();
+            if !name.is_empty() {
+                names.insert(name);
+            }
+        }
+    }
+    names
+}
+
+/// Local names exported through `export { a, b as c }` lists.
+fn ts_export_list_names(source: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    let mut rest = source;
+    while let Some(index) = rest.find("export {") {
+        let after = &rest[index + "export {".len()..];
+        let Some(close) = after.find('}') else {
+            break;
+        };
+        for entry in after[..close].split(',') {
+            let local = entry.split(" as ").next().unwrap_or_default().trim();
+            let local = local.strip_prefix("type ").unwrap_or(local).trim();
+            if !local.is_empty() {
+                names.insert(local.to_owned());
+            }
+        }
+        rest = &after[close..];
+    }
+    names
+}
+
+/// Matches exports by package, language, kind and qualified name; returns
+/// the added/removed/signature changes and the pairs whose signature held.
+fn diff_api_surfaces<'a>(
+    before: &'a [ApiItem],
+    after: &'a [ApiItem],
+) -> (Vec<ApiChange>, Vec<(&'a ApiItem, &'a ApiItem)>) {
+    type Key<'k> = (&'k str, &'k str, &'k str, &'k str);
+    fn group(items: &[ApiItem]) -> BTreeMap<Key<'_>, Vec<&ApiItem>> {
+        let mut grouped = BTreeMap::<Key<'_>, Vec<&ApiItem>>::new();
+        for item in items {
+            grouped
+                .entry((
+                    item.package.as_str(),
+                    item.language.as_str(),
+                    item.kind.as_str(),
+                    item.qualified_name.as_str(),
+                ))
+                .or_default()
+                .push(item);
+        }
+        for group in grouped.values_mut() {
+            group.sort_by(|left, right| {
+                left.signature_fingerprint
+                    .cmp(&right.signature_fingerprint)
+                    .then_with(|| left.file_path.cmp(&right.file_path))
+            });
+        }
+        grouped
+    }
+    let change = |kind, item: &ApiItem, before: Option<String>, after: Option<String>| ApiChange {
+        kind,
+        package: item.package.clone(),
+        qualified_name: item.qualified_name.clone(),
+        symbol_kind: item.kind.clone(),
+        language: item.language.clone(),
+        file_path: item.file_path.clone(),
+        before_signature: before,
+        after_signature: after,
+        similarity: None,
+        method: None,
+    };
+    let signatures = |items: &[&ApiItem]| {
+        items
+            .iter()
+            .map(|item| item.signature_fingerprint.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let before = group(before);
+    let after = group(after);
+    let mut changes = Vec::new();
+    let mut unchanged = Vec::new();
+    for (key, base_items) in &before {
+        let Some(head_items) = after.get(key) else {
+            changes.push(change(
+                ApiChangeKind::Removed,
+                base_items[0],
+                Some(signatures(base_items).join(", ")),
+                None,
+            ));
+            continue;
+        };
+        let (base_signatures, head_signatures) = (signatures(base_items), signatures(head_items));
+        if base_signatures == head_signatures {
+            unchanged.extend(base_items.iter().copied().zip(head_items.iter().copied()));
+        } else {
+            changes.push(change(
+                ApiChangeKind::SignatureChanged,
+                head_items[0],
+                Some(base_signatures.join(", ")),
+                Some(head_signatures.join(", ")),
+            ));
+        }
+    }
+    for (key, head_items) in &after {
+        if !before.contains_key(key) {
+            changes.push(change(
+                ApiChangeKind::Added,
+                head_items[0],
+                None,
+                Some(signatures(head_items).join(", ")),
+            ));
+        }
+    }
+    (changes, unchanged)
+}
+
+fn summarize_packages(
+    before: &[ApiItem],
+    after: &[ApiItem],
+    changes: &[ApiChange],
+) -> Vec<PackageApiSummary> {
+    let mut summaries = BTreeMap::<String, PackageApiSummary>::new();
+    let packages = before
+        .iter()
+        .chain(after)
+        .map(|item| item.package.as_str())
+        .chain(changes.iter().map(|change| change.package.as_str()));
+    for package in packages {
+        summaries
+            .entry(package.to_owned())
+            .or_insert_with(|| PackageApiSummary {
+                package: package.to_owned(),
+                exported_before: 0,
+                exported_after: 0,
+                added: 0,
+                removed: 0,
+                signature_changed: 0,
+                silent_behavior_changes: 0,
+            });
+    }
+    for item in before {
+        if let Some(summary) = summaries.get_mut(&item.package) {
+            summary.exported_before += 1;
+        }
+    }
+    for item in after {
+        if let Some(summary) = summaries.get_mut(&item.package) {
+            summary.exported_after += 1;
+        }
+    }
+    for change in changes {
+        let Some(summary) = summaries.get_mut(&change.package) else {
+            continue;
+        };
+        match change.kind {
+            ApiChangeKind::Added => summary.added += 1,
+            ApiChangeKind::Removed => summary.removed += 1,
+            ApiChangeKind::SignatureChanged => summary.signature_changed += 1,
+            ApiChangeKind::SilentBehaviorChange => summary.silent_behavior_changes += 1,
+        }
+    }
+    summaries.into_values().collect()
+}
+
+/// Files at a commit, or the working tree when `commit` is `None`.
+pub(crate) struct GitTree<'a> {
+    pub(crate) workspace: &'a Path,
+    pub(crate) commit: Option<String>,
+}
+
+impl GitTree<'_> {
+    fn files(&self) -> Result<Vec<String>, AnalysisError> {
+        let listing = match self.commit.as_deref() {
+            Some(commit) => git_stdout(self.workspace, &["ls-tree", "-r", "--name-only", commit])?,
+            None => git_stdout(
+                self.workspace,
+                &["ls-files", "--cached", "--others", "--exclude-standard"],
+            )?,
+        };
+        Ok(listing
+            .lines()
+            .map(|line| normalize_path(line.trim()))
+            .filter(|line| !line.is_empty())
+            .collect())
+    }
+
+    pub(crate) fn read(&self, path: &str) -> Result<Option<String>, AnalysisError> {
+        match self.commit.as_deref() {
+            Some(commit) => {
+                let output = git_command(self.workspace)
+                    .args(["show", format!("{commit}:{path}").as_str()])
+                    .output()?;
+                Ok(output
+                    .status
+                    .success()
+                    .then(|| String::from_utf8_lossy(&output.stdout).into_owned()))
+            }
+            None => match std::fs::read_to_string(self.workspace.join(path)) {
+                Ok(source) => Ok(Some(source)),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(err.into()),
+            },
+        }
+    }
+}
+
+fn collect_api_surface(
+    tree: &GitTree<'_>,
+    extractor: &mut SymbolExtractor,
+    notes: &mut Vec<String>,
+) -> Result<Vec<ApiItem>, AnalysisError> {
+    let files = tree.files()?;
+
+    let mut packages = BTreeMap::<String, String>::new();
+    for file in &files {
+        let path = Path::new(file);
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !PACKAGE_MANIFESTS.contains(&file_name) {
+            continue;
+        }
+        let dir = path
+            .parent()
+            .map(|parent| normalize_path(&parent.to_string_lossy()))
+            .unwrap_or_default();
+        let Some(manifest) = tree.read(file)? else {
+            continue;
+        };
+        if let Some(name) = package_name(file_name, &manifest, &dir) {
+            packages.insert(dir, name);
+        }
+    }
+
+    let mut items = Vec::new();
+    for file in &files {
+        let Some(language) = language_for_path(Path::new(file)) else {
+            continue;
+        };
+        let Some(source) = tree.read(file)? else {
+            continue;
+        };
+        let symbols = match extractor.extract_from_source(language, file, &source) {
+            Ok(symbols) => symbols,
+            Err(err) => {
+                notes.push(format!("skipped {file}: {err}"));
+                continue;
+            }
+        };
+        let package = package_for_path(&packages, file);
+        items.extend(
+            exported_symbols(language, &source, &symbols)
+                .into_iter()
+                .map(|symbol| ApiItem {
+                    package: package.clone(),
+                    language: symbol.language.as_str().to_owned(),
+                    kind: symbol.kind.as_str().to_owned(),
+                    qualified_name: symbol.qualified_name.clone(),
+                    file_path: symbol.file_path.clone(),
+                    symbol_id: symbol.id.clone(),
+                    signature_fingerprint: symbol.signature_fingerprint.clone(),
+                }),
+        );
+    }
+    Ok(items)
+}
+
+/// Declared package name; workspace-only manifests declare none.
+pub(crate) fn package_name(file_name: &str, manifest: &str, dir: &str) -> Option<String> {
+    let name = match file_name {
+        "Cargo.toml" => toml::from_str::<toml::Value>(manifest)
+            .ok()?
+            .get("package")?
+            .get("name")?
+            .as_str()
+            .map(str::to_owned),
+        "package.json" => serde_json::from_str::<serde_json::Value>(manifest)
+            .ok()?
+            .get("name")?
+            .as_str()
+            .map(str::to_owned),
+        "pyproject.toml" => {
+            let value = toml::from_str::<toml::Value>(manifest).ok()?;
+            value
+                .get("project")
+                .and_then(|project| project.get("name"))
+                .or_else(|| {
+                    value
+                        .get("tool")
+                        .and_then(|tool| tool.get("poetry"))
+                        .and_then(|poetry| poetry.get("name"))
+                })?
+                .as_str()
+                .map(str::to_owned)
+        }
+        _ => Path::new(dir)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned()),
+    };
+    name.filter(|name| !name.trim().is_empty())
+}
+
+/// The package whose manifest directory is the longest prefix of `path`.
+fn package_for_path(packages: &BTreeMap<String, String>, path: &str) -> String {
+    packages
+        .iter()
+        .filter(|(dir, _)| {
+            dir.is_empty()
+                || path
+                    .strip_prefix(dir.as_str())
+                    .is_some_and(|rest| rest.starts_with('/'))
+        })
+        .max_by_key(|(dir, _)| dir.len())
+        .map(|(_, name)| name.clone())
+        .unwrap_or_else(|| ROOT_PACKAGE.to_owned())
+}
+
+/// SIR recorded at `commit`, else the newest version from before it.
+fn sir_at_commit(
+    store: &SqliteStore,
+    symbol_id: &str,
+    commit: &str,
+    commit_time: i64,
+) -> Result<Option<String>, AnalysisError> {
+    let record = match store.resolve_sir_baseline_by_selector(
+        symbol_id,
+        SirHistoryBaselineSelector::CommitHash(commit.to_owned()),
+    )? {
+        Some(record) => Some(record),
+        None => store.resolve_sir_baseline_by_selector(
+            symbol_id,
+            SirHistoryBaselineSelector::CreatedAt(commit_time),
+        )?,
+    };
+    Ok(record.map(|record| record.sir_json))
+}
+
+pub(crate) fn resolve_commit(workspace: &Path, reference: &str) -> Result<String, AnalysisError> {
+    let reference = reference.trim();
+    if reference.is_empty() || reference.starts_with('-') {
+        return Err(AnalysisError::Git(format!("invalid git ref '{reference}'")));
+    }
+    let spec = format!("{reference}^{{commit}}");
+    Ok(
+        git_stdout(workspace, &["rev-parse", "--verify", spec.as_str()])?
+            .trim()
+            .to_ascii_lowercase(),
+    )
+}
+
+fn commit_time(workspace: &Path, commit: &str) -> Result<i64, AnalysisError> {
+    git_stdout(workspace, &["show", "-s", "--format=%ct", commit])?
+        .trim()
+        .parse::<i64>()
+        .map_err(|err| AnalysisError::Git(format!("invalid commit time for {commit}: {err}")))
+}
+
+fn git_command(workspace: &Path) -> Command {
+    let mut command = Command::new("git");
+    command.arg("-C").arg(workspace);
+    command
+}
+
+pub(crate) fn git_stdout(workspace: &Path, args: &[&str]) -> Result<String, AnalysisError> {
+    let output = git_command(workspace).args(args).output()?;
+    if !output.status.success() {
+        return Err(AnalysisError::Git(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exported_names(language: Language, path: &str, source: &str) -> Vec<String> {
+        let mut extractor = SymbolExtractor::new().expect("extractor");
+        let symbols = extractor
+            .extract_from_source(language, path, source)
+            .expect("extract");
+        let mut names = exported_symbols(language, source, &symbols)
+            .into_iter()
+            .map(|symbol| symbol.qualified_name.clone())
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    fn item(package: &str, name: &str, signature: &str) -> ApiItem {
+        ApiItem {
+            package: package.to_owned(),
+            language: "rust".to_owned(),
+            kind: "function".to_owned(),
+            qualified_name: name.to_owned(),
+            file_path: "src/lib.rs".to_owned(),
+            symbol_id: format!("{name}-{signature}"),
+            signature_fingerprint: signature.to_owned(),
+        }
+    }
+
+    #[test]
+    fn exported_symbols_follow_each_language_visibility_rules() {
+        let rust = exported_names(
+            Language::Rust,
+            "src/lib.rs",
+            r#"
+pub fn open() {}
+pub(crate) fn internal() {}
+fn private() {}
+
+pub trait Store {
+    fn get(&self);
+}
+
+pub struct Handle;
+
+impl Handle {
+    pub fn close(&self) {}
+    fn reset(&self) {}
+}
+
+impl Store for Handle {
+    fn get(&self) {}
+}
+"#,
+        );
+        assert!(rust.contains(&"open".to_owned()));
+        assert!(rust.contains(&"Store".to_owned()));
+        assert!(rust.contains(&"Handle::get".to_owned()));
+        assert!(rust.iter().any(|name| name.ends_with("close")));
+        assert!(!rust.iter().any(|name| name.ends_with("internal")));
+        assert!(!rust.iter().any(|name| name.ends_with("private")));
+        assert!(!rust.iter().any(|name| name.ends_with("reset")));
+
+        let python = exported_names(
+            Language::Python,
+            "pkg/api.py",
+            "__all__ = [\"load\"]\n\ndef load():\n    pass\n\ndef save():\n    pass\n\ndef _helper():\n    pass\n",
+        );
+        assert_eq!(python, vec!["pkg.api::load".to_owned()]);
+
+        let typescript = exported_names(
+            Language::TypeScript,
+            "src/index.ts",
+            "export function connect() {}\nfunction local() {}\nfunction shared() {}\nexport { shared };\n",
+        );
+        assert_eq!(typescript, vec!["connect".to_owned(), "shared".to_owned()]);
+    }
+
+    #[test]
+    fn diff_classifies_added_removed_and_signature_changes() {
+        let before = vec![
+            item("core", "open", "sig-a"),
+            item("core", "close", "sig-b"),
+            item("core", "flush", "sig-c"),
+        ];
+        let after = vec![
+            item("core", "open", "sig-a"),
+            item("core", "close", "sig-b2"),
+            item("core", "sync", "sig-d"),
+        ];
+
+        let (changes, unchanged) = diff_api_surfaces(&before, &after);
+        let kinds = changes
+            .iter()
+            .map(|change| (change.kind, change.qualified_name.as_str()))
+            .collect::<Vec<_>>();
+        assert!(kinds.contains(&(ApiChangeKind::SignatureChanged, "close")));
+        assert!(kinds.contains(&(ApiChangeKind::Removed, "flush")));
+        assert!(kinds.contains(&(ApiChangeKind::Added, "sync")));
+        assert_eq!(changes.len(), 3);
+        assert_eq!(unchanged.len(), 1);
+        assert_eq!(unchanged[0].0.qualified_name, "open");
+
+        let summaries = summarize_packages(&before, &after, &changes);
+        assert_eq!(
+            summaries,
+            vec![PackageApiSummary {
+                package: "core".to_owned(),
+                exported_before: 3,
+                exported_after: 3,
+                added: 1,
+                removed: 1,
+                signature_changed: 1,
+                silent_behavior_changes: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn packages_resolve_to_nearest_named_manifest() {
+        assert_eq!(
+            package_name("Cargo.toml", "[workspace]\nmembers = []\n", ""),
+            None
+        );
+        assert_eq!(
+            package_name(
+                "Cargo.toml",
+                "[package]\nname = \"aether-core\"\n",
+                "crates/core"
+            )
+            .as_deref(),
+            Some("aether-core")
+        );
+        assert_eq!(
+            package_name("package.json", r#"{"name": "@aether/sdk"}"#, "sdk").as_deref(),
+            Some("@aether/sdk")
+        );
+
+        let packages = BTreeMap::from([
+            ("crates/core".to_owned(), "aether-core".to_owned()),
+            ("sdk".to_owned(), "@aether/sdk".to_owned()),
+        ]);
+        assert_eq!(
+            package_for_path(&packages, "crates/core/src/lib.rs"),
+            "aether-core"
+        );
+        assert_eq!(
+            package_for_path(&packages, "crates/core-extra/src/lib.rs"),
+            "."
+        );
+        assert_eq!(
+            package_for_path(&packages, "sdk/src/index.ts"),
+            "@aether/sdk"
+        );
+    }
+}
@@ -0,0 +1,502 @@
+//! Declared architecture layers (`.aether/architecture.toml`) checked
+//! against the structural dependency graph.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use aether_core::{EdgeKind, normalize_path};
+use aether_store::{LocatedEdgeRecord, SqliteStore};
+use serde::{Deserialize, Serialize};
+
+use crate::coupling::{AnalysisError, wildcard_match};
+
+pub const ARCHITECTURE_SPEC_PATH: &str = ".aether/architecture.toml";
+const ARCHITECTURE_SCHEMA_VERSION: &str = "1.0";
+const CHECKED_EDGE_KINDS: &[EdgeKind] = &[EdgeKind::Calls, EdgeKind::DependsOn, EdgeKind::TypeRef];
+
+/// Layers declared in `.aether/architecture.toml`:
+///
+/// ```toml
+/// [[layer]]
+/// name = "core"
+/// paths = ["crates/aether-core/*"]
+///
+/// [[layer]]
+/// name = "store"
+/// paths = ["crates/aether-store/*"]
+/// symbols = ["aether_store::*"]
+/// allow = ["core"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ArchitectureSpec {
+    #[serde(default, rename = "layer")]
+    pub layers: Vec<ArchitectureLayer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchitectureLayer {
+    pub name: String,
+    /// Workspace-relative file globs; `*` also spans directories.
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Globs over qualified names and unresolved import paths.
+    #[serde(default)]
+    pub symbols: Vec<String>,
+    /// When set, the only other layers this layer may depend on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow: Option<Vec<String>>,
+    /// Layers this layer must never depend on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forbid: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchitectureRule {
+    /// The target layer is in the source layer's `forbid` list.
+    Forbidden,
+    /// The source layer has an `allow` list that omits the target layer.
+    NotAllowed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchitectureViolation {
+    pub source_layer: String,
+    pub target_layer: String,
+    pub source_symbol: String,
+    pub target_symbol: String,
+    pub edge_kind: String,
+    pub file: String,
+    pub line: Option<usize>,
+    pub target_file: Option<String>,
+    pub rule: ArchitectureRule,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchitectureCheckResult {
+    pub schema_version: String,
+    pub spec_path: String,
+    pub layers: Vec<String>,
+    pub edges_checked: u32,
+    pub violations: Vec<ArchitectureViolation>,
+}
+
+/// A dependency an agent is about to introduce. Each side is a symbol id,
+/// qualified name, workspace-relative file path or import path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchitectureDependencyRequest {
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchitectureDependencyVerdict {
+    pub allowed: bool,
+    pub source_layer: Option<String>,
+    pub target_layer: Option<String>,
+    pub rule: Option<ArchitectureRule>,
+    pub message: String,
+}
+
+impl ArchitectureSpec {
+    pub fn parse(text: &str) -> Result<Self, AnalysisError> {
+        let spec: Self = toml::from_str(text).map_err(|err| {
+            AnalysisError::Message(format!("invalid {ARCHITECTURE_SPEC_PATH}: {err}"))
+        })?;
+        spec.validate()?;
+        Ok(spec)
+    }
+
+    fn validate(&self) -> Result<(), AnalysisError> {
+        let invalid = |message: String| {
+            AnalysisError::Message(format!("{ARCHITECTURE_SPEC_PATH}: {message}"))
+        };
+        let mut names = HashSet::new();
+        for layer in &self.layers {
+            let name = layer.name.trim();
+            if name.is_empty() {
+                return Err(invalid("layer with an empty name".to_owned()));
+            }
+            if !names.insert(name) {
+                return Err(invalid(format!("layer '{name}' is declared twice")));
+            }
+            if layer.paths.is_empty() && layer.symbols.is_empty() {
+                return Err(invalid(format!(
+                    "layer '{name}' needs at least one of `paths` or `symbols`"
+                )));
+            }
+        }
+        for layer in &self.layers {
+            for reference in layer.allow.iter().flatten().chain(layer.forbid.iter()) {
+                if !names.contains(reference.trim()) {
+                    return Err(invalid(format!(
+                        "layer '{}' refers to unknown layer '{}'",
+                        layer.name.trim(),
+                        reference.trim()
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The first declared layer whose `paths` match `file` or whose
+    /// `symbols` match `name`.
+    pub fn layer_for(&self, file: Option<&str>, name: Option<&str>) -> Option<&ArchitectureLayer> {
+        let file = file.map(normalize_path);
+        self.layers.iter().find(|layer| {
+            file.as_deref().is_some_and(|file| {
+                layer
+                    .paths
+                    .iter()
+                    .any(|pattern| wildcard_match(pattern.trim(), file))
+            }) || name.is_some_and(|name| {
+                layer
+                    .symbols
+                    .iter()
+                    .any(|pattern| wildcard_match(pattern.trim(), name))
+            })
+        })
+    }
+
+    /// The rule a dependency from `source` onto `target` breaks, if any.
+    pub fn rule_broken(
+        &self,
+        source: &ArchitectureLayer,
+        target: &ArchitectureLayer,
+    ) -> Option<ArchitectureRule> {
+        let target_name = target.name.trim();
+        if source.name.trim() == target_name {
+            return None;
+        }
+        if source
+            .forbid
+            .iter()
+            .any(|layer| layer.trim() == target_name)
+        {
+            return Some(ArchitectureRule::Forbidden);
+        }
+        match &source.allow {
+            Some(allow) if !allow.iter().any(|layer| layer.trim() == target_name) => {
+                Some(ArchitectureRule::NotAllowed)
+            }
+            _ => None,
+        }
+    }
+
+    fn rule_message(
+        &self,
+        source: &ArchitectureLayer,
+        target: &ArchitectureLayer,
+        rule: ArchitectureRule,
+    ) -> String {
+        match rule {
+            ArchitectureRule::Forbidden => format!(
+                "layer '{}' must not depend on layer '{}'",
+                source.name.trim(),
+                target.name.trim()
+            ),
+            ArchitectureRule::NotAllowed => {
+                let allowed = source.allow.as_deref().unwrap_or_default();
+                if allowed.is_empty() {
+                    format!(
+                        "layer '{}' may not depend on other layers (found '{}')",
+                        source.name.trim(),
+                        target.name.trim()
+                    )
+                } else {
+                    format!(
+                        "layer '{}' may only depend on {} (found '{}')",
+                        source.name.trim(),
+                        allowed
+                            .iter()
+                            .map(|layer| format!("'{}'", layer.trim()))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        target.name.trim()
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Checks `edges` against `spec`. Edges with an endpoint outside every
+/// layer are ignored; lines are left for the caller to fill in.
+pub fn check_architecture_edges(
+    spec: &ArchitectureSpec,
+    edges: &[LocatedEdgeRecord],
+) -> Vec<ArchitectureViolation> {
+    let mut violations = Vec::new();
+    let mut seen = HashSet::new();
+    for edge in edges {
+        let Some(source_layer) = spec.layer_for(
+            Some(&edge.source_file),
+            edge.source_qualified_name.as_deref(),
+        ) else {
+            continue;
+        };
+        let Some(target_layer) = spec.layer_for(
+            edge.target_file.as_deref(),
+            Some(edge.target_qualified_name.as_str()),
+        ) else {
+            continue;
+        };
+        let Some(rule) = spec.rule_broken(source_layer, target_layer) else {
+            continue;
+        };
+        if !seen.insert((
+            edge.source_id.as_str(),
+            edge.target_qualified_name.as_str(),
+            edge.edge_kind.as_str(),
+            edge.target_file.as_deref(),
+        )) {
+            continue;
+        }
+
+        violations.push(ArchitectureViolation {
+            source_layer: source_layer.name.trim().to_owned(),
+            target_layer: target_layer.name.trim().to_owned(),
+            source_symbol: edge
+                .source_qualified_name
+                .clone()
+                .unwrap_or_else(|| edge.source_id.clone()),
+            target_symbol: edge.target_qualified_name.clone(),
+            edge_kind: edge.edge_kind.clone(),
+            file: edge.source_file.clone(),
+            line: None,
+            target_file: edge.target_file.clone(),
+            rule,
+            message: spec.rule_message(source_layer, target_layer, rule),
+        });
+    }
+    violations
+}
+
+pub struct ArchitectureAnalyzer {
+    workspace: PathBuf,
+    spec: ArchitectureSpec,
+}
+
+impl ArchitectureAnalyzer {
+    pub fn new(workspace: impl AsRef<Path>) -> Result<Self, AnalysisError> {
+        let workspace = workspace.as_ref().to_path_buf();
+        let path = workspace.join(ARCHITECTURE_SPEC_PATH);
+        let text = std::fs::read_to_string(&path).map_err(|err| {
+            AnalysisError::Message(format!("failed to read {}: {err}", path.display()))
+        })?;
+        Ok(Self {
+            workspace,
+            spec: ArchitectureSpec::parse(&text)?,
+        })
+    }
+
+    pub fn spec(&self) -> &ArchitectureSpec {
+        &self.spec
+    }
+
+    pub fn check(&self, store: &SqliteStore) -> Result<ArchitectureCheckResult, AnalysisError> {
+        let edges = store.list_located_edges(CHECKED_EDGE_KINDS)?;
+        let mut violations = check_architecture_edges(&self.spec, &edges);
+
+        let mut sources = HashMap::<String, Option<String>>::new();
+        for violation in &mut violations {
+            let source = sources.entry(violation.file.clone()).or_insert_with(|| {
+                std::fs::read_to_string(self.workspace.join(&violation.file)).ok()
+            });
+            violation.line = source
+                .as_deref()
+                .and_then(|source| reference_line(source, &violation.target_symbol));
+        }
+
+        Ok(ArchitectureCheckResult {
+            schema_version: ARCHITECTURE_SCHEMA_VERSION.to_owned(),
+            spec_path: ARCHITECTURE_SPEC_PATH.to_owned(),
+            layers: self
+                .spec
+                .layers
+                .iter()
+                .map(|layer| layer.name.trim().to_owned())
+                .collect(),
+            edges_checked: edges.len() as u32,
+            violations,
+        })
+    }
+
+    pub fn check_dependency(
+        &self,
+        store: &SqliteStore,
+        request: &ArchitectureDependencyRequest,
+    ) -> Result<ArchitectureDependencyVerdict, AnalysisError> {
+        let (source_file, source_name) = self.resolve_endpoint(store, &request.source)?;
+        let (target_file, target_name) = self.resolve_endpoint(store, &request.target)?;
+        let source_layer = self
+            .spec
+            .layer_for(source_file.as_deref(), source_name.as_deref());
+        let target_layer = self
+            .spec
+            .layer_for(target_file.as_deref(), target_name.as_deref());
+
+        let (Some(source_layer), Some(target_layer)) = (source_layer, target_layer) else {
+            let unmatched = if source_layer.is_none() {
+                request.source.trim()
+            } else {
+                request.target.trim()
+            };
+            return Ok(ArchitectureDependencyVerdict {
+                allowed: true,
+                source_layer: source_layer.map(|layer| layer.name.trim().to_owned()),
+                target_layer: target_layer.map(|layer| layer.name.trim().to_owned()),
+                rule: None,
+                message: format!("'{unmatched}' is not in any declared layer"),
+            });
+        };
+
+        let rule = self.spec.rule_broken(source_layer, target_layer);
+        let message = match rule {
+            Some(rule) => self.spec.rule_message(source_layer, target_layer, rule),
+            None => format!(
+                "layer '{}' may depend on layer '{}'",
+                source_layer.name.trim(),
+                target_layer.name.trim()
+            ),
+        };
+        Ok(ArchitectureDependencyVerdict {
+            allowed: rule.is_none(),
+            source_layer: Some(source_layer.name.trim().to_owned()),
+            target_layer: Some(target_layer.name.trim().to_owned()),
+            rule,
+            message,
+        })
+    }
+
+    /// Resolves a symbol id, qualified name or file path to the file and
+    /// name used for layer matching.
+    fn resolve_endpoint(
+        &self,
+        store: &SqliteStore,
+        value: &str,
+    ) -> Result<(Option<String>, Option<String>), AnalysisError> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(AnalysisError::Message(
+                "dependency source and target must not be empty".to_owned(),
+            ));
+        }
+        let symbol = match store.get_symbol_record(value)? {
+            Some(symbol) => Some(symbol),
+            None => store.get_symbol_by_qualified_name(value)?,
+        };
+        if let Some(symbol) = symbol {
+            return Ok((Some(symbol.file_path), Some(symbol.qualified_name)));
+        }
+        if self.workspace.join(value).is_file() {
+            return Ok((Some(normalize_path(value)), None));
+        }
+        Ok((None, Some(value.to_owned())))
+    }
+}
+
+/// 1-based line of the first mention of the target's leaf name.
+fn reference_line(source: &str, target: &str) -> Option<usize> {
+    let leaf = target
+        .rsplit(['/', '.', ':'])
+        .find(|segment| !segment.trim().is_empty())?
+        .trim();
+    source
+        .lines()
+        .position(|line| line.contains(leaf))
+        .map(|index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r#"
+[[layer]]
+name = "core"
+paths = ["crates/core/*"]
+allow = []
+
+[[layer]]
+name = "store"
+paths = ["crates/store/*"]
+symbols = ["store::*"]
+allow = ["core"]
+
+[[layer]]
+name = "daemon"
+paths = ["crates/daemon/*"]
+forbid = ["store"]
+"#;
+
+    fn edge(source_file: &str, target: &str, target_file: Option<&str>) -> LocatedEdgeRecord {
+        LocatedEdgeRecord {
+            source_id: format!("sym-{source_file}"),
+            source_qualified_name: Some(format!("{source_file}::caller")),
+            source_file: source_file.to_owned(),
+            target_qualified_name: target.to_owned(),
+            target_id: target_file.map(|file| format!("sym-{file}")),
+            target_file: target_file.map(str::to_owned),
+            edge_kind: "calls".to_owned(),
+        }
+    }
+
+    #[test]
+    fn spec_rejects_unknown_layers_and_empty_matchers() {
+        let err = ArchitectureSpec::parse(
+            "[[layer]]\nname = \"a\"\npaths = [\"a/*\"]\nforbid = [\"b\"]\n",
+        )
+        .expect_err("unknown layer");
+        assert!(err.to_string().contains("unknown layer 'b'"));
+
+        let err = ArchitectureSpec::parse("[[layer]]\nname = \"a\"\n").expect_err("no matchers");
+        assert!(err.to_string().contains("needs at least one"));
+    }
+
+    #[test]
+    fn check_reports_forbidden_and_not_allowed_edges() {
+        let spec = ArchitectureSpec::parse(SPEC).expect("spec");
+        let edges = vec![
+            edge(
+                "crates/store/src/lib.rs",
+                "Symbol",
+                Some("crates/core/src/lib.rs"),
+            ),
+            edge(
+                "crates/core/src/lib.rs",
+                "open",
+                Some("crates/store/src/lib.rs"),
+            ),
+            edge("crates/daemon/src/main.rs", "store::SqliteStore", None),
+            edge(
+                "crates/daemon/src/main.rs",
+                "Symbol",
+                Some("crates/core/src/lib.rs"),
+            ),
+            edge("tools/gen.rs", "open", Some("crates/store/src/lib.rs")),
+        ];
+
+        let violations = check_architecture_edges(&spec, &edges);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].source_layer, "core");
+        assert_eq!(violations[0].target_layer, "store");
+        assert_eq!(violations[0].rule, ArchitectureRule::NotAllowed);
+        assert_eq!(violations[1].source_layer, "daemon");
+        assert_eq!(violations[1].target_symbol, "store::SqliteStore");
+        assert_eq!(violations[1].rule, ArchitectureRule::Forbidden);
+        assert_eq!(
+            violations[1].message,
+            "layer 'daemon' must not depend on layer 'store'"
+        );
+    }
+
+    #[test]
+    fn reference_line_finds_target_leaf() {
+        let source = "use crate::x;\n\nfn run() {\n    store::open();\n}\n";
+        assert_eq!(reference_line(source, "store::open"), Some(4));
+        assert_eq!(reference_line(source, "missing"), None);
+    }
+}
@@ -0,0 +1,183 @@
+//! Choke points: symbols whose failure cuts a whole region of the dependency
+//! graph off, either because every path from an entry point into the region
+//! runs through them (dominators) or because the graph falls apart without
+//! them (articulation points).
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::graph_algorithms::{
+    GraphAlgorithmEdge, articulation_points, bridges, dominated_counts, k_core,
+};
+
+/// Regions smaller than this are ordinary fan-out, not a choke point.
+pub const DEFAULT_MIN_CHOKE_REGION: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChokePoint {
+    pub symbol_id: String,
+    /// Symbols that entry points reach only through this one.
+    pub dominated: usize,
+    /// Symbols cut off from the rest of the graph without this one, ignoring
+    /// edge direction.
+    pub disconnected: usize,
+    /// k-core number: how deep in the densest part of the graph it sits.
+    pub core_number: usize,
+    /// Links to this symbol that are the only connection between two regions.
+    pub bridges: usize,
+}
+
+impl ChokePoint {
+    /// Symbols lost when this one fails, by whichever measure is larger.
+    pub fn region(&self) -> usize {
+        self.dominated.max(self.disconnected)
+    }
+
+    pub fn describe(&self) -> String {
+        match (self.dominated, self.disconnected) {
+            (0, disconnected) => format!("removing it disconnects {disconnected} symbol(s)"),
+            (dominated, 0) => format!("sole path from entry points to {dominated} symbol(s)"),
+            (dominated, disconnected) => format!(
+                "sole path from entry points to {dominated} symbol(s); removing it disconnects \
+                 {disconnected}"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChokePointEntry {
+    pub symbol_id: String,
+    pub symbol_name: String,
+    pub file: String,
+    pub dominated_symbols: u32,
+    pub disconnected_symbols: u32,
+    pub core_number: u32,
+    pub bridges: u32,
+    pub note: String,
+}
+
+impl ChokePointEntry {
+    pub fn new(point: &ChokePoint, symbol_name: String, file: String) -> Self {
+        Self {
+            symbol_id: point.symbol_id.clone(),
+            symbol_name,
+            file,
+            dominated_symbols: point.dominated as u32,
+            disconnected_symbols: point.disconnected as u32,
+            core_number: point.core_number as u32,
+            bridges: point.bridges as u32,
+            note: point.describe(),
+        }
+    }
+}
+
+/// Where dominator analysis starts: symbols nothing else depends on.
+pub fn graph_entry_points(edges: &[GraphAlgorithmEdge]) -> Vec<String> {
+    let targets = edges
+        .iter()
+        .filter(|edge| edge.source_id != edge.target_id)
+        .map(|edge| edge.target_id.as_str())
+        .collect::<HashSet<_>>();
+    let mut entries = edges
+        .iter()
+        .map(|edge| edge.source_id.as_str())
+        .filter(|source| !targets.contains(source))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries
+}
+
+/// Symbols whose failure cuts off at least `min_region` others, largest
+/// region first.
+pub fn find_choke_points(edges: &[GraphAlgorithmEdge], min_region: usize) -> Vec<ChokePoint> {
+    let entries = graph_entry_points(edges);
+    // Entry points dominate everything below them by construction, so only
+    // what they disconnect counts for them.
+    let dominated = dominated_counts(edges, &entries)
+        .into_iter()
+        .filter(|(symbol_id, _)| entries.binary_search(symbol_id).is_err())
+        .collect::<HashMap<_, _>>();
+    let disconnected = articulation_points(edges)
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+    let cores = k_core(edges);
+    let mut bridge_counts = HashMap::<String, usize>::new();
+    for (left, right) in bridges(edges) {
+        *bridge_counts.entry(left).or_default() += 1;
+        *bridge_counts.entry(right).or_default() += 1;
+    }
+
+    let candidates = dominated
+        .keys()
+        .chain(disconnected.keys())
+        .collect::<HashSet<_>>();
+    let mut points = candidates
+        .into_iter()
+        .map(|symbol_id| ChokePoint {
+            symbol_id: symbol_id.clone(),
+            dominated: dominated.get(symbol_id).copied().unwrap_or(0),
+            disconnected: disconnected.get(symbol_id).copied().unwrap_or(0),
+            core_number: cores.get(symbol_id).copied().unwrap_or(0),
+            bridges: bridge_counts.get(symbol_id).copied().unwrap_or(0),
+        })
+        .filter(|point| point.region() >= min_region.max(1))
+        .collect::<Vec<_>>();
+    points.sort_by(|left, right| {
+        right
+            .region()
+            .cmp(&left.region())
+            .then_with(|| left.symbol_id.cmp(&right.symbol_id))
+    });
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(source: &str, target: &str) -> GraphAlgorithmEdge {
+        GraphAlgorithmEdge {
+            source_id: source.to_owned(),
+            target_id: target.to_owned(),
+            edge_kind: "calls".to_owned(),
+        }
+    }
+
+    #[test]
+    fn gateway_into_a_region_is_a_choke_point() {
+        let edges = vec![
+            edge("main", "router"),
+            edge("admin", "router"),
+            edge("router", "db"),
+            edge("db", "pool"),
+            edge("db", "query"),
+            edge("query", "pool"),
+            edge("main", "log"),
+            edge("admin", "log"),
+        ];
+        assert_eq!(graph_entry_points(&edges), vec!["admin", "main"]);
+
+        let points = find_choke_points(&edges, 1);
+        assert_eq!(
+            points
+                .iter()
+                .map(|point| (
+                    point.symbol_id.as_str(),
+                    point.dominated,
+                    point.disconnected
+                ))
+                .collect::<Vec<_>>(),
+            vec![("router", 3, 3), ("db", 2, 2)]
+        );
+        assert_eq!(
+            points[0].describe(),
+            "sole path from entry points to 3 symbol(s); removing it disconnects 3"
+        );
+        assert_eq!(find_choke_points(&edges, DEFAULT_MIN_CHOKE_REGION).len(), 1);
+    }
+}
@@ -13,6 +13,10 @@ use gix::bstr::ByteSlice;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::choke_points::{ChokePointEntry, DEFAULT_MIN_CHOKE_REGION, find_choke_points};
+use crate::graph_algorithms::GraphAlgorithmEdge;
+use crate::ownership::{DEFAULT_SUGGESTED_REVIEWERS, OwnershipAnalyzer, SuggestedReviewer};
+
 const STALE_COMMIT_THRESHOLD: i64 = 100;
 
 #[derive(Debug, Error)]
@@ -156,6 +160,9 @@ pub struct BlastRadiusEntry {
     pub total_commits: i64,
     pub last_co_change_commit: String,
     pub last_co_change_at: i64,
+    /// Profiled self samples in the file, when execution weighting is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_samples: Option<i64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -163,6 +170,12 @@ pub struct BlastRadiusResult {
     pub target_file: String,
     pub mining_state: Option<CouplingMiningStateRecord>,
     pub coupled_files: Vec<BlastRadiusEntry>,
+    /// Owners of the coupled files, by CODEOWNERS and recency-weighted blame.
+    #[serde(default)]
+    pub suggested_reviewers: Vec<SuggestedReviewer>,
+    /// Symbols in the target file whose failure cuts off a region of the graph.
+    #[serde(default)]
+    pub choke_points: Vec<ChokePointEntry>,
 }
 
 #[derive(Debug, Clone)]
@@ -170,6 +183,7 @@ pub struct CouplingAnalyzer {
     workspace: PathBuf,
     config: CouplingConfig,
     graph_backend: GraphBackend,
+    execution_weighted: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -192,6 +206,7 @@ impl CouplingAnalyzer {
             workspace,
             config: config.coupling,
             graph_backend: config.storage.graph_backend,
+            execution_weighted: config.profiles.execution_weighted,
         })
     }
 
@@ -497,24 +512,129 @@ impl CouplingAnalyzer {
                 total_commits,
                 last_co_change_commit: edge.last_co_change_commit,
                 last_co_change_at: edge.last_co_change_at,
+                execution_samples: None,
             });
         }
 
+        // Endpoint edges cross language boundaries that rarely co-change in
+        // one commit, so linked files count as static coupling on their own.
+        let endpoint_score = fused_score(
+            0.0,
+            1.0,
+            0.0,
+            self.config.temporal_weight,
+            self.config.static_weight,
+            self.config.semantic_weight,
+        );
+        if endpoint_score >= request.min_risk.min_score() {
+            for file in store.list_endpoint_linked_files(file_path.as_str())? {
+                if coupled_files.iter().any(|entry| entry.file == file) {
+                    continue;
+                }
+                coupled_files.push(BlastRadiusEntry {
+                    file,
+                    risk_level: RiskLevel::from_score(endpoint_score),
+                    fused_score: endpoint_score,
+                    coupling_type: CouplingType::Structural,
+                    signals: SignalBreakdown {
+                        temporal: 0.0,
+                        static_signal: 1.0,
+                        semantic: 0.0,
+                    },
+                    co_change_count: 0,
+                    total_commits: 0,
+                    last_co_change_commit: String::new(),
+                    last_co_change_at: 0,
+                    execution_samples: None,
+                });
+            }
+        }
+
+        // With execution weighting, a file's fused score counts up to double
+        // in proportion to how hot it ran, so hot files sort first among
+        // similarly coupled ones.
+        let max_samples = if self.execution_weighted {
+            let samples = store
+                .list_file_sample_counts()?
+                .into_iter()
+                .map(|count| (count.file_path, count.samples))
+                .collect::<HashMap<_, _>>();
+            for entry in &mut coupled_files {
+                entry.execution_samples = Some(samples.get(&entry.file).copied().unwrap_or(0));
+            }
+            samples.values().copied().max().unwrap_or(0)
+        } else {
+            0
+        };
+        let ordering_score = |entry: &BlastRadiusEntry| {
+            let heat = match entry.execution_samples {
+                Some(samples) if max_samples > 0 => samples as f32 / max_samples as f32,
+                _ => 0.0,
+            };
+            entry.fused_score * (1.0 + heat)
+        };
         coupled_files.sort_by(|left, right| {
-            right
-                .fused_score
-                .partial_cmp(&left.fused_score)
+            ordering_score(right)
+                .partial_cmp(&ordering_score(left))
                 .unwrap_or(std::cmp::Ordering::Equal)
                 .then_with(|| left.file.cmp(&right.file))
         });
+        let suggested_reviewers = OwnershipAnalyzer::new(&self.workspace)?.suggest_reviewers(
+            &coupled_files
+                .iter()
+                .map(|entry| (entry.file.clone(), f64::from(entry.fused_score)))
+                .collect::<Vec<_>>(),
+            DEFAULT_SUGGESTED_REVIEWERS,
+        );
+        let choke_points = self.choke_points_for_file(&store, &file_path)?;
 
         Ok(BlastRadiusResult {
             target_file: file_path,
             mining_state,
             coupled_files,
+            suggested_reviewers,
+            choke_points,
         })
     }
 
+    /// Choke points among the symbols of `file_path`, over the whole
+    /// dependency graph.
+    pub fn choke_points_for_file(
+        &self,
+        store: &SqliteStore,
+        file_path: &str,
+    ) -> Result<Vec<ChokePointEntry>, AnalysisError> {
+        let symbols = store
+            .list_symbols_for_file(file_path)?
+            .into_iter()
+            .map(|symbol| (symbol.id, symbol.qualified_name))
+            .collect::<HashMap<_, _>>();
+        if symbols.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let edges = store
+            .list_graph_dependency_edges()?
+            .into_iter()
+            .map(|edge| GraphAlgorithmEdge {
+                source_id: edge.source_symbol_id,
+                target_id: edge.target_symbol_id,
+                edge_kind: edge.edge_kind,
+            })
+            .collect::<Vec<_>>();
+        Ok(find_choke_points(&edges, DEFAULT_MIN_CHOKE_REGION)
+            .iter()
+            .filter_map(|point| {
+                let name = symbols.get(&point.symbol_id)?;
+                Some(ChokePointEntry::new(
+                    point,
+                    name.clone(),
+                    file_path.to_owned(),
+                ))
+            })
+            .collect())
+    }
+
     pub fn coupling_report(&self, top: u32) -> Result<Vec<CouplingEdge>, AnalysisError> {
         let graph = open_surreal_graph_store_readonly(&self.workspace)?;
         self.coupling_report_with_graph(&graph, top)
@@ -854,7 +974,9 @@ fn normalize_rename_path(path: &str) -> String {
     value.to_owned()
 }
 
-fn wildcard_match(pattern: &str, text: &str) -> bool {
+/// Glob match where `*` spans any run of characters (including `/` and `::`)
+/// and `?` matches exactly one.
+pub fn wildcard_match(pattern: &str, text: &str) -> bool {
     if pattern == "*" {
         return true;
     }
@@ -0,0 +1,770 @@
+//! Dead-code report: symbols that no declared entry point reaches over the
+//! structural graph, with a confidence level for how far the graph can be
+//! trusted for each one.
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use aether_config::{DeadCodeConfig, load_workspace_config};
+use aether_core::{EdgeKind, Language, Symbol, normalize_path};
+use aether_parse::{SymbolExtractor, language_for_path};
+use aether_store::{SqliteStore, SymbolRecord};
+use serde::{Deserialize, Serialize};
+
+use crate::api_surface::exported_symbols;
+use crate::coupling::{AnalysisError, wildcard_match};
+
+/// `references` and `endpoint` edges are followed too, so constants and
+/// route handlers reached through them are not reported.
+pub const DEAD_CODE_EDGE_KINDS: &[EdgeKind] = &[
+    EdgeKind::Calls,
+    EdgeKind::DependsOn,
+    EdgeKind::TypeRef,
+    EdgeKind::Implements,
+    EdgeKind::References,
+    EdgeKind::Endpoint,
+];
+const DEAD_CODE_SCHEMA_VERSION: &str = "1.0";
+/// Kinds that live or die with their parent and are never reported alone.
+const CONTAINED_KINDS: &[&str] = &["field", "enum_variant", "module"];
+const TEST_ATTRIBUTES: &[&str] = &["#[test]", "#[bench]", "#[rstest", "::test]", "::test("];
+const EXPORT_MARKERS: &[&str] = &[
+    "no_mangle",
+    "export_name",
+    "wasm_bindgen",
+    "pyfunction",
+    "pyclass",
+    "pymethods",
+    "napi",
+    "extern \"C\"",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadCodeConfidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl DeadCodeConfidence {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+}
+
+impl std::str::FromStr for DeadCodeConfidence {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            other => Err(format!(
+                "invalid confidence '{other}', expected one of: low, medium, high"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadCodeRootKind {
+    Configured,
+    Main,
+    Test,
+    HttpHandler,
+    Exported,
+    PublicApi,
+}
+
+impl DeadCodeRootKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Configured => "configured",
+            Self::Main => "main",
+            Self::Test => "test",
+            Self::HttpHandler => "http_handler",
+            Self::Exported => "exported",
+            Self::PublicApi => "public_api",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadCodeRequest {
+    pub min_confidence: DeadCodeConfidence,
+    /// Only report symbols under this workspace-relative path.
+    pub path_prefix: Option<String>,
+    pub limit: Option<usize>,
+    /// Overrides `[dead_code] public_api_roots`.
+    pub public_api_roots: Option<bool>,
+}
+
+impl Default for DeadCodeRequest {
+    fn default() -> Self {
+        Self {
+            min_confidence: DeadCodeConfidence::Low,
+            path_prefix: None,
+            limit: None,
+            public_api_roots: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnreachableSymbol {
+    pub symbol_id: String,
+    pub qualified_name: String,
+    pub file_path: String,
+    pub language: String,
+    pub kind: String,
+    pub confidence: DeadCodeConfidence,
+    pub reasons: Vec<String>,
+    /// Unreachable symbols that still reference this one.
+    pub dead_referrers: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeadCodeReport {
+    pub schema_version: String,
+    pub edge_kinds: Vec<String>,
+    pub total_symbols: usize,
+    pub reachable_symbols: usize,
+    /// Root count per root kind.
+    pub roots: BTreeMap<String, usize>,
+    /// Unreachable symbols per confidence level, before `limit`.
+    pub confidence_counts: BTreeMap<String, usize>,
+    pub unreachable: Vec<UnreachableSymbol>,
+    pub notes: Vec<String>,
+}
+
+/// What the source says about a symbol beyond the indexed graph.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct SourceFacts {
+    exported: bool,
+    /// Attributes, decorators and the declaration line.
+    markers: Vec<String>,
+}
+
+pub struct DeadCodeAnalyzer {
+    workspace: PathBuf,
+    config: DeadCodeConfig,
+}
+
+impl DeadCodeAnalyzer {
+    pub fn new(workspace: impl AsRef<Path>) -> Result<Self, AnalysisError> {
+        let workspace = workspace.as_ref().to_path_buf();
+        let config = load_workspace_config(&workspace)?;
+        Ok(Self {
+            workspace,
+            config: config.dead_code,
+        })
+    }
+
+    pub fn config(&self) -> &DeadCodeConfig {
+        &self.config
+    }
+
+    pub fn analyze(
+        &self,
+        store: &SqliteStore,
+        request: &DeadCodeRequest,
+    ) -> Result<DeadCodeReport, AnalysisError> {
+        let symbols = store.list_all_symbol_records()?;
+        let edges = store.list_located_edges(DEAD_CODE_EDGE_KINDS)?;
+        let handlers = store
+            .list_endpoint_routes()?
+            .into_iter()
+            .map(|route| route.handler)
+            .collect::<HashSet<_>>();
+
+        let mut notes = Vec::new();
+        let facts = self.source_facts(&symbols, &mut notes)?;
+        let public_api_roots = request
+            .public_api_roots
+            .unwrap_or(self.config.public_api_roots);
+
+        let mut roots = HashMap::new();
+        for symbol in &symbols {
+            let symbol_facts = facts.get(symbol.id.as_str()).cloned().unwrap_or_default();
+            if let Some(kind) = root_kind(
+                symbol,
+                &symbol_facts,
+                &self.config,
+                &handlers,
+                public_api_roots,
+            ) {
+                roots.insert(symbol.id.as_str(), kind);
+            }
+        }
+
+        let mut outgoing = HashMap::<&str, Vec<&str>>::new();
+        let mut unresolved_leaves = HashSet::new();
+        for edge in &edges {
+            match edge.target_id.as_deref() {
+                Some(target) if target != edge.source_id => outgoing
+                    .entry(edge.source_id.as_str())
+                    .or_default()
+                    .push(target),
+                Some(_) => {}
+                None => {
+                    unresolved_leaves.insert(leaf_name(&edge.target_qualified_name));
+                }
+            }
+        }
+        let reachable = reachable_from(roots.keys().copied(), &outgoing);
+
+        let mut dead_referrers = HashMap::<&str, usize>::new();
+        for (source, targets) in &outgoing {
+            if reachable.contains(source) {
+                continue;
+            }
+            for target in targets.iter().collect::<HashSet<_>>() {
+                *dead_referrers.entry(target).or_default() += 1;
+            }
+        }
+        let reachable_names = symbols
+            .iter()
+            .filter(|symbol| reachable.contains(symbol.id.as_str()))
+            .map(|symbol| symbol.qualified_name.as_str())
+            .collect::<HashSet<_>>();
+
+        let path_prefix = request
+            .path_prefix
+            .as_deref()
+            .map(|prefix| normalize_path(prefix.trim()))
+            .filter(|prefix| !prefix.is_empty() && prefix != ".");
+        let mut unreachable = Vec::new();
+        for symbol in &symbols {
+            if reachable.contains(symbol.id.as_str())
+                || CONTAINED_KINDS.contains(&symbol.kind.as_str())
+                || self
+                    .config
+                    .ignore_paths
+                    .iter()
+                    .any(|pattern| wildcard_match(pattern, &symbol.file_path))
+                || path_prefix.as_deref().is_some_and(|prefix| {
+                    symbol.file_path != prefix
+                        && !symbol.file_path.starts_with(&format!("{prefix}/"))
+                })
+            {
+                continue;
+            }
+            let symbol_facts = facts.get(symbol.id.as_str()).cloned().unwrap_or_default();
+            let parent_reachable = parent_name(&symbol.qualified_name)
+                .is_some_and(|parent| reachable_names.contains(parent));
+            let Some((confidence, reasons)) = assess(
+                symbol,
+                &symbol_facts,
+                parent_reachable,
+                unresolved_leaves.contains(leaf_name(&symbol.qualified_name)),
+                dead_referrers.get(symbol.id.as_str()).copied().unwrap_or(0),
+            ) else {
+                continue;
+            };
+            unreachable.push(UnreachableSymbol {
+                symbol_id: symbol.id.clone(),
+                qualified_name: symbol.qualified_name.clone(),
+                file_path: symbol.file_path.clone(),
+                language: symbol.language.clone(),
+                kind: symbol.kind.clone(),
+                confidence,
+                reasons,
+                dead_referrers: dead_referrers.get(symbol.id.as_str()).copied().unwrap_or(0),
+            });
+        }
+
+        let mut confidence_counts = BTreeMap::new();
+        for entry in &unreachable {
+            *confidence_counts
+                .entry(entry.confidence.as_str().to_owned())
+                .or_insert(0) += 1;
+        }
+        unreachable.retain(|entry| entry.confidence >= request.min_confidence);
+        unreachable.sort_by(|left, right| {
+            right
+                .confidence
+                .cmp(&left.confidence)
+                .then_with(|| left.file_path.cmp(&right.file_path))
+                .then_with(|| left.qualified_name.cmp(&right.qualified_name))
+                .then_with(|| left.symbol_id.cmp(&right.symbol_id))
+        });
+        if let Some(limit) = request.limit {
+            unreachable.truncate(limit);
+        }
+
+        let mut root_counts = BTreeMap::new();
+        for kind in roots.values() {
+            *root_counts.entry(kind.as_str().to_owned()).or_insert(0) += 1;
+        }
+        if roots.is_empty() {
+            notes.push(
+                "no entry points found; add `[dead_code] roots` to .aether/config.toml".to_owned(),
+            );
+        }
+
+        Ok(DeadCodeReport {
+            schema_version: DEAD_CODE_SCHEMA_VERSION.to_owned(),
+            edge_kinds: DEAD_CODE_EDGE_KINDS
+                .iter()
+                .map(|kind| kind.as_str().to_owned())
+                .collect(),
+            total_symbols: symbols.len(),
+            reachable_symbols: reachable.len(),
+            roots: root_counts,
+            confidence_counts,
+            unreachable,
+            notes,
+        })
+    }
+
+    /// Re-parses every indexed file for visibility, attributes and decorators,
+    /// which the store does not keep.
+    fn source_facts(
+        &self,
+        symbols: &[SymbolRecord],
+        notes: &mut Vec<String>,
+    ) -> Result<HashMap<String, SourceFacts>, AnalysisError> {
+        let mut extractor =
+            SymbolExtractor::new().map_err(|err| AnalysisError::Message(err.to_string()))?;
+        let files = symbols
+            .iter()
+            .map(|symbol| symbol.file_path.as_str())
+            .collect::<std::collections::BTreeSet<_>>();
+
+        let mut facts = HashMap::new();
+        let mut unreadable = 0usize;
+        for file in files {
+            let Some(language) = language_for_path(Path::new(file)) else {
+                continue;
+            };
+            let Ok(source) = std::fs::read_to_string(self.workspace.join(file)) else {
+                unreadable += 1;
+                continue;
+            };
+            let Ok(parsed) = extractor.extract_from_source(language, file, &source) else {
+                unreadable += 1;
+                continue;
+            };
+            facts.extend(source_facts_for_file(language, &source, &parsed));
+        }
+        if unreadable > 0 {
+            notes.push(format!(
+                "{unreadable} indexed file(s) could not be read or parsed; \
+                 entry points in them were not detected"
+            ));
+        }
+        Ok(facts)
+    }
+}
+
+fn source_facts_for_file(
+    language: Language,
+    source: &str,
+    symbols: &[Symbol],
+) -> HashMap<String, SourceFacts> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let exported = exported_symbols(language, source, symbols)
+        .into_iter()
+        .map(|symbol| symbol.id.as_str())
+        .collect::<HashSet<_>>();
+    symbols
+        .iter()
+        .map(|symbol| {
+            (
+                symbol.id.clone(),
+                SourceFacts {
+                    exported: exported.contains(symbol.id.as_str()),
+                    markers: declaration_markers(&lines, symbol),
+                },
+            )
+        })
+        .collect()
+}
+
+/// Attributes and decorators around the start of `symbol`, then its
+/// declaration line.
+fn declaration_markers(lines: &[&str], symbol: &Symbol) -> Vec<String> {
+    let start = symbol.range.start.line.saturating_sub(1);
+    let is_marker = |line: &str| line.starts_with("#[") || line.starts_with('@');
+    let mut markers = lines[..start.min(lines.len())]
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .take_while(|line| is_marker(line) || line.starts_with("///") || line.starts_with("//"))
+        .filter(|line| is_marker(line))
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    markers.reverse();
+    for line in lines.iter().skip(start).map(|line| line.trim()) {
+        if line.is_empty() {
+            continue;
+        }
+        markers.push(line.to_owned());
+        if !is_marker(line) {
+            break;
+        }
+    }
+    markers
+}
+
+fn root_kind(
+    symbol: &SymbolRecord,
+    facts: &SourceFacts,
+    config: &DeadCodeConfig,
+    handlers: &HashSet<String>,
+    public_api_roots: bool,
+) -> Option<DeadCodeRootKind> {
+    if config
+        .roots
+        .iter()
+        .any(|pattern| wildcard_match(pattern, &symbol.qualified_name))
+        || config
+            .root_paths
+            .iter()
+            .any(|pattern| wildcard_match(pattern, &symbol.file_path))
+    {
+        return Some(DeadCodeRootKind::Configured);
+    }
+    if symbol.kind == "function" && leaf_name(&symbol.qualified_name) == "main" {
+        return Some(DeadCodeRootKind::Main);
+    }
+    if is_test_path(&symbol.file_path)
+        || facts.markers.iter().any(|marker| {
+            marker.starts_with("#[")
+                && TEST_ATTRIBUTES
+                    .iter()
+                    .any(|attribute| marker.contains(attribute))
+        })
+    {
+        return Some(DeadCodeRootKind::Test);
+    }
+    if handlers.contains(&symbol.qualified_name) {
+        return Some(DeadCodeRootKind::HttpHandler);
+    }
+    if facts.markers.iter().any(|marker| {
+        EXPORT_MARKERS
+            .iter()
+            .any(|export_marker| marker.contains(export_marker))
+    }) {
+        return Some(DeadCodeRootKind::Exported);
+    }
+    (public_api_roots && facts.exported).then_some(DeadCodeRootKind::PublicApi)
+}
+
+/// Confidence that an unreachable symbol is really dead; `None` when it is
+/// known to be live outside the graph.
+fn assess(
+    symbol: &SymbolRecord,
+    facts: &SourceFacts,
+    parent_reachable: bool,
+    unresolved_reference: bool,
+    dead_referrers: usize,
+) -> Option<(DeadCodeConfidence, Vec<String>)> {
+    let leaf = leaf_name(&symbol.qualified_name);
+    let is_method = symbol.kind == "method";
+    if symbol.language == "python" && is_method && parent_reachable && is_dunder(leaf) {
+        return None;
+    }
+
+    let mut confidence = DeadCodeConfidence::High;
+    let mut reasons = vec![if dead_referrers > 0 {
+        format!("referenced only by {dead_referrers} other unreachable symbol(s)")
+    } else {
+        "no path from any entry point".to_owned()
+    }];
+    let mut lower = |level: DeadCodeConfidence, reason: String| {
+        confidence = confidence.min(level);
+        reasons.push(reason);
+    };
+
+    if is_method && parent_reachable {
+        lower(
+            DeadCodeConfidence::Low,
+            "method of a reachable type; may be called through dynamic dispatch".to_owned(),
+        );
+    }
+    if unresolved_reference {
+        lower(
+            DeadCodeConfidence::Medium,
+            format!("an unresolved reference to `{leaf}` may point here"),
+        );
+    }
+    match symbol.language.as_str() {
+        "python" => {
+            lower(
+                DeadCodeConfidence::Low,
+                "Python can reach it through getattr, string imports or registries".to_owned(),
+            );
+            if let Some(decorator) = facts.markers.iter().find(|marker| marker.starts_with('@')) {
+                lower(
+                    DeadCodeConfidence::Low,
+                    format!("decorated with {decorator}; the decorator may register it"),
+                );
+            }
+        }
+        "typescript" | "tsx" | "javascript" | "jsx" if is_method => lower(
+            DeadCodeConfidence::Medium,
+            "JavaScript methods can be reached by dynamic property access".to_owned(),
+        ),
+        _ => {}
+    }
+    Some((confidence, reasons))
+}
+
+fn reachable_from<'a>(
+    roots: impl IntoIterator<Item = &'a str>,
+    outgoing: &HashMap<&'a str, Vec<&'a str>>,
+) -> HashSet<&'a str> {
+    let mut reachable = HashSet::new();
+    let mut queue = VecDeque::new();
+    for root in roots {
+        if reachable.insert(root) {
+            queue.push_back(root);
+        }
+    }
+    while let Some(current) = queue.pop_front() {
+        for next in outgoing.get(current).into_iter().flatten() {
+            if reachable.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    reachable
+}
+
+pub(crate) fn is_test_path(path: &str) -> bool {
+    let path = normalize_path(path);
+    let file_name = path.rsplit('/').next().unwrap_or(path.as_str());
+    path.split('/').any(|segment| {
+        matches!(
+            segment,
+            "tests" | "test" | "__tests__" | "benches" | "examples"
+        )
+    }) || file_name.starts_with("test_")
+        || file_name.ends_with("_test.py")
+        || file_name == "conftest.py"
+        || [".test.", ".spec."]
+            .iter()
+            .any(|marker| file_name.contains(marker))
+}
+
+fn leaf_name(qualified_name: &str) -> &str {
+    let leaf = qualified_name.rsplit("::").next().unwrap_or(qualified_name);
+    leaf.rsplit('.').next().unwrap_or(leaf)
+}
+
+fn parent_name(qualified_name: &str) -> Option<&str> {
+    qualified_name
+        .rsplit_once("::")
+        .map(|(parent, _)| parent)
+        .filter(|parent| !parent.is_empty())
+}
+
+fn is_dunder(name: &str) -> bool {
+    name.len() > 4 && name.starts_with("__") && name.ends_with("__")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: &str, qualified_name: &str, kind: &str, language: &str) -> SymbolRecord {
+        SymbolRecord {
+            id: id.to_owned(),
+            file_path: "src/lib.rs".to_owned(),
+            language: language.to_owned(),
+            kind: kind.to_owned(),
+            qualified_name: qualified_name.to_owned(),
+            signature_fingerprint: format!("sig-{id}"),
+            last_seen_at: 0,
+        }
+    }
+
+    #[test]
+    fn roots_cover_mains_tests_handlers_exports_and_configuration() {
+        let config = DeadCodeConfig {
+            roots: vec!["plugins::*".to_owned()],
+            ..DeadCodeConfig::default()
+        };
+        let handlers = HashSet::from(["api::get_user".to_owned()]);
+        let facts = |markers: &[&str], exported| SourceFacts {
+            exported,
+            markers: markers.iter().map(|marker| (*marker).to_owned()).collect(),
+        };
+        let kind_of = |symbol: SymbolRecord, facts: SourceFacts, public_api| {
+            root_kind(&symbol, &facts, &config, &handlers, public_api)
+        };
+
+        assert_eq!(
+            kind_of(
+                record("a", "main", "function", "rust"),
+                facts(&[], false),
+                true
+            ),
+            Some(DeadCodeRootKind::Main)
+        );
+        assert_eq!(
+            kind_of(
+                record("b", "tests::parses", "function", "rust"),
+                facts(&["#[tokio::test]", "async fn parses() {"], false),
+                true
+            ),
+            Some(DeadCodeRootKind::Test)
+        );
+        assert_eq!(
+            kind_of(
+                record("c", "api::get_user", "function", "rust"),
+                facts(&[], false),
+                true
+            ),
+            Some(DeadCodeRootKind::HttpHandler)
+        );
+        assert_eq!(
+            kind_of(
+                record("d", "ffi_entry", "function", "rust"),
+                facts(
+                    &["#[unsafe(no_mangle)]", "pub extern \"C\" fn ffi_entry() {"],
+                    false
+                ),
+                true
+            ),
+            Some(DeadCodeRootKind::Exported)
+        );
+        assert_eq!(
+            kind_of(
+                record("e", "plugins::load", "function", "rust"),
+                facts(&[], false),
+                true
+            ),
+            Some(DeadCodeRootKind::Configured)
+        );
+        assert_eq!(
+            kind_of(
+                record("f", "open", "function", "rust"),
+                facts(&[], true),
+                true
+            ),
+            Some(DeadCodeRootKind::PublicApi)
+        );
+        assert_eq!(
+            kind_of(
+                record("f", "open", "function", "rust"),
+                facts(&[], true),
+                false
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn confidence_drops_for_dynamic_dispatch_and_python_reflection() {
+        let plain = SourceFacts::default();
+        let (confidence, _) = assess(
+            &record("a", "helper", "function", "rust"),
+            &plain,
+            false,
+            false,
+            0,
+        )
+        .expect("reported");
+        assert_eq!(confidence, DeadCodeConfidence::High);
+
+        let (confidence, reasons) = assess(
+            &record("b", "Handle::render", "method", "rust"),
+            &plain,
+            true,
+            false,
+            0,
+        )
+        .expect("reported");
+        assert_eq!(confidence, DeadCodeConfidence::Low);
+        assert!(
+            reasons
+                .iter()
+                .any(|reason| reason.contains("dynamic dispatch"))
+        );
+
+        let (confidence, _) = assess(
+            &record("c", "helper", "function", "rust"),
+            &plain,
+            false,
+            true,
+            0,
+        )
+        .expect("reported");
+        assert_eq!(confidence, DeadCodeConfidence::Medium);
+
+        let decorated = SourceFacts {
+            exported: false,
+            markers: vec!["@app.command()".to_owned(), "def sync():".to_owned()],
+        };
+        let (confidence, reasons) = assess(
+            &record("d", "cli::sync", "function", "python"),
+            &decorated,
+            false,
+            false,
+            0,
+        )
+        .expect("reported");
+        assert_eq!(confidence, DeadCodeConfidence::Low);
+        assert!(
+            reasons
+                .iter()
+                .any(|reason| reason.contains("@app.command()"))
+        );
+
+        assert!(
+            assess(
+                &record("e", "models::User::__repr__", "method", "python"),
+                &plain,
+                true,
+                false,
+                0,
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn reachability_follows_edges_from_roots_only() {
+        let outgoing = HashMap::from([
+            ("main", vec!["run"]),
+            ("run", vec!["helper"]),
+            ("dead", vec!["helper", "orphan"]),
+        ]);
+        let reachable = reachable_from(["main"], &outgoing);
+        assert_eq!(reachable, HashSet::from(["main", "run", "helper"]));
+        assert!(!reachable.contains("orphan"));
+    }
+
+    #[test]
+    fn markers_include_attributes_above_and_inside_the_range() {
+        let source = "/// Docs\n#[test]\nfn parses() {\n}\n\n#[inline]\npub fn fast() {}\n";
+        let mut extractor = SymbolExtractor::new().expect("extractor");
+        let symbols = extractor
+            .extract_from_source(Language::Rust, "src/lib.rs", source)
+            .expect("extract");
+        let lines = source.lines().collect::<Vec<_>>();
+        let markers = symbols
+            .iter()
+            .map(|symbol| {
+                (
+                    symbol.qualified_name.as_str(),
+                    declaration_markers(&lines, symbol),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        assert_eq!(markers["parses"], vec!["#[test]", "fn parses() {"]);
+        assert_eq!(markers["fast"], vec!["#[inline]", "pub fn fast() {}"]);
+        assert!(is_test_path("web/src/__tests__/app.ts"));
+        assert!(is_test_path("pkg/test_models.py"));
+        assert!(!is_test_path("crates/aether-core/src/lib.rs"));
+    }
+}
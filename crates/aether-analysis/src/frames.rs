@@ -0,0 +1,341 @@
+//! Stack frame to symbol resolution shared by profile ingestion and stack
+//! trace explanation: by source location when a frame carries one, by
+//! qualified name otherwise.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use aether_core::normalize_path;
+use aether_parse::{SymbolExtractor, language_for_path};
+use aether_store::SymbolRecord;
+
+/// Line span of an indexed symbol, from a fresh parse of its file.
+#[derive(Debug, Clone, Copy)]
+struct SymbolSpan {
+    start_line: usize,
+    end_line: usize,
+    record: usize,
+}
+
+pub(crate) struct FrameResolver<'a> {
+    workspace: &'a Path,
+    workspace_prefix: String,
+    records: Vec<SymbolRecord>,
+    segments: Vec<Vec<String>>,
+    by_leaf: HashMap<String, Vec<usize>>,
+    files: BTreeSet<String>,
+    extractor: Option<SymbolExtractor>,
+    spans: HashMap<String, Vec<SymbolSpan>>,
+}
+
+impl<'a> FrameResolver<'a> {
+    pub(crate) fn new(workspace: &'a Path, records: Vec<SymbolRecord>) -> Self {
+        let segments = records
+            .iter()
+            .map(|record| name_segments(&record.qualified_name))
+            .collect::<Vec<_>>();
+        let mut by_leaf = HashMap::<String, Vec<usize>>::new();
+        for (index, segments) in segments.iter().enumerate() {
+            if let Some(leaf) = segments.last() {
+                by_leaf.entry(leaf.clone()).or_default().push(index);
+            }
+        }
+        let files = records
+            .iter()
+            .map(|record| record.file_path.clone())
+            .collect();
+
+        Self {
+            workspace,
+            workspace_prefix: normalize_path(&workspace.to_string_lossy()),
+            records,
+            segments,
+            by_leaf,
+            files,
+            extractor: None,
+            spans: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn record(&self, index: usize) -> &SymbolRecord {
+        &self.records[index]
+    }
+
+    /// Index of the symbol a frame ran in, if it is an indexed symbol.
+    pub(crate) fn resolve(
+        &mut self,
+        name: &str,
+        file: Option<&str>,
+        line: Option<usize>,
+    ) -> Option<usize> {
+        let file = file.and_then(|file| self.indexed_file(file));
+        if let (Some(file), Some(line)) = (file.as_deref(), line)
+            && let Some(index) = self.symbol_at(file, line)
+        {
+            return Some(index);
+        }
+        self.symbol_named(name, file.as_deref())
+    }
+
+    /// 1-based line span of an indexed symbol in the current source.
+    pub(crate) fn span(&mut self, index: usize) -> Option<(usize, usize)> {
+        let file = self.records.get(index)?.file_path.clone();
+        self.spans_for(&file)
+            .iter()
+            .find(|span| span.record == index)
+            .map(|span| (span.start_line, span.end_line))
+    }
+
+    /// Maps an absolute or build-relative path onto an indexed file.
+    pub(crate) fn indexed_file(&self, path: &str) -> Option<String> {
+        let path = normalize_path(path.trim());
+        let relative = path
+            .strip_prefix(self.workspace_prefix.as_str())
+            .map(|rest| rest.trim_start_matches('/'))
+            .unwrap_or(path.as_str())
+            .trim_start_matches("./");
+        if self.files.contains(relative) {
+            return Some(relative.to_owned());
+        }
+        self.files
+            .iter()
+            .filter(|file| relative.ends_with(&format!("/{file}")))
+            .max_by_key(|file| file.len())
+            .cloned()
+    }
+
+    /// Innermost symbol whose span covers `line` (1-based).
+    fn symbol_at(&mut self, file: &str, line: usize) -> Option<usize> {
+        self.spans_for(file)
+            .iter()
+            .filter(|span| span.start_line <= line && line <= span.end_line)
+            .min_by_key(|span| span.end_line - span.start_line)
+            .map(|span| span.record)
+    }
+
+    fn spans_for(&mut self, file: &str) -> &[SymbolSpan] {
+        if !self.spans.contains_key(file) {
+            let spans = self.parse_spans(file);
+            self.spans.insert(file.to_owned(), spans);
+        }
+        &self.spans[file]
+    }
+
+    fn parse_spans(&mut self, file: &str) -> Vec<SymbolSpan> {
+        let Some(language) = language_for_path(Path::new(file)) else {
+            return Vec::new();
+        };
+        let Ok(source) = std::fs::read_to_string(self.workspace.join(file)) else {
+            return Vec::new();
+        };
+        if self.extractor.is_none() {
+            self.extractor = SymbolExtractor::new().ok();
+        }
+        let Some(extractor) = self.extractor.as_mut() else {
+            return Vec::new();
+        };
+        let Ok(symbols) = extractor.extract_from_source(language, file, &source) else {
+            return Vec::new();
+        };
+
+        // A symbol edited since indexing gets a new id, so fall back to its
+        // name within the file.
+        let in_file = self
+            .records
+            .iter()
+            .enumerate()
+            .filter(|(_, record)| record.file_path == file)
+            .collect::<Vec<_>>();
+        symbols
+            .iter()
+            .filter_map(|symbol| {
+                let record = in_file
+                    .iter()
+                    .find(|(_, record)| record.id == symbol.id)
+                    .or_else(|| {
+                        in_file
+                            .iter()
+                            .find(|(_, record)| record.qualified_name == symbol.qualified_name)
+                    })?
+                    .0;
+                Some(SymbolSpan {
+                    start_line: symbol.range.start.line,
+                    end_line: symbol.range.end.line,
+                    record,
+                })
+            })
+            .collect()
+    }
+
+    /// The symbol whose qualified name is the longest suffix of the frame
+    /// name; ambiguous matches resolve only within the frame's file.
+    fn symbol_named(&self, name: &str, file: Option<&str>) -> Option<usize> {
+        let segments = name_segments(name);
+        let leaf = segments.last()?;
+        let matches = self
+            .by_leaf
+            .get(leaf)?
+            .iter()
+            .copied()
+            .filter(|index| segments.ends_with(&self.segments[*index]))
+            .collect::<Vec<_>>();
+        let longest = matches
+            .iter()
+            .map(|index| self.segments[*index].len())
+            .max()?;
+        let mut best = matches
+            .into_iter()
+            .filter(|index| self.segments[*index].len() == longest)
+            .collect::<Vec<_>>();
+        if best.len() > 1
+            && let Some(file) = file
+        {
+            best.retain(|index| self.records[*index].file_path == file);
+        }
+        match best.as_slice() {
+            [index] => Some(*index),
+            _ => None,
+        }
+    }
+}
+
+/// Splits a frame or qualified name into path segments, dropping the parts
+/// that vary by toolchain: Rust hashes and closures, generic arguments,
+/// parameter lists, trait qualifications, C++ qualifiers and perf's `_[k]`
+/// annotations.
+pub(crate) fn name_segments(name: &str) -> Vec<String> {
+    let mut name = name.trim();
+    for annotation in ["_[k]", "_[j]", "_[i]", "_[w]"] {
+        name = name.strip_suffix(annotation).unwrap_or(name);
+    }
+    let name = strip_trait_qualification(name);
+    // Go method receivers: `pkg.(*Type).Method`.
+    let name = name.replace("(*", "").replace(").", ".");
+    let name = strip_groups(&name);
+
+    let mut segments = name
+        .split("::")
+        .flat_map(|segment| segment.rsplit('/').next().unwrap_or(segment).split('.'))
+        // C++ qualifiers such as `const` trail the parameter list.
+        .filter_map(|segment| segment.split_whitespace().next())
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    if segments.last().is_some_and(|last| is_rust_hash(last)) {
+        segments.pop();
+    }
+    segments
+}
+
+/// `<Type as Trait>::method` -> `Type::method`.
+fn strip_trait_qualification(name: &str) -> String {
+    let Some(inner) = name.strip_prefix('<') else {
+        return name.to_owned();
+    };
+    let mut depth = 1usize;
+    for (offset, ch) in inner.char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    let qualified = &inner[..offset];
+                    let ty = qualified.split(" as ").next().unwrap_or(qualified);
+                    return format!("{}{}", ty.trim(), &inner[offset + 1..]);
+                }
+            }
+            _ => {}
+        }
+    }
+    name.to_owned()
+}
+
+/// Drops `<...>`, `(...)`, `[...]` and `{...}` groups, nested or not.
+fn strip_groups(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut depth = 0usize;
+    for ch in name.chars() {
+        match ch {
+            '<' | '(' | '[' | '{' => depth += 1,
+            '>' | ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => out.push(ch),
+            _ => {}
+        }
+    }
+    out
+}
+
+fn is_rust_hash(segment: &str) -> bool {
+    segment.len() == 17
+        && segment.starts_with('h')
+        && segment[1..].chars().all(|ch| ch.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: &str, file_path: &str, qualified_name: &str) -> SymbolRecord {
+        SymbolRecord {
+            id: id.to_owned(),
+            file_path: file_path.to_owned(),
+            language: "rust".to_owned(),
+            kind: "function".to_owned(),
+            qualified_name: qualified_name.to_owned(),
+            signature_fingerprint: format!("sig-{id}"),
+            last_seen_at: 0,
+        }
+    }
+
+    #[test]
+    fn frame_names_normalize_across_toolchains() {
+        assert_eq!(
+            name_segments("aether_store::SqliteStore::open::h0123456789abcdef"),
+            vec!["aether_store", "SqliteStore", "open"]
+        );
+        assert_eq!(
+            name_segments("<aether_store::SqliteStore as aether_store::Store>::get::{{closure}}"),
+            vec!["aether_store", "SqliteStore", "get"]
+        );
+        assert_eq!(
+            name_segments("github.com/acme/api/server.(*Handler).Serve"),
+            vec!["server", "Handler", "Serve"]
+        );
+        assert_eq!(
+            name_segments("app.views.render_page"),
+            vec!["app", "views", "render_page"]
+        );
+        assert_eq!(name_segments("memcpy_[k]"), vec!["memcpy"]);
+    }
+
+    #[test]
+    fn resolves_by_longest_qualified_suffix() {
+        let workspace = tempfile::tempdir().expect("tempdir");
+        let mut resolver = FrameResolver::new(
+            workspace.path(),
+            vec![
+                record("sym-open", "src/store.rs", "SqliteStore::open"),
+                record("sym-config-new", "src/config.rs", "Config::new"),
+                record("sym-run-a", "src/a.rs", "run"),
+                record("sym-run-b", "src/b.rs", "run"),
+            ],
+        );
+
+        let open = resolver.resolve("aether_store::store::SqliteStore::open", None, None);
+        assert_eq!(
+            open.map(|index| resolver.record(index).id.as_str()),
+            Some("sym-open")
+        );
+        assert_eq!(
+            resolver.resolve("tokio::runtime::Runtime::new", None, None),
+            None
+        );
+        assert_eq!(resolver.resolve("demo::run", None, None), None);
+
+        let absolute = format!("{}/src/b.rs", workspace.path().display());
+        let run = resolver.resolve("demo::run", Some(&absolute), None);
+        assert_eq!(
+            run.map(|index| resolver.record(index).id.as_str()),
+            Some("sym-run-b")
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-pub use aether_graph_algo::GraphAlgorithmEdge;
+pub use aether_graph_algo::{EdgeWeights, GraphAlgorithmEdge};
 
 pub fn bfs_shortest_path(edges: &[GraphAlgorithmEdge], from_id: &str, to_id: &str) -> Vec<String> {
     aether_graph_algo::bfs_shortest_path_sync(edges, from_id, to_id).unwrap_or_default()
@@ -20,6 +20,24 @@ pub fn betweenness_centrality(edges: &[GraphAlgorithmEdge]) -> Vec<(String, f64)
     aether_graph_algo::betweenness_centrality_sync(edges)
 }
 
+pub fn weighted_page_rank(
+    edges: &[GraphAlgorithmEdge],
+    weights: &EdgeWeights,
+    damping: f64,
+    iterations: usize,
+) -> HashMap<String, f64> {
+    aether_graph_algo::weighted_page_rank_sync(edges, weights, damping, iterations)
+        .into_iter()
+        .collect()
+}
+
+pub fn weighted_betweenness_centrality(
+    edges: &[GraphAlgorithmEdge],
+    weights: &EdgeWeights,
+) -> Vec<(String, f64)> {
+    aether_graph_algo::weighted_betweenness_centrality_sync(edges, weights)
+}
+
 pub fn louvain_communities(edges: &[GraphAlgorithmEdge]) -> HashMap<String, usize> {
     aether_graph_algo::louvain_sync(edges).into_iter().collect()
 }
@@ -38,3 +56,31 @@ pub fn cross_community_edges(
 ) -> Vec<(String, String, String)> {
     aether_graph_algo::cross_community_edges_sync(edges, communities)
 }
+
+pub fn dominator_tree(
+    edges: &[GraphAlgorithmEdge],
+    entry_ids: &[String],
+) -> HashMap<String, Option<String>> {
+    aether_graph_algo::dominator_tree_sync(edges, entry_ids)
+        .into_iter()
+        .collect()
+}
+
+pub fn dominated_counts(
+    edges: &[GraphAlgorithmEdge],
+    entry_ids: &[String],
+) -> Vec<(String, usize)> {
+    aether_graph_algo::dominated_counts_sync(edges, entry_ids)
+}
+
+pub fn k_core(edges: &[GraphAlgorithmEdge]) -> HashMap<String, usize> {
+    aether_graph_algo::k_core_sync(edges).into_iter().collect()
+}
+
+pub fn articulation_points(edges: &[GraphAlgorithmEdge]) -> Vec<(String, usize)> {
+    aether_graph_algo::articulation_points_sync(edges)
+}
+
+pub fn bridges(edges: &[GraphAlgorithmEdge]) -> Vec<(String, String)> {
+    aether_graph_algo::bridges_sync(edges)
+}
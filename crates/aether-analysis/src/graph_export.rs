@@ -0,0 +1,684 @@
+//! Dependency graph export to Graphviz DOT, GraphML, Mermaid and JSON Graph
+//! Format, filtered by community, path, edge kind or distance from a root
+//! symbol, with PageRank, community, health risk and SIR intent per node.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use aether_core::EdgeKind;
+use aether_store::{SqliteStore, SurrealGraphStore, block_on_store_future};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+use crate::coupling::{AnalysisError, wildcard_match};
+use crate::graph_algorithms::{GraphAlgorithmEdge, louvain_communities, page_rank};
+use crate::health::{HealthAnalyzer, HealthInclude, HealthRequest};
+
+const GRAPH_EXPORT_SCHEMA_VERSION: &str = "1.0";
+pub const DEFAULT_GRAPH_EXPORT_DEPTH: usize = 2;
+/// The health report ranks at most this many symbols; the rest carry no risk.
+const HEALTH_RISK_LIMIT: u32 = 200;
+const EXPORT_EDGE_KINDS: [EdgeKind; 6] = [
+    EdgeKind::Calls,
+    EdgeKind::DependsOn,
+    EdgeKind::TypeRef,
+    EdgeKind::Implements,
+    EdgeKind::Endpoint,
+    EdgeKind::References,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphExportFormat {
+    /// Graphviz DOT.
+    Dot,
+    /// GraphML, as read by yEd and Gephi.
+    Graphml,
+    /// Mermaid flowchart, one subgraph per community.
+    Mermaid,
+    /// JSON Graph Format v2.
+    Json,
+}
+
+impl GraphExportFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dot => "dot",
+            Self::Graphml => "graphml",
+            Self::Mermaid => "mermaid",
+            Self::Json => "json",
+        }
+    }
+}
+
+impl FromStr for GraphExportFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "dot" | "graphviz" | "gv" => Ok(Self::Dot),
+            "graphml" => Ok(Self::Graphml),
+            "mermaid" | "mmd" => Ok(Self::Mermaid),
+            "json" | "jgf" | "json-graph" => Ok(Self::Json),
+            other => Err(format!(
+                "invalid graph format '{other}', expected one of: dot, graphml, mermaid, json"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphExportRequest {
+    pub community: Option<i64>,
+    /// `*`/`?` glob over workspace-relative file paths.
+    pub path_glob: Option<String>,
+    /// Edge kinds to keep; empty keeps every dependency edge.
+    #[serde(default)]
+    pub edge_kinds: Vec<String>,
+    /// Symbol id or qualified name to export the neighborhood of.
+    pub root: Option<String>,
+    /// Hops from `root`, following edges in either direction.
+    pub depth: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedNode {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub file: String,
+    pub pagerank: f64,
+    pub community: Option<i64>,
+    pub health_risk: Option<f64>,
+    pub intent: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ExportedEdge {
+    pub source: String,
+    pub target: String,
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphExport {
+    pub schema_version: String,
+    pub nodes: Vec<ExportedNode>,
+    pub edges: Vec<ExportedEdge>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphExporter {
+    workspace: PathBuf,
+}
+
+impl GraphExporter {
+    pub fn new(workspace: impl AsRef<Path>) -> Self {
+        Self {
+            workspace: workspace.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Exports the filtered dependency graph. Communities and health risk
+    /// come from the graph store; without one, communities are detected on
+    /// the spot and health risk is left out.
+    pub fn export(
+        &self,
+        store: &SqliteStore,
+        graph: Option<&SurrealGraphStore>,
+        request: &GraphExportRequest,
+    ) -> Result<GraphExport, AnalysisError> {
+        let edge_kinds = parse_edge_kinds(&request.edge_kinds)?;
+        let mut notes = Vec::new();
+
+        let records = store.list_all_symbol_records()?;
+        let root = match request.root.as_deref().map(str::trim) {
+            Some(root) if !root.is_empty() => Some(
+                records
+                    .iter()
+                    .find(|record| record.id == root || record.qualified_name == root)
+                    .map(|record| record.id.clone())
+                    .ok_or_else(|| {
+                        AnalysisError::Message(format!("no indexed symbol matches root '{root}'"))
+                    })?,
+            ),
+            _ => None,
+        };
+
+        let edges = store
+            .list_graph_dependency_edges()?
+            .into_iter()
+            .map(|edge| GraphAlgorithmEdge {
+                source_id: edge.source_symbol_id,
+                target_id: edge.target_symbol_id,
+                edge_kind: edge.edge_kind,
+            })
+            .collect::<Vec<_>>();
+        let pagerank = page_rank(&edges, 0.85, 25);
+
+        let communities =
+            match graph.map(|graph| block_on_store_future(graph.list_louvain_communities())) {
+                Some(Ok(Ok(assignments))) => assignments.into_iter().collect::<HashMap<_, _>>(),
+                _ => {
+                    notes.push(
+                        "graph store unavailable; communities were detected locally and their ids \
+                     may differ from `communities`"
+                            .to_owned(),
+                    );
+                    louvain_communities(&edges)
+                        .into_iter()
+                        .map(|(symbol_id, community)| (symbol_id, community as i64))
+                        .collect()
+                }
+            };
+        let health_risk = match graph {
+            Some(graph) => self.health_risk(store, graph).unwrap_or_else(|err| {
+                notes.push(format!("health analysis failed; risk left out: {err}"));
+                HashMap::new()
+            }),
+            None => {
+                notes.push("graph store unavailable; health risk left out".to_owned());
+                HashMap::new()
+            }
+        };
+
+        let candidates = records
+            .iter()
+            .filter(|record| {
+                root.as_deref() == Some(record.id.as_str())
+                    || (request
+                        .community
+                        .is_none_or(|community| communities.get(&record.id) == Some(&community))
+                        && request
+                            .path_glob
+                            .as_deref()
+                            .is_none_or(|glob| wildcard_match(glob, &record.file_path)))
+            })
+            .map(|record| record.id.clone())
+            .collect::<HashSet<_>>();
+        let (kept, kept_edges) = select_subgraph(
+            &candidates,
+            &edges,
+            &edge_kinds,
+            root.as_deref(),
+            request.depth.unwrap_or(DEFAULT_GRAPH_EXPORT_DEPTH),
+        );
+
+        let kept_ids = kept.iter().cloned().collect::<Vec<_>>();
+        let intents = store
+            .list_sir_blobs_for_ids(&kept_ids)?
+            .into_iter()
+            .filter_map(|(symbol_id, blob)| {
+                let sir = serde_json::from_str::<Value>(&blob).ok()?;
+                let intent = sir.get("intent").and_then(Value::as_str)?.trim().to_owned();
+                (!intent.is_empty()).then_some((symbol_id, intent))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut nodes = records
+            .iter()
+            .filter(|record| kept.contains(&record.id))
+            .map(|record| ExportedNode {
+                id: record.id.clone(),
+                name: record.qualified_name.clone(),
+                kind: record.kind.clone(),
+                file: record.file_path.clone(),
+                pagerank: pagerank.get(&record.id).copied().unwrap_or(0.0),
+                community: communities.get(&record.id).copied(),
+                health_risk: health_risk.get(&record.id).copied(),
+                intent: intents.get(&record.id).cloned(),
+            })
+            .collect::<Vec<_>>();
+        nodes.sort_by(|left, right| left.name.cmp(&right.name).then(left.id.cmp(&right.id)));
+        if nodes.is_empty() {
+            notes.push("no symbols matched the filters".to_owned());
+        }
+
+        Ok(GraphExport {
+            schema_version: GRAPH_EXPORT_SCHEMA_VERSION.to_owned(),
+            nodes,
+            edges: kept_edges,
+            notes,
+        })
+    }
+
+    fn health_risk(
+        &self,
+        store: &SqliteStore,
+        graph: &SurrealGraphStore,
+    ) -> Result<HashMap<String, f64>, AnalysisError> {
+        let analyzer = HealthAnalyzer::new(&self.workspace)?;
+        let request = HealthRequest {
+            include: vec![HealthInclude::CriticalSymbols],
+            limit: HEALTH_RISK_LIMIT,
+            min_risk: 0.0,
+        };
+        let report =
+            block_on_store_future(analyzer.analyze_with_handles(&request, store, Some(graph)))??;
+        Ok(report
+            .critical_symbols
+            .into_iter()
+            .map(|entry| (entry.symbol_id, entry.risk_score))
+            .collect())
+    }
+}
+
+pub(crate) fn parse_edge_kinds(raw: &[String]) -> Result<HashSet<&'static str>, AnalysisError> {
+    raw.iter()
+        .map(|kind| {
+            let kind = kind.trim().to_ascii_lowercase();
+            EXPORT_EDGE_KINDS
+                .iter()
+                .map(|known| known.as_str())
+                .find(|known| *known == kind)
+                .ok_or_else(|| {
+                    AnalysisError::Message(format!(
+                        "unknown edge kind '{kind}', expected one of: {}",
+                        EXPORT_EDGE_KINDS.map(EdgeKind::as_str).join(", ")
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Keeps the edges of an allowed kind between candidates and, with a root,
+/// only what lies within `depth` hops of it in either direction.
+fn select_subgraph(
+    candidates: &HashSet<String>,
+    edges: &[GraphAlgorithmEdge],
+    edge_kinds: &HashSet<&str>,
+    root: Option<&str>,
+    depth: usize,
+) -> (BTreeSet<String>, Vec<ExportedEdge>) {
+    let edges = edges
+        .iter()
+        .filter(|edge| {
+            edge.source_id != edge.target_id
+                && (edge_kinds.is_empty() || edge_kinds.contains(edge.edge_kind.as_str()))
+                && candidates.contains(&edge.source_id)
+                && candidates.contains(&edge.target_id)
+        })
+        .collect::<Vec<_>>();
+
+    let kept = match root {
+        Some(root) => {
+            let mut neighbors = HashMap::<&str, Vec<&str>>::new();
+            for edge in &edges {
+                neighbors
+                    .entry(edge.source_id.as_str())
+                    .or_default()
+                    .push(edge.target_id.as_str());
+                neighbors
+                    .entry(edge.target_id.as_str())
+                    .or_default()
+                    .push(edge.source_id.as_str());
+            }
+            let mut reached = BTreeSet::from([root.to_owned()]);
+            let mut queue = VecDeque::from([(root, 0usize)]);
+            while let Some((symbol_id, hops)) = queue.pop_front() {
+                if hops == depth {
+                    continue;
+                }
+                for &next in neighbors.get(symbol_id).into_iter().flatten() {
+                    if reached.insert(next.to_owned()) {
+                        queue.push_back((next, hops + 1));
+                    }
+                }
+            }
+            reached
+        }
+        None => candidates.iter().cloned().collect(),
+    };
+
+    let edges = edges
+        .into_iter()
+        .filter(|edge| kept.contains(&edge.source_id) && kept.contains(&edge.target_id))
+        .map(|edge| ExportedEdge {
+            source: edge.source_id.clone(),
+            target: edge.target_id.clone(),
+            kind: edge.edge_kind.clone(),
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    (kept, edges)
+}
+
+impl GraphExport {
+    pub fn render(&self, format: GraphExportFormat) -> String {
+        match format {
+            GraphExportFormat::Dot => self.render_dot(),
+            GraphExportFormat::Graphml => self.render_graphml(),
+            GraphExportFormat::Mermaid => self.render_mermaid(),
+            GraphExportFormat::Json => self.render_json_graph(),
+        }
+    }
+
+    fn render_dot(&self) -> String {
+        let mut out = String::from("digraph aether {\n  rankdir=LR;\n  node [shape=box];\n");
+        for node in &self.nodes {
+            let mut attributes = vec![
+                format!("label=\"{}\"", escape_dot(&node.name)),
+                format!("kind=\"{}\"", escape_dot(&node.kind)),
+                format!("file=\"{}\"", escape_dot(&node.file)),
+                format!("pagerank=\"{:.6}\"", node.pagerank),
+            ];
+            if let Some(community) = node.community {
+                attributes.push(format!("community=\"{community}\""));
+            }
+            if let Some(risk) = node.health_risk {
+                attributes.push(format!("health_risk=\"{risk:.4}\""));
+            }
+            if let Some(intent) = node.intent.as_deref() {
+                attributes.push(format!("intent=\"{}\"", escape_dot(intent)));
+            }
+            let _ = writeln!(
+                out,
+                "  \"{}\" [{}];",
+                escape_dot(&node.id),
+                attributes.join(", ")
+            );
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                out,
+                "  \"{}\" -> \"{}\" [kind=\"{}\"];",
+                escape_dot(&edge.source),
+                escape_dot(&edge.target),
+                escape_dot(&edge.kind)
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    fn render_graphml(&self) -> String {
+        let mut out = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+        );
+        for (key, domain, kind) in [
+            ("name", "node", "string"),
+            ("kind", "node", "string"),
+            ("file", "node", "string"),
+            ("pagerank", "node", "double"),
+            ("community", "node", "long"),
+            ("health_risk", "node", "double"),
+            ("intent", "node", "string"),
+            ("edge_kind", "edge", "string"),
+        ] {
+            let name = key.strip_prefix("edge_").unwrap_or(key);
+            let _ = writeln!(
+                out,
+                "  <key id=\"{key}\" for=\"{domain}\" attr.name=\"{name}\" attr.type=\"{kind}\"/>"
+            );
+        }
+        out.push_str("  <graph id=\"aether\" edgedefault=\"directed\">\n");
+        for node in &self.nodes {
+            let _ = writeln!(out, "    <node id=\"{}\">", escape_xml(&node.id));
+            let mut data = vec![
+                ("name", escape_xml(&node.name)),
+                ("kind", escape_xml(&node.kind)),
+                ("file", escape_xml(&node.file)),
+                ("pagerank", format!("{:.6}", node.pagerank)),
+            ];
+            if let Some(community) = node.community {
+                data.push(("community", community.to_string()));
+            }
+            if let Some(risk) = node.health_risk {
+                data.push(("health_risk", format!("{risk:.4}")));
+            }
+            if let Some(intent) = node.intent.as_deref() {
+                data.push(("intent", escape_xml(intent)));
+            }
+            for (key, value) in data {
+                let _ = writeln!(out, "      <data key=\"{key}\">{value}</data>");
+            }
+            out.push_str("    </node>\n");
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                out,
+                "    <edge source=\"{}\" target=\"{}\"><data key=\"edge_kind\">{}</data></edge>",
+                escape_xml(&edge.source),
+                escape_xml(&edge.target),
+                escape_xml(&edge.kind)
+            );
+        }
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+
+    fn render_mermaid(&self) -> String {
+        let index = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.id.as_str(), index))
+            .collect::<HashMap<_, _>>();
+        let mut by_community = BTreeMap::<Option<i64>, Vec<usize>>::new();
+        for (position, node) in self.nodes.iter().enumerate() {
+            by_community
+                .entry(node.community)
+                .or_default()
+                .push(position);
+        }
+
+        let mut out = String::from("flowchart LR\n");
+        for (community, members) in &by_community {
+            let indent = match community {
+                Some(community) => {
+                    let _ = writeln!(
+                        out,
+                        "  subgraph community_{}[\"Community {community}\"]",
+                        community.to_string().replace('-', "m")
+                    );
+                    "    "
+                }
+                None => "  ",
+            };
+            for &position in members {
+                let _ = writeln!(
+                    out,
+                    "{indent}n{position}[\"{}\"]",
+                    escape_mermaid(&self.nodes[position].name)
+                );
+            }
+            if community.is_some() {
+                out.push_str("  end\n");
+            }
+        }
+        for edge in &self.edges {
+            let (Some(source), Some(target)) = (
+                index.get(edge.source.as_str()),
+                index.get(edge.target.as_str()),
+            ) else {
+                continue;
+            };
+            let _ = writeln!(out, "  n{source} -->|{}| n{target}", edge.kind);
+        }
+        out
+    }
+
+    fn render_json_graph(&self) -> String {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| {
+                let mut metadata = Map::new();
+                metadata.insert("kind".to_owned(), json!(node.kind));
+                metadata.insert("file".to_owned(), json!(node.file));
+                metadata.insert("pagerank".to_owned(), json!(node.pagerank));
+                metadata.insert("community".to_owned(), json!(node.community));
+                metadata.insert("health_risk".to_owned(), json!(node.health_risk));
+                metadata.insert("intent".to_owned(), json!(node.intent));
+                (
+                    node.id.clone(),
+                    json!({ "label": node.name, "metadata": metadata }),
+                )
+            })
+            .collect::<Map<_, _>>();
+        let edges = self
+            .edges
+            .iter()
+            .map(|edge| {
+                json!({
+                    "source": edge.source,
+                    "target": edge.target,
+                    "relation": edge.kind,
+                    "directed": true,
+                })
+            })
+            .collect::<Vec<_>>();
+        let document = json!({
+            "graph": {
+                "id": "aether",
+                "type": "dependency",
+                "directed": true,
+                "metadata": {
+                    "schema_version": self.schema_version,
+                    "notes": self.notes,
+                },
+                "nodes": nodes,
+                "edges": edges,
+            }
+        });
+        serde_json::to_string_pretty(&document).expect("graph export serialization cannot fail")
+    }
+}
+
+fn escape_dot(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_mermaid(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+        .replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(source: &str, target: &str, kind: &str) -> GraphAlgorithmEdge {
+        GraphAlgorithmEdge {
+            source_id: source.to_owned(),
+            target_id: target.to_owned(),
+            edge_kind: kind.to_owned(),
+        }
+    }
+
+    #[test]
+    fn select_subgraph_walks_depth_from_root_over_allowed_kinds() {
+        let candidates = ["a", "b", "c", "d", "e"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect::<HashSet<_>>();
+        let edges = vec![
+            edge("a", "b", "calls"),
+            edge("b", "c", "calls"),
+            edge("c", "d", "calls"),
+            edge("e", "a", "calls"),
+            edge("a", "d", "type_ref"),
+            edge("a", "x", "calls"),
+        ];
+
+        let (kept, kept_edges) =
+            select_subgraph(&candidates, &edges, &HashSet::from(["calls"]), Some("a"), 2);
+        assert_eq!(
+            kept.into_iter().collect::<Vec<_>>(),
+            vec!["a", "b", "c", "e"]
+        );
+        assert_eq!(
+            kept_edges
+                .iter()
+                .map(|edge| format!("{}->{}", edge.source, edge.target))
+                .collect::<Vec<_>>(),
+            vec!["a->b", "b->c", "e->a"]
+        );
+
+        let (kept, kept_edges) = select_subgraph(&candidates, &edges, &HashSet::new(), None, 0);
+        assert_eq!(kept.len(), 5);
+        assert_eq!(kept_edges.len(), 5);
+    }
+
+    #[test]
+    fn render_formats_escape_labels_and_group_communities() {
+        let export = GraphExport {
+            schema_version: GRAPH_EXPORT_SCHEMA_VERSION.to_owned(),
+            nodes: vec![
+                ExportedNode {
+                    id: "sym-total".to_owned(),
+                    name: "Cart::total".to_owned(),
+                    kind: "function".to_owned(),
+                    file: "src/cart.rs".to_owned(),
+                    pagerank: 0.25,
+                    community: Some(4),
+                    health_risk: Some(0.5),
+                    intent: Some("Sums \"line\" prices".to_owned()),
+                },
+                ExportedNode {
+                    id: "sym-vec".to_owned(),
+                    name: "Vec<Item>".to_owned(),
+                    kind: "struct".to_owned(),
+                    file: "src/item.rs".to_owned(),
+                    pagerank: 0.1,
+                    community: None,
+                    health_risk: None,
+                    intent: None,
+                },
+            ],
+            edges: vec![ExportedEdge {
+                source: "sym-total".to_owned(),
+                target: "sym-vec".to_owned(),
+                kind: "type_ref".to_owned(),
+            }],
+            notes: Vec::new(),
+        };
+
+        assert_eq!(
+            export.render(GraphExportFormat::Dot),
+            "digraph aether {\n  rankdir=LR;\n  node [shape=box];\n  \
+             \"sym-total\" [label=\"Cart::total\", kind=\"function\", file=\"src/cart.rs\", \
+             pagerank=\"0.250000\", community=\"4\", health_risk=\"0.5000\", \
+             intent=\"Sums \\\"line\\\" prices\"];\n  \
+             \"sym-vec\" [label=\"Vec<Item>\", kind=\"struct\", file=\"src/item.rs\", \
+             pagerank=\"0.100000\"];\n  \
+             \"sym-total\" -> \"sym-vec\" [kind=\"type_ref\"];\n}\n"
+        );
+        assert_eq!(
+            export.render(GraphExportFormat::Mermaid),
+            "flowchart LR\n  n1[\"Vec#lt;Item#gt;\"]\n  subgraph community_4[\"Community 4\"]\n    \
+             n0[\"Cart::total\"]\n  end\n  n0 -->|type_ref| n1\n"
+        );
+        assert!(
+            export
+                .render(GraphExportFormat::Graphml)
+                .contains("<data key=\"name\">Vec&lt;Item&gt;</data>")
+        );
+        let json = serde_json::from_str::<Value>(&export.render(GraphExportFormat::Json))
+            .expect("valid JSON");
+        assert_eq!(
+            json["graph"]["nodes"]["sym-total"]["metadata"]["community"],
+            json!(4)
+        );
+        assert_eq!(json["graph"]["edges"][0]["relation"], json!("type_ref"));
+    }
+}
@@ -0,0 +1,930 @@
+//! A small declarative path query language over the dependency graph:
+//!
+//! ```text
+//! from kind = function and path = src/http/*
+//! to name = db::execute
+//! via calls
+//! hops ..6
+//! avoid name = auth::check
+//! limit 20
+//! ```
+//!
+//! `from` and `to` select the endpoints, `through` nodes must all appear
+//! between them and `avoid` nodes are never entered. Predicates compare a
+//! field (`id`, `name`, `kind`, `path`, `language`, `intent`, `side_effects`,
+//! `error_modes`) with `=`/`!=` (glob) or `~`/`!~` (case-insensitive
+//! substring) and combine with `and`, which binds tighter than `or`.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+
+use aether_store::{
+    GraphDependencyEdgeRecord, GraphStore, SqliteStore, SymbolRecord, block_on_store_future,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::coupling::{AnalysisError, wildcard_match};
+use crate::graph_export::parse_edge_kinds;
+
+const GRAPH_QUERY_SCHEMA_VERSION: &str = "1.0";
+pub const DEFAULT_GRAPH_QUERY_LIMIT: usize = 20;
+pub const MAX_GRAPH_QUERY_LIMIT: usize = 200;
+const DEFAULT_MAX_HOPS: usize = 6;
+const MAX_HOPS: usize = 12;
+/// Edge expansions one query may spend before it reports partial results.
+const SEARCH_BUDGET: usize = 250_000;
+const CLAUSE_KEYWORDS: [&str; 7] = ["from", "to", "via", "hops", "through", "avoid", "limit"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeField {
+    Id,
+    Name,
+    Kind,
+    Path,
+    Language,
+    Intent,
+    SideEffects,
+    ErrorModes,
+}
+
+impl NodeField {
+    fn is_sir(self) -> bool {
+        matches!(self, Self::Intent | Self::SideEffects | Self::ErrorModes)
+    }
+}
+
+impl FromStr for NodeField {
+    type Err = AnalysisError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "id" => Ok(Self::Id),
+            "name" => Ok(Self::Name),
+            "kind" => Ok(Self::Kind),
+            "path" | "file" => Ok(Self::Path),
+            "language" | "lang" => Ok(Self::Language),
+            "intent" => Ok(Self::Intent),
+            "side_effects" | "side_effect" => Ok(Self::SideEffects),
+            "error_modes" | "error_mode" => Ok(Self::ErrorModes),
+            other => Err(query_error(format!(
+                "unknown field '{other}', expected one of: id, name, kind, path, language, \
+                 intent, side_effects, error_modes"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PredicateOp {
+    /// `=`: `*`/`?` glob match.
+    Glob,
+    /// `!=`: no glob match.
+    NotGlob,
+    /// `~`: case-insensitive substring.
+    Contains,
+    /// `!~`: no case-insensitive substring.
+    NotContains,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PredicateTerm {
+    pub field: NodeField,
+    pub op: PredicateOp,
+    pub value: String,
+}
+
+/// Terms in disjunctive normal form: any group matches when all its terms do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodePredicate {
+    pub any_of: Vec<Vec<PredicateTerm>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphQuery {
+    pub from: NodePredicate,
+    pub to: NodePredicate,
+    /// Edge kinds a path may follow; empty follows every dependency edge.
+    pub edge_kinds: Vec<String>,
+    pub min_hops: usize,
+    pub max_hops: usize,
+    /// Each predicate must match at least one node strictly between the ends.
+    pub through: Vec<NodePredicate>,
+    /// Nodes matching any of these are never part of a path.
+    pub avoid: Vec<NodePredicate>,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphQueryPathNode {
+    pub symbol_id: String,
+    pub qualified_name: String,
+    pub kind: String,
+    pub file_path: String,
+    pub intent: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphQueryPath {
+    pub nodes: Vec<GraphQueryPathNode>,
+    /// Kinds of the edge between `nodes[i]` and `nodes[i + 1]`, `|`-joined
+    /// when the two symbols are linked more than one way.
+    pub edge_kinds: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphQueryResult {
+    pub schema_version: String,
+    pub paths: Vec<GraphQueryPath>,
+    /// Paths found before `limit` was applied.
+    pub total_paths: usize,
+    pub notes: Vec<String>,
+}
+
+pub fn parse_graph_query(input: &str) -> Result<GraphQuery, AnalysisError> {
+    let mut parser = QueryParser {
+        tokens: tokenize(input)?,
+        position: 0,
+    };
+    let mut from = None;
+    let mut to = None;
+    let mut edge_kinds = Vec::new();
+    let mut hops = None;
+    let mut through = Vec::new();
+    let mut avoid = Vec::new();
+    let mut limit = None;
+
+    while let Some(token) = parser.next() {
+        let Token::Word(keyword) = token else {
+            return Err(query_error(format!(
+                "expected a clause ({}), found {}",
+                CLAUSE_KEYWORDS.join(", "),
+                token.describe()
+            )));
+        };
+        match keyword.to_ascii_lowercase().as_str() {
+            "from" => set_once(&mut from, parser.predicate("from")?, "from")?,
+            "to" => set_once(&mut to, parser.predicate("to")?, "to")?,
+            "via" => edge_kinds.extend(parser.word_list("via")?),
+            "hops" => set_once(&mut hops, parse_hops(&parser.word("hops")?)?, "hops")?,
+            "through" => through.push(parser.predicate("through")?),
+            "avoid" => avoid.push(parser.predicate("avoid")?),
+            "limit" => {
+                let raw = parser.word("limit")?;
+                let value = raw
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|value| (1..=MAX_GRAPH_QUERY_LIMIT).contains(value))
+                    .ok_or_else(|| {
+                        query_error(format!(
+                            "limit must be between 1 and {MAX_GRAPH_QUERY_LIMIT}, found '{raw}'"
+                        ))
+                    })?;
+                set_once(&mut limit, value, "limit")?;
+            }
+            other => {
+                return Err(query_error(format!(
+                    "unknown clause '{other}', expected one of: {}",
+                    CLAUSE_KEYWORDS.join(", ")
+                )));
+            }
+        }
+    }
+
+    let mut edge_kinds = parse_edge_kinds(&edge_kinds)?
+        .into_iter()
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    edge_kinds.sort();
+    let (min_hops, max_hops) = hops.unwrap_or((1, DEFAULT_MAX_HOPS));
+    Ok(GraphQuery {
+        from: from.ok_or_else(|| query_error("a `from` clause is required"))?,
+        to: to.ok_or_else(|| query_error("a `to` clause is required"))?,
+        edge_kinds,
+        min_hops,
+        max_hops,
+        through,
+        avoid,
+        limit: limit.unwrap_or(DEFAULT_GRAPH_QUERY_LIMIT),
+    })
+}
+
+/// Runs `query` against any graph backend, taking symbols and SIR from the
+/// local store. Paths come back shortest first.
+pub fn evaluate_graph_query(
+    store: &SqliteStore,
+    graph: &dyn GraphStore,
+    query: &GraphQuery,
+) -> Result<GraphQueryResult, AnalysisError> {
+    let mut records = store.list_all_symbol_records()?;
+    records.sort_by(|left, right| {
+        left.qualified_name
+            .cmp(&right.qualified_name)
+            .then(left.id.cmp(&right.id))
+    });
+    let edges = block_on_store_future(graph.list_dependency_edges())??;
+
+    let uses_sir = [&query.from, &query.to]
+        .into_iter()
+        .chain(&query.through)
+        .chain(&query.avoid)
+        .flat_map(|predicate| predicate.any_of.iter().flatten())
+        .any(|term| term.field.is_sir());
+    let blobs = if uses_sir {
+        let ids = records
+            .iter()
+            .map(|record| record.id.clone())
+            .collect::<Vec<_>>();
+        store.list_sir_blobs_for_ids(&ids)?
+    } else {
+        HashMap::new()
+    };
+    let nodes = records
+        .into_iter()
+        .map(|record| {
+            let blob = blobs.get(&record.id).map(String::as_str);
+            QueryNode::new(record, blob)
+        })
+        .collect::<Vec<_>>();
+
+    let search = search_paths(&nodes, &edges, query);
+    let mut notes = Vec::new();
+    if search.truncated {
+        notes.push(format!(
+            "search stopped after {SEARCH_BUDGET} edge expansions; narrow the query or lower \
+             `hops` for complete results"
+        ));
+    }
+    if search.paths.is_empty() {
+        notes.push("no paths matched the query".to_owned());
+    }
+    let total_paths = search.paths.len();
+    let paths = search
+        .paths
+        .into_iter()
+        .take(query.limit)
+        .collect::<Vec<_>>();
+
+    let path_ids = paths
+        .iter()
+        .flat_map(|path| {
+            path.nodes
+                .iter()
+                .map(|&index| nodes[index].record.id.clone())
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let intents = store
+        .list_sir_blobs_for_ids(&path_ids)?
+        .into_iter()
+        .filter_map(|(symbol_id, blob)| Some((symbol_id, sir_intent(&blob)?)))
+        .collect::<HashMap<_, _>>();
+
+    Ok(GraphQueryResult {
+        schema_version: GRAPH_QUERY_SCHEMA_VERSION.to_owned(),
+        paths: paths
+            .into_iter()
+            .map(|path| GraphQueryPath {
+                nodes: path
+                    .nodes
+                    .iter()
+                    .map(|&index| {
+                        let record = &nodes[index].record;
+                        GraphQueryPathNode {
+                            symbol_id: record.id.clone(),
+                            qualified_name: record.qualified_name.clone(),
+                            kind: record.kind.clone(),
+                            file_path: record.file_path.clone(),
+                            intent: intents.get(&record.id).cloned(),
+                        }
+                    })
+                    .collect(),
+                edge_kinds: path.edge_kinds,
+            })
+            .collect(),
+        total_paths,
+        notes,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Op(PredicateOp),
+    Comma,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Self::Word(word) => format!("'{word}'"),
+            Self::Quoted(value) => format!("\"{value}\""),
+            Self::Op(_) => "an operator".to_owned(),
+            Self::Comma => "','".to_owned(),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, AnalysisError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&ch) = chars.peek() {
+        match ch {
+            ch if ch.is_whitespace() => {
+                chars.next();
+            }
+            ',' => {
+                chars.next();
+                tokens.push(Token::Comma);
+            }
+            '=' => {
+                chars.next();
+                tokens.push(Token::Op(PredicateOp::Glob));
+            }
+            '~' => {
+                chars.next();
+                tokens.push(Token::Op(PredicateOp::Contains));
+            }
+            '!' => {
+                chars.next();
+                let op = match chars.next() {
+                    Some('=') => PredicateOp::NotGlob,
+                    Some('~') => PredicateOp::NotContains,
+                    _ => return Err(query_error("expected '!=' or '!~' after '!'")),
+                };
+                tokens.push(Token::Op(op));
+            }
+            '"' | '\'' => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\\') => match chars.next() {
+                            Some(escaped) => value.push(escaped),
+                            None => return Err(query_error("unterminated quoted value")),
+                        },
+                        Some(next) if next == ch => break,
+                        Some(next) => value.push(next),
+                        None => return Err(query_error("unterminated quoted value")),
+                    }
+                }
+                tokens.push(Token::Quoted(value));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, ',' | '=' | '~' | '!' | '"' | '\'') {
+                        break;
+                    }
+                    word.push(next);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct QueryParser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl QueryParser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += usize::from(token.is_some());
+        token
+    }
+
+    fn peek_word(&self) -> Option<String> {
+        match self.tokens.get(self.position) {
+            Some(Token::Word(word)) => Some(word.to_ascii_lowercase()),
+            _ => None,
+        }
+    }
+
+    fn word(&mut self, clause: &str) -> Result<String, AnalysisError> {
+        match self.next() {
+            Some(Token::Word(word) | Token::Quoted(word)) => Ok(word),
+            Some(token) => Err(query_error(format!(
+                "`{clause}` expects a value, found {}",
+                token.describe()
+            ))),
+            None => Err(query_error(format!("`{clause}` expects a value"))),
+        }
+    }
+
+    fn word_list(&mut self, clause: &str) -> Result<Vec<String>, AnalysisError> {
+        let mut words = vec![self.word(clause)?];
+        while self.tokens.get(self.position) == Some(&Token::Comma) {
+            self.position += 1;
+            words.push(self.word(clause)?);
+        }
+        Ok(words)
+    }
+
+    fn predicate(&mut self, clause: &str) -> Result<NodePredicate, AnalysisError> {
+        let mut any_of = vec![Vec::new()];
+        loop {
+            let field = match self.next() {
+                Some(Token::Word(field)) => field.parse::<NodeField>()?,
+                _ => {
+                    return Err(query_error(format!(
+                        "`{clause}` expects `<field> <op> <value>`"
+                    )));
+                }
+            };
+            let op = match self.next() {
+                Some(Token::Op(op)) => op,
+                _ => {
+                    return Err(query_error(format!(
+                        "`{clause}` expects one of =, !=, ~, !~ after the field"
+                    )));
+                }
+            };
+            let value = self.word(clause)?;
+            any_of
+                .last_mut()
+                .expect("predicate always has a group")
+                .push(PredicateTerm { field, op, value });
+
+            match self.peek_word().as_deref() {
+                Some("and") => self.position += 1,
+                Some("or") => {
+                    self.position += 1;
+                    any_of.push(Vec::new());
+                }
+                _ => break,
+            }
+        }
+        Ok(NodePredicate { any_of })
+    }
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, clause: &str) -> Result<(), AnalysisError> {
+    if slot.replace(value).is_some() {
+        return Err(query_error(format!("`{clause}` may appear only once")));
+    }
+    Ok(())
+}
+
+/// `N` is at most N hops; `N..M`, `..M` and `N..` set explicit bounds.
+fn parse_hops(raw: &str) -> Result<(usize, usize), AnalysisError> {
+    let invalid = || {
+        query_error(format!(
+            "invalid hops '{raw}', expected N, N..M, ..M or N.. within 1..{MAX_HOPS}"
+        ))
+    };
+    let bound = |value: &str, default: usize| {
+        if value.is_empty() {
+            Ok(default)
+        } else {
+            value.parse::<usize>().map_err(|_| invalid())
+        }
+    };
+    let (min, max) = match raw.split_once("..") {
+        Some((min, max)) => (bound(min, 1)?, bound(max, MAX_HOPS)?),
+        None => (1, bound(raw, DEFAULT_MAX_HOPS)?),
+    };
+    if min == 0 || min > max || max > MAX_HOPS {
+        return Err(invalid());
+    }
+    Ok((min, max))
+}
+
+fn query_error(message: impl Into<String>) -> AnalysisError {
+    AnalysisError::Message(format!("invalid graph query: {}", message.into()))
+}
+
+fn sir_intent(blob: &str) -> Option<String> {
+    let sir = serde_json::from_str::<Value>(blob).ok()?;
+    let intent = sir.get("intent").and_then(Value::as_str)?.trim();
+    (!intent.is_empty()).then(|| intent.to_owned())
+}
+
+struct QueryNode {
+    record: SymbolRecord,
+    intent: Option<String>,
+    side_effects: Vec<String>,
+    error_modes: Vec<String>,
+}
+
+impl QueryNode {
+    fn new(record: SymbolRecord, sir_blob: Option<&str>) -> Self {
+        let sir = sir_blob.and_then(|blob| serde_json::from_str::<Value>(blob).ok());
+        let strings = |key: &str| -> Vec<String> {
+            sir.as_ref()
+                .and_then(|sir| sir.get(key))
+                .and_then(Value::as_array)
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_owned)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Self {
+            intent: sir_blob.and_then(sir_intent),
+            side_effects: strings("side_effects"),
+            error_modes: strings("error_modes"),
+            record,
+        }
+    }
+
+    fn values(&self, field: NodeField) -> Vec<&str> {
+        match field {
+            NodeField::Id => vec![self.record.id.as_str()],
+            NodeField::Name => vec![self.record.qualified_name.as_str()],
+            NodeField::Kind => vec![self.record.kind.as_str()],
+            NodeField::Path => vec![self.record.file_path.as_str()],
+            NodeField::Language => vec![self.record.language.as_str()],
+            NodeField::Intent => self.intent.as_deref().into_iter().collect(),
+            NodeField::SideEffects => self.side_effects.iter().map(String::as_str).collect(),
+            NodeField::ErrorModes => self.error_modes.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+impl PredicateTerm {
+    fn matches(&self, node: &QueryNode) -> bool {
+        let needle = self.value.to_lowercase();
+        let hit = node
+            .values(self.field)
+            .into_iter()
+            .any(|value| match self.op {
+                PredicateOp::Glob | PredicateOp::NotGlob => wildcard_match(&self.value, value),
+                PredicateOp::Contains | PredicateOp::NotContains => {
+                    value.to_lowercase().contains(&needle)
+                }
+            });
+        match self.op {
+            PredicateOp::Glob | PredicateOp::Contains => hit,
+            PredicateOp::NotGlob | PredicateOp::NotContains => !hit,
+        }
+    }
+}
+
+impl NodePredicate {
+    fn matches(&self, node: &QueryNode) -> bool {
+        self.any_of
+            .iter()
+            .any(|group| group.iter().all(|term| term.matches(node)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IndexedPath {
+    nodes: Vec<usize>,
+    edge_kinds: Vec<String>,
+}
+
+struct PathSearch {
+    paths: Vec<IndexedPath>,
+    truncated: bool,
+}
+
+/// Enumerates simple paths from every `from` node to the first `to` node
+/// reached, over `nodes` in display order.
+fn search_paths(
+    nodes: &[QueryNode],
+    edges: &[GraphDependencyEdgeRecord],
+    query: &GraphQuery,
+) -> PathSearch {
+    let index = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, node)| (node.record.id.as_str(), position))
+        .collect::<HashMap<_, _>>();
+    let avoided = nodes
+        .iter()
+        .map(|node| query.avoid.iter().any(|predicate| predicate.matches(node)))
+        .collect::<Vec<_>>();
+    let edge_kinds = query
+        .edge_kinds
+        .iter()
+        .map(String::as_str)
+        .collect::<HashSet<_>>();
+
+    let mut links = BTreeMap::<(usize, usize), BTreeSet<&str>>::new();
+    for edge in edges {
+        if !edge_kinds.is_empty() && !edge_kinds.contains(edge.edge_kind.as_str()) {
+            continue;
+        }
+        let (Some(&source), Some(&target)) = (
+            index.get(edge.source_symbol_id.as_str()),
+            index.get(edge.target_symbol_id.as_str()),
+        ) else {
+            continue;
+        };
+        if source != target && !avoided[source] && !avoided[target] {
+            links
+                .entry((source, target))
+                .or_default()
+                .insert(edge.edge_kind.as_str());
+        }
+    }
+    let mut forward = vec![Vec::new(); nodes.len()];
+    let mut backward = vec![Vec::new(); nodes.len()];
+    for &(source, target) in links.keys() {
+        forward[source].push(target);
+        backward[target].push(source);
+    }
+
+    let is_target = nodes
+        .iter()
+        .zip(&avoided)
+        .map(|(node, avoided)| !avoided && query.to.matches(node))
+        .collect::<Vec<_>>();
+    // Hops from each node to its nearest target, to prune walks that cannot
+    // arrive within `max_hops`.
+    let mut distance = vec![usize::MAX; nodes.len()];
+    let mut queue = VecDeque::new();
+    for (position, _) in is_target.iter().enumerate().filter(|(_, target)| **target) {
+        distance[position] = 0;
+        queue.push_back(position);
+    }
+    while let Some(current) = queue.pop_front() {
+        for &previous in &backward[current] {
+            if distance[previous] == usize::MAX {
+                distance[previous] = distance[current] + 1;
+                queue.push_back(previous);
+            }
+        }
+    }
+
+    let through = query
+        .through
+        .iter()
+        .map(|predicate| {
+            nodes
+                .iter()
+                .map(|node| predicate.matches(node))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut walker = PathWalker {
+        forward: &forward,
+        distance: &distance,
+        is_target: &is_target,
+        through: &through,
+        min_hops: query.min_hops,
+        max_hops: query.max_hops,
+        budget: SEARCH_BUDGET,
+        path: Vec::new(),
+        on_path: vec![false; nodes.len()],
+        found: Vec::new(),
+    };
+    for (position, node) in nodes.iter().enumerate() {
+        if avoided[position] || distance[position] == usize::MAX || !query.from.matches(node) {
+            continue;
+        }
+        walker.path.push(position);
+        walker.on_path[position] = true;
+        walker.walk(position);
+        walker.on_path[position] = false;
+        walker.path.pop();
+    }
+
+    let mut paths = walker
+        .found
+        .into_iter()
+        .map(|path| IndexedPath {
+            edge_kinds: path
+                .windows(2)
+                .map(|pair| {
+                    links
+                        .get(&(pair[0], pair[1]))
+                        .map(|kinds| kinds.iter().copied().collect::<Vec<_>>().join("|"))
+                        .unwrap_or_default()
+                })
+                .collect(),
+            nodes: path,
+        })
+        .collect::<Vec<_>>();
+    paths.sort_by(|left, right| {
+        left.nodes
+            .len()
+            .cmp(&right.nodes.len())
+            .then(left.nodes.cmp(&right.nodes))
+    });
+    PathSearch {
+        paths,
+        truncated: walker.budget == 0,
+    }
+}
+
+struct PathWalker<'a> {
+    forward: &'a [Vec<usize>],
+    distance: &'a [usize],
+    is_target: &'a [bool],
+    through: &'a [Vec<bool>],
+    min_hops: usize,
+    max_hops: usize,
+    budget: usize,
+    path: Vec<usize>,
+    on_path: Vec<bool>,
+    found: Vec<Vec<usize>>,
+}
+
+impl PathWalker<'_> {
+    fn walk(&mut self, current: usize) {
+        let hops = self.path.len() - 1;
+        if hops > 0 && self.is_target[current] {
+            if hops >= self.min_hops && self.passes_through() {
+                self.found.push(self.path.clone());
+            }
+            return;
+        }
+
+        let forward = self.forward;
+        for &next in &forward[current] {
+            if self.budget == 0 {
+                return;
+            }
+            self.budget -= 1;
+            if self.on_path[next]
+                || self.distance[next] == usize::MAX
+                || hops + 1 + self.distance[next] > self.max_hops
+            {
+                continue;
+            }
+            self.path.push(next);
+            self.on_path[next] = true;
+            self.walk(next);
+            self.on_path[next] = false;
+            self.path.pop();
+        }
+    }
+
+    fn passes_through(&self) -> bool {
+        let interior = &self.path[1..self.path.len() - 1];
+        self.through
+            .iter()
+            .all(|matches| interior.iter().any(|&position| matches[position]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, kind: &str, file_path: &str, intent: Option<&str>) -> QueryNode {
+        let blob = intent.map(|intent| {
+            serde_json::json!({ "intent": intent, "side_effects": ["writes database"] }).to_string()
+        });
+        QueryNode::new(
+            SymbolRecord {
+                id: id.to_owned(),
+                file_path: file_path.to_owned(),
+                language: "rust".to_owned(),
+                kind: kind.to_owned(),
+                qualified_name: id.to_owned(),
+                signature_fingerprint: String::new(),
+                last_seen_at: 0,
+            },
+            blob.as_deref(),
+        )
+    }
+
+    fn edge(source: &str, target: &str, kind: &str) -> GraphDependencyEdgeRecord {
+        GraphDependencyEdgeRecord {
+            source_symbol_id: source.to_owned(),
+            target_symbol_id: target.to_owned(),
+            edge_kind: kind.to_owned(),
+        }
+    }
+
+    #[test]
+    fn parse_graph_query_reads_clauses_and_precedence() {
+        let query = parse_graph_query(
+            "FROM kind = function and path = 'src/http/*' or name ~ handler \
+             to name = db::execute via calls, type_ref hops 2..4 \
+             avoid name = \"auth::check\" through side_effects !~ cache limit 5",
+        )
+        .expect("query parses");
+
+        assert_eq!(query.from.any_of.len(), 2);
+        assert_eq!(
+            query.from.any_of[0][1],
+            PredicateTerm {
+                field: NodeField::Path,
+                op: PredicateOp::Glob,
+                value: "src/http/*".to_owned(),
+            }
+        );
+        assert_eq!(query.from.any_of[1][0].op, PredicateOp::Contains);
+        assert_eq!(query.edge_kinds, vec!["calls", "type_ref"]);
+        assert_eq!((query.min_hops, query.max_hops), (2, 4));
+        assert_eq!(query.avoid[0].any_of[0][0].value, "auth::check");
+        assert_eq!(query.through[0].any_of[0][0].op, PredicateOp::NotContains);
+        assert_eq!(query.limit, 5);
+
+        let defaults = parse_graph_query("from name = a to name = b").expect("query parses");
+        assert_eq!(
+            (defaults.min_hops, defaults.max_hops),
+            (1, DEFAULT_MAX_HOPS)
+        );
+        assert_eq!(defaults.limit, DEFAULT_GRAPH_QUERY_LIMIT);
+
+        for invalid in [
+            "from name = a",
+            "from name = a to name = b to name = c",
+            "from owner = a to name = b",
+            "from name = a to name = b hops 0..3",
+            "from name = a to name = b via imports",
+            "from name = 'a to name = b",
+        ] {
+            assert!(parse_graph_query(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn search_paths_honours_avoid_through_and_hop_bounds() {
+        let nodes = vec![
+            node("auth::check", "function", "src/auth.rs", None),
+            node("db::execute", "function", "src/db.rs", Some("Runs SQL")),
+            node("http::get", "function", "src/http/get.rs", None),
+            node("http::post", "function", "src/http/post.rs", None),
+            node("repo::load", "function", "src/repo.rs", None),
+            node(
+                "repo::save",
+                "function",
+                "src/repo.rs",
+                Some("Persists rows"),
+            ),
+        ];
+        let edges = vec![
+            edge("http::get", "repo::load", "calls"),
+            edge("repo::load", "db::execute", "calls"),
+            edge("http::post", "auth::check", "calls"),
+            edge("auth::check", "repo::save", "calls"),
+            edge("http::post", "repo::save", "calls"),
+            edge("http::post", "repo::save", "type_ref"),
+            edge("repo::save", "db::execute", "calls"),
+            edge("db::execute", "http::get", "references"),
+        ];
+        let names = |search: &PathSearch| {
+            search
+                .paths
+                .iter()
+                .map(|path| {
+                    path.nodes
+                        .iter()
+                        .map(|&index| nodes[index].record.qualified_name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" > ")
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let query = parse_graph_query("from path = src/http/* to name = db::execute")
+            .expect("query parses");
+        let search = search_paths(&nodes, &edges, &query);
+        assert_eq!(
+            names(&search),
+            vec![
+                "http::get > repo::load > db::execute",
+                "http::post > repo::save > db::execute",
+                "http::post > auth::check > repo::save > db::execute",
+            ]
+        );
+        assert_eq!(search.paths[1].edge_kinds, vec!["calls|type_ref", "calls"]);
+        assert!(!search.truncated);
+
+        let query = parse_graph_query(
+            "from path = src/http/* to name = db::execute avoid name = auth::check \
+             through intent ~ persist",
+        )
+        .expect("query parses");
+        assert_eq!(
+            names(&search_paths(&nodes, &edges, &query)),
+            vec!["http::post > repo::save > db::execute"]
+        );
+
+        let query = parse_graph_query(
+            "from path = src/http/* to side_effects = 'writes *' hops 3 via calls",
+        )
+        .expect("query parses");
+        assert_eq!(
+            names(&search_paths(&nodes, &edges, &query)),
+            vec![
+                "http::post > repo::save",
+                "http::get > repo::load > db::execute",
+                "http::post > auth::check > repo::save",
+            ]
+        );
+
+        let query = parse_graph_query("from path = src/http/* to name = db::execute hops 3..3")
+            .expect("query parses");
+        assert_eq!(
+            names(&search_paths(&nodes, &edges, &query)),
+            vec!["http::post > auth::check > repo::save > db::execute"]
+        );
+    }
+}
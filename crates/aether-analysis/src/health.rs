@@ -9,11 +9,14 @@ use aether_store::{
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::choke_points::{ChokePointEntry, DEFAULT_MIN_CHOKE_REGION, find_choke_points};
 use crate::coupling::AnalysisError;
 use crate::graph_algorithms::{
-    GraphAlgorithmEdge, betweenness_centrality, connected_components, louvain_communities,
-    page_rank, strongly_connected_components,
+    EdgeWeights, GraphAlgorithmEdge, betweenness_centrality, connected_components,
+    louvain_communities, page_rank, strongly_connected_components, weighted_betweenness_centrality,
+    weighted_page_rank,
 };
+use crate::profiles::execution_edge_weights;
 
 const HEALTH_SCHEMA_VERSION: &str = "1.0";
 const DEFAULT_LIMIT: u32 = 10;
@@ -29,6 +32,7 @@ pub enum HealthInclude {
     Cycles,
     Orphans,
     RiskHotspots,
+    ChokePoints,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -61,6 +65,8 @@ pub struct HealthReport {
     pub orphans: Vec<OrphanEntry>,
     pub risk_hotspots: Vec<RiskHotspotEntry>,
     #[serde(default)]
+    pub choke_points: Vec<ChokePointEntry>,
+    #[serde(default)]
     pub notes: Vec<String>,
 }
 
@@ -150,6 +156,7 @@ pub struct HealthAnalyzer {
     workspace: PathBuf,
     config: HealthConfig,
     graph_backend: GraphBackend,
+    execution_weighted: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -197,6 +204,7 @@ impl HealthAnalyzer {
             workspace,
             config: config.health,
             graph_backend: config.storage.graph_backend,
+            execution_weighted: config.profiles.execution_weighted,
         })
     }
 
@@ -255,6 +263,39 @@ impl HealthAnalyzer {
             .await
     }
 
+    /// Sampled call-edge weights when `[profiles].execution_weighted` is
+    /// set and a profile has been ingested; `None` keeps centrality static.
+    fn execution_weights(
+        &self,
+        store: &SqliteStore,
+        notes: &mut Vec<String>,
+    ) -> Option<EdgeWeights> {
+        if !self.execution_weighted {
+            return None;
+        }
+        let weights = match store.list_edge_sample_counts() {
+            Ok(counts) => execution_edge_weights(&counts),
+            Err(err) => {
+                notes.push(format!(
+                    "Profile samples unavailable ({err}); using static centrality"
+                ));
+                return None;
+            }
+        };
+        if weights.is_empty() {
+            notes.push(
+                "Execution weighting enabled but no profiles ingested; using static centrality"
+                    .to_owned(),
+            );
+            return None;
+        }
+        notes.push(format!(
+            "Centrality weighted by {} profiled call edges",
+            weights.len()
+        ));
+        Some(weights)
+    }
+
     pub async fn centrality_by_file(&self) -> Result<FileCentralityReport, AnalysisError> {
         let mut notes = Vec::new();
 
@@ -380,10 +421,14 @@ impl HealthAnalyzer {
             }
         };
 
+        let weights = self.execution_weights(store, &mut notes);
         let algo_edges = dependency_edges.clone();
-        let pagerank_scores = tokio::task::spawn_blocking(move || page_rank(&algo_edges, 0.85, 25))
-            .await
-            .map_err(|err| AnalysisError::Message(format!("spawn_blocking failed: {err}")))?;
+        let pagerank_scores = tokio::task::spawn_blocking(move || match weights {
+            Some(weights) => weighted_page_rank(&algo_edges, &weights, 0.85, 25),
+            None => page_rank(&algo_edges, 0.85, 25),
+        })
+        .await
+        .map_err(|err| AnalysisError::Message(format!("spawn_blocking failed: {err}")))?;
 
         let mut symbol_ids = BTreeSet::<String>::new();
         for symbol in &symbol_rows {
@@ -556,17 +601,27 @@ impl HealthAnalyzer {
             notes.push("No dependency edges found in SurrealDB".to_owned());
         }
 
+        let weights = self.execution_weights(store, &mut notes);
         let algo_edges = dependency_edges.clone();
-        let (pagerank_scores, betweenness_scores, scc, cc, communities) =
+        let (pagerank_scores, betweenness_scores, scc, cc, communities, choke_points) =
             tokio::task::spawn_blocking(move || {
+                let (pagerank, betweenness) = match weights {
+                    Some(weights) => (
+                        weighted_page_rank(&algo_edges, &weights, 0.85, 25),
+                        weighted_betweenness_centrality(&algo_edges, &weights),
+                    ),
+                    None => (
+                        page_rank(&algo_edges, 0.85, 25),
+                        betweenness_centrality(&algo_edges),
+                    ),
+                };
                 (
-                    page_rank(&algo_edges, 0.85, 25),
-                    betweenness_centrality(&algo_edges)
-                        .into_iter()
-                        .collect::<HashMap<_, _>>(),
+                    pagerank,
+                    betweenness.into_iter().collect::<HashMap<_, _>>(),
                     strongly_connected_components(&algo_edges),
                     connected_components(&algo_edges),
                     louvain_communities(&algo_edges),
+                    find_choke_points(&algo_edges, DEFAULT_MIN_CHOKE_REGION),
                 )
             })
             .await
@@ -679,6 +734,10 @@ impl HealthAnalyzer {
         }
 
         let max_pagerank = pagerank_scores.values().copied().fold(0.0f64, f64::max);
+        let choke_regions = choke_points
+            .iter()
+            .map(|point| (point.symbol_id.as_str(), point.region()))
+            .collect::<HashMap<_, _>>();
 
         let mut computed_symbols = Vec::<ComputedSymbol>::new();
         for symbol_id in &symbol_ids_vec {
@@ -740,6 +799,9 @@ impl HealthAnalyzer {
             if contribution_recency > 0.1 {
                 risk_factors.push("not accessed recently".to_owned());
             }
+            if let Some(region) = choke_regions.get(symbol_id.as_str()) {
+                risk_factors.push(format!("choke point cutting off {region} symbols"));
+            }
 
             computed_symbols.push(ComputedSymbol {
                 symbol_id: symbol_id.clone(),
@@ -893,6 +955,14 @@ impl HealthAnalyzer {
             })
             .collect::<Vec<_>>();
 
+        let choke_points_all = choke_points
+            .iter()
+            .map(|point| {
+                let symbol = to_symbol_ref(&point.symbol_id, &symbol_snapshot_by_id);
+                ChokePointEntry::new(point, symbol.name, symbol.file)
+            })
+            .collect::<Vec<_>>();
+
         let communities_detected =
             communities.values().copied().collect::<HashSet<_>>().len() as u32;
 
@@ -950,6 +1020,15 @@ impl HealthAnalyzer {
             Vec::new()
         };
 
+        let choke_points = if include.contains(&HealthInclude::ChokePoints) {
+            choke_points_all
+                .into_iter()
+                .take(limit as usize)
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+
         Ok(HealthReport {
             schema_version: HEALTH_SCHEMA_VERSION.to_owned(),
             analysis,
@@ -958,6 +1037,7 @@ impl HealthAnalyzer {
             cycles,
             orphans,
             risk_hotspots,
+            choke_points,
             notes,
         })
     }
@@ -983,6 +1063,7 @@ fn effective_include_set(raw: &[HealthInclude]) -> HashSet<HealthInclude> {
             HealthInclude::Cycles,
             HealthInclude::Orphans,
             HealthInclude::RiskHotspots,
+            HealthInclude::ChokePoints,
         ]
         .into_iter()
         .collect();
@@ -1007,6 +1088,7 @@ fn empty_report(analyzed_at: i64, notes: Vec<String>) -> HealthReport {
         cycles: Vec::new(),
         orphans: Vec::new(),
         risk_hotspots: Vec::new(),
+        choke_points: Vec::new(),
         notes,
     }
 }
@@ -1091,7 +1173,7 @@ async fn query_dependency_edges(
                 edge_kind: edge_kind
             }
             FROM depends_on
-            WHERE edge_kind INSIDE ["calls", "depends_on", "type_ref", "implements"];
+            WHERE edge_kind INSIDE ["calls", "depends_on", "type_ref", "implements", "endpoint", "references"];
             "#,
         )
         .await
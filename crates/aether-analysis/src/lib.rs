@@ -1,20 +1,53 @@
+mod affected_tests;
+mod api_surface;
+mod architecture;
 mod causal;
+mod choke_points;
 mod coupling;
+mod dead_code;
 mod drift;
+mod frames;
 mod graph_algorithms;
+mod graph_export;
+mod graph_query;
 mod health;
+mod ownership;
+mod profiles;
 mod refactor;
 mod sir_quality_signals;
+mod stack_traces;
 mod test_intents;
+mod test_results;
 
+pub use affected_tests::{
+    AFFECTED_TESTS_EDGE_KINDS, AffectedTest, AffectedTestsAnalyzer, AffectedTestsReport,
+    AffectedTestsRequest, ChangedSymbol, DEFAULT_AFFECTED_TESTS_MAX_DEPTH, RunnerCommand,
+    SymbolChangeType, TestRunner, TestSelectionReason,
+};
+pub use api_surface::{
+    ApiChange, ApiChangeKind, ApiItem, DEFAULT_RELEASE_CHECK_THRESHOLD, PackageApiSummary,
+    ReleaseCheckReport, ReleaseCheckRequest, check_release, exported_symbols,
+};
+pub use architecture::{
+    ARCHITECTURE_SPEC_PATH, ArchitectureAnalyzer, ArchitectureCheckResult,
+    ArchitectureDependencyRequest, ArchitectureDependencyVerdict, ArchitectureLayer,
+    ArchitectureRule, ArchitectureSpec, ArchitectureViolation, check_architecture_edges,
+};
 pub use causal::{
     CausalAnalyzer, CausalChainChange, CausalChainCoupling, CausalChainEntry, CausalChainSirDiff,
     TraceCauseAnalysisWindow, TraceCauseRequest, TraceCauseResult, TraceCauseTarget,
 };
+pub use choke_points::{
+    ChokePoint, ChokePointEntry, DEFAULT_MIN_CHOKE_REGION, find_choke_points, graph_entry_points,
+};
 pub use coupling::{
     AnalysisError, BlastRadiusEntry, BlastRadiusRequest, BlastRadiusResult, CouplingAnalyzer,
     CouplingEdge, CouplingMiningOutcome, CouplingType, MineCouplingRequest, RiskLevel,
-    SignalBreakdown,
+    SignalBreakdown, wildcard_match,
+};
+pub use dead_code::{
+    DEAD_CODE_EDGE_KINDS, DeadCodeAnalyzer, DeadCodeConfidence, DeadCodeReport, DeadCodeRequest,
+    DeadCodeRootKind, UnreachableSymbol,
 };
 pub use drift::{
     AcknowledgeDriftRequest, AcknowledgeDriftResult, BoundaryViolationEntry, CommunitiesRequest,
@@ -23,13 +56,45 @@ pub use drift::{
     OrphanedSubgraphEntry, SemanticDriftEntry, StructuralAnomalies,
 };
 pub use graph_algorithms::{
-    GraphAlgorithmEdge, betweenness_centrality, bfs_shortest_path, connected_components,
-    cross_community_edges, louvain_communities, page_rank, strongly_connected_components,
+    EdgeWeights, GraphAlgorithmEdge, articulation_points, betweenness_centrality,
+    bfs_shortest_path, bridges, connected_components, cross_community_edges, dominated_counts,
+    dominator_tree, k_core, louvain_communities, page_rank, strongly_connected_components,
+    weighted_betweenness_centrality, weighted_page_rank,
+};
+pub use graph_export::{
+    DEFAULT_GRAPH_EXPORT_DEPTH, ExportedEdge, ExportedNode, GraphExport, GraphExportFormat,
+    GraphExportRequest, GraphExporter,
+};
+pub use graph_query::{
+    DEFAULT_GRAPH_QUERY_LIMIT, GraphQuery, GraphQueryPath, GraphQueryPathNode, GraphQueryResult,
+    MAX_GRAPH_QUERY_LIMIT, NodeField, NodePredicate, PredicateOp, PredicateTerm,
+    evaluate_graph_query, parse_graph_query,
 };
 pub use health::*;
+pub use ownership::{
+    AuthorShare, CODEOWNERS_PATHS, CodeOwners, CodeOwnersRule, CommunityOwnership,
+    DEFAULT_OWNERSHIP_LIMIT, DEFAULT_SUGGESTED_REVIEWERS, OwnershipAnalyzer, OwnershipReport,
+    OwnershipRequest, ReviewerSource, SuggestedReviewer, SymbolOwnership,
+};
+pub use profiles::{
+    EXECUTION_WEIGHT_SCALE, HotSymbol, HotSymbolsReport, IngestProfileRequest, IngestedProfile,
+    ParsedProfile, ProfileAnalyzer, ProfileFormat, ProfileFrame, ProfileSample,
+    execution_edge_weights, parse_profile,
+};
 pub use refactor::*;
 pub use sir_quality_signals::{
     SirQualitySignals, blend_normalized_quality, compute_confidence_percentiles,
     compute_sir_quality_signals,
 };
+pub use stack_traces::{
+    DEFAULT_TRACE_COMMIT_LIMIT, DEFAULT_TRACE_MAX_FRAMES, ExplainTraceReport, ExplainTraceRequest,
+    ExplainedFrame, ParsedTrace, StackTraceAnalyzer, SuspectCommit, TraceCommit, TraceFrame,
+    TraceLanguage, TraceSirChange, TraceViolation, parse_stack_trace,
+};
 pub use test_intents::{InferredTestTarget, TestGuard, TestIntentAnalyzer};
+pub use test_results::{
+    DEFAULT_FLAKY_MIN_RUNS, DEFAULT_FLAKY_WINDOW_RUNS, FailureTriage, FlakyTest, FlakyTestsReport,
+    FlakyTestsRequest, IngestTestReportRequest, IngestedTestRun, ParsedTestCase, TestOutcome,
+    TestReportFormat, TestResultsAnalyzer, TriageReport, TriageRequest, TriageSuspect,
+    parse_test_report,
+};
@@ -0,0 +1,620 @@
+//! Code ownership: CODEOWNERS rules plus git blame weighted by recency,
+//! rolled up per symbol and per Louvain community, and the reviewers to ask
+//! about files a change reaches.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use aether_core::GitContext;
+use aether_health::git_signals::{WeightedBlameLine, bus_factor, weighted_blame_lines};
+use aether_store::{SqliteStore, SurrealGraphStore, SymbolRecord, block_on_store_future};
+use serde::{Deserialize, Serialize};
+
+use crate::coupling::{AnalysisError, wildcard_match};
+use crate::frames::FrameResolver;
+
+const OWNERSHIP_SCHEMA_VERSION: &str = "1.0";
+/// Where GitHub looks for a CODEOWNERS file, in order.
+pub const CODEOWNERS_PATHS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+pub const DEFAULT_OWNERSHIP_LIMIT: usize = 20;
+pub const DEFAULT_SUGGESTED_REVIEWERS: usize = 5;
+const AUTHORS_PER_ENTRY: usize = 5;
+/// Affected files blamed when suggesting reviewers, highest scored first.
+const REVIEWER_FILE_LIMIT: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeOwnersRule {
+    pub pattern: String,
+    /// Empty for a rule that removes ownership.
+    pub owners: Vec<String>,
+    /// 1-based line in the CODEOWNERS file.
+    pub line: usize,
+}
+
+/// Parsed CODEOWNERS file; the last rule matching a path decides its owners.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodeOwners {
+    pub source: Option<String>,
+    rules: Vec<CodeOwnersRule>,
+}
+
+impl CodeOwners {
+    pub fn parse(text: &str) -> Self {
+        let rules = text
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    return None;
+                }
+                let mut tokens = line.split_whitespace();
+                let pattern = tokens.next()?;
+                let pattern = pattern.strip_prefix('\\').unwrap_or(pattern);
+                let owners = tokens
+                    .take_while(|token| !token.starts_with('#'))
+                    .map(str::to_owned)
+                    .collect();
+                Some(CodeOwnersRule {
+                    pattern: pattern.to_owned(),
+                    owners,
+                    line: index + 1,
+                })
+            })
+            .collect();
+
+        Self {
+            source: None,
+            rules,
+        }
+    }
+
+    /// The first CODEOWNERS file of [`CODEOWNERS_PATHS`] in the workspace,
+    /// or no rules when there is none.
+    pub fn load(workspace: &Path) -> Result<Self, AnalysisError> {
+        for candidate in CODEOWNERS_PATHS {
+            let path = workspace.join(candidate);
+            if !path.is_file() {
+                continue;
+            }
+            let mut owners = Self::parse(&std::fs::read_to_string(&path)?);
+            owners.source = Some(candidate.to_owned());
+            return Ok(owners);
+        }
+        Ok(Self::default())
+    }
+
+    pub fn rules(&self) -> &[CodeOwnersRule] {
+        &self.rules
+    }
+
+    /// Owners of a workspace-relative path.
+    pub fn owners_of(&self, path: &str) -> &[String] {
+        let path = path.trim_start_matches("./");
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| codeowners_pattern_matches(&rule.pattern, path))
+            .map(|rule| rule.owners.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// gitignore-style matching as GitHub applies it to CODEOWNERS: a pattern
+/// without an inner slash matches at any depth, `**` spans directories, and
+/// a directory or plain name also owns everything beneath it.
+fn codeowners_pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, directory) = match pattern.strip_suffix('/') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+    let anchored = pattern.contains('/');
+    let pattern = pattern.trim_start_matches('/');
+    if pattern.is_empty() {
+        return false;
+    }
+
+    let mut segments = pattern.split('/').collect::<Vec<_>>();
+    if !anchored {
+        segments.insert(0, "**");
+    }
+    // `docs/*` owns the files in docs but not those nested deeper.
+    let owns_contents = directory
+        || !segments
+            .last()
+            .is_some_and(|segment| segment.contains(['*', '?']));
+    let path = path.split('/').collect::<Vec<_>>();
+    segments_match(&segments, &path, directory, owns_contents)
+}
+
+fn segments_match(pattern: &[&str], path: &[&str], directory: bool, owns_contents: bool) -> bool {
+    match pattern.split_first() {
+        None => {
+            if path.is_empty() {
+                !directory
+            } else {
+                owns_contents
+            }
+        }
+        Some((&"**", rest)) => (0..=path.len())
+            .any(|skip| segments_match(rest, &path[skip..], directory, owns_contents)),
+        Some((segment, rest)) => {
+            path.first()
+                .is_some_and(|name| wildcard_match(segment, name))
+                && segments_match(rest, &path[1..], directory, owns_contents)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthorShare {
+    pub author: String,
+    /// Blamed lines last changed by this author.
+    pub lines: usize,
+    /// Those lines weighted by recency.
+    pub weight: f64,
+    /// Fraction of the total weight.
+    pub share: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SymbolOwnership {
+    pub symbol_id: String,
+    pub qualified_name: String,
+    pub file_path: String,
+    pub start_line: Option<usize>,
+    pub end_line: Option<usize>,
+    pub codeowners: Vec<String>,
+    pub authors: Vec<AuthorShare>,
+    pub bus_factor: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommunityOwnership {
+    pub community_id: i64,
+    pub symbol_count: usize,
+    pub file_count: usize,
+    /// CODEOWNERS owners, most symbols owned first.
+    pub codeowners: Vec<String>,
+    pub authors: Vec<AuthorShare>,
+    pub bus_factor: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnershipRequest {
+    /// Report the symbols of this workspace-relative file.
+    pub file: Option<String>,
+    /// Report one symbol, by id or qualified name.
+    pub symbol: Option<String>,
+    /// Communities to report when no target is given, lowest bus factor first.
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnershipReport {
+    pub schema_version: String,
+    pub codeowners_file: Option<String>,
+    pub symbols: Vec<SymbolOwnership>,
+    /// Communities of the reported symbols, or the riskiest communities when
+    /// no target was given.
+    pub communities: Vec<CommunityOwnership>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewerSource {
+    Codeowners,
+    Blame,
+}
+
+impl ReviewerSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Codeowners => "codeowners",
+            Self::Blame => "blame",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuggestedReviewer {
+    pub reviewer: String,
+    /// CODEOWNERS when any affected file names the reviewer there.
+    pub source: ReviewerSource,
+    pub score: f64,
+    /// Affected files the reviewer owns, highest scored first.
+    pub files: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OwnershipAnalyzer {
+    workspace: PathBuf,
+    codeowners: CodeOwners,
+}
+
+impl OwnershipAnalyzer {
+    pub fn new(workspace: impl AsRef<Path>) -> Result<Self, AnalysisError> {
+        let workspace = workspace.as_ref().to_path_buf();
+        let codeowners = CodeOwners::load(&workspace)?;
+        Ok(Self {
+            workspace,
+            codeowners,
+        })
+    }
+
+    pub fn codeowners(&self) -> &CodeOwners {
+        &self.codeowners
+    }
+
+    /// Ownership of the requested file or symbol and of its communities;
+    /// without a target, the communities with the lowest bus factor.
+    /// Communities need the graph store and are skipped without one.
+    pub fn report(
+        &self,
+        store: &SqliteStore,
+        graph: Option<&SurrealGraphStore>,
+        request: &OwnershipRequest,
+    ) -> Result<OwnershipReport, AnalysisError> {
+        let limit = request.limit.unwrap_or(DEFAULT_OWNERSHIP_LIMIT).max(1);
+        let mut notes = Vec::new();
+        let mut blame = BlameCache::new(&self.workspace);
+        if blame.git.is_none() {
+            notes.push(
+                "workspace is not a git repository; ownership comes from CODEOWNERS only"
+                    .to_owned(),
+            );
+        }
+        if self.codeowners.source.is_none() {
+            notes.push(format!(
+                "no CODEOWNERS file found (looked in {})",
+                CODEOWNERS_PATHS.join(", ")
+            ));
+        }
+
+        let records = store.list_all_symbol_records()?;
+        let mut resolver = FrameResolver::new(&self.workspace, records.clone());
+        let targets = match (request.symbol.as_deref(), request.file.as_deref()) {
+            (Some(symbol), _) => records
+                .iter()
+                .enumerate()
+                .filter(|(_, record)| record.id == symbol || record.qualified_name == symbol)
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+            (None, Some(file)) => match resolver.indexed_file(file) {
+                Some(file) => records
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, record)| record.file_path == file)
+                    .map(|(index, _)| index)
+                    .collect(),
+                None => Vec::new(),
+            },
+            (None, None) => Vec::new(),
+        };
+        let has_target = request.symbol.is_some() || request.file.is_some();
+        if has_target && targets.is_empty() {
+            notes.push("no indexed symbol matched the request".to_owned());
+        }
+
+        let mut symbols = Vec::with_capacity(targets.len());
+        for &index in &targets {
+            let record = &records[index];
+            // Without a parsed span the whole file stands in for the symbol.
+            let span = resolver.span(index);
+            let authors = blame.authors(&record.file_path, span.into_iter().collect());
+            symbols.push(SymbolOwnership {
+                symbol_id: record.id.clone(),
+                qualified_name: record.qualified_name.clone(),
+                file_path: record.file_path.clone(),
+                start_line: span.map(|(start, _)| start),
+                end_line: span.map(|(_, end)| end),
+                codeowners: self.codeowners.owners_of(&record.file_path).to_vec(),
+                bus_factor: bus_factor(authors.iter().map(|author| author.weight)),
+                authors: top_authors(authors),
+            });
+        }
+
+        let mut communities = Vec::new();
+        match graph {
+            Some(graph) if !has_target || !targets.is_empty() => {
+                let index_by_id = records
+                    .iter()
+                    .enumerate()
+                    .map(|(index, record)| (record.id.as_str(), index))
+                    .collect::<HashMap<_, _>>();
+                let mut members = BTreeMap::<i64, Vec<usize>>::new();
+                for (symbol_id, community_id) in
+                    block_on_store_future(graph.list_louvain_communities())??
+                {
+                    if let Some(index) = index_by_id.get(symbol_id.as_str()) {
+                        members.entry(community_id).or_default().push(*index);
+                    }
+                }
+                if has_target {
+                    let wanted = targets.iter().copied().collect::<HashSet<_>>();
+                    members.retain(|_, indices| indices.iter().any(|index| wanted.contains(index)));
+                }
+                if members.is_empty() {
+                    notes.push("no communities found; run indexing to build them".to_owned());
+                }
+                for (community_id, indices) in members {
+                    communities.push(self.community_ownership(
+                        community_id,
+                        &indices,
+                        &records,
+                        &mut resolver,
+                        &mut blame,
+                    ));
+                }
+                if !has_target {
+                    communities.sort_by(|left, right| {
+                        bus_factor_rank(left.bus_factor)
+                            .cmp(&bus_factor_rank(right.bus_factor))
+                            .then_with(|| right.symbol_count.cmp(&left.symbol_count))
+                            .then_with(|| left.community_id.cmp(&right.community_id))
+                    });
+                    communities.truncate(limit);
+                }
+            }
+            Some(_) => {}
+            None => notes.push("graph store unavailable; community ownership skipped".to_owned()),
+        }
+
+        Ok(OwnershipReport {
+            schema_version: OWNERSHIP_SCHEMA_VERSION.to_owned(),
+            codeowners_file: self.codeowners.source.clone(),
+            symbols,
+            communities,
+            notes,
+        })
+    }
+
+    /// Owners of the files a change reaches, each file weighted by `score`.
+    /// CODEOWNERS owners carry a file's full score, blame authors their
+    /// share of it.
+    pub fn suggest_reviewers(
+        &self,
+        files: &[(String, f64)],
+        limit: usize,
+    ) -> Vec<SuggestedReviewer> {
+        let mut ranked = files.to_vec();
+        ranked.sort_by(|left, right| {
+            right
+                .1
+                .total_cmp(&left.1)
+                .then_with(|| left.0.cmp(&right.0))
+        });
+        let mut seen = HashSet::new();
+        ranked.retain(|(file, _)| seen.insert(file.clone()));
+        ranked.truncate(REVIEWER_FILE_LIMIT);
+
+        let mut blame = BlameCache::new(&self.workspace);
+        let mut reviewers = BTreeMap::<String, SuggestedReviewer>::new();
+        let mut credit = |reviewer: &str, source: ReviewerSource, score: f64, file: &str| {
+            let entry = reviewers
+                .entry(reviewer.to_owned())
+                .or_insert_with(|| SuggestedReviewer {
+                    reviewer: reviewer.to_owned(),
+                    source,
+                    score: 0.0,
+                    files: Vec::new(),
+                });
+            if source == ReviewerSource::Codeowners {
+                entry.source = source;
+            }
+            entry.score += score;
+            if !entry.files.iter().any(|known| known == file) {
+                entry.files.push(file.to_owned());
+            }
+        };
+        for (file, score) in &ranked {
+            for owner in self.codeowners.owners_of(file) {
+                credit(
+                    owner.as_str(),
+                    ReviewerSource::Codeowners,
+                    *score,
+                    file.as_str(),
+                );
+            }
+            for author in blame.authors(file, Vec::new()) {
+                credit(
+                    author.author.as_str(),
+                    ReviewerSource::Blame,
+                    score * author.share,
+                    file.as_str(),
+                );
+            }
+        }
+
+        let mut reviewers = reviewers.into_values().collect::<Vec<_>>();
+        reviewers.sort_by(|left, right| {
+            right
+                .score
+                .total_cmp(&left.score)
+                .then_with(|| left.reviewer.cmp(&right.reviewer))
+        });
+        reviewers.truncate(limit);
+        reviewers
+    }
+
+    fn community_ownership(
+        &self,
+        community_id: i64,
+        indices: &[usize],
+        records: &[SymbolRecord],
+        resolver: &mut FrameResolver<'_>,
+        blame: &mut BlameCache,
+    ) -> CommunityOwnership {
+        let mut spans_by_file = BTreeMap::<&str, Vec<(usize, usize)>>::new();
+        let mut codeowner_symbols = HashMap::<&str, usize>::new();
+        for &index in indices {
+            let record = &records[index];
+            let spans = spans_by_file.entry(record.file_path.as_str()).or_default();
+            if let Some(span) = resolver.span(index) {
+                spans.push(span);
+            }
+            for owner in self.codeowners.owners_of(&record.file_path) {
+                *codeowner_symbols.entry(owner.as_str()).or_default() += 1;
+            }
+        }
+
+        let mut merged = BTreeMap::<String, (usize, f64)>::new();
+        for (file, spans) in &spans_by_file {
+            // A symbol whose span could not be parsed contributes no lines
+            // rather than its whole file.
+            if spans.is_empty() {
+                continue;
+            }
+            for author in blame.authors(file, spans.clone()) {
+                let entry = merged.entry(author.author).or_default();
+                entry.0 += author.lines;
+                entry.1 += author.weight;
+            }
+        }
+        let authors = author_shares(merged);
+        let mut codeowners = codeowner_symbols.into_iter().collect::<Vec<_>>();
+        codeowners.sort_by(|left, right| right.1.cmp(&left.1).then_with(|| left.0.cmp(right.0)));
+
+        CommunityOwnership {
+            community_id,
+            symbol_count: indices.len(),
+            file_count: spans_by_file.len(),
+            codeowners: codeowners
+                .into_iter()
+                .map(|(owner, _)| owner.to_owned())
+                .collect(),
+            bus_factor: bus_factor(authors.iter().map(|author| author.weight)),
+            authors: top_authors(authors),
+        }
+    }
+}
+
+/// Unknown (0) sorts after every real bus factor.
+fn bus_factor_rank(bus_factor: usize) -> usize {
+    if bus_factor == 0 {
+        usize::MAX
+    } else {
+        bus_factor
+    }
+}
+
+fn top_authors(mut authors: Vec<AuthorShare>) -> Vec<AuthorShare> {
+    authors.truncate(AUTHORS_PER_ENTRY);
+    authors
+}
+
+fn author_shares(by_author: BTreeMap<String, (usize, f64)>) -> Vec<AuthorShare> {
+    let total = by_author.values().map(|(_, weight)| weight).sum::<f64>();
+    let mut authors = by_author
+        .into_iter()
+        .map(|(author, (lines, weight))| AuthorShare {
+            author,
+            lines,
+            weight,
+            share: if total > 0.0 { weight / total } else { 0.0 },
+        })
+        .collect::<Vec<_>>();
+    authors.sort_by(|left, right| {
+        right
+            .weight
+            .total_cmp(&left.weight)
+            .then_with(|| left.author.cmp(&right.author))
+    });
+    authors
+}
+
+/// Weighted blame per file, computed once per report.
+struct BlameCache {
+    workspace: PathBuf,
+    git: Option<GitContext>,
+    files: HashMap<String, Vec<WeightedBlameLine>>,
+}
+
+impl BlameCache {
+    fn new(workspace: &Path) -> Self {
+        Self {
+            workspace: workspace.to_path_buf(),
+            git: GitContext::open(workspace),
+            files: HashMap::new(),
+        }
+    }
+
+    /// Authors of the lines of `file` inside `spans` (1-based, inclusive),
+    /// or of the whole file when `spans` is empty; uncommitted lines have no
+    /// author and are left out.
+    fn authors(&mut self, file: &str, spans: Vec<(usize, usize)>) -> Vec<AuthorShare> {
+        let Some(git) = self.git.as_ref() else {
+            return Vec::new();
+        };
+        let lines = self
+            .files
+            .entry(file.to_owned())
+            .or_insert_with(|| weighted_blame_lines(git, &self.workspace.join(file)));
+
+        let mut by_author = BTreeMap::<String, (usize, f64)>::new();
+        for line in lines.iter() {
+            let number = line.line_number as usize;
+            if line.author.is_empty()
+                || (!spans.is_empty()
+                    && !spans
+                        .iter()
+                        .any(|(start, end)| *start <= number && number <= *end))
+            {
+                continue;
+            }
+            let entry = by_author.entry(line.author.clone()).or_default();
+            entry.0 += 1;
+            entry.1 += line.weight;
+        }
+        author_shares(by_author)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeOwners, author_shares};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn codeowners_last_matching_rule_wins() {
+        let owners = CodeOwners::parse(
+            "# Default owners\n\
+             *        @org/core\n\
+             *.md     @docs-team  # prose\n\
+             /crates/aether-store/   @org/storage @dana\n\
+             docs/*   @writers\n\
+             apps/    @org/apps\n\
+             /crates/aether-store/src/generated.rs\n",
+        );
+
+        assert_eq!(owners.owners_of("src/main.rs"), ["@org/core"]);
+        assert_eq!(owners.owners_of("README.md"), ["@docs-team"]);
+        assert_eq!(
+            owners.owners_of("crates/aether-store/src/lib.rs"),
+            ["@org/storage", "@dana"]
+        );
+        assert!(
+            owners
+                .owners_of("crates/aether-store/src/generated.rs")
+                .is_empty()
+        );
+        assert_eq!(owners.owners_of("docs/guide.txt"), ["@writers"]);
+        assert_eq!(owners.owners_of("docs/deep/guide.txt"), ["@org/core"]);
+        assert_eq!(owners.owners_of("web/apps/index.ts"), ["@org/apps"]);
+        assert_eq!(owners.rules()[2].line, 4);
+    }
+
+    #[test]
+    fn author_shares_sort_by_recency_weight() {
+        let shares = author_shares(BTreeMap::from([
+            ("ana".to_owned(), (30, 3.0)),
+            ("bo".to_owned(), (10, 9.0)),
+        ]));
+
+        assert_eq!(shares[0].author, "bo");
+        assert_eq!(shares[0].lines, 10);
+        assert!((shares[0].share - 0.75).abs() < 1e-9);
+        assert_eq!(shares[1].author, "ana");
+    }
+}
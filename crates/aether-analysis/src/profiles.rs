@@ -0,0 +1,1017 @@
+//! Runtime profile ingestion: perf/inferno collapsed stacks, pprof protobuf
+//! and speedscope JSON mapped onto indexed symbols as per-symbol and
+//! per-edge sample counts, and the edge weights derived from them for
+//! execution-weighted centrality.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aether_core::{content_hash, normalize_path};
+use aether_store::{EdgeSampleCount, ProfileRecord, SqliteStore, SymbolSampleCount};
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::coupling::AnalysisError;
+use crate::frames::FrameResolver;
+use crate::graph_algorithms::EdgeWeights;
+
+/// The hottest sampled edge weighs `1.0 + EXECUTION_WEIGHT_SCALE`; edges
+/// never sampled keep their static weight of 1.0.
+pub const EXECUTION_WEIGHT_SCALE: f64 = 9.0;
+const PROFILES_SCHEMA_VERSION: &str = "1.0";
+const HOTTEST_LIMIT: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileFormat {
+    /// `frame;frame;frame count` lines, as written by inferno's and
+    /// FlameGraph's `stackcollapse-*` scripts or `py-spy --format raw`.
+    Collapsed,
+    /// pprof protobuf, plain or gzipped.
+    Pprof,
+    /// speedscope JSON, as written by `py-spy --format speedscope`.
+    Speedscope,
+}
+
+impl ProfileFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Collapsed => "collapsed",
+            Self::Pprof => "pprof",
+            Self::Speedscope => "speedscope",
+        }
+    }
+
+    /// Guesses the format from the profile content.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            return Some(Self::Pprof);
+        }
+        let Ok(content) = std::str::from_utf8(bytes) else {
+            return Some(Self::Pprof);
+        };
+        let trimmed = content.trim_start();
+        if trimmed.starts_with('{') {
+            return serde_json::from_str::<Value>(trimmed)
+                .ok()
+                .filter(|profile| {
+                    profile.get("shared").is_some() && profile.get("profiles").is_some()
+                })
+                .map(|_| Self::Speedscope);
+        }
+        let mut lines = trimmed
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .peekable();
+        lines.peek()?;
+        lines
+            .all(|line| collapsed_line(line).is_some())
+            .then_some(Self::Collapsed)
+    }
+}
+
+impl FromStr for ProfileFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "collapsed" | "folded" | "inferno" | "perf" => Ok(Self::Collapsed),
+            "pprof" | "pb" => Ok(Self::Pprof),
+            "speedscope" | "py-spy" => Ok(Self::Speedscope),
+            other => Err(format!(
+                "invalid profile format '{other}', expected one of: collapsed, pprof, speedscope"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ProfileFrame {
+    pub name: String,
+    pub file: Option<String>,
+    pub line: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileSample {
+    /// Indexes into [`ParsedProfile::frames`], outermost frame first.
+    pub stack: Vec<usize>,
+    pub count: u64,
+}
+
+/// A profile as read from disk, before frames are mapped to symbols.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedProfile {
+    pub frames: Vec<ProfileFrame>,
+    pub samples: Vec<ProfileSample>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngestProfileRequest {
+    pub path: PathBuf,
+    /// `None` detects the format from the content.
+    pub format: Option<ProfileFormat>,
+    /// Drop previously ingested profiles instead of adding to them.
+    pub replace: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HotSymbol {
+    pub symbol_id: String,
+    pub symbol_name: String,
+    pub file: String,
+    pub self_samples: i64,
+    pub total_samples: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IngestedProfile {
+    pub schema_version: String,
+    pub profile: ProfileRecord,
+    pub frames: usize,
+    pub frames_mapped: usize,
+    pub symbols_sampled: usize,
+    pub edges_sampled: usize,
+    pub hottest: Vec<HotSymbol>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HotSymbolsReport {
+    pub schema_version: String,
+    pub profiles: Vec<ProfileRecord>,
+    /// Symbols by inclusive samples over all ingested profiles.
+    pub symbols: Vec<HotSymbol>,
+}
+
+pub struct ProfileAnalyzer {
+    workspace: PathBuf,
+}
+
+impl ProfileAnalyzer {
+    pub fn new(workspace: impl AsRef<Path>) -> Result<Self, AnalysisError> {
+        Ok(Self {
+            workspace: workspace.as_ref().to_path_buf(),
+        })
+    }
+
+    /// Parses a profile, maps its frames to symbols and stores the sample
+    /// counts. Re-ingesting the same profile replaces the earlier copy.
+    pub fn ingest(
+        &self,
+        store: &SqliteStore,
+        request: &IngestProfileRequest,
+    ) -> Result<IngestedProfile, AnalysisError> {
+        let bytes = std::fs::read(&request.path)?;
+        let format = match request.format {
+            Some(format) => format,
+            None => ProfileFormat::detect(&bytes).ok_or_else(|| {
+                AnalysisError::Message(format!(
+                    "could not detect the format of {}; pass it explicitly",
+                    request.path.display()
+                ))
+            })?,
+        };
+        let profile = parse_profile(format, &bytes)?;
+
+        let mut notes = Vec::new();
+        let records = store.list_all_symbol_records()?;
+        if records.is_empty() {
+            notes.push("no symbols are indexed; no frames could be mapped".to_owned());
+        }
+        let mut resolver = FrameResolver::new(&self.workspace, records);
+        let frame_symbols = profile
+            .frames
+            .iter()
+            .map(|frame| {
+                resolver
+                    .resolve(&frame.name, frame.file.as_deref(), frame.line)
+                    .map(|index| resolver.record(index).id.clone())
+            })
+            .collect::<Vec<_>>();
+        let frames_mapped = frame_symbols.iter().filter(|id| id.is_some()).count();
+        let counts = count_samples(&profile.samples, &frame_symbols);
+        if counts.total > 0 && counts.mapped == 0 {
+            notes.push(
+                "no sampled frame matched an indexed symbol; check that the profile has symbols \
+                 and was taken from this workspace"
+                    .to_owned(),
+            );
+        }
+
+        let workspace = normalize_path(&self.workspace.to_string_lossy());
+        let record = ProfileRecord {
+            profile_id: content_hash(&format!(
+                "{}\n{}",
+                format.as_str(),
+                String::from_utf8_lossy(&bytes)
+            )),
+            format: format.as_str().to_owned(),
+            source_path: relative_path(&request.path.to_string_lossy(), &workspace),
+            recorded_at: now_secs(),
+            total_samples: counts.total,
+            mapped_samples: counts.mapped,
+        };
+        if request.replace {
+            store.clear_profiles()?;
+        }
+        store.record_profile(&record, &counts.symbols, &counts.edges)?;
+
+        let mut hottest = counts.symbols.clone();
+        hottest.truncate(HOTTEST_LIMIT);
+        Ok(IngestedProfile {
+            schema_version: PROFILES_SCHEMA_VERSION.to_owned(),
+            profile: record,
+            frames: profile.frames.len(),
+            frames_mapped,
+            symbols_sampled: counts.symbols.len(),
+            edges_sampled: counts.edges.len(),
+            hottest: hot_symbols(store, &hottest)?,
+            notes,
+        })
+    }
+
+    /// Symbols by inclusive samples over every ingested profile.
+    pub fn hot_symbols(
+        &self,
+        store: &SqliteStore,
+        limit: usize,
+    ) -> Result<HotSymbolsReport, AnalysisError> {
+        let mut counts = store.list_symbol_sample_counts()?;
+        counts.truncate(limit.max(1));
+        Ok(HotSymbolsReport {
+            schema_version: PROFILES_SCHEMA_VERSION.to_owned(),
+            profiles: store.list_profiles(u32::MAX)?,
+            symbols: hot_symbols(store, &counts)?,
+        })
+    }
+}
+
+/// Edge weights for execution-weighted centrality, scaled linearly from
+/// 1.0 for an unsampled edge to `1.0 + EXECUTION_WEIGHT_SCALE` for the
+/// hottest one. Empty when no profile has been ingested.
+pub fn execution_edge_weights(counts: &[EdgeSampleCount]) -> EdgeWeights {
+    let max = counts.iter().map(|count| count.samples).max().unwrap_or(0);
+    if max <= 0 {
+        return EdgeWeights::new();
+    }
+    counts
+        .iter()
+        .filter(|count| count.samples > 0)
+        .map(|count| {
+            (
+                (count.caller_id.clone(), count.callee_id.clone()),
+                1.0 + EXECUTION_WEIGHT_SCALE * count.samples as f64 / max as f64,
+            )
+        })
+        .collect()
+}
+
+pub fn parse_profile(format: ProfileFormat, bytes: &[u8]) -> Result<ParsedProfile, AnalysisError> {
+    match format {
+        ProfileFormat::Collapsed => {
+            let content = std::str::from_utf8(bytes).map_err(|err| {
+                AnalysisError::Message(format!("collapsed stacks are not UTF-8: {err}"))
+            })?;
+            Ok(parse_collapsed(content))
+        }
+        ProfileFormat::Pprof => parse_pprof(bytes),
+        ProfileFormat::Speedscope => parse_speedscope(bytes),
+    }
+}
+
+#[derive(Debug, Default)]
+struct ProfileBuilder {
+    profile: ParsedProfile,
+    index: HashMap<ProfileFrame, usize>,
+}
+
+impl ProfileBuilder {
+    fn frame(&mut self, frame: ProfileFrame) -> usize {
+        if let Some(index) = self.index.get(&frame) {
+            return *index;
+        }
+        let index = self.profile.frames.len();
+        self.profile.frames.push(frame.clone());
+        self.index.insert(frame, index);
+        index
+    }
+
+    fn sample(&mut self, stack: Vec<usize>, count: u64) {
+        if !stack.is_empty() && count > 0 {
+            self.profile.samples.push(ProfileSample { stack, count });
+        }
+    }
+}
+
+fn parse_collapsed(content: &str) -> ParsedProfile {
+    let mut builder = ProfileBuilder::default();
+    for line in content.lines().map(str::trim) {
+        let Some((stack, count)) = collapsed_line(line) else {
+            continue;
+        };
+        let stack = stack
+            .split(';')
+            .filter(|frame| !frame.trim().is_empty())
+            .map(|frame| builder.frame(collapsed_frame(frame)))
+            .collect();
+        builder.sample(stack, count);
+    }
+    builder.profile
+}
+
+fn collapsed_line(line: &str) -> Option<(&str, u64)> {
+    let (stack, count) = line.rsplit_once(char::is_whitespace)?;
+    Some((stack.trim_end(), count.parse().ok()?))
+}
+
+/// `name (path/to/file.py:42)`, as py-spy writes frames, or a bare name.
+fn collapsed_frame(frame: &str) -> ProfileFrame {
+    let frame = frame.trim();
+    let located = frame
+        .strip_suffix(')')
+        .and_then(|rest| rest.rsplit_once(" ("))
+        .filter(|(_, location)| location.contains('.'));
+    let Some((name, location)) = located else {
+        return ProfileFrame {
+            name: frame.to_owned(),
+            file: None,
+            line: None,
+        };
+    };
+    let (file, line) = match location.rsplit_once(':') {
+        Some((file, line)) if line.parse::<usize>().is_ok() => (file, line.parse().ok()),
+        _ => (location, None),
+    };
+    ProfileFrame {
+        name: name.trim().to_owned(),
+        file: Some(file.to_owned()),
+        line,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SpeedscopeFile {
+    shared: SpeedscopeShared,
+    #[serde(default)]
+    profiles: Vec<SpeedscopeProfile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpeedscopeShared {
+    frames: Vec<SpeedscopeFrame>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpeedscopeFrame {
+    name: String,
+    #[serde(default)]
+    file: Option<String>,
+    #[serde(default)]
+    line: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum SpeedscopeProfile {
+    Sampled {
+        #[serde(default)]
+        unit: Option<String>,
+        samples: Vec<Vec<usize>>,
+        weights: Vec<f64>,
+    },
+    Evented {
+        #[serde(default)]
+        unit: Option<String>,
+        events: Vec<SpeedscopeEvent>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct SpeedscopeEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    frame: usize,
+    at: f64,
+}
+
+fn parse_speedscope(bytes: &[u8]) -> Result<ParsedProfile, AnalysisError> {
+    let file = serde_json::from_slice::<SpeedscopeFile>(bytes)
+        .map_err(|err| AnalysisError::Message(format!("invalid speedscope profile: {err}")))?;
+    let frames = file
+        .shared
+        .frames
+        .into_iter()
+        .map(|frame| ProfileFrame {
+            name: frame.name,
+            file: frame.file.filter(|file| !file.trim().is_empty()),
+            line: frame.line.filter(|line| *line > 0),
+        })
+        .collect::<Vec<_>>();
+    let valid = |stack: &[usize]| stack.iter().all(|frame| *frame < frames.len());
+
+    let mut samples = Vec::new();
+    for profile in file.profiles {
+        match profile {
+            SpeedscopeProfile::Sampled {
+                unit,
+                samples: stacks,
+                weights,
+            } => {
+                let scale = unit_scale(unit.as_deref());
+                for (stack, weight) in stacks.into_iter().zip(weights) {
+                    let count = (weight * scale).round().max(0.0) as u64;
+                    if valid(&stack) && count > 0 {
+                        samples.push(ProfileSample { stack, count });
+                    }
+                }
+            }
+            SpeedscopeProfile::Evented { unit, events } => {
+                // Each interval between events is charged to the stack open
+                // during it.
+                let scale = unit_scale(unit.as_deref());
+                let mut open = Vec::<usize>::new();
+                let mut last_at = None::<f64>;
+                for event in events {
+                    if let Some(last_at) = last_at {
+                        let count = ((event.at - last_at) * scale).round().max(0.0) as u64;
+                        if !open.is_empty() && count > 0 && valid(&open) {
+                            samples.push(ProfileSample {
+                                stack: open.clone(),
+                                count,
+                            });
+                        }
+                    }
+                    last_at = Some(event.at);
+                    match event.kind.as_str() {
+                        "O" => open.push(event.frame),
+                        "C" => {
+                            if let Some(position) =
+                                open.iter().rposition(|frame| *frame == event.frame)
+                            {
+                                open.truncate(position);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    Ok(ParsedProfile { frames, samples })
+}
+
+/// Time-valued weights are counted in microseconds; anything else as-is.
+fn unit_scale(unit: Option<&str>) -> f64 {
+    match unit.unwrap_or("none") {
+        "nanoseconds" => 0.001,
+        "milliseconds" => 1_000.0,
+        "seconds" => 1_000_000.0,
+        _ => 1.0,
+    }
+}
+
+fn parse_pprof(bytes: &[u8]) -> Result<ParsedProfile, AnalysisError> {
+    let mut decoded = Vec::new();
+    let bytes = if bytes.starts_with(&[0x1f, 0x8b]) {
+        GzDecoder::new(bytes)
+            .read_to_end(&mut decoded)
+            .map_err(|err| AnalysisError::Message(format!("invalid gzipped pprof: {err}")))?;
+        decoded.as_slice()
+    } else {
+        bytes
+    };
+
+    let mut sample_types = Vec::<i64>::new();
+    let mut default_sample_type = 0i64;
+    let mut raw_samples = Vec::<(Vec<u64>, Vec<i64>)>::new();
+    let mut locations = HashMap::<u64, Vec<(u64, i64)>>::new();
+    let mut functions = HashMap::<u64, (i64, i64)>::new();
+    let mut strings = Vec::<String>::new();
+
+    let mut profile = ProtoReader::new(bytes);
+    while let Some((field, value)) = profile.next_field()? {
+        match (field, value) {
+            (1, ProtoValue::Bytes(value_type)) => {
+                let mut reader = ProtoReader::new(value_type);
+                let mut kind = 0;
+                while let Some((field, value)) = reader.next_field()? {
+                    if let (1, ProtoValue::Varint(value)) = (field, value) {
+                        kind = value as i64;
+                    }
+                }
+                sample_types.push(kind);
+            }
+            (2, ProtoValue::Bytes(sample)) => {
+                let mut reader = ProtoReader::new(sample);
+                let mut location_ids = Vec::new();
+                let mut values = Vec::new();
+                while let Some((field, value)) = reader.next_field()? {
+                    match field {
+                        1 => location_ids.extend(value.varints()?),
+                        2 => values.extend(value.varints()?.into_iter().map(|value| value as i64)),
+                        _ => {}
+                    }
+                }
+                raw_samples.push((location_ids, values));
+            }
+            (4, ProtoValue::Bytes(location)) => {
+                let mut reader = ProtoReader::new(location);
+                let mut id = 0;
+                let mut lines = Vec::new();
+                while let Some((field, value)) = reader.next_field()? {
+                    match (field, value) {
+                        (1, ProtoValue::Varint(value)) => id = value,
+                        (4, ProtoValue::Bytes(line)) => {
+                            let mut reader = ProtoReader::new(line);
+                            let (mut function_id, mut line_number) = (0, 0);
+                            while let Some((field, value)) = reader.next_field()? {
+                                match (field, value) {
+                                    (1, ProtoValue::Varint(value)) => function_id = value,
+                                    (2, ProtoValue::Varint(value)) => line_number = value as i64,
+                                    _ => {}
+                                }
+                            }
+                            lines.push((function_id, line_number));
+                        }
+                        _ => {}
+                    }
+                }
+                locations.insert(id, lines);
+            }
+            (5, ProtoValue::Bytes(function)) => {
+                let mut reader = ProtoReader::new(function);
+                let (mut id, mut name, mut system_name, mut filename) = (0, 0, 0, 0);
+                while let Some((field, value)) = reader.next_field()? {
+                    if let ProtoValue::Varint(value) = value {
+                        match field {
+                            1 => id = value,
+                            2 => name = value as i64,
+                            3 => system_name = value as i64,
+                            4 => filename = value as i64,
+                            _ => {}
+                        }
+                    }
+                }
+                let name = if name > 0 { name } else { system_name };
+                functions.insert(id, (name, filename));
+            }
+            (6, ProtoValue::Bytes(string)) => {
+                strings.push(String::from_utf8_lossy(string).into_owned());
+            }
+            (14, ProtoValue::Varint(value)) => default_sample_type = value as i64,
+            _ => {}
+        }
+    }
+
+    let string = |index: i64| {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| strings.get(index))
+            .filter(|value| !value.is_empty())
+    };
+    // CPU profiles carry both a sample count and a duration; prefer the
+    // count, then the declared default.
+    let value_index = sample_types
+        .iter()
+        .position(|kind| string(*kind).is_some_and(|kind| kind == "samples"))
+        .or_else(|| {
+            sample_types
+                .iter()
+                .position(|kind| default_sample_type > 0 && *kind == default_sample_type)
+        })
+        .unwrap_or(0);
+
+    let mut builder = ProfileBuilder::default();
+    for (location_ids, values) in raw_samples {
+        let count = values.get(value_index).copied().unwrap_or(0).max(0) as u64;
+        // Locations are leaf first, and so are the inlined lines within one.
+        let mut stack = Vec::new();
+        for location_id in location_ids {
+            for (function_id, line) in locations.get(&location_id).into_iter().flatten() {
+                let Some((name, filename)) = functions.get(function_id) else {
+                    continue;
+                };
+                let Some(name) = string(*name) else {
+                    continue;
+                };
+                stack.push(builder.frame(ProfileFrame {
+                    name: name.clone(),
+                    file: string(*filename).cloned(),
+                    line: usize::try_from(*line).ok().filter(|line| *line > 0),
+                }));
+            }
+        }
+        stack.reverse();
+        builder.sample(stack, count);
+    }
+    Ok(builder.profile)
+}
+
+/// Just enough protobuf wire-format decoding to read pprof.
+struct ProtoReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ProtoValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed,
+}
+
+impl ProtoValue<'_> {
+    /// A repeated integer field, packed or not.
+    fn varints(self) -> Result<Vec<u64>, AnalysisError> {
+        match self {
+            Self::Varint(value) => Ok(vec![value]),
+            Self::Bytes(bytes) => {
+                let mut reader = ProtoReader::new(bytes);
+                let mut values = Vec::new();
+                while reader.position < bytes.len() {
+                    values.push(reader.varint()?);
+                }
+                Ok(values)
+            }
+            Self::Fixed => Ok(Vec::new()),
+        }
+    }
+}
+
+impl<'a> ProtoReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn next_field(&mut self) -> Result<Option<(u64, ProtoValue<'a>)>, AnalysisError> {
+        if self.position >= self.bytes.len() {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let value = match key & 0x7 {
+            0 => ProtoValue::Varint(self.varint()?),
+            1 => {
+                self.take(8)?;
+                ProtoValue::Fixed
+            }
+            2 => {
+                let length = usize::try_from(self.varint()?).map_err(|_| truncated())?;
+                ProtoValue::Bytes(self.take(length)?)
+            }
+            5 => {
+                self.take(4)?;
+                ProtoValue::Fixed
+            }
+            wire_type => {
+                return Err(AnalysisError::Message(format!(
+                    "invalid pprof profile: unsupported wire type {wire_type}"
+                )));
+            }
+        };
+        Ok(Some((key >> 3, value)))
+    }
+
+    fn varint(&mut self) -> Result<u64, AnalysisError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.bytes.get(self.position).ok_or_else(truncated)?;
+            self.position += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(AnalysisError::Message(
+            "invalid pprof profile: varint too long".to_owned(),
+        ))
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], AnalysisError> {
+        let end = self.position.checked_add(length).ok_or_else(truncated)?;
+        let bytes = self.bytes.get(self.position..end).ok_or_else(truncated)?;
+        self.position = end;
+        Ok(bytes)
+    }
+}
+
+fn truncated() -> AnalysisError {
+    AnalysisError::Message("invalid pprof profile: truncated message".to_owned())
+}
+
+#[derive(Debug, Default)]
+struct SampleCounts {
+    total: i64,
+    mapped: i64,
+    /// Hottest first.
+    symbols: Vec<SymbolSampleCount>,
+    edges: Vec<EdgeSampleCount>,
+}
+
+/// Unmapped frames (std, runtime, native libraries) are skipped, so a call
+/// through them links the nearest mapped caller and callee. Recursion
+/// counts a symbol or edge once per sample.
+fn count_samples(samples: &[ProfileSample], frame_symbols: &[Option<String>]) -> SampleCounts {
+    let mut counts = SampleCounts::default();
+    let mut by_symbol = HashMap::<&str, (i64, i64)>::new();
+    let mut by_edge = BTreeMap::<(&str, &str), i64>::new();
+
+    for sample in samples {
+        let count = i64::try_from(sample.count).unwrap_or(i64::MAX);
+        counts.total = counts.total.saturating_add(count);
+        let mut stack = sample
+            .stack
+            .iter()
+            .filter_map(|frame| frame_symbols.get(*frame)?.as_deref())
+            .collect::<Vec<_>>();
+        stack.dedup();
+        let Some(&innermost) = stack.last() else {
+            continue;
+        };
+        counts.mapped = counts.mapped.saturating_add(count);
+        by_symbol.entry(innermost).or_default().0 += count;
+        for symbol_id in stack.iter().copied().collect::<BTreeSet<_>>() {
+            by_symbol.entry(symbol_id).or_default().1 += count;
+        }
+        let edges = stack
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .filter(|(caller, callee)| caller != callee)
+            .collect::<BTreeSet<_>>();
+        for edge in edges {
+            *by_edge.entry(edge).or_default() += count;
+        }
+    }
+
+    counts.symbols = by_symbol
+        .into_iter()
+        .map(
+            |(symbol_id, (self_samples, total_samples))| SymbolSampleCount {
+                symbol_id: symbol_id.to_owned(),
+                self_samples,
+                total_samples,
+            },
+        )
+        .collect();
+    counts.symbols.sort_by(|left, right| {
+        right
+            .total_samples
+            .cmp(&left.total_samples)
+            .then_with(|| right.self_samples.cmp(&left.self_samples))
+            .then_with(|| left.symbol_id.cmp(&right.symbol_id))
+    });
+    counts.edges = by_edge
+        .into_iter()
+        .map(|((caller_id, callee_id), samples)| EdgeSampleCount {
+            caller_id: caller_id.to_owned(),
+            callee_id: callee_id.to_owned(),
+            samples,
+        })
+        .collect();
+    counts
+}
+
+fn hot_symbols(
+    store: &SqliteStore,
+    counts: &[SymbolSampleCount],
+) -> Result<Vec<HotSymbol>, AnalysisError> {
+    let ids = counts
+        .iter()
+        .map(|count| count.symbol_id.clone())
+        .collect::<Vec<_>>();
+    let rows = store.get_symbol_search_results_batch(&ids)?;
+    Ok(counts
+        .iter()
+        .map(|count| {
+            let row = rows.get(&count.symbol_id);
+            HotSymbol {
+                symbol_id: count.symbol_id.clone(),
+                symbol_name: row
+                    .map(|row| row.qualified_name.clone())
+                    .unwrap_or_else(|| count.symbol_id.clone()),
+                file: row.map(|row| row.file_path.clone()).unwrap_or_default(),
+                self_samples: count.self_samples,
+                total_samples: count.total_samples,
+            }
+        })
+        .collect())
+}
+
+fn relative_path(path: &str, workspace: &str) -> String {
+    let path = normalize_path(path.trim());
+    let relative = path
+        .strip_prefix(workspace)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .unwrap_or(path.as_str());
+    relative.trim_start_matches("./").to_owned()
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|value| value.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame_names(profile: &ParsedProfile, sample: usize) -> Vec<&str> {
+        profile.samples[sample]
+            .stack
+            .iter()
+            .map(|frame| profile.frames[*frame].name.as_str())
+            .collect()
+    }
+
+    fn varint(mut value: u64, out: &mut Vec<u8>) {
+        while value >= 0x80 {
+            out.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    fn bytes_field(field: u64, bytes: &[u8], out: &mut Vec<u8>) {
+        varint((field << 3) | 2, out);
+        varint(bytes.len() as u64, out);
+        out.extend_from_slice(bytes);
+    }
+
+    fn varint_field(field: u64, value: u64, out: &mut Vec<u8>) {
+        varint(field << 3, out);
+        varint(value, out);
+    }
+
+    #[test]
+    fn parses_collapsed_stacks_with_py_spy_locations() {
+        let content = "main;app::serve;app::handle 7\n\
+                       run (app/server.py:10);render (app/views.py:42) 3\n\
+                       not a stack\n";
+        assert_eq!(
+            ProfileFormat::detect(b"main;app::serve 7\n"),
+            Some(ProfileFormat::Collapsed)
+        );
+
+        let profile = parse_collapsed(content);
+        assert_eq!(profile.samples.len(), 2);
+        assert_eq!(
+            frame_names(&profile, 0),
+            vec!["main", "app::serve", "app::handle"]
+        );
+        assert_eq!(profile.samples[0].count, 7);
+        let render = &profile.frames[*profile.samples[1].stack.last().unwrap()];
+        assert_eq!(render.file.as_deref(), Some("app/views.py"));
+        assert_eq!(render.line, Some(42));
+    }
+
+    #[test]
+    fn parses_speedscope_sampled_and_evented_profiles() {
+        let content = r#"{
+            "$schema": "https://www.speedscope.app/file-format-schema.json",
+            "shared": {"frames": [
+                {"name": "main", "file": "app/main.py", "line": 1},
+                {"name": "work", "file": "app/work.py", "line": 5}
+            ]},
+            "profiles": [
+                {"type": "sampled", "unit": "none", "samples": [[0, 1], [0]], "weights": [4, 1]},
+                {"type": "evented", "unit": "milliseconds", "startValue": 0, "endValue": 3,
+                 "events": [
+                    {"type": "O", "frame": 0, "at": 0},
+                    {"type": "O", "frame": 1, "at": 1},
+                    {"type": "C", "frame": 1, "at": 3},
+                    {"type": "C", "frame": 0, "at": 3}
+                 ]}
+            ]
+        }"#;
+        assert_eq!(
+            ProfileFormat::detect(content.as_bytes()),
+            Some(ProfileFormat::Speedscope)
+        );
+
+        let profile = parse_speedscope(content.as_bytes()).expect("parse speedscope");
+        let samples = profile
+            .samples
+            .iter()
+            .map(|sample| (sample.stack.clone(), sample.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            samples,
+            vec![
+                (vec![0, 1], 4),
+                (vec![0], 1),
+                (vec![0], 1_000),
+                (vec![0, 1], 2_000),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_pprof_stacks_leaf_last() {
+        let mut out = Vec::new();
+        let strings = ["", "samples", "count", "main", "handle", "src/main.rs"];
+
+        let mut value_type = Vec::new();
+        varint_field(1, 1, &mut value_type);
+        varint_field(2, 2, &mut value_type);
+        bytes_field(1, &value_type, &mut out);
+
+        // Packed location ids, leaf first.
+        let mut sample = Vec::new();
+        let mut location_ids = Vec::new();
+        varint(2, &mut location_ids);
+        varint(1, &mut location_ids);
+        bytes_field(1, &location_ids, &mut sample);
+        varint_field(2, 5, &mut sample);
+        bytes_field(2, &sample, &mut out);
+
+        for (id, function_id, line) in [(1, 1, 3), (2, 2, 12)] {
+            let mut line_message = Vec::new();
+            varint_field(1, function_id, &mut line_message);
+            varint_field(2, line, &mut line_message);
+            let mut location = Vec::new();
+            varint_field(1, id, &mut location);
+            bytes_field(4, &line_message, &mut location);
+            bytes_field(4, &location, &mut out);
+        }
+        for (id, name) in [(1, 3), (2, 4)] {
+            let mut function = Vec::new();
+            varint_field(1, id, &mut function);
+            varint_field(2, name, &mut function);
+            varint_field(4, 5, &mut function);
+            bytes_field(5, &function, &mut out);
+        }
+        for string in strings {
+            bytes_field(6, string.as_bytes(), &mut out);
+        }
+
+        let profile = parse_profile(ProfileFormat::Pprof, &out).expect("parse pprof");
+        assert_eq!(frame_names(&profile, 0), vec!["main", "handle"]);
+        assert_eq!(profile.samples[0].count, 5);
+        let handle = &profile.frames[profile.samples[0].stack[1]];
+        assert_eq!(handle.file.as_deref(), Some("src/main.rs"));
+        assert_eq!(handle.line, Some(12));
+    }
+
+    #[test]
+    fn counts_skip_unmapped_frames_and_recursion() {
+        let samples = vec![
+            ProfileSample {
+                stack: vec![0, 1, 2, 3],
+                count: 3,
+            },
+            ProfileSample {
+                stack: vec![0, 3, 3],
+                count: 2,
+            },
+            ProfileSample {
+                stack: vec![1],
+                count: 4,
+            },
+        ];
+        let frame_symbols = vec![
+            Some("sym-main".to_owned()),
+            None,
+            None,
+            Some("sym-parse".to_owned()),
+        ];
+
+        let counts = count_samples(&samples, &frame_symbols);
+        assert_eq!((counts.total, counts.mapped), (9, 5));
+        assert_eq!(
+            counts.symbols,
+            vec![
+                SymbolSampleCount {
+                    symbol_id: "sym-parse".to_owned(),
+                    self_samples: 5,
+                    total_samples: 5,
+                },
+                SymbolSampleCount {
+                    symbol_id: "sym-main".to_owned(),
+                    self_samples: 0,
+                    total_samples: 5,
+                },
+            ]
+        );
+        assert_eq!(
+            counts.edges,
+            vec![EdgeSampleCount {
+                caller_id: "sym-main".to_owned(),
+                callee_id: "sym-parse".to_owned(),
+                samples: 5,
+            }]
+        );
+
+        let weights = execution_edge_weights(&counts.edges);
+        assert_eq!(
+            weights[&("sym-main".to_owned(), "sym-parse".to_owned())],
+            1.0 + EXECUTION_WEIGHT_SCALE
+        );
+    }
+}
@@ -1045,15 +1045,15 @@ where
     runtime.block_on(future)
 }
 
-struct SimilarityEngine {
+pub(crate) struct SimilarityEngine {
     runtime: tokio::runtime::Runtime,
     provider: Option<Box<dyn EmbeddingProvider>>,
-    notes: Vec<String>,
-    used_embeddings: bool,
+    pub(crate) notes: Vec<String>,
+    pub(crate) used_embeddings: bool,
 }
 
 impl SimilarityEngine {
-    fn new(workspace: &Path) -> Result<Self, AnalysisError> {
+    pub(crate) fn new(workspace: &Path) -> Result<Self, AnalysisError> {
         let runtime = tokio::runtime::Builder::new_current_thread()
             .enable_all()
             .build()
@@ -1083,7 +1083,7 @@ impl SimilarityEngine {
         })
     }
 
-    fn compare(
+    pub(crate) fn compare(
         &mut self,
         snapshot_json: &str,
         current_json: &str,
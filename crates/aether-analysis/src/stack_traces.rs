@@ -0,0 +1,936 @@
+//! Stack trace explanation: parses Rust panics, Python tracebacks and
+//! JavaScript stacks, maps their frames onto indexed symbols, and gathers for
+//! each one its SIR, recent SIR changes, open contract violations and the
+//! commits that last touched it.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aether_store::{SirHistoryStore, SirStateStore, SqliteStore, SymbolRecord};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api_surface::git_stdout;
+use crate::coupling::AnalysisError;
+use crate::frames::FrameResolver;
+
+const TRACE_SCHEMA_VERSION: &str = "1.0";
+pub const DEFAULT_TRACE_MAX_FRAMES: usize = 10;
+pub const DEFAULT_TRACE_COMMIT_LIMIT: usize = 5;
+const SIR_CHANGES_PER_FRAME: usize = 3;
+const VIOLATIONS_PER_FRAME: usize = 5;
+const COMMITS_PER_FRAME: usize = 3;
+/// Weight of a frame relative to the frame it called.
+const FRAME_DECAY: f64 = 0.7;
+/// Age in days at which a commit's recency factor halves.
+const RECENCY_HALF_DAYS: f64 = 30.0;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+/// Hash git blame reports for lines that are not committed yet.
+const UNCOMMITTED: &str = "0000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TraceLanguage {
+    #[serde(rename = "rust")]
+    Rust,
+    #[serde(rename = "python")]
+    Python,
+    #[serde(rename = "javascript")]
+    JavaScript,
+}
+
+impl TraceLanguage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+            Self::Python => "python",
+            Self::JavaScript => "javascript",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceFrame {
+    /// Function name as printed; empty for a bare panic location.
+    pub function: String,
+    pub file: Option<String>,
+    /// 1-based.
+    pub line: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParsedTrace {
+    pub language: Option<TraceLanguage>,
+    /// Panic message or exception line.
+    pub message: Option<String>,
+    /// Innermost frame first.
+    pub frames: Vec<TraceFrame>,
+    /// Earlier chained tracebacks that were skipped.
+    pub chained: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExplainTraceRequest {
+    pub trace: String,
+    /// Resolved frames to explain, innermost first.
+    pub max_frames: Option<usize>,
+    /// Suspect commits to rank.
+    pub commit_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceSirChange {
+    pub version: i64,
+    pub created_at: i64,
+    pub commit: Option<String>,
+    pub intent_changed: bool,
+    pub intent: String,
+    pub error_modes_added: Vec<String>,
+    pub error_modes_removed: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceViolation {
+    pub contract_id: i64,
+    pub clause_type: String,
+    pub clause_text: String,
+    pub violation_type: String,
+    pub reason: Option<String>,
+    pub confidence: Option<f64>,
+    pub detected_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceCommit {
+    pub commit: String,
+    pub author: String,
+    pub date: String,
+    pub summary: String,
+    /// Lines of the symbol last changed by this commit.
+    pub lines: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExplainedFrame {
+    /// Index of the frame in the parsed trace, innermost first.
+    pub position: usize,
+    pub function: String,
+    /// `file:line` as printed in the trace.
+    pub location: Option<String>,
+    pub symbol_id: String,
+    pub symbol_name: String,
+    pub file: String,
+    pub start_line: Option<usize>,
+    pub end_line: Option<usize>,
+    pub intent: Option<String>,
+    pub error_modes: Vec<String>,
+    /// Most recent SIR changes first.
+    pub sir_changes: Vec<TraceSirChange>,
+    pub violations: Vec<TraceViolation>,
+    /// Most recent commits touching the symbol's lines first.
+    pub commits: Vec<TraceCommit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuspectCommit {
+    pub rank: u32,
+    pub score: f64,
+    pub commit: String,
+    pub author: String,
+    pub date: String,
+    pub summary: String,
+    /// Symbols on the trace this commit touched, innermost first.
+    pub symbols: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExplainTraceReport {
+    pub schema_version: String,
+    pub language: Option<TraceLanguage>,
+    pub message: Option<String>,
+    pub frames_total: usize,
+    pub frames_resolved: usize,
+    /// Resolved frames, innermost first.
+    pub chain: Vec<ExplainedFrame>,
+    pub suspect_commits: Vec<SuspectCommit>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StackTraceAnalyzer {
+    workspace: PathBuf,
+}
+
+/// Blame of one resolved frame's line span.
+#[derive(Debug, Clone, Default)]
+struct FrameBlame {
+    symbol_name: String,
+    span_lines: usize,
+    lines_by_commit: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Clone)]
+struct CommitMeta {
+    author: String,
+    date: String,
+    timestamp: i64,
+    summary: String,
+}
+
+impl StackTraceAnalyzer {
+    pub fn new(workspace: impl AsRef<Path>) -> Result<Self, AnalysisError> {
+        Ok(Self {
+            workspace: workspace.as_ref().to_path_buf(),
+        })
+    }
+
+    pub fn explain(
+        &self,
+        store: &SqliteStore,
+        request: &ExplainTraceRequest,
+    ) -> Result<ExplainTraceReport, AnalysisError> {
+        let parsed = parse_stack_trace(&request.trace);
+        let max_frames = request
+            .max_frames
+            .unwrap_or(DEFAULT_TRACE_MAX_FRAMES)
+            .max(1);
+        let commit_limit = request
+            .commit_limit
+            .unwrap_or(DEFAULT_TRACE_COMMIT_LIMIT)
+            .max(1);
+
+        let mut notes = Vec::new();
+        if parsed.frames.is_empty() {
+            notes.push(
+                "no stack frames recognized; expected a Rust panic backtrace, Python traceback \
+                 or JavaScript stack"
+                    .to_owned(),
+            );
+        }
+        if parsed.chained > 0 {
+            notes.push(format!(
+                "{} earlier chained traceback(s) skipped; explaining the last one",
+                parsed.chained
+            ));
+        }
+
+        let mut resolver = FrameResolver::new(&self.workspace, store.list_all_symbol_records()?);
+        let mut chain = Vec::new();
+        let mut blames = Vec::new();
+        let mut frames_resolved = 0usize;
+        let mut previous = None::<String>;
+        let git_available =
+            git_stdout(&self.workspace, &["rev-parse", "--is-inside-work-tree"]).is_ok();
+        for (position, frame) in parsed.frames.iter().enumerate() {
+            let Some(index) = resolver.resolve(&frame.function, frame.file.as_deref(), frame.line)
+            else {
+                continue;
+            };
+            let record = resolver.record(index).clone();
+            // The panic site, closures and recursion repeat the same symbol.
+            if previous.as_deref() == Some(record.id.as_str()) {
+                continue;
+            }
+            previous = Some(record.id.clone());
+            frames_resolved += 1;
+            if chain.len() >= max_frames {
+                continue;
+            }
+
+            let span = resolver.span(index);
+            let mut blame = FrameBlame {
+                symbol_name: record.qualified_name.clone(),
+                ..FrameBlame::default()
+            };
+            if let Some((start, end)) = span
+                && git_available
+            {
+                match blame_span(&self.workspace, &record.file_path, start, end) {
+                    Ok(lines_by_commit) => {
+                        blame.span_lines = end + 1 - start;
+                        blame.lines_by_commit = lines_by_commit;
+                    }
+                    Err(err) => {
+                        notes.push(format!("could not blame {}: {err}", record.file_path));
+                    }
+                }
+            }
+            if let Some(uncommitted) = blame.lines_by_commit.remove(UNCOMMITTED) {
+                notes.push(format!(
+                    "{} has {uncommitted} uncommitted line(s)",
+                    record.qualified_name
+                ));
+            }
+            chain.push(explain_frame(store, position, frame, &record, span)?);
+            blames.push(blame);
+        }
+        if !git_available && !chain.is_empty() {
+            notes.push("workspace is not a git repository; commits were not traced".to_owned());
+        }
+        if !parsed.frames.is_empty() && frames_resolved == 0 {
+            notes.push(
+                "no frame matched an indexed symbol; check that the trace comes from this \
+                 workspace"
+                    .to_owned(),
+            );
+        }
+
+        let hashes = blames
+            .iter()
+            .flat_map(|blame| blame.lines_by_commit.keys().cloned())
+            .collect::<HashSet<_>>();
+        let commits = if hashes.is_empty() {
+            HashMap::new()
+        } else {
+            commit_metadata(&self.workspace, hashes)?
+        };
+        for (frame, blame) in chain.iter_mut().zip(&blames) {
+            frame.commits = frame_commits(blame, &commits);
+        }
+        let suspect_commits = rank_suspect_commits(&blames, &commits, now_secs(), commit_limit);
+
+        Ok(ExplainTraceReport {
+            schema_version: TRACE_SCHEMA_VERSION.to_owned(),
+            language: parsed.language,
+            message: parsed.message,
+            frames_total: parsed.frames.len(),
+            frames_resolved,
+            chain,
+            suspect_commits,
+            notes,
+        })
+    }
+}
+
+fn explain_frame(
+    store: &SqliteStore,
+    position: usize,
+    frame: &TraceFrame,
+    record: &SymbolRecord,
+    span: Option<(usize, usize)>,
+) -> Result<ExplainedFrame, AnalysisError> {
+    let sir = store
+        .read_sir_blob(&record.id)?
+        .and_then(|blob| serde_json::from_str::<Value>(&blob).ok());
+    let intent = sir
+        .as_ref()
+        .map(sir_intent)
+        .filter(|intent| !intent.is_empty());
+    let error_modes = sir.as_ref().map(sir_error_modes).unwrap_or_default();
+
+    let history = store.list_sir_history(&record.id)?;
+    let sir_changes = history
+        .windows(2)
+        .rev()
+        .filter_map(|pair| {
+            let before = serde_json::from_str::<Value>(&pair[0].sir_json).ok()?;
+            let after = serde_json::from_str::<Value>(&pair[1].sir_json).ok()?;
+            let before_modes = sir_error_modes(&before);
+            let after_modes = sir_error_modes(&after);
+            let change = TraceSirChange {
+                version: pair[1].version,
+                created_at: pair[1].created_at,
+                commit: pair[1].commit_hash.clone(),
+                intent_changed: sir_intent(&before) != sir_intent(&after),
+                intent: sir_intent(&after),
+                error_modes_added: after_modes
+                    .iter()
+                    .filter(|mode| !before_modes.contains(mode))
+                    .cloned()
+                    .collect(),
+                error_modes_removed: before_modes
+                    .iter()
+                    .filter(|mode| !after_modes.contains(mode))
+                    .cloned()
+                    .collect(),
+            };
+            (change.intent_changed
+                || !change.error_modes_added.is_empty()
+                || !change.error_modes_removed.is_empty())
+            .then_some(change)
+        })
+        .take(SIR_CHANGES_PER_FRAME)
+        .collect();
+
+    let mut violations = Vec::new();
+    let mut seen_contracts = HashSet::new();
+    for violation in store.list_violations_for_symbol(&record.id, 100)? {
+        if violation.dismissed || !seen_contracts.insert(violation.contract_id) {
+            continue;
+        }
+        let Some(contract) = store.get_intent_contract(violation.contract_id)? else {
+            continue;
+        };
+        if !contract.active {
+            continue;
+        }
+        violations.push(TraceViolation {
+            contract_id: contract.id,
+            clause_type: contract.clause_type,
+            clause_text: contract.clause_text,
+            violation_type: violation.violation_type,
+            reason: violation.reason,
+            confidence: violation.confidence,
+            detected_at: violation.detected_at,
+        });
+        if violations.len() >= VIOLATIONS_PER_FRAME {
+            break;
+        }
+    }
+
+    Ok(ExplainedFrame {
+        position,
+        function: frame.function.clone(),
+        location: frame.file.as_ref().map(|file| match frame.line {
+            Some(line) => format!("{file}:{line}"),
+            None => file.clone(),
+        }),
+        symbol_id: record.id.clone(),
+        symbol_name: record.qualified_name.clone(),
+        file: record.file_path.clone(),
+        start_line: span.map(|(start, _)| start),
+        end_line: span.map(|(_, end)| end),
+        intent,
+        error_modes,
+        sir_changes,
+        violations,
+        commits: Vec::new(),
+    })
+}
+
+fn sir_intent(sir: &Value) -> String {
+    ["intent", "purpose"]
+        .iter()
+        .find_map(|key| sir.get(key).and_then(Value::as_str))
+        .unwrap_or_default()
+        .trim()
+        .to_owned()
+}
+
+fn sir_error_modes(sir: &Value) -> Vec<String> {
+    sir.get("error_modes")
+        .and_then(Value::as_array)
+        .map(|modes| {
+            modes
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::trim)
+                .filter(|mode| !mode.is_empty())
+                .map(str::to_owned)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Lines of `start..=end` last changed by each commit.
+fn blame_span(
+    workspace: &Path,
+    file: &str,
+    start: usize,
+    end: usize,
+) -> Result<BTreeMap<String, usize>, AnalysisError> {
+    let range = format!("{start},{end}");
+    let output = git_stdout(
+        workspace,
+        &["blame", "--porcelain", "-L", range.as_str(), "--", file],
+    )?;
+    let mut lines_by_commit = BTreeMap::new();
+    for line in output.lines() {
+        // Every blamed line starts with `<hash> <orig-line> <final-line>`.
+        let mut fields = line.split(' ');
+        let Some(hash) = fields.next() else {
+            continue;
+        };
+        if hash.len() == 40 && hash.chars().all(|ch| ch.is_ascii_hexdigit()) && fields.count() >= 2
+        {
+            *lines_by_commit
+                .entry(hash.to_ascii_lowercase())
+                .or_insert(0) += 1;
+        }
+    }
+    Ok(lines_by_commit)
+}
+
+fn commit_metadata(
+    workspace: &Path,
+    hashes: HashSet<String>,
+) -> Result<HashMap<String, CommitMeta>, AnalysisError> {
+    let mut args = vec!["show", "-s", "--format=%H%x1f%an%x1f%as%x1f%ct%x1f%s"];
+    let mut hashes = hashes.into_iter().collect::<Vec<_>>();
+    hashes.sort();
+    args.extend(hashes.iter().map(String::as_str));
+    let output = git_stdout(workspace, &args)?;
+    Ok(output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\u{1f}');
+            let hash = fields.next()?.trim().to_ascii_lowercase();
+            let author = fields.next()?.to_owned();
+            let date = fields.next()?.to_owned();
+            let timestamp = fields.next()?.trim().parse::<i64>().ok()?;
+            let summary = fields.next().unwrap_or_default().to_owned();
+            Some((
+                hash,
+                CommitMeta {
+                    author,
+                    date,
+                    timestamp,
+                    summary,
+                },
+            ))
+        })
+        .collect())
+}
+
+fn frame_commits(blame: &FrameBlame, commits: &HashMap<String, CommitMeta>) -> Vec<TraceCommit> {
+    let mut touched = blame
+        .lines_by_commit
+        .iter()
+        .filter_map(|(hash, lines)| Some((hash, *lines, commits.get(hash)?)))
+        .collect::<Vec<_>>();
+    touched.sort_by(|left, right| {
+        right
+            .2
+            .timestamp
+            .cmp(&left.2.timestamp)
+            .then_with(|| left.0.cmp(right.0))
+    });
+    touched
+        .into_iter()
+        .take(COMMITS_PER_FRAME)
+        .map(|(hash, lines, meta)| TraceCommit {
+            commit: hash.clone(),
+            author: meta.author.clone(),
+            date: meta.date.clone(),
+            summary: meta.summary.clone(),
+            lines,
+        })
+        .collect()
+}
+
+/// Scores each commit by the share of every frame's lines it last changed,
+/// weighting inner frames above their callers and recent commits above old
+/// ones.
+fn rank_suspect_commits(
+    blames: &[FrameBlame],
+    commits: &HashMap<String, CommitMeta>,
+    now: i64,
+    limit: usize,
+) -> Vec<SuspectCommit> {
+    let mut scores = HashMap::<&str, (f64, Vec<String>)>::new();
+    for (depth, blame) in blames.iter().enumerate() {
+        if blame.span_lines == 0 {
+            continue;
+        }
+        let frame_weight = FRAME_DECAY.powi(depth as i32);
+        for (hash, lines) in &blame.lines_by_commit {
+            let Some(meta) = commits.get(hash) else {
+                continue;
+            };
+            let age_days = (now - meta.timestamp).max(0) as f64 / SECONDS_PER_DAY;
+            let recency = 1.0 / (1.0 + age_days / RECENCY_HALF_DAYS);
+            let share = *lines as f64 / blame.span_lines as f64;
+            let entry = scores.entry(hash.as_str()).or_default();
+            entry.0 += frame_weight * share * recency;
+            if !entry.1.contains(&blame.symbol_name) {
+                entry.1.push(blame.symbol_name.clone());
+            }
+        }
+    }
+
+    let mut ranked = scores
+        .into_iter()
+        .filter_map(|(hash, (score, symbols))| Some((hash, score, symbols, commits.get(hash)?)))
+        .collect::<Vec<_>>();
+    ranked.sort_by(|left, right| {
+        right
+            .1
+            .total_cmp(&left.1)
+            .then_with(|| right.3.timestamp.cmp(&left.3.timestamp))
+            .then_with(|| left.0.cmp(right.0))
+    });
+    ranked
+        .into_iter()
+        .take(limit)
+        .enumerate()
+        .map(|(rank, (hash, score, symbols, meta))| SuspectCommit {
+            rank: rank as u32 + 1,
+            score,
+            commit: hash.to_owned(),
+            author: meta.author.clone(),
+            date: meta.date.clone(),
+            summary: meta.summary.clone(),
+            symbols,
+        })
+        .collect()
+}
+
+/// Parses the last Rust panic, Python traceback or JavaScript stack in `text`.
+/// Log prefixes before a frame are tolerated; unrecognized lines are skipped.
+pub fn parse_stack_trace(text: &str) -> ParsedTrace {
+    let mut trace = ParsedTrace::default();
+    let mut python_frames = Vec::<TraceFrame>::new();
+    let mut awaiting_location = false;
+    let mut previous_text = None::<String>;
+    let mut lines = text.lines().peekable();
+
+    while let Some(raw) = lines.next() {
+        let line = raw.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some((_, header)) = line.split_once("panicked at ") {
+            trace.language = Some(TraceLanguage::Rust);
+            trace.frames.clear();
+            awaiting_location = false;
+            if let Some(quoted) = header.strip_prefix('\'')
+                && let Some((message, location)) = quoted.rsplit_once("', ")
+            {
+                // Before Rust 1.73: `panicked at 'message', src/main.rs:10:5`.
+                trace.message = Some(message.to_owned());
+                trace.frames.extend(location_frame(location));
+            } else {
+                trace
+                    .frames
+                    .extend(location_frame(header.trim_end_matches(':')));
+                trace.message = lines
+                    .next_if(|next| !next.trim().is_empty() && !next.contains("backtrace"))
+                    .map(|next| next.trim().to_owned());
+            }
+            continue;
+        }
+
+        if line.starts_with("Traceback (most recent call last)") {
+            if !python_frames.is_empty() {
+                trace.chained += 1;
+            }
+            trace.language = Some(TraceLanguage::Python);
+            python_frames.clear();
+            trace.message = None;
+            continue;
+        }
+        if trace.language == Some(TraceLanguage::Python) {
+            if let Some(frame) = python_frame(line) {
+                python_frames.push(frame);
+            } else if !raw.starts_with(char::is_whitespace)
+                && !line.starts_with("During handling of the above exception")
+                && !line.starts_with("The above exception was the direct cause")
+            {
+                trace.message = Some(line.to_owned());
+            }
+            continue;
+        }
+
+        if let Some(function) = rust_frame(line) {
+            if trace.language.is_none() {
+                trace.language = Some(TraceLanguage::Rust);
+            }
+            if trace.language == Some(TraceLanguage::Rust) {
+                trace.frames.push(TraceFrame {
+                    function: function.to_owned(),
+                    file: None,
+                    line: None,
+                });
+                awaiting_location = true;
+                continue;
+            }
+        }
+        if trace.language == Some(TraceLanguage::Rust) {
+            if awaiting_location
+                && let Some(location) = line.strip_prefix("at ")
+                && let Some((file, number)) = parse_location(location)
+                && let Some(frame) = trace.frames.last_mut()
+            {
+                frame.file = Some(file);
+                frame.line = Some(number);
+            }
+            awaiting_location = false;
+            continue;
+        }
+
+        if let Some(frame) = javascript_frame(line) {
+            if trace.language.is_none() {
+                trace.language = Some(TraceLanguage::JavaScript);
+                trace.message = previous_text.take();
+            }
+            trace.frames.push(frame);
+            continue;
+        }
+        if trace.language.is_none() {
+            previous_text = Some(line.to_owned());
+        }
+    }
+
+    if trace.language == Some(TraceLanguage::Python) {
+        python_frames.reverse();
+        trace.frames = python_frames;
+    }
+    trace
+}
+
+fn location_frame(location: &str) -> Option<TraceFrame> {
+    let (file, line) = parse_location(location)?;
+    Some(TraceFrame {
+        function: String::new(),
+        file: Some(file),
+        line: Some(line),
+    })
+}
+
+/// `12: name` or, in older backtraces, `12: 0x55d0c1a2 - name`.
+fn rust_frame(line: &str) -> Option<&str> {
+    let (number, rest) = line.split_once(':')?;
+    if number.is_empty() || !number.chars().all(|ch| ch.is_ascii_digit()) || !rest.starts_with(' ')
+    {
+        return None;
+    }
+    let rest = rest.trim();
+    let name = match rest.split_once(" - ") {
+        Some((address, name)) if address.starts_with("0x") => name.trim(),
+        _ => rest,
+    };
+    (!name.is_empty() && !name.starts_with("0x")).then_some(name)
+}
+
+/// `File "app/views.py", line 12, in render`.
+fn python_frame(line: &str) -> Option<TraceFrame> {
+    let rest = line.strip_prefix("File \"")?;
+    let (file, rest) = rest.split_once('"')?;
+    let rest = rest.trim_start_matches(',').trim().strip_prefix("line ")?;
+    let (number, rest) = rest.split_once(',').unwrap_or((rest, ""));
+    let function = rest.trim().strip_prefix("in ").unwrap_or_default().trim();
+    Some(TraceFrame {
+        function: function.to_owned(),
+        file: Some(clean_path(file)),
+        line: number.trim().parse().ok(),
+    })
+}
+
+/// V8's `at name (file:line:col)` / `at file:line:col`, or the
+/// `name@file:line:col` frames of SpiderMonkey and JavaScriptCore.
+fn javascript_frame(line: &str) -> Option<TraceFrame> {
+    let v8 = line
+        .strip_prefix("at ")
+        .or_else(|| line.split_once("    at ").map(|(_, rest)| rest));
+    let (function, location) = match v8 {
+        Some(rest) => {
+            let rest = rest.strip_prefix("async ").unwrap_or(rest);
+            match rest
+                .strip_suffix(')')
+                .and_then(|inner| inner.rsplit_once(" ("))
+            {
+                Some((function, location)) => {
+                    (function.strip_prefix("new ").unwrap_or(function), location)
+                }
+                None => ("", rest),
+            }
+        }
+        None => {
+            let (function, location) = line.split_once('@')?;
+            if function.contains(char::is_whitespace) {
+                return None;
+            }
+            (function, location)
+        }
+    };
+    let (file, number) = parse_location(location)?;
+    Some(TraceFrame {
+        function: function.trim().to_owned(),
+        file: Some(file),
+        line: Some(number),
+    })
+}
+
+/// `path:line:column` or `path:line`.
+fn parse_location(text: &str) -> Option<(String, usize)> {
+    let (rest, last) = text.trim().rsplit_once(':')?;
+    let last = last.trim().parse::<usize>().ok()?;
+    let (path, line) = match rest.rsplit_once(':') {
+        Some((path, line)) => match line.parse::<usize>() {
+            Ok(line) => (path, line),
+            Err(_) => (rest, last),
+        },
+        None => (rest, last),
+    };
+    let path = clean_path(path);
+    (!path.is_empty()).then_some((path, line))
+}
+
+/// Drops URL schemes such as `file://` and `webpack:///` and a leading `./`.
+fn clean_path(path: &str) -> String {
+    let path = path.trim();
+    let path = path
+        .strip_prefix("file://")
+        .or_else(|| path.split_once(":///").map(|(_, rest)| rest))
+        .unwrap_or(path);
+    path.trim_start_matches("./").to_owned()
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|value| value.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locations(trace: &ParsedTrace) -> Vec<(String, Option<String>, Option<usize>)> {
+        trace
+            .frames
+            .iter()
+            .map(|frame| (frame.function.clone(), frame.file.clone(), frame.line))
+            .collect()
+    }
+
+    fn frame(function: &str, file: &str, line: usize) -> (String, Option<String>, Option<usize>) {
+        (function.to_owned(), Some(file.to_owned()), Some(line))
+    }
+
+    #[test]
+    fn parses_rust_python_and_javascript_traces() {
+        let rust = parse_stack_trace(
+            "2026-03-01T10:00:00Z ERROR thread 'tokio-runtime-worker' panicked at src/cart.rs:42:9:\n\
+             called `Option::unwrap()` on a `None` value\n\
+             stack backtrace:\n\
+             \x20  0: rust_begin_unwind\n\
+             \x20            at /rustc/abc/library/std/src/panicking.rs:652:5\n\
+             \x20  1: core::panicking::panic\n\
+             \x20  2: shop::cart::Cart::total\n\
+             \x20            at ./src/cart.rs:42:9\n\
+             \x20  3: 0x55d0c1a2 - shop::api::checkout\n\
+             \x20            at ./src/api.rs:17:5\n",
+        );
+        assert_eq!(rust.language, Some(TraceLanguage::Rust));
+        assert_eq!(
+            rust.message.as_deref(),
+            Some("called `Option::unwrap()` on a `None` value")
+        );
+        assert_eq!(
+            locations(&rust),
+            vec![
+                frame("", "src/cart.rs", 42),
+                frame(
+                    "rust_begin_unwind",
+                    "/rustc/abc/library/std/src/panicking.rs",
+                    652
+                ),
+                ("core::panicking::panic".to_owned(), None, None),
+                frame("shop::cart::Cart::total", "src/cart.rs", 42),
+                frame("shop::api::checkout", "src/api.rs", 17),
+            ]
+        );
+
+        let old_rust =
+            parse_stack_trace("thread 'main' panicked at 'index out of bounds', src/lib.rs:3:5");
+        assert_eq!(old_rust.message.as_deref(), Some("index out of bounds"));
+        assert_eq!(locations(&old_rust), vec![frame("", "src/lib.rs", 3)]);
+
+        let python = parse_stack_trace(
+            "Traceback (most recent call last):\n\
+             \x20 File \"/srv/app/app/api.py\", line 8, in checkout\n\
+             \x20   total = cart.total()\n\
+             \x20 File \"/srv/app/app/cart.py\", line 21, in total\n\
+             \x20   return sum(item.price for item in self.items)\n\
+             TypeError: unsupported operand type(s) for +: 'int' and 'NoneType'\n",
+        );
+        assert_eq!(python.language, Some(TraceLanguage::Python));
+        assert_eq!(
+            python.message.as_deref(),
+            Some("TypeError: unsupported operand type(s) for +: 'int' and 'NoneType'")
+        );
+        assert_eq!(
+            locations(&python),
+            vec![
+                frame("total", "/srv/app/app/cart.py", 21),
+                frame("checkout", "/srv/app/app/api.py", 8),
+            ]
+        );
+
+        let javascript = parse_stack_trace(
+            "TypeError: Cannot read properties of undefined (reading 'price')\n\
+             \x20   at Cart.total (/srv/app/src/cart.js:12:20)\n\
+             \x20   at async checkout (file:///srv/app/src/api.js:5:3)\n\
+             \x20   at node:internal/process/task_queues:95:5\n\
+             render@webpack:///./src/view.js:7:11\n",
+        );
+        assert_eq!(javascript.language, Some(TraceLanguage::JavaScript));
+        assert_eq!(
+            javascript.message.as_deref(),
+            Some("TypeError: Cannot read properties of undefined (reading 'price')")
+        );
+        assert_eq!(
+            locations(&javascript),
+            vec![
+                frame("Cart.total", "/srv/app/src/cart.js", 12),
+                frame("checkout", "/srv/app/src/api.js", 5),
+                frame("", "node:internal/process/task_queues", 95),
+                frame("render", "src/view.js", 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn chained_python_tracebacks_keep_the_last() {
+        let trace = parse_stack_trace(
+            "Traceback (most recent call last):\n\
+             \x20 File \"app/db.py\", line 3, in load\n\
+             KeyError: 'id'\n\
+             \n\
+             During handling of the above exception, another exception occurred:\n\
+             \n\
+             Traceback (most recent call last):\n\
+             \x20 File \"app/api.py\", line 9, in handler\n\
+             ValueError: missing id\n",
+        );
+        assert_eq!(trace.chained, 1);
+        assert_eq!(trace.message.as_deref(), Some("ValueError: missing id"));
+        assert_eq!(locations(&trace), vec![frame("handler", "app/api.py", 9)]);
+    }
+
+    #[test]
+    fn suspect_commits_favor_inner_frames_and_recent_changes() {
+        let day = SECONDS_PER_DAY as i64;
+        let now = 400 * day;
+        let meta = |timestamp: i64| CommitMeta {
+            author: "dev".to_owned(),
+            date: "2026-01-01".to_owned(),
+            timestamp,
+            summary: "change".to_owned(),
+        };
+        let commits = HashMap::from([
+            ("recent".to_owned(), meta(now - day)),
+            ("old".to_owned(), meta(now - 300 * day)),
+            ("caller".to_owned(), meta(now - day)),
+        ]);
+        let blames = vec![
+            FrameBlame {
+                symbol_name: "Cart::total".to_owned(),
+                span_lines: 10,
+                lines_by_commit: BTreeMap::from([("recent".to_owned(), 4), ("old".to_owned(), 6)]),
+            },
+            FrameBlame {
+                symbol_name: "checkout".to_owned(),
+                span_lines: 10,
+                lines_by_commit: BTreeMap::from([
+                    ("caller".to_owned(), 4),
+                    ("recent".to_owned(), 6),
+                ]),
+            },
+        ];
+
+        let ranked = rank_suspect_commits(&blames, &commits, now, 5);
+        assert_eq!(
+            ranked
+                .iter()
+                .map(|suspect| suspect.commit.as_str())
+                .collect::<Vec<_>>(),
+            vec!["recent", "caller", "old"]
+        );
+        assert_eq!(ranked[0].symbols, vec!["Cart::total", "checkout"]);
+        assert_eq!(ranked[0].rank, 1);
+    }
+}
@@ -0,0 +1,1345 @@
+//! Test result ingestion: JUnit XML, cargo-nextest (libtest) JSON, pytest
+//! JSON reports and jest JSON parsed into a run history linked to test
+//! symbols, flakiness scores across runs, and failure triage through causal
+//! chain tracing.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aether_core::{content_hash, normalize_path};
+use aether_store::{
+    SqliteStore, SurrealGraphStore, TestIntentRecord, TestResultRecord, TestRunRecord,
+};
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api_surface::resolve_commit;
+use crate::causal::{CausalAnalyzer, CausalChainEntry, TraceCauseRequest};
+use crate::coupling::AnalysisError;
+
+pub const DEFAULT_FLAKY_WINDOW_RUNS: u32 = 30;
+pub const DEFAULT_FLAKY_MIN_RUNS: usize = 3;
+const TEST_RESULTS_SCHEMA_VERSION: &str = "1.0";
+const MAX_MESSAGE_CHARS: usize = 4_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestReportFormat {
+    Junit,
+    /// Line-delimited libtest JSON, as written by
+    /// `cargo nextest run --message-format libtest-json`.
+    Nextest,
+    /// `pytest --json-report` output.
+    Pytest,
+    /// `jest --json` output.
+    Jest,
+}
+
+impl TestReportFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Junit => "junit",
+            Self::Nextest => "nextest",
+            Self::Pytest => "pytest",
+            Self::Jest => "jest",
+        }
+    }
+
+    /// Guesses the format from the report content.
+    pub fn detect(content: &str) -> Option<Self> {
+        let trimmed = content.trim_start();
+        if trimmed.starts_with('<') {
+            return Some(Self::Junit);
+        }
+        if let Ok(Value::Object(report)) = serde_json::from_str::<Value>(trimmed) {
+            if report.contains_key("testResults") {
+                return Some(Self::Jest);
+            }
+            if report.contains_key("tests") {
+                return Some(Self::Pytest);
+            }
+            return None;
+        }
+        let first_line = trimmed.lines().next()?;
+        serde_json::from_str::<Value>(first_line)
+            .ok()
+            .filter(|event| event.get("type").is_some())
+            .map(|_| Self::Nextest)
+    }
+}
+
+impl FromStr for TestReportFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "junit" | "xml" => Ok(Self::Junit),
+            "nextest" | "libtest" => Ok(Self::Nextest),
+            "pytest" => Ok(Self::Pytest),
+            "jest" => Ok(Self::Jest),
+            other => Err(format!(
+                "invalid test report format '{other}', expected one of: junit, nextest, pytest, jest"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestOutcome {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+impl TestOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "passed" => Some(Self::Passed),
+            "failed" => Some(Self::Failed),
+            "skipped" => Some(Self::Skipped),
+            _ => None,
+        }
+    }
+}
+
+/// One test case as read from a report, before it is linked to a symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedTestCase {
+    /// JUnit suite or class, libtest binary, or jest `describe` titles.
+    pub suite: Option<String>,
+    pub name: String,
+    pub file_path: Option<String>,
+    pub outcome: TestOutcome,
+    pub duration_ms: Option<i64>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngestTestReportRequest {
+    pub path: PathBuf,
+    /// `None` detects the format from the content.
+    pub format: Option<TestReportFormat>,
+    /// Store the run in the history; triage can run without recording.
+    pub record: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IngestedTestRun {
+    pub schema_version: String,
+    pub run: TestRunRecord,
+    /// Results linked to a test symbol.
+    pub linked: usize,
+    pub results: Vec<TestResultRecord>,
+    pub recorded: bool,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlakyTestsRequest {
+    /// Most recent runs considered.
+    pub window_runs: u32,
+    /// Tests with fewer non-skipped results are not scored.
+    pub min_runs: usize,
+}
+
+impl Default for FlakyTestsRequest {
+    fn default() -> Self {
+        Self {
+            window_runs: DEFAULT_FLAKY_WINDOW_RUNS,
+            min_runs: DEFAULT_FLAKY_MIN_RUNS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlakyTest {
+    pub test_key: String,
+    pub test_name: String,
+    pub file_path: Option<String>,
+    pub symbol_id: Option<String>,
+    /// Non-skipped results in the window.
+    pub runs: usize,
+    pub passed: usize,
+    pub failed: usize,
+    /// Pass/fail changes between consecutive runs.
+    pub flips: usize,
+    /// Commits on which the test both passed and failed.
+    pub inconsistent_commits: usize,
+    /// 0.0 (stable) to 1.0 (flips every run).
+    pub flakiness: f32,
+    pub last_outcome: TestOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlakyTestsReport {
+    pub schema_version: String,
+    pub runs_considered: usize,
+    pub tests_scored: usize,
+    /// Tests that both passed and failed in the window, flakiest first.
+    pub tests: Vec<FlakyTest>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriageRequest {
+    pub lookback: Option<String>,
+    pub max_depth: Option<u32>,
+    /// Causal candidates traced per failing test.
+    pub limit: Option<u32>,
+    pub flaky: FlakyTestsRequest,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailureTriage {
+    pub test_key: String,
+    pub test_name: String,
+    pub file_path: Option<String>,
+    pub symbol_id: Option<String>,
+    pub message: Option<String>,
+    /// Flakiness from the run history, when the test has been scored.
+    pub flakiness: Option<f32>,
+    pub causal_chain: Vec<CausalChainEntry>,
+    pub notes: Vec<String>,
+}
+
+/// An upstream change implicated by one or more failing tests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TriageSuspect {
+    pub rank: u32,
+    /// Causal scores summed over the failures that reach this change, each
+    /// discounted by the failing test's flakiness.
+    pub score: f32,
+    pub symbol_id: String,
+    pub symbol_name: String,
+    pub file: String,
+    pub commit: String,
+    pub author: String,
+    pub date: String,
+    pub failing_tests: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TriageReport {
+    pub schema_version: String,
+    pub run_id: String,
+    pub failed: usize,
+    pub suspects: Vec<TriageSuspect>,
+    pub failures: Vec<FailureTriage>,
+    pub notes: Vec<String>,
+}
+
+pub struct TestResultsAnalyzer {
+    workspace: PathBuf,
+}
+
+impl TestResultsAnalyzer {
+    pub fn new(workspace: impl AsRef<Path>) -> Result<Self, AnalysisError> {
+        Ok(Self {
+            workspace: workspace.as_ref().to_path_buf(),
+        })
+    }
+
+    /// Parses a report, links each case to its test symbol and, when
+    /// requested, stores it as a run. Re-ingesting the same report replaces
+    /// the earlier run.
+    pub fn ingest(
+        &self,
+        store: &SqliteStore,
+        request: &IngestTestReportRequest,
+    ) -> Result<IngestedTestRun, AnalysisError> {
+        let content = std::fs::read_to_string(&request.path)?;
+        let format = match request.format {
+            Some(format) => format,
+            None => TestReportFormat::detect(&content).ok_or_else(|| {
+                AnalysisError::Message(format!(
+                    "could not detect the format of {}; pass it explicitly",
+                    request.path.display()
+                ))
+            })?,
+        };
+        let cases = parse_test_report(format, &content)?;
+
+        let mut notes = Vec::new();
+        let intents = store.list_all_test_intents()?;
+        if intents.is_empty() {
+            notes.push(
+                "no test intents are indexed; results are stored without symbol links".to_owned(),
+            );
+        }
+        let qualified_names = store
+            .list_all_symbol_records()?
+            .into_iter()
+            .map(|record| (record.id, record.qualified_name))
+            .collect::<HashMap<_, _>>();
+
+        let run_id = content_hash(&format!("{}\n{content}", format.as_str()));
+        let workspace = normalize_path(&self.workspace.to_string_lossy());
+        let results = link_test_cases(&run_id, &cases, &intents, &qualified_names, &workspace);
+        let linked = results
+            .iter()
+            .filter(|result| result.symbol_id.is_some())
+            .count();
+        if linked < results.len() && !intents.is_empty() {
+            notes.push(format!(
+                "{} result(s) could not be linked to an indexed test",
+                results.len() - linked
+            ));
+        }
+
+        let count = |outcome: TestOutcome| {
+            results
+                .iter()
+                .filter(|result| result.outcome == outcome.as_str())
+                .count() as i64
+        };
+        let run = TestRunRecord {
+            run_id,
+            format: format.as_str().to_owned(),
+            source_path: relative_path(&request.path.to_string_lossy(), &workspace),
+            commit_hash: resolve_commit(&self.workspace, "HEAD").ok(),
+            recorded_at: now_secs(),
+            total: results.len() as i64,
+            passed: count(TestOutcome::Passed),
+            failed: count(TestOutcome::Failed),
+            skipped: count(TestOutcome::Skipped),
+        };
+        if request.record {
+            store.record_test_run(&run, &results)?;
+        }
+
+        Ok(IngestedTestRun {
+            schema_version: TEST_RESULTS_SCHEMA_VERSION.to_owned(),
+            run,
+            linked,
+            results,
+            recorded: request.record,
+            notes,
+        })
+    }
+
+    pub fn flaky_tests(
+        &self,
+        store: &SqliteStore,
+        request: &FlakyTestsRequest,
+    ) -> Result<FlakyTestsReport, AnalysisError> {
+        let runs = store.list_test_runs(request.window_runs)?;
+        let commits = runs
+            .iter()
+            .filter_map(|run| {
+                run.commit_hash
+                    .clone()
+                    .map(|commit| (run.run_id.clone(), commit))
+            })
+            .collect::<HashMap<_, _>>();
+        let results = store.list_recent_test_results(request.window_runs)?;
+        let scored = score_flakiness(&results, &commits, request.min_runs);
+        let tests_scored = scored.len();
+
+        let mut tests = scored
+            .into_iter()
+            .filter(|test| test.passed > 0 && test.failed > 0)
+            .collect::<Vec<_>>();
+        tests.sort_by(|left, right| {
+            right
+                .flakiness
+                .total_cmp(&left.flakiness)
+                .then_with(|| right.failed.cmp(&left.failed))
+                .then_with(|| left.test_key.cmp(&right.test_key))
+        });
+
+        Ok(FlakyTestsReport {
+            schema_version: TEST_RESULTS_SCHEMA_VERSION.to_owned(),
+            runs_considered: runs.len(),
+            tests_scored,
+            tests,
+        })
+    }
+
+    /// Traces each failing test of `run` back through its upstream
+    /// dependencies and ranks the semantic changes most likely responsible.
+    pub fn triage(
+        &self,
+        store: &SqliteStore,
+        graph: &SurrealGraphStore,
+        run: &IngestedTestRun,
+        request: &TriageRequest,
+    ) -> Result<TriageReport, AnalysisError> {
+        let flaky = self.flaky_tests(store, &request.flaky)?;
+        let flakiness = flaky
+            .tests
+            .iter()
+            .map(|test| (test.test_key.as_str(), test.flakiness))
+            .collect::<HashMap<_, _>>();
+        let causal = CausalAnalyzer::new(&self.workspace)?;
+
+        let mut failures = Vec::new();
+        for result in run
+            .results
+            .iter()
+            .filter(|result| result.outcome == TestOutcome::Failed.as_str())
+        {
+            let mut triage = FailureTriage {
+                test_key: result.test_key.clone(),
+                test_name: result.test_name.clone(),
+                file_path: result.file_path.clone(),
+                symbol_id: result.symbol_id.clone(),
+                message: result.message.clone(),
+                flakiness: flakiness.get(result.test_key.as_str()).copied(),
+                causal_chain: Vec::new(),
+                notes: Vec::new(),
+            };
+            if triage.flakiness.is_some_and(|score| score >= 0.5) {
+                triage
+                    .notes
+                    .push("test is flaky in recent runs; failure may be unrelated".to_owned());
+            }
+            match result.symbol_id.as_deref() {
+                Some(symbol_id) => match causal.trace_cause_with_graph(
+                    graph,
+                    TraceCauseRequest {
+                        target_symbol_id: symbol_id.to_owned(),
+                        lookback: request.lookback.clone(),
+                        max_depth: request.max_depth,
+                        limit: request.limit,
+                    },
+                ) {
+                    Ok(trace) => {
+                        if trace.causal_chain.is_empty() {
+                            triage
+                                .notes
+                                .push("no upstream semantic changes in the lookback".to_owned());
+                        }
+                        triage.causal_chain = trace.causal_chain;
+                        triage.notes.extend(trace.notes);
+                    }
+                    Err(err) => triage.notes.push(format!("causal trace failed: {err}")),
+                },
+                None => triage
+                    .notes
+                    .push("not linked to an indexed test symbol".to_owned()),
+            }
+            failures.push(triage);
+        }
+
+        let mut notes = Vec::new();
+        if flaky.runs_considered <= 1 {
+            notes.push("no run history yet; flakiness is not available".to_owned());
+        }
+        Ok(TriageReport {
+            schema_version: TEST_RESULTS_SCHEMA_VERSION.to_owned(),
+            run_id: run.run.run_id.clone(),
+            failed: failures.len(),
+            suspects: rank_suspects(&failures),
+            failures,
+            notes,
+        })
+    }
+}
+
+pub fn parse_test_report(
+    format: TestReportFormat,
+    content: &str,
+) -> Result<Vec<ParsedTestCase>, AnalysisError> {
+    match format {
+        TestReportFormat::Junit => parse_junit(content),
+        TestReportFormat::Nextest => parse_libtest_json(content),
+        TestReportFormat::Pytest => parse_pytest_json(content),
+        TestReportFormat::Jest => parse_jest_json(content),
+    }
+}
+
+fn parse_junit(content: &str) -> Result<Vec<ParsedTestCase>, AnalysisError> {
+    let mut reader = Reader::from_str(content);
+    let mut suites = Vec::<Option<String>>::new();
+    let mut case: Option<ParsedTestCase> = None;
+    let mut body: Option<String> = None;
+    let mut cases = Vec::new();
+
+    loop {
+        let event = reader.read_event().map_err(|err| {
+            AnalysisError::Message(format!(
+                "invalid JUnit XML at byte {}: {err}",
+                reader.buffer_position()
+            ))
+        })?;
+        match event {
+            Event::Start(element) => {
+                open_junit_element(&element, &mut suites, &mut case, &mut body);
+            }
+            Event::Empty(element) => {
+                open_junit_element(&element, &mut suites, &mut case, &mut body);
+                close_junit_element(
+                    element.local_name().as_ref(),
+                    &mut suites,
+                    &mut case,
+                    &mut body,
+                    &mut cases,
+                );
+            }
+            Event::End(element) => close_junit_element(
+                element.local_name().as_ref(),
+                &mut suites,
+                &mut case,
+                &mut body,
+                &mut cases,
+            ),
+            Event::Text(text) => {
+                if let Some(body) = body.as_mut() {
+                    body.push_str(&text.decode().map_err(xml_encoding_error)?);
+                }
+            }
+            Event::CData(text) => {
+                if let Some(body) = body.as_mut() {
+                    body.push_str(&text.decode().map_err(xml_encoding_error)?);
+                }
+            }
+            Event::GeneralRef(reference) => {
+                if let Some(body) = body.as_mut() {
+                    match reference.resolve_char_ref() {
+                        Ok(Some(ch)) => body.push(ch),
+                        _ => {
+                            let name = reference.decode().map_err(xml_encoding_error)?;
+                            match &*name {
+                                "lt" => body.push('<'),
+                                "gt" => body.push('>'),
+                                "amp" => body.push('&'),
+                                "quot" => body.push('"'),
+                                "apos" => body.push('\''),
+                                other => {
+                                    body.push('&');
+                                    body.push_str(other);
+                                    body.push(';');
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(cases)
+}
+
+fn open_junit_element(
+    element: &BytesStart<'_>,
+    suites: &mut Vec<Option<String>>,
+    case: &mut Option<ParsedTestCase>,
+    body: &mut Option<String>,
+) {
+    match element.local_name().as_ref() {
+        b"testsuite" => suites.push(xml_attribute(element, b"name")),
+        b"testcase" => {
+            let name = xml_attribute(element, b"name").unwrap_or_default();
+            *case = Some(ParsedTestCase {
+                suite: xml_attribute(element, b"classname")
+                    .or_else(|| suites.last().cloned().flatten()),
+                name,
+                file_path: xml_attribute(element, b"file"),
+                outcome: TestOutcome::Passed,
+                duration_ms: xml_attribute(element, b"time")
+                    .and_then(|time| time.parse::<f64>().ok())
+                    .map(seconds_to_millis),
+                message: None,
+            });
+        }
+        b"failure" | b"error" => {
+            if let Some(case) = case.as_mut() {
+                case.outcome = TestOutcome::Failed;
+                if case.message.is_none() {
+                    case.message = xml_attribute(element, b"message");
+                }
+                *body = Some(String::new());
+            }
+        }
+        b"skipped" => {
+            if let Some(case) = case.as_mut()
+                && case.outcome != TestOutcome::Failed
+            {
+                case.outcome = TestOutcome::Skipped;
+            }
+        }
+        _ => {}
+    }
+}
+
+fn close_junit_element(
+    name: &[u8],
+    suites: &mut Vec<Option<String>>,
+    case: &mut Option<ParsedTestCase>,
+    body: &mut Option<String>,
+    cases: &mut Vec<ParsedTestCase>,
+) {
+    match name {
+        b"testsuite" => {
+            suites.pop();
+        }
+        b"testcase" => {
+            if let Some(case) = case.take()
+                && !case.name.is_empty()
+            {
+                cases.push(case);
+            }
+        }
+        b"failure" | b"error" => {
+            if let (Some(case), Some(text)) = (case.as_mut(), body.take())
+                && case.message.is_none()
+            {
+                case.message = failure_summary(&text);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn xml_attribute(element: &BytesStart<'_>, name: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attribute| attribute.key.as_ref() == name)
+        .and_then(|attribute| attribute.unescape_value().ok())
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+fn xml_encoding_error(err: impl std::fmt::Display) -> AnalysisError {
+    AnalysisError::Message(format!("invalid JUnit XML: {err}"))
+}
+
+#[derive(Debug, Deserialize)]
+struct LibtestEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    event: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    exec_time: Option<f64>,
+    #[serde(default)]
+    stdout: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+/// libtest JSON events; nextest prefixes names with `<binary-id>$`.
+fn parse_libtest_json(content: &str) -> Result<Vec<ParsedTestCase>, AnalysisError> {
+    let mut cases = Vec::new();
+    for line in content
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with('{'))
+    {
+        let event = serde_json::from_str::<LibtestEvent>(line)?;
+        if event.kind != "test" {
+            continue;
+        }
+        let outcome = match event.event.as_str() {
+            "ok" => TestOutcome::Passed,
+            "failed" | "timeout" => TestOutcome::Failed,
+            "ignored" => TestOutcome::Skipped,
+            _ => continue,
+        };
+        let (suite, name) = match event.name.split_once('$') {
+            Some((binary, name)) => (Some(binary.to_owned()), name.to_owned()),
+            None => (None, event.name.clone()),
+        };
+        cases.push(ParsedTestCase {
+            suite,
+            name,
+            file_path: None,
+            outcome,
+            duration_ms: event.exec_time.map(seconds_to_millis),
+            message: (outcome == TestOutcome::Failed)
+                .then(|| event.message.or(event.stdout))
+                .flatten()
+                .and_then(|text| failure_summary(&text)),
+        });
+    }
+    Ok(cases)
+}
+
+#[derive(Debug, Deserialize)]
+struct PytestReport {
+    #[serde(default)]
+    tests: Vec<PytestTest>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PytestTest {
+    nodeid: String,
+    outcome: String,
+    #[serde(default)]
+    setup: Option<PytestStage>,
+    #[serde(default)]
+    call: Option<PytestStage>,
+    #[serde(default)]
+    teardown: Option<PytestStage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PytestStage {
+    #[serde(default)]
+    duration: Option<f64>,
+    #[serde(default)]
+    crash: Option<PytestCrash>,
+    #[serde(default)]
+    longrepr: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PytestCrash {
+    #[serde(default)]
+    message: Option<String>,
+}
+
+fn parse_pytest_json(content: &str) -> Result<Vec<ParsedTestCase>, AnalysisError> {
+    let report = serde_json::from_str::<PytestReport>(content)?;
+    let mut cases = Vec::new();
+    for test in report.tests {
+        let outcome = match test.outcome.as_str() {
+            "passed" | "xfailed" | "xpassed" => TestOutcome::Passed,
+            "failed" | "error" => TestOutcome::Failed,
+            "skipped" => TestOutcome::Skipped,
+            _ => continue,
+        };
+        let stages = [&test.setup, &test.call, &test.teardown];
+        let duration = stages
+            .iter()
+            .filter_map(|stage| stage.as_ref()?.duration)
+            .sum::<f64>();
+        let message = (outcome == TestOutcome::Failed)
+            .then(|| {
+                stages.iter().find_map(|stage| {
+                    let stage = stage.as_ref()?;
+                    stage
+                        .crash
+                        .as_ref()
+                        .and_then(|crash| crash.message.clone())
+                        .or_else(|| match stage.longrepr.as_ref()? {
+                            Value::String(text) => Some(text.clone()),
+                            _ => None,
+                        })
+                })
+            })
+            .flatten()
+            .and_then(|text| failure_summary(&text));
+        let (file_path, name) = match test.nodeid.split_once("::") {
+            Some((file_path, name)) => (Some(file_path.to_owned()), name.to_owned()),
+            None => (None, test.nodeid.clone()),
+        };
+        cases.push(ParsedTestCase {
+            suite: None,
+            name,
+            file_path,
+            outcome,
+            duration_ms: Some(seconds_to_millis(duration)),
+            message,
+        });
+    }
+    Ok(cases)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JestReport {
+    #[serde(default)]
+    test_results: Vec<JestFileResult>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JestFileResult {
+    name: String,
+    #[serde(default)]
+    assertion_results: Vec<JestAssertion>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JestAssertion {
+    #[serde(default)]
+    ancestor_titles: Vec<String>,
+    title: String,
+    status: String,
+    #[serde(default)]
+    duration: Option<f64>,
+    #[serde(default)]
+    failure_messages: Vec<String>,
+}
+
+fn parse_jest_json(content: &str) -> Result<Vec<ParsedTestCase>, AnalysisError> {
+    let report = serde_json::from_str::<JestReport>(content)?;
+    let mut cases = Vec::new();
+    for file in report.test_results {
+        for assertion in file.assertion_results {
+            let outcome = match assertion.status.as_str() {
+                "passed" => TestOutcome::Passed,
+                "failed" => TestOutcome::Failed,
+                "pending" | "skipped" | "todo" | "disabled" | "focused" => TestOutcome::Skipped,
+                _ => continue,
+            };
+            cases.push(ParsedTestCase {
+                suite: (!assertion.ancestor_titles.is_empty())
+                    .then(|| assertion.ancestor_titles.join(" ")),
+                name: assertion.title,
+                file_path: Some(file.name.clone()),
+                outcome,
+                duration_ms: assertion.duration.map(|duration| duration.round() as i64),
+                message: assertion
+                    .failure_messages
+                    .first()
+                    .and_then(|text| failure_summary(text)),
+            });
+        }
+    }
+    Ok(cases)
+}
+
+/// The panic line (and the one after it) from Rust test output, otherwise
+/// the trimmed text, capped at `MAX_MESSAGE_CHARS`.
+fn failure_summary(text: &str) -> Option<String> {
+    let lines = text.lines().map(str::trim).collect::<Vec<_>>();
+    let summary = match lines.iter().position(|line| line.contains("panicked at")) {
+        Some(index) => lines[index..]
+            .iter()
+            .filter(|line| !line.is_empty())
+            .take(2)
+            .copied()
+            .collect::<Vec<_>>()
+            .join("\n"),
+        None => text.trim().to_owned(),
+    };
+    (!summary.is_empty()).then(|| summary.chars().take(MAX_MESSAGE_CHARS).collect())
+}
+
+fn seconds_to_millis(seconds: f64) -> i64 {
+    (seconds.max(0.0) * 1000.0).round() as i64
+}
+
+/// Links each case to the indexed test it ran. A case is linked only when
+/// exactly one intent matches, narrowing by file and then by the symbol's
+/// qualified name.
+fn link_test_cases(
+    run_id: &str,
+    cases: &[ParsedTestCase],
+    intents: &[TestIntentRecord],
+    qualified_names: &HashMap<String, String>,
+    workspace: &str,
+) -> Vec<TestResultRecord> {
+    let mut by_name = HashMap::<&str, Vec<&TestIntentRecord>>::new();
+    for intent in intents.iter().filter(|intent| intent.symbol_id.is_some()) {
+        by_name
+            .entry(intent.test_name.as_str())
+            .or_default()
+            .push(intent);
+        by_name
+            .entry(intent.intent_text.as_str())
+            .or_default()
+            .push(intent);
+    }
+
+    let mut results = BTreeMap::<String, TestResultRecord>::new();
+    for case in cases {
+        let file_path = case
+            .file_path
+            .as_deref()
+            .map(|path| relative_path(path, workspace));
+        let leaf = test_leaf_name(&case.name);
+        let title = case.name.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        let mut seen = HashSet::new();
+        let mut candidates = [leaf, title.as_str()]
+            .into_iter()
+            .flat_map(|key| by_name.get(key).into_iter().flatten().copied())
+            .filter(|intent| seen.insert(intent.intent_id.as_str()))
+            .collect::<Vec<_>>();
+        if let Some(file_path) = file_path.as_deref() {
+            candidates.retain(|intent| intent.file_path == file_path);
+        }
+        if candidates.len() > 1 {
+            let path = case.name.split('[').next().unwrap_or(&case.name);
+            let narrowed = candidates
+                .iter()
+                .copied()
+                .filter(|intent| {
+                    intent
+                        .symbol_id
+                        .as_ref()
+                        .and_then(|symbol_id| qualified_names.get(symbol_id))
+                        .is_some_and(|qualified_name| qualified_name.ends_with(path))
+                })
+                .collect::<Vec<_>>();
+            if !narrowed.is_empty() {
+                candidates = narrowed;
+            }
+        }
+        if candidates.len() > 1
+            && let Some(suite) = case.suite.as_deref()
+        {
+            let narrowed = candidates
+                .iter()
+                .copied()
+                .filter(|intent| suite_matches(suite, intent))
+                .collect::<Vec<_>>();
+            if !narrowed.is_empty() {
+                candidates = narrowed;
+            }
+        }
+        let linked = match candidates.as_slice() {
+            [intent] => Some(*intent),
+            _ => None,
+        };
+
+        let file_path = file_path.or_else(|| linked.map(|intent| intent.file_path.clone()));
+        let scope = file_path.as_deref().or(case.suite.as_deref());
+        let test_key = match scope {
+            Some(scope) => format!("{scope}::{}", case.name),
+            None => case.name.clone(),
+        };
+        // Retried cases appear more than once; the last attempt wins.
+        results.insert(
+            test_key.clone(),
+            TestResultRecord {
+                run_id: run_id.to_owned(),
+                test_key,
+                test_name: case.name.clone(),
+                file_path,
+                symbol_id: linked.and_then(|intent| intent.symbol_id.clone()),
+                outcome: case.outcome.as_str().to_owned(),
+                duration_ms: case.duration_ms,
+                message: case.message.clone(),
+            },
+        );
+    }
+    results.into_values().collect()
+}
+
+/// Whether a jest `describe` title, JUnit classname (`tests.test_cart.Cart`)
+/// or libtest binary (`parser::lib`) points at the intent's test.
+fn suite_matches(suite: &str, intent: &TestIntentRecord) -> bool {
+    if intent.group_label.as_deref() == Some(suite) {
+        return true;
+    }
+    let stem = intent
+        .file_path
+        .rsplit_once('.')
+        .map_or(intent.file_path.as_str(), |(stem, _)| stem);
+    if suite.replace('.', "/").starts_with(stem) {
+        return true;
+    }
+    let crate_name = suite.split("::").next().unwrap_or(suite).replace('-', "_");
+    intent
+        .file_path
+        .split('/')
+        .any(|segment| segment.replace('-', "_") == crate_name)
+}
+
+/// `tests::parses_input` -> `parses_input`, `test_add[1-2]` -> `test_add`.
+fn test_leaf_name(name: &str) -> &str {
+    let leaf = name.rsplit("::").next().unwrap_or(name);
+    leaf.split('[').next().unwrap_or(leaf).trim()
+}
+
+fn relative_path(path: &str, workspace: &str) -> String {
+    let path = normalize_path(path.trim());
+    let relative = path
+        .strip_prefix(workspace)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .unwrap_or(path.as_str());
+    relative.trim_start_matches("./").to_owned()
+}
+
+/// `results` must be grouped by test and ordered oldest run first, as
+/// returned by `list_recent_test_results`.
+fn score_flakiness(
+    results: &[TestResultRecord],
+    commits: &HashMap<String, String>,
+    min_runs: usize,
+) -> Vec<FlakyTest> {
+    let mut by_test = BTreeMap::<&str, Vec<&TestResultRecord>>::new();
+    for result in results {
+        by_test
+            .entry(result.test_key.as_str())
+            .or_default()
+            .push(result);
+    }
+
+    let mut scored = Vec::new();
+    for (test_key, history) in by_test {
+        let observed = history
+            .iter()
+            .filter_map(|result| {
+                let outcome = TestOutcome::parse(result.outcome.as_str())?;
+                (outcome != TestOutcome::Skipped).then_some((*result, outcome))
+            })
+            .collect::<Vec<_>>();
+        let Some(&(last, last_outcome)) = observed.last() else {
+            continue;
+        };
+        if observed.len() < min_runs.max(1) {
+            continue;
+        }
+
+        let failed = observed
+            .iter()
+            .filter(|(_, outcome)| *outcome == TestOutcome::Failed)
+            .count();
+        let flips = observed
+            .windows(2)
+            .filter(|pair| pair[0].1 != pair[1].1)
+            .count();
+        let mut outcomes_by_commit = HashMap::<&str, HashSet<TestOutcome>>::new();
+        for (result, outcome) in &observed {
+            if let Some(commit) = commits.get(&result.run_id) {
+                outcomes_by_commit
+                    .entry(commit.as_str())
+                    .or_default()
+                    .insert(*outcome);
+            }
+        }
+        let inconsistent_commits = outcomes_by_commit
+            .values()
+            .filter(|outcomes| outcomes.len() > 1)
+            .count();
+
+        let flip_rate = if observed.len() > 1 {
+            flips as f32 / (observed.len() - 1) as f32
+        } else {
+            0.0
+        };
+        // A test that both passes and fails on one commit is flaky by
+        // definition; flips alone also catch real breakages that were fixed.
+        let flakiness = if outcomes_by_commit.is_empty() {
+            flip_rate
+        } else {
+            0.5 * flip_rate + 0.5 * inconsistent_commits as f32 / outcomes_by_commit.len() as f32
+        };
+
+        scored.push(FlakyTest {
+            test_key: test_key.to_owned(),
+            test_name: last.test_name.clone(),
+            file_path: last.file_path.clone(),
+            symbol_id: last.symbol_id.clone(),
+            runs: observed.len(),
+            passed: observed.len() - failed,
+            failed,
+            flips,
+            inconsistent_commits,
+            flakiness: flakiness.clamp(0.0, 1.0),
+            last_outcome,
+        });
+    }
+    scored
+}
+
+/// Groups the causal candidates of every failure by symbol and commit.
+fn rank_suspects(failures: &[FailureTriage]) -> Vec<TriageSuspect> {
+    let mut suspects = BTreeMap::<(String, String), TriageSuspect>::new();
+    for failure in failures {
+        let weight = 1.0 - failure.flakiness.unwrap_or(0.0).clamp(0.0, 1.0);
+        for entry in &failure.causal_chain {
+            let suspect = suspects
+                .entry((entry.symbol_id.clone(), entry.change.commit.clone()))
+                .or_insert_with(|| TriageSuspect {
+                    rank: 0,
+                    score: 0.0,
+                    symbol_id: entry.symbol_id.clone(),
+                    symbol_name: entry.symbol_name.clone(),
+                    file: entry.file.clone(),
+                    commit: entry.change.commit.clone(),
+                    author: entry.change.author.clone(),
+                    date: entry.change.date.clone(),
+                    failing_tests: Vec::new(),
+                });
+            suspect.score += entry.causal_score * weight;
+            if !suspect.failing_tests.contains(&failure.test_key) {
+                suspect.failing_tests.push(failure.test_key.clone());
+            }
+        }
+    }
+
+    let mut ranked = suspects.into_values().collect::<Vec<_>>();
+    ranked.sort_by(|left, right| {
+        right
+            .score
+            .total_cmp(&left.score)
+            .then_with(|| right.failing_tests.len().cmp(&left.failing_tests.len()))
+            .then_with(|| left.symbol_id.cmp(&right.symbol_id))
+    });
+    for (index, suspect) in ranked.iter_mut().enumerate() {
+        suspect.rank = index as u32 + 1;
+    }
+    ranked
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|value| value.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::causal::{CausalChainChange, CausalChainCoupling, CausalChainSirDiff};
+
+    use super::*;
+
+    fn intent(file_path: &str, test_name: &str, text: &str, symbol_id: &str) -> TestIntentRecord {
+        TestIntentRecord {
+            intent_id: format!("{file_path}:{test_name}:{text}"),
+            file_path: file_path.to_owned(),
+            test_name: test_name.to_owned(),
+            intent_text: text.to_owned(),
+            group_label: None,
+            language: "rust".to_owned(),
+            symbol_id: Some(symbol_id.to_owned()),
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn result(run_id: &str, outcome: &str) -> TestResultRecord {
+        TestResultRecord {
+            run_id: run_id.to_owned(),
+            test_key: "tests/api.rs::retries".to_owned(),
+            test_name: "retries".to_owned(),
+            file_path: Some("tests/api.rs".to_owned()),
+            symbol_id: None,
+            outcome: outcome.to_owned(),
+            duration_ms: None,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn detects_and_parses_each_report_format() {
+        let junit = r#"<?xml version="1.0"?>
+<testsuites>
+  <testsuite name="parser">
+    <testcase classname="parser" name="tests::parses_input" time="0.012"/>
+    <testcase classname="parser" name="tests::rejects_garbage" time="0.5">
+      <failure message="assertion failed: x &lt; 2">stack</failure>
+    </testcase>
+    <testcase name="tests::slow"><skipped/></testcase>
+  </testsuite>
+</testsuites>"#;
+        assert_eq!(
+            TestReportFormat::detect(junit),
+            Some(TestReportFormat::Junit)
+        );
+        let cases = parse_test_report(TestReportFormat::Junit, junit).expect("parse junit");
+        assert_eq!(cases.len(), 3);
+        assert_eq!(cases[0].duration_ms, Some(12));
+        assert_eq!(cases[1].outcome, TestOutcome::Failed);
+        assert_eq!(cases[1].message.as_deref(), Some("assertion failed: x < 2"));
+        assert_eq!(cases[2].suite.as_deref(), Some("parser"));
+        assert_eq!(cases[2].outcome, TestOutcome::Skipped);
+
+        let nextest = concat!(
+            r#"{"type":"suite","event":"started","test_count":2}"#,
+            "\n",
+            r#"{"type":"test","event":"ok","name":"parser::lib$tests::parses_input","exec_time":0.003}"#,
+            "\n",
+            r#"{"type":"test","event":"failed","name":"parser::lib$tests::rejects","stdout":"running\nthread 'tests::rejects' panicked at src/lib.rs:9:5:\nboom\n"}"#,
+        );
+        assert_eq!(
+            TestReportFormat::detect(nextest),
+            Some(TestReportFormat::Nextest)
+        );
+        let cases = parse_test_report(TestReportFormat::Nextest, nextest).expect("parse nextest");
+        assert_eq!(cases[0].suite.as_deref(), Some("parser::lib"));
+        assert_eq!(cases[0].name, "tests::parses_input");
+        assert_eq!(
+            cases[1].message.as_deref(),
+            Some("thread 'tests::rejects' panicked at src/lib.rs:9:5:\nboom")
+        );
+
+        let pytest = r#"{"tests":[{"nodeid":"tests/test_cart.py::TestCart::test_total[2]","outcome":"failed",
+            "call":{"duration":0.25,"crash":{"message":"AssertionError: 3 != 4"}}}]}"#;
+        assert_eq!(
+            TestReportFormat::detect(pytest),
+            Some(TestReportFormat::Pytest)
+        );
+        let cases = parse_test_report(TestReportFormat::Pytest, pytest).expect("parse pytest");
+        assert_eq!(cases[0].file_path.as_deref(), Some("tests/test_cart.py"));
+        assert_eq!(cases[0].name, "TestCart::test_total[2]");
+        assert_eq!(cases[0].duration_ms, Some(250));
+
+        let jest = r#"{"numTotalTests":1,"testResults":[{"name":"/repo/web/api.test.ts",
+            "assertionResults":[{"ancestorTitles":["api"],"title":"loads user","status":"pending"}]}]}"#;
+        assert_eq!(TestReportFormat::detect(jest), Some(TestReportFormat::Jest));
+        let cases = parse_test_report(TestReportFormat::Jest, jest).expect("parse jest");
+        assert_eq!(cases[0].suite.as_deref(), Some("api"));
+        assert_eq!(cases[0].outcome, TestOutcome::Skipped);
+    }
+
+    #[test]
+    fn cases_link_to_a_single_matching_test_intent() {
+        let intents = vec![
+            intent(
+                "crates/parser/src/lib.rs",
+                "parses_input",
+                "parses input",
+                "sym-a",
+            ),
+            intent(
+                "crates/lexer/src/lib.rs",
+                "parses_input",
+                "parses input",
+                "sym-b",
+            ),
+            intent("tests/test_cart.py", "test_total", "total", "sym-c"),
+            TestIntentRecord {
+                language: "typescript".to_owned(),
+                group_label: Some("api".to_owned()),
+                ..intent("web/api.test.ts", "it", "loads user", "sym-d")
+            },
+        ];
+        let qualified_names = HashMap::from([
+            ("sym-a".to_owned(), "parser::tests::parses_input".to_owned()),
+            ("sym-b".to_owned(), "lexer::tests::parses_input".to_owned()),
+        ]);
+        let case = |suite: Option<&str>, name: &str, file_path: Option<&str>| ParsedTestCase {
+            suite: suite.map(str::to_owned),
+            name: name.to_owned(),
+            file_path: file_path.map(str::to_owned),
+            outcome: TestOutcome::Failed,
+            duration_ms: None,
+            message: None,
+        };
+        let results = link_test_cases(
+            "run-1",
+            &[
+                case(Some("parser"), "tests::parses_input", None),
+                case(Some("other"), "parses_input", None),
+                case(None, "TestCart::test_total[2]", Some("tests/test_cart.py")),
+                case(Some("api"), "loads user", Some("/repo/web/api.test.ts")),
+            ],
+            &intents,
+            &qualified_names,
+            "/repo",
+        );
+        let links = results
+            .iter()
+            .map(|result| (result.test_key.as_str(), result.symbol_id.as_deref()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            links,
+            vec![
+                (
+                    "crates/parser/src/lib.rs::tests::parses_input",
+                    Some("sym-a")
+                ),
+                ("other::parses_input", None),
+                ("tests/test_cart.py::TestCart::test_total[2]", Some("sym-c")),
+                ("web/api.test.ts::loads user", Some("sym-d")),
+            ]
+        );
+    }
+
+    #[test]
+    fn flakiness_rewards_flips_and_same_commit_disagreement() {
+        let results = vec![
+            result("run-1", "passed"),
+            result("run-2", "failed"),
+            result("run-3", "skipped"),
+            result("run-4", "passed"),
+            result("run-5", "failed"),
+        ];
+        let commits = HashMap::from([
+            ("run-1".to_owned(), "c1".to_owned()),
+            ("run-2".to_owned(), "c1".to_owned()),
+            ("run-4".to_owned(), "c2".to_owned()),
+            ("run-5".to_owned(), "c3".to_owned()),
+        ]);
+
+        let scored = score_flakiness(&results, &commits, 3);
+        assert_eq!(scored.len(), 1);
+        let test = &scored[0];
+        assert_eq!((test.runs, test.passed, test.failed), (4, 2, 2));
+        assert_eq!(test.flips, 3);
+        assert_eq!(test.inconsistent_commits, 1);
+        assert!((test.flakiness - (0.5 + 0.5 / 3.0)).abs() < 1e-6);
+        assert_eq!(test.last_outcome, TestOutcome::Failed);
+
+        assert!(score_flakiness(&results, &commits, 5).is_empty());
+        let without_commits = score_flakiness(&results, &HashMap::new(), 3);
+        assert!((without_commits[0].flakiness - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn suspects_shared_by_failures_rank_first() {
+        let entry = |symbol_id: &str, commit: &str, causal_score: f32| CausalChainEntry {
+            rank: 1,
+            causal_score,
+            symbol_id: symbol_id.to_owned(),
+            symbol_name: symbol_id.to_owned(),
+            file: "src/lib.rs".to_owned(),
+            dependency_path: Vec::new(),
+            depth: 1,
+            change: CausalChainChange {
+                commit: commit.to_owned(),
+                author: "dev".to_owned(),
+                date: "2026-01-01".to_owned(),
+                change_magnitude: 0.5,
+                sir_diff: CausalChainSirDiff {
+                    purpose_changed: false,
+                    purpose_before: String::new(),
+                    purpose_after: String::new(),
+                    edge_cases_added: Vec::new(),
+                    edge_cases_removed: Vec::new(),
+                    dependencies_added: Vec::new(),
+                    dependencies_removed: Vec::new(),
+                },
+            },
+            coupling: CausalChainCoupling {
+                fused_score: 0.0,
+                coupling_type: "structural".to_owned(),
+            },
+        };
+        let failure = |test_key: &str, flakiness: Option<f32>, chain| FailureTriage {
+            test_key: test_key.to_owned(),
+            test_name: test_key.to_owned(),
+            file_path: None,
+            symbol_id: None,
+            message: None,
+            flakiness,
+            causal_chain: chain,
+            notes: Vec::new(),
+        };
+
+        let suspects = rank_suspects(&[
+            failure(
+                "a",
+                None,
+                vec![entry("shared", "c1", 0.4), entry("solo", "c2", 0.6)],
+            ),
+            failure("b", None, vec![entry("shared", "c1", 0.4)]),
+            failure("flaky", Some(1.0), vec![entry("noise", "c3", 0.9)]),
+        ]);
+        assert_eq!(
+            suspects
+                .iter()
+                .map(|suspect| (suspect.rank, suspect.symbol_id.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, "shared"), (2, "solo"), (3, "noise")]
+        );
+        assert_eq!(suspects[0].failing_tests, vec!["a", "b"]);
+        assert_eq!(suspects[2].score, 0.0);
+    }
+}
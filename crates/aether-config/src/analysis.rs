@@ -65,6 +65,43 @@ impl Default for DriftConfig {
     }
 }
 
+/// Extra entry points for `dead-code`, on top of mains, tests, route
+/// handlers, exported symbols and (optionally) the public API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeadCodeConfig {
+    #[serde(default = "default_dead_code_public_api_roots")]
+    pub public_api_roots: bool,
+    /// Qualified-name globs treated as roots.
+    #[serde(default)]
+    pub roots: Vec<String>,
+    /// File globs whose symbols are all roots.
+    #[serde(default)]
+    pub root_paths: Vec<String>,
+    /// File globs left out of the report.
+    #[serde(default)]
+    pub ignore_paths: Vec<String>,
+}
+
+impl Default for DeadCodeConfig {
+    fn default() -> Self {
+        Self {
+            public_api_roots: default_dead_code_public_api_roots(),
+            roots: Vec::new(),
+            root_paths: Vec::new(),
+            ignore_paths: Vec::new(),
+        }
+    }
+}
+
+/// Runtime profiles ingested with `aetherd profile ingest`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ProfilesConfig {
+    /// Weight PageRank and betweenness by sampled call counts in health
+    /// scoring, blast radius ordering and task context ranking.
+    #[serde(default)]
+    pub execution_weighted: bool,
+}
+
 pub(crate) fn default_coupling_enabled() -> bool {
     true
 }
@@ -120,3 +157,7 @@ fn default_drift_auto_analyze() -> bool {
 pub(crate) fn default_drift_hub_percentile() -> u32 {
     DEFAULT_DRIFT_HUB_PERCENTILE
 }
+
+fn default_dead_code_public_api_roots() -> bool {
+    true
+}
@@ -52,6 +52,7 @@ pub const DEFAULT_HEALTH_SCORE_CHURN_30D_HIGH: usize = 15;
 pub const DEFAULT_HEALTH_SCORE_CHURN_90D_HIGH: usize = 30;
 pub const DEFAULT_HEALTH_SCORE_AUTHOR_COUNT_HIGH: usize = 6;
 pub const DEFAULT_HEALTH_SCORE_BLAME_AGE_SPREAD_HIGH_SECS: u64 = 15_552_000;
+pub const DEFAULT_HEALTH_SCORE_BUS_FACTOR_HEALTHY: usize = 3;
 pub const DEFAULT_HEALTH_SCORE_DRIFT_DENSITY_HIGH: f32 = 0.30;
 pub const DEFAULT_HEALTH_SCORE_STALE_SIR_HIGH: f32 = 0.40;
 pub const DEFAULT_HEALTH_SCORE_TEST_GAP_HIGH: f32 = 0.50;
@@ -24,6 +24,14 @@ pub struct ContinuousConfig {
     pub auto_submit: bool,
     #[serde(default = "default_requeue_pass")]
     pub requeue_pass: String,
+    /// Incremental PageRank/Louvain updates allowed before the persisted
+    /// graph state is recomputed from scratch.
+    #[serde(default = "default_graph_full_recompute_every")]
+    pub graph_full_recompute_every: u32,
+    /// Fraction of edges that may change before an incremental update gives
+    /// way to a full recompute.
+    #[serde(default = "default_graph_max_incremental_delta")]
+    pub graph_max_incremental_delta: f64,
 }
 
 impl Default for ContinuousConfig {
@@ -40,6 +48,8 @@ impl Default for ContinuousConfig {
             max_requeue_per_run: default_max_requeue(),
             auto_submit: false,
             requeue_pass: default_requeue_pass(),
+            graph_full_recompute_every: default_graph_full_recompute_every(),
+            graph_max_incremental_delta: default_graph_max_incremental_delta(),
         }
     }
 }
@@ -79,3 +89,11 @@ fn default_max_requeue() -> usize {
 fn default_requeue_pass() -> String {
     "triage".to_owned()
 }
+
+fn default_graph_full_recompute_every() -> u32 {
+    24
+}
+
+fn default_graph_max_incremental_delta() -> f64 {
+    0.2
+}
@@ -4,15 +4,16 @@ use crate::constants::{
     DEFAULT_HEALTH_DRIFT_WEIGHT, DEFAULT_HEALTH_NO_SIR_WEIGHT, DEFAULT_HEALTH_PAGERANK_WEIGHT,
     DEFAULT_HEALTH_RECENCY_WEIGHT, DEFAULT_HEALTH_SCORE_AUTHOR_COUNT_HIGH,
     DEFAULT_HEALTH_SCORE_BLAME_AGE_SPREAD_HIGH_SECS, DEFAULT_HEALTH_SCORE_BOUNDARY_LEAKAGE_HIGH,
-    DEFAULT_HEALTH_SCORE_CHURN_30D_HIGH, DEFAULT_HEALTH_SCORE_CHURN_90D_HIGH,
-    DEFAULT_HEALTH_SCORE_DEAD_FEATURE_FAIL, DEFAULT_HEALTH_SCORE_DEAD_FEATURE_WARN,
-    DEFAULT_HEALTH_SCORE_DRIFT_DENSITY_HIGH, DEFAULT_HEALTH_SCORE_FILE_LOC_FAIL,
-    DEFAULT_HEALTH_SCORE_FILE_LOC_WARN, DEFAULT_HEALTH_SCORE_INTERNAL_DEP_FAIL,
-    DEFAULT_HEALTH_SCORE_INTERNAL_DEP_WARN, DEFAULT_HEALTH_SCORE_STALE_REF_FAIL,
-    DEFAULT_HEALTH_SCORE_STALE_REF_WARN, DEFAULT_HEALTH_SCORE_STALE_SIR_HIGH,
-    DEFAULT_HEALTH_SCORE_TEST_GAP_HIGH, DEFAULT_HEALTH_SCORE_TODO_DENSITY_FAIL,
-    DEFAULT_HEALTH_SCORE_TODO_DENSITY_WARN, DEFAULT_HEALTH_SCORE_TRAIT_METHOD_FAIL,
-    DEFAULT_HEALTH_SCORE_TRAIT_METHOD_WARN, DEFAULT_HEALTH_TEST_GAP_WEIGHT,
+    DEFAULT_HEALTH_SCORE_BUS_FACTOR_HEALTHY, DEFAULT_HEALTH_SCORE_CHURN_30D_HIGH,
+    DEFAULT_HEALTH_SCORE_CHURN_90D_HIGH, DEFAULT_HEALTH_SCORE_DEAD_FEATURE_FAIL,
+    DEFAULT_HEALTH_SCORE_DEAD_FEATURE_WARN, DEFAULT_HEALTH_SCORE_DRIFT_DENSITY_HIGH,
+    DEFAULT_HEALTH_SCORE_FILE_LOC_FAIL, DEFAULT_HEALTH_SCORE_FILE_LOC_WARN,
+    DEFAULT_HEALTH_SCORE_INTERNAL_DEP_FAIL, DEFAULT_HEALTH_SCORE_INTERNAL_DEP_WARN,
+    DEFAULT_HEALTH_SCORE_STALE_REF_FAIL, DEFAULT_HEALTH_SCORE_STALE_REF_WARN,
+    DEFAULT_HEALTH_SCORE_STALE_SIR_HIGH, DEFAULT_HEALTH_SCORE_TEST_GAP_HIGH,
+    DEFAULT_HEALTH_SCORE_TODO_DENSITY_FAIL, DEFAULT_HEALTH_SCORE_TODO_DENSITY_WARN,
+    DEFAULT_HEALTH_SCORE_TRAIT_METHOD_FAIL, DEFAULT_HEALTH_SCORE_TRAIT_METHOD_WARN,
+    DEFAULT_HEALTH_TEST_GAP_WEIGHT,
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -94,6 +95,8 @@ pub struct HealthScoreConfig {
     pub author_count_high: usize,
     #[serde(default = "default_health_score_blame_age_spread_high_secs")]
     pub blame_age_spread_high_secs: u64,
+    #[serde(default = "default_health_score_bus_factor_healthy")]
+    pub bus_factor_healthy: usize,
     #[serde(default = "default_health_score_drift_density_high")]
     pub drift_density_high: f32,
     #[serde(default = "default_health_score_stale_sir_high")]
@@ -130,6 +133,7 @@ impl Default for HealthScoreConfig {
             churn_90d_high: default_health_score_churn_90d_high(),
             author_count_high: default_health_score_author_count_high(),
             blame_age_spread_high_secs: default_health_score_blame_age_spread_high_secs(),
+            bus_factor_healthy: default_health_score_bus_factor_healthy(),
             drift_density_high: default_health_score_drift_density_high(),
             stale_sir_high: default_health_score_stale_sir_high(),
             test_gap_high: default_health_score_test_gap_high(),
@@ -238,6 +242,10 @@ pub(crate) fn default_health_score_blame_age_spread_high_secs() -> u64 {
     DEFAULT_HEALTH_SCORE_BLAME_AGE_SPREAD_HIGH_SECS
 }
 
+pub(crate) fn default_health_score_bus_factor_healthy() -> usize {
+    DEFAULT_HEALTH_SCORE_BUS_FACTOR_HEALTHY
+}
+
 pub(crate) fn default_health_score_drift_density_high() -> f32 {
     DEFAULT_HEALTH_SCORE_DRIFT_DENSITY_HIGH
 }
@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::root::AetherConfig;
+
+/// Extensions handled by the built-in grammars; plugins cannot claim them.
+pub const BUILTIN_LANGUAGE_EXTENSIONS: &[&str] = &["rs", "ts", "tsx", "js", "jsx", "py", "pyi"];
+
+/// Tree-sitter grammar loaded at startup (`[[languages]]`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LanguagePluginConfig {
+    pub name: String,
+    /// File extensions, without the leading dot.
+    pub extensions: Vec<String>,
+    /// Compiled grammar shared library, absolute or relative to the workspace.
+    pub grammar: String,
+    /// Exported language function; defaults to `tree_sitter_<name>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Directory holding `symbols.scm`, `edges.scm` and optionally `test_intents.scm`.
+    pub queries: String,
+}
+
+impl LanguagePluginConfig {
+    pub fn language_symbol(&self) -> String {
+        self.symbol
+            .as_deref()
+            .map(str::trim)
+            .filter(|symbol| !symbol.is_empty())
+            .map(str::to_owned)
+            .unwrap_or_else(|| format!("tree_sitter_{}", self.name.replace('-', "_")))
+    }
+
+    /// Declared extensions, normalized and without built-in ones.
+    pub fn plugin_extensions(&self) -> Vec<String> {
+        self.extensions
+            .iter()
+            .map(|extension| normalize_extension(extension))
+            .filter(|extension| !extension.is_empty())
+            .filter(|extension| !BUILTIN_LANGUAGE_EXTENSIONS.contains(&extension.as_str()))
+            .collect()
+    }
+
+    pub fn is_wasm_grammar(&self) -> bool {
+        self.grammar.trim().to_ascii_lowercase().ends_with(".wasm")
+    }
+}
+
+pub fn is_valid_language_name(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|ch| ch.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_' || ch == '-')
+}
+
+/// Returns the loadable `[[languages]]` declarations.
+///
+/// Entries with invalid names, missing paths, WASM grammars, no usable
+/// extensions and later duplicate names are skipped; all are reported by
+/// `validate_config`.
+pub fn declared_language_plugins(config: &AetherConfig) -> Vec<LanguagePluginConfig> {
+    let mut seen = HashSet::new();
+    config
+        .languages
+        .iter()
+        .filter(|language| is_valid_language_name(language.name.as_str()))
+        .filter(|language| {
+            !language.grammar.trim().is_empty() && !language.queries.trim().is_empty()
+        })
+        .filter(|language| !language.is_wasm_grammar())
+        .filter(|language| !language.plugin_extensions().is_empty())
+        .filter(|language| seen.insert(language.name.clone()))
+        .cloned()
+        .collect()
+}
+
+fn normalize_extension(extension: &str) -> String {
+    extension
+        .trim()
+        .trim_start_matches('.')
+        .to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::declared_language_plugins;
+    use crate::root::parse_workspace_config_str;
+
+    #[test]
+    fn declared_languages_skip_unloadable_entries() {
+        let config = parse_workspace_config_str(
+            r#"
+[[languages]]
+name = "kotlin"
+extensions = [".kt", "KTS", "py"]
+grammar = "grammars/libtree-sitter-kotlin.so"
+queries = "grammars/kotlin"
+
+[[languages]]
+name = "ruby"
+extensions = ["rb"]
+grammar = "grammars/tree-sitter-ruby.wasm"
+queries = "grammars/ruby"
+
+[[languages]]
+name = "python3"
+extensions = ["py"]
+grammar = "grammars/libtree-sitter-python.so"
+queries = "grammars/python"
+
+[[languages]]
+name = "kotlin"
+extensions = ["kt"]
+grammar = "other/libtree-sitter-kotlin.so"
+symbol = "tree_sitter_kotlin_alt"
+queries = "other/kotlin"
+"#,
+        )
+        .expect("parse config");
+
+        let languages = declared_language_plugins(&config);
+        assert_eq!(languages.len(), 1);
+        assert_eq!(languages[0].name, "kotlin");
+        assert_eq!(languages[0].plugin_extensions(), vec!["kt", "kts"]);
+        assert_eq!(languages[0].language_symbol(), "tree_sitter_kotlin");
+        assert_eq!(
+            config.languages[3].language_symbol(),
+            "tree_sitter_kotlin_alt"
+        );
+    }
+}
@@ -6,16 +6,20 @@ mod contracts;
 mod embeddings;
 mod health;
 mod inference;
+mod language_plugins;
 mod normalize;
 mod planner;
+mod redaction;
 mod root;
 mod search;
 mod seismograph;
+mod sir_extensions;
 mod sir_quality;
 mod storage;
 mod validate;
 mod verification;
 mod watcher;
+mod workspace_roots;
 
 pub use analysis::*;
 pub use batch::*;
@@ -25,12 +29,16 @@ pub use contracts::*;
 pub use embeddings::*;
 pub use health::*;
 pub use inference::*;
+pub use language_plugins::*;
 pub use planner::*;
+pub use redaction::*;
 pub use root::*;
 pub use search::*;
 pub use seismograph::*;
+pub use sir_extensions::*;
 pub use sir_quality::*;
 pub use storage::*;
 pub use validate::*;
 pub use verification::*;
 pub use watcher::*;
+pub use workspace_roots::*;
@@ -44,8 +44,9 @@ use crate::{
     sir_quality::{
         SirQualityConfig, default_deep_concurrency, default_deep_confidence_threshold,
         default_deep_max_neighbors, default_deep_priority_threshold, default_deep_timeout_secs,
-        default_triage_concurrency, default_triage_confidence_threshold,
-        default_triage_priority_threshold, default_triage_timeout_secs,
+        default_grounding_min_score, default_triage_concurrency,
+        default_triage_confidence_threshold, default_triage_priority_threshold,
+        default_triage_timeout_secs,
     },
     verification::{
         default_verify_container_image, default_verify_container_runtime,
@@ -174,6 +175,8 @@ fn normalize_sir_quality_config(config: &mut SirQualityConfig) {
     if config.deep_timeout_secs == 0 {
         config.deep_timeout_secs = default_deep_timeout_secs();
     }
+    config.grounding_min_score =
+        normalize_probability(config.grounding_min_score, default_grounding_min_score());
 }
 
 fn normalize_patterns(patterns: Vec<String>) -> Vec<String> {
@@ -538,6 +541,24 @@ pub(crate) fn normalize_config(mut config: AetherConfig) -> AetherConfig {
     normalize_health_weights(&mut config.health);
     normalize_planner_config(&mut config.planner);
     normalize_health_score_config(&mut config.health_score);
+    for field in &mut config.sir_extensions {
+        field.name = field.name.trim().to_owned();
+        field.description = field.description.trim().to_owned();
+    }
+    for root in &mut config.roots {
+        root.name = root.name.trim().to_owned();
+        root.path = root.path.trim().to_owned();
+        root.exclude.retain(|pattern| !pattern.trim().is_empty());
+    }
+    for link in &mut config.root_links {
+        link.from_root = link
+            .from_root
+            .take()
+            .map(|name| name.trim().to_owned())
+            .filter(|name| !name.is_empty());
+        link.from_symbol = link.from_symbol.trim().to_owned();
+        link.to_symbol = link.to_symbol.trim().to_owned();
+    }
 
     let api_key_env = config.inference.api_key_env.trim();
     if api_key_env.is_empty() {
@@ -802,6 +823,7 @@ deep_max_neighbors = 0
 deep_concurrency = 0
 triage_thinking = " low "
 deep_thinking = " high "
+grounding_min_score = 4.0
 "#,
         );
 
@@ -822,6 +844,8 @@ deep_thinking = " high "
         );
         assert_eq!(config.sir_quality.deep_timeout_secs, 180);
         assert_eq!(config.sir_quality.deep_thinking.as_deref(), Some("high"));
+        assert!(config.sir_quality.grounding_check);
+        assert_eq!(config.sir_quality.grounding_min_score, 1.0);
     }
 
     #[test]
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactionSensitivity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl RedactionSensitivity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+/// A workspace-specific pattern (`[[redaction.patterns]]`), e.g. internal
+/// hostnames or customer identifiers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactionPatternConfig {
+    pub name: String,
+    /// Regular expression; when it has a capture group only the first group
+    /// is replaced.
+    pub pattern: String,
+    #[serde(default = "default_pattern_sensitivity")]
+    pub sensitivity: RedactionSensitivity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactionConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Also redact text sent to providers running on this machine (Ollama,
+    /// Candle). Cloud providers and batch JSONL are always covered.
+    #[serde(default)]
+    pub local_providers: bool,
+    #[serde(default = "default_true")]
+    pub emails: bool,
+    #[serde(default = "default_true")]
+    pub high_entropy: bool,
+    /// Shortest token the high-entropy detector considers.
+    #[serde(default = "default_min_entropy_length")]
+    pub min_entropy_length: usize,
+    /// Refuse the whole request when a finding is at least this sensitive;
+    /// unset only redacts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_at: Option<RedactionSensitivity>,
+    /// Append every redaction to `.aether/redaction/audit.jsonl`.
+    #[serde(default = "default_true")]
+    pub audit: bool,
+    /// Literal values that are never redacted, e.g. documented example keys.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<RedactionPatternConfig>,
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            local_providers: false,
+            emails: true,
+            high_entropy: true,
+            min_entropy_length: default_min_entropy_length(),
+            block_at: None,
+            audit: true,
+            allow: Vec::new(),
+            patterns: Vec::new(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_min_entropy_length() -> usize {
+    20
+}
+
+fn default_pattern_sensitivity() -> RedactionSensitivity {
+    RedactionSensitivity::High
+}
@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use aether_sir::{SirExtensionField, is_valid_extension_name};
+
+use crate::root::AetherConfig;
+
+/// Returns the usable `[[sir_extensions]]` declarations.
+///
+/// Entries with invalid names and later duplicates are skipped; both are
+/// reported by `validate_config`.
+pub fn declared_sir_extensions(config: &AetherConfig) -> Vec<SirExtensionField> {
+    let mut seen = HashSet::new();
+    config
+        .sir_extensions
+        .iter()
+        .filter(|field| is_valid_extension_name(field.name.as_str()))
+        .filter(|field| seen.insert(field.name.clone()))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_sir::SirExtensionKind;
+
+    use super::declared_sir_extensions;
+    use crate::root::parse_workspace_config_str;
+
+    #[test]
+    fn sir_extensions_parse_from_array_of_tables() {
+        let config = parse_workspace_config_str(
+            r#"
+[[sir_extensions]]
+name = "auth_requirements"
+type = "string_list"
+description = "Authentication or authorization checks callers must satisfy"
+
+[[sir_extensions]]
+name = "pii_handled"
+type = "boolean"
+description = "Whether personal data flows through this symbol"
+required = true
+"#,
+        )
+        .expect("parse config");
+
+        let fields = declared_sir_extensions(&config);
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name, "auth_requirements");
+        assert_eq!(fields[0].kind, SirExtensionKind::StringList);
+        assert!(!fields[0].required);
+        assert_eq!(fields[1].kind, SirExtensionKind::Boolean);
+        assert!(fields[1].required);
+    }
+
+    #[test]
+    fn declared_sir_extensions_skips_invalid_and_duplicate_names() {
+        let config = parse_workspace_config_str(
+            r#"
+[[sir_extensions]]
+name = "intent"
+type = "string"
+
+[[sir_extensions]]
+name = "concurrency_assumptions"
+type = "string"
+
+[[sir_extensions]]
+name = "concurrency_assumptions"
+type = "string_list"
+"#,
+        )
+        .expect("parse config");
+
+        let fields = declared_sir_extensions(&config);
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "concurrency_assumptions");
+        assert_eq!(fields[0].kind, SirExtensionKind::String);
+    }
+}
@@ -71,6 +71,17 @@ pub struct SirQualityConfig {
 
     #[serde(default = "default_deep_timeout_secs")]
     pub deep_timeout_secs: u64,
+
+    /// Cross-check SIR dependency claims against the static call graph.
+    #[serde(default = "default_grounding_check")]
+    pub grounding_check: bool,
+
+    /// SIRs scoring below this grounding score are queued for regeneration.
+    #[serde(default = "default_grounding_min_score")]
+    pub grounding_min_score: f64,
+
+    #[serde(default = "default_grounding_max_regenerations")]
+    pub grounding_max_regenerations: u32,
 }
 
 impl Default for SirQualityConfig {
@@ -99,6 +110,9 @@ impl Default for SirQualityConfig {
             deep_max_neighbors: default_deep_max_neighbors(),
             deep_concurrency: default_deep_concurrency(),
             deep_timeout_secs: default_deep_timeout_secs(),
+            grounding_check: default_grounding_check(),
+            grounding_min_score: default_grounding_min_score(),
+            grounding_max_regenerations: default_grounding_max_regenerations(),
         }
     }
 }
@@ -146,3 +160,15 @@ pub(crate) fn default_deep_concurrency() -> usize {
 pub(crate) fn default_deep_timeout_secs() -> u64 {
     180
 }
+
+pub(crate) fn default_grounding_check() -> bool {
+    true
+}
+
+pub(crate) fn default_grounding_min_score() -> f64 {
+    0.5
+}
+
+pub(crate) fn default_grounding_max_regenerations() -> u32 {
+    1
+}
@@ -1,11 +1,17 @@
+use std::collections::HashSet;
+
+use aether_sir::is_valid_extension_name;
+
 use crate::{
     constants::{DEFAULT_COHERE_API_KEY_ENV, DEFAULT_GEMINI_API_KEY_ENV},
     embeddings::EmbeddingProviderKind,
     inference::{InferenceProviderKind, TieredConfig},
+    language_plugins::{BUILTIN_LANGUAGE_EXTENSIONS, is_valid_language_name},
     root::AetherConfig,
     search::SearchRerankerKind,
     storage::GraphBackend,
     verification::{VerifyContainerConfig, VerifyMicrovmConfig, VerifyMode},
+    workspace_roots::is_valid_root_name,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -337,6 +343,180 @@ pub fn validate_config(config: &AetherConfig) -> Vec<ConfigWarning> {
                 ),
             });
         }
+
+        if !(continuous.graph_max_incremental_delta >= 0.0
+            && continuous.graph_max_incremental_delta <= 1.0)
+        {
+            warnings.push(ConfigWarning {
+                code: "continuous_graph_delta_invalid",
+                message: format!(
+                    "[continuous].graph_max_incremental_delta={} is out of range; expected 0.0..=1.0",
+                    continuous.graph_max_incremental_delta
+                ),
+            });
+        }
+    }
+
+    for pattern in &config.redaction.patterns {
+        if pattern.name.trim().is_empty() || pattern.pattern.trim().is_empty() {
+            warnings.push(ConfigWarning {
+                code: "redaction_pattern_incomplete",
+                message: format!(
+                    "[[redaction.patterns]] name='{}' needs both a name and a pattern; it will be ignored",
+                    pattern.name
+                ),
+            });
+        }
+    }
+    if config.redaction.high_entropy && config.redaction.min_entropy_length < 12 {
+        warnings.push(ConfigWarning {
+            code: "redaction_entropy_length_low",
+            message: format!(
+                "[redaction].min_entropy_length={} will redact ordinary identifiers; 16 or more is recommended",
+                config.redaction.min_entropy_length
+            ),
+        });
+    }
+
+    let mut extension_names = HashSet::new();
+    for field in &config.sir_extensions {
+        if !is_valid_extension_name(field.name.as_str()) {
+            warnings.push(ConfigWarning {
+                code: "sir_extension_name_invalid",
+                message: format!(
+                    "[[sir_extensions]] name='{}' must be a lowercase identifier that does not shadow a core SIR field; it will be ignored",
+                    field.name
+                ),
+            });
+        } else if !extension_names.insert(field.name.as_str()) {
+            warnings.push(ConfigWarning {
+                code: "sir_extension_duplicate",
+                message: format!(
+                    "[[sir_extensions]] name='{}' is declared more than once; only the first declaration is used",
+                    field.name
+                ),
+            });
+        }
+    }
+
+    let mut root_names = HashSet::new();
+    for root in &config.roots {
+        if !is_valid_root_name(root.name.as_str()) {
+            warnings.push(ConfigWarning {
+                code: "workspace_root_name_invalid",
+                message: format!(
+                    "[[roots]] name='{}' must use lowercase letters, digits, '-' or '_'; it will be ignored",
+                    root.name
+                ),
+            });
+        } else if root.path_prefix().is_empty() {
+            warnings.push(ConfigWarning {
+                code: "workspace_root_path_empty",
+                message: format!(
+                    "[[roots]] name='{}' has no path; it will be ignored",
+                    root.name
+                ),
+            });
+        } else if !root_names.insert(root.name.as_str()) {
+            warnings.push(ConfigWarning {
+                code: "workspace_root_duplicate",
+                message: format!(
+                    "[[roots]] name='{}' is declared more than once; only the first declaration is used",
+                    root.name
+                ),
+            });
+        }
+    }
+    for link in &config.root_links {
+        if let Some(from_root) = link.from_root.as_deref()
+            && !root_names.contains(from_root)
+        {
+            warnings.push(ConfigWarning {
+                code: "root_link_unknown_root",
+                message: format!(
+                    "[[root_links]] from_root='{from_root}' does not name a declared root; the link will be ignored"
+                ),
+            });
+        }
+        if link.from_symbol.is_empty() || link.to_symbol.is_empty() {
+            warnings.push(ConfigWarning {
+                code: "root_link_incomplete",
+                message: "[[root_links]] entries need both from_symbol and to_symbol; the link will be ignored"
+                    .to_owned(),
+            });
+        }
+    }
+
+    let mut language_names = HashSet::new();
+    for language in &config.languages {
+        if !is_valid_language_name(language.name.as_str()) {
+            warnings.push(ConfigWarning {
+                code: "language_plugin_name_invalid",
+                message: format!(
+                    "[[languages]] name='{}' must start with a lowercase letter and use lowercase letters, digits, '-' or '_'; it will be ignored",
+                    language.name
+                ),
+            });
+            continue;
+        }
+        if language.grammar.trim().is_empty() || language.queries.trim().is_empty() {
+            warnings.push(ConfigWarning {
+                code: "language_plugin_incomplete",
+                message: format!(
+                    "[[languages]] name='{}' needs both grammar and queries; it will be ignored",
+                    language.name
+                ),
+            });
+            continue;
+        }
+        if language.is_wasm_grammar() {
+            warnings.push(ConfigWarning {
+                code: "language_plugin_wasm_unsupported",
+                message: format!(
+                    "[[languages]] name='{}' points at a WASM grammar; only shared-library grammars are supported, so it will be ignored",
+                    language.name
+                ),
+            });
+            continue;
+        }
+        let builtin = language
+            .extensions
+            .iter()
+            .map(|extension| {
+                extension
+                    .trim()
+                    .trim_start_matches('.')
+                    .to_ascii_lowercase()
+            })
+            .filter(|extension| BUILTIN_LANGUAGE_EXTENSIONS.contains(&extension.as_str()))
+            .collect::<Vec<_>>();
+        if !builtin.is_empty() {
+            warnings.push(ConfigWarning {
+                code: "language_plugin_builtin_extension",
+                message: format!(
+                    "[[languages]] name='{}' claims built-in extensions {}; the built-in grammar keeps them",
+                    language.name,
+                    builtin.join(", ")
+                ),
+            });
+        }
+        if language.plugin_extensions().is_empty() {
+            warnings.push(ConfigWarning {
+                code: "language_plugin_no_extensions",
+                message: format!(
+                    "[[languages]] name='{}' has no usable extensions; it will be ignored",
+                    language.name
+                ),
+            });
+        } else if !language_names.insert(language.name.as_str()) {
+            warnings.push(ConfigWarning {
+                code: "language_plugin_duplicate",
+                message: format!(
+                    "[[languages]] name='{}' is declared more than once; only the first declaration is used",
+                    language.name
+                ),
+            });
+        }
     }
 
     warnings
@@ -346,7 +526,7 @@ pub fn validate_config(config: &AetherConfig) -> Vec<ConfigWarning> {
 mod tests {
     use crate::{
         AetherConfig, DEFAULT_GEMINI_API_KEY_ENV,
-        analysis::{CouplingConfig, DriftConfig},
+        analysis::{CouplingConfig, DeadCodeConfig, DriftConfig, ProfilesConfig},
         continuous::ContinuousConfig,
         embeddings::{
             CandleEmbeddingsConfig, EmbeddingProviderKind, EmbeddingVectorBackend, EmbeddingsConfig,
@@ -354,6 +534,7 @@ mod tests {
         health::{HealthConfig, HealthScoreConfig},
         inference::{InferenceConfig, InferenceProviderKind, default_sir_concurrency},
         planner::PlannerConfig,
+        redaction::RedactionConfig,
         root::{DashboardConfig, GeneralConfig, parse_workspace_config_str},
         search::{ProvidersConfig, SearchConfig},
         sir_quality::SirQualityConfig,
@@ -404,6 +585,8 @@ mod tests {
             },
             coupling: CouplingConfig::default(),
             drift: DriftConfig::default(),
+            dead_code: DeadCodeConfig::default(),
+            profiles: ProfilesConfig::default(),
             health: HealthConfig::default(),
             planner: PlannerConfig::default(),
             health_score: HealthScoreConfig::default(),
@@ -413,6 +596,11 @@ mod tests {
             seismograph: None,
             contracts: None,
             watcher: None,
+            redaction: RedactionConfig::default(),
+            sir_extensions: Vec::new(),
+            roots: Vec::new(),
+            root_links: Vec::new(),
+            languages: Vec::new(),
         };
 
         let codes = warning_codes(&validate_config(&config));
@@ -548,6 +736,7 @@ deep_thinking = "medium"
             continuous: Some(ContinuousConfig {
                 schedule: "weekly".to_owned(),
                 requeue_pass: "unknown".to_owned(),
+                graph_max_incremental_delta: 1.5,
                 ..ContinuousConfig::default()
             }),
             ..AetherConfig::default()
@@ -556,5 +745,91 @@ deep_thinking = "medium"
         let codes = warning_codes(&validate_config(&config));
         assert!(codes.contains(&"continuous_schedule_invalid"));
         assert!(codes.contains(&"continuous_requeue_pass_invalid"));
+        assert!(codes.contains(&"continuous_graph_delta_invalid"));
+    }
+
+    #[test]
+    fn validate_config_warns_on_invalid_sir_extensions() {
+        let config = parse_workspace_config_str(
+            r#"
+[[sir_extensions]]
+name = "Side-Effects"
+type = "string"
+
+[[sir_extensions]]
+name = "pii_handled"
+type = "boolean"
+
+[[sir_extensions]]
+name = "pii_handled"
+type = "string"
+"#,
+        )
+        .expect("parse config");
+
+        let codes = warning_codes(&validate_config(&config));
+        assert!(codes.contains(&"sir_extension_name_invalid"));
+        assert!(codes.contains(&"sir_extension_duplicate"));
+    }
+
+    #[test]
+    fn validate_config_warns_on_invalid_roots_and_links() {
+        let config = parse_workspace_config_str(
+            r#"
+[[roots]]
+name = "SDK"
+path = "../sdk"
+
+[[roots]]
+name = "web"
+path = "../web"
+
+[[roots]]
+name = "web"
+path = "../web-next"
+
+[[root_links]]
+from_root = "mobile"
+from_symbol = "Client.fetch"
+to_symbol = "handlers::fetch"
+"#,
+        )
+        .expect("parse config");
+
+        let codes = warning_codes(&validate_config(&config));
+        assert!(codes.contains(&"workspace_root_name_invalid"));
+        assert!(codes.contains(&"workspace_root_duplicate"));
+        assert!(codes.contains(&"root_link_unknown_root"));
+    }
+
+    #[test]
+    fn validate_config_warns_on_invalid_language_plugins() {
+        let config = parse_workspace_config_str(
+            r#"
+[[languages]]
+name = "Kotlin"
+extensions = ["kt"]
+grammar = "libtree-sitter-kotlin.so"
+queries = "queries/kotlin"
+
+[[languages]]
+name = "ruby"
+extensions = ["rb"]
+grammar = "tree-sitter-ruby.wasm"
+queries = "queries/ruby"
+
+[[languages]]
+name = "starlark"
+extensions = ["py", "bzl"]
+grammar = "libtree-sitter-starlark.so"
+queries = ""
+"#,
+        )
+        .expect("parse config");
+
+        let codes = warning_codes(&validate_config(&config));
+        assert!(codes.contains(&"language_plugin_name_invalid"));
+        assert!(codes.contains(&"language_plugin_wasm_unsupported"));
+        assert!(codes.contains(&"language_plugin_incomplete"));
     }
 }
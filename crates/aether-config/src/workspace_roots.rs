@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::root::AetherConfig;
+
+/// Additional repository indexed into this workspace's store (`[[roots]]`).
+///
+/// Files from the root are recorded under `path` joined with their
+/// root-relative path, so symbol ids stay distinct per repository and every
+/// stored file path still resolves from the primary workspace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceRootConfig {
+    pub name: String,
+    /// Root directory, absolute or relative to the primary workspace.
+    pub path: String,
+    /// Gitignore-style globs, relative to the root, that are not indexed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
+
+impl WorkspaceRootConfig {
+    /// Stored file-path prefix for files under this root, without a trailing slash.
+    pub fn path_prefix(&self) -> String {
+        let normalized = self.path.trim().replace('\\', "/");
+        let trimmed = normalized.trim_end_matches('/');
+        match trimmed.strip_prefix("./") {
+            Some(rest) => rest.to_owned(),
+            None => trimmed.to_owned(),
+        }
+    }
+}
+
+/// Explicit cross-root edge (`[[root_links]]`), e.g. an SDK method that calls
+/// an HTTP handler in another repository.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RootLinkConfig {
+    /// Root declaring the calling symbol; omitted for the primary workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_root: Option<String>,
+    /// Qualified name of the calling symbol.
+    pub from_symbol: String,
+    /// Qualified name of the symbol it reaches, in any root.
+    pub to_symbol: String,
+}
+
+pub fn is_valid_root_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_' || ch == '-')
+}
+
+/// Returns the usable `[[roots]]` declarations.
+///
+/// Entries with invalid names, empty paths and later duplicate names are
+/// skipped; all are reported by `validate_config`.
+pub fn declared_workspace_roots(config: &AetherConfig) -> Vec<WorkspaceRootConfig> {
+    let mut seen = HashSet::new();
+    config
+        .roots
+        .iter()
+        .filter(|root| is_valid_root_name(root.name.as_str()))
+        .filter(|root| !root.path_prefix().is_empty())
+        .filter(|root| seen.insert(root.name.clone()))
+        .cloned()
+        .collect()
+}
+
+/// Finds the declared root a stored file path belongs to, preferring the
+/// longest matching prefix. Returns `None` for primary-workspace files.
+pub fn root_for_file_path<'a>(
+    roots: &'a [WorkspaceRootConfig],
+    file_path: &str,
+) -> Option<&'a WorkspaceRootConfig> {
+    let file_path = file_path.replace('\\', "/");
+    roots
+        .iter()
+        .filter(|root| {
+            let prefix = root.path_prefix();
+            file_path
+                .strip_prefix(prefix.as_str())
+                .is_some_and(|rest| rest.starts_with('/'))
+        })
+        .max_by_key(|root| root.path_prefix().len())
+}
+
+/// Rewrites a root-qualified path such as `sdk:src/client.ts` into the stored
+/// file path. Unqualified paths and unknown root names are returned unchanged.
+pub fn qualify_root_path(roots: &[WorkspaceRootConfig], path: &str) -> String {
+    if let Some((name, rest)) = path.split_once(':')
+        && is_valid_root_name(name)
+        && let Some(root) = roots.iter().find(|root| root.name == name)
+    {
+        let rest = rest.trim_start_matches(['/', '\\']);
+        return format!("{}/{}", root.path_prefix(), rest.replace('\\', "/"));
+    }
+    path.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{declared_workspace_roots, qualify_root_path, root_for_file_path};
+    use crate::root::parse_workspace_config_str;
+
+    fn config() -> crate::AetherConfig {
+        parse_workspace_config_str(
+            r#"
+[[roots]]
+name = "sdk"
+path = "../sdk/"
+exclude = ["generated/**"]
+
+[[roots]]
+name = "sdk-web"
+path = "../sdk/web"
+
+[[roots]]
+name = "Bad Name"
+path = "../infra"
+
+[[roots]]
+name = "sdk"
+path = "../other"
+
+[[root_links]]
+from_root = "sdk"
+from_symbol = "ApiClient.getUser"
+to_symbol = "handlers::get_user"
+"#,
+        )
+        .expect("parse config")
+    }
+
+    #[test]
+    fn declared_roots_skip_invalid_and_duplicate_names() {
+        let config = config();
+        let roots = declared_workspace_roots(&config);
+
+        let names = roots
+            .iter()
+            .map(|root| root.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["sdk", "sdk-web"]);
+        assert_eq!(roots[0].path_prefix(), "../sdk");
+        assert_eq!(roots[0].exclude, vec!["generated/**".to_owned()]);
+        assert_eq!(config.root_links.len(), 1);
+        assert_eq!(config.root_links[0].from_root.as_deref(), Some("sdk"));
+    }
+
+    #[test]
+    fn file_paths_resolve_to_longest_matching_root() {
+        let roots = declared_workspace_roots(&config());
+
+        let root = |path: &str| root_for_file_path(&roots, path).map(|root| root.name.as_str());
+        assert_eq!(root("../sdk/src/client.ts"), Some("sdk"));
+        assert_eq!(root("../sdk/web/app.ts"), Some("sdk-web"));
+        assert_eq!(root("../sdkx/src/lib.rs"), None);
+        assert_eq!(root("src/lib.rs"), None);
+    }
+
+    #[test]
+    fn root_qualified_paths_map_to_stored_paths() {
+        let roots = declared_workspace_roots(&config());
+
+        assert_eq!(
+            qualify_root_path(&roots, "sdk:src/client.ts"),
+            "../sdk/src/client.ts"
+        );
+        assert_eq!(qualify_root_path(&roots, "src/lib.rs"), "src/lib.rs");
+        assert_eq!(qualify_root_path(&roots, "infra:main.tf"), "infra:main.tf");
+    }
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+
+/// Method recorded for routes that accept any HTTP method.
+pub const ANY_HTTP_METHOD: &str = "*";
+
+/// Placeholder for a path parameter after normalization (`:id`, `{id}`, `<int:id>`, `${id}`).
+pub const ENDPOINT_PARAM_SEGMENT: &str = "{}";
+
+/// Route declaration, e.g. an axum `.route(...)`, a FastAPI decorator or an
+/// Express `router.get(...)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct EndpointRoute {
+    pub file_path: String,
+    /// Upper-case HTTP method, or [`ANY_HTTP_METHOD`].
+    pub method: String,
+    /// Normalized path, see [`normalize_endpoint_path`].
+    pub path: String,
+    /// Qualified name of the handler symbol.
+    pub handler: String,
+}
+
+/// Client call site with a literal path, e.g. `fetch("/users/1")` or
+/// `requests.get("http://api/users")`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct EndpointCall {
+    pub file_path: String,
+    /// Symbol containing the call.
+    pub source_id: String,
+    /// Upper-case HTTP method.
+    pub method: String,
+    /// Normalized path, see [`normalize_endpoint_path`].
+    pub path: String,
+}
+
+impl EndpointRoute {
+    /// Whether a client call reaches this route.
+    ///
+    /// Paths match segment by segment with parameters matching anything. A
+    /// call path may carry extra leading segments (a mount prefix such as
+    /// `/api/v1`) as long as the route has at least one literal segment.
+    pub fn matches(&self, call: &EndpointCall) -> bool {
+        if self.method != ANY_HTTP_METHOD && self.method != call.method {
+            return false;
+        }
+
+        let route = path_segments(&self.path);
+        let called = path_segments(&call.path);
+        if called.len() < route.len() {
+            return false;
+        }
+        if called.len() > route.len()
+            && route
+                .iter()
+                .all(|segment| *segment == ENDPOINT_PARAM_SEGMENT)
+        {
+            return false;
+        }
+
+        called[called.len() - route.len()..]
+            .iter()
+            .zip(route.iter())
+            .all(|(called, route)| {
+                called == route
+                    || *called == ENDPOINT_PARAM_SEGMENT
+                    || *route == ENDPOINT_PARAM_SEGMENT
+            })
+    }
+}
+
+/// Upper-cases a method name, mapping `any`/`all`/`*` to [`ANY_HTTP_METHOD`].
+/// Returns `None` for names that are not HTTP methods.
+pub fn normalize_http_method(raw: &str) -> Option<String> {
+    let method = raw.trim().to_ascii_uppercase();
+    match method.as_str() {
+        "GET" | "POST" | "PUT" | "PATCH" | "DELETE" | "HEAD" | "OPTIONS" => Some(method),
+        "ANY" | "ALL" | "*" => Some(ANY_HTTP_METHOD.to_owned()),
+        _ => None,
+    }
+}
+
+/// Normalizes a route or client path literal.
+///
+/// Strips the scheme and host of absolute URLs, the query string and fragment,
+/// and a leading base-URL placeholder (`${BASE}/users`), then rewrites every
+/// parameter segment to [`ENDPOINT_PARAM_SEGMENT`]. Returns `None` when the
+/// literal does not look like a path.
+pub fn normalize_endpoint_path(raw: &str) -> Option<String> {
+    let mut path = raw.trim();
+    if let Some((scheme, rest)) = path.split_once("://") {
+        if !matches!(scheme, "http" | "https") {
+            return None;
+        }
+        path = rest.find('/').map_or("/", |index| &rest[index..]);
+    }
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+
+    let mut segments = path.split('/').collect::<Vec<_>>();
+    if !path.starts_with('/') {
+        match segments.first() {
+            Some(first) if is_param_segment(first) && segments.len() > 1 => {
+                segments.remove(0);
+            }
+            _ => return None,
+        }
+    }
+
+    let normalized = segments
+        .into_iter()
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            if is_param_segment(segment) {
+                ENDPOINT_PARAM_SEGMENT
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>();
+    if normalized
+        .iter()
+        .any(|segment| segment.chars().any(char::is_whitespace))
+    {
+        return None;
+    }
+    Some(format!("/{}", normalized.join("/")))
+}
+
+fn is_param_segment(segment: &str) -> bool {
+    segment.starts_with(':')
+        || segment.starts_with('*')
+        || segment.contains('{')
+        || segment.contains("${")
+        || (segment.starts_with('<') && segment.ends_with('>'))
+}
+
+fn path_segments(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(method: &str, path: &str) -> EndpointRoute {
+        EndpointRoute {
+            file_path: "src/routes.rs".to_owned(),
+            method: method.to_owned(),
+            path: normalize_endpoint_path(path).expect("route path"),
+            handler: "get_user".to_owned(),
+        }
+    }
+
+    fn call(method: &str, path: &str) -> EndpointCall {
+        EndpointCall {
+            file_path: "web/api.ts".to_owned(),
+            source_id: "sym-client".to_owned(),
+            method: method.to_owned(),
+            path: normalize_endpoint_path(path).expect("call path"),
+        }
+    }
+
+    #[test]
+    fn paths_normalize_parameters_hosts_and_queries() {
+        let normalize = |raw: &str| normalize_endpoint_path(raw);
+        assert_eq!(normalize("/users/:id").as_deref(), Some("/users/{}"));
+        assert_eq!(normalize("/users/{user_id}/").as_deref(), Some("/users/{}"));
+        assert_eq!(normalize("/users/<int:id>").as_deref(), Some("/users/{}"));
+        assert_eq!(
+            normalize("https://api.example.com:8443/users/${id}?full=1").as_deref(),
+            Some("/users/{}")
+        );
+        assert_eq!(normalize("${BASE_URL}/users").as_deref(), Some("/users"));
+        assert_eq!(normalize("http://localhost").as_deref(), Some("/"));
+        assert_eq!(normalize("users"), None);
+        assert_eq!(normalize("ftp://host/file"), None);
+        assert_eq!(normalize("/not a path"), None);
+    }
+
+    #[test]
+    fn routes_match_calls_by_method_and_segments() {
+        let get_user = route("GET", "/users/:id");
+        assert!(get_user.matches(&call("GET", "/users/42")));
+        assert!(get_user.matches(&call("GET", "/api/v1/users/${id}")));
+        assert!(!get_user.matches(&call("POST", "/users/42")));
+        assert!(!get_user.matches(&call("GET", "/users")));
+        assert!(!get_user.matches(&call("GET", "/teams/42")));
+
+        assert!(route(ANY_HTTP_METHOD, "/health").matches(&call("HEAD", "/health")));
+        assert!(!route("GET", "/{id}").matches(&call("GET", "/api/users")));
+    }
+
+    #[test]
+    fn http_methods_normalize() {
+        assert_eq!(normalize_http_method("post").as_deref(), Some("POST"));
+        assert_eq!(normalize_http_method("all").as_deref(), Some("*"));
+        assert_eq!(normalize_http_method("route"), None);
+    }
+}
@@ -1,12 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::str::FromStr;
+use std::sync::{Mutex, PoisonError};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+pub mod endpoint;
 pub mod git;
 pub mod secret;
 
+pub use endpoint::{
+    ANY_HTTP_METHOD, ENDPOINT_PARAM_SEGMENT, EndpointCall, EndpointRoute, normalize_endpoint_path,
+    normalize_http_method,
+};
 pub use git::{BlameLine, CommitInfo, GitContext};
 pub use secret::Secret;
 
@@ -161,8 +167,7 @@ fn format_hover_list(items: &[String]) -> String {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Ord, PartialOrd)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum Language {
     Rust,
     TypeScript,
@@ -170,9 +175,21 @@ pub enum Language {
     JavaScript,
     Jsx,
     Python,
+    /// Grammar loaded at runtime from a `[[languages]]` plugin, named by the
+    /// plugin's `name`.
+    Plugin(&'static str),
 }
 
 impl Language {
+    const BUILT_IN: [Self; 6] = [
+        Self::Rust,
+        Self::TypeScript,
+        Self::Tsx,
+        Self::JavaScript,
+        Self::Jsx,
+        Self::Python,
+    ];
+
     pub fn as_str(self) -> &'static str {
         match self {
             Self::Rust => "rust",
@@ -181,10 +198,65 @@ impl Language {
             Self::JavaScript => "javascript",
             Self::Jsx => "jsx",
             Self::Python => "python",
+            Self::Plugin(name) => name,
+        }
+    }
+
+    /// The plugin language called `name`. Names are interned so `Language`
+    /// stays `Copy`.
+    pub fn plugin(name: &str) -> Self {
+        static NAMES: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+        let mut names = NAMES.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(interned) = names.get(name) {
+            return Self::Plugin(interned);
+        }
+        let interned: &'static str = Box::leak(name.to_owned().into_boxed_str());
+        names.insert(interned);
+        Self::Plugin(interned)
+    }
+
+    /// The built-in language with this [`Language::as_str`] name, if any.
+    pub fn built_in(name: &str) -> Option<Self> {
+        Self::BUILT_IN
+            .into_iter()
+            .find(|language| language.as_str() == name)
+    }
+
+    /// Inverse of [`Language::as_str`]: names that are not built-in languages
+    /// are plugin languages. Returns `None` for an empty name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name.is_empty() {
+            return None;
+        }
+        Some(Self::built_in(name).unwrap_or_else(|| Self::plugin(name)))
+    }
+
+    fn serde_name(self) -> &'static str {
+        match self {
+            Self::TypeScript => "type_script",
+            Self::JavaScript => "java_script",
+            other => other.as_str(),
         }
     }
 }
 
+impl Serialize for Language {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.serde_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Self::BUILT_IN
+            .into_iter()
+            .find(|language| language.serde_name() == name)
+            .or_else(|| Self::from_name(&name))
+            .ok_or_else(|| serde::de::Error::custom("language name must not be empty"))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Ord, PartialOrd)]
 #[serde(rename_all = "snake_case")]
 pub enum SymbolKind {
@@ -197,6 +269,12 @@ pub enum SymbolKind {
     Trait,
     Interface,
     TypeAlias,
+    Constant,
+    Static,
+    Macro,
+    Module,
+    Field,
+    EnumVariant,
 }
 
 impl SymbolKind {
@@ -211,6 +289,12 @@ impl SymbolKind {
             Self::Trait => "trait",
             Self::Interface => "interface",
             Self::TypeAlias => "type_alias",
+            Self::Constant => "constant",
+            Self::Static => "static",
+            Self::Macro => "macro",
+            Self::Module => "module",
+            Self::Field => "field",
+            Self::EnumVariant => "enum_variant",
         }
     }
 }
@@ -251,6 +335,10 @@ pub enum EdgeKind {
     DependsOn,
     TypeRef,
     Implements,
+    /// Client call site reaching an HTTP/RPC route handler, possibly in another language.
+    Endpoint,
+    /// Read of a constant, static, field or enum variant, or a macro invocation.
+    References,
 }
 
 impl EdgeKind {
@@ -260,6 +348,8 @@ impl EdgeKind {
             Self::DependsOn => "depends_on",
             Self::TypeRef => "type_ref",
             Self::Implements => "implements",
+            Self::Endpoint => "endpoint",
+            Self::References => "references",
         }
     }
 }
@@ -520,4 +610,14 @@ mod tests {
         assert_eq!(format!("{secret}"), "[REDACTED]");
         assert_eq!(secret.expose(), "top-secret-token");
     }
+
+    #[test]
+    fn plugin_languages_keep_their_names() {
+        let kotlin = Language::plugin("kotlin");
+        assert_eq!(kotlin.as_str(), "kotlin");
+        assert_eq!(Language::from_name("kotlin"), Some(kotlin));
+        assert_eq!(Language::from_name("python"), Some(Language::Python));
+        assert_ne!(Language::plugin("ruby"), kotlin);
+        assert_eq!(Language::from_name(""), None);
+    }
 }
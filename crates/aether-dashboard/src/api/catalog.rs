@@ -1,5 +1,5 @@
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use aether_store::SirStateStore;
 use serde_json::Value;
@@ -18,6 +18,7 @@ pub(crate) struct CatalogSir {
     pub dependencies: Vec<String>,
     pub error_modes: Vec<String>,
     pub is_async: bool,
+    pub extensions: BTreeMap<String, Value>,
 }
 
 #[derive(Debug, Clone)]
@@ -419,6 +420,15 @@ fn parse_sir(blob: &str) -> CatalogSir {
     let side_effects = as_string_vec(value.get("side_effects"));
     let dependencies = as_string_vec(value.get("dependencies"));
     let error_modes = as_string_vec(value.get("error_modes"));
+    let extensions = value
+        .get("extensions")
+        .and_then(Value::as_object)
+        .map(|map| {
+            map.iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
 
     let intent_lower = intent.to_ascii_lowercase();
     let is_async = intent_lower.contains("async")
@@ -434,6 +444,7 @@ fn parse_sir(blob: &str) -> CatalogSir {
         dependencies,
         error_modes,
         is_async,
+        extensions,
     }
 }
 
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::response::IntoResponse;
+use serde::Deserialize;
+
+use aether_analysis::{DeadCodeAnalyzer, DeadCodeConfidence, DeadCodeReport, DeadCodeRequest};
+
+use crate::state::SharedState;
+use crate::support::{self, DashboardState};
+
+const DEFAULT_LIMIT: usize = 100;
+const MAX_LIMIT: usize = 500;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct DeadCodeQuery {
+    pub min_confidence: Option<String>,
+    pub path: Option<String>,
+    pub limit: Option<usize>,
+}
+
+pub(crate) async fn dead_code_handler(
+    State(state): State<Arc<DashboardState>>,
+    Query(query): Query<DeadCodeQuery>,
+) -> impl IntoResponse {
+    let shared = state.shared.clone();
+    match support::run_blocking_with_timeout(move || load_dead_code(shared.as_ref(), &query)).await
+    {
+        Ok(data) => support::api_json(state.shared.as_ref(), data).into_response(),
+        Err(err) => {
+            if let Some(message) = support::extract_timeout_error_message(err.as_str()) {
+                support::json_timeout_error(message)
+            } else {
+                support::json_internal_error(err)
+            }
+        }
+    }
+}
+
+pub(crate) fn load_dead_code(
+    shared: &SharedState,
+    query: &DeadCodeQuery,
+) -> Result<DeadCodeReport, String> {
+    let min_confidence = match query.min_confidence.as_deref() {
+        Some(value) if !value.trim().is_empty() => value.parse::<DeadCodeConfidence>()?,
+        _ => DeadCodeConfidence::Low,
+    };
+    let analyzer = DeadCodeAnalyzer::new(&shared.workspace).map_err(|err| err.to_string())?;
+    analyzer
+        .analyze(
+            shared.store.as_ref(),
+            &DeadCodeRequest {
+                min_confidence,
+                path_prefix: query.path.clone().filter(|path| !path.trim().is_empty()),
+                limit: Some(query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)),
+                public_api_roots: None,
+            },
+        )
+        .map_err(|err| err.to_string())
+}
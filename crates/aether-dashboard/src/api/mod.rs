@@ -23,6 +23,7 @@ pub(crate) mod contracts;
 pub(crate) mod coupling;
 mod coupling_matrix;
 pub(crate) mod daemon_status;
+pub(crate) mod dead_code;
 pub(crate) mod decompose;
 pub(crate) mod difficulty;
 pub(crate) mod drift;
@@ -189,6 +190,7 @@ pub(crate) fn api_router() -> Router<Arc<DashboardState>> {
             "/api/v1/contracts/dismiss",
             post(contracts::dismiss_handler),
         )
+        .route("/api/v1/dead-code", get(dead_code::dead_code_handler))
         // Phase Repo R.5: Context Builder
         .route(
             "/api/v1/context/file-tree",
@@ -919,7 +919,12 @@ fn build_health(
     }
 
     // Non-paired usize fields
-    for key in &["churn_30d_high", "churn_90d_high", "author_count_high"] {
+    for key in &[
+        "churn_30d_high",
+        "churn_90d_high",
+        "author_count_high",
+        "bus_factor_healthy",
+    ] {
         let form_key = format!("health_score.{key}");
         match parse_usize_min(form, &form_key, 0) {
             Ok(v) => {
@@ -1098,6 +1103,18 @@ fn build_continuous(
         Err(e) => errors.push(e),
     }
 
+    // Graph maintenance
+    match parse_usize_min(form, "graph_full_recompute_every", 1) {
+        Ok(v) => {
+            obj.insert("graph_full_recompute_every".into(), Value::Number(v.into()));
+        }
+        Err(e) => errors.push(e),
+    }
+    match parse_threshold(form, "graph_max_incremental_delta") {
+        Ok(v) => insert_f64(&mut obj, "graph_max_incremental_delta", v),
+        Err(e) => errors.push(e),
+    }
+
     sections.insert("continuous".into(), Value::Object(obj));
 }
 
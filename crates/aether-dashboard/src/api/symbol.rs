@@ -2,6 +2,7 @@ use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
+use aether_sir::format_extension_value;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
@@ -58,6 +59,14 @@ pub(crate) struct BlastRadiusSection {
     pub affected_symbols: usize,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct SirExtensionItem {
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    pub value: String,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub(crate) struct SymbolAlternative {
     pub name: String,
@@ -90,6 +99,8 @@ pub(crate) struct SymbolDeepDiveData {
     pub centrality_rank: usize,
     pub centrality_narrative: String,
 
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<SirExtensionItem>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub alternatives: Vec<SymbolAlternative>,
     #[serde(skip_serializing_if = "String::is_empty")]
@@ -279,6 +290,7 @@ pub(crate) fn build_symbol_deep_dive(
         .collect::<Vec<_>>();
 
     let difficulty = difficulty_for_symbol(&symbol);
+    let extensions = extension_items(shared, &symbol);
 
     let data = SymbolDeepDiveData {
         name: symbol.name,
@@ -306,6 +318,7 @@ pub(crate) fn build_symbol_deep_dive(
         centrality: catalog.centrality(symbol.id.as_str()),
         centrality_rank: rank,
         centrality_narrative,
+        extensions,
         alternatives,
         matched_by: resolved.matched_by.to_owned(),
     };
@@ -313,6 +326,25 @@ pub(crate) fn build_symbol_deep_dive(
     Ok(Some(SymbolDeepDiveBuild { data }))
 }
 
+fn extension_items(shared: &SharedState, symbol: &CatalogSymbol) -> Vec<SirExtensionItem> {
+    symbol
+        .sir
+        .extensions
+        .iter()
+        .map(|(name, value)| SirExtensionItem {
+            name: name.clone(),
+            description: shared
+                .config
+                .sir_extensions
+                .iter()
+                .find(|field| field.name == *name)
+                .map(|field| field.description.clone())
+                .unwrap_or_default(),
+            value: format_extension_value(value),
+        })
+        .collect()
+}
+
 fn dependency_items(catalog: &SymbolCatalog, symbol: &CatalogSymbol) -> Vec<DependencyItem> {
     let mut parsed_dependency_reasons = HashMap::<String, Option<String>>::new();
     for entry in &symbol.sir.dependencies {
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::response::Html;
+use maud::html;
+
+use aether_analysis::DeadCodeConfidence;
+
+use crate::api::dead_code::{self, DeadCodeQuery};
+use crate::support::{self, DashboardState};
+
+pub(crate) async fn dead_code_fragment(
+    State(state): State<Arc<DashboardState>>,
+    Query(query): Query<DeadCodeQuery>,
+) -> Html<String> {
+    let shared = state.shared.clone();
+    let selected = query
+        .min_confidence
+        .clone()
+        .unwrap_or_else(|| "low".to_owned());
+    let report = match support::run_blocking_with_timeout(move || {
+        dead_code::load_dead_code(shared.as_ref(), &query)
+    })
+    .await
+    {
+        Ok(report) => report,
+        Err(err) => {
+            let detail = support::extract_timeout_error_message(err.as_str()).unwrap_or(err);
+            return support::html_markup_response(html! {
+                (support::html_error_state("Failed to compute dead code", &detail))
+            });
+        }
+    };
+    let count = |level: &str| report.confidence_counts.get(level).copied().unwrap_or(0);
+
+    support::html_markup_response(html! {
+        div class="space-y-4" {
+            (support::explanation_header(
+                "Dead Code",
+                "Code that nothing in the project seems to use.",
+                "Symbols with no path from an entry point (main, tests, route handlers, exported symbols, public API) over calls, imports, type references and implementations.",
+                "Reachability over calls/depends_on/type_ref/implements/references/endpoint edges. Confidence drops for dynamic dispatch, unresolved references and reflection-heavy Python.",
+            ))
+
+            div class="flex items-center justify-between gap-3" {
+                h2 class="text-lg font-semibold" {
+                    span class="beginner-only" { "Unused Code" }
+                    span class="intermediate-only" { "Dead Code" }
+                    span class="expert-only" { "Unreachable Symbols" }
+                }
+                select name="min_confidence" class="form-select text-xs rounded border border-surface-3 bg-surface-1 px-2 py-1"
+                    hx-get="/dashboard/frag/dead-code" hx-target="#main-content" {
+                    @for level in ["low", "medium", "high"] {
+                        option value=(level) selected[selected == level] { "≥ " (level) }
+                    }
+                }
+            }
+
+            div class="grid grid-cols-2 lg:grid-cols-4 gap-3" {
+                div class="rounded-xl border border-surface-3/30 bg-surface-0/55 p-4" {
+                    div class="text-xs text-text-muted" { "Reachable" }
+                    div class="text-2xl font-semibold" { (report.reachable_symbols) " / " (report.total_symbols) }
+                }
+                div class="rounded-xl border border-surface-3/30 bg-surface-0/55 p-4" {
+                    div class="text-xs text-text-muted" { "High confidence" }
+                    div class="text-2xl font-semibold text-red-400" { (count("high")) }
+                }
+                div class="rounded-xl border border-surface-3/30 bg-surface-0/55 p-4" {
+                    div class="text-xs text-text-muted" { "Medium confidence" }
+                    div class="text-2xl font-semibold text-amber-400" { (count("medium")) }
+                }
+                div class="rounded-xl border border-surface-3/30 bg-surface-0/55 p-4" {
+                    div class="text-xs text-text-muted" { "Low confidence" }
+                    div class="text-2xl font-semibold text-text-secondary" { (count("low")) }
+                }
+            }
+
+            div class="flex flex-wrap gap-1.5" {
+                @for (kind, roots) in &report.roots {
+                    span class="badge badge-cyan" { (kind) ": " (roots) }
+                }
+            }
+
+            @if report.unreachable.is_empty() {
+                (support::html_empty_state("No unreachable symbols at this confidence", Some("aetherd dead-code")))
+            } @else {
+                table class="data-table" {
+                    thead {
+                        tr {
+                            th { "Symbol" }
+                            th { "Kind" }
+                            th { "Confidence" }
+                            th { "Why" }
+                        }
+                    }
+                    tbody {
+                        @for entry in &report.unreachable {
+                            tr {
+                                td class="align-top" {
+                                    div class="font-medium text-text-primary" { (&entry.qualified_name) }
+                                    div class="text-xs text-text-muted" { (support::normalized_display_path(&entry.file_path)) }
+                                }
+                                td class="align-top" {
+                                    span class={ "badge " (support::badge_class_for_kind(&entry.kind)) } { (&entry.kind) }
+                                }
+                                td class="align-top" {
+                                    span class={ "badge " (confidence_badge_class(entry.confidence)) } { (entry.confidence.as_str()) }
+                                }
+                                td class="align-top text-xs text-text-secondary leading-5" {
+                                    (entry.reasons.join("; "))
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            @for note in &report.notes {
+                p class="text-xs text-text-muted" { (note) }
+            }
+        }
+    })
+}
+
+fn confidence_badge_class(confidence: DeadCodeConfidence) -> &'static str {
+    match confidence {
+        DeadCodeConfidence::High => "badge-red",
+        DeadCodeConfidence::Medium => "badge-yellow",
+        DeadCodeConfidence::Low => "badge-muted",
+    }
+}
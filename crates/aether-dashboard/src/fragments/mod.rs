@@ -20,6 +20,7 @@ mod continuous;
 mod contracts;
 mod coupling;
 mod coupling_chord;
+mod dead_code;
 mod decompose;
 mod drift_table;
 mod drift_timeline;
@@ -184,6 +185,10 @@ pub(crate) fn fragment_router() -> Router<Arc<DashboardState>> {
             "/dashboard/frag/contracts",
             get(contracts::contracts_fragment),
         )
+        .route(
+            "/dashboard/frag/dead-code",
+            get(dead_code::dead_code_fragment),
+        )
         // Phase 9.3: Wizard (for "Run Setup Again" from sidebar)
         .route(
             "/dashboard/frag/wizard/step/{n}",
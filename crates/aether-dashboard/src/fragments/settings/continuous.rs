@@ -134,6 +134,26 @@ pub(crate) fn render(config: &AetherConfig) -> Markup {
                 "PageRank damping factor for priority ranking",
             ))
 
+            (helpers::number_input(
+                "graph_full_recompute_every",
+                "Full Graph Recompute Every",
+                cont.graph_full_recompute_every,
+                "Incremental PageRank/community updates before the next full recompute",
+                Some("1"),
+                None,
+                None,
+            ))
+
+            (helpers::slider_input(
+                "graph_max_incremental_delta",
+                "Max Incremental Edge Delta",
+                cont.graph_max_incremental_delta,
+                0.0,
+                1.0,
+                0.01,
+                "Fraction of changed edges above which a run recomputes the graph from scratch",
+            ))
+
             (helpers::save_reset_buttons("continuous"))
         }
     }
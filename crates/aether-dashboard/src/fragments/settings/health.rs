@@ -288,6 +288,16 @@ pub(crate) fn render(config: &AetherConfig) -> Markup {
             None,
         ))
 
+        (helpers::number_input(
+            "health_score.bus_factor_healthy",
+            "Healthy Bus Factor",
+            hs.bus_factor_healthy,
+            "Fewest owners a crate needs before its bus factor adds git pressure (0 disables)",
+            None,
+            None,
+            None,
+        ))
+
         // Semantic Metrics
         (helpers::section_divider("Semantic Metrics"))
 
@@ -97,6 +97,20 @@ fn render_symbol_deep_dive(build: &SymbolDeepDiveBuild) -> maud::Markup {
                 p class="text-sm text-text-secondary" { (data.creation_narrative.as_str()) }
             }
 
+            @if !data.extensions.is_empty() {
+                section class="rounded-xl border border-surface-3/40 bg-surface-1/40 p-5 space-y-2" {
+                    h2 class="text-lg font-semibold" { "Custom SIR Fields" }
+                    dl class="grid gap-2 text-sm sm:grid-cols-[max-content_1fr]" {
+                        @for item in &data.extensions {
+                            dt class="font-mono text-text-muted" title=(item.description.as_str()) {
+                                (item.name.as_str())
+                            }
+                            dd class="text-text-secondary" { (item.value.as_str()) }
+                        }
+                    }
+                }
+            }
+
             section class="grid gap-4 xl:grid-cols-2" {
                 article class="rounded-xl border border-surface-3/40 bg-surface-1/40 p-5 space-y-3" {
                     h3 class="text-base font-semibold" { "What Depends on This" }
@@ -1234,6 +1234,48 @@ async fn contracts_api_returns_ok() {
     assert!(json["data"]["recent_violations"].is_array());
 }
 
+#[tokio::test]
+async fn dead_code_api_and_fragment_return_report() {
+    let (_tmp, app, _ids) = seeded_app().await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/dead-code?min_confidence=medium&limit=5")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert!(json["data"]["total_symbols"].is_number());
+    assert!(json["data"]["roots"].is_object());
+    assert!(json["data"]["unreachable"].is_array());
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/dashboard/frag/dead-code")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = String::from_utf8(
+        to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap()
+            .to_vec(),
+    )
+    .unwrap();
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert!(body.contains("Unreachable Symbols"));
+}
+
 struct TestIds {
     primary: String,
 }
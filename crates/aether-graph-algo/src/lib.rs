@@ -1,4 +1,5 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 
 use petgraph::Direction;
 use petgraph::algo::kosaraju_scc;
@@ -194,6 +195,156 @@ pub fn page_rank_sync(
     scored
 }
 
+/// PageRank kept up to date from edge deltas rather than recomputed.
+///
+/// Works on the unnormalised score `reach(v) = 1 + damping * sum(reach(u) /
+/// out_degree(u))` over in-neighbours `u`. Normalised to sum to one it is the
+/// fixed point `page_rank_sync` iterates towards, but it has no global
+/// dangling-mass term, so a changed edge only disturbs what lies downstream of
+/// its source. Starting from `previous_reach` for `previous_edges`, the
+/// residual each changed source leaves on its old and new targets is pushed
+/// forward until every node is within `tolerance` (every reach is at least
+/// one). Diffing the edge lists is linear; propagation is proportional to the
+/// region the change reaches. An empty previous state is a full computation.
+pub fn incremental_page_rank_sync(
+    previous_edges: &[GraphAlgorithmEdge],
+    previous_reach: &HashMap<String, f64>,
+    edges: &[GraphAlgorithmEdge],
+    damping: f64,
+    tolerance: f64,
+) -> IncrementalPageRank {
+    let previous_out = out_adjacency(previous_edges);
+    let current_out = out_adjacency(edges);
+    let mut ids = edges
+        .iter()
+        .flat_map(|edge| [edge.source_id.as_str(), edge.target_id.as_str()])
+        .collect::<Vec<_>>();
+    ids.sort_unstable();
+    ids.dedup();
+    if ids.is_empty() {
+        return IncrementalPageRank::default();
+    }
+    let index = ids
+        .iter()
+        .enumerate()
+        .map(|(position, id)| (*id, position))
+        .collect::<HashMap<_, _>>();
+    let targets = ids
+        .iter()
+        .map(|id| {
+            current_out.get(id).map_or_else(Vec::new, |targets| {
+                targets.iter().map(|target| index[target]).collect()
+            })
+        })
+        .collect::<Vec<Vec<usize>>>();
+
+    let mut reach = vec![0.0; ids.len()];
+    let mut residual = vec![0.0; ids.len()];
+    for (position, id) in ids.iter().enumerate() {
+        match previous_reach.get(*id) {
+            Some(score) => reach[position] = *score,
+            None => residual[position] = 1.0,
+        }
+    }
+    let mut changed_sources = previous_out
+        .keys()
+        .chain(current_out.keys())
+        .copied()
+        .filter(|source| previous_out.get(source) != current_out.get(source))
+        .collect::<Vec<_>>();
+    changed_sources.sort_unstable();
+    changed_sources.dedup();
+    for source in changed_sources {
+        let Some(score) = previous_reach.get(source).copied() else {
+            continue;
+        };
+        for (out, sign) in [
+            (previous_out.get(source), -1.0),
+            (current_out.get(source), 1.0),
+        ] {
+            let Some(out) = out else {
+                continue;
+            };
+            let share = sign * damping * score / out.len() as f64;
+            for target in out {
+                if let Some(&position) = index.get(target) {
+                    residual[position] += share;
+                }
+            }
+        }
+    }
+
+    let mut queued = residual
+        .iter()
+        .map(|value: &f64| value.abs() > tolerance)
+        .collect::<Vec<_>>();
+    let mut queue = (0..ids.len())
+        .filter(|position| queued[*position])
+        .collect::<VecDeque<_>>();
+    let mut pushes = 0;
+    while let Some(node) = queue.pop_front() {
+        queued[node] = false;
+        let pending = std::mem::take(&mut residual[node]);
+        reach[node] += pending;
+        pushes += 1;
+        if targets[node].is_empty() {
+            continue;
+        }
+        let share = damping * pending / targets[node].len() as f64;
+        for &target in &targets[node] {
+            residual[target] += share;
+            if !queued[target] && residual[target].abs() > tolerance {
+                queued[target] = true;
+                queue.push_back(target);
+            }
+        }
+    }
+
+    let total = reach.iter().sum::<f64>();
+    let reach = ids
+        .iter()
+        .map(|id| (*id).to_owned())
+        .zip(reach)
+        .collect::<Vec<_>>();
+    let ranks = sort_by_score(
+        reach
+            .iter()
+            .map(|(id, score)| (id.clone(), score / total))
+            .collect(),
+    );
+    IncrementalPageRank {
+        ranks,
+        reach,
+        pushes,
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IncrementalPageRank {
+    /// Normalised PageRank, highest first, as `page_rank_sync` returns it.
+    pub ranks: Vec<(String, f64)>,
+    /// Unnormalised scores by id; feed these back in as the next
+    /// `previous_reach`.
+    pub reach: Vec<(String, f64)>,
+    /// Residual pushes performed, a measure of how far the change spread.
+    pub pushes: usize,
+}
+
+/// Sorted targets per source, parallel edges kept, matching how
+/// `build_digraph` counts out-degree.
+fn out_adjacency(edges: &[GraphAlgorithmEdge]) -> HashMap<&str, Vec<&str>> {
+    let mut out = HashMap::<&str, Vec<&str>>::new();
+    for edge in edges {
+        out.entry(edge.source_id.as_str())
+            .or_default()
+            .push(edge.target_id.as_str());
+    }
+    for targets in out.values_mut() {
+        targets.sort_unstable();
+    }
+    out
+}
+
 pub fn betweenness_centrality_sync(edges: &[GraphAlgorithmEdge]) -> Vec<(String, f64)> {
     let (graph, _, names) = build_digraph(edges);
     let nodes = graph.node_indices().collect::<Vec<_>>();
@@ -310,6 +461,210 @@ pub fn betweenness_centrality_sync(edges: &[GraphAlgorithmEdge]) -> Vec<(String,
     scored
 }
 
+/// Per-edge weights keyed by `(source_id, target_id)`. Edges without an
+/// entry, or with a non-positive weight, weigh 1.0.
+pub type EdgeWeights = HashMap<(String, String), f64>;
+
+/// PageRank where each node splits its rank across outgoing edges in
+/// proportion to their weight instead of evenly.
+pub fn weighted_page_rank_sync(
+    edges: &[GraphAlgorithmEdge],
+    weights: &EdgeWeights,
+    damping: f64,
+    iterations: usize,
+) -> Vec<(String, f64)> {
+    let (graph, _, _) = build_digraph(edges);
+    let node_count = graph.node_count();
+    if node_count == 0 {
+        return Vec::new();
+    }
+
+    let mut edge_weights = vec![0.0f64; graph.edge_count()];
+    let mut out_weight = vec![0.0f64; node_count];
+    for edge in graph.edge_references() {
+        let weight = edge_weight(weights, &graph[edge.source()], &graph[edge.target()]);
+        edge_weights[edge.id().index()] = weight;
+        out_weight[edge.source().index()] += weight;
+    }
+
+    let node_count_f = node_count as f64;
+    let mut rank = vec![1.0 / node_count_f; node_count];
+    let base = (1.0 - damping) / node_count_f;
+
+    for _ in 0..iterations {
+        let dangling_sum = (0..node_count)
+            .filter(|node| out_weight[*node] <= 0.0)
+            .map(|node| rank[node])
+            .sum::<f64>();
+
+        let mut next = vec![base + damping * (dangling_sum / node_count_f); node_count];
+        for edge in graph.edge_references() {
+            let source = edge.source().index();
+            next[edge.target().index()] +=
+                damping * rank[source] * edge_weights[edge.id().index()] / out_weight[source];
+        }
+        rank = next;
+    }
+
+    sort_by_score(
+        graph
+            .node_indices()
+            .map(|node| (graph[node].clone(), rank[node.index()]))
+            .collect(),
+    )
+}
+
+/// Betweenness over weighted shortest paths, where an edge's length is the
+/// inverse of its weight so heavy edges make short paths.
+pub fn weighted_betweenness_centrality_sync(
+    edges: &[GraphAlgorithmEdge],
+    weights: &EdgeWeights,
+) -> Vec<(String, f64)> {
+    let (graph, _, _) = build_digraph(edges);
+    let node_count = graph.node_count();
+    if node_count == 0 {
+        return Vec::new();
+    }
+
+    // Parallel edges between the same pair collapse to the shortest one.
+    let mut adjacency = vec![Vec::<(usize, f64)>::new(); node_count];
+    for edge in graph.edge_references() {
+        let length = 1.0 / edge_weight(weights, &graph[edge.source()], &graph[edge.target()]);
+        let target = edge.target().index();
+        let outgoing = &mut adjacency[edge.source().index()];
+        match outgoing.iter_mut().find(|(next, _)| *next == target) {
+            Some(existing) => existing.1 = existing.1.min(length),
+            None => outgoing.push((target, length)),
+        }
+    }
+
+    let mut centrality = vec![0.0f64; node_count];
+    for source in 0..node_count {
+        let mut stack = Vec::<usize>::with_capacity(node_count);
+        let mut predecessors = vec![Vec::<usize>::new(); node_count];
+        let mut sigma = vec![0.0f64; node_count];
+        let mut distance = vec![f64::INFINITY; node_count];
+        let mut settled = vec![false; node_count];
+        sigma[source] = 1.0;
+        distance[source] = 0.0;
+
+        let mut queue = BinaryHeap::new();
+        queue.push(PathCandidate {
+            distance: 0.0,
+            node: source,
+        });
+        while let Some(candidate) = queue.pop() {
+            let node = candidate.node;
+            if settled[node] {
+                continue;
+            }
+            settled[node] = true;
+            stack.push(node);
+
+            for &(next, length) in &adjacency[node] {
+                if settled[next] {
+                    continue;
+                }
+                let reached = candidate.distance + length;
+                if reached < distance[next] - PATH_LENGTH_EPSILON {
+                    distance[next] = reached;
+                    sigma[next] = sigma[node];
+                    predecessors[next] = vec![node];
+                    queue.push(PathCandidate {
+                        distance: reached,
+                        node: next,
+                    });
+                } else if (reached - distance[next]).abs() <= PATH_LENGTH_EPSILON {
+                    sigma[next] += sigma[node];
+                    predecessors[next].push(node);
+                }
+            }
+        }
+
+        let mut dependency = vec![0.0f64; node_count];
+        while let Some(node) = stack.pop() {
+            for &predecessor in &predecessors[node] {
+                dependency[predecessor] +=
+                    (sigma[predecessor] / sigma[node]) * (1.0 + dependency[node]);
+            }
+            if node != source {
+                centrality[node] += dependency[node];
+            }
+        }
+    }
+
+    let normalization = if node_count > 2 {
+        ((node_count - 1) * (node_count - 2)) as f64
+    } else {
+        0.0
+    };
+
+    sort_by_score(
+        graph
+            .node_indices()
+            .map(|node| {
+                let score = if normalization > f64::EPSILON {
+                    centrality[node.index()] / normalization
+                } else {
+                    0.0
+                };
+                (graph[node].clone(), score)
+            })
+            .collect(),
+    )
+}
+
+const PATH_LENGTH_EPSILON: f64 = 1e-9;
+
+/// Dijkstra queue entry, ordered so `BinaryHeap` pops the nearest node.
+#[derive(Debug, Clone, Copy)]
+struct PathCandidate {
+    distance: f64,
+    node: usize,
+}
+
+impl PartialEq for PathCandidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PathCandidate {}
+
+impl PartialOrd for PathCandidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PathCandidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+fn edge_weight(weights: &EdgeWeights, source: &str, target: &str) -> f64 {
+    weights
+        .get(&(source.to_owned(), target.to_owned()))
+        .copied()
+        .filter(|weight| weight.is_finite() && *weight > 0.0)
+        .unwrap_or(1.0)
+}
+
+fn sort_by_score(mut scored: Vec<(String, f64)>) -> Vec<(String, f64)> {
+    scored.sort_by(|left, right| {
+        right
+            .1
+            .partial_cmp(&left.1)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| left.0.cmp(&right.0))
+    });
+    scored
+}
+
 pub fn louvain_sync(edges: &[GraphAlgorithmEdge]) -> Vec<(String, usize)> {
     louvain_with_resolution_sync(edges, 1.0)
 }
@@ -413,6 +768,132 @@ pub fn louvain_with_resolution_sync(
         }
     }
 
+    relabel_communities(communities, &names)
+}
+
+/// Repairs `previous` Louvain assignments after the graph changed instead of
+/// starting over. Only `seeds` (symbols whose edges changed) and their
+/// neighbours are re-evaluated, and a symbol that moves queues its own
+/// neighbours, so communities away from the change keep their members.
+/// Symbols new to the graph start as singletons. Local moves cannot split a
+/// community that a deletion disconnected, so callers should still rerun
+/// `louvain_with_resolution_sync` now and then.
+pub fn louvain_repair_sync(
+    edges: &[GraphAlgorithmEdge],
+    previous: &HashMap<String, usize>,
+    seeds: &HashSet<String>,
+    resolution: f64,
+) -> Vec<(String, usize)> {
+    let (graph, by_id, names) = build_undirected_weighted_graph(edges);
+    let total_weight = graph.edge_weights().copied().sum::<f64>();
+    if graph.node_count() == 0 || total_weight <= f64::EPSILON {
+        return louvain_with_resolution_sync(edges, resolution);
+    }
+
+    let mut node_order = graph.node_indices().collect::<Vec<_>>();
+    node_order.sort_by(|left, right| names[left].cmp(&names[right]));
+
+    let mut next_community = previous.values().max().map_or(0, |max| max + 1);
+    let mut communities = HashMap::<NodeIndex, usize>::new();
+    let mut degrees = HashMap::<NodeIndex, f64>::new();
+    let mut sum_tot = HashMap::<usize, f64>::new();
+    for &node in &node_order {
+        let community = previous.get(&names[&node]).copied().unwrap_or_else(|| {
+            next_community += 1;
+            next_community - 1
+        });
+        let degree = graph.edges(node).map(|edge| *edge.weight()).sum::<f64>();
+        communities.insert(node, community);
+        degrees.insert(node, degree);
+        *sum_tot.entry(community).or_insert(0.0) += degree;
+    }
+
+    let mut queue = VecDeque::new();
+    let mut queued = HashSet::new();
+    let mut seed_nodes = seeds
+        .iter()
+        .filter_map(|seed| by_id.get(seed.as_str()).copied())
+        .collect::<Vec<_>>();
+    seed_nodes.sort_by(|left, right| names[left].cmp(&names[right]));
+    for seed in seed_nodes {
+        for node in std::iter::once(seed).chain(graph.neighbors(seed)) {
+            if queued.insert(node) {
+                queue.push_back(node);
+            }
+        }
+    }
+
+    // Every move strictly raises modularity, so this terminates; the budget
+    // only caps pathological float ties at what 50 full sweeps would cost.
+    let two_m = 2.0 * total_weight;
+    let resolution = resolution.max(0.0);
+    let mut budget = 50 * graph.node_count();
+    while let Some(node) = queue.pop_front() {
+        queued.remove(&node);
+        let current = communities[&node];
+        let k_i = degrees[&node];
+        if k_i <= f64::EPSILON {
+            continue;
+        }
+
+        let mut neighbor_weight_by_community = HashMap::<usize, f64>::new();
+        for edge in graph.edges(node) {
+            let neighbor = if edge.source() == node {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            *neighbor_weight_by_community
+                .entry(communities[&neighbor])
+                .or_insert(0.0) += *edge.weight();
+        }
+        if let Some(total) = sum_tot.get_mut(&current) {
+            *total -= k_i;
+        }
+
+        let gain = |community: usize| {
+            neighbor_weight_by_community
+                .get(&community)
+                .copied()
+                .unwrap_or(0.0)
+                - resolution * k_i * sum_tot.get(&community).copied().unwrap_or(0.0) / two_m
+        };
+        let mut candidates = neighbor_weight_by_community
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        candidates.sort_unstable();
+        let mut best_community = current;
+        let mut best_gain = gain(current);
+        for candidate in candidates {
+            let candidate_gain = gain(candidate);
+            if candidate_gain > best_gain + 1e-12 {
+                best_gain = candidate_gain;
+                best_community = candidate;
+            }
+        }
+
+        communities.insert(node, best_community);
+        *sum_tot.entry(best_community).or_insert(0.0) += k_i;
+        if best_community != current && budget > 0 {
+            budget -= 1;
+            for neighbor in graph.neighbors(node) {
+                if queued.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+
+    relabel_communities(communities, &names)
+}
+
+/// Numbers communities from smallest to largest, breaking ties by members,
+/// so the same partition always gets the same ids.
+fn relabel_communities(
+    communities: HashMap<NodeIndex, usize>,
+    names: &HashMap<NodeIndex, String>,
+) -> Vec<(String, usize)> {
     let mut members_by_community = HashMap::<usize, Vec<String>>::new();
     for (node, community) in &communities {
         members_by_community
@@ -536,6 +1017,354 @@ pub fn cross_community_edges_sync(
     out
 }
 
+/// Immediate dominator of every symbol reachable from `entry_ids`, computed
+/// with Lengauer–Tarjan. Entry points, and symbols reachable from several
+/// entry points along disjoint paths, have no dominator of their own.
+pub fn dominator_tree_sync(
+    edges: &[GraphAlgorithmEdge],
+    entry_ids: &[String],
+) -> Vec<(String, Option<String>)> {
+    let (graph, by_id, names) = build_digraph(edges);
+    let tree = DominatorTree::compute(&graph, &by_id, entry_ids);
+    let mut out = tree
+        .order
+        .iter()
+        .skip(1)
+        .filter_map(|&node| {
+            let idom = tree.idom[node];
+            let dominator = (idom != tree.root)
+                .then(|| names.get(&NodeIndex::new(idom)).cloned())
+                .flatten();
+            Some((names.get(&NodeIndex::new(node))?.clone(), dominator))
+        })
+        .collect::<Vec<_>>();
+    out.sort();
+    out
+}
+
+/// How many other symbols each symbol dominates from `entry_ids`: every
+/// path from an entry point to them passes through it. Symbols dominating
+/// nothing are left out; the rest sort by count, largest first.
+pub fn dominated_counts_sync(
+    edges: &[GraphAlgorithmEdge],
+    entry_ids: &[String],
+) -> Vec<(String, usize)> {
+    let (graph, by_id, names) = build_digraph(edges);
+    let tree = DominatorTree::compute(&graph, &by_id, entry_ids);
+    let mut sizes = vec![1usize; tree.idom.len()];
+    for &node in tree.order.iter().skip(1).rev() {
+        sizes[tree.idom[node]] += sizes[node];
+    }
+    sort_by_count(
+        tree.order
+            .iter()
+            .skip(1)
+            .filter(|&&node| sizes[node] > 1)
+            .filter_map(|&node| Some((names.get(&NodeIndex::new(node))?.clone(), sizes[node] - 1)))
+            .collect(),
+    )
+}
+
+/// Core number of every symbol, ignoring edge direction: the largest k such
+/// that the symbol belongs to a subgraph where every symbol has at least k
+/// neighbors. Sorted by core number, largest first.
+pub fn k_core_sync(edges: &[GraphAlgorithmEdge]) -> Vec<(String, usize)> {
+    let (adjacency, names) = undirected_adjacency(edges);
+    let mut degree = adjacency.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut removed = vec![false; adjacency.len()];
+    let mut core = vec![0usize; adjacency.len()];
+    let mut heap = degree
+        .iter()
+        .enumerate()
+        .map(|(node, degree)| Reverse((*degree, node)))
+        .collect::<BinaryHeap<_>>();
+    let mut current = 0usize;
+
+    while let Some(Reverse((node_degree, node))) = heap.pop() {
+        if removed[node] || node_degree != degree[node] {
+            continue;
+        }
+        removed[node] = true;
+        current = current.max(node_degree);
+        core[node] = current;
+        for &neighbor in &adjacency[node] {
+            if !removed[neighbor] {
+                degree[neighbor] -= 1;
+                heap.push(Reverse((degree[neighbor], neighbor)));
+            }
+        }
+    }
+
+    sort_by_count(
+        names
+            .into_iter()
+            .enumerate()
+            .map(|(node, name)| (name, core[node]))
+            .collect(),
+    )
+}
+
+/// Symbols whose removal splits their weakly connected component, with how
+/// many symbols end up cut off from the largest remaining piece. Sorted by
+/// that count, largest first.
+pub fn articulation_points_sync(edges: &[GraphAlgorithmEdge]) -> Vec<(String, usize)> {
+    let (adjacency, names) = undirected_adjacency(edges);
+    let cuts = CutStructure::compute(&adjacency);
+    sort_by_count(
+        cuts.articulation_points
+            .into_iter()
+            .map(|(node, cut_off)| (names[node].clone(), cut_off))
+            .collect(),
+    )
+}
+
+/// Links whose removal splits their weakly connected component, ignoring
+/// edge direction. Each pair is ordered by id.
+pub fn bridges_sync(edges: &[GraphAlgorithmEdge]) -> Vec<(String, String)> {
+    let (adjacency, names) = undirected_adjacency(edges);
+    let mut bridges = CutStructure::compute(&adjacency)
+        .bridges
+        .into_iter()
+        .map(|(left, right)| {
+            let (left, right) = (names[left].clone(), names[right].clone());
+            if left <= right {
+                (left, right)
+            } else {
+                (right, left)
+            }
+        })
+        .collect::<Vec<_>>();
+    bridges.sort();
+    bridges
+}
+
+const UNVISITED: usize = usize::MAX;
+
+/// Dominator tree over node indices, rooted at a virtual node linked to
+/// every entry point so several entries share one tree.
+struct DominatorTree {
+    root: usize,
+    /// Reachable nodes in DFS preorder, starting with the root.
+    order: Vec<usize>,
+    idom: Vec<usize>,
+}
+
+impl DominatorTree {
+    fn compute(
+        graph: &DiGraph<String, String>,
+        by_id: &HashMap<String, NodeIndex>,
+        entry_ids: &[String],
+    ) -> Self {
+        let root = graph.node_count();
+        let mut successors = graph
+            .node_indices()
+            .map(|node| {
+                graph
+                    .neighbors_directed(node, Direction::Outgoing)
+                    .map(|next| next.index())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        successors.push(
+            entry_ids
+                .iter()
+                .filter_map(|id| by_id.get(id.as_str()).map(|node| node.index()))
+                .collect(),
+        );
+        let mut predecessors = vec![Vec::new(); successors.len()];
+        for (node, next) in successors.iter().enumerate() {
+            for &target in next {
+                predecessors[target].push(node);
+            }
+        }
+
+        let mut dfnum = vec![UNVISITED; successors.len()];
+        let mut parent = vec![UNVISITED; successors.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![(root, UNVISITED)];
+        while let Some((node, from)) = stack.pop() {
+            if dfnum[node] != UNVISITED {
+                continue;
+            }
+            dfnum[node] = order.len();
+            order.push(node);
+            parent[node] = from;
+            for &next in successors[node].iter().rev() {
+                if dfnum[next] == UNVISITED {
+                    stack.push((next, node));
+                }
+            }
+        }
+
+        // Semidominators are kept as DFS numbers, as in the original paper.
+        let mut semi = dfnum.clone();
+        let mut ancestor = vec![UNVISITED; successors.len()];
+        let mut label = (0..successors.len()).collect::<Vec<_>>();
+        let mut idom = vec![UNVISITED; successors.len()];
+        let mut bucket = vec![Vec::new(); successors.len()];
+        for &node in order.iter().skip(1).rev() {
+            for &previous in &predecessors[node] {
+                if dfnum[previous] == UNVISITED {
+                    continue;
+                }
+                let candidate = eval(previous, &mut ancestor, &mut label, &semi);
+                semi[node] = semi[node].min(semi[candidate]);
+            }
+            bucket[order[semi[node]]].push(node);
+            let from = parent[node];
+            ancestor[node] = from;
+            for dominated in std::mem::take(&mut bucket[from]) {
+                let candidate = eval(dominated, &mut ancestor, &mut label, &semi);
+                idom[dominated] = if semi[candidate] < semi[dominated] {
+                    candidate
+                } else {
+                    from
+                };
+            }
+        }
+        for &node in order.iter().skip(1) {
+            if idom[node] != order[semi[node]] {
+                idom[node] = idom[idom[node]];
+            }
+        }
+
+        Self { root, order, idom }
+    }
+}
+
+fn eval(node: usize, ancestor: &mut [usize], label: &mut [usize], semi: &[usize]) -> usize {
+    if ancestor[node] == UNVISITED {
+        return node;
+    }
+    // Path compression, iteratively so long call chains cannot overflow the stack.
+    let mut path = Vec::new();
+    let mut current = node;
+    while ancestor[ancestor[current]] != UNVISITED {
+        path.push(current);
+        current = ancestor[current];
+    }
+    while let Some(current) = path.pop() {
+        let parent = ancestor[current];
+        if semi[label[parent]] < semi[label[current]] {
+            label[current] = label[parent];
+        }
+        ancestor[current] = ancestor[parent];
+    }
+    label[node]
+}
+
+/// Articulation points and bridges of an undirected graph, from one
+/// iterative Hopcroft–Tarjan pass.
+struct CutStructure {
+    /// Articulation point and the nodes cut off from the largest piece left.
+    articulation_points: Vec<(usize, usize)>,
+    bridges: Vec<(usize, usize)>,
+}
+
+impl CutStructure {
+    fn compute(adjacency: &[Vec<usize>]) -> Self {
+        let node_count = adjacency.len();
+        let mut disc = vec![UNVISITED; node_count];
+        let mut low = vec![0usize; node_count];
+        let mut parent = vec![UNVISITED; node_count];
+        let mut subtree = vec![1usize; node_count];
+        let mut separated = vec![0usize; node_count];
+        let mut largest_piece = vec![0usize; node_count];
+        let mut separating_children = vec![0usize; node_count];
+        let mut articulation_points = Vec::new();
+        let mut bridges = Vec::new();
+        let mut timer = 0usize;
+
+        for start in 0..node_count {
+            if disc[start] != UNVISITED {
+                continue;
+            }
+            let mut component = vec![start];
+            disc[start] = timer;
+            low[start] = timer;
+            timer += 1;
+            let mut stack = vec![(start, 0usize)];
+            while let Some(frame) = stack.last_mut() {
+                let node = frame.0;
+                if let Some(&next) = adjacency[node].get(frame.1) {
+                    frame.1 += 1;
+                    if disc[next] == UNVISITED {
+                        parent[next] = node;
+                        disc[next] = timer;
+                        low[next] = timer;
+                        timer += 1;
+                        component.push(next);
+                        stack.push((next, 0));
+                    } else if next != parent[node] {
+                        low[node] = low[node].min(disc[next]);
+                    }
+                    continue;
+                }
+
+                stack.pop();
+                let from = parent[node];
+                if from == UNVISITED {
+                    continue;
+                }
+                low[from] = low[from].min(low[node]);
+                subtree[from] += subtree[node];
+                if low[node] > disc[from] {
+                    bridges.push((from, node));
+                }
+                if low[node] >= disc[from] {
+                    separated[from] += subtree[node];
+                    largest_piece[from] = largest_piece[from].max(subtree[node]);
+                    separating_children[from] += 1;
+                }
+            }
+
+            let others = subtree[start] - 1;
+            for &node in &component {
+                let required = if node == start { 2 } else { 1 };
+                if separating_children[node] < required {
+                    continue;
+                }
+                let rest = others - separated[node];
+                articulation_points.push((node, others - largest_piece[node].max(rest)));
+            }
+        }
+
+        Self {
+            articulation_points,
+            bridges,
+        }
+    }
+}
+
+/// Deduplicated neighbor lists ignoring direction and self-loops, with the
+/// symbol id of every node index.
+fn undirected_adjacency(edges: &[GraphAlgorithmEdge]) -> (Vec<Vec<usize>>, Vec<String>) {
+    let (graph, _, mut names) = build_digraph(edges);
+    let adjacency = graph
+        .node_indices()
+        .map(|node| {
+            let mut neighbors = graph
+                .neighbors_undirected(node)
+                .filter(|next| *next != node)
+                .map(|next| next.index())
+                .collect::<Vec<_>>();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        })
+        .collect::<Vec<_>>();
+    let names = graph
+        .node_indices()
+        .map(|node| names.remove(&node).unwrap_or_default())
+        .collect();
+    (adjacency, names)
+}
+
+fn sort_by_count(mut counted: Vec<(String, usize)>) -> Vec<(String, usize)> {
+    counted.sort_by(|left, right| right.1.cmp(&left.1).then_with(|| left.0.cmp(&right.0)));
+    counted
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -736,4 +1565,207 @@ mod tests {
         let scores = betweenness_centrality_sync(&[]);
         assert!(scores.is_empty());
     }
+
+    #[test]
+    fn weighted_pagerank_follows_heavy_edges() {
+        let edges = vec![edge("a", "b"), edge("a", "c")];
+        let weights = EdgeWeights::from([(("a".to_owned(), "b".to_owned()), 9.0)]);
+        let scores = weighted_page_rank_sync(&edges, &weights, 0.85, 25)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        assert!(scores["b"] > scores["c"]);
+
+        let unweighted = weighted_page_rank_sync(&edges, &EdgeWeights::new(), 0.85, 25);
+        for ((id, score), (expected_id, expected)) in
+            unweighted.iter().zip(page_rank_sync(&edges, 0.85, 25))
+        {
+            assert_eq!(id, &expected_id);
+            assert!((score - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn weighted_betweenness_prefers_the_hot_path() {
+        let edges = vec![
+            edge("a", "b"),
+            edge("a", "c"),
+            edge("b", "d"),
+            edge("c", "d"),
+        ];
+        let unweighted = betweenness_centrality_sync(&edges)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        assert_eq!(unweighted["b"], unweighted["c"]);
+
+        let weights = EdgeWeights::from([
+            (("a".to_owned(), "b".to_owned()), 10.0),
+            (("b".to_owned(), "d".to_owned()), 10.0),
+        ]);
+        let scores = weighted_betweenness_centrality_sync(&edges, &weights)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        assert!(scores["b"] > 0.0);
+        assert_eq!(scores["c"], 0.0);
+    }
+
+    #[test]
+    fn dominators_find_the_single_gateway_into_a_region() {
+        let edges = vec![
+            edge("main", "router"),
+            edge("router", "auth"),
+            edge("router", "cache"),
+            edge("auth", "db"),
+            edge("cache", "db"),
+            edge("db", "pool"),
+            edge("cli", "db"),
+        ];
+        let entries = vec!["main".to_owned()];
+
+        let tree = dominator_tree_sync(&edges, &entries)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        assert_eq!(tree["main"], None);
+        assert_eq!(tree["router"].as_deref(), Some("main"));
+        assert_eq!(tree["db"].as_deref(), Some("router"));
+        assert_eq!(tree["pool"].as_deref(), Some("db"));
+        assert!(!tree.contains_key("cli"));
+
+        assert_eq!(
+            dominated_counts_sync(&edges, &entries),
+            vec![
+                ("main".to_owned(), 5),
+                ("router".to_owned(), 4),
+                ("db".to_owned(), 1),
+            ]
+        );
+
+        // A second entry reaching `db` on its own takes `db` out of the
+        // region `router` guards.
+        let entries = vec!["main".to_owned(), "cli".to_owned()];
+        let counts = dominated_counts_sync(&edges, &entries)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        assert_eq!(counts["router"], 2);
+        assert_eq!(counts["db"], 1);
+    }
+
+    #[test]
+    fn k_core_separates_dense_cluster_from_tail() {
+        let edges = vec![
+            edge("a", "b"),
+            edge("b", "c"),
+            edge("c", "a"),
+            edge("a", "d"),
+            edge("b", "d"),
+            edge("c", "d"),
+            edge("d", "tail"),
+        ];
+        let cores = k_core_sync(&edges).into_iter().collect::<HashMap<_, _>>();
+        assert_eq!(cores["a"], 3);
+        assert_eq!(cores["d"], 3);
+        assert_eq!(cores["tail"], 1);
+    }
+
+    #[test]
+    fn articulation_points_and_bridges_split_two_triangles() {
+        let edges = vec![
+            edge("a", "b"),
+            edge("b", "c"),
+            edge("c", "a"),
+            edge("c", "d"),
+            edge("d", "e"),
+            edge("e", "f"),
+            edge("f", "d"),
+            edge("f", "g"),
+        ];
+        assert_eq!(
+            articulation_points_sync(&edges),
+            vec![
+                ("d".to_owned(), 3),
+                ("c".to_owned(), 2),
+                ("f".to_owned(), 1),
+            ]
+        );
+        assert_eq!(
+            bridges_sync(&edges),
+            vec![
+                ("c".to_owned(), "d".to_owned()),
+                ("f".to_owned(), "g".to_owned()),
+            ]
+        );
+        assert!(articulation_points_sync(&[edge("x", "y"), edge("y", "x")]).is_empty());
+    }
+
+    #[test]
+    fn incremental_page_rank_follows_edge_deltas() {
+        let before = vec![
+            edge("a", "b"),
+            edge("b", "c"),
+            edge("c", "a"),
+            edge("c", "d"),
+            edge("x", "y"),
+        ];
+        let full = incremental_page_rank_sync(&[], &HashMap::new(), &before, 0.85, 1e-10);
+        let converged = page_rank_sync(&before, 0.85, 200)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        for (id, score) in &full.ranks {
+            assert!((score - converged[id]).abs() < 1e-8, "{id}");
+        }
+
+        let after = vec![
+            edge("a", "b"),
+            edge("b", "c"),
+            edge("c", "d"),
+            edge("d", "e"),
+            edge("x", "y"),
+        ];
+        let previous = full.reach.iter().cloned().collect::<HashMap<_, _>>();
+        let updated = incremental_page_rank_sync(&before, &previous, &after, 0.85, 1e-10);
+        let scratch = incremental_page_rank_sync(&[], &HashMap::new(), &after, 0.85, 1e-10);
+        assert_eq!(updated.reach.len(), scratch.reach.len());
+        for ((id, left), (scratch_id, right)) in updated.reach.iter().zip(&scratch.reach) {
+            assert_eq!(id, scratch_id);
+            assert!((left - right).abs() < 1e-8, "{id}");
+        }
+
+        let mut grown = after.clone();
+        grown.push(edge("e", "f"));
+        let previous = updated.reach.iter().cloned().collect::<HashMap<_, _>>();
+        let leaf = incremental_page_rank_sync(&after, &previous, &grown, 0.85, 1e-10);
+        assert_eq!(leaf.pushes, 1);
+        let leaf_reach = leaf.reach.iter().cloned().collect::<HashMap<_, _>>();
+        assert_eq!(leaf_reach["x"], previous["x"]);
+    }
+
+    #[test]
+    fn louvain_repair_only_moves_symbols_near_the_change() {
+        let edges = vec![
+            edge("a", "b"),
+            edge("b", "c"),
+            edge("c", "a"),
+            edge("x", "y"),
+            edge("y", "z"),
+            edge("z", "x"),
+            edge("c", "x"),
+        ];
+        let previous = louvain_sync(&edges).into_iter().collect::<HashMap<_, _>>();
+
+        let mut changed = edges.clone();
+        changed.extend([edge("n", "x"), edge("n", "y")]);
+        let seeds = ["n", "x", "y"].map(str::to_owned).into_iter().collect();
+        let repaired = louvain_repair_sync(&changed, &previous, &seeds, 1.0)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(repaired.len(), 7);
+        assert_eq!(repaired["n"], repaired["x"]);
+        assert_eq!(repaired["a"], repaired["b"]);
+        assert_eq!(repaired["a"], repaired["c"]);
+        assert_ne!(repaired["a"], repaired["x"]);
+        assert_eq!(
+            louvain_repair_sync(&edges, &previous, &HashSet::new(), 1.0),
+            louvain_sync(&edges)
+        );
+    }
 }
@@ -9,6 +9,8 @@ const STALE_REF_TEMPLATE: &str = "{context} still has {value} stale backend refe
 const GIT_CHURN_TEMPLATE: &str =
     "{context} saw {value} commits in 30 days - high churn concentration";
 const GIT_AUTHOR_TEMPLATE: &str = "{value} authors touching {context} - coordination hotspot";
+const GIT_BUS_FACTOR_TEMPLATE: &str =
+    "{value} author(s) own half of {context} - knowledge concentrated in too few people";
 const BOUNDARY_LEAKAGE_TEMPLATE: &str =
     "High boundary leakage - symbols in {context} span {value} communities";
 const ZOMBIE_TEMPLATE: &str =
@@ -27,6 +29,7 @@ pub fn explain_violation(metric: &str, value: f64, _threshold: f64, context: &st
         "stale_backend_refs" => STALE_REF_TEMPLATE,
         "git_churn_30d" => GIT_CHURN_TEMPLATE,
         "git_author_count" => GIT_AUTHOR_TEMPLATE,
+        "git_bus_factor" => GIT_BUS_FACTOR_TEMPLATE,
         "boundary_leakage" => BOUNDARY_LEAKAGE_TEMPLATE,
         "zombie_file" => ZOMBIE_TEMPLATE,
         "false_stable" => FALSE_STABLE_TEMPLATE,
@@ -1,20 +1,30 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use aether_config::HealthScoreConfig;
-use aether_core::git::GitContext;
+use aether_core::git::{BlameLine, GitContext};
 
 use crate::metrics::count_loc;
 use crate::models::{GitSignals, HealthError};
 use crate::scanner::WorkspaceCrate;
 
 const GIT_FILE_LIMIT: usize = 20;
-const CHURN_30D_WEIGHT: f64 = 0.35;
-const CHURN_90D_WEIGHT: f64 = 0.25;
-const AUTHOR_COUNT_WEIGHT: f64 = 0.25;
+const CHURN_30D_WEIGHT: f64 = 0.30;
+const CHURN_90D_WEIGHT: f64 = 0.20;
+const AUTHOR_COUNT_WEIGHT: f64 = 0.20;
 const BLAME_AGE_WEIGHT: f64 = 0.15;
+const BUS_FACTOR_WEIGHT: f64 = 0.15;
+const HISTORY_LIMIT: usize = 500;
+/// Age in days at which a blamed line's ownership weight halves.
+pub const OWNERSHIP_HALF_LIFE_DAYS: f64 = 180.0;
+/// Weight kept by lines older than the walked history, so long-stable code
+/// still has an owner.
+const MIN_OWNERSHIP_WEIGHT: f64 = 0.1;
+/// Share of the weighted lines the bus-factor authors must own together.
+const BUS_FACTOR_SHARE: f64 = 0.5;
+const SECONDS_PER_DAY: f64 = 86_400.0;
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct FileGitStats {
@@ -22,6 +32,18 @@ pub struct FileGitStats {
     pub commits_90d: usize,
     pub author_count: usize,
     pub blame_age_std_dev: f64,
+    /// Recency-weighted blamed lines per author.
+    pub author_weights: BTreeMap<String, f64>,
+}
+
+/// A blamed line with its ownership weight, `0.5^(age / half-life)` of the
+/// commit that last touched it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedBlameLine {
+    pub line_number: u32,
+    pub commit_hash: String,
+    pub author: String,
+    pub weight: f64,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -38,14 +60,11 @@ pub(crate) struct GitSignalAnalysis {
 }
 
 pub fn compute_file_git_stats(git: &GitContext, file_path: &Path) -> FileGitStats {
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs() as i64;
+    let now = now_secs();
     let cutoff_30d = now - 30 * 24 * 60 * 60;
     let cutoff_90d = now - 90 * 24 * 60 * 60;
 
-    let commits = git.file_log(file_path, 500);
+    let commits = git.file_log(file_path, HISTORY_LIMIT);
     let commits_30d = commits
         .iter()
         .filter(|commit| commit.timestamp >= cutoff_30d)
@@ -68,13 +87,89 @@ pub fn compute_file_git_stats(git: &GitContext, file_path: &Path) -> FileGitStat
         .iter()
         .filter_map(|line| commit_timestamps.get(line.commit_hash.as_str()).copied())
         .collect::<Vec<_>>();
+    let mut author_weights = BTreeMap::<String, f64>::new();
+    for line in weigh_blame(&blame, &commit_timestamps, now) {
+        if !line.author.is_empty() {
+            *author_weights.entry(line.author).or_default() += line.weight;
+        }
+    }
 
     FileGitStats {
         commits_30d,
         commits_90d,
         author_count: authors.len(),
         blame_age_std_dev: standard_deviation(&blame_timestamps),
+        author_weights,
+    }
+}
+
+/// Blame of a file at HEAD with each line weighted by the recency of the
+/// commit that last touched it.
+pub fn weighted_blame_lines(git: &GitContext, file_path: &Path) -> Vec<WeightedBlameLine> {
+    let blame = git.blame_lines(file_path);
+    if blame.is_empty() {
+        return Vec::new();
+    }
+    let commit_timestamps = git
+        .file_log(file_path, HISTORY_LIMIT)
+        .into_iter()
+        .map(|commit| (commit.hash, commit.timestamp))
+        .collect::<HashMap<_, _>>();
+    weigh_blame(&blame, &commit_timestamps, now_secs())
+}
+
+/// Ownership weight of a line last changed at `timestamp`; lines whose
+/// commit fell outside the walked history keep the minimum weight.
+pub fn ownership_weight(timestamp: Option<i64>, now: i64) -> f64 {
+    let Some(timestamp) = timestamp else {
+        return MIN_OWNERSHIP_WEIGHT;
+    };
+    let age_days = (now - timestamp).max(0) as f64 / SECONDS_PER_DAY;
+    0.5f64
+        .powf(age_days / OWNERSHIP_HALF_LIFE_DAYS)
+        .max(MIN_OWNERSHIP_WEIGHT)
+}
+
+/// Fewest authors who together own at least half of the weighted lines; 0
+/// when there is nothing to own.
+pub fn bus_factor(author_weights: impl IntoIterator<Item = f64>) -> usize {
+    let mut weights = author_weights
+        .into_iter()
+        .filter(|weight| *weight > 0.0)
+        .collect::<Vec<_>>();
+    let total = weights.iter().sum::<f64>();
+    if total <= 0.0 {
+        return 0;
+    }
+    weights.sort_by(|left, right| right.total_cmp(left));
+
+    let mut covered = 0.0;
+    for (index, weight) in weights.iter().enumerate() {
+        covered += weight;
+        if covered >= total * BUS_FACTOR_SHARE {
+            return index + 1;
+        }
     }
+    weights.len()
+}
+
+fn weigh_blame(
+    blame: &[BlameLine],
+    commit_timestamps: &HashMap<String, i64>,
+    now: i64,
+) -> Vec<WeightedBlameLine> {
+    blame
+        .iter()
+        .map(|line| WeightedBlameLine {
+            line_number: line.line_number,
+            commit_hash: line.commit_hash.clone(),
+            author: line.author.clone(),
+            weight: ownership_weight(
+                commit_timestamps.get(line.commit_hash.as_str()).copied(),
+                now,
+            ),
+        })
+        .collect()
 }
 
 pub fn normalize_git_signals(stats: &FileGitStats, config: &HealthScoreConfig) -> GitSignals {
@@ -87,13 +182,19 @@ pub fn normalize_git_signals(stats: &FileGitStats, config: &HealthScoreConfig) -
         (stats.blame_age_std_dev / config.blame_age_spread_high_secs as f64).clamp(0.0, 1.0)
     };
 
-    GitSignals {
+    let mut signals = GitSignals {
         churn_30d,
         churn_90d,
         author_count,
         blame_age_spread,
-        git_pressure: combined_git_pressure(churn_30d, churn_90d, author_count, blame_age_spread),
-    }
+        ..GitSignals::default()
+    };
+    apply_bus_factor(
+        &mut signals,
+        bus_factor(stats.author_weights.values().copied()),
+        config,
+    );
+    signals
 }
 
 pub fn aggregate_crate_git_signals(file_stats: &[GitSignals]) -> GitSignals {
@@ -119,13 +220,22 @@ pub fn aggregate_crate_git_signals(file_stats: &[GitSignals]) -> GitSignals {
         .sum::<f64>()
         / file_stats.len() as f64;
 
-    GitSignals {
+    let mut signals = GitSignals {
         churn_30d,
         churn_90d,
         author_count,
         blame_age_spread,
-        git_pressure: combined_git_pressure(churn_30d, churn_90d, author_count, blame_age_spread),
-    }
+        ..GitSignals::default()
+    };
+    signals.git_pressure = combined_git_pressure(&signals);
+    signals
+}
+
+/// Sets the bus factor of `signals` and folds its risk into git pressure.
+pub fn apply_bus_factor(signals: &mut GitSignals, bus_factor: usize, config: &HealthScoreConfig) {
+    signals.bus_factor = bus_factor;
+    signals.bus_factor_risk = normalize_bus_factor(bus_factor, config.bus_factor_healthy);
+    signals.git_pressure = combined_git_pressure(signals);
 }
 
 pub(crate) fn analyze_crate_git_signals(
@@ -153,12 +263,25 @@ pub(crate) fn analyze_crate_git_signals(
         })
         .collect::<Vec<_>>();
 
-    let signals = aggregate_crate_git_signals(
+    let mut signals = aggregate_crate_git_signals(
         &files
             .iter()
             .map(|entry| entry.normalized.clone())
             .collect::<Vec<_>>(),
     );
+    // Ownership is merged across files: a crate whose files each have a
+    // different sole author still spreads its knowledge.
+    let mut author_weights = HashMap::<&str, f64>::new();
+    for entry in &files {
+        for (author, weight) in &entry.raw.author_weights {
+            *author_weights.entry(author.as_str()).or_default() += weight;
+        }
+    }
+    apply_bus_factor(
+        &mut signals,
+        bus_factor(author_weights.into_values()),
+        config,
+    );
 
     Ok(GitSignalAnalysis { files, signals })
 }
@@ -197,19 +320,32 @@ fn normalize_author_count(value: usize, high: usize) -> f64 {
     ((value - 1) as f64 / (high - 1) as f64).clamp(0.0, 1.0)
 }
 
-fn combined_git_pressure(
-    churn_30d: f64,
-    churn_90d: f64,
-    author_count: f64,
-    blame_age_spread: f64,
-) -> f64 {
-    (churn_30d * CHURN_30D_WEIGHT
-        + churn_90d * CHURN_90D_WEIGHT
-        + author_count * AUTHOR_COUNT_WEIGHT
-        + blame_age_spread * BLAME_AGE_WEIGHT)
+/// A single owner is full risk and `healthy` owners none; 0 owners means
+/// nothing was blamed and `healthy` 0 disables the signal.
+fn normalize_bus_factor(bus_factor: usize, healthy: usize) -> f64 {
+    if bus_factor == 0 || healthy <= 1 {
+        return 0.0;
+    }
+
+    (healthy.saturating_sub(bus_factor) as f64 / (healthy - 1) as f64).clamp(0.0, 1.0)
+}
+
+fn combined_git_pressure(signals: &GitSignals) -> f64 {
+    (signals.churn_30d * CHURN_30D_WEIGHT
+        + signals.churn_90d * CHURN_90D_WEIGHT
+        + signals.author_count * AUTHOR_COUNT_WEIGHT
+        + signals.blame_age_spread * BLAME_AGE_WEIGHT
+        + signals.bus_factor_risk * BUS_FACTOR_WEIGHT)
         .clamp(0.0, 1.0)
 }
 
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
 fn standard_deviation(values: &[i64]) -> f64 {
     if values.len() <= 1 {
         return 0.0;
@@ -232,7 +368,12 @@ fn standard_deviation(values: &[i64]) -> f64 {
 mod tests {
     use aether_config::HealthScoreConfig;
 
-    use super::{FileGitStats, aggregate_crate_git_signals, normalize_git_signals};
+    use std::collections::BTreeMap;
+
+    use super::{
+        FileGitStats, aggregate_crate_git_signals, bus_factor, normalize_git_signals,
+        ownership_weight,
+    };
 
     #[test]
     fn normalize_git_signals_respects_thresholds() {
@@ -242,6 +383,7 @@ mod tests {
             commits_90d: 15,
             author_count: 3,
             blame_age_std_dev: 7_776_000.0,
+            ..FileGitStats::default()
         };
 
         let normalized = normalize_git_signals(&stats, &config);
@@ -259,6 +401,7 @@ mod tests {
                     commits_90d: 10,
                     author_count: 2,
                     blame_age_std_dev: 100.0,
+                    ..FileGitStats::default()
                 },
                 &HealthScoreConfig::default(),
             ),
@@ -268,6 +411,7 @@ mod tests {
                     commits_90d: 30,
                     author_count: 6,
                     blame_age_std_dev: 15_552_000.0,
+                    ..FileGitStats::default()
                 },
                 &HealthScoreConfig::default(),
             ),
@@ -278,4 +422,29 @@ mod tests {
         assert_eq!(aggregated.author_count, 1.0);
         assert!(aggregated.blame_age_spread > 0.49);
     }
+
+    #[test]
+    fn bus_factor_counts_owners_of_half_the_weighted_lines() {
+        assert_eq!(bus_factor(Vec::new()), 0);
+        assert_eq!(bus_factor([10.0, 1.0, 1.0]), 1);
+        assert_eq!(bus_factor([4.0, 3.0, 3.0]), 2);
+        assert_eq!(bus_factor([1.0, 1.0, 1.0, 1.0]), 2);
+
+        let now = 400 * 86_400;
+        assert_eq!(ownership_weight(Some(now), now), 1.0);
+        assert!((ownership_weight(Some(now - 180 * 86_400), now) - 0.5).abs() < 1e-9);
+        assert_eq!(ownership_weight(None, now), 0.1);
+
+        let config = HealthScoreConfig::default();
+        let solo = normalize_git_signals(
+            &FileGitStats {
+                author_weights: BTreeMap::from([("ana".to_owned(), 40.0)]),
+                ..FileGitStats::default()
+            },
+            &config,
+        );
+        assert_eq!(solo.bus_factor, 1);
+        assert_eq!(solo.bus_factor_risk, 1.0);
+        assert!((solo.git_pressure - 0.15).abs() < 1e-9);
+    }
 }
@@ -413,6 +413,22 @@ fn build_violations(
                 hotspot.path.clone(),
             );
         }
+
+        let bus_factor = analysis.signals.bus_factor;
+        if bus_factor > 0 && bus_factor < config.bus_factor_healthy {
+            violations.push(Violation {
+                metric: "git_bus_factor".to_owned(),
+                value: bus_factor as f64,
+                threshold: config.bus_factor_healthy as f64,
+                severity: ViolationLevel::Warn,
+                reason: explain_violation(
+                    "git_bus_factor",
+                    bus_factor as f64,
+                    config.bus_factor_healthy as f64,
+                    &crate_info.name,
+                ),
+            });
+        }
     }
 
     if let Some(analysis) = semantic_analysis {
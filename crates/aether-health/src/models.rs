@@ -182,6 +182,12 @@ pub struct GitSignals {
     pub churn_90d: f64,
     pub author_count: f64,
     pub blame_age_spread: f64,
+    /// Fewest authors owning half of the recency-weighted blamed lines; 0
+    /// when nothing could be blamed.
+    #[serde(default)]
+    pub bus_factor: usize,
+    #[serde(default)]
+    pub bus_factor_risk: f64,
     pub git_pressure: f64,
 }
 
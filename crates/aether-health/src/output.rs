@@ -287,6 +287,10 @@ pub fn format_crate_explanation(
         lines.push(format!("  churn_90d: {:.2}", git.churn_90d));
         lines.push(format!("  author_count: {:.2}", git.author_count));
         lines.push(format!("  blame_age_spread: {:.2}", git.blame_age_spread));
+        lines.push(format!(
+            "  bus_factor: {} (risk {:.2})",
+            git.bus_factor, git.bus_factor_risk
+        ));
         lines.push(format!("  git_pressure: {:.2}", git.git_pressure));
     }
 
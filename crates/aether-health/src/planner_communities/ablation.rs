@@ -744,6 +744,12 @@ fn ablation_parse_symbol_kind(raw: &str) -> SymbolKind {
         "trait" => SymbolKind::Trait,
         "interface" => SymbolKind::Interface,
         "type_alias" | "typealias" => SymbolKind::TypeAlias,
+        "constant" => SymbolKind::Constant,
+        "static" => SymbolKind::Static,
+        "macro" => SymbolKind::Macro,
+        "module" => SymbolKind::Module,
+        "field" => SymbolKind::Field,
+        "enum_variant" => SymbolKind::EnumVariant,
         _ => SymbolKind::Function,
     }
 }
@@ -2,6 +2,7 @@ mod embedding;
 mod http;
 mod loaders;
 mod providers;
+mod redaction;
 mod reranker;
 mod sir_parsing;
 pub mod sir_prompt;
@@ -18,7 +19,12 @@ pub use loaders::{
     load_provider_from_env_or_mock, load_reranker_provider_from_config, summarize_text_with_config,
 };
 pub use providers::{GeminiProvider, OpenAiCompatProvider, Qwen3LocalProvider, TieredProvider};
+pub use redaction::{
+    RedactedText, RedactingEmbeddingProvider, RedactingInferenceProvider, RedactionAuditRecord,
+    RedactionCategory, RedactionFinding, Redactor,
+};
 pub use reranker::{MockRerankerProvider, RerankCandidate, RerankResult, RerankerProvider};
+pub use sir_parsing::build_retry_prompt;
 pub use sir_prompt::PromptTier;
 pub use types::{
     EmbeddingProvider, EmbeddingProviderOverrides, EmbeddingPurpose, GEMINI_API_KEY_ENV,
@@ -167,6 +167,7 @@ mod tests {
                 edge_cases: None,
                 complexity: None,
                 method_dependencies: None,
+                extensions: Default::default(),
             })
         }
     }
@@ -198,6 +199,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 64,
+            extension_fields: Vec::new(),
         };
 
         let sir = provider
@@ -236,6 +238,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: false,
             line_count: 8,
+            extension_fields: Vec::new(),
         };
 
         let sir = provider
@@ -274,6 +277,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 64,
+            extension_fields: Vec::new(),
         };
 
         let sir = provider
@@ -312,6 +316,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 64,
+            extension_fields: Vec::new(),
         };
 
         let err = provider
@@ -3,11 +3,8 @@ use serde_json::Value;
 
 use crate::types::InferError;
 
-pub(crate) fn build_retry_prompt(
-    original_prompt: &str,
-    error: &str,
-    previous_output: &str,
-) -> String {
+/// Appends corrective feedback about a rejected SIR to the original prompt.
+pub fn build_retry_prompt(original_prompt: &str, error: &str, previous_output: &str) -> String {
     format!(
         "{original_prompt}\n\nYour previous response was invalid. Error: {error}. Previous output: {previous_output}. Please respond again with STRICT JSON only, fixing the error above."
     )
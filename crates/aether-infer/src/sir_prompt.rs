@@ -268,6 +268,13 @@ fn is_function_like(kind: &str) -> bool {
     matches!(kind, "function" | "method")
 }
 
+fn is_value_definition(kind: &str) -> bool {
+    matches!(
+        kind,
+        "constant" | "static" | "variable" | "field" | "enum_variant"
+    )
+}
+
 fn is_test_like(context: &SirContext) -> bool {
     context
         .qualified_name
@@ -290,6 +297,35 @@ Bad intent example: 'Define a database structure'"
         );
     }
 
+    if is_value_definition(context.kind.as_str()) {
+        sections.push(
+            "For constants, statics, fields and enum variants: describe what behavior this value\n\
+controls and what breaks if it changes. State units, valid ranges and defaults when visible.\n\
+Inputs and outputs should be empty arrays. List types and other constants used in the\n\
+initializer as dependencies. side_effects is empty unless the value is mutable global state.\n\
+Good intent example: 'Upper bound on retry attempts for outbound webhook delivery; raising it lengthens worst-case queue latency'\n\
+Bad intent example: 'A constant for retries'"
+                .to_owned(),
+        );
+    }
+
+    if context.kind == "macro" {
+        sections.push(
+            "For macros: describe what code the macro expands to and why a macro is used instead of a function.\n\
+Inputs are the accepted argument forms (one per matcher arm); outputs are the generated items or expressions.\n\
+List items the expansion references as dependencies."
+                .to_owned(),
+        );
+    }
+
+    if context.kind == "module" {
+        sections.push(
+            "For modules: describe the module's responsibility and the boundary it draws.\n\
+Inputs and outputs should be empty arrays. List the most important items it exposes or depends on as dependencies."
+                .to_owned(),
+        );
+    }
+
     if is_function_like(context.kind.as_str()) {
         if context.is_public && context.line_count > 30 {
             sections.push(
@@ -325,16 +361,53 @@ For dependencies, list the production code under test."
     }
 }
 
-fn strict_response_contract(_context: &SirContext) -> &'static str {
-    "Respond with STRICT JSON only (no markdown, no prose) and exactly these fields: \
+fn extension_contract(context: &SirContext) -> String {
+    if context.extension_fields.is_empty() {
+        return String::new();
+    }
+
+    let mut section = String::from(
+        "Also include an \"extensions\" object with these workspace-specific fields:\n",
+    );
+    for field in &context.extension_fields {
+        let presence = if field.required {
+            "required"
+        } else {
+            "omit when not applicable"
+        };
+        section.push_str(&format!(
+            "- {} ({}, {}): {}\n",
+            field.name,
+            field.kind.json_shape(),
+            presence,
+            field.description
+        ));
+    }
+    section
+}
+
+fn strict_response_contract(context: &SirContext) -> String {
+    format!(
+        "Respond with STRICT JSON only (no markdown, no prose) and exactly these fields: \
 intent (string), inputs (array of string), outputs (array of string), side_effects (array of string), dependencies (array of string), error_modes (array of string), confidence (number in [0.0,1.0]).\n\
+{}\
 Do not add any extra keys.\n\n\
-"
+",
+        extension_contract(context)
+    )
 }
 
-fn enriched_response_contract(_context: &SirContext) -> &'static str {
-    "\n\nRespond with STRICT JSON only. Exactly these fields: intent, inputs, outputs,\n\
-side_effects, dependencies, error_modes, confidence."
+fn enriched_response_contract(context: &SirContext) -> String {
+    let mut contract = String::from(
+        "\n\nRespond with STRICT JSON only. Exactly these fields: intent, inputs, outputs,\n\
+side_effects, dependencies, error_modes, confidence.",
+    );
+    let extensions = extension_contract(context);
+    if !extensions.is_empty() {
+        contract.push('\n');
+        contract.push_str(extensions.trim_end());
+    }
+    contract
 }
 
 pub fn build_sir_prompt_for_kind(symbol_text: &str, context: &SirContext) -> String {
@@ -448,7 +521,7 @@ including conditional mutations, correct confidence reflecting your certainty.",
         );
     }
 
-    prompt.push_str(enriched_response_contract(context));
+    prompt.push_str(&enriched_response_contract(context));
     prompt
 }
 
@@ -531,16 +604,28 @@ pub fn sir_scan_user_prompt(symbol_text: &str, context: &SirContext) -> String {
 - language: {}\n\
 - file_path: {}\n\
 - qualified_name: {}\n\
-{}\n\n\
+{}{}\n\n\
 Symbol text:\n{}",
         context.language,
         context.file_path,
         context.qualified_name,
         kind_specific_guidance(context),
+        user_prompt_extension_section(context),
         symbol_text,
     )
 }
 
+/// Extension instructions for per-symbol user prompts; the cached system
+/// prompts only describe the core schema.
+fn user_prompt_extension_section(context: &SirContext) -> String {
+    let extensions = extension_contract(context);
+    if extensions.is_empty() {
+        String::new()
+    } else {
+        format!("\n\n{}", extensions.trim_end())
+    }
+}
+
 /// Static system instruction for enriched (triage/deep) pass at the given tier.
 pub fn sir_enriched_system_prompt(tier: PromptTier) -> String {
     let mut prompt = String::from(
@@ -621,6 +706,8 @@ including conditional mutations, correct confidence reflecting your certainty.",
         );
     }
 
+    prompt.push_str(&user_prompt_extension_section(context));
+
     if include_cot {
         prompt.push_str(
             "\n\nBefore outputting JSON, you MUST wrap your analysis inside <thinking> tags:\n\
@@ -668,11 +755,33 @@ mod tests {
             kind: "struct".to_owned(),
             is_public: true,
             line_count: 12,
+            extension_fields: Vec::new(),
         };
         let prompt = build_sir_prompt_for_kind("pub struct State {}", &context);
         assert!(prompt.contains("For type definitions: describe WHY this type exists"));
     }
 
+    #[test]
+    fn build_sir_prompt_for_kind_includes_value_and_macro_guidance() {
+        let mut context = SirContext {
+            language: "rust".to_owned(),
+            file_path: "src/config.rs".to_owned(),
+            qualified_name: "MAX_RETRIES".to_owned(),
+            priority_score: None,
+            kind: "constant".to_owned(),
+            is_public: true,
+            line_count: 1,
+            extension_fields: Vec::new(),
+        };
+        let prompt = build_sir_prompt_for_kind("pub const MAX_RETRIES: u32 = 5;", &context);
+        assert!(prompt.contains("For constants, statics, fields and enum variants"));
+        assert!(!prompt.contains("For type definitions"));
+
+        context.kind = "macro".to_owned();
+        let prompt = build_sir_prompt_for_kind("macro_rules! ensure { () => {} }", &context);
+        assert!(prompt.contains("For macros: describe what code the macro expands to"));
+    }
+
     #[test]
     fn build_sir_prompt_for_kind_keeps_function_schema_strict() {
         let context = SirContext {
@@ -683,6 +792,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 8,
+            extension_fields: Vec::new(),
         };
 
         let prompt = build_sir_prompt_for_kind("pub fn run() {}", &context);
@@ -699,6 +809,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 55,
+            extension_fields: Vec::new(),
         };
         let prompt = build_sir_prompt_for_kind("pub fn run() -> Result<()> {}", &context);
         assert!(prompt.contains("For complex public methods"));
@@ -715,6 +826,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: false,
             line_count: 14,
+            extension_fields: Vec::new(),
         };
         let prompt = build_sir_prompt_for_kind("fn test_retry_reset() {}", &context);
         assert!(prompt.contains("For test functions: describe what behavior is being verified"));
@@ -730,6 +842,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 60,
+            extension_fields: Vec::new(),
         };
         let enrichment = SirEnrichmentContext {
             file_intent: Some("Coordinates request lifecycle".to_owned()),
@@ -755,6 +868,7 @@ mod tests {
                 edge_cases: None,
                 complexity: None,
                 method_dependencies: None,
+                extensions: Default::default(),
             }),
             priority_reason: "high PageRank + public method".to_owned(),
             caller_contract_clauses: Vec::new(),
@@ -780,6 +894,7 @@ mod tests {
             kind: "trait".to_owned(),
             is_public: true,
             line_count: 60,
+            extension_fields: Vec::new(),
         };
         let enrichment = SirEnrichmentContext {
             file_intent: None,
@@ -804,6 +919,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 60,
+            extension_fields: Vec::new(),
         };
         let enrichment = SirEnrichmentContext {
             file_intent: None,
@@ -828,6 +944,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 60,
+            extension_fields: Vec::new(),
         };
         let enrichment = SirEnrichmentContext {
             file_intent: None,
@@ -865,6 +982,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 10,
+            extension_fields: Vec::new(),
         };
         let enrichment = SirEnrichmentContext {
             file_intent: None,
@@ -890,6 +1008,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 8,
+            extension_fields: Vec::new(),
         };
         let original = build_sir_prompt_for_kind("pub fn run() {}", &context);
         let system = sir_scan_system_prompt(PromptTier::Compact);
@@ -936,6 +1055,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: true,
             line_count: 60,
+            extension_fields: Vec::new(),
         };
         let enrichment = SirEnrichmentContext {
             file_intent: Some("Coordinates request lifecycle".to_owned()),
@@ -1,7 +1,7 @@
 use aether_config::{
     DEFAULT_GEMINI_API_KEY_ENV, EmbeddingProviderKind, InferenceProviderKind, SearchRerankerKind,
 };
-use aether_sir::SirAnnotation;
+use aether_sir::{SirAnnotation, SirExtensionField};
 use async_trait::async_trait;
 use thiserror::Error;
 
@@ -16,6 +16,8 @@ pub struct SirContext {
     pub kind: String,
     pub is_public: bool,
     pub line_count: usize,
+    /// Workspace-declared extension fields the response must include.
+    pub extension_fields: Vec<SirExtensionField>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -122,6 +124,8 @@ pub enum InferError {
     ModelUnavailable(String),
     #[error("failed to lock shared resource: {0}")]
     LockPoisoned(String),
+    #[error("request blocked by redaction policy: {0}")]
+    RedactionBlocked(String),
 }
 
 #[async_trait]
@@ -260,6 +264,7 @@ mod tests {
                 edge_cases: None,
                 complexity: None,
                 method_dependencies: None,
+                extensions: Default::default(),
             })
         }
     }
@@ -275,6 +280,7 @@ mod tests {
             kind: "function".to_owned(),
             is_public: false,
             line_count: 1,
+            extension_fields: Vec::new(),
         };
 
         let sir = provider
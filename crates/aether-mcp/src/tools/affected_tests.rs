@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use aether_analysis::{
+    AffectedTest, AffectedTestsAnalyzer, AffectedTestsRequest, ChangedSymbol,
+    DEFAULT_AFFECTED_TESTS_MAX_DEPTH,
+};
+use aether_store::SurrealGraphStore;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::AetherMcpServer;
+use crate::AetherMcpError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherAffectedTestsRequest {
+    /// Git ref to compare against, e.g. "origin/main"
+    pub base: String,
+    /// Git ref with the changes (defaults to the working tree)
+    pub head: Option<String>,
+    /// Dependency hops walked back from each changed symbol (default 4, max 10)
+    pub depth: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherChangedSymbol {
+    pub symbol_id: String,
+    pub qualified_name: String,
+    pub file_path: String,
+    /// "added", "modified" or "removed"
+    pub change: String,
+    pub covering_tests: u32,
+    pub uncovered: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherAffectedTest {
+    pub file_path: String,
+    pub test_name: String,
+    pub intent_text: String,
+    /// "cargo", "pytest" or "jest"
+    pub runner: String,
+    pub package: Option<String>,
+    /// `cargo test` filter, pytest node id or jest `-t` name
+    pub selector: String,
+    /// "changed", "graph" or "tested_by"
+    pub reason: String,
+    pub depth: Option<u32>,
+    pub confidence: Option<f32>,
+    pub triggered_by: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherAffectedTestsResponse {
+    pub base_commit: String,
+    pub head_commit: Option<String>,
+    pub changed_files: u32,
+    pub changed_symbols: Vec<AetherChangedSymbol>,
+    pub uncovered_symbols: u32,
+    pub tests: Vec<AetherAffectedTest>,
+    /// Shell commands that run only the affected tests, one per runner
+    pub commands: Vec<String>,
+    pub notes: Vec<String>,
+}
+
+impl From<ChangedSymbol> for AetherChangedSymbol {
+    fn from(value: ChangedSymbol) -> Self {
+        Self {
+            symbol_id: value.symbol_id,
+            qualified_name: value.qualified_name,
+            file_path: value.file_path,
+            change: value.change.as_str().to_owned(),
+            covering_tests: value.covering_tests as u32,
+            uncovered: value.uncovered,
+        }
+    }
+}
+
+impl From<AffectedTest> for AetherAffectedTest {
+    fn from(value: AffectedTest) -> Self {
+        Self {
+            file_path: value.file_path,
+            test_name: value.test_name,
+            intent_text: value.intent_text,
+            runner: value.runner.as_str().to_owned(),
+            package: value.package,
+            selector: value.selector,
+            reason: value.reason.as_str().to_owned(),
+            depth: value.depth.map(|depth| depth as u32),
+            confidence: value.confidence,
+            triggered_by: value.triggered_by,
+        }
+    }
+}
+
+impl AetherMcpServer {
+    pub fn aether_affected_tests_logic(
+        &self,
+        graph: Option<Arc<SurrealGraphStore>>,
+        request: AetherAffectedTestsRequest,
+    ) -> Result<AetherAffectedTestsResponse, AetherMcpError> {
+        let analyzer = AffectedTestsAnalyzer::new(self.workspace())?;
+        let report = analyzer.analyze(
+            self.state.store.as_ref(),
+            graph.as_deref(),
+            &AffectedTestsRequest {
+                base_ref: request.base,
+                head_ref: request.head,
+                max_depth: request
+                    .depth
+                    .map(|depth| depth.clamp(1, 10) as usize)
+                    .unwrap_or(DEFAULT_AFFECTED_TESTS_MAX_DEPTH),
+            },
+        )?;
+
+        Ok(AetherAffectedTestsResponse {
+            base_commit: report.base_commit,
+            head_commit: report.head_commit,
+            changed_files: report.changed_files as u32,
+            changed_symbols: report.changed_symbols.into_iter().map(Into::into).collect(),
+            uncovered_symbols: report.uncovered_symbols as u32,
+            tests: report.tests.into_iter().map(Into::into).collect(),
+            commands: report
+                .commands
+                .into_iter()
+                .map(|command| command.command)
+                .collect(),
+            notes: report.notes,
+        })
+    }
+}
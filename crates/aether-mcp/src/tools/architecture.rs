@@ -0,0 +1,121 @@
+use aether_analysis::{
+    ArchitectureAnalyzer, ArchitectureDependencyRequest, ArchitectureRule, ArchitectureViolation,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::AetherMcpServer;
+use crate::AetherMcpError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherArchCheckRequest {
+    /// Source of a proposed dependency: symbol ID, qualified name or file path.
+    /// Omit source and target to check every indexed edge.
+    pub source: Option<String>,
+    /// Target of a proposed dependency: symbol ID, qualified name, file path or import path
+    pub target: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherArchViolation {
+    pub source_layer: String,
+    pub target_layer: String,
+    pub source_symbol: String,
+    pub target_symbol: String,
+    pub edge_kind: String,
+    pub file: String,
+    pub line: Option<usize>,
+    pub target_file: Option<String>,
+    /// "forbidden" or "not_allowed"
+    pub rule: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherArchVerdict {
+    pub allowed: bool,
+    pub source_layer: Option<String>,
+    pub target_layer: Option<String>,
+    pub rule: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherArchCheckResponse {
+    pub layers: Vec<String>,
+    /// Set when a proposed dependency was checked
+    pub verdict: Option<AetherArchVerdict>,
+    pub edges_checked: u32,
+    pub violations: Vec<AetherArchViolation>,
+}
+
+impl From<ArchitectureViolation> for AetherArchViolation {
+    fn from(value: ArchitectureViolation) -> Self {
+        Self {
+            source_layer: value.source_layer,
+            target_layer: value.target_layer,
+            source_symbol: value.source_symbol,
+            target_symbol: value.target_symbol,
+            edge_kind: value.edge_kind,
+            file: value.file,
+            line: value.line,
+            target_file: value.target_file,
+            rule: rule_name(value.rule).to_owned(),
+            message: value.message,
+        }
+    }
+}
+
+fn rule_name(rule: ArchitectureRule) -> &'static str {
+    match rule {
+        ArchitectureRule::Forbidden => "forbidden",
+        ArchitectureRule::NotAllowed => "not_allowed",
+    }
+}
+
+impl AetherMcpServer {
+    pub fn aether_arch_check_logic(
+        &self,
+        request: AetherArchCheckRequest,
+    ) -> Result<AetherArchCheckResponse, AetherMcpError> {
+        let analyzer = ArchitectureAnalyzer::new(self.workspace())?;
+        let store = self.state.store.as_ref();
+        let layers = analyzer
+            .spec()
+            .layers
+            .iter()
+            .map(|layer| layer.name.trim().to_owned())
+            .collect::<Vec<_>>();
+
+        match (request.source, request.target) {
+            (Some(source), Some(target)) => {
+                let verdict = analyzer
+                    .check_dependency(store, &ArchitectureDependencyRequest { source, target })?;
+                Ok(AetherArchCheckResponse {
+                    layers,
+                    verdict: Some(AetherArchVerdict {
+                        allowed: verdict.allowed,
+                        source_layer: verdict.source_layer,
+                        target_layer: verdict.target_layer,
+                        rule: verdict.rule.map(|rule| rule_name(rule).to_owned()),
+                        message: verdict.message,
+                    }),
+                    edges_checked: 0,
+                    violations: Vec::new(),
+                })
+            }
+            (None, None) => {
+                let result = analyzer.check(store)?;
+                Ok(AetherArchCheckResponse {
+                    layers,
+                    verdict: None,
+                    edges_checked: result.edges_checked,
+                    violations: result.violations.into_iter().map(Into::into).collect(),
+                })
+            }
+            _ => Err(AetherMcpError::Message(
+                "source and target must be given together".to_owned(),
+            )),
+        }
+    }
+}
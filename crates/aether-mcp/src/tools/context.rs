@@ -161,6 +161,8 @@ fn inverse_relation_label(kind: EdgeKind) -> String {
         EdgeKind::DependsOn => "depended_on_by".to_owned(),
         EdgeKind::TypeRef => "type_ref_by".to_owned(),
         EdgeKind::Implements => "implemented_by".to_owned(),
+        EdgeKind::Endpoint => "endpoint_called_by".to_owned(),
+        EdgeKind::References => "referenced_by".to_owned(),
     }
 }
 
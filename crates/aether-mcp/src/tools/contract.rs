@@ -1,11 +1,16 @@
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
 use aether_infer::{EmbeddingProviderOverrides, load_embedding_provider_from_config};
 use aether_store::{
-    IntentContractRecord, IntentViolationRecord, SirStateStore, SqliteStore, SymbolCatalogStore,
+    IntentContractRecord, IntentViolationRecord, POLICY_CONTRACT_CREATOR_PREFIX,
+    SOURCE_CONTRACT_CREATOR, SirStateStore, SqliteStore, SurrealGraphStore, SymbolCatalogStore,
     SymbolRecord,
 };
-use aetherd::contracts::{ClauseStatus, ContractVerifier};
+use aetherd::contracts::{
+    CONTRACT_POLICY_PATH, ClauseStatus, ContractVerifier, GraphEvidence, StructuralClause,
+    is_structural_clause_type,
+};
 use rusqlite::{Connection, OpenFlags, OptionalExtension, params};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -23,14 +28,17 @@ const NOTE_INVALID_CLAUSE_EMBEDDING: &str =
 const NOTE_NO_EMBEDDING: &str = "No embedding available for comparison.";
 const NOTE_NO_SIR: &str = "No SIR available for comparison.";
 const NOTE_INCOMPATIBLE_EMBEDDING: &str = "Stored embeddings are incompatible for comparison.";
+const NOTE_SYMBOL_NOT_INDEXED: &str = "Symbol is not indexed in the graph.";
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AetherContractAddRequest {
     /// Symbol ID or qualified name
     pub symbol: String,
-    /// Clause type: "must", "must_not", or "preserves"
+    /// Clause type: "must", "must_not", or "preserves", or a structural
+    /// graph predicate: "must_call", "must_not_reach", or "must_be_tested"
     pub clause_type: String,
-    /// Clause text describing the behavioral expectation
+    /// Clause text describing the behavioral expectation. Structural clauses
+    /// take "<qualified name> [within N hops]" or "at least N tests".
     pub clause_text: String,
     /// Who is creating this contract (default "claude_code")
     pub created_by: Option<String>,
@@ -185,8 +193,10 @@ fn validate_clause_type(value: &str) -> Result<String, AetherMcpError> {
     let normalized = value.trim().to_ascii_lowercase();
     match normalized.as_str() {
         "must" | "must_not" | "preserves" => Ok(normalized),
+        _ if is_structural_clause_type(normalized.as_str()) => Ok(normalized),
         _ => Err(AetherMcpError::Message(format!(
-            "clause_type must be one of: must, must_not, preserves (got '{}')",
+            "clause_type must be one of: must, must_not, preserves, must_call, \
+             must_not_reach, must_be_tested (got '{}')",
             value.trim()
         ))),
     }
@@ -428,13 +438,29 @@ impl AetherMcpServer {
         let store = self.state.store.as_ref();
         let symbol = resolve_symbol_selector(store, request.symbol.as_str())?;
         let clause_type = validate_clause_type(request.clause_type.as_str())?;
-        let clause_text = request.clause_text.trim();
+        let structural = StructuralClause::parse(clause_type.as_str(), &request.clause_text)
+            .map_err(|err| AetherMcpError::Message(format!("invalid {clause_type} clause: {err}")))?
+            .map(|clause| clause.clause_text());
+        let clause_text = structural
+            .as_deref()
+            .unwrap_or_else(|| request.clause_text.trim());
         if clause_text.is_empty() {
             return Err(AetherMcpError::Message(
                 "clause_text must not be empty".to_owned(),
             ));
         }
+        let embedding_json = embedding_json.filter(|_| structural.is_none());
         let created_by = normalize_optional_text(request.created_by, CLAUDE_CODE_CREATOR);
+        if created_by == SOURCE_CONTRACT_CREATOR {
+            return Err(AetherMcpError::Message(
+                "source-declared contracts come from @aether annotations in code".to_owned(),
+            ));
+        }
+        if created_by.starts_with(POLICY_CONTRACT_CREATOR_PREFIX) {
+            return Err(AetherMcpError::Message(format!(
+                "policy contracts are materialized from {CONTRACT_POLICY_PATH}"
+            )));
+        }
 
         let contract_id = store.insert_intent_contract(
             symbol.id.as_str(),
@@ -497,11 +523,23 @@ impl AetherMcpServer {
         self.state.require_writable()?;
 
         let store = self.state.store.as_ref();
-        store
+        let contract = store
             .get_intent_contract(request.contract_id)?
             .ok_or_else(|| {
                 AetherMcpError::Message(format!("contract #{} not found", request.contract_id))
             })?;
+        if let Some(policy) = contract.policy_name() {
+            return Err(AetherMcpError::Message(format!(
+                "contract #{} comes from policy '{policy}'; edit {CONTRACT_POLICY_PATH} instead",
+                request.contract_id
+            )));
+        }
+        if contract.created_by == SOURCE_CONTRACT_CREATOR {
+            return Err(AetherMcpError::Message(format!(
+                "contract #{} is declared in source; remove its @aether annotation instead",
+                request.contract_id
+            )));
+        }
         store.deactivate_contract(request.contract_id)?;
 
         Ok(AetherContractRemoveResponse {
@@ -513,6 +551,14 @@ impl AetherMcpServer {
     pub fn aether_contract_check_logic(
         &self,
         request: AetherContractCheckRequest,
+    ) -> Result<AetherContractCheckResponse, AetherMcpError> {
+        self.aether_contract_check_logic_with_graph(None, request)
+    }
+
+    pub fn aether_contract_check_logic_with_graph(
+        &self,
+        graph: Option<Arc<SurrealGraphStore>>,
+        request: AetherContractCheckRequest,
     ) -> Result<AetherContractCheckResponse, AetherMcpError> {
         let store = self.state.store.as_ref();
         let contracts = if let Some(symbol) = request.symbol.as_deref() {
@@ -547,6 +593,7 @@ impl AetherMcpServer {
 
         let symbol_ids = grouped.keys().cloned().collect::<Vec<_>>();
         let qualified_names = qualified_names_by_symbol_id(store, &symbol_ids)?;
+        let evidence = GraphEvidence::new(store, Arc::clone(&self.state.graph), graph);
 
         let mut symbols_checked = Vec::new();
         let mut total_clauses = 0_u32;
@@ -566,6 +613,43 @@ impl AetherMcpServer {
             let mut ambiguous = 0_u32;
 
             for contract in contracts {
+                if is_structural_clause_type(contract.clause_type.as_str()) {
+                    let (status, note) = match (
+                        StructuralClause::parse(&contract.clause_type, &contract.clause_text),
+                        store.get_symbol_record(symbol_id.as_str())?,
+                    ) {
+                        (Ok(Some(clause)), Some(symbol)) => {
+                            match clause.evaluate(&symbol, &evidence) {
+                                Ok(outcome) => (outcome.status, outcome.reason),
+                                Err(err) => (
+                                    ClauseStatus::Ambiguous,
+                                    format!("graph query failed: {err}"),
+                                ),
+                            }
+                        }
+                        (Err(err), _) => (
+                            ClauseStatus::Ambiguous,
+                            format!("invalid structural clause: {err}"),
+                        ),
+                        _ => (ClauseStatus::Ambiguous, NOTE_SYMBOL_NOT_INDEXED.to_owned()),
+                    };
+                    match status {
+                        ClauseStatus::Pass => passed += 1,
+                        ClauseStatus::Fail => failed += 1,
+                        ClauseStatus::Ambiguous => ambiguous += 1,
+                    }
+                    clause_results.push(ClauseResultOutput {
+                        contract_id: contract.id,
+                        clause_type: contract.clause_type,
+                        clause_text: contract.clause_text,
+                        status: clause_status_label(&status).to_owned(),
+                        similarity: None,
+                        judge_reason: None,
+                        note: Some(note),
+                    });
+                    continue;
+                }
+
                 let mut invalid_clause_embedding = false;
                 let clause_embedding = match contract.clause_embedding_json.as_deref() {
                     Some(json) => match serde_json::from_str::<Vec<f32>>(json) {
@@ -735,9 +819,10 @@ mod tests {
         CLAUDE_CODE_CREATOR, NOTE_ACTIVE_ONLY, NOTE_INVALID_CLAUSE_EMBEDDING, NOTE_NO_EMBEDDING,
     };
     use crate::AetherMcpServer;
+    use aether_core::{EdgeKind, SymbolEdge};
     use aether_store::{
-        SemanticIndexStore, SirStateStore, SqliteStore, SymbolCatalogStore, SymbolEmbeddingRecord,
-        SymbolRecord,
+        POLICY_CONTRACT_CREATOR_PREFIX, SOURCE_CONTRACT_CREATOR, SemanticIndexStore, SirStateStore,
+        SqliteStore, SymbolCatalogStore, SymbolEmbeddingRecord, SymbolRecord, SymbolRelationStore,
     };
     use tempfile::tempdir;
 
@@ -915,6 +1000,46 @@ model = "{embedding_model}"
         assert!(list.contracts.is_empty());
     }
 
+    #[test]
+    fn contract_remove_refuses_source_and_policy_contracts() {
+        let temp = tempdir().expect("tempdir");
+        write_test_config(temp.path(), false, "mock-64d");
+        seed_symbol(temp.path(), "sym-contract", "crate::payments::process");
+        let store = SqliteStore::open(temp.path()).expect("open store");
+        let source = store
+            .insert_intent_contract(
+                "sym-contract",
+                "must",
+                "reject zero amounts",
+                None,
+                SOURCE_CONTRACT_CREATOR,
+            )
+            .expect("insert source contract");
+        let policy = store
+            .insert_intent_contract(
+                "sym-contract",
+                "must",
+                "write an audit record",
+                None,
+                &format!("{POLICY_CONTRACT_CREATOR_PREFIX}payments-audit"),
+            )
+            .expect("insert policy contract");
+        let server = AetherMcpServer::new(temp.path(), false).expect("server");
+
+        let err = server
+            .aether_contract_remove_logic(AetherContractRemoveRequest {
+                contract_id: source,
+            })
+            .expect_err("source contracts are owned by annotations");
+        assert!(err.to_string().contains("@aether annotation"));
+        let err = server
+            .aether_contract_remove_logic(AetherContractRemoveRequest {
+                contract_id: policy,
+            })
+            .expect_err("policy contracts are owned by the policy file");
+        assert!(err.to_string().contains("payments-audit"));
+    }
+
     #[test]
     fn contract_check_passes_similar_sir() {
         let temp = tempdir().expect("tempdir");
@@ -1041,6 +1166,55 @@ model = "{embedding_model}"
         );
     }
 
+    #[test]
+    fn contract_check_evaluates_structural_clauses_against_graph() {
+        let temp = tempdir().expect("tempdir");
+        write_test_config(temp.path(), false, "mock-64d");
+        seed_symbol(temp.path(), "sym-structural", "crate::payments::charge");
+        SqliteStore::open(temp.path())
+            .expect("open store")
+            .upsert_edges(&[SymbolEdge {
+                source_id: "sym-structural".to_owned(),
+                target_qualified_name: "audit::log".to_owned(),
+                edge_kind: EdgeKind::Calls,
+                file_path: "src/lib.rs".to_owned(),
+            }])
+            .expect("upsert edge");
+        let server = AetherMcpServer::new(temp.path(), false).expect("server");
+
+        for (clause_type, clause_text) in [
+            ("must_call", "audit::log"),
+            ("must_not_reach", "std::process::exit"),
+            ("must_be_tested", ""),
+        ] {
+            let add = server
+                .aether_contract_add_logic(
+                    AetherContractAddRequest {
+                        symbol: "sym-structural".to_owned(),
+                        clause_type: clause_type.to_owned(),
+                        clause_text: clause_text.to_owned(),
+                        created_by: None,
+                    },
+                    Some("[0.1,0.2]".to_owned()),
+                )
+                .expect("add structural contract");
+            assert!(!add.has_embedding);
+        }
+
+        let response = server
+            .aether_contract_check_logic(AetherContractCheckRequest {
+                symbol: Some("sym-structural".to_owned()),
+            })
+            .expect("check contracts");
+        let results = &response.symbols_checked[0].clause_results;
+        assert_eq!(results[0].clause_text, "audit::log within 1 hop");
+        assert_eq!(results[0].status, "pass");
+        assert_eq!(results[1].status, "pass");
+        assert_eq!(results[2].clause_text, "at least 1 test");
+        assert_eq!(results[2].status, "ambiguous");
+        assert!(results.iter().all(|result| result.similarity.is_none()));
+    }
+
     #[test]
     fn contract_violations_returns_history() {
         let temp = tempdir().expect("tempdir");
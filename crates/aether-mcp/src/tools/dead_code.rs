@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+
+use aether_analysis::{DeadCodeAnalyzer, DeadCodeConfidence, DeadCodeRequest, UnreachableSymbol};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{AetherMcpServer, effective_limit};
+use crate::AetherMcpError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherDeadCodeRequest {
+    /// Lowest confidence to report: "low" (default), "medium" or "high"
+    pub min_confidence: Option<String>,
+    /// Only report symbols under this workspace-relative path
+    pub path: Option<String>,
+    /// Maximum symbols to return (default 20, max 100)
+    pub limit: Option<u32>,
+    /// Treat the public API as an entry point (defaults to `[dead_code] public_api_roots`)
+    pub public_api_roots: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherUnreachableSymbol {
+    pub symbol_id: String,
+    pub qualified_name: String,
+    pub file_path: String,
+    pub language: String,
+    pub kind: String,
+    /// "high", "medium" or "low"
+    pub confidence: String,
+    pub reasons: Vec<String>,
+    pub dead_referrers: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherDeadCodeResponse {
+    pub total_symbols: u32,
+    pub reachable_symbols: u32,
+    /// Entry-point count per root kind
+    pub roots: BTreeMap<String, u32>,
+    /// Unreachable symbols per confidence level, before the limit
+    pub confidence_counts: BTreeMap<String, u32>,
+    pub unreachable: Vec<AetherUnreachableSymbol>,
+    pub notes: Vec<String>,
+}
+
+impl From<UnreachableSymbol> for AetherUnreachableSymbol {
+    fn from(value: UnreachableSymbol) -> Self {
+        Self {
+            symbol_id: value.symbol_id,
+            qualified_name: value.qualified_name,
+            file_path: value.file_path,
+            language: value.language,
+            kind: value.kind,
+            confidence: value.confidence.as_str().to_owned(),
+            reasons: value.reasons,
+            dead_referrers: value.dead_referrers as u32,
+        }
+    }
+}
+
+impl AetherMcpServer {
+    pub fn aether_dead_code_logic(
+        &self,
+        request: AetherDeadCodeRequest,
+    ) -> Result<AetherDeadCodeResponse, AetherMcpError> {
+        let min_confidence = match request.min_confidence.as_deref() {
+            Some(value) => value
+                .parse::<DeadCodeConfidence>()
+                .map_err(AetherMcpError::Message)?,
+            None => DeadCodeConfidence::Low,
+        };
+        let analyzer = DeadCodeAnalyzer::new(self.workspace())?;
+        let report = analyzer.analyze(
+            self.state.store.as_ref(),
+            &DeadCodeRequest {
+                min_confidence,
+                path_prefix: request.path,
+                limit: Some(effective_limit(request.limit) as usize),
+                public_api_roots: request.public_api_roots,
+            },
+        )?;
+        let counts = |values: BTreeMap<String, usize>| {
+            values
+                .into_iter()
+                .map(|(key, count)| (key, count as u32))
+                .collect()
+        };
+
+        Ok(AetherDeadCodeResponse {
+            total_symbols: report.total_symbols as u32,
+            reachable_symbols: report.reachable_symbols as u32,
+            roots: counts(report.roots),
+            confidence_counts: counts(report.confidence_counts),
+            unreachable: report.unreachable.into_iter().map(Into::into).collect(),
+            notes: report.notes,
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use aether_analysis::{
+    GraphQueryPath, GraphQueryPathNode, MAX_GRAPH_QUERY_LIMIT, evaluate_graph_query,
+    parse_graph_query,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::AetherMcpServer;
+use crate::AetherMcpError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherGraphQueryRequest {
+    /// Path query: `from <pred> to <pred>` plus optional `via <kinds>`,
+    /// `hops N|N..M`, `through <pred>`, `avoid <pred>` and `limit N`.
+    /// Predicates compare id, name, kind, path, language, intent, side_effects
+    /// or error_modes with = / != (glob) or ~ / !~ (substring), joined by and/or
+    pub query: String,
+    /// Maximum paths to return, overriding the query's `limit` (clamped to 1..200)
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherGraphQueryNode {
+    pub symbol_id: String,
+    pub qualified_name: String,
+    pub kind: String,
+    pub file_path: String,
+    /// SIR intent, when the symbol has one
+    pub intent: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherGraphQueryPath {
+    pub nodes: Vec<AetherGraphQueryNode>,
+    /// Edge kinds between consecutive nodes, `|`-joined when there are several
+    pub edge_kinds: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherGraphQueryResponse {
+    pub schema_version: String,
+    /// Shortest paths first
+    pub paths: Vec<AetherGraphQueryPath>,
+    /// Paths found before the limit was applied
+    pub total_paths: u32,
+    pub notes: Vec<String>,
+}
+
+impl From<GraphQueryPathNode> for AetherGraphQueryNode {
+    fn from(value: GraphQueryPathNode) -> Self {
+        Self {
+            symbol_id: value.symbol_id,
+            qualified_name: value.qualified_name,
+            kind: value.kind,
+            file_path: value.file_path,
+            intent: value.intent,
+        }
+    }
+}
+
+impl From<GraphQueryPath> for AetherGraphQueryPath {
+    fn from(value: GraphQueryPath) -> Self {
+        Self {
+            nodes: value.nodes.into_iter().map(Into::into).collect(),
+            edge_kinds: value.edge_kinds,
+        }
+    }
+}
+
+impl AetherMcpServer {
+    pub fn aether_graph_query_logic(
+        &self,
+        request: AetherGraphQueryRequest,
+    ) -> Result<AetherGraphQueryResponse, AetherMcpError> {
+        let mut query = parse_graph_query(&request.query)?;
+        if let Some(limit) = request.limit {
+            query.limit = (limit as usize).clamp(1, MAX_GRAPH_QUERY_LIMIT);
+        }
+        let result =
+            evaluate_graph_query(self.state.store.as_ref(), self.state.graph.as_ref(), &query)?;
+
+        Ok(AetherGraphQueryResponse {
+            schema_version: result.schema_version,
+            paths: result.paths.into_iter().map(Into::into).collect(),
+            total_paths: result.total_paths as u32,
+            notes: result.notes,
+        })
+    }
+}
@@ -34,6 +34,7 @@ pub enum AetherHealthInclude {
     Cycles,
     Orphans,
     RiskHotspots,
+    ChokePoints,
 }
 
 impl From<AetherHealthInclude> for aether_analysis::HealthInclude {
@@ -44,6 +45,7 @@ impl From<AetherHealthInclude> for aether_analysis::HealthInclude {
             AetherHealthInclude::Cycles => aether_analysis::HealthInclude::Cycles,
             AetherHealthInclude::Orphans => aether_analysis::HealthInclude::Orphans,
             AetherHealthInclude::RiskHotspots => aether_analysis::HealthInclude::RiskHotspots,
+            AetherHealthInclude::ChokePoints => aether_analysis::HealthInclude::ChokePoints,
         }
     }
 }
@@ -139,6 +141,37 @@ pub struct AetherRiskHotspotEntry {
     pub risk_factors: Vec<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherChokePointEntry {
+    pub symbol_id: String,
+    pub symbol_name: String,
+    pub file: String,
+    /// Symbols that entry points reach only through this one
+    pub dominated_symbols: u32,
+    /// Symbols cut off from the rest of the graph without this one
+    pub disconnected_symbols: u32,
+    /// k-core number of the symbol
+    pub core_number: u32,
+    /// Links to this symbol that alone connect two regions
+    pub bridges: u32,
+    pub note: String,
+}
+
+impl From<aether_analysis::ChokePointEntry> for AetherChokePointEntry {
+    fn from(value: aether_analysis::ChokePointEntry) -> Self {
+        Self {
+            symbol_id: value.symbol_id,
+            symbol_name: value.symbol_name,
+            file: value.file,
+            dominated_symbols: value.dominated_symbols,
+            disconnected_symbols: value.disconnected_symbols,
+            core_number: value.core_number,
+            bridges: value.bridges,
+            note: value.note,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AetherHealthResponse {
     pub schema_version: String,
@@ -148,6 +181,7 @@ pub struct AetherHealthResponse {
     pub cycles: Vec<AetherCycleEntry>,
     pub orphans: Vec<AetherOrphanEntry>,
     pub risk_hotspots: Vec<AetherRiskHotspotEntry>,
+    pub choke_points: Vec<AetherChokePointEntry>,
     pub notes: Vec<String>,
 }
 
@@ -266,6 +300,7 @@ impl AetherMcpServer {
                     risk_factors: entry.risk_factors,
                 })
                 .collect(),
+            choke_points: report.choke_points.into_iter().map(Into::into).collect(),
             notes: report.notes,
         })
     }
@@ -804,6 +839,12 @@ fn parse_symbol_kind(raw: &str) -> SymbolKind {
         "trait" => SymbolKind::Trait,
         "interface" => SymbolKind::Interface,
         "type_alias" => SymbolKind::TypeAlias,
+        "constant" => SymbolKind::Constant,
+        "static" => SymbolKind::Static,
+        "macro" => SymbolKind::Macro,
+        "module" => SymbolKind::Module,
+        "field" => SymbolKind::Field,
+        "enum_variant" => SymbolKind::EnumVariant,
         _ => SymbolKind::Function,
     }
 }
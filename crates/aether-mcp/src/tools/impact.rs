@@ -5,7 +5,7 @@ use std::path::Path;
 
 use aether_analysis::{
     AnalysisError, BlastRadiusRequest, BlastRadiusResult, CouplingAnalyzer,
-    RiskLevel as CouplingRiskLevel, TestIntentAnalyzer,
+    RiskLevel as CouplingRiskLevel, SuggestedReviewer, TestIntentAnalyzer,
 };
 use aether_core::normalize_path;
 use aether_store::{
@@ -15,7 +15,9 @@ use aether_store::{
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::{AetherMcpServer, MEMORY_SCHEMA_VERSION, current_unix_timestamp_millis};
+use super::{
+    AetherChokePointEntry, AetherMcpServer, MEMORY_SCHEMA_VERSION, current_unix_timestamp_millis,
+};
 use crate::AetherMcpError;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -88,6 +90,19 @@ pub struct AetherBlastRadiusResponse {
     pub mining_state: Option<AetherBlastRadiusMiningState>,
     pub coupled_files: Vec<AetherBlastRadiusCoupledFile>,
     pub test_guards: Vec<AetherBlastRadiusTestGuard>,
+    /// Owners of the coupled files to ask for review
+    pub suggested_reviewers: Vec<AetherSuggestedReviewer>,
+    /// Symbols in the target file whose failure cuts off a region of the graph
+    pub choke_points: Vec<AetherChokePointEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherSuggestedReviewer {
+    pub reviewer: String,
+    /// "codeowners" or "blame"
+    pub source: String,
+    pub score: f64,
+    pub files: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -124,6 +139,17 @@ pub struct AetherTestIntentsResponse {
     pub intents: Vec<AetherTestIntentEntry>,
 }
 
+impl From<SuggestedReviewer> for AetherSuggestedReviewer {
+    fn from(value: SuggestedReviewer) -> Self {
+        Self {
+            reviewer: value.reviewer,
+            source: value.source.as_str().to_owned(),
+            score: value.score,
+            files: value.files,
+        }
+    }
+}
+
 impl AetherMcpServer {
     pub fn aether_blast_radius_logic(
         &self,
@@ -145,6 +171,8 @@ impl AetherMcpServer {
                 mining_state: None,
                 coupled_files: Vec::new(),
                 test_guards: Vec::new(),
+                suggested_reviewers: Vec::new(),
+                choke_points: Vec::new(),
             });
         }
 
@@ -176,11 +204,13 @@ impl AetherMcpServer {
                     "configured graph backend 'sqlite' does not support Surreal graph operations",
                 ) =>
             {
+                let store = SqliteStore::open(self.workspace())?;
                 BlastRadiusResult {
                     target_file: target_file.clone(),
-                    mining_state: SqliteStore::open(self.workspace())?
-                        .get_coupling_mining_state()?,
+                    mining_state: store.get_coupling_mining_state()?,
                     coupled_files: Vec::new(),
+                    suggested_reviewers: Vec::new(),
+                    choke_points: analyzer.choke_points_for_file(&store, &target_file)?,
                 }
             }
             Err(err) => return Err(err.into()),
@@ -269,6 +299,12 @@ impl AetherMcpServer {
             mining_state,
             coupled_files,
             test_guards,
+            suggested_reviewers: blast
+                .suggested_reviewers
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            choke_points: blast.choke_points.into_iter().map(Into::into).collect(),
         })
     }
 
@@ -12,42 +12,54 @@ use rmcp::transport::stdio;
 use crate::AetherMcpError;
 use crate::state::SharedState;
 
+mod affected_tests;
+mod architecture;
 mod audit;
 mod common;
 mod context;
 mod contract;
 mod cross_symbol;
+mod dead_code;
 mod drift;
 mod enhance;
+mod graph_query;
 mod health;
 mod history;
 mod impact;
 mod memory;
+mod ownership;
 mod refactor;
 mod router;
 mod search;
 mod sir;
 mod sir_inject;
+mod stack_trace;
 mod status;
 mod trait_split;
 mod usage_matrix;
 #[cfg(feature = "verification")]
 mod verification;
 
+pub use affected_tests::*;
+pub use architecture::*;
 pub use audit::*;
 pub use context::*;
 pub use contract::*;
 pub use cross_symbol::*;
+pub use dead_code::*;
 pub use drift::*;
 pub use enhance::*;
+pub use graph_query::*;
 pub use health::*;
 pub use history::*;
 pub use impact::*;
 pub use memory::*;
+pub use ownership::*;
 pub use refactor::*;
 pub use search::*;
 pub use sir::*;
 pub use sir_inject::*;
+pub use stack_trace::*;
 pub use status::*;
 pub use trait_split::*;
 pub use usage_matrix::*;
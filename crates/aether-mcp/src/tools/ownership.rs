@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use aether_analysis::{
+    AuthorShare, CommunityOwnership, OwnershipAnalyzer, OwnershipRequest, SymbolOwnership,
+};
+use aether_store::SurrealGraphStore;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::AetherMcpServer;
+use crate::AetherMcpError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherOwnershipRequest {
+    /// Report the symbols of this workspace-relative file
+    pub file: Option<String>,
+    /// Report one symbol, by id or qualified name
+    pub symbol: Option<String>,
+    /// Communities to list when no file or symbol is given, lowest bus factor first (default 20)
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherAuthorShare {
+    pub author: String,
+    /// Blamed lines last changed by this author
+    pub lines: u32,
+    /// Share of the recency-weighted lines
+    pub share: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherSymbolOwnership {
+    pub symbol_id: String,
+    pub qualified_name: String,
+    pub file_path: String,
+    pub start_line: Option<u32>,
+    pub end_line: Option<u32>,
+    pub codeowners: Vec<String>,
+    pub authors: Vec<AetherAuthorShare>,
+    /// Fewest authors owning half of the weighted lines; 0 when unknown
+    pub bus_factor: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherCommunityOwnership {
+    pub community_id: i64,
+    pub symbol_count: u32,
+    pub file_count: u32,
+    pub codeowners: Vec<String>,
+    pub authors: Vec<AetherAuthorShare>,
+    pub bus_factor: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherOwnershipResponse {
+    pub schema_version: String,
+    pub codeowners_file: Option<String>,
+    pub symbols: Vec<AetherSymbolOwnership>,
+    pub communities: Vec<AetherCommunityOwnership>,
+    pub notes: Vec<String>,
+}
+
+impl From<AuthorShare> for AetherAuthorShare {
+    fn from(value: AuthorShare) -> Self {
+        Self {
+            author: value.author,
+            lines: value.lines as u32,
+            share: value.share,
+        }
+    }
+}
+
+impl From<SymbolOwnership> for AetherSymbolOwnership {
+    fn from(value: SymbolOwnership) -> Self {
+        Self {
+            symbol_id: value.symbol_id,
+            qualified_name: value.qualified_name,
+            file_path: value.file_path,
+            start_line: value.start_line.map(|line| line as u32),
+            end_line: value.end_line.map(|line| line as u32),
+            codeowners: value.codeowners,
+            authors: value.authors.into_iter().map(Into::into).collect(),
+            bus_factor: value.bus_factor as u32,
+        }
+    }
+}
+
+impl From<CommunityOwnership> for AetherCommunityOwnership {
+    fn from(value: CommunityOwnership) -> Self {
+        Self {
+            community_id: value.community_id,
+            symbol_count: value.symbol_count as u32,
+            file_count: value.file_count as u32,
+            codeowners: value.codeowners,
+            authors: value.authors.into_iter().map(Into::into).collect(),
+            bus_factor: value.bus_factor as u32,
+        }
+    }
+}
+
+impl AetherMcpServer {
+    pub fn aether_ownership_logic_with_graph(
+        &self,
+        graph: Option<Arc<SurrealGraphStore>>,
+        request: AetherOwnershipRequest,
+    ) -> Result<AetherOwnershipResponse, AetherMcpError> {
+        let analyzer = OwnershipAnalyzer::new(self.workspace())?;
+        let report = analyzer.report(
+            self.state.store.as_ref(),
+            graph.as_deref(),
+            &OwnershipRequest {
+                file: request
+                    .file
+                    .map(|file| file.trim().to_owned())
+                    .filter(|file| !file.is_empty()),
+                symbol: request
+                    .symbol
+                    .map(|symbol| symbol.trim().to_owned())
+                    .filter(|symbol| !symbol.is_empty()),
+                limit: request.limit.map(|limit| limit.clamp(1, 200) as usize),
+            },
+        )?;
+
+        Ok(AetherOwnershipResponse {
+            schema_version: report.schema_version,
+            codeowners_file: report.codeowners_file,
+            symbols: report.symbols.into_iter().map(Into::into).collect(),
+            communities: report.communities.into_iter().map(Into::into).collect(),
+            notes: report.notes,
+        })
+    }
+}
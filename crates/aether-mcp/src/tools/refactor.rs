@@ -593,6 +593,7 @@ fn build_sir_context(
         kind: symbol.kind.as_str().to_owned(),
         is_public: infer_symbol_text_is_public(symbol_text),
         line_count: symbol_text.lines().count(),
+        extension_fields: Vec::new(),
     }
 }
 
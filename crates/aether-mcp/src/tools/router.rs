@@ -1,9 +1,11 @@
+use aetherd::contracts::is_structural_clause_type;
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::{Implementation, ServerCapabilities, ServerInfo};
 use rmcp::{ErrorData as McpError, Json, ServerHandler, tool, tool_handler, tool_router};
 
 use super::{
-    AetherAcknowledgeDriftRequest, AetherAcknowledgeDriftResponse, AetherAskRequest,
+    AetherAcknowledgeDriftRequest, AetherAcknowledgeDriftResponse, AetherAffectedTestsRequest,
+    AetherAffectedTestsResponse, AetherArchCheckRequest, AetherArchCheckResponse, AetherAskRequest,
     AetherAskResponse, AetherAuditCandidatesRequest, AetherAuditCandidatesResponse,
     AetherAuditCrossSymbolRequest, AetherAuditCrossSymbolResponse, AetherAuditReportRequest,
     AetherAuditReportResponse, AetherAuditResolveRequest, AetherAuditResolveResponse,
@@ -13,22 +15,24 @@ use super::{
     AetherContractCheckResponse, AetherContractDismissRequest, AetherContractDismissResponse,
     AetherContractListRequest, AetherContractListResponse, AetherContractRemoveRequest,
     AetherContractRemoveResponse, AetherContractViolationsRequest,
-    AetherContractViolationsResponse, AetherDependenciesRequest, AetherDependenciesResponse,
-    AetherDriftReportRequest, AetherDriftReportResponse, AetherEnhancePromptRequest,
-    AetherEnhancePromptResponse, AetherExplainRequest, AetherExplainResponse, AetherGetSirRequest,
-    AetherGetSirResponse, AetherHealthExplainRequest, AetherHealthHotspotsRequest,
-    AetherHealthRequest, AetherHealthResponse, AetherMcpServer, AetherRecallRequest,
-    AetherRecallResponse, AetherRefactorPrepRequest, AetherRefactorPrepResponse,
-    AetherRememberRequest, AetherRememberResponse, AetherSearchRequest, AetherSearchResponse,
-    AetherSessionNoteResponse, AetherSirContextRequest, AetherSirContextResponse,
-    AetherSirInjectRequest, AetherSirInjectResponse, AetherStatusResponse,
-    AetherSuggestTraitSplitRequest, AetherSuggestTraitSplitResponse, AetherSymbolLookupRequest,
-    AetherSymbolLookupResponse, AetherSymbolTimelineRequest, AetherSymbolTimelineResponse,
-    AetherTestIntentsRequest, AetherTestIntentsResponse, AetherTextResponse,
-    AetherTraceCauseRequest, AetherTraceCauseResponse, AetherUsageMatrixRequest,
-    AetherUsageMatrixResponse, AetherVerifyIntentRequest, AetherVerifyIntentResponse,
-    AetherWhyChangedRequest, AetherWhyChangedResponse, SERVER_DESCRIPTION, SERVER_NAME,
-    SERVER_VERSION,
+    AetherContractViolationsResponse, AetherDeadCodeRequest, AetherDeadCodeResponse,
+    AetherDependenciesRequest, AetherDependenciesResponse, AetherDriftReportRequest,
+    AetherDriftReportResponse, AetherEnhancePromptRequest, AetherEnhancePromptResponse,
+    AetherExplainRequest, AetherExplainResponse, AetherExplainTraceRequest,
+    AetherExplainTraceResponse, AetherGetSirRequest, AetherGetSirResponse, AetherGraphQueryRequest,
+    AetherGraphQueryResponse, AetherHealthExplainRequest, AetherHealthHotspotsRequest,
+    AetherHealthRequest, AetherHealthResponse, AetherMcpServer, AetherOwnershipRequest,
+    AetherOwnershipResponse, AetherRecallRequest, AetherRecallResponse, AetherRefactorPrepRequest,
+    AetherRefactorPrepResponse, AetherRememberRequest, AetherRememberResponse, AetherSearchRequest,
+    AetherSearchResponse, AetherSessionNoteResponse, AetherSirContextRequest,
+    AetherSirContextResponse, AetherSirInjectRequest, AetherSirInjectResponse,
+    AetherStatusResponse, AetherSuggestTraitSplitRequest, AetherSuggestTraitSplitResponse,
+    AetherSymbolLookupRequest, AetherSymbolLookupResponse, AetherSymbolTimelineRequest,
+    AetherSymbolTimelineResponse, AetherTestIntentsRequest, AetherTestIntentsResponse,
+    AetherTextResponse, AetherTraceCauseRequest, AetherTraceCauseResponse,
+    AetherUsageMatrixRequest, AetherUsageMatrixResponse, AetherVerifyIntentRequest,
+    AetherVerifyIntentResponse, AetherWhyChangedRequest, AetherWhyChangedResponse,
+    SERVER_DESCRIPTION, SERVER_NAME, SERVER_VERSION,
 };
 #[cfg(feature = "verification")]
 use super::{AetherVerifyRequest, AetherVerifyResponse};
@@ -311,7 +315,7 @@ impl AetherMcpServer {
 
     #[tool(
         name = "aether_contract_add",
-        description = "Add a behavioral contract (must/must_not/preserves) on a symbol"
+        description = "Add a behavioral contract (must/must_not/preserves) or a structural graph contract (must_call/must_not_reach/must_be_tested) on a symbol"
     )]
     pub async fn aether_contract_add(
         &self,
@@ -319,9 +323,12 @@ impl AetherMcpServer {
     ) -> Result<Json<AetherContractAddResponse>, McpError> {
         self.verbose_log("MCP tool called: aether_contract_add");
         self.state.require_writable().map_err(to_mcp_error)?;
-        let embedding_json = self
-            .maybe_embed_contract_clause(request.clause_text.as_str())
-            .await;
+        let embedding_json = if is_structural_clause_type(request.clause_type.trim()) {
+            None
+        } else {
+            self.maybe_embed_contract_clause(request.clause_text.as_str())
+                .await
+        };
         let server = self.clone();
         tokio::task::spawn_blocking(move || {
             server.aether_contract_add_logic(request, embedding_json)
@@ -368,19 +375,22 @@ impl AetherMcpServer {
 
     #[tool(
         name = "aether_contract_check",
-        description = "Verify intent contracts against current SIR using embedding similarity"
+        description = "Verify intent contracts against current SIR using embedding similarity, and structural contracts exactly against the graph"
     )]
     pub async fn aether_contract_check(
         &self,
         Parameters(request): Parameters<AetherContractCheckRequest>,
     ) -> Result<Json<AetherContractCheckResponse>, McpError> {
         self.verbose_log("MCP tool called: aether_contract_check");
+        let graph = self.state.surreal_graph().await.ok();
         let server = self.clone();
-        tokio::task::spawn_blocking(move || server.aether_contract_check_logic(request))
-            .await
-            .map_err(|err| McpError::internal_error(err.to_string(), None))?
-            .map(Json)
-            .map_err(to_mcp_error)
+        tokio::task::spawn_blocking(move || {
+            server.aether_contract_check_logic_with_graph(graph, request)
+        })
+        .await
+        .map_err(|err| McpError::internal_error(err.to_string(), None))?
+        .map(Json)
+        .map_err(to_mcp_error)
     }
 
     #[tool(
@@ -471,6 +481,43 @@ impl AetherMcpServer {
         .map_err(to_mcp_error)
     }
 
+    #[tool(
+        name = "aether_ownership",
+        description = "Show who owns a file, symbol or community: CODEOWNERS owners, recency-weighted git blame authors and the bus factor. Without a target, lists the communities with the lowest bus factor"
+    )]
+    pub async fn aether_ownership(
+        &self,
+        Parameters(request): Parameters<AetherOwnershipRequest>,
+    ) -> Result<Json<AetherOwnershipResponse>, McpError> {
+        self.verbose_log("MCP tool called: aether_ownership");
+        let graph = self.state.surreal_graph().await.ok();
+        let server = self.clone();
+        tokio::task::spawn_blocking(move || {
+            server.aether_ownership_logic_with_graph(graph, request)
+        })
+        .await
+        .map_err(|err| McpError::internal_error(err.to_string(), None))?
+        .map(Json)
+        .map_err(to_mcp_error)
+    }
+
+    #[tool(
+        name = "aether_graph_query",
+        description = "Find dependency paths with a small query language, e.g. `from path = src/http/* to name = db::execute avoid name = auth::check`. Supports node predicates on id, name, kind, path, language and SIR fields, `via` edge kinds, `hops` bounds and `through`/`avoid` constraints; returns paths with SIR intents"
+    )]
+    pub async fn aether_graph_query(
+        &self,
+        Parameters(request): Parameters<AetherGraphQueryRequest>,
+    ) -> Result<Json<AetherGraphQueryResponse>, McpError> {
+        self.verbose_log("MCP tool called: aether_graph_query");
+        let server = self.clone();
+        tokio::task::spawn_blocking(move || server.aether_graph_query_logic(request))
+            .await
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?
+            .map(Json)
+            .map_err(to_mcp_error)
+    }
+
     #[tool(
         name = "aether_test_intents",
         description = "Query extracted behavioral test intents for a file or symbol"
@@ -508,6 +555,58 @@ impl AetherMcpServer {
         .map_err(to_mcp_error)
     }
 
+    #[tool(
+        name = "aether_arch_check",
+        description = "Check declared architecture layers (.aether/architecture.toml) against the dependency graph, or check one proposed dependency before introducing it"
+    )]
+    pub async fn aether_arch_check(
+        &self,
+        Parameters(request): Parameters<AetherArchCheckRequest>,
+    ) -> Result<Json<AetherArchCheckResponse>, McpError> {
+        self.verbose_log("MCP tool called: aether_arch_check");
+        let server = self.clone();
+        tokio::task::spawn_blocking(move || server.aether_arch_check_logic(request))
+            .await
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?
+            .map(Json)
+            .map_err(to_mcp_error)
+    }
+
+    #[tool(
+        name = "aether_dead_code",
+        description = "List symbols unreachable from entry points (mains, tests, route handlers, exported symbols, public API) over the dependency graph, with a confidence level per symbol"
+    )]
+    pub async fn aether_dead_code(
+        &self,
+        Parameters(request): Parameters<AetherDeadCodeRequest>,
+    ) -> Result<Json<AetherDeadCodeResponse>, McpError> {
+        self.verbose_log("MCP tool called: aether_dead_code");
+        let server = self.clone();
+        tokio::task::spawn_blocking(move || server.aether_dead_code_logic(request))
+            .await
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?
+            .map(Json)
+            .map_err(to_mcp_error)
+    }
+
+    #[tool(
+        name = "aether_affected_tests",
+        description = "Select the tests affected by changes since a git ref: changed symbols are mapped back over the dependency graph and tested_by links to covering tests, with cargo/nextest, pytest and jest filters and the changed symbols no test covers"
+    )]
+    pub async fn aether_affected_tests(
+        &self,
+        Parameters(request): Parameters<AetherAffectedTestsRequest>,
+    ) -> Result<Json<AetherAffectedTestsResponse>, McpError> {
+        self.verbose_log("MCP tool called: aether_affected_tests");
+        let graph = self.state.surreal_graph().await.ok();
+        let server = self.clone();
+        tokio::task::spawn_blocking(move || server.aether_affected_tests_logic(graph, request))
+            .await
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?
+            .map(Json)
+            .map_err(to_mcp_error)
+    }
+
     #[tool(
         name = "aether_health",
         description = "Get codebase health metrics including critical symbols, bottlenecks, dependency cycles, orphaned code, and risk hotspots."
@@ -607,6 +706,23 @@ impl AetherMcpServer {
         .map_err(to_mcp_error)
     }
 
+    #[tool(
+        name = "aether_explain_trace",
+        description = "Map a Rust panic backtrace, Python traceback or JavaScript stack onto indexed symbols and explain each frame with its SIR intent, error modes, recent SIR changes, open contract violations and the recent commits most likely responsible"
+    )]
+    pub async fn aether_explain_trace(
+        &self,
+        Parameters(request): Parameters<AetherExplainTraceRequest>,
+    ) -> Result<Json<AetherExplainTraceResponse>, McpError> {
+        self.verbose_log("MCP tool called: aether_explain_trace");
+        let server = self.clone();
+        tokio::task::spawn_blocking(move || server.aether_explain_trace_logic(request))
+            .await
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?
+            .map(Json)
+            .map_err(to_mcp_error)
+    }
+
     #[tool(
         name = "aether_acknowledge_drift",
         description = "Acknowledge drift findings and create a project note"
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -128,6 +128,8 @@ pub struct SirAnnotationView {
     pub edge_cases: Option<String>,
     pub complexity: Option<String>,
     pub method_dependencies: Option<HashMap<String, Vec<String>>>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extensions: BTreeMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -155,6 +157,7 @@ impl From<aether_sir::SirAnnotation> for SirAnnotationView {
             edge_cases: value.edge_cases,
             complexity: value.complexity,
             method_dependencies: value.method_dependencies,
+            extensions: value.extensions,
         }
     }
 }
@@ -685,17 +688,8 @@ fn required_request_field<'a>(
 
 fn parse_language_field(language: Option<&str>) -> Result<Language, AetherMcpError> {
     let value = required_request_field(language, "language")?;
-    match value.to_ascii_lowercase().as_str() {
-        "rust" => Ok(Language::Rust),
-        "typescript" => Ok(Language::TypeScript),
-        "tsx" => Ok(Language::Tsx),
-        "javascript" => Ok(Language::JavaScript),
-        "jsx" => Ok(Language::Jsx),
-        "python" => Ok(Language::Python),
-        _ => Err(AetherMcpError::Message(format!(
-            "unsupported language: {value}"
-        ))),
-    }
+    Language::from_name(&value.to_ascii_lowercase())
+        .ok_or_else(|| AetherMcpError::Message(format!("unsupported language: {value}")))
 }
 
 fn aggregate_module_rollup(file_rollups: &[(String, FileSir)]) -> FileSir {
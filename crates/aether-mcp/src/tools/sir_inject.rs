@@ -81,6 +81,7 @@ fn empty_sir_annotation(confidence: f32) -> SirAnnotation {
         edge_cases: None,
         complexity: None,
         method_dependencies: None,
+        extensions: Default::default(),
     }
 }
 
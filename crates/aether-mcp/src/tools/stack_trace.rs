@@ -0,0 +1,203 @@
+use aether_analysis::{
+    ExplainTraceRequest, ExplainedFrame, StackTraceAnalyzer, SuspectCommit, TraceCommit,
+    TraceSirChange, TraceViolation,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::AetherMcpServer;
+use crate::AetherMcpError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherExplainTraceRequest {
+    /// Rust panic backtrace, Python traceback or JavaScript stack, as logged
+    pub trace: String,
+    /// Resolved frames to explain, innermost first (default 10)
+    pub max_frames: Option<u32>,
+    /// Suspect commits to rank (default 5)
+    pub commit_limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherTraceSirChange {
+    pub version: i64,
+    pub created_at: i64,
+    pub commit: Option<String>,
+    pub intent_changed: bool,
+    pub intent: String,
+    pub error_modes_added: Vec<String>,
+    pub error_modes_removed: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherTraceViolation {
+    pub contract_id: i64,
+    pub clause_type: String,
+    pub clause_text: String,
+    pub violation_type: String,
+    pub reason: Option<String>,
+    pub confidence: Option<f64>,
+    pub detected_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherTraceCommit {
+    pub commit: String,
+    pub author: String,
+    pub date: String,
+    pub summary: String,
+    /// Lines of the symbol last changed by this commit
+    pub lines: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherTraceFrame {
+    /// Index of the frame in the trace, innermost first
+    pub position: u32,
+    pub function: String,
+    pub location: Option<String>,
+    pub symbol_id: String,
+    pub symbol_name: String,
+    pub file: String,
+    pub start_line: Option<u32>,
+    pub end_line: Option<u32>,
+    pub intent: Option<String>,
+    pub error_modes: Vec<String>,
+    pub sir_changes: Vec<AetherTraceSirChange>,
+    pub violations: Vec<AetherTraceViolation>,
+    pub commits: Vec<AetherTraceCommit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherSuspectCommit {
+    pub rank: u32,
+    pub score: f64,
+    pub commit: String,
+    pub author: String,
+    pub date: String,
+    pub summary: String,
+    pub symbols: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AetherExplainTraceResponse {
+    pub schema_version: String,
+    /// "rust", "python" or "javascript"
+    pub language: Option<String>,
+    pub message: Option<String>,
+    pub frames_total: u32,
+    pub frames_resolved: u32,
+    /// Resolved frames, innermost first
+    pub chain: Vec<AetherTraceFrame>,
+    pub suspect_commits: Vec<AetherSuspectCommit>,
+    pub notes: Vec<String>,
+}
+
+impl From<TraceSirChange> for AetherTraceSirChange {
+    fn from(value: TraceSirChange) -> Self {
+        Self {
+            version: value.version,
+            created_at: value.created_at,
+            commit: value.commit,
+            intent_changed: value.intent_changed,
+            intent: value.intent,
+            error_modes_added: value.error_modes_added,
+            error_modes_removed: value.error_modes_removed,
+        }
+    }
+}
+
+impl From<TraceViolation> for AetherTraceViolation {
+    fn from(value: TraceViolation) -> Self {
+        Self {
+            contract_id: value.contract_id,
+            clause_type: value.clause_type,
+            clause_text: value.clause_text,
+            violation_type: value.violation_type,
+            reason: value.reason,
+            confidence: value.confidence,
+            detected_at: value.detected_at,
+        }
+    }
+}
+
+impl From<TraceCommit> for AetherTraceCommit {
+    fn from(value: TraceCommit) -> Self {
+        Self {
+            commit: value.commit,
+            author: value.author,
+            date: value.date,
+            summary: value.summary,
+            lines: value.lines as u32,
+        }
+    }
+}
+
+impl From<ExplainedFrame> for AetherTraceFrame {
+    fn from(value: ExplainedFrame) -> Self {
+        Self {
+            position: value.position as u32,
+            function: value.function,
+            location: value.location,
+            symbol_id: value.symbol_id,
+            symbol_name: value.symbol_name,
+            file: value.file,
+            start_line: value.start_line.map(|line| line as u32),
+            end_line: value.end_line.map(|line| line as u32),
+            intent: value.intent,
+            error_modes: value.error_modes,
+            sir_changes: value.sir_changes.into_iter().map(Into::into).collect(),
+            violations: value.violations.into_iter().map(Into::into).collect(),
+            commits: value.commits.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<SuspectCommit> for AetherSuspectCommit {
+    fn from(value: SuspectCommit) -> Self {
+        Self {
+            rank: value.rank,
+            score: value.score,
+            commit: value.commit,
+            author: value.author,
+            date: value.date,
+            summary: value.summary,
+            symbols: value.symbols,
+        }
+    }
+}
+
+impl AetherMcpServer {
+    pub fn aether_explain_trace_logic(
+        &self,
+        request: AetherExplainTraceRequest,
+    ) -> Result<AetherExplainTraceResponse, AetherMcpError> {
+        if request.trace.trim().is_empty() {
+            return Err(AetherMcpError::Message(
+                "trace must not be empty".to_owned(),
+            ));
+        }
+        let analyzer = StackTraceAnalyzer::new(self.workspace())?;
+        let report = analyzer.explain(
+            self.state.store.as_ref(),
+            &ExplainTraceRequest {
+                trace: request.trace,
+                max_frames: request.max_frames.map(|value| value.clamp(1, 50) as usize),
+                commit_limit: request
+                    .commit_limit
+                    .map(|value| value.clamp(1, 50) as usize),
+            },
+        )?;
+
+        Ok(AetherExplainTraceResponse {
+            schema_version: report.schema_version,
+            language: report.language.map(|language| language.as_str().to_owned()),
+            message: report.message,
+            frames_total: report.frames_total as u32,
+            frames_resolved: report.frames_resolved as u32,
+            chain: report.chain.into_iter().map(Into::into).collect(),
+            suspect_commits: report.suspect_commits.into_iter().map(Into::into).collect(),
+            notes: report.notes,
+        })
+    }
+}
@@ -248,6 +248,7 @@ fn run_index_and_seed_sir(workspace: &Path) -> Result<()> {
             edge_cases: None,
             complexity: None,
             method_dependencies: None,
+            extensions: Default::default(),
         };
         let sir_json = serde_json::to_string(&sir)?;
         let hash = sir_hash(&sir);
@@ -638,6 +639,7 @@ fn mcp_get_sir_returns_method_dependencies_when_present() -> Result<()> {
             "load".to_owned(),
             vec!["StoreError".to_owned(), "SymbolRecord".to_owned()],
         )])),
+        extensions: Default::default(),
     };
     store.write_sir_blob(symbol.id.as_str(), serde_json::to_string(&sir)?.as_str())?;
 
@@ -1325,6 +1327,7 @@ fn mcp_suggest_trait_split_returns_clusters() -> Result<()> {
         edge_cases: None,
         complexity: None,
         method_dependencies: None,
+        extensions: Default::default(),
     };
     store.write_sir_blob(
         "trait-example-store",
@@ -1492,6 +1495,7 @@ fn mcp_suggest_trait_split_accepts_struct_targets() -> Result<()> {
         edge_cases: None,
         complexity: None,
         method_dependencies: None,
+        extensions: Default::default(),
     };
     store.write_sir_blob(
         "struct-example-store",
@@ -1661,6 +1665,7 @@ fn mcp_suggest_trait_split_falls_back_to_same_file_implementor_methods() -> Resu
         edge_cases: None,
         complexity: None,
         method_dependencies: None,
+        extensions: Default::default(),
     };
     store.write_sir_blob(
         "struct-sqlite-store",
@@ -2901,6 +2906,8 @@ vector_backend = "sqlite"
         "mining_state",
         "coupled_files",
         "test_guards",
+        "suggested_reviewers",
+        "choke_points",
     ] {
         assert!(object.contains_key(key), "missing key: {key}");
     }
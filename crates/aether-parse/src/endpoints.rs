@@ -0,0 +1,783 @@
+//! Extraction of HTTP route declarations and client call sites with literal paths.
+
+use aether_core::{
+    ANY_HTTP_METHOD, EndpointCall, EndpointRoute, Language, Symbol, normalize_endpoint_path,
+    normalize_http_method,
+};
+use tree_sitter::Node;
+
+use crate::parser::{enclosing_function_symbol_id, node_text};
+
+const METHOD_NAMES: &[&str] = &[
+    "get", "post", "put", "patch", "delete", "head", "options", "any", "all",
+];
+
+/// Receivers whose method calls are HTTP clients rather than route registrations.
+const TS_CLIENT_RECEIVERS: &[&str] = &["axios", "ky", "superagent", "request"];
+const TS_CLIENT_RECEIVER_SUFFIXES: &[&str] = &["http", "client", "api"];
+const PYTHON_CLIENT_MODULES: &[&str] = &["requests", "httpx"];
+
+#[derive(Debug, Default)]
+pub(crate) struct ExtractedEndpoints {
+    pub(crate) routes: Vec<EndpointRoute>,
+    pub(crate) calls: Vec<EndpointCall>,
+}
+
+pub(crate) fn extract_endpoints(
+    language: Language,
+    file_path: &str,
+    source: &[u8],
+    root: Node<'_>,
+    symbols: &[Symbol],
+) -> ExtractedEndpoints {
+    // Framework detection is grammar-specific; plugin grammars have no node names to match.
+    if matches!(language, Language::Plugin(_)) {
+        return ExtractedEndpoints::default();
+    }
+    let mut extractor = EndpointExtractor {
+        file_path,
+        source,
+        symbols,
+        found: ExtractedEndpoints::default(),
+    };
+
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        match language {
+            Language::Rust => extractor.visit_rust(node),
+            Language::TypeScript | Language::Tsx | Language::JavaScript | Language::Jsx => {
+                extractor.visit_typescript(node)
+            }
+            Language::Python => extractor.visit_python(node),
+            Language::Plugin(_) => {}
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+
+    let mut found = extractor.found;
+    found.routes.sort();
+    found.routes.dedup();
+    found.calls.sort();
+    found.calls.dedup();
+    found
+}
+
+struct EndpointExtractor<'a> {
+    file_path: &'a str,
+    source: &'a [u8],
+    symbols: &'a [Symbol],
+    found: ExtractedEndpoints,
+}
+
+impl EndpointExtractor<'_> {
+    fn visit_rust(&mut self, node: Node<'_>) {
+        match node.kind() {
+            "call_expression" if !self.rust_route_call(node) => self.rust_client_call(node),
+            "function_item" => self.rust_route_attributes(node),
+            _ => {}
+        }
+    }
+
+    /// axum `.route("/path", get(handler).post(other))` and actix
+    /// `.route("/path", web::get().to(handler))` or
+    /// `web::resource("/path").route(web::get().to(handler))`.
+    fn rust_route_call(&mut self, node: Node<'_>) -> bool {
+        let Some(function) = node.child_by_field_name("function") else {
+            return false;
+        };
+        if function.kind() != "field_expression" || self.field_name(function) != Some("route") {
+            return false;
+        }
+        let args = named_arguments(node);
+        let (path, router) = match args.as_slice() {
+            [path, router] => (self.rust_string(*path), *router),
+            [router] => (
+                function
+                    .child_by_field_name("value")
+                    .and_then(|receiver| self.rust_resource_path(receiver)),
+                *router,
+            ),
+            _ => return false,
+        };
+        let Some(path) = path.as_deref().and_then(normalize_endpoint_path) else {
+            return false;
+        };
+
+        let mut handlers = Vec::new();
+        self.rust_method_router(router, &mut handlers);
+        for (method, handler) in handlers {
+            self.push_route(method, path.clone(), handler.as_str());
+        }
+        true
+    }
+
+    fn rust_resource_path(&self, receiver: Node<'_>) -> Option<String> {
+        if receiver.kind() != "call_expression" {
+            return None;
+        }
+        let function = receiver.child_by_field_name("function")?;
+        let name = node_text(function, self.source);
+        if !(name == "resource" || name.ends_with("::resource")) {
+            return None;
+        }
+        self.rust_string(*named_arguments(receiver).first()?)
+    }
+
+    fn rust_method_router(&self, node: Node<'_>, out: &mut Vec<(String, String)>) {
+        if node.kind() != "call_expression" {
+            return;
+        }
+        let Some(function) = node.child_by_field_name("function") else {
+            return;
+        };
+        let args = named_arguments(node);
+        match function.kind() {
+            "identifier" | "scoped_identifier" => {
+                let name = node_text(function, self.source);
+                let name = name.rsplit("::").next().unwrap_or_default();
+                if let (Some(method), Some(handler)) = (normalize_http_method(name), args.first()) {
+                    out.push((method, node_text(*handler, self.source)));
+                }
+            }
+            "field_expression" => {
+                let Some(receiver) = function.child_by_field_name("value") else {
+                    return;
+                };
+                let field = self.field_name(function).unwrap_or_default();
+                if field == "to" {
+                    // actix `web::get().to(handler)`
+                    let method = receiver
+                        .child_by_field_name("function")
+                        .map(|callee| node_text(callee, self.source))
+                        .and_then(|callee| {
+                            normalize_http_method(callee.rsplit("::").next().unwrap_or_default())
+                        });
+                    if let (Some(method), Some(handler)) = (method, args.first()) {
+                        out.push((method, node_text(*handler, self.source)));
+                    }
+                    return;
+                }
+                // axum `get(a).post(b)`
+                self.rust_method_router(receiver, out);
+                if let (Some(method), Some(handler)) = (normalize_http_method(field), args.first())
+                {
+                    out.push((method, node_text(*handler, self.source)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// actix `#[get("/path")]` attribute macros on handler functions.
+    fn rust_route_attributes(&mut self, function: Node<'_>) {
+        let Some(name) = function
+            .child_by_field_name("name")
+            .map(|name| node_text(name, self.source))
+        else {
+            return;
+        };
+
+        let mut sibling = function.prev_named_sibling();
+        while let Some(attribute) = sibling.filter(|node| node.kind() == "attribute_item") {
+            sibling = attribute.prev_named_sibling();
+            let text = node_text(attribute, self.source);
+            let Some(inner) = text
+                .trim()
+                .strip_prefix("#[")
+                .and_then(|rest| rest.strip_suffix(']'))
+            else {
+                continue;
+            };
+            let Some((macro_name, arguments)) = inner.split_once('(') else {
+                continue;
+            };
+            let macro_name = macro_name.trim().rsplit("::").next().unwrap_or_default();
+            let Some(method) = normalize_http_method(macro_name) else {
+                continue;
+            };
+            let Some(path) =
+                first_quoted(arguments).and_then(|path| normalize_endpoint_path(&path))
+            else {
+                continue;
+            };
+            if let Some(handler) = self.symbol_for_definition(function, name.as_str()) {
+                self.push_route(method, path, handler.as_str());
+            }
+        }
+    }
+
+    /// reqwest `client.get("https://...")`, `reqwest::get("https://...")` and
+    /// `client.request(Method::POST, "https://...")`. Only absolute URLs count,
+    /// so map lookups such as `cache.get("/key")` are ignored.
+    fn rust_client_call(&mut self, node: Node<'_>) {
+        let Some(function) = node.child_by_field_name("function") else {
+            return;
+        };
+        let args = named_arguments(node);
+        let (method, url) = match function.kind() {
+            "field_expression" => match self.field_name(function) {
+                Some("request") => match args.as_slice() {
+                    [method, url, ..] => {
+                        let method = node_text(*method, self.source);
+                        (
+                            normalize_http_method(method.rsplit("::").next().unwrap_or_default()),
+                            Some(*url),
+                        )
+                    }
+                    _ => return,
+                },
+                Some(field) => (normalize_http_method(field), args.first().copied()),
+                None => return,
+            },
+            "scoped_identifier" => {
+                let callee = node_text(function, self.source);
+                if !callee.starts_with("reqwest::") {
+                    return;
+                }
+                (
+                    normalize_http_method(callee.rsplit("::").next().unwrap_or_default()),
+                    args.first().copied(),
+                )
+            }
+            _ => return,
+        };
+        let (Some(method), Some(url)) = (method, url) else {
+            return;
+        };
+        if method == ANY_HTTP_METHOD {
+            return;
+        }
+        if let Some(url) = self.rust_string(url).filter(|url| is_absolute_url(url)) {
+            self.push_call(node, method, url.as_str());
+        }
+    }
+
+    fn rust_string(&self, node: Node<'_>) -> Option<String> {
+        let text = node_text(node, self.source);
+        match node.kind() {
+            "string_literal" => text
+                .strip_prefix('"')
+                .and_then(|rest| rest.strip_suffix('"'))
+                .map(str::to_owned),
+            "raw_string_literal" => {
+                let text = text.strip_prefix('r')?.trim_matches('#');
+                text.strip_prefix('"')
+                    .and_then(|rest| rest.strip_suffix('"'))
+                    .map(str::to_owned)
+            }
+            _ => None,
+        }
+    }
+
+    fn visit_typescript(&mut self, node: Node<'_>) {
+        if node.kind() != "call_expression" {
+            return;
+        }
+        let Some(function) = node.child_by_field_name("function") else {
+            return;
+        };
+        let args = named_arguments(node);
+
+        match function.kind() {
+            "identifier" => {
+                let callee = node_text(function, self.source);
+                if callee != "fetch" && callee != "axios" {
+                    return;
+                }
+                let Some(path) = args.first().and_then(|arg| self.typescript_string(*arg)) else {
+                    return;
+                };
+                let method = args
+                    .get(1)
+                    .and_then(|options| self.typescript_method_option(*options))
+                    .unwrap_or_else(|| "GET".to_owned());
+                self.push_call(node, method, path.as_str());
+            }
+            "member_expression" => {
+                let Some(property) = function
+                    .child_by_field_name("property")
+                    .map(|property| node_text(property, self.source))
+                else {
+                    return;
+                };
+                if !METHOD_NAMES.contains(&property.as_str()) {
+                    return;
+                }
+                let Some(method) = normalize_http_method(property.as_str()) else {
+                    return;
+                };
+                let Some(path) = args.first().and_then(|arg| self.typescript_string(*arg)) else {
+                    return;
+                };
+                let receiver = function
+                    .child_by_field_name("object")
+                    .map(|object| node_text(object, self.source))
+                    .unwrap_or_default();
+
+                let handler = args.last().filter(|_| args.len() >= 2);
+                if !is_typescript_client_receiver(receiver.as_str())
+                    && let Some(handler) = handler
+                    && matches!(
+                        handler.kind(),
+                        "identifier"
+                            | "member_expression"
+                            | "arrow_function"
+                            | "function_expression"
+                            | "function"
+                    )
+                {
+                    // Express-style `router.get("/path", ...middleware, handler)`;
+                    // inline handlers have no symbol to link to.
+                    if matches!(handler.kind(), "identifier" | "member_expression")
+                        && let Some(path) = normalize_endpoint_path(&path)
+                    {
+                        let handler = node_text(*handler, self.source);
+                        self.push_route(method, path, handler.as_str());
+                    }
+                    return;
+                }
+                if method != ANY_HTTP_METHOD {
+                    self.push_call(node, method, path.as_str());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn typescript_string(&self, node: Node<'_>) -> Option<String> {
+        let text = node_text(node, self.source);
+        match node.kind() {
+            "string" => Some(text.trim_matches(['"', '\'']).to_owned()),
+            "template_string" => Some(text.trim_matches('`').to_owned()),
+            _ => None,
+        }
+    }
+
+    /// `method` property of a `fetch`/`axios` options object.
+    fn typescript_method_option(&self, options: Node<'_>) -> Option<String> {
+        if options.kind() != "object" {
+            return None;
+        }
+        let mut cursor = options.walk();
+        options
+            .named_children(&mut cursor)
+            .filter(|pair| pair.kind() == "pair")
+            .find_map(|pair| {
+                let key = node_text(pair.child_by_field_name("key")?, self.source);
+                if key.trim_matches(['"', '\'']) != "method" {
+                    return None;
+                }
+                let value = self.typescript_string(pair.child_by_field_name("value")?)?;
+                normalize_http_method(value.as_str())
+            })
+    }
+
+    fn visit_python(&mut self, node: Node<'_>) {
+        match node.kind() {
+            "decorated_definition" => self.python_route_decorators(node),
+            "call" => self.python_client_call(node),
+            _ => {}
+        }
+    }
+
+    /// FastAPI `@app.get("/path")` / `@router.api_route(...)` and Flask
+    /// `@app.route("/path", methods=["POST"])` decorators.
+    fn python_route_decorators(&mut self, node: Node<'_>) {
+        let Some(definition) = node.child_by_field_name("definition") else {
+            return;
+        };
+        if definition.kind() != "function_definition" {
+            return;
+        }
+        let Some(name) = definition
+            .child_by_field_name("name")
+            .map(|name| node_text(name, self.source))
+        else {
+            return;
+        };
+
+        let mut cursor = node.walk();
+        let decorators = node
+            .named_children(&mut cursor)
+            .filter(|child| child.kind() == "decorator")
+            .collect::<Vec<_>>();
+        for decorator in decorators {
+            let Some(call) = decorator
+                .named_child(0)
+                .filter(|child| child.kind() == "call")
+            else {
+                continue;
+            };
+            let Some(function) = call
+                .child_by_field_name("function")
+                .filter(|function| function.kind() == "attribute")
+            else {
+                continue;
+            };
+            let Some(attribute) = function
+                .child_by_field_name("attribute")
+                .map(|attribute| node_text(attribute, self.source))
+            else {
+                continue;
+            };
+
+            let args = named_arguments(call);
+            let methods = match attribute.as_str() {
+                "route" | "api_route" => self
+                    .python_methods_keyword(&args)
+                    .unwrap_or_else(|| vec!["GET".to_owned()]),
+                other => match normalize_http_method(other) {
+                    Some(method) => vec![method],
+                    None => continue,
+                },
+            };
+            let Some(path) = args
+                .first()
+                .and_then(|arg| self.python_string(*arg))
+                .and_then(|path| normalize_endpoint_path(&path))
+            else {
+                continue;
+            };
+            let Some(handler) = self.symbol_for_definition(definition, name.as_str()) else {
+                continue;
+            };
+            for method in methods {
+                self.push_route(method, path.clone(), handler.as_str());
+            }
+        }
+    }
+
+    fn python_methods_keyword(&self, args: &[Node<'_>]) -> Option<Vec<String>> {
+        let value = args.iter().find_map(|arg| {
+            (arg.kind() == "keyword_argument"
+                && arg
+                    .child_by_field_name("name")
+                    .is_some_and(|name| node_text(name, self.source) == "methods"))
+            .then(|| arg.child_by_field_name("value"))
+            .flatten()
+        })?;
+        let mut cursor = value.walk();
+        let methods = value
+            .named_children(&mut cursor)
+            .filter_map(|item| self.python_string(item))
+            .filter_map(|method| normalize_http_method(method.as_str()))
+            .collect::<Vec<_>>();
+        (!methods.is_empty()).then_some(methods)
+    }
+
+    /// `requests.get(url)`, `httpx.post(url)`, `requests.request("PUT", url)`
+    /// and other `.get(...)`-style calls on absolute URLs (e.g. sessions).
+    fn python_client_call(&mut self, node: Node<'_>) {
+        let Some(function) = node
+            .child_by_field_name("function")
+            .filter(|function| function.kind() == "attribute")
+        else {
+            return;
+        };
+        let receiver = function
+            .child_by_field_name("object")
+            .map(|object| node_text(object, self.source))
+            .unwrap_or_default();
+        let Some(attribute) = function
+            .child_by_field_name("attribute")
+            .map(|attribute| node_text(attribute, self.source))
+        else {
+            return;
+        };
+
+        let args = named_arguments(node)
+            .into_iter()
+            .filter(|arg| arg.kind() != "keyword_argument")
+            .collect::<Vec<_>>();
+        let (method, url) = if attribute == "request" {
+            match args.as_slice() {
+                [method, url, ..] => (
+                    self.python_string(*method)
+                        .and_then(|method| normalize_http_method(method.as_str())),
+                    self.python_string(*url),
+                ),
+                _ => return,
+            }
+        } else {
+            (
+                normalize_http_method(attribute.as_str()),
+                args.first().and_then(|arg| self.python_string(*arg)),
+            )
+        };
+        let (Some(method), Some(url)) = (method, url) else {
+            return;
+        };
+        if method == ANY_HTTP_METHOD {
+            return;
+        }
+        if PYTHON_CLIENT_MODULES.contains(&receiver.as_str()) || is_absolute_url(url.as_str()) {
+            self.push_call(node, method, url.as_str());
+        }
+    }
+
+    fn python_string(&self, node: Node<'_>) -> Option<String> {
+        if node.kind() != "string" {
+            return None;
+        }
+        let text = node_text(node, self.source);
+        let text = text.trim_start_matches(|ch: char| "rRbBuUfF".contains(ch));
+        Some(text.trim_matches(['"', '\'']).to_owned())
+    }
+
+    fn field_name(&self, field_expression: Node<'_>) -> Option<&str> {
+        let field = field_expression.child_by_field_name("field")?;
+        std::str::from_utf8(&self.source[field.start_byte()..field.end_byte()]).ok()
+    }
+
+    /// Qualified name of the symbol extracted for a function definition.
+    fn symbol_for_definition(&self, definition: Node<'_>, name: &str) -> Option<String> {
+        let line = definition.start_position().row + 1;
+        self.symbols
+            .iter()
+            .filter(|symbol| symbol.name == name)
+            .find(|symbol| symbol.range.start.line <= line && line <= symbol.range.end.line)
+            .map(|symbol| symbol.qualified_name.clone())
+    }
+
+    fn push_route(&mut self, method: String, path: String, handler: &str) {
+        let handler = self.handler_target(handler);
+        if handler.is_empty() {
+            return;
+        }
+        self.found.routes.push(EndpointRoute {
+            file_path: self.file_path.to_owned(),
+            method,
+            path,
+            handler,
+        });
+    }
+
+    /// Resolves a handler expression such as `handlers::get_user` or
+    /// `controller.getUser` to a symbol in this file when possible, otherwise
+    /// to its last path segment.
+    fn handler_target(&self, handler: &str) -> String {
+        let handler = handler.trim();
+        let leaf = handler.rsplit(['.', ':']).next().unwrap_or(handler).trim();
+        if handler.contains("::")
+            && self
+                .symbols
+                .iter()
+                .any(|symbol| symbol.qualified_name == handler)
+        {
+            return handler.to_owned();
+        }
+        self.symbols
+            .iter()
+            .find(|symbol| symbol.name == leaf)
+            .map(|symbol| symbol.qualified_name.clone())
+            .unwrap_or_else(|| leaf.to_owned())
+    }
+
+    fn push_call(&mut self, node: Node<'_>, method: String, url: &str) {
+        let Some(path) = normalize_endpoint_path(url) else {
+            return;
+        };
+        let Some(source_id) = enclosing_function_symbol_id(self.symbols, node) else {
+            return;
+        };
+        self.found.calls.push(EndpointCall {
+            file_path: self.file_path.to_owned(),
+            source_id,
+            method,
+            path,
+        });
+    }
+}
+
+fn named_arguments(call: Node<'_>) -> Vec<Node<'_>> {
+    let Some(arguments) = call.child_by_field_name("arguments") else {
+        return Vec::new();
+    };
+    let mut cursor = arguments.walk();
+    arguments
+        .named_children(&mut cursor)
+        .filter(|child| !matches!(child.kind(), "comment" | "line_comment" | "block_comment"))
+        .collect()
+}
+
+fn is_absolute_url(value: &str) -> bool {
+    value.starts_with("http://") || value.starts_with("https://")
+}
+
+fn is_typescript_client_receiver(receiver: &str) -> bool {
+    let leaf = receiver
+        .rsplit('.')
+        .next()
+        .unwrap_or(receiver)
+        .to_ascii_lowercase();
+    TS_CLIENT_RECEIVERS.contains(&leaf.as_str())
+        || TS_CLIENT_RECEIVER_SUFFIXES
+            .iter()
+            .any(|suffix| leaf.ends_with(suffix))
+}
+
+/// First double-quoted string in attribute arguments, e.g. `"/users/{id}"`.
+fn first_quoted(text: &str) -> Option<String> {
+    let start = text.find('"')? + 1;
+    let end = start + text[start..].find('"')?;
+    Some(text[start..end].to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_core::Language;
+
+    use crate::SymbolExtractor;
+
+    fn extract(language: Language, path: &str, source: &str) -> crate::ExtractedFile {
+        let mut extractor = SymbolExtractor::new().expect("extractor");
+        extractor
+            .extract_with_edges_from_source(language, path, source)
+            .expect("extract")
+    }
+
+    fn routes(extracted: &crate::ExtractedFile) -> Vec<(String, String, String)> {
+        extracted
+            .endpoint_routes
+            .iter()
+            .map(|route| {
+                (
+                    route.method.clone(),
+                    route.path.clone(),
+                    route.handler.clone(),
+                )
+            })
+            .collect()
+    }
+
+    fn calls(extracted: &crate::ExtractedFile) -> Vec<(String, String, String)> {
+        extracted
+            .endpoint_calls
+            .iter()
+            .map(|call| {
+                let source = extracted
+                    .symbols
+                    .iter()
+                    .find(|symbol| symbol.id == call.source_id)
+                    .map(|symbol| symbol.name.clone())
+                    .unwrap_or_default();
+                (call.method.clone(), call.path.clone(), source)
+            })
+            .collect()
+    }
+
+    fn triple(method: &str, path: &str, name: &str) -> (String, String, String) {
+        (method.to_owned(), path.to_owned(), name.to_owned())
+    }
+
+    #[test]
+    fn extracts_axum_and_actix_routes_and_reqwest_calls() {
+        let source = r#"
+async fn get_user() {}
+async fn update_user() {}
+
+#[get("/teams/{team_id}")]
+async fn get_team() {}
+
+fn router() {
+    Router::new()
+        .route("/users/:id", get(get_user).put(handlers::update_user))
+        .route("/health", axum::routing::any(health));
+    App::new().service(web::resource("/orgs/{id}").route(web::delete().to(delete_org)));
+}
+
+async fn sync_profile(client: &reqwest::Client) {
+    client.post("https://api.example.com/profiles/7").send().await;
+    reqwest::get("http://localhost:8080/users/42").await;
+    cache.get("/not-a-request");
+}
+"#;
+        let extracted = extract(Language::Rust, "src/server.rs", source);
+
+        assert_eq!(
+            routes(&extracted),
+            vec![
+                triple("*", "/health", "health"),
+                triple("DELETE", "/orgs/{}", "delete_org"),
+                triple("GET", "/teams/{}", "get_team"),
+                triple("GET", "/users/{}", "get_user"),
+                triple("PUT", "/users/{}", "update_user"),
+            ]
+        );
+        assert_eq!(
+            calls(&extracted),
+            vec![
+                triple("GET", "/users/42", "sync_profile"),
+                triple("POST", "/profiles/7", "sync_profile"),
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_express_routes_and_fetch_axios_calls() {
+        let source = r#"
+function getUser(req, res) {}
+
+router.get("/users/:id", auth, getUser);
+app.post("/users", (req, res) => {});
+
+async function loadUser(id) {
+  await fetch(`${API_URL}/users/${id}`);
+  await fetch("/users", { method: "POST", body: "{}" });
+  await axios.delete(`/users/${id}`);
+  await api.put("/users/1", payload);
+}
+"#;
+        let extracted = extract(Language::TypeScript, "web/src/api.ts", source);
+
+        assert_eq!(
+            routes(&extracted),
+            vec![triple("GET", "/users/{}", "getUser")]
+        );
+        assert_eq!(
+            calls(&extracted),
+            vec![
+                triple("DELETE", "/users/{}", "loadUser"),
+                triple("GET", "/users/{}", "loadUser"),
+                triple("POST", "/users", "loadUser"),
+                triple("PUT", "/users/1", "loadUser"),
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_fastapi_flask_routes_and_requests_calls() {
+        let source = r#"
+@app.get("/users/{user_id}")
+def get_user(user_id):
+    return {}
+
+@bp.route("/users/<int:user_id>", methods=["PUT", "PATCH"])
+def update_user(user_id):
+    return {}
+
+def sync(user_id):
+    requests.get(f"http://api.internal/users/{user_id}", timeout=5)
+    session.post("https://api.internal/users")
+    values.get("/ignored")
+"#;
+        let extracted = extract(Language::Python, "service/app.py", source);
+
+        assert_eq!(
+            routes(&extracted),
+            vec![
+                triple("GET", "/users/{}", "service.app::get_user"),
+                triple("PATCH", "/users/{}", "service.app::update_user"),
+                triple("PUT", "/users/{}", "service.app::update_user"),
+            ]
+        );
+        assert_eq!(
+            calls(&extracted),
+            vec![
+                triple("GET", "/users/{}", "sync"),
+                triple("POST", "/users", "sync"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,439 @@
+//! Retained syntax trees for incremental reparsing.
+//!
+//! A [`SyntaxSnapshot`] keeps the tree-sitter tree and source of a parsed file.
+//! Reparsing applies the text change as an [`InputEdit`], lets tree-sitter reuse
+//! the untouched subtrees, and reruns the symbol query only over the top-level
+//! items the edit touched. Symbols outside those items are carried over with
+//! their ranges shifted past the edit.
+
+use std::ops::Range;
+
+use aether_core::{Language, Position, Symbol, normalize_path};
+use anyhow::{Result, anyhow};
+use tree_sitter::{InputEdit, Node, Point, Tree};
+
+use crate::parser::{SymbolExtractor, extract_symbols, node_range};
+
+/// Parsed file retained between edits.
+pub struct SyntaxSnapshot {
+    config_id: &'static str,
+    language: Language,
+    file_path: String,
+    source: String,
+    tree: Tree,
+    symbols: Vec<Symbol>,
+}
+
+impl SyntaxSnapshot {
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Symbols in the snapshot, sorted by id.
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// Start positions of syntax errors and missing nodes, in source order.
+    pub fn error_positions(&self) -> Vec<Position> {
+        let mut positions = Vec::new();
+        let mut stack = vec![self.tree.root_node()];
+        while let Some(node) = stack.pop() {
+            if !node.has_error() {
+                continue;
+            }
+            if node.is_error() || node.is_missing() {
+                positions.push(node_range(node).start);
+                continue;
+            }
+            let mut cursor = node.walk();
+            stack.extend(node.children(&mut cursor));
+        }
+        positions.sort_by_key(|position| (position.line, position.column));
+        positions
+    }
+}
+
+impl std::fmt::Debug for SyntaxSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyntaxSnapshot")
+            .field("config_id", &self.config_id)
+            .field("language", &self.language)
+            .field("file_path", &self.file_path)
+            .field("source_len", &self.source.len())
+            .field("symbols", &self.symbols.len())
+            .finish()
+    }
+}
+
+impl SymbolExtractor {
+    /// Parses `source` from scratch and retains the tree for later reparsing.
+    pub fn parse_snapshot(
+        &mut self,
+        language: Language,
+        file_path: &str,
+        source: String,
+    ) -> Result<SyntaxSnapshot> {
+        let file_path = normalize_path(file_path);
+        let config_id = self
+            .config_for_language_and_path(language, &file_path)
+            .map(|config| config.id)
+            .ok_or_else(|| anyhow!("unsupported file extension for {file_path}"))?;
+        let tree = self.parse_tree(config_id, &source, None)?;
+
+        let mut snapshot = SyntaxSnapshot {
+            config_id,
+            language,
+            file_path,
+            source,
+            tree,
+            symbols: Vec::new(),
+        };
+        snapshot.symbols = self.symbols_in_range(&snapshot, None)?;
+        Ok(snapshot)
+    }
+
+    /// Applies `source` as an edit of `previous`, reusing its tree and every
+    /// symbol outside the top-level items the edit touched.
+    pub fn reparse_snapshot(
+        &mut self,
+        previous: SyntaxSnapshot,
+        source: String,
+    ) -> Result<SyntaxSnapshot> {
+        let Some(edit) = input_edit(&previous.source, &source) else {
+            return Ok(SyntaxSnapshot { source, ..previous });
+        };
+        if previous
+            .symbols
+            .iter()
+            .any(|symbol| symbol.range.start_byte.is_none() || symbol.range.end_byte.is_none())
+        {
+            return self.parse_snapshot(previous.language, &previous.file_path, source);
+        }
+
+        let SyntaxSnapshot {
+            config_id,
+            language,
+            file_path,
+            tree: mut old_tree,
+            symbols: previous_symbols,
+            ..
+        } = previous;
+        old_tree.edit(&edit);
+        let tree = self.parse_tree(config_id, &source, Some(&old_tree))?;
+
+        let changed = std::iter::once(edit.start_byte..edit.new_end_byte)
+            .chain(
+                old_tree
+                    .changed_ranges(&tree)
+                    .map(|range| range.start_byte..range.end_byte),
+            )
+            .collect::<Vec<_>>();
+        let dirty = top_level_dirty_ranges(tree.root_node(), &changed);
+
+        let mut snapshot = SyntaxSnapshot {
+            config_id,
+            language,
+            file_path,
+            source,
+            tree,
+            symbols: Vec::new(),
+        };
+        let mut symbols = Vec::new();
+        for range in &dirty {
+            symbols.extend(self.symbols_in_range(&snapshot, Some(range.clone()))?);
+        }
+        for symbol in previous_symbols {
+            let Some(symbol) = shift_symbol(symbol, &edit) else {
+                continue;
+            };
+            let (start, end) = byte_span(&symbol);
+            let reextracted = dirty
+                .iter()
+                .any(|range| start < range.end && end > range.start)
+                || symbols
+                    .iter()
+                    .any(|current| current.id == symbol.id || byte_span(current) == (start, end));
+            if !reextracted {
+                symbols.push(symbol);
+            }
+        }
+        symbols.sort_by(|a, b| a.id.cmp(&b.id));
+        symbols.dedup_by(|a, b| a.id == b.id);
+
+        snapshot.symbols = symbols;
+        Ok(snapshot)
+    }
+
+    fn parse_tree(
+        &mut self,
+        config_id: &str,
+        source: &str,
+        old_tree: Option<&Tree>,
+    ) -> Result<Tree> {
+        let parser = self
+            .parsers
+            .get_mut(config_id)
+            .ok_or_else(|| anyhow!("missing parser for language config {config_id}"))?;
+        parser
+            .parse(source, old_tree)
+            .ok_or_else(|| anyhow!("tree-sitter parser produced no syntax tree"))
+    }
+
+    fn symbols_in_range(
+        &self,
+        snapshot: &SyntaxSnapshot,
+        byte_range: Option<Range<usize>>,
+    ) -> Result<Vec<Symbol>> {
+        let config = self
+            .registry
+            .get_by_id(snapshot.config_id)
+            .ok_or_else(|| anyhow!("missing language config {}", snapshot.config_id))?;
+        let mut symbols = extract_symbols(
+            snapshot.language,
+            &snapshot.file_path,
+            snapshot.source.as_bytes(),
+            snapshot.tree.root_node(),
+            config,
+            byte_range,
+        );
+        symbols.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(symbols)
+    }
+}
+
+/// Single edit turning `old` into `new`, found by trimming their common
+/// prefix and suffix. `None` when the texts are identical.
+fn input_edit(old: &str, new: &str) -> Option<InputEdit> {
+    if old == new {
+        return None;
+    }
+    let (old_bytes, new_bytes) = (old.as_bytes(), new.as_bytes());
+    let prefix = old_bytes
+        .iter()
+        .zip(new_bytes)
+        .take_while(|(left, right)| left == right)
+        .count();
+    let max_suffix = old_bytes.len().min(new_bytes.len()) - prefix;
+    let suffix = old_bytes
+        .iter()
+        .rev()
+        .zip(new_bytes.iter().rev())
+        .take(max_suffix)
+        .take_while(|(left, right)| left == right)
+        .count();
+
+    let old_end_byte = old_bytes.len() - suffix;
+    let new_end_byte = new_bytes.len() - suffix;
+    Some(InputEdit {
+        start_byte: prefix,
+        old_end_byte,
+        new_end_byte,
+        start_position: point_at(old_bytes, prefix),
+        old_end_position: point_at(old_bytes, old_end_byte),
+        new_end_position: point_at(new_bytes, new_end_byte),
+    })
+}
+
+fn point_at(source: &[u8], byte: usize) -> Point {
+    let before = &source[..byte];
+    let row = before.iter().filter(|value| **value == b'\n').count();
+    let line_start = before
+        .iter()
+        .rposition(|value| *value == b'\n')
+        .map_or(0, |index| index + 1);
+    Point {
+        row,
+        column: byte - line_start,
+    }
+}
+
+/// Spans of the top-level items touching any changed range, merged.
+fn top_level_dirty_ranges(root: Node<'_>, changed: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut cursor = root.walk();
+    let mut dirty = root
+        .children(&mut cursor)
+        .map(|child| child.start_byte()..child.end_byte())
+        .filter(|item| {
+            changed
+                .iter()
+                .any(|range| item.start <= range.end && item.end >= range.start)
+        })
+        .collect::<Vec<_>>();
+    dirty.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(dirty.len());
+    for range in dirty {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+fn byte_span(symbol: &Symbol) -> (usize, usize) {
+    (
+        symbol.range.start_byte.unwrap_or_default(),
+        symbol.range.end_byte.unwrap_or_default(),
+    )
+}
+
+/// Moves a symbol from before the edit into the edited text, or `None` when
+/// the edit overlaps it.
+fn shift_symbol(mut symbol: Symbol, edit: &InputEdit) -> Option<Symbol> {
+    let (start, end) = byte_span(&symbol);
+    if end < edit.start_byte {
+        return Some(symbol);
+    }
+    if start <= edit.old_end_byte {
+        return None;
+    }
+
+    symbol.range.start = shift_position(symbol.range.start, edit);
+    symbol.range.end = shift_position(symbol.range.end, edit);
+    symbol.range.start_byte = Some(start - edit.old_end_byte + edit.new_end_byte);
+    symbol.range.end_byte = Some(end - edit.old_end_byte + edit.new_end_byte);
+    Some(symbol)
+}
+
+/// Shifts a 1-based position that lies after the edit's old end.
+fn shift_position(position: Position, edit: &InputEdit) -> Position {
+    let row = position.line - 1;
+    let column = position.column - 1;
+    let (row, column) = if row == edit.old_end_position.row {
+        (
+            edit.new_end_position.row,
+            edit.new_end_position.column + column - edit.old_end_position.column,
+        )
+    } else {
+        (
+            row - edit.old_end_position.row + edit.new_end_position.row,
+            column,
+        )
+    };
+    Position {
+        line: row + 1,
+        column: column + 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_matches_full_parse(language: Language, path: &str, before: &str, after: &str) {
+        let mut extractor = SymbolExtractor::new().expect("extractor");
+        let previous = extractor
+            .parse_snapshot(language, path, before.to_owned())
+            .expect("parse before");
+        let incremental = extractor
+            .reparse_snapshot(previous, after.to_owned())
+            .expect("reparse after");
+        let full = extractor
+            .extract_from_source(language, path, after)
+            .expect("full parse after");
+        assert_eq!(incremental.symbols(), full.as_slice(), "edit: {after}");
+    }
+
+    #[test]
+    fn reparse_matches_full_parse_for_rust_edits() {
+        let before = "fn keep() -> i32 { 1 }\n\nstruct Widget;\n\nimpl Widget {\n    fn size(&self) -> u32 { 1 }\n    fn name(&self) -> &str { \"w\" }\n}\n\nfn tail() {}\n";
+        for after in [
+            before.replace("{ 1 }\n\nstruct", "{ 42 }\n\nstruct"),
+            before.replace(
+                "fn keep() -> i32 { 1 }\n",
+                "fn keep() -> i32 {\n    let value = 1;\n    value\n}\n",
+            ),
+            before.replace("impl Widget", "impl Gadget"),
+            before.replace("    fn name(&self) -> &str { \"w\" }\n", ""),
+            before.replace(
+                "fn tail() {}",
+                "fn tail() {}\n\nfn added(x: u8) -> u8 { x }",
+            ),
+            format!("// header\n{before}"),
+            String::new(),
+        ] {
+            assert_matches_full_parse(Language::Rust, "src/lib.rs", before, &after);
+        }
+    }
+
+    #[test]
+    fn reparse_matches_full_parse_for_python_and_typescript_edits() {
+        let python =
+            "class Service:\n    def run(self):\n        return 1\n\ndef helper():\n    return 2\n";
+        assert_matches_full_parse(
+            Language::Python,
+            "app/service.py",
+            python,
+            &python.replace("class Service", "class Worker"),
+        );
+        assert_matches_full_parse(
+            Language::Python,
+            "app/service.py",
+            python,
+            &python.replace("return 2", "return helper_two()"),
+        );
+
+        let typescript = "export function load(id: string) {\n  return id;\n}\n\nexport class Store {\n  save(value: number) {\n    return value;\n  }\n}\n";
+        assert_matches_full_parse(
+            Language::TypeScript,
+            "web/store.ts",
+            typescript,
+            &typescript.replace("return id;", "return `${id}!`;"),
+        );
+    }
+
+    #[test]
+    fn reparse_keeps_untouched_symbols_and_shifts_their_ranges() {
+        let before = "fn first() {}\nfn second() {}\n";
+        let after = "fn first() { work(); }\nfn second() {}\n";
+        let mut extractor = SymbolExtractor::new().expect("extractor");
+        let previous = extractor
+            .parse_snapshot(Language::Rust, "src/lib.rs", before.to_owned())
+            .expect("parse before");
+        let second_before = previous
+            .symbols()
+            .iter()
+            .find(|symbol| symbol.name == "second")
+            .cloned()
+            .expect("second before");
+
+        let edit = input_edit(before, after).expect("edit");
+        assert_eq!(edit.start_byte, 12);
+        assert_eq!(edit.new_end_byte - edit.old_end_byte, 9);
+
+        let snapshot = extractor
+            .reparse_snapshot(previous, after.to_owned())
+            .expect("reparse");
+        let second_after = snapshot
+            .symbols()
+            .iter()
+            .find(|symbol| symbol.name == "second")
+            .expect("second after");
+        assert_eq!(second_after.id, second_before.id);
+        assert_eq!(second_after.content_hash, second_before.content_hash);
+        assert_eq!(second_after.range.start_byte, Some(23));
+        assert_eq!(second_after.range.start, Position { line: 2, column: 1 });
+    }
+
+    #[test]
+    fn error_positions_report_syntax_errors() {
+        let mut extractor = SymbolExtractor::new().expect("extractor");
+        let clean = extractor
+            .parse_snapshot(Language::Rust, "src/lib.rs", "fn ok() {}\n".to_owned())
+            .expect("parse clean");
+        assert!(clean.error_positions().is_empty());
+
+        let broken = extractor
+            .parse_snapshot(
+                Language::Rust,
+                "src/lib.rs",
+                "fn ok() {}\nfn broken( {}\n".to_owned(),
+            )
+            .expect("parse broken");
+        let positions = broken.error_positions();
+        assert!(!positions.is_empty());
+        assert!(positions.iter().all(|position| position.line == 2));
+    }
+}
@@ -5,10 +5,16 @@ use aether_core::{
 };
 use tree_sitter::{Node, Query};
 
+use crate::parser::{
+    REFERENCE_SOURCE_KINDS, enclosing_symbol_id, is_screaming_case, resolve_same_file_symbol,
+};
 use crate::parser::{TestIntent, humanize_test_name, normalize_intent_text};
 use crate::parser::{build_symbol, enclosing_function_symbol_id, named_child_text, node_text};
 use crate::registry::{LanguageConfig, LanguageHooks, QueryCaptures};
 
+/// Base classes whose class-body assignments are enum members.
+const ENUM_BASES: &[&str] = &["Enum", "IntEnum", "StrEnum", "Flag", "IntFlag"];
+
 pub fn config() -> LanguageConfig {
     let ts_language = tree_sitter_python::LANGUAGE.into();
     let symbol_query = Query::new(&ts_language, include_str!("../queries/python_symbols.scm"))
@@ -121,6 +127,20 @@ impl LanguageHooks for PythonHooks {
                         .collect(),
                 )
             }
+            "edge.references" => {
+                // Unmatched identifiers must not fall through to the default mapper.
+                let Some(target) = python_reference_target(node, source, file_path, symbols) else {
+                    return Some(Vec::new());
+                };
+                let source_id = enclosing_symbol_id(symbols, node, REFERENCE_SOURCE_KINDS)
+                    .unwrap_or_else(|| file_source_id(file_path));
+                Some(vec![SymbolEdge {
+                    source_id,
+                    target_qualified_name: target,
+                    edge_kind: EdgeKind::References,
+                    file_path: file_path.to_owned(),
+                }])
+            }
             _ => None,
         }
     }
@@ -249,11 +269,12 @@ impl PythonHooks {
         source: &[u8],
         node: Node<'_>,
     ) -> Option<Symbol> {
-        if has_ancestor_kind(node, "function_definition")
-            || has_ancestor_kind(node, "class_definition")
-        {
+        if has_ancestor_kind(node, "function_definition") {
             return None;
         }
+        if has_ancestor_kind(node, "class_definition") {
+            return self.map_class_attribute_symbol(language, file_path, source, node);
+        }
         let name_node = node
             .child_by_field_name("left")
             .or_else(|| node.child_by_field_name("name"))
@@ -262,7 +283,12 @@ impl PythonHooks {
         if name.is_empty() {
             return None;
         }
-        if node.kind() == "assignment" {
+        let kind = if name_node.kind() == "identifier" && is_screaming_case(&name) {
+            SymbolKind::Constant
+        } else {
+            SymbolKind::Variable
+        };
+        if node.kind() == "assignment" && kind == SymbolKind::Variable {
             let has_type = node.child_by_field_name("type").is_some();
             if !has_type && name != "__all__" {
                 return None;
@@ -274,7 +300,51 @@ impl PythonHooks {
         Some(build_symbol(
             language,
             file_path,
-            SymbolKind::Variable,
+            kind,
+            &name,
+            &qualified_name,
+            node,
+            source,
+        ))
+    }
+
+    /// Direct class-body assignments: enum members on `Enum` subclasses,
+    /// fields otherwise.
+    fn map_class_attribute_symbol(
+        &self,
+        language: Language,
+        file_path: &str,
+        source: &[u8],
+        node: Node<'_>,
+    ) -> Option<Symbol> {
+        let class_node = node
+            .parent()
+            .filter(|parent| parent.kind() == "expression_statement")
+            .and_then(|statement| statement.parent())
+            .filter(|body| body.kind() == "block")
+            .and_then(|body| body.parent())
+            .filter(|class_node| class_node.kind() == "class_definition")?;
+        let name_node = node
+            .child_by_field_name("left")
+            .filter(|left| left.kind() == "identifier")?;
+        let name = node_text(name_node, source).trim().to_owned();
+        if name.is_empty() {
+            return None;
+        }
+
+        let kind = if is_enum_class(class_node, source) {
+            SymbolKind::EnumVariant
+        } else {
+            SymbolKind::Field
+        };
+        let class_name = nearest_ancestor_name(node, source, &["class_definition"])?;
+        let qualified_name = self
+            .qualify_name(file_path, &name, Some(&class_name))
+            .unwrap_or_else(|| format!("{}::{class_name}::{name}", python_module_path(file_path)));
+        Some(build_symbol(
+            language,
+            file_path,
+            kind,
             &name,
             &qualified_name,
             node,
@@ -376,6 +446,127 @@ fn python_call_target(node: Node<'_>, source: &[u8]) -> Option<String> {
     (!target.is_empty()).then_some(target)
 }
 
+/// Target of a constant read, a `Class.MEMBER` access on a same-file or
+/// imported class, or a `self.field` read.
+fn python_reference_target(
+    node: Node<'_>,
+    source: &[u8],
+    file_path: &str,
+    symbols: &[Symbol],
+) -> Option<String> {
+    let parent = node.parent()?;
+    let is_callee = parent.kind() == "call" && parent.child_by_field_name("function") == Some(node);
+
+    match node.kind() {
+        "identifier" => {
+            let is_binding = match parent.kind() {
+                "assignment" | "augmented_assignment" => {
+                    parent.child_by_field_name("left") == Some(node)
+                }
+                "attribute" => parent.child_by_field_name("attribute") == Some(node),
+                "keyword_argument" | "function_definition" | "class_definition" => {
+                    parent.child_by_field_name("name") == Some(node)
+                }
+                "dotted_name"
+                | "aliased_import"
+                | "parameters"
+                | "default_parameter"
+                | "typed_parameter"
+                | "typed_default_parameter"
+                | "global_statement" => true,
+                _ => false,
+            };
+            if is_callee || is_binding {
+                return None;
+            }
+            let name = node_text(node, source);
+            let name = name.trim();
+            if let Some(symbol) = resolve_same_file_symbol(symbols, &[SymbolKind::Constant], name) {
+                return Some(symbol.qualified_name.clone());
+            }
+            if !is_screaming_case(name) {
+                return None;
+            }
+            Some(
+                python_imported_name_path(node, source, file_path, name)
+                    .unwrap_or_else(|| name.to_owned()),
+            )
+        }
+        "attribute" => {
+            if is_callee {
+                return None;
+            }
+            let object = node_text(node.child_by_field_name("object")?, source);
+            let object = object.trim();
+            let attribute = node_text(node.child_by_field_name("attribute")?, source);
+            let attribute = attribute.trim();
+
+            if object == "self" {
+                let class_name = nearest_ancestor_name(node, source, &["class_definition"])?;
+                return resolve_same_file_symbol(
+                    symbols,
+                    &[SymbolKind::Field],
+                    &format!("{class_name}::{attribute}"),
+                )
+                .map(|symbol| symbol.qualified_name.clone());
+            }
+
+            if !object.starts_with(|ch: char| ch.is_ascii_uppercase())
+                || !is_screaming_case(attribute)
+            {
+                return None;
+            }
+            let path = format!("{object}::{attribute}");
+            resolve_same_file_symbol(
+                symbols,
+                &[SymbolKind::Field, SymbolKind::EnumVariant],
+                &path,
+            )
+            .map(|symbol| symbol.qualified_name.clone())
+            .or_else(|| {
+                python_imported_name_path(node, source, file_path, object)
+                    .map(|class_path| format!("{class_path}::{attribute}"))
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Qualified name of `name` when a top-level `from X import name` brings it in.
+fn python_imported_name_path(
+    node: Node<'_>,
+    source: &[u8],
+    file_path: &str,
+    name: &str,
+) -> Option<String> {
+    let mut root = node;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+
+    let mut cursor = root.walk();
+    root.named_children(&mut cursor)
+        .filter(|statement| statement.kind() == "import_from_statement")
+        .find_map(|statement| {
+            let text = node_text(statement, source);
+            let rest = text.trim().strip_prefix("from")?.trim();
+            let (module_raw, names_raw) = rest.split_once(" import ")?;
+            let module = resolve_import_module(module_raw.trim(), file_path);
+            if module.is_empty() {
+                return None;
+            }
+            let names_raw = names_raw.trim();
+            let names_raw = names_raw
+                .strip_prefix('(')
+                .and_then(|value| value.strip_suffix(')'))
+                .unwrap_or(names_raw);
+            names_raw.split(',').find_map(|entry| {
+                let (imported, visible) = entry.split_once(" as ").unwrap_or((entry, entry));
+                (visible.trim() == name).then(|| format!("{module}::{}", imported.trim()))
+            })
+        })
+}
+
 fn python_import_targets(node: Node<'_>, source: &[u8], file_path: &str) -> Vec<String> {
     let text = node_text(node, source);
     match node.kind() {
@@ -520,6 +711,18 @@ fn has_ancestor_kind(node: Node<'_>, kind: &str) -> bool {
     false
 }
 
+fn is_enum_class(class_node: Node<'_>, source: &[u8]) -> bool {
+    let Some(superclasses) = class_node.child_by_field_name("superclasses") else {
+        return false;
+    };
+    let mut cursor = superclasses.walk();
+    superclasses.named_children(&mut cursor).any(|base| {
+        let text = node_text(base, source);
+        let leaf = text.trim().rsplit('.').next().unwrap_or_default();
+        ENUM_BASES.contains(&leaf)
+    })
+}
+
 fn is_directly_decorated(node: Node<'_>) -> bool {
     node.parent()
         .is_some_and(|parent| parent.kind() == "decorated_definition")
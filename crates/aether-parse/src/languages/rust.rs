@@ -5,8 +5,9 @@ use streaming_iterator::StreamingIterator;
 use tree_sitter::{Node, Query, QueryCursor};
 
 use crate::parser::{
-    TestIntent, build_symbol, collect_scoped_identifier_segments, has_ancestor_kind,
-    humanize_test_name, node_text, normalize_intent_text, rust_call_target, rust_qualified_name,
+    REFERENCE_SOURCE_KINDS, TestIntent, build_symbol, collect_scoped_identifier_segments,
+    enclosing_symbol_id, has_ancestor_kind, humanize_test_name, is_screaming_case, node_text,
+    normalize_intent_text, resolve_same_file_symbol, rust_call_target, rust_qualified_name,
     rust_source_function_id, rust_use_target,
 };
 use crate::registry::{LanguageConfig, LanguageHooks, QueryCaptures};
@@ -83,6 +84,46 @@ const STDLIB_TYPES: &[&str] = &[
     "OsString",
 ];
 
+/// Standard library macros; invocations of these are not recorded as references.
+const STD_MACROS: &[&str] = &[
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "cfg",
+    "column",
+    "compile_error",
+    "concat",
+    "dbg",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "env",
+    "eprint",
+    "eprintln",
+    "file",
+    "format",
+    "format_args",
+    "include",
+    "include_bytes",
+    "include_str",
+    "line",
+    "matches",
+    "module_path",
+    "option_env",
+    "panic",
+    "print",
+    "println",
+    "stringify",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "vec",
+    "write",
+    "writeln",
+];
+
+const RUST_CONSTANT_KINDS: &[SymbolKind] = &[SymbolKind::Constant, SymbolKind::Static];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct TypeCandidate {
     leaf: String,
@@ -116,6 +157,12 @@ impl LanguageHooks for RustHooks {
             "symbol.enum" => SymbolKind::Enum,
             "symbol.trait" => SymbolKind::Trait,
             "symbol.type_alias" => SymbolKind::TypeAlias,
+            "symbol.constant" => SymbolKind::Constant,
+            "symbol.static" => SymbolKind::Static,
+            "symbol.macro" => SymbolKind::Macro,
+            "symbol.module" => SymbolKind::Module,
+            "symbol.field" => SymbolKind::Field,
+            "symbol.enum_variant" => SymbolKind::EnumVariant,
             "symbol.function" => {
                 if has_ancestor_kind(node, "impl_item") || has_ancestor_kind(node, "trait_item") {
                     SymbolKind::Method
@@ -387,6 +434,30 @@ pub(crate) fn extract_rust_edges(
                     });
                 }
             }
+            "edge.references" => {
+                let Some(node) = captures.node("edge.references") else {
+                    continue;
+                };
+                let Some(target) = rust_reference_target(node, source, symbols) else {
+                    continue;
+                };
+                let Some(source_id) = enclosing_symbol_id(symbols, node, REFERENCE_SOURCE_KINDS)
+                else {
+                    continue;
+                };
+                if symbols
+                    .iter()
+                    .any(|symbol| symbol.id == source_id && symbol.qualified_name == target)
+                {
+                    continue;
+                }
+                edges.push(SymbolEdge {
+                    source_id,
+                    target_qualified_name: target,
+                    edge_kind: EdgeKind::References,
+                    file_path: file_path.to_owned(),
+                });
+            }
             "edge.implements" => {
                 let Some(self_type) = captures.node("self_type") else {
                     continue;
@@ -416,6 +487,116 @@ pub(crate) fn extract_rust_edges(
     edges
 }
 
+/// Target of a constant, static, field or enum-variant read, or of a
+/// user-defined macro invocation. Same-file symbols resolve to their
+/// qualified name; everything else keeps the name as written.
+fn rust_reference_target(node: Node<'_>, source: &[u8], symbols: &[Symbol]) -> Option<String> {
+    if has_ancestor_kind(node, "use_declaration") {
+        return None;
+    }
+
+    match node.kind() {
+        "macro_invocation" => {
+            let macro_node = node.child_by_field_name("macro")?;
+            let name = match macro_node.kind() {
+                "scoped_identifier" => node_text(macro_node.child_by_field_name("name")?, source),
+                _ => node_text(macro_node, source),
+            };
+            let name = name.trim();
+            if name.is_empty() || STD_MACROS.contains(&name) {
+                return None;
+            }
+            Some(
+                resolve_same_file_symbol(symbols, &[SymbolKind::Macro], name)
+                    .map(|symbol| symbol.qualified_name.clone())
+                    .unwrap_or_else(|| name.to_owned()),
+            )
+        }
+        "identifier" => {
+            let parent = node.parent()?;
+            if parent.kind() == "scoped_identifier"
+                || parent.kind() == "macro_invocation"
+                || parent.child_by_field_name("name") == Some(node)
+            {
+                return None;
+            }
+            let name = node_text(node, source);
+            let name = name.trim();
+            if !is_screaming_case(name) {
+                return None;
+            }
+            Some(
+                resolve_same_file_symbol(symbols, RUST_CONSTANT_KINDS, name)
+                    .map(|symbol| symbol.qualified_name.clone())
+                    .unwrap_or_else(|| name.to_owned()),
+            )
+        }
+        "scoped_identifier" => {
+            let parent = node.parent()?;
+            if parent.kind() == "scoped_identifier" || parent.kind() == "macro_invocation" {
+                return None;
+            }
+            let name = node_text(node.child_by_field_name("name")?, source);
+            let name = name.trim();
+            let owner = node
+                .child_by_field_name("path")
+                .and_then(|path| collect_type_path_segments(path, source).pop())
+                .map(|segment| {
+                    if segment == "Self" {
+                        rust_impl_type_name(node, source).unwrap_or(segment)
+                    } else {
+                        segment
+                    }
+                })
+                .filter(|segment| segment.starts_with(|ch: char| ch.is_ascii_uppercase()));
+
+            let (kinds, path) = if is_screaming_case(name) {
+                let path = match owner {
+                    Some(owner) => format!("{owner}::{name}"),
+                    None => name.to_owned(),
+                };
+                (RUST_CONSTANT_KINDS, path)
+            } else if name.starts_with(|ch: char| ch.is_ascii_uppercase()) {
+                (
+                    &[SymbolKind::EnumVariant][..],
+                    format!("{}::{name}", owner?),
+                )
+            } else {
+                return None;
+            };
+            Some(
+                resolve_same_file_symbol(symbols, kinds, &path)
+                    .map(|symbol| symbol.qualified_name.clone())
+                    .unwrap_or(path),
+            )
+        }
+        "field_expression" => {
+            let field = node_text(node.child_by_field_name("field")?, source);
+            let owner = rust_impl_type_name(node, source)?;
+            resolve_same_file_symbol(
+                symbols,
+                &[SymbolKind::Field],
+                &format!("{owner}::{}", field.trim()),
+            )
+            .map(|symbol| symbol.qualified_name.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Name of the type an enclosing `impl` block is for, without generics.
+fn rust_impl_type_name(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(cursor) = current {
+        if cursor.kind() == "impl_item" {
+            let type_node = cursor.child_by_field_name("type")?;
+            return primary_type_candidate(type_node, source).map(|candidate| candidate.leaf);
+        }
+        current = cursor.parent();
+    }
+    None
+}
+
 fn collect_type_candidates(
     node: Node<'_>,
     source: &[u8],
@@ -2,8 +2,9 @@ use aether_core::{EdgeKind, Language, Symbol, SymbolEdge, SymbolKind, file_sourc
 use tree_sitter::{Node, Query};
 
 use crate::parser::{
-    TestIntent, build_symbol, normalize_intent_text, typescript_call_target,
-    typescript_qualified_name, typescript_source_function_id,
+    REFERENCE_SOURCE_KINDS, TestIntent, build_symbol, enclosing_symbol_id, is_screaming_case,
+    named_child_text, node_text, normalize_intent_text, resolve_same_file_symbol,
+    typescript_call_target, typescript_qualified_name, typescript_source_function_id,
 };
 use crate::registry::{LanguageConfig, LanguageHooks, QueryCaptures};
 
@@ -91,6 +92,23 @@ impl LanguageHooks for TypeScriptHooks {
             "symbol.function" => SymbolKind::Function,
             "symbol.method" => SymbolKind::Method,
             "symbol.type_alias" => SymbolKind::TypeAlias,
+            "symbol.enum" => SymbolKind::Enum,
+            "symbol.enum_variant" => SymbolKind::EnumVariant,
+            "symbol.field" => SymbolKind::Field,
+            "symbol.module" => SymbolKind::Module,
+            "symbol.const_declaration" => {
+                // Arrow functions and classes bound to a const are not values.
+                let is_callable = node.child_by_field_name("value").is_some_and(|value| {
+                    matches!(
+                        value.kind(),
+                        "arrow_function" | "function_expression" | "function" | "class"
+                    )
+                });
+                if is_callable {
+                    return None;
+                }
+                SymbolKind::Constant
+            }
             _ => return None,
         };
 
@@ -112,7 +130,7 @@ impl LanguageHooks for TypeScriptHooks {
         captures: &QueryCaptures<'_, '_>,
         source: &[u8],
         file_path: &str,
-        _symbols: &[Symbol],
+        symbols: &[Symbol],
     ) -> Option<Vec<SymbolEdge>> {
         let capture_name = captures.first_capture_name_with_prefix("edge.")?;
         let node = captures.node_with_prefix("edge.")?;
@@ -145,6 +163,20 @@ impl LanguageHooks for TypeScriptHooks {
                     file_path: file_path.to_owned(),
                 }])
             }
+            "edge.references" => {
+                // Unmatched identifiers must not fall through to the default mapper.
+                let Some(target) = typescript_reference_target(node, source, symbols) else {
+                    return Some(Vec::new());
+                };
+                let source_id = enclosing_symbol_id(symbols, node, REFERENCE_SOURCE_KINDS)
+                    .unwrap_or_else(|| file_source_id(file_path));
+                Some(vec![SymbolEdge {
+                    source_id,
+                    target_qualified_name: target,
+                    edge_kind: EdgeKind::References,
+                    file_path: file_path.to_owned(),
+                }])
+            }
             _ => None,
         }
     }
@@ -191,6 +223,88 @@ impl LanguageHooks for TypeScriptHooks {
     }
 }
 
+/// Target of a constant read, an `Enum.Member` / `Class.STATIC` access or a
+/// `this.field` read.
+fn typescript_reference_target(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[Symbol],
+) -> Option<String> {
+    let parent = node.parent()?;
+    let is_callee =
+        parent.kind() == "call_expression" && parent.child_by_field_name("function") == Some(node);
+
+    match node.kind() {
+        "identifier" => {
+            if is_callee
+                || parent.child_by_field_name("name") == Some(node)
+                || matches!(
+                    parent.kind(),
+                    "import_specifier" | "import_clause" | "namespace_import" | "export_specifier"
+                )
+            {
+                return None;
+            }
+            let name = node_text(node, source);
+            let name = name.trim();
+            match resolve_same_file_symbol(symbols, &[SymbolKind::Constant], name) {
+                Some(symbol) => Some(symbol.qualified_name.clone()),
+                None => is_screaming_case(name).then(|| name.to_owned()),
+            }
+        }
+        "member_expression" => {
+            if is_callee {
+                return None;
+            }
+            let object = node.child_by_field_name("object")?;
+            let property = node_text(node.child_by_field_name("property")?, source);
+            let property = property.trim();
+            if object.kind() == "this" {
+                let class = typescript_enclosing_class_name(node, source)?;
+                return resolve_same_file_symbol(
+                    symbols,
+                    &[SymbolKind::Field],
+                    &format!("{class}::{property}"),
+                )
+                .map(|symbol| symbol.qualified_name.clone());
+            }
+
+            let object = node_text(object, source);
+            let object = object.trim();
+            let is_upper = |text: &str| text.starts_with(|ch: char| ch.is_ascii_uppercase());
+            if !is_upper(object) || !is_upper(property) {
+                return None;
+            }
+            let path = format!("{object}::{property}");
+            Some(
+                resolve_same_file_symbol(
+                    symbols,
+                    &[
+                        SymbolKind::EnumVariant,
+                        SymbolKind::Field,
+                        SymbolKind::Constant,
+                    ],
+                    &path,
+                )
+                .map(|symbol| symbol.qualified_name.clone())
+                .unwrap_or(path),
+            )
+        }
+        _ => None,
+    }
+}
+
+fn typescript_enclosing_class_name(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if parent.kind() == "class_declaration" {
+            return named_child_text(parent, "name", source);
+        }
+        current = parent.parent();
+    }
+    None
+}
+
 fn call_name(function: Node<'_>, source: &[u8]) -> Option<String> {
     match function.kind() {
         "identifier" => Some(crate::parser::node_text(function, source)),
@@ -1,9 +1,19 @@
+mod endpoints;
+mod incremental;
 mod languages;
 mod parser;
+mod plugins;
 mod registry;
+mod source_contracts;
 
+pub use incremental::SyntaxSnapshot;
 pub use parser::{
     ExtractedFile, RustUsePathAtCursor, RustUsePrefix, SymbolExtractor, TestIntent,
     language_for_path, rust_use_path_at_cursor,
 };
+pub use plugins::{
+    EDGES_QUERY_FILE, GenericLanguageHooks, LanguagePlugin, LanguagePluginSpec, SYMBOLS_QUERY_FILE,
+    TEST_INTENTS_QUERY_FILE, install_language_plugins,
+};
 pub use registry::{LanguageConfig, LanguageHooks, LanguageRegistry};
+pub use source_contracts::SourceContract;
@@ -1,22 +1,27 @@
 use std::collections::HashMap;
+use std::ops::Range;
 use std::path::Path;
 
 use aether_core::{
-    EdgeKind, Language, Position, SourceRange, Symbol, SymbolEdge, SymbolKind, content_hash,
-    file_source_id, normalize_for_fingerprint, normalize_path, signature_fingerprint,
-    stable_symbol_id,
+    EdgeKind, EndpointCall, EndpointRoute, Language, Position, SourceRange, Symbol, SymbolEdge,
+    SymbolKind, content_hash, file_source_id, normalize_for_fingerprint, normalize_path,
+    signature_fingerprint, stable_symbol_id,
 };
 use anyhow::{Result, anyhow};
 use streaming_iterator::StreamingIterator;
 use tree_sitter::{Node, Parser, Point, QueryCursor};
 
 use crate::registry::{LanguageConfig, LanguageRegistry, QueryCaptures, default_registry};
+use crate::source_contracts::{SourceContract, extract_source_contracts};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExtractedFile {
     pub symbols: Vec<Symbol>,
     pub edges: Vec<SymbolEdge>,
     pub test_intents: Vec<TestIntent>,
+    pub endpoint_routes: Vec<EndpointRoute>,
+    pub endpoint_calls: Vec<EndpointCall>,
+    pub source_contracts: Vec<SourceContract>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -30,8 +35,8 @@ pub struct TestIntent {
 }
 
 pub struct SymbolExtractor {
-    registry: LanguageRegistry,
-    parsers: HashMap<&'static str, Parser>,
+    pub(crate) registry: LanguageRegistry,
+    pub(crate) parsers: HashMap<&'static str, Parser>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -50,7 +55,11 @@ pub struct RustUsePathAtCursor {
 
 impl SymbolExtractor {
     pub fn new() -> Result<Self> {
-        let registry = default_registry();
+        Self::with_registry(default_registry())
+    }
+
+    /// Builds an extractor over an explicit set of language configs.
+    pub fn with_registry(registry: LanguageRegistry) -> Result<Self> {
         let mut parsers = HashMap::new();
 
         for config in registry.configs() {
@@ -84,12 +93,12 @@ impl SymbolExtractor {
         path: &Path,
         source: &str,
     ) -> Result<ExtractedFile> {
-        let language = language_for_path(path)
+        let config = self.registry.get_by_path(path);
+        let language = self
+            .path_language(path)
             .ok_or_else(|| anyhow!("unsupported file extension: {}", path.display()))?;
         let file_path = normalize_path(&path.to_string_lossy());
-        let config_id = self
-            .registry
-            .get_by_path(path)
+        let config_id = config
             .map(|config| config.id)
             .ok_or_else(|| anyhow!("no parser config for extension: {}", path.display()))?;
 
@@ -103,6 +112,16 @@ impl SymbolExtractor {
         )
     }
 
+    /// The language of `path`: a built-in language by extension, otherwise
+    /// the plugin language whose registered extensions cover it.
+    pub fn path_language(&self, path: &Path) -> Option<Language> {
+        language_for_path(path).or_else(|| {
+            self.registry
+                .get_by_path(path)
+                .map(|config| Language::Plugin(config.id))
+        })
+    }
+
     pub fn extract_with_edges_from_source(
         &mut self,
         language: Language,
@@ -125,7 +144,7 @@ impl SymbolExtractor {
         )
     }
 
-    fn config_for_language_and_path(
+    pub(crate) fn config_for_language_and_path(
         &self,
         language: Language,
         file_path: &str,
@@ -140,6 +159,7 @@ impl SymbolExtractor {
             Language::TypeScript => "typescript",
             Language::Tsx | Language::JavaScript | Language::Jsx => "tsx_js",
             Language::Python => "python",
+            Language::Plugin(id) => id,
         };
         self.registry.get_by_id(fallback_id)
     }
@@ -167,7 +187,7 @@ fn extract_with_config(
     let root = tree.root_node();
     let source_bytes = source.as_bytes();
 
-    let mut symbols = extract_symbols(language, file_path, source_bytes, root, config);
+    let mut symbols = extract_symbols(language, file_path, source_bytes, root, config, None);
     symbols.sort_by(|a, b| a.id.cmp(&b.id));
 
     let mut edges = extract_edges(language, file_path, source_bytes, root, config, &symbols);
@@ -177,21 +197,33 @@ fn extract_with_config(
         extract_test_intents(language, file_path, source_bytes, root, config, &symbols);
     sort_and_dedupe_test_intents(&mut test_intents);
 
+    let endpoints =
+        crate::endpoints::extract_endpoints(language, file_path, source_bytes, root, &symbols);
+    let source_contracts = extract_source_contracts(language, source, &symbols);
+
     Ok(ExtractedFile {
         symbols,
         edges,
         test_intents,
+        endpoint_routes: endpoints.routes,
+        endpoint_calls: endpoints.calls,
+        source_contracts,
     })
 }
 
-fn extract_symbols(
+/// Runs the symbol query over `root`, or only over matches intersecting `byte_range`.
+pub(crate) fn extract_symbols(
     language: Language,
     file_path: &str,
     source: &[u8],
     root: Node<'_>,
     config: &LanguageConfig,
+    byte_range: Option<Range<usize>>,
 ) -> Vec<Symbol> {
     let mut cursor = QueryCursor::new();
+    if let Some(byte_range) = byte_range {
+        cursor.set_byte_range(byte_range);
+    }
     let mut symbols = Vec::new();
 
     let mut query_matches = cursor.matches(&config.symbol_query, root, source);
@@ -422,6 +454,12 @@ fn parse_symbol_kind(value: &str) -> Option<SymbolKind> {
         "trait" => Some(SymbolKind::Trait),
         "interface" => Some(SymbolKind::Interface),
         "type_alias" => Some(SymbolKind::TypeAlias),
+        "constant" => Some(SymbolKind::Constant),
+        "static" => Some(SymbolKind::Static),
+        "macro" => Some(SymbolKind::Macro),
+        "module" => Some(SymbolKind::Module),
+        "field" => Some(SymbolKind::Field),
+        "enum_variant" => Some(SymbolKind::EnumVariant),
         _ => None,
     }
 }
@@ -437,6 +475,12 @@ fn symbol_kind_from_capture_name(capture_name: &str) -> Option<SymbolKind> {
         "trait" => Some(SymbolKind::Trait),
         "interface" => Some(SymbolKind::Interface),
         "type_alias" => Some(SymbolKind::TypeAlias),
+        "constant" => Some(SymbolKind::Constant),
+        "static" => Some(SymbolKind::Static),
+        "macro" => Some(SymbolKind::Macro),
+        "module" => Some(SymbolKind::Module),
+        "field" => Some(SymbolKind::Field),
+        "enum_variant" => Some(SymbolKind::EnumVariant),
         _ => None,
     }
 }
@@ -447,6 +491,7 @@ fn parse_edge_kind(value: &str) -> Option<EdgeKind> {
         "depends_on" => Some(EdgeKind::DependsOn),
         "type_ref" => Some(EdgeKind::TypeRef),
         "implements" => Some(EdgeKind::Implements),
+        "references" => Some(EdgeKind::References),
         _ => None,
     }
 }
@@ -457,6 +502,7 @@ fn edge_kind_from_capture_name(capture_name: &str) -> Option<EdgeKind> {
         "depends_on" => Some(EdgeKind::DependsOn),
         "type_ref" => Some(EdgeKind::TypeRef),
         "implements" => Some(EdgeKind::Implements),
+        "references" => Some(EdgeKind::References),
         _ => None,
     }
 }
@@ -480,7 +526,7 @@ pub fn language_for_path(path: &Path) -> Option<Language> {
         "js" => Some(Language::JavaScript),
         "jsx" => Some(Language::Jsx),
         "py" | "pyi" => Some(Language::Python),
-        _ => None,
+        other => crate::plugins::plugin_language_for_extension(other),
     }
 }
 
@@ -546,7 +592,11 @@ pub(crate) fn build_symbol(
     source: &[u8],
 ) -> Symbol {
     let symbol_text = node_text(node, source);
-    let signature_text = declaration_prefix(node, source);
+    let signature_text = if is_value_kind(kind) {
+        value_declaration_prefix(node, source)
+    } else {
+        declaration_prefix(node, source)
+    };
     let signature = signature_fingerprint(&signature_text);
     let id = stable_symbol_id(language, file_path, kind, qualified_name, &signature);
 
@@ -572,6 +622,25 @@ pub(crate) fn declaration_prefix(node: Node<'_>, source: &[u8]) -> String {
     byte_range_text(source, start, end)
 }
 
+/// Declaration text up to the initializer, so retuning a constant's value
+/// keeps its symbol id while still changing its content hash.
+fn value_declaration_prefix(node: Node<'_>, source: &[u8]) -> String {
+    let start = node.start_byte();
+    let end = node
+        .child_by_field_name("value")
+        .or_else(|| node.child_by_field_name("right"))
+        .map(|value| value.start_byte())
+        .unwrap_or_else(|| node.end_byte());
+    byte_range_text(source, start, end)
+}
+
+fn is_value_kind(kind: SymbolKind) -> bool {
+    matches!(
+        kind,
+        SymbolKind::Constant | SymbolKind::Static | SymbolKind::Field | SymbolKind::EnumVariant
+    )
+}
+
 pub(crate) fn has_ancestor_kind(node: Node<'_>, kind: &str) -> bool {
     let mut current = node.parent();
     while let Some(parent) = current {
@@ -718,12 +787,32 @@ pub(crate) fn typescript_call_target(node: Node<'_>, source: &[u8]) -> Option<St
 }
 
 pub(crate) fn enclosing_function_symbol_id(symbols: &[Symbol], node: Node<'_>) -> Option<String> {
+    enclosing_symbol_id(symbols, node, &[SymbolKind::Function, SymbolKind::Method])
+}
+
+/// Symbol kinds that can own a `references` edge.
+pub(crate) const REFERENCE_SOURCE_KINDS: &[SymbolKind] = &[
+    SymbolKind::Function,
+    SymbolKind::Method,
+    SymbolKind::Constant,
+    SymbolKind::Static,
+    SymbolKind::Field,
+    SymbolKind::EnumVariant,
+    SymbolKind::Variable,
+];
+
+/// Innermost symbol of one of `kinds` whose range contains `node`.
+pub(crate) fn enclosing_symbol_id(
+    symbols: &[Symbol],
+    node: Node<'_>,
+    kinds: &[SymbolKind],
+) -> Option<String> {
     let start = node.start_position();
     let end = node.end_position();
 
     symbols
         .iter()
-        .filter(|symbol| matches!(symbol.kind, SymbolKind::Function | SymbolKind::Method))
+        .filter(|symbol| kinds.contains(&symbol.kind))
         .filter(|symbol| {
             let symbol_start = point_from_position(symbol.range.start);
             let symbol_end = point_from_position(symbol.range.end);
@@ -746,6 +835,30 @@ pub(crate) fn enclosing_function_symbol_id(symbols: &[Symbol], node: Node<'_>) -
         .map(|symbol| symbol.id.clone())
 }
 
+/// The unique same-file symbol of one of `kinds` whose qualified name is
+/// `path` or ends with `::path`.
+pub(crate) fn resolve_same_file_symbol<'a>(
+    symbols: &'a [Symbol],
+    kinds: &[SymbolKind],
+    path: &str,
+) -> Option<&'a Symbol> {
+    let suffix = format!("::{path}");
+    let mut matches = symbols.iter().filter(|symbol| {
+        kinds.contains(&symbol.kind)
+            && (symbol.qualified_name == path || symbol.qualified_name.ends_with(&suffix))
+    });
+    let first = matches.next()?;
+    matches.next().is_none().then_some(first)
+}
+
+/// `MAX_RETRIES`-style names, which are treated as constant reads.
+pub(crate) fn is_screaming_case(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|ch| ch.is_ascii_uppercase())
+        && name.len() > 1
+        && chars.all(|ch| ch.is_ascii_uppercase() || ch.is_ascii_digit() || ch == '_')
+}
+
 pub(crate) fn rust_qualified_name(node: Node<'_>, name: &str, source: &[u8]) -> String {
     qualify(&collect_rust_context(node, source), name)
 }
@@ -843,7 +956,7 @@ fn collect_rust_context(node: Node<'_>, source: &[u8]) -> Vec<String> {
                     .unwrap_or_else(|| "impl".to_owned());
                 context.push(target);
             }
-            "trait_item" => {
+            "trait_item" | "struct_item" | "enum_item" | "union_item" | "enum_variant" => {
                 if let Some(name) = named_child_text(cursor, "name", source) {
                     context.push(name);
                 }
@@ -864,7 +977,11 @@ fn collect_typescript_context(node: Node<'_>, source: &[u8]) -> Vec<String> {
 
     while let Some(cursor) = current {
         match cursor.kind() {
-            "class_declaration" | "interface_declaration" => {
+            "class_declaration"
+            | "interface_declaration"
+            | "enum_declaration"
+            | "internal_module"
+            | "module" => {
                 if let Some(name) = named_child_text(cursor, "name", source) {
                     context.push(name);
                 }
@@ -1061,6 +1178,82 @@ type UserId = string;
         assert!(names.contains(&"UserId".to_owned()));
     }
 
+    #[test]
+    fn extracts_source_contract_annotations_above_symbols() {
+        let contracts_of = |language, path: &str, source: &str| {
+            let extracted = extract_with_edges(language, path, source);
+            extracted
+                .source_contracts
+                .iter()
+                .map(|contract| {
+                    let owner = extracted
+                        .symbols
+                        .iter()
+                        .find(|symbol| symbol.id == contract.symbol_id)
+                        .map(|symbol| symbol.qualified_name.clone())
+                        .unwrap_or_default();
+                    (
+                        owner,
+                        contract.clause_type.clone(),
+                        contract.clause_text.clone(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let triple = |owner: &str, clause_type: &str, text: &str| {
+            (owner.to_owned(), clause_type.to_owned(), text.to_owned())
+        };
+
+        let rust = r#"
+/// Validates a payment amount.
+/// @aether must: reject zero amounts
+/// @aether must-not: modify the balance
+#[inline]
+pub fn validate_amount(amount: u64) -> bool { amount > 0 }
+
+// @aether must: unrelated, separated by a blank line
+
+fn helper() {}
+"#;
+        let mut found = contracts_of(Language::Rust, "src/payments.rs", rust);
+        found.sort();
+        assert_eq!(
+            found,
+            vec![
+                triple("validate_amount", "must", "reject zero amounts"),
+                triple("validate_amount", "must_not", "modify the balance"),
+            ]
+        );
+
+        let typescript = r#"
+/**
+ * @aether preserves: idempotency
+ */
+export function charge(id: string) {}
+"#;
+        assert_eq!(
+            contracts_of(Language::TypeScript, "src/charge.ts", typescript),
+            vec![triple("charge", "preserves", "idempotency")]
+        );
+
+        let python = r#"
+# @aether must_call: audit.log
+# @aether must_be_tested
+@retry
+def refund(order):
+    pass
+"#;
+        let mut found = contracts_of(Language::Python, "pkg/refund.py", python);
+        found.sort();
+        assert_eq!(
+            found,
+            vec![
+                triple("pkg.refund::refund", "must_be_tested", ""),
+                triple("pkg.refund::refund", "must_call", "audit.log"),
+            ]
+        );
+    }
+
     #[test]
     fn extracts_rust_test_intents_from_names_and_doc_comments() {
         let source = r#"
@@ -1492,6 +1685,187 @@ trait Processor {
         assert_eq!(method.kind, SymbolKind::Method);
     }
 
+    #[test]
+    fn extracts_rust_value_symbol_kinds() {
+        let source = r#"
+pub const MAX_RETRIES: u32 = 3;
+static GREETING: &str = "hi";
+
+macro_rules! retry {
+    ($body:expr) => { $body };
+}
+
+mod config {
+    pub const TIMEOUT_MS: u64 = 500;
+}
+
+mod external;
+
+pub struct Limits {
+    pub burst: u32,
+}
+
+enum Mode {
+    Fast,
+    Slow { delay: u64 },
+}
+
+impl Limits {
+    const DEFAULT_BURST: u32 = 10;
+}
+
+fn run() {
+    const LOCAL: u32 = 1;
+}
+"#;
+
+        let symbols = extract(Language::Rust, "src/lib.rs", source);
+        let kind_of = |qualified_name: &str| {
+            symbols
+                .iter()
+                .find(|symbol| symbol.qualified_name == qualified_name)
+                .map(|symbol| symbol.kind)
+        };
+
+        assert_eq!(kind_of("MAX_RETRIES"), Some(SymbolKind::Constant));
+        assert_eq!(kind_of("GREETING"), Some(SymbolKind::Static));
+        assert_eq!(kind_of("retry"), Some(SymbolKind::Macro));
+        assert_eq!(kind_of("config"), Some(SymbolKind::Module));
+        assert_eq!(kind_of("config::TIMEOUT_MS"), Some(SymbolKind::Constant));
+        assert_eq!(kind_of("Limits::burst"), Some(SymbolKind::Field));
+        assert_eq!(kind_of("Mode::Fast"), Some(SymbolKind::EnumVariant));
+        assert_eq!(kind_of("Mode::Slow::delay"), Some(SymbolKind::Field));
+        assert_eq!(kind_of("Limits::DEFAULT_BURST"), Some(SymbolKind::Constant));
+        assert_eq!(kind_of("external"), None);
+        assert!(symbols.iter().all(|symbol| symbol.name != "LOCAL"));
+    }
+
+    #[test]
+    fn rust_constant_id_survives_value_change() {
+        let before = extract(Language::Rust, "src/lib.rs", "const LIMIT: u32 = 3;\n");
+        let after = extract(Language::Rust, "src/lib.rs", "const LIMIT: u32 = 4;\n");
+
+        assert_eq!(before[0].id, after[0].id);
+        assert_ne!(before[0].content_hash, after[0].content_hash);
+    }
+
+    #[test]
+    fn extracts_rust_reference_edges() {
+        let source = r#"
+const MAX_RETRIES: u32 = 3;
+const DOUBLE: u32 = MAX_RETRIES * 2;
+
+macro_rules! retry {
+    ($body:expr) => { $body };
+}
+
+struct Limits {
+    burst: u32,
+}
+
+enum Mode {
+    Fast,
+}
+
+impl Limits {
+    const CAP: u32 = 10;
+
+    fn check(&self, mode: Mode) -> bool {
+        println!("checking");
+        let within = self.burst < Self::CAP + other::TIMEOUT;
+        retry!(within && MAX_RETRIES > 0) && mode == Mode::Fast
+    }
+}
+"#;
+
+        let extracted = extract_with_edges(Language::Rust, "src/lib.rs", source);
+        assert_eq!(
+            edge_targets_for_source(&extracted, "Limits::check", EdgeKind::References),
+            vec![
+                "Limits::CAP".to_owned(),
+                "Limits::burst".to_owned(),
+                "MAX_RETRIES".to_owned(),
+                "Mode::Fast".to_owned(),
+                "TIMEOUT".to_owned(),
+                "retry".to_owned(),
+            ]
+        );
+        assert_eq!(
+            edge_targets_for_source(&extracted, "DOUBLE", EdgeKind::References),
+            vec!["MAX_RETRIES".to_owned()]
+        );
+        assert!(
+            edge_targets_for_source(&extracted, "MAX_RETRIES", EdgeKind::References).is_empty()
+        );
+    }
+
+    #[test]
+    fn extracts_typescript_value_symbols_and_references() {
+        let source = r#"
+export const MAX_ITEMS = 10;
+const defaultName = "cart";
+const build = () => defaultName;
+
+enum Color {
+    Red,
+    Green = 2,
+}
+
+namespace Shapes {
+    export function area(): number { return 0; }
+}
+
+interface Options {
+    limit: number;
+}
+
+class Cart {
+    items: string[] = [];
+
+    add(item: string) {
+        if (this.items.length < MAX_ITEMS) {
+            this.items.push(item);
+        }
+        return Color.Red;
+    }
+}
+"#;
+
+        let extracted = extract_with_edges(Language::TypeScript, "src/cart.ts", source);
+        let kind_of = |qualified_name: &str| {
+            extracted
+                .symbols
+                .iter()
+                .find(|symbol| symbol.qualified_name == qualified_name)
+                .map(|symbol| symbol.kind)
+        };
+
+        assert_eq!(kind_of("MAX_ITEMS"), Some(SymbolKind::Constant));
+        assert_eq!(kind_of("defaultName"), Some(SymbolKind::Constant));
+        assert_eq!(kind_of("build"), None);
+        assert_eq!(kind_of("Color"), Some(SymbolKind::Enum));
+        assert_eq!(kind_of("Color::Red"), Some(SymbolKind::EnumVariant));
+        assert_eq!(kind_of("Color::Green"), Some(SymbolKind::EnumVariant));
+        assert_eq!(kind_of("Shapes"), Some(SymbolKind::Module));
+        assert_eq!(kind_of("Shapes::area"), Some(SymbolKind::Function));
+        assert_eq!(kind_of("Options::limit"), Some(SymbolKind::Field));
+        assert_eq!(kind_of("Cart::items"), Some(SymbolKind::Field));
+
+        assert_eq!(
+            edge_targets_for_source(&extracted, "Cart::add", EdgeKind::References),
+            vec![
+                "Cart::items".to_owned(),
+                "Color::Red".to_owned(),
+                "MAX_ITEMS".to_owned(),
+            ]
+        );
+        assert!(extracted.edges.iter().any(|edge| {
+            edge.edge_kind == EdgeKind::References
+                && edge.source_id == file_source_id("src/cart.ts")
+                && edge.target_qualified_name == "defaultName"
+        }));
+    }
+
     #[test]
     fn rust_source_range_includes_byte_offsets() {
         let source = "fn alpha() {\n    beta();\n}\n";
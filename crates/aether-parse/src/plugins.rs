@@ -0,0 +1,449 @@
+//! Tree-sitter grammars loaded at runtime from shared libraries.
+//!
+//! A plugin pairs a compiled grammar (`libtree-sitter-kotlin.so`) with a query
+//! pack directory holding `symbols.scm`, `edges.scm` and optionally
+//! `test_intents.scm`. Query packs use the same capture conventions as the
+//! built-in languages, interpreted by [`GenericLanguageHooks`]:
+//!
+//! - symbols: `@symbol.<kind>` on the declaration, `@name` for its name and an
+//!   optional `@parent` for the enclosing type;
+//! - edges: `@edge.<kind>` on the reference, with an optional `@target`;
+//! - test intents: `@test` on the test declaration, `@test.name` for its name
+//!   and an optional `@test.description` string.
+//!
+//! Installed plugins join every registry built by [`default_registry`] after
+//! the built-in languages, so built-in extensions cannot be overridden.
+//!
+//! [`default_registry`]: crate::registry::default_registry
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use aether_core::{Language, Symbol};
+use anyhow::{Context, Result, anyhow};
+use tree_sitter::{LANGUAGE_VERSION, MIN_COMPATIBLE_LANGUAGE_VERSION, Query};
+
+use crate::parser::{
+    TestIntent, enclosing_function_symbol_id, humanize_test_name, normalize_intent_text,
+};
+use crate::registry::{LanguageConfig, LanguageHooks, QueryCaptures};
+
+pub const SYMBOLS_QUERY_FILE: &str = "symbols.scm";
+pub const EDGES_QUERY_FILE: &str = "edges.scm";
+pub const TEST_INTENTS_QUERY_FILE: &str = "test_intents.scm";
+
+static INSTALLED_PLUGINS: RwLock<Vec<LanguagePlugin>> = RwLock::new(Vec::new());
+
+/// Where to find a runtime grammar and its query pack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguagePluginSpec {
+    pub name: String,
+    pub extensions: Vec<String>,
+    /// Shared library exporting the grammar.
+    pub grammar_path: PathBuf,
+    /// Exported language function, usually `tree_sitter_<name>`.
+    pub language_symbol: String,
+    pub queries_dir: PathBuf,
+}
+
+/// A loaded grammar and its query pack, compiled once at load.
+#[derive(Clone)]
+pub struct LanguagePlugin {
+    config: Arc<LanguageConfig>,
+}
+
+impl std::fmt::Debug for LanguagePlugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LanguagePlugin")
+            .field("id", &self.config.id)
+            .field("extensions", &self.config.extensions)
+            .field("abi_version", &self.abi_version())
+            .finish()
+    }
+}
+
+impl LanguagePlugin {
+    /// Loads the grammar library and query pack, and checks that the queries
+    /// compile against the grammar.
+    pub fn load(spec: &LanguagePluginSpec) -> Result<Self> {
+        let ts_language = load_grammar(&spec.grammar_path, &spec.language_symbol)?;
+        let read_query = |file_name: &str| {
+            let path = spec.queries_dir.join(file_name);
+            std::fs::read_to_string(&path)
+                .with_context(|| format!("failed to read query file {}", path.display()))
+        };
+        let test_intents_path = spec.queries_dir.join(TEST_INTENTS_QUERY_FILE);
+        let test_intent_query = test_intents_path
+            .is_file()
+            .then(|| read_query(TEST_INTENTS_QUERY_FILE))
+            .transpose()?;
+
+        Self::from_parts(
+            &spec.name,
+            &spec.extensions,
+            ts_language,
+            read_query(SYMBOLS_QUERY_FILE)?,
+            read_query(EDGES_QUERY_FILE)?,
+            test_intent_query,
+        )
+    }
+
+    pub(crate) fn from_parts(
+        name: &str,
+        extensions: &[String],
+        ts_language: tree_sitter::Language,
+        symbol_query: String,
+        edge_query: String,
+        test_intent_query: Option<String>,
+    ) -> Result<Self> {
+        let extensions = extensions
+            .iter()
+            .map(|extension| {
+                extension
+                    .trim()
+                    .trim_start_matches('.')
+                    .to_ascii_lowercase()
+            })
+            .filter(|extension| !extension.is_empty())
+            .map(|extension| &*Box::leak(extension.into_boxed_str()))
+            .collect::<Vec<_>>();
+        if extensions.is_empty() {
+            return Err(anyhow!(
+                "language plugin '{name}' declares no file extensions"
+            ));
+        }
+
+        if Language::built_in(name).is_some() {
+            return Err(anyhow!(
+                "language plugin '{name}' shadows a built-in language"
+            ));
+        }
+
+        let symbol_query = compile_query(&ts_language, &symbol_query, SYMBOLS_QUERY_FILE)?;
+        require_captures(&symbol_query, SYMBOLS_QUERY_FILE, "name", "symbol.")?;
+        let edge_query = compile_query(&ts_language, &edge_query, EDGES_QUERY_FILE)?;
+        require_captures(&edge_query, EDGES_QUERY_FILE, "target", "edge.")?;
+        let test_intent_query = test_intent_query
+            .as_deref()
+            .map(|source| {
+                let query = compile_query(&ts_language, source, TEST_INTENTS_QUERY_FILE)?;
+                require_captures(&query, TEST_INTENTS_QUERY_FILE, "test", "test.name")?;
+                Ok::<_, anyhow::Error>(query)
+            })
+            .transpose()?;
+
+        // Registry entries are keyed by `&'static str`; plugins are loaded once
+        // at startup, so leaking their extensions is bounded. The id is the
+        // interned plugin language name.
+        Ok(Self {
+            config: Arc::new(LanguageConfig {
+                id: Language::plugin(name).as_str(),
+                extensions: Box::leak(extensions.into_boxed_slice()),
+                ts_language,
+                symbol_query,
+                edge_query,
+                test_intent_query,
+                module_markers: &[],
+                hooks: Some(Box::new(GenericLanguageHooks)),
+            }),
+        })
+    }
+
+    pub fn id(&self) -> &'static str {
+        self.config.id
+    }
+
+    /// The language symbols from this plugin are recorded under.
+    pub fn language(&self) -> Language {
+        Language::Plugin(self.config.id)
+    }
+
+    pub fn extensions(&self) -> &'static [&'static str] {
+        self.config.extensions
+    }
+
+    /// ABI version the grammar was generated with.
+    pub fn abi_version(&self) -> usize {
+        self.config.ts_language.version()
+    }
+
+    pub fn has_test_intent_query(&self) -> bool {
+        self.config.test_intent_query.is_some()
+    }
+
+    /// The registry entry compiled from the query pack, using
+    /// [`GenericLanguageHooks`].
+    pub fn config(&self) -> Arc<LanguageConfig> {
+        Arc::clone(&self.config)
+    }
+}
+
+/// Makes `plugins` part of every registry built from now on, replacing any
+/// previously installed set.
+pub fn install_language_plugins(plugins: Vec<LanguagePlugin>) {
+    *INSTALLED_PLUGINS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = plugins;
+}
+
+pub(crate) fn installed_language_plugins() -> Vec<LanguagePlugin> {
+    INSTALLED_PLUGINS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// The language of the installed plugin that handles files with `extension`.
+pub(crate) fn plugin_language_for_extension(extension: &str) -> Option<Language> {
+    INSTALLED_PLUGINS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .find(|plugin| plugin.extensions().contains(&extension))
+        .map(LanguagePlugin::language)
+}
+
+/// Hooks for plugin languages: symbols and edges use the default capture
+/// mapping, test intents come from `@test`/`@test.name` captures.
+pub struct GenericLanguageHooks;
+
+impl LanguageHooks for GenericLanguageHooks {
+    fn map_test_intent(
+        &self,
+        language: Language,
+        captures: &QueryCaptures<'_, '_>,
+        source: &[u8],
+        file_path: &str,
+        symbols: &[Symbol],
+    ) -> Option<Vec<TestIntent>> {
+        let node = captures.node("test")?;
+        let test_name = normalize_intent_text(&captures.capture_text("test.name", source)?);
+        if test_name.is_empty() {
+            return None;
+        }
+
+        let intent_text = captures
+            .capture_text("test.description", source)
+            .map(|text| normalize_intent_text(&text))
+            .filter(|text| !text.is_empty())
+            .unwrap_or_else(|| humanize_test_name(&test_name));
+        if intent_text.is_empty() {
+            return None;
+        }
+
+        Some(vec![TestIntent {
+            file_path: file_path.to_owned(),
+            test_name,
+            intent_text,
+            group_label: None,
+            language,
+            symbol_id: enclosing_function_symbol_id(symbols, node),
+        }])
+    }
+}
+
+fn compile_query(language: &tree_sitter::Language, source: &str, file_name: &str) -> Result<Query> {
+    Query::new(language, source).map_err(|err| {
+        anyhow!(
+            "{file_name}:{}:{}: {:?} error: {}",
+            err.row + 1,
+            err.column + 1,
+            err.kind,
+            err.message
+        )
+    })
+}
+
+/// Requires a capture named `exact` or starting with `prefix`.
+fn require_captures(query: &Query, file_name: &str, exact: &str, prefix: &str) -> Result<()> {
+    let names = query.capture_names();
+    if names
+        .iter()
+        .any(|name| *name == exact || name.starts_with(prefix))
+    {
+        return Ok(());
+    }
+    Err(anyhow!(
+        "{file_name} captures neither @{exact} nor @{prefix}*; found: {}",
+        if names.is_empty() {
+            "none".to_owned()
+        } else {
+            names.join(", ")
+        }
+    ))
+}
+
+#[cfg(unix)]
+fn load_grammar(path: &Path, symbol: &str) -> Result<tree_sitter::Language> {
+    use std::ffi::{CStr, CString};
+    use std::os::unix::ffi::OsStrExt;
+
+    use tree_sitter_language::LanguageFn;
+
+    fn last_error() -> String {
+        // SAFETY: dlerror returns null or a NUL-terminated thread-local string.
+        let message = unsafe { libc::dlerror() };
+        if message.is_null() {
+            return "unknown error".to_owned();
+        }
+        // SAFETY: checked non-null above.
+        unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .with_context(|| format!("invalid grammar path {}", path.display()))?;
+    let c_symbol =
+        CString::new(symbol).with_context(|| format!("invalid grammar symbol '{symbol}'"))?;
+
+    // SAFETY: both arguments are valid NUL-terminated strings. The handle is
+    // never closed: the grammar's parse tables live in the library and every
+    // tree built from it points into them.
+    let handle = unsafe { libc::dlopen(c_path.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL) };
+    if handle.is_null() {
+        return Err(anyhow!(
+            "failed to load grammar {}: {}",
+            path.display(),
+            last_error()
+        ));
+    }
+    // SAFETY: `handle` is a live library handle and `c_symbol` is NUL-terminated.
+    let address = unsafe { libc::dlsym(handle, c_symbol.as_ptr()) };
+    if address.is_null() {
+        return Err(anyhow!(
+            "grammar {} does not export '{symbol}': {}",
+            path.display(),
+            last_error()
+        ));
+    }
+
+    // SAFETY: tree-sitter grammars export `const TSLanguage *tree_sitter_<name>(void)`.
+    let language_fn = unsafe {
+        LanguageFn::from_raw(std::mem::transmute::<
+            *mut libc::c_void,
+            unsafe extern "C" fn() -> *const (),
+        >(address))
+    };
+    let language = tree_sitter::Language::new(language_fn);
+    let version = language.version();
+    if !(MIN_COMPATIBLE_LANGUAGE_VERSION..=LANGUAGE_VERSION).contains(&version) {
+        return Err(anyhow!(
+            "grammar {} uses ABI version {version}; supported versions are {MIN_COMPATIBLE_LANGUAGE_VERSION} to {LANGUAGE_VERSION}",
+            path.display()
+        ));
+    }
+    Ok(language)
+}
+
+#[cfg(not(unix))]
+fn load_grammar(path: &Path, _symbol: &str) -> Result<tree_sitter::Language> {
+    Err(anyhow!(
+        "cannot load grammar {}: runtime grammars are only supported on Unix",
+        path.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::parser::SymbolExtractor;
+    use crate::registry::LanguageRegistry;
+
+    const SYMBOLS: &str = "(function_definition name: (identifier) @name) @symbol.function\n(class_definition name: (identifier) @name) @symbol.class\n";
+    const EDGES: &str = "(call function: (identifier) @target) @edge.calls\n";
+    const TEST_INTENTS: &str = "(function_definition name: (identifier) @test.name (#match? @test.name \"^check_\")) @test\n";
+
+    /// A plugin backed by the bundled Python grammar, standing in for a shared library.
+    fn plugin(test_intents: Option<&str>) -> LanguagePlugin {
+        LanguagePlugin::from_parts(
+            "snake",
+            &[".SNK".to_owned()],
+            tree_sitter_python::LANGUAGE.into(),
+            SYMBOLS.to_owned(),
+            EDGES.to_owned(),
+            test_intents.map(str::to_owned),
+        )
+        .expect("plugin")
+    }
+
+    #[test]
+    fn plugin_queries_extract_symbols_edges_and_test_intents() {
+        let mut registry = LanguageRegistry::new();
+        registry.register(plugin(Some(TEST_INTENTS)).config());
+        let mut extractor = SymbolExtractor::with_registry(registry).expect("extractor");
+
+        let source = "class Cart:\n    pass\n\ndef total():\n    return 1\n\ndef check_total_is_one():\n    total()\n";
+        let extracted = extractor
+            .extract_with_edges_from_path(Path::new("lib/cart.snk"), source)
+            .expect("extract");
+
+        let names = extracted
+            .symbols
+            .iter()
+            .map(|symbol| (symbol.qualified_name.as_str(), symbol.language))
+            .collect::<Vec<_>>();
+        assert!(names.contains(&("cart::Cart", Language::plugin("snake"))));
+        assert!(names.contains(&("cart::total", Language::plugin("snake"))));
+        assert!(
+            extracted
+                .edges
+                .iter()
+                .any(|edge| edge.target_qualified_name == "total")
+        );
+        assert_eq!(extracted.test_intents.len(), 1);
+        assert_eq!(extracted.test_intents[0].test_name, "check_total_is_one");
+        assert!(extracted.test_intents[0].symbol_id.is_some());
+    }
+
+    #[test]
+    fn invalid_query_packs_report_the_failing_file() {
+        let bad_syntax = LanguagePlugin::from_parts(
+            "snake",
+            &["snk".to_owned()],
+            tree_sitter_python::LANGUAGE.into(),
+            "(function_definition @name".to_owned(),
+            EDGES.to_owned(),
+            None,
+        )
+        .expect_err("syntax error");
+        assert!(bad_syntax.to_string().starts_with("symbols.scm:1:"));
+
+        let missing_captures = LanguagePlugin::from_parts(
+            "snake",
+            &["snk".to_owned()],
+            tree_sitter_python::LANGUAGE.into(),
+            SYMBOLS.to_owned(),
+            "(call) @call".to_owned(),
+            None,
+        )
+        .expect_err("missing captures");
+        assert!(missing_captures.to_string().contains("edges.scm"));
+
+        let no_extensions = LanguagePlugin::from_parts(
+            "snake",
+            &[" ".to_owned()],
+            tree_sitter_python::LANGUAGE.into(),
+            SYMBOLS.to_owned(),
+            EDGES.to_owned(),
+            None,
+        )
+        .expect_err("no extensions");
+        assert!(no_extensions.to_string().contains("no file extensions"));
+    }
+
+    #[test]
+    fn missing_grammar_library_is_reported() {
+        let err = LanguagePlugin::load(&LanguagePluginSpec {
+            name: "kotlin".to_owned(),
+            extensions: vec!["kt".to_owned()],
+            grammar_path: PathBuf::from("/nonexistent/libtree-sitter-kotlin.so"),
+            language_symbol: "tree_sitter_kotlin".to_owned(),
+            queries_dir: PathBuf::from("/nonexistent/queries"),
+        })
+        .expect_err("missing grammar");
+        assert!(err.to_string().contains("libtree-sitter-kotlin.so"));
+    }
+}
@@ -1,3 +1,5 @@
 (call) @edge.call
 (import_statement) @edge.depends_on
 (import_from_statement) @edge.depends_on
+(identifier) @edge.references
+(attribute object: (identifier) attribute: (identifier)) @edge.references
@@ -5,3 +5,8 @@
 (impl_item
   trait: (_) @trait
   type: (_) @self_type) @edge.implements
+(macro_invocation) @edge.references
+((identifier) @edge.references
+  (#match? @edge.references "^[A-Z][A-Z0-9_]+$"))
+(scoped_identifier name: (identifier)) @edge.references
+(field_expression value: (self)) @edge.references
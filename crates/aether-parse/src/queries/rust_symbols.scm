@@ -3,3 +3,12 @@
 (trait_item name: (type_identifier) @name) @symbol.trait
 (type_item name: (type_identifier) @name) @symbol.type_alias
 (function_item name: (identifier) @name) @symbol.function
+(source_file (const_item name: (identifier) @name) @symbol.constant)
+(declaration_list (const_item name: (identifier) @name) @symbol.constant)
+(source_file (static_item name: (identifier) @name) @symbol.static)
+(declaration_list (static_item name: (identifier) @name) @symbol.static)
+(source_file (macro_definition name: (identifier) @name) @symbol.macro)
+(declaration_list (macro_definition name: (identifier) @name) @symbol.macro)
+(mod_item name: (identifier) @name body: (declaration_list)) @symbol.module
+(field_declaration name: (field_identifier) @name) @symbol.field
+(enum_variant name: (identifier) @name) @symbol.enum_variant
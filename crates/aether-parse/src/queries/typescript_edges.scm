@@ -1,2 +1,5 @@
 [(call_expression) (new_expression)] @edge.call
 (import_statement source: (string (string_fragment) @source)) @edge.depends_on
+(identifier) @edge.references
+(member_expression object: (identifier) property: (property_identifier)) @edge.references
+(member_expression object: (this) property: [(property_identifier) (private_property_identifier)]) @edge.references
@@ -3,3 +3,12 @@
 (function_declaration name: (identifier) @name) @symbol.function
 (method_definition name: [(property_identifier) (private_property_identifier) (identifier)] @name) @symbol.method
 (type_alias_declaration name: (type_identifier) @name) @symbol.type_alias
+(enum_declaration name: (identifier) @name) @symbol.enum
+(enum_body name: (property_identifier) @name @symbol.enum_variant)
+(enum_assignment name: (property_identifier) @name) @symbol.enum_variant
+(public_field_definition name: [(property_identifier) (private_property_identifier)] @name) @symbol.field
+(interface_body (property_signature name: (property_identifier) @name) @symbol.field)
+(internal_module name: (identifier) @name) @symbol.module
+(module name: (identifier) @name) @symbol.module
+(program (lexical_declaration kind: "const" (variable_declarator name: (identifier) @name) @symbol.const_declaration))
+(program (export_statement declaration: (lexical_declaration kind: "const" (variable_declarator name: (identifier) @name) @symbol.const_declaration)))
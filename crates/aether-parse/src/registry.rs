@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 
 use aether_core::{Language, Symbol, SymbolEdge};
 use tree_sitter::{Node, Query, QueryCapture};
@@ -131,7 +132,7 @@ impl<'q, 'tree> QueryCaptures<'q, 'tree> {
 }
 
 pub struct LanguageRegistry {
-    configs: Vec<LanguageConfig>,
+    configs: Vec<Arc<LanguageConfig>>,
     extension_index: HashMap<String, usize>,
 }
 
@@ -143,7 +144,8 @@ impl LanguageRegistry {
         }
     }
 
-    pub fn register(&mut self, config: LanguageConfig) {
+    pub fn register(&mut self, config: impl Into<Arc<LanguageConfig>>) {
+        let config = config.into();
         let index = self.configs.len();
         for ext in config.extensions {
             let normalized = normalize_extension(ext);
@@ -155,7 +157,7 @@ impl LanguageRegistry {
     pub fn get_by_extension(&self, extension: &str) -> Option<&LanguageConfig> {
         let normalized = normalize_extension(extension);
         let index = *self.extension_index.get(&normalized)?;
-        self.configs.get(index)
+        self.configs.get(index).map(Arc::as_ref)
     }
 
     pub fn get_by_path(&self, path: &Path) -> Option<&LanguageConfig> {
@@ -164,10 +166,13 @@ impl LanguageRegistry {
     }
 
     pub fn get_by_id(&self, id: &str) -> Option<&LanguageConfig> {
-        self.configs.iter().find(|config| config.id == id)
+        self.configs
+            .iter()
+            .find(|config| config.id == id)
+            .map(Arc::as_ref)
     }
 
-    pub fn configs(&self) -> &[LanguageConfig] {
+    pub fn configs(&self) -> &[Arc<LanguageConfig>] {
         &self.configs
     }
 }
@@ -184,6 +189,9 @@ pub fn default_registry() -> LanguageRegistry {
     registry.register(languages::rust::config());
     registry.register(languages::typescript::config());
     registry.register(languages::typescript::tsx_js_config());
+    for plugin in crate::plugins::installed_language_plugins() {
+        registry.register(plugin.config());
+    }
     registry
 }
 
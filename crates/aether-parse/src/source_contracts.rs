@@ -0,0 +1,137 @@
+//! Extraction of `@aether <clause>: <text>` contract annotations from the
+//! comments directly above a symbol.
+
+use std::collections::HashMap;
+
+use aether_core::{Language, Symbol};
+
+const ANNOTATION_TAG: &str = "@aether";
+
+/// Clause types accepted in source annotations. `must-not`/`must not`
+/// spellings normalize to the underscore form.
+const CLAUSE_TYPES: &[&str] = &[
+    "must",
+    "must_not",
+    "preserves",
+    "must_call",
+    "must_not_reach",
+    "must_be_tested",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SourceContract {
+    pub symbol_id: String,
+    pub clause_type: String,
+    pub clause_text: String,
+    /// 1-based line of the annotation
+    pub line: usize,
+}
+
+pub(crate) fn extract_source_contracts(
+    language: Language,
+    source: &str,
+    symbols: &[Symbol],
+) -> Vec<SourceContract> {
+    if !source.contains(ANNOTATION_TAG) {
+        return Vec::new();
+    }
+    let lines = source.lines().collect::<Vec<_>>();
+
+    // The outermost symbol starting on a line owns the comment block above it.
+    let mut owners = HashMap::<usize, &Symbol>::new();
+    for symbol in symbols {
+        let span = |symbol: &Symbol| symbol.range.end.line - symbol.range.start.line;
+        owners
+            .entry(symbol.range.start.line)
+            .and_modify(|owner| {
+                if span(symbol) > span(owner) {
+                    *owner = symbol;
+                }
+            })
+            .or_insert(symbol);
+    }
+
+    let mut contracts = Vec::new();
+    for (start_line, symbol) in owners {
+        let mut row = start_line.saturating_sub(1);
+        while row > 0 {
+            row -= 1;
+            let line = lines.get(row).copied().unwrap_or_default().trim();
+            if is_attribute_line(language, line) {
+                continue;
+            }
+            let Some(comment) = comment_body(language, line) else {
+                break;
+            };
+            if let Some((clause_type, clause_text)) = parse_annotation(comment) {
+                contracts.push(SourceContract {
+                    symbol_id: symbol.id.clone(),
+                    clause_type,
+                    clause_text,
+                    line: row + 1,
+                });
+            }
+        }
+    }
+
+    contracts.sort();
+    contracts.dedup_by(|left, right| {
+        left.symbol_id == right.symbol_id
+            && left.clause_type == right.clause_type
+            && left.clause_text == right.clause_text
+    });
+    contracts
+}
+
+fn is_attribute_line(language: Language, line: &str) -> bool {
+    match language {
+        Language::Rust => line.starts_with("#["),
+        Language::Python
+        | Language::TypeScript
+        | Language::Tsx
+        | Language::JavaScript
+        | Language::Jsx => line.starts_with('@'),
+        Language::Plugin(_) => false,
+    }
+}
+
+/// Text of a comment line with its marker stripped, or `None` for code.
+fn comment_body(language: Language, line: &str) -> Option<&str> {
+    let markers: &[&str] = match language {
+        // `//!` is an inner doc comment for the enclosing module, not this symbol.
+        Language::Rust if line.starts_with("//!") => return None,
+        Language::Rust => &["///", "//"],
+        Language::Python => &["#"],
+        Language::TypeScript | Language::Tsx | Language::JavaScript | Language::Jsx => {
+            &["/**", "/*", "*/", "//", "*"]
+        }
+        Language::Plugin(_) => &["//", "#"],
+    };
+    markers.iter().find_map(|marker| {
+        line.strip_prefix(marker)
+            .map(|rest| rest.trim_end_matches("*/").trim())
+    })
+}
+
+fn parse_annotation(comment: &str) -> Option<(String, String)> {
+    let rest = comment.strip_prefix(ANNOTATION_TAG)?;
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let (clause_type, clause_text) = rest.split_once(':').unwrap_or((rest, ""));
+    let clause_type = clause_type
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("_")
+        .replace('-', "_")
+        .to_ascii_lowercase();
+    if !CLAUSE_TYPES.contains(&clause_type.as_str()) {
+        return None;
+    }
+    let clause_text = clause_text.trim().to_owned();
+    // Only must_be_tested has a meaningful default for an empty clause.
+    if clause_text.is_empty() && clause_type != "must_be_tested" {
+        return None;
+    }
+    Some((clause_type, clause_text))
+}
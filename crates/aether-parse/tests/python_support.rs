@@ -34,7 +34,7 @@ fn extracts_python_symbols_with_expected_kinds_and_qualified_names() {
         .iter()
         .map(|symbol| (symbol.name.as_str(), symbol.kind))
         .collect::<std::collections::HashMap<_, _>>();
-    assert_eq!(by_name.get("MODULE_FLAG"), Some(&SymbolKind::Variable));
+    assert_eq!(by_name.get("MODULE_FLAG"), Some(&SymbolKind::Constant));
     assert_eq!(by_name.get("UserId"), Some(&SymbolKind::TypeAlias));
     assert_eq!(by_name.get("top_level"), Some(&SymbolKind::Function));
     assert_eq!(by_name.get("nested"), Some(&SymbolKind::Function));
@@ -107,3 +107,79 @@ fn init_module_uses_package_name_for_qualified_symbols() {
     assert!(names.contains(&"tests.fixtures.python_package::PACKAGE_VALUE".to_owned()));
     assert!(names.contains(&"tests.fixtures.python_package::bootstrap".to_owned()));
 }
+
+#[test]
+fn extracts_python_constants_fields_enum_members_and_references() {
+    let source = r#"
+from enum import Enum
+from .settings import DEFAULT_TIMEOUT, Limits
+
+MAX_RETRIES = 3
+name = "worker"
+
+
+class Color(Enum):
+    RED = 1
+    GREEN = 2
+
+
+class Worker:
+    retries: int = MAX_RETRIES
+    label = "w"
+
+    def run(self):
+        if self.retries > MAX_RETRIES:
+            return Color.RED
+        return DEFAULT_TIMEOUT + Limits.BURST + self.missing
+"#;
+    let extracted = extract_with_edges("pkg/worker.py", source);
+    let kind_of = |qualified_name: &str| {
+        extracted
+            .symbols
+            .iter()
+            .find(|symbol| symbol.qualified_name == qualified_name)
+            .map(|symbol| symbol.kind)
+    };
+
+    assert_eq!(
+        kind_of("pkg.worker::MAX_RETRIES"),
+        Some(SymbolKind::Constant)
+    );
+    assert_eq!(kind_of("pkg.worker::name"), None);
+    assert_eq!(
+        kind_of("pkg.worker::Color::RED"),
+        Some(SymbolKind::EnumVariant)
+    );
+    assert_eq!(
+        kind_of("pkg.worker::Worker::retries"),
+        Some(SymbolKind::Field)
+    );
+    assert_eq!(
+        kind_of("pkg.worker::Worker::label"),
+        Some(SymbolKind::Field)
+    );
+
+    let run_id = extracted
+        .symbols
+        .iter()
+        .find(|symbol| symbol.qualified_name == "pkg.worker::Worker::run")
+        .map(|symbol| symbol.id.clone())
+        .expect("run method");
+    let mut references = extracted
+        .edges
+        .iter()
+        .filter(|edge| edge.edge_kind == EdgeKind::References && edge.source_id == run_id)
+        .map(|edge| edge.target_qualified_name.clone())
+        .collect::<Vec<_>>();
+    references.sort();
+    assert_eq!(
+        references,
+        vec![
+            "pkg.settings::DEFAULT_TIMEOUT".to_owned(),
+            "pkg.settings::Limits::BURST".to_owned(),
+            "pkg.worker::Color::RED".to_owned(),
+            "pkg.worker::MAX_RETRIES".to_owned(),
+            "pkg.worker::Worker::retries".to_owned(),
+        ]
+    );
+}
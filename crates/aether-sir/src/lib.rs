@@ -12,6 +12,82 @@ pub enum SirLevel {
     Module,
 }
 
+/// Value type of a workspace-declared SIR extension field.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SirExtensionKind {
+    String,
+    StringList,
+    Boolean,
+    Number,
+}
+
+impl SirExtensionKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::StringList => "string_list",
+            Self::Boolean => "boolean",
+            Self::Number => "number",
+        }
+    }
+
+    /// JSON shape description used in prompts and validation errors.
+    pub fn json_shape(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::StringList => "array of string",
+            Self::Boolean => "boolean",
+            Self::Number => "number",
+        }
+    }
+
+    fn accepts(self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::StringList => value
+                .as_array()
+                .is_some_and(|items| items.iter().all(Value::is_string)),
+            Self::Boolean => value.is_boolean(),
+            Self::Number => value.is_number(),
+        }
+    }
+}
+
+impl std::fmt::Display for SirExtensionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Extra structured SIR field declared in workspace configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SirExtensionField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: SirExtensionKind,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Built-in SIR keys that extension fields may not shadow.
+pub const CORE_SIR_FIELDS: &[&str] = &[
+    "intent",
+    "behavior",
+    "inputs",
+    "outputs",
+    "side_effects",
+    "dependencies",
+    "error_modes",
+    "confidence",
+    "edge_cases",
+    "complexity",
+    "method_dependencies",
+    "extensions",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SirAnnotation {
     pub intent: String,
@@ -30,6 +106,9 @@ pub struct SirAnnotation {
     /// Per-method dependency map for traits/structs. None for functions.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub method_dependencies: Option<HashMap<String, Vec<String>>>,
+    /// Workspace-declared extension fields keyed by field name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extensions: BTreeMap<String, Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -51,6 +130,21 @@ pub enum SirError {
     InvalidConfidence,
     #[error("complexity must be one of: Low, Medium, High, Critical, Unknown")]
     InvalidComplexity,
+    #[error(
+        "extension field name '{0}' must be a lowercase identifier that does not shadow a core field"
+    )]
+    InvalidExtensionName(String),
+    #[error("extension field '{0}' must be a string, number, boolean, or array of strings")]
+    InvalidExtensionValue(String),
+    #[error("extension field '{0}' is not declared in the workspace SIR schema")]
+    UndeclaredExtension(String),
+    #[error("required extension field '{0}' is missing")]
+    MissingExtension(String),
+    #[error("extension field '{name}' must be {}", expected.json_shape())]
+    ExtensionTypeMismatch {
+        name: String,
+        expected: SirExtensionKind,
+    },
 }
 
 pub fn validate_sir(sir: &SirAnnotation) -> Result<(), SirError> {
@@ -66,9 +160,115 @@ pub fn validate_sir(sir: &SirAnnotation) -> Result<(), SirError> {
         return Err(SirError::InvalidComplexity);
     }
 
+    for (name, value) in &sir.extensions {
+        if !is_valid_extension_name(name) {
+            return Err(SirError::InvalidExtensionName(name.clone()));
+        }
+        if !is_valid_extension_value(value) {
+            return Err(SirError::InvalidExtensionValue(name.clone()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates `sir.extensions` against the declared workspace schema.
+///
+/// Every present key must be declared with a matching type, and every
+/// `required` field must be present.
+pub fn validate_sir_extensions(
+    sir: &SirAnnotation,
+    fields: &[SirExtensionField],
+) -> Result<(), SirError> {
+    for (name, value) in &sir.extensions {
+        let Some(field) = fields.iter().find(|field| field.name == *name) else {
+            return Err(SirError::UndeclaredExtension(name.clone()));
+        };
+        if !field.kind.accepts(value) {
+            return Err(SirError::ExtensionTypeMismatch {
+                name: name.clone(),
+                expected: field.kind,
+            });
+        }
+    }
+
+    for field in fields.iter().filter(|field| field.required) {
+        if !sir.extensions.contains_key(field.name.as_str()) {
+            return Err(SirError::MissingExtension(field.name.clone()));
+        }
+    }
+
     Ok(())
 }
 
+/// Drops extension keys that are not part of the declared schema.
+///
+/// Models occasionally invent extra keys; they are discarded rather than
+/// failing the whole annotation.
+pub fn retain_declared_extensions(sir: &mut SirAnnotation, fields: &[SirExtensionField]) {
+    sir.extensions
+        .retain(|name, _| fields.iter().any(|field| field.name == *name));
+}
+
+pub fn is_valid_extension_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    first.is_ascii_lowercase()
+        && chars.all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_')
+        && !CORE_SIR_FIELDS.contains(&name)
+}
+
+fn is_valid_extension_value(value: &Value) -> bool {
+    match value {
+        Value::String(_) | Value::Number(_) | Value::Bool(_) => true,
+        Value::Array(items) => items.iter().all(Value::is_string),
+        Value::Null | Value::Object(_) => false,
+    }
+}
+
+/// Returns true when the extension field `name` matches `expected`.
+///
+/// Strings compare case-insensitively, string lists match when any element
+/// does, and booleans/numbers compare by parsed value.
+pub fn sir_extension_matches(sir: &SirAnnotation, name: &str, expected: &str) -> bool {
+    let expected = expected.trim();
+    match sir.extensions.get(name) {
+        Some(Value::String(value)) => value.trim().eq_ignore_ascii_case(expected),
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|item| item.trim().eq_ignore_ascii_case(expected)),
+        Some(Value::Bool(value)) => expected
+            .to_ascii_lowercase()
+            .parse::<bool>()
+            .is_ok_and(|parsed| parsed == *value),
+        Some(Value::Number(value)) => match (value.as_f64(), expected.parse::<f64>()) {
+            (Some(actual), Ok(parsed)) => (actual - parsed).abs() < f64::EPSILON,
+            _ => false,
+        },
+        Some(Value::Null) | Some(Value::Object(_)) | None => false,
+    }
+}
+
+/// Renders an extension value as a single display line.
+pub fn format_extension_value(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| {
+                item.as_str()
+                    .map(ToOwned::to_owned)
+                    .unwrap_or_else(|| item.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
 pub fn canonicalize_sir_json(sir: &SirAnnotation) -> String {
     let behavior = normalize_optional_text(sir.behavior.as_deref());
     let mut inputs = sir.inputs.clone();
@@ -98,6 +298,14 @@ pub fn canonicalize_sir_json(sir: &SirAnnotation) -> String {
         canonical.insert("edge_cases", Value::from(edge_cases));
     }
     canonical.insert("error_modes", Value::from(error_modes));
+    if !sir.extensions.is_empty() {
+        let canonical_extensions = sir
+            .extensions
+            .iter()
+            .map(|(name, value)| (name.clone(), canonicalize_extension_value(value)))
+            .collect::<serde_json::Map<_, _>>();
+        canonical.insert("extensions", Value::Object(canonical_extensions));
+    }
     canonical.insert("inputs", Value::from(inputs));
     canonical.insert("intent", Value::from(sir.intent.clone()));
     if let Some(method_dependencies) = &sir.method_dependencies {
@@ -121,6 +329,24 @@ pub fn canonicalize_sir_json(sir: &SirAnnotation) -> String {
     serde_json::to_string(&canonical).expect("canonical sir serialization cannot fail")
 }
 
+fn canonicalize_extension_value(value: &Value) -> Value {
+    match value {
+        Value::String(text) => Value::from(text.trim()),
+        Value::Array(items) => {
+            let mut values = items
+                .iter()
+                .map(|item| match item {
+                    Value::String(text) => Value::from(text.trim()),
+                    other => other.clone(),
+                })
+                .collect::<Vec<_>>();
+            values.sort_by_key(|item| item.to_string());
+            Value::Array(values)
+        }
+        other => other.clone(),
+    }
+}
+
 pub fn sir_hash(sir: &SirAnnotation) -> String {
     let canonical = canonicalize_sir_json(sir);
     blake3::hash(canonical.as_bytes()).to_hex().to_string()
@@ -217,6 +443,7 @@ mod tests {
             edge_cases: None,
             complexity: None,
             method_dependencies: None,
+            extensions: BTreeMap::new(),
         }
     }
 
@@ -263,6 +490,7 @@ mod tests {
             edge_cases: None,
             complexity: None,
             method_dependencies: None,
+            extensions: BTreeMap::new(),
         };
 
         let canonical_a = canonicalize_sir_json(&sir_a);
@@ -369,6 +597,142 @@ mod tests {
         );
     }
 
+    fn sample_extension_fields() -> Vec<SirExtensionField> {
+        vec![
+            SirExtensionField {
+                name: "auth_requirements".to_owned(),
+                kind: SirExtensionKind::StringList,
+                description: "Authentication checks the caller must satisfy".to_owned(),
+                required: false,
+            },
+            SirExtensionField {
+                name: "pii_handled".to_owned(),
+                kind: SirExtensionKind::Boolean,
+                description: "Whether personal data flows through this symbol".to_owned(),
+                required: true,
+            },
+        ]
+    }
+
+    #[test]
+    fn extensions_are_omitted_from_canonical_json_when_empty() {
+        let sir = sample_sir();
+
+        assert!(!canonicalize_sir_json(&sir).contains("extensions"));
+        assert!(
+            !serde_json::to_string(&sir)
+                .expect("serialize")
+                .contains("extensions")
+        );
+    }
+
+    #[test]
+    fn extension_canonicalization_is_stable_for_list_reordering() {
+        let mut sir_a = sample_sir();
+        sir_a.extensions.insert(
+            "auth_requirements".to_owned(),
+            serde_json::json!(["session", " admin "]),
+        );
+        sir_a
+            .extensions
+            .insert("pii_handled".to_owned(), Value::Bool(true));
+
+        let mut sir_b = sample_sir();
+        sir_b
+            .extensions
+            .insert("pii_handled".to_owned(), Value::Bool(true));
+        sir_b.extensions.insert(
+            "auth_requirements".to_owned(),
+            serde_json::json!(["admin", "session"]),
+        );
+
+        assert_eq!(sir_hash(&sir_a), sir_hash(&sir_b));
+        assert!(canonicalize_sir_json(&sir_a).contains(
+            "\"extensions\":{\"auth_requirements\":[\"admin\",\"session\"],\"pii_handled\":true}"
+        ));
+        assert_ne!(sir_hash(&sir_a), sir_hash(&sample_sir()));
+    }
+
+    #[test]
+    fn validate_sir_rejects_extensions_shadowing_core_fields() {
+        let mut sir = sample_sir();
+        sir.extensions
+            .insert("intent".to_owned(), Value::from("override"));
+
+        let err = validate_sir(&sir).expect_err("expected invalid extension name");
+        assert_eq!(err, SirError::InvalidExtensionName("intent".to_owned()));
+    }
+
+    #[test]
+    fn validate_sir_rejects_nested_extension_values() {
+        let mut sir = sample_sir();
+        sir.extensions
+            .insert("pii_handled".to_owned(), serde_json::json!({"yes": true}));
+
+        let err = validate_sir(&sir).expect_err("expected invalid extension value");
+        assert_eq!(
+            err,
+            SirError::InvalidExtensionValue("pii_handled".to_owned())
+        );
+    }
+
+    #[test]
+    fn validate_sir_extensions_checks_declared_types_and_required_fields() {
+        let fields = sample_extension_fields();
+        let mut sir = sample_sir();
+
+        assert_eq!(
+            validate_sir_extensions(&sir, &fields),
+            Err(SirError::MissingExtension("pii_handled".to_owned()))
+        );
+
+        sir.extensions
+            .insert("pii_handled".to_owned(), Value::from("yes"));
+        assert_eq!(
+            validate_sir_extensions(&sir, &fields),
+            Err(SirError::ExtensionTypeMismatch {
+                name: "pii_handled".to_owned(),
+                expected: SirExtensionKind::Boolean,
+            })
+        );
+
+        sir.extensions
+            .insert("pii_handled".to_owned(), Value::Bool(false));
+        sir.extensions
+            .insert("owner_team".to_owned(), Value::from("payments"));
+        assert_eq!(
+            validate_sir_extensions(&sir, &fields),
+            Err(SirError::UndeclaredExtension("owner_team".to_owned()))
+        );
+
+        retain_declared_extensions(&mut sir, &fields);
+        assert_eq!(validate_sir_extensions(&sir, &fields), Ok(()));
+    }
+
+    #[test]
+    fn sir_extension_matches_compares_by_value_type() {
+        let mut sir = sample_sir();
+        sir.extensions.insert(
+            "auth_requirements".to_owned(),
+            serde_json::json!(["Session", "admin"]),
+        );
+        sir.extensions
+            .insert("pii_handled".to_owned(), Value::Bool(true));
+        sir.extensions
+            .insert("max_retries".to_owned(), Value::from(3));
+
+        assert!(sir_extension_matches(&sir, "auth_requirements", "session"));
+        assert!(!sir_extension_matches(&sir, "auth_requirements", "mfa"));
+        assert!(sir_extension_matches(&sir, "pii_handled", "TRUE"));
+        assert!(!sir_extension_matches(&sir, "pii_handled", "false"));
+        assert!(sir_extension_matches(&sir, "max_retries", "3"));
+        assert!(!sir_extension_matches(&sir, "missing", "3"));
+        assert_eq!(
+            format_extension_value(&sir.extensions["auth_requirements"]),
+            "Session, admin"
+        );
+    }
+
     #[test]
     fn file_canonicalization_is_stable_for_list_reordering() {
         let file_a = FileSir {
@@ -1,4 +1,4 @@
-use rusqlite::{OptionalExtension, params};
+use rusqlite::{OptionalExtension, Transaction, TransactionBehavior, params};
 
 use crate::{SqliteStore, StoreError, time::current_unix_timestamp_secs};
 
@@ -30,6 +30,47 @@ pub struct IntentViolationRecord {
     pub dismissed_reason: Option<String>,
 }
 
+/// `created_by` of clauses declared in `@aether` source annotations. These are
+/// owned by the indexer and reconciled from source on every index.
+pub const SOURCE_CONTRACT_CREATOR: &str = "source";
+
+/// `created_by` prefix of clauses materialized from a contract policy; the
+/// policy name follows the prefix.
+pub const POLICY_CONTRACT_CREATOR_PREFIX: &str = "policy:";
+
+impl IntentContractRecord {
+    /// Name of the policy this clause was materialized from.
+    pub fn policy_name(&self) -> Option<&str> {
+        self.created_by.strip_prefix(POLICY_CONTRACT_CREATOR_PREFIX)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DeclaredContract {
+    pub symbol_id: String,
+    pub clause_type: String,
+    pub clause_text: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SourceContractSync {
+    pub added: usize,
+    pub deactivated: usize,
+}
+
+/// Active clauses and open violations of every contract sharing a `created_by`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractCreatorSummary {
+    pub created_by: String,
+    pub symbol_count: usize,
+    pub clause_count: usize,
+    /// Clauses whose latest verification failed.
+    pub failing_clause_count: usize,
+    pub open_violation_count: usize,
+    /// Symbols with undismissed violations, most violations first.
+    pub violating_symbols: Vec<(String, usize)>,
+}
+
 fn unix_now() -> i64 {
     current_unix_timestamp_secs()
 }
@@ -235,6 +276,192 @@ impl SqliteStore {
         Ok(count)
     }
 
+    /// Reconcile the clauses `created_by` owns for `symbol_ids` with
+    /// `declared`: new clauses are inserted, clauses no longer declared are
+    /// deactivated and unchanged clauses keep their streak. Clauses from any
+    /// other creator are left alone.
+    pub fn sync_declared_contracts(
+        &self,
+        created_by: &str,
+        symbol_ids: &[String],
+        declared: &[DeclaredContract],
+    ) -> Result<SourceContractSync, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let tx = Transaction::new_unchecked(&conn, TransactionBehavior::Immediate)?;
+        let mut sync = SourceContractSync::default();
+        let now = unix_now();
+
+        {
+            let mut select = tx.prepare(
+                r#"
+                SELECT id, clause_type, clause_text
+                FROM intent_contracts
+                WHERE symbol_id = ?1 AND created_by = ?2 AND active = 1
+                "#,
+            )?;
+            let mut deactivate =
+                tx.prepare("UPDATE intent_contracts SET active = 0 WHERE id = ?1")?;
+            let mut insert = tx.prepare(
+                r#"
+                INSERT INTO intent_contracts
+                    (symbol_id, clause_type, clause_text, clause_embedding_json,
+                     created_at, created_by, active, violation_streak)
+                VALUES (?1, ?2, ?3, NULL, ?4, ?5, 1, 0)
+                "#,
+            )?;
+
+            let mut symbol_ids = symbol_ids
+                .iter()
+                .map(String::as_str)
+                .chain(declared.iter().map(|clause| clause.symbol_id.as_str()))
+                .collect::<Vec<_>>();
+            symbol_ids.sort_unstable();
+            symbol_ids.dedup();
+
+            for symbol_id in symbol_ids {
+                let mut wanted = declared
+                    .iter()
+                    .filter(|clause| clause.symbol_id == symbol_id)
+                    .map(|clause| (clause.clause_type.as_str(), clause.clause_text.as_str()))
+                    .collect::<Vec<_>>();
+                wanted.sort_unstable();
+                wanted.dedup();
+
+                let existing = select
+                    .query_map(params![symbol_id, created_by], |row| {
+                        Ok((
+                            row.get::<_, i64>(0)?,
+                            row.get::<_, String>(1)?,
+                            row.get::<_, String>(2)?,
+                        ))
+                    })?
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                for (id, clause_type, clause_text) in &existing {
+                    let key = (clause_type.as_str(), clause_text.as_str());
+                    match wanted.binary_search(&key) {
+                        Ok(index) => {
+                            wanted.remove(index);
+                        }
+                        Err(_) => {
+                            deactivate.execute(params![id])?;
+                            sync.deactivated += 1;
+                        }
+                    }
+                }
+
+                for (clause_type, clause_text) in wanted {
+                    insert.execute(params![
+                        symbol_id,
+                        clause_type,
+                        clause_text,
+                        now,
+                        created_by,
+                    ])?;
+                    sync.added += 1;
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(sync)
+    }
+
+    pub fn sync_source_contracts(
+        &self,
+        symbol_ids: &[String],
+        declared: &[DeclaredContract],
+    ) -> Result<SourceContractSync, StoreError> {
+        self.sync_declared_contracts(SOURCE_CONTRACT_CREATOR, symbol_ids, declared)
+    }
+
+    /// Distinct `created_by` values of active clauses starting with `prefix`.
+    pub fn list_active_contract_creators(&self, prefix: &str) -> Result<Vec<String>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT DISTINCT created_by
+            FROM intent_contracts
+            WHERE active = 1 AND substr(created_by, 1, length(?1)) = ?1
+            ORDER BY created_by
+            "#,
+        )?;
+        let rows = stmt.query_map(params![prefix], |row| row.get::<_, String>(0))?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
+    pub fn deactivate_contracts_created_by(&self, created_by: &str) -> Result<usize, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let changed = conn.execute(
+            "UPDATE intent_contracts SET active = 0 WHERE created_by = ?1 AND active = 1",
+            params![created_by],
+        )?;
+        Ok(changed)
+    }
+
+    /// Per-creator rollup of active clauses whose `created_by` starts with
+    /// `prefix`, ordered by creator.
+    pub fn summarize_contracts_by_creator(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<ContractCreatorSummary>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT created_by,
+                   COUNT(DISTINCT symbol_id),
+                   COUNT(*),
+                   SUM(CASE WHEN violation_streak > 0 THEN 1 ELSE 0 END)
+            FROM intent_contracts
+            WHERE active = 1 AND substr(created_by, 1, length(?1)) = ?1
+            GROUP BY created_by
+            ORDER BY created_by
+            "#,
+        )?;
+        let mut summaries = stmt
+            .query_map(params![prefix], |row| {
+                Ok(ContractCreatorSummary {
+                    created_by: row.get(0)?,
+                    symbol_count: row.get::<_, i64>(1)?.max(0) as usize,
+                    clause_count: row.get::<_, i64>(2)?.max(0) as usize,
+                    failing_clause_count: row.get::<_, i64>(3)?.max(0) as usize,
+                    open_violation_count: 0,
+                    violating_symbols: Vec::new(),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT c.created_by, v.symbol_id, COUNT(*) AS violations
+            FROM intent_violations v
+            JOIN intent_contracts c ON c.id = v.contract_id
+            WHERE c.active = 1 AND v.dismissed = 0
+              AND substr(c.created_by, 1, length(?1)) = ?1
+            GROUP BY c.created_by, v.symbol_id
+            ORDER BY violations DESC, v.symbol_id
+            "#,
+        )?;
+        let rows = stmt.query_map(params![prefix], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?.max(0) as usize,
+            ))
+        })?;
+        for row in rows {
+            let (created_by, symbol_id, violations) = row?;
+            if let Some(summary) = summaries
+                .iter_mut()
+                .find(|summary| summary.created_by == created_by)
+            {
+                summary.open_violation_count += violations;
+                summary.violating_symbols.push((symbol_id, violations));
+            }
+        }
+        Ok(summaries)
+    }
+
     pub fn get_intent_contract(
         &self,
         contract_id: i64,
@@ -454,6 +681,145 @@ mod tests {
         assert_eq!(all[1].symbol_id, "sym_b");
     }
 
+    #[test]
+    fn sync_source_contracts_adds_keeps_and_deactivates() {
+        let (store, _dir) = open_test_store();
+        let declared = |clause_type: &str, clause_text: &str| DeclaredContract {
+            symbol_id: "sym_a".to_owned(),
+            clause_type: clause_type.to_owned(),
+            clause_text: clause_text.to_owned(),
+        };
+        let human = store
+            .insert_intent_contract("sym_a", "must", "log every refund", None, "human")
+            .unwrap();
+
+        let sync = store
+            .sync_source_contracts(
+                &["sym_a".to_owned()],
+                &[
+                    declared("must", "reject zero amounts"),
+                    declared("must_not", "modify the balance"),
+                ],
+            )
+            .unwrap();
+        assert_eq!(
+            sync,
+            SourceContractSync {
+                added: 2,
+                deactivated: 0
+            }
+        );
+        let kept = store
+            .list_active_contracts_for_symbol("sym_a")
+            .unwrap()
+            .into_iter()
+            .find(|contract| contract.clause_text == "reject zero amounts")
+            .unwrap();
+        assert_eq!(kept.created_by, SOURCE_CONTRACT_CREATOR);
+        store.update_contract_streak(kept.id, 1).unwrap();
+
+        let sync = store
+            .sync_source_contracts(
+                &["sym_a".to_owned()],
+                &[
+                    declared("must", "reject zero amounts"),
+                    declared("must_not", "modify the account balance"),
+                ],
+            )
+            .unwrap();
+        assert_eq!(
+            sync,
+            SourceContractSync {
+                added: 1,
+                deactivated: 1
+            }
+        );
+        assert_eq!(
+            store
+                .get_intent_contract(kept.id)
+                .unwrap()
+                .unwrap()
+                .violation_streak,
+            1
+        );
+
+        let sync = store
+            .sync_source_contracts(&["sym_a".to_owned()], &[])
+            .unwrap();
+        assert_eq!(
+            sync,
+            SourceContractSync {
+                added: 0,
+                deactivated: 2
+            }
+        );
+        let active = store.list_active_contracts_for_symbol("sym_a").unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, human);
+    }
+
+    #[test]
+    fn summarize_contracts_by_creator_rolls_up_policy_clauses() {
+        let (store, _dir) = open_test_store();
+        let creator = format!("{POLICY_CONTRACT_CREATOR_PREFIX}audit");
+        let declared = |symbol_id: &str| DeclaredContract {
+            symbol_id: symbol_id.to_owned(),
+            clause_type: "must".to_owned(),
+            clause_text: "write an audit record".to_owned(),
+        };
+        let scope = ["sym_a".to_owned(), "sym_b".to_owned()];
+        store
+            .sync_declared_contracts(&creator, &scope, &[declared("sym_a"), declared("sym_b")])
+            .unwrap();
+        store
+            .insert_intent_contract("sym_c", "must", "unrelated", None, "human")
+            .unwrap();
+
+        let failing = store.list_active_contracts_for_symbol("sym_b").unwrap()[0].clone();
+        assert_eq!(failing.policy_name(), Some("audit"));
+        store.update_contract_streak(failing.id, 2).unwrap();
+        for version in 1..=2 {
+            store
+                .insert_intent_violation(failing.id, "sym_b", version, "judge_fail", None, None)
+                .unwrap();
+        }
+        let dismissed = store
+            .insert_intent_violation(failing.id, "sym_b", 3, "judge_fail", None, None)
+            .unwrap();
+        store
+            .dismiss_violation(dismissed, "false positive")
+            .unwrap();
+
+        let summaries = store
+            .summarize_contracts_by_creator(POLICY_CONTRACT_CREATOR_PREFIX)
+            .unwrap();
+        assert_eq!(
+            summaries,
+            vec![ContractCreatorSummary {
+                created_by: creator.clone(),
+                symbol_count: 2,
+                clause_count: 2,
+                failing_clause_count: 1,
+                open_violation_count: 2,
+                violating_symbols: vec![("sym_b".to_owned(), 2)],
+            }]
+        );
+        assert_eq!(
+            store
+                .list_active_contract_creators(POLICY_CONTRACT_CREATOR_PREFIX)
+                .unwrap(),
+            vec![creator.clone()]
+        );
+
+        assert_eq!(store.deactivate_contracts_created_by(&creator).unwrap(), 2);
+        assert!(
+            store
+                .summarize_contracts_by_creator(POLICY_CONTRACT_CREATOR_PREFIX)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn list_recent_violations_returns_ordered_by_detected_at_desc() {
         let (store, _dir) = open_test_store();
@@ -0,0 +1,160 @@
+use aether_core::{EndpointCall, EndpointRoute};
+
+use super::*;
+
+impl SqliteStore {
+    /// Replaces the route declarations and client call sites recorded for a file.
+    pub fn replace_endpoints_for_file(
+        &self,
+        file_path: &str,
+        routes: &[EndpointRoute],
+        calls: &[EndpointCall],
+    ) -> Result<(), StoreError> {
+        let file_path = normalize_path(file_path.trim());
+        if file_path.is_empty() {
+            return Ok(());
+        }
+
+        let conn = self.conn.lock().unwrap();
+        let tx = Transaction::new_unchecked(&conn, TransactionBehavior::Immediate)?;
+        tx.execute(
+            "DELETE FROM endpoint_routes WHERE file_path = ?1",
+            params![file_path],
+        )?;
+        tx.execute(
+            "DELETE FROM endpoint_calls WHERE file_path = ?1",
+            params![file_path],
+        )?;
+        {
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT OR IGNORE INTO endpoint_routes (file_path, method, path, handler)
+                VALUES (?1, ?2, ?3, ?4)
+                "#,
+            )?;
+            for route in routes {
+                stmt.execute(params![file_path, route.method, route.path, route.handler])?;
+            }
+
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT OR IGNORE INTO endpoint_calls (file_path, source_id, method, path)
+                VALUES (?1, ?2, ?3, ?4)
+                "#,
+            )?;
+            for call in calls {
+                stmt.execute(params![file_path, call.source_id, call.method, call.path])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub fn list_endpoint_routes(&self) -> Result<Vec<EndpointRoute>, StoreError> {
+        self.query_endpoint_routes(None)
+    }
+
+    pub fn list_endpoint_routes_for_file(
+        &self,
+        file_path: &str,
+    ) -> Result<Vec<EndpointRoute>, StoreError> {
+        self.query_endpoint_routes(Some(normalize_path(file_path.trim()).as_str()))
+    }
+
+    fn query_endpoint_routes(
+        &self,
+        file_path: Option<&str>,
+    ) -> Result<Vec<EndpointRoute>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT file_path, method, path, handler
+            FROM endpoint_routes
+            WHERE ?1 IS NULL OR file_path = ?1
+            ORDER BY file_path ASC, method ASC, path ASC, handler ASC
+            "#,
+        )?;
+        let rows = stmt.query_map(params![file_path], |row| {
+            Ok(EndpointRoute {
+                file_path: row.get(0)?,
+                method: row.get(1)?,
+                path: row.get(2)?,
+                handler: row.get(3)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
+    pub fn list_endpoint_calls(&self) -> Result<Vec<EndpointCall>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT file_path, source_id, method, path
+            FROM endpoint_calls
+            ORDER BY file_path ASC, source_id ASC, method ASC, path ASC
+            "#,
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(EndpointCall {
+                file_path: row.get(0)?,
+                source_id: row.get(1)?,
+                method: row.get(2)?,
+                path: row.get(3)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
+    /// Replaces the `endpoint` edges owned by a client file, leaving its other edges intact.
+    pub fn replace_endpoint_edges_for_file(
+        &self,
+        file_path: &str,
+        edges: &[SymbolEdge],
+    ) -> Result<(), StoreError> {
+        let file_path = normalize_path(file_path.trim());
+        if file_path.is_empty() {
+            return Ok(());
+        }
+
+        self.conn.lock().unwrap().execute(
+            "DELETE FROM symbol_edges WHERE file_path = ?1 AND edge_kind = ?2",
+            params![file_path, EdgeKind::Endpoint.as_str()],
+        )?;
+        let edges = edges
+            .iter()
+            .filter(|edge| edge.edge_kind == EdgeKind::Endpoint && edge.file_path == file_path)
+            .cloned()
+            .collect::<Vec<_>>();
+        self.store_upsert_edges(&edges)
+    }
+
+    /// Files joined to `file_path` by `endpoint` edges in either direction:
+    /// route files its clients call and client files calling its handlers.
+    pub fn list_endpoint_linked_files(&self, file_path: &str) -> Result<Vec<String>, StoreError> {
+        let file_path = normalize_path(file_path.trim());
+        if file_path.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT DISTINCT linked FROM (
+                SELECT target.file_path AS linked
+                FROM symbol_edges e
+                JOIN symbols target ON target.qualified_name = e.target_qualified_name
+                WHERE e.edge_kind = 'endpoint' AND e.file_path = ?1
+                UNION
+                SELECT e.file_path AS linked
+                FROM symbol_edges e
+                JOIN symbols target ON target.qualified_name = e.target_qualified_name
+                WHERE e.edge_kind = 'endpoint' AND target.file_path = ?1
+            )
+            WHERE linked <> ?1
+            ORDER BY linked ASC
+            "#,
+        )?;
+        let rows = stmt.query_map(params![file_path], |row| row.get::<_, String>(0))?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+}
@@ -41,6 +41,19 @@ pub struct GraphDependencyEdgeRecord {
     pub edge_kind: String,
 }
 
+/// A symbol edge with both endpoints' names and files; the target side is
+/// empty when `target_qualified_name` does not resolve to an indexed symbol.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocatedEdgeRecord {
+    pub source_id: String,
+    pub source_qualified_name: Option<String>,
+    pub source_file: String,
+    pub target_qualified_name: String,
+    pub target_id: Option<String>,
+    pub target_file: Option<String>,
+    pub edge_kind: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SymbolNeighborRecord {
     pub symbol_id: String,
@@ -56,8 +69,14 @@ pub struct GraphSyncStats {
     pub unresolved_edges: usize,
 }
 
-pub(crate) const STRUCTURAL_EDGE_KINDS: &[&str] =
-    &["calls", "depends_on", "type_ref", "implements"];
+pub(crate) const STRUCTURAL_EDGE_KINDS: &[&str] = &[
+    "calls",
+    "depends_on",
+    "type_ref",
+    "implements",
+    "endpoint",
+    "references",
+];
 
 pub(crate) fn edge_kind_from_str(value: &str) -> Option<EdgeKind> {
     match value {
@@ -65,6 +84,8 @@ pub(crate) fn edge_kind_from_str(value: &str) -> Option<EdgeKind> {
         "depends_on" => Some(EdgeKind::DependsOn),
         "type_ref" => Some(EdgeKind::TypeRef),
         "implements" => Some(EdgeKind::Implements),
+        "endpoint" => Some(EdgeKind::Endpoint),
+        "references" => Some(EdgeKind::References),
         _ => None,
     }
 }
@@ -180,7 +201,7 @@ impl SqliteStore {
             FROM symbol_edges e
             JOIN symbols target
               ON target.qualified_name = e.target_qualified_name
-            WHERE e.edge_kind IN ('calls', 'depends_on', 'type_ref', 'implements')
+            WHERE e.edge_kind IN ('calls', 'depends_on', 'type_ref', 'implements', 'endpoint', 'references')
             ORDER BY e.source_id ASC, target.id ASC, e.edge_kind ASC
             "#,
         )?;
@@ -194,6 +215,53 @@ impl SqliteStore {
         rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
     }
 
+    pub fn list_located_edges(
+        &self,
+        edge_kinds: &[EdgeKind],
+    ) -> Result<Vec<LocatedEdgeRecord>, StoreError> {
+        if edge_kinds.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = (0..edge_kinds.len())
+            .map(|index| format!("?{}", index + 1))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = format!(
+            r#"
+            SELECT e.source_id, source.qualified_name, e.file_path, e.target_qualified_name,
+                   target.id, target.file_path, e.edge_kind
+            FROM symbol_edges e
+            LEFT JOIN symbols source
+              ON source.id = e.source_id
+            LEFT JOIN symbols target
+              ON target.qualified_name = e.target_qualified_name
+            WHERE e.edge_kind IN ({placeholders})
+            ORDER BY e.file_path ASC, e.source_id ASC, e.target_qualified_name ASC,
+                     e.edge_kind ASC, target.id ASC
+            "#
+        );
+        let params = edge_kinds
+            .iter()
+            .map(|kind| kind.as_str().to_owned())
+            .collect::<Vec<_>>();
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(params), |row| {
+            Ok(LocatedEdgeRecord {
+                source_id: row.get(0)?,
+                source_qualified_name: row.get(1)?,
+                source_file: row.get(2)?,
+                target_qualified_name: row.get(3)?,
+                target_id: row.get(4)?,
+                target_file: row.get(5)?,
+                edge_kind: row.get(6)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
     pub fn list_symbol_edges_for_source_and_kinds(
         &self,
         source_id: &str,
@@ -282,7 +350,7 @@ impl SqliteStore {
                 JOIN symbols source ON source.id = e.source_id
                 LEFT JOIN symbols target ON target.qualified_name = e.target_qualified_name
                 WHERE e.file_path = ?1
-                  AND e.edge_kind IN ('calls', 'type_ref', 'implements')
+                  AND e.edge_kind IN ('calls', 'type_ref', 'implements', 'endpoint', 'references')
                   AND target.id IS NULL
                 ORDER BY e.source_id ASC, e.target_qualified_name ASC, e.edge_kind ASC
                 "#,
@@ -313,7 +381,7 @@ impl SqliteStore {
                 JOIN symbols source ON source.id = e.source_id
                 JOIN symbols target ON target.qualified_name = e.target_qualified_name
                 WHERE e.file_path = ?1
-                  AND e.edge_kind IN ('calls', 'type_ref', 'implements')
+                  AND e.edge_kind IN ('calls', 'type_ref', 'implements', 'endpoint', 'references')
                 ORDER BY e.source_id ASC, target.id ASC, e.edge_kind ASC
                 "#,
             )?;
@@ -442,6 +510,8 @@ impl SqliteStore {
                     WHEN 'depends_on' THEN 'depended_on_by'
                     WHEN 'implements' THEN 'implemented_by'
                     WHEN 'type_ref' THEN 'type_ref_by'
+                    WHEN 'endpoint' THEN 'endpoint_called_by'
+                    WHEN 'references' THEN 'referenced_by'
                     ELSE e.edge_kind || '_reverse'
                 END,
                 s_source.qualified_name,
@@ -610,7 +680,7 @@ impl SqliteStore {
                 FROM symbol_edges e
                 JOIN symbols s_source ON s_source.id = e.source_id
                 JOIN symbols s_target ON s_target.qualified_name = e.target_qualified_name
-                WHERE e.edge_kind IN ('calls', 'depends_on', 'type_ref', 'implements')
+                WHERE e.edge_kind IN ('calls', 'depends_on', 'type_ref', 'implements', 'endpoint', 'references')
                   AND (
                       (s_source.file_path = ?1 AND s_target.file_path = ?2)
                       OR
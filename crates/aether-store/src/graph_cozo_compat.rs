@@ -8,8 +8,8 @@ use std::sync::OnceLock;
 use async_trait::async_trait;
 
 use super::{
-    CouplingEdgeRecord, GraphStore, ResolvedEdge, StoreError, SurrealGraphStore, SymbolRecord,
-    TestedByRecord, UpstreamDependencyTraversal,
+    CouplingEdgeRecord, GraphDependencyEdgeRecord, GraphStore, ResolvedEdge, StoreError,
+    SurrealGraphStore, SymbolRecord, TestedByRecord, UpstreamDependencyTraversal,
 };
 
 pub type CrossCommunityEdge = (String, String, String, i64, i64);
@@ -214,6 +214,10 @@ impl GraphStore for CozoGraphStore {
         self.inner.get_call_chain(symbol_id, depth).await
     }
 
+    async fn list_dependency_edges(&self) -> Result<Vec<GraphDependencyEdgeRecord>, StoreError> {
+        GraphStore::list_dependency_edges(&self.inner).await
+    }
+
     async fn delete_edges_for_file(&self, file_path: &str) -> Result<(), StoreError> {
         self.inner.delete_edges_for_file(file_path).await
     }
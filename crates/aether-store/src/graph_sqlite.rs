@@ -5,7 +5,9 @@ use std::time::Duration;
 use async_trait::async_trait;
 use rusqlite::{Connection, OpenFlags, params};
 
-use super::{GraphStore, ResolvedEdge, StoreError, SymbolRecord, run_migrations};
+use super::{
+    GraphDependencyEdgeRecord, GraphStore, ResolvedEdge, StoreError, SymbolRecord, run_migrations,
+};
 
 pub struct SqliteGraphStore {
     sqlite_path: PathBuf,
@@ -236,6 +238,28 @@ impl GraphStore for SqliteGraphStore {
         Ok(levels)
     }
 
+    async fn list_dependency_edges(&self) -> Result<Vec<GraphDependencyEdgeRecord>, StoreError> {
+        let conn = self.connection()?;
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT DISTINCT e.source_id, target.id, e.edge_kind
+            FROM symbol_edges e
+            JOIN symbols source ON source.id = e.source_id
+            JOIN symbols target ON target.qualified_name = e.target_qualified_name
+            ORDER BY e.source_id ASC, target.id ASC, e.edge_kind ASC
+            "#,
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(GraphDependencyEdgeRecord {
+                source_symbol_id: row.get(0)?,
+                target_symbol_id: row.get(1)?,
+                edge_kind: row.get(2)?,
+            })
+        })?;
+
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
     async fn delete_edges_for_file(&self, _file_path: &str) -> Result<(), StoreError> {
         Ok(())
     }
@@ -305,6 +329,19 @@ mod tests {
             .expect("get dependencies");
         assert_eq!(deps.len(), 1);
         assert_eq!(deps[0].id, beta.id);
+
+        let edges = graph
+            .list_dependency_edges()
+            .await
+            .expect("list dependency edges");
+        assert_eq!(
+            edges,
+            vec![GraphDependencyEdgeRecord {
+                source_symbol_id: alpha.id.clone(),
+                target_symbol_id: beta.id.clone(),
+                edge_kind: "calls".to_owned(),
+            }]
+        );
     }
 
     #[tokio::test]
@@ -924,6 +924,8 @@ impl GraphStore for SurrealGraphStore {
             EdgeKind::DependsOn => "depends_on",
             EdgeKind::TypeRef => "type_ref",
             EdgeKind::Implements => "implements",
+            EdgeKind::Endpoint => "endpoint",
+            EdgeKind::References => "references",
         }
         .to_owned();
         self.db
@@ -1227,6 +1229,10 @@ impl GraphStore for SurrealGraphStore {
         Ok(())
     }
 
+    async fn list_dependency_edges(&self) -> Result<Vec<GraphDependencyEdgeRecord>, StoreError> {
+        SurrealGraphStore::list_dependency_edges(self).await
+    }
+
     async fn delete_symbols_batch(&self, symbol_ids: &[String]) -> Result<(), StoreError> {
         SurrealGraphStore::delete_symbols_batch(self, symbol_ids).await
     }
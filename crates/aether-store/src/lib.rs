@@ -22,19 +22,23 @@ mod analysis;
 mod audit;
 mod contracts;
 mod embeddings;
+mod endpoints;
 mod fingerprint_history;
 mod graph;
 mod lexical;
 mod lifecycle;
+mod profiles;
 mod project_notes;
 mod schema;
 mod seismograph;
+mod sir_grounding;
 mod sir_history;
 mod sir_meta;
 mod snapshots;
 mod symbols;
 mod task_context_history;
 mod test_intents;
+mod test_runs;
 mod thresholds;
 mod time;
 mod write_intents;
@@ -62,14 +66,18 @@ pub use analysis::{
     CommunitySnapshotRecord, CouplingMiningStateRecord, DriftAnalysisStateRecord, DriftResultRecord,
 };
 pub use audit::{AuditFinding, AuditFindingFilters, AuditSeverityCounts, NewAuditFinding};
-pub use contracts::{IntentContractRecord, IntentViolationRecord};
+pub use contracts::{
+    ContractCreatorSummary, DeclaredContract, IntentContractRecord, IntentViolationRecord,
+    POLICY_CONTRACT_CREATOR_PREFIX, SOURCE_CONTRACT_CREATOR, SourceContractSync,
+};
 pub use embeddings::{SemanticSearchResult, SymbolEmbeddingMetaRecord, SymbolEmbeddingRecord};
 pub use fingerprint_history::SirFingerprintHistoryRecord;
 pub use graph::{
-    CouplingEdgeRecord, GraphDependencyEdgeRecord, GraphSyncStats, ResolvedEdge,
+    CouplingEdgeRecord, GraphDependencyEdgeRecord, GraphSyncStats, LocatedEdgeRecord, ResolvedEdge,
     SymbolNeighborRecord, TestedByRecord, UpstreamDependencyEdgeRecord,
     UpstreamDependencyNodeRecord, UpstreamDependencyTraversal,
 };
+pub use profiles::{EdgeSampleCount, FileSampleCount, ProfileRecord, SymbolSampleCount};
 pub use project_notes::{
     ProjectEntityRefRecord, ProjectNoteEmbeddingRecord, ProjectNoteRecord,
     ProjectNoteSemanticSearchResult,
@@ -78,6 +86,7 @@ pub use schema::SchemaVersion;
 pub use seismograph::{
     AftershockModelRecord, CascadeRecord, CommunityStabilityRecord, SeismographMetricRecord,
 };
+pub use sir_grounding::SirGroundingRecord;
 pub use sir_history::{
     SirHistoryBaselineSelector, SirHistoryRecord, SirHistoryResolvedPair, SirHistorySelector,
     SirVersionWriteResult,
@@ -87,6 +96,7 @@ pub use snapshots::{IntentSnapshot, IntentSnapshotSummary, SnapshotEntry};
 pub use symbols::{SymbolMetadata, SymbolRecord, SymbolSearchResult};
 pub use task_context_history::TaskContextHistoryRecord;
 pub use test_intents::TestIntentRecord;
+pub use test_runs::{TestResultRecord, TestRunRecord};
 pub use thresholds::{CalibrationEmbeddingRecord, ThresholdCalibrationRecord};
 pub use write_intents::{BatchCompleteResult, IntentOperation, WriteIntent, WriteIntentStatus};
 
@@ -395,6 +405,8 @@ pub trait GraphStore: Send + Sync {
         symbol_id: &str,
         depth: u32,
     ) -> Result<Vec<Vec<SymbolRecord>>, StoreError>;
+    /// Every dependency edge between two indexed symbols, of any kind.
+    async fn list_dependency_edges(&self) -> Result<Vec<GraphDependencyEdgeRecord>, StoreError>;
     async fn delete_edges_for_file(&self, file_path: &str) -> Result<(), StoreError>;
     async fn delete_symbols_batch(&self, symbol_ids: &[String]) -> Result<(), StoreError>;
 }
@@ -63,6 +63,7 @@ fn delete_symbol_fully(tx: &Transaction<'_>, symbol_id: &str) -> Result<(), Stor
         ("community_snapshot", "symbol_id"),
         ("drift_results", "symbol_id"),
         ("sir_quality", "sir_id"),
+        ("sir_grounding", "sir_id"),
         ("sir", "id"),
         ("symbols", "id"),
     ] {
@@ -0,0 +1,219 @@
+use super::*;
+
+/// One ingested runtime profile.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileRecord {
+    pub profile_id: String,
+    /// `collapsed`, `pprof` or `speedscope`.
+    pub format: String,
+    pub source_path: String,
+    pub recorded_at: i64,
+    pub total_samples: i64,
+    /// Samples with at least one frame mapped to an indexed symbol.
+    pub mapped_samples: i64,
+}
+
+/// Samples attributed to a symbol. `self_samples` counts stacks where it was
+/// the innermost mapped frame; `total_samples` counts every stack it was on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolSampleCount {
+    pub symbol_id: String,
+    pub self_samples: i64,
+    pub total_samples: i64,
+}
+
+/// Samples in which `caller_id` called `callee_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EdgeSampleCount {
+    pub caller_id: String,
+    pub callee_id: String,
+    pub samples: i64,
+}
+
+/// Self samples summed over the symbols of one file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileSampleCount {
+    pub file_path: String,
+    pub samples: i64,
+}
+
+impl SqliteStore {
+    /// Stores a profile and its sample counts, replacing any earlier copy of
+    /// the profile.
+    pub fn record_profile(
+        &self,
+        profile: &ProfileRecord,
+        symbols: &[SymbolSampleCount],
+        edges: &[EdgeSampleCount],
+    ) -> Result<(), StoreError> {
+        let profile_id = profile.profile_id.trim();
+        if profile_id.is_empty() {
+            return Ok(());
+        }
+
+        let conn = self.conn.lock().unwrap();
+        let tx = Transaction::new_unchecked(&conn, TransactionBehavior::Immediate)?;
+        tx.execute(
+            "DELETE FROM profiles WHERE profile_id = ?1",
+            params![profile_id],
+        )?;
+        tx.execute(
+            r#"
+            INSERT INTO profiles (
+                profile_id, format, source_path, recorded_at, total_samples, mapped_samples
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+            params![
+                profile_id,
+                profile.format.trim(),
+                normalize_path(profile.source_path.trim()),
+                profile.recorded_at.max(0),
+                profile.total_samples.max(0),
+                profile.mapped_samples.max(0),
+            ],
+        )?;
+        {
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT OR REPLACE INTO profile_symbol_samples (
+                    profile_id, symbol_id, self_samples, total_samples
+                )
+                VALUES (?1, ?2, ?3, ?4)
+                "#,
+            )?;
+            for count in symbols {
+                let symbol_id = count.symbol_id.trim();
+                if symbol_id.is_empty() {
+                    continue;
+                }
+                stmt.execute(params![
+                    profile_id,
+                    symbol_id,
+                    count.self_samples.max(0),
+                    count.total_samples.max(0),
+                ])?;
+            }
+        }
+        {
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT OR REPLACE INTO profile_edge_samples (
+                    profile_id, caller_id, callee_id, samples
+                )
+                VALUES (?1, ?2, ?3, ?4)
+                "#,
+            )?;
+            for count in edges {
+                let caller_id = count.caller_id.trim();
+                let callee_id = count.callee_id.trim();
+                if caller_id.is_empty() || callee_id.is_empty() || caller_id == callee_id {
+                    continue;
+                }
+                stmt.execute(params![
+                    profile_id,
+                    caller_id,
+                    callee_id,
+                    count.samples.max(0)
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Drops every ingested profile and its sample counts.
+    pub fn clear_profiles(&self) -> Result<(), StoreError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("DELETE FROM profiles", [])?;
+        Ok(())
+    }
+
+    /// Most recent profiles first.
+    pub fn list_profiles(&self, limit: u32) -> Result<Vec<ProfileRecord>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT profile_id, format, source_path, recorded_at, total_samples, mapped_samples
+            FROM profiles
+            ORDER BY recorded_at DESC, profile_id DESC
+            LIMIT ?1
+            "#,
+        )?;
+        let rows = stmt.query_map(params![limit.max(1) as i64], |row| {
+            Ok(ProfileRecord {
+                profile_id: row.get(0)?,
+                format: row.get(1)?,
+                source_path: row.get(2)?,
+                recorded_at: row.get(3)?,
+                total_samples: row.get(4)?,
+                mapped_samples: row.get(5)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
+    /// Sample counts summed over all ingested profiles, hottest first.
+    pub fn list_symbol_sample_counts(&self) -> Result<Vec<SymbolSampleCount>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT symbol_id, SUM(self_samples), SUM(total_samples)
+            FROM profile_symbol_samples
+            GROUP BY symbol_id
+            ORDER BY SUM(total_samples) DESC, symbol_id ASC
+            "#,
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(SymbolSampleCount {
+                symbol_id: row.get(0)?,
+                self_samples: row.get(1)?,
+                total_samples: row.get(2)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
+    /// Edge sample counts summed over all ingested profiles.
+    pub fn list_edge_sample_counts(&self) -> Result<Vec<EdgeSampleCount>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT caller_id, callee_id, SUM(samples)
+            FROM profile_edge_samples
+            GROUP BY caller_id, callee_id
+            ORDER BY caller_id ASC, callee_id ASC
+            "#,
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(EdgeSampleCount {
+                caller_id: row.get(0)?,
+                callee_id: row.get(1)?,
+                samples: row.get(2)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
+    /// Self samples per file over all ingested profiles, for symbols that are
+    /// still indexed.
+    pub fn list_file_sample_counts(&self) -> Result<Vec<FileSampleCount>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT s.file_path, SUM(p.self_samples)
+            FROM profile_symbol_samples p
+            JOIN symbols s ON s.id = p.symbol_id
+            GROUP BY s.file_path
+            ORDER BY SUM(p.self_samples) DESC, s.file_path ASC
+            "#,
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(FileSampleCount {
+                file_path: row.get(0)?,
+                samples: row.get(1)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+}
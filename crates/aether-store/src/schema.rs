@@ -135,7 +135,7 @@ pub(crate) fn run_migrations(conn: &Connection) -> Result<(), StoreError> {
         CREATE TABLE IF NOT EXISTS symbol_edges (
             source_id TEXT NOT NULL,
             target_qualified_name TEXT NOT NULL,
-            edge_kind TEXT NOT NULL CHECK (edge_kind IN ('calls', 'depends_on', 'type_ref', 'implements')),
+            edge_kind TEXT NOT NULL CHECK (edge_kind IN ('calls', 'depends_on', 'type_ref', 'implements', 'endpoint', 'references')),
             file_path TEXT NOT NULL,
             PRIMARY KEY (source_id, target_qualified_name, edge_kind)
         );
@@ -676,6 +676,129 @@ pub(crate) fn run_migrations(conn: &Connection) -> Result<(), StoreError> {
         conn.execute("PRAGMA user_version = 18", [])?;
     }
 
+    if version < 19 {
+        conn.execute_batch(
+            r#"
+        CREATE TABLE IF NOT EXISTS sir_grounding (
+            sir_id TEXT PRIMARY KEY REFERENCES sir(id) ON DELETE CASCADE,
+            grounding_score REAL NOT NULL,
+            claimed_count INTEGER NOT NULL,
+            grounded_count INTEGER NOT NULL,
+            ungrounded_claims TEXT NOT NULL DEFAULT '[]',
+            missing_callees TEXT NOT NULL DEFAULT '[]',
+            regeneration_attempts INTEGER NOT NULL DEFAULT 0,
+            retry_feedback TEXT,
+            checked_at INTEGER NOT NULL
+        );
+        "#,
+        )?;
+        conn.execute("PRAGMA user_version = 19", [])?;
+    }
+
+    if version < 20 {
+        upgrade_symbol_edges_table(conn)?;
+        conn.execute_batch(
+            r#"
+        CREATE TABLE IF NOT EXISTS endpoint_routes (
+            file_path TEXT NOT NULL,
+            method TEXT NOT NULL,
+            path TEXT NOT NULL,
+            handler TEXT NOT NULL,
+            PRIMARY KEY (file_path, method, path, handler)
+        );
+
+        CREATE TABLE IF NOT EXISTS endpoint_calls (
+            file_path TEXT NOT NULL,
+            source_id TEXT NOT NULL,
+            method TEXT NOT NULL,
+            path TEXT NOT NULL,
+            PRIMARY KEY (file_path, source_id, method, path)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_endpoint_calls_path
+            ON endpoint_calls(path);
+        "#,
+        )?;
+        conn.execute("PRAGMA user_version = 20", [])?;
+    }
+
+    if version < 21 {
+        upgrade_symbol_edges_table(conn)?;
+        conn.execute("PRAGMA user_version = 21", [])?;
+    }
+
+    if version < 22 {
+        conn.execute_batch(
+            r#"
+        CREATE TABLE IF NOT EXISTS test_runs (
+            run_id TEXT PRIMARY KEY,
+            format TEXT NOT NULL,
+            source_path TEXT NOT NULL,
+            commit_hash TEXT,
+            recorded_at INTEGER NOT NULL,
+            total INTEGER NOT NULL DEFAULT 0,
+            passed INTEGER NOT NULL DEFAULT 0,
+            failed INTEGER NOT NULL DEFAULT 0,
+            skipped INTEGER NOT NULL DEFAULT 0
+        );
+
+        CREATE TABLE IF NOT EXISTS test_results (
+            run_id TEXT NOT NULL REFERENCES test_runs(run_id) ON DELETE CASCADE,
+            test_key TEXT NOT NULL,
+            test_name TEXT NOT NULL,
+            file_path TEXT,
+            symbol_id TEXT,
+            outcome TEXT NOT NULL CHECK (outcome IN ('passed', 'failed', 'skipped')),
+            duration_ms INTEGER,
+            message TEXT,
+            PRIMARY KEY (run_id, test_key)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_test_runs_recorded
+            ON test_runs(recorded_at);
+
+        CREATE INDEX IF NOT EXISTS idx_test_results_key
+            ON test_results(test_key);
+        "#,
+        )?;
+        conn.execute("PRAGMA user_version = 22", [])?;
+    }
+
+    if version < 23 {
+        conn.execute_batch(
+            r#"
+        CREATE TABLE IF NOT EXISTS profiles (
+            profile_id TEXT PRIMARY KEY,
+            format TEXT NOT NULL,
+            source_path TEXT NOT NULL,
+            recorded_at INTEGER NOT NULL,
+            total_samples INTEGER NOT NULL DEFAULT 0,
+            mapped_samples INTEGER NOT NULL DEFAULT 0
+        );
+
+        CREATE TABLE IF NOT EXISTS profile_symbol_samples (
+            profile_id TEXT NOT NULL REFERENCES profiles(profile_id) ON DELETE CASCADE,
+            symbol_id TEXT NOT NULL,
+            self_samples INTEGER NOT NULL DEFAULT 0,
+            total_samples INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (profile_id, symbol_id)
+        );
+
+        CREATE TABLE IF NOT EXISTS profile_edge_samples (
+            profile_id TEXT NOT NULL REFERENCES profiles(profile_id) ON DELETE CASCADE,
+            caller_id TEXT NOT NULL,
+            callee_id TEXT NOT NULL,
+            samples INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (profile_id, caller_id, callee_id)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_profile_symbol_samples_symbol
+            ON profile_symbol_samples(symbol_id);
+        "#,
+        )?;
+        conn.execute("PRAGMA user_version = 23", [])?;
+    }
+
     conn.execute_batch(
         r#"
         CREATE TABLE IF NOT EXISTS schema_version (
@@ -737,6 +860,8 @@ fn rebuild_symbol_neighbors(conn: &Connection) -> Result<(), StoreError> {
                 WHEN 'depends_on' THEN 'depended_on_by'
                 WHEN 'implements' THEN 'implemented_by'
                 WHEN 'type_ref' THEN 'type_ref_by'
+                WHEN 'endpoint' THEN 'endpoint_called_by'
+                WHEN 'references' THEN 'referenced_by'
                 ELSE e.edge_kind || '_reverse'
             END,
             s_source.qualified_name,
@@ -804,7 +929,7 @@ fn upgrade_symbol_edges_table(conn: &Connection) -> Result<(), StoreError> {
         CREATE TABLE symbol_edges (
             source_id TEXT NOT NULL,
             target_qualified_name TEXT NOT NULL,
-            edge_kind TEXT NOT NULL CHECK (edge_kind IN ('calls', 'depends_on', 'type_ref', 'implements')),
+            edge_kind TEXT NOT NULL CHECK (edge_kind IN ('calls', 'depends_on', 'type_ref', 'implements', 'endpoint', 'references')),
             file_path TEXT NOT NULL,
             PRIMARY KEY (source_id, target_qualified_name, edge_kind)
         );
@@ -0,0 +1,136 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SirGroundingRecord {
+    pub sir_id: String,
+    pub grounding_score: f64,
+    pub claimed_count: i64,
+    pub grounded_count: i64,
+    pub ungrounded_claims: Vec<String>,
+    pub missing_callees: Vec<String>,
+    pub regeneration_attempts: i64,
+    /// Discrepancy summary fed into the next regeneration prompt, if one is queued.
+    pub retry_feedback: Option<String>,
+    pub checked_at: i64,
+}
+
+fn parse_string_list(raw: &str) -> Result<Vec<String>, StoreError> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(json_from_str::<Vec<String>>(trimmed)?)
+}
+
+impl SqliteStore {
+    pub fn upsert_sir_grounding(&self, record: &SirGroundingRecord) -> Result<(), StoreError> {
+        let sir_id = record.sir_id.trim();
+        if sir_id.is_empty() {
+            return Ok(());
+        }
+
+        let ungrounded_claims = serde_json::to_string(&record.ungrounded_claims)?;
+        let missing_callees = serde_json::to_string(&record.missing_callees)?;
+        let retry_feedback = record
+            .retry_feedback
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+
+        self.conn.lock().unwrap().execute(
+            r#"
+            INSERT INTO sir_grounding (
+                sir_id, grounding_score, claimed_count, grounded_count, ungrounded_claims,
+                missing_callees, regeneration_attempts, retry_feedback, checked_at
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            ON CONFLICT(sir_id) DO UPDATE SET
+                grounding_score = excluded.grounding_score,
+                claimed_count = excluded.claimed_count,
+                grounded_count = excluded.grounded_count,
+                ungrounded_claims = excluded.ungrounded_claims,
+                missing_callees = excluded.missing_callees,
+                regeneration_attempts = excluded.regeneration_attempts,
+                retry_feedback = excluded.retry_feedback,
+                checked_at = excluded.checked_at
+            "#,
+            params![
+                sir_id,
+                record.grounding_score.clamp(0.0, 1.0),
+                record.claimed_count.max(0),
+                record.grounded_count.max(0),
+                ungrounded_claims,
+                missing_callees,
+                record.regeneration_attempts.max(0),
+                retry_feedback,
+                record.checked_at.max(0),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    pub fn get_sir_grounding(
+        &self,
+        sir_id: &str,
+    ) -> Result<Option<SirGroundingRecord>, StoreError> {
+        let sir_id = sir_id.trim();
+        if sir_id.is_empty() {
+            return Ok(None);
+        }
+
+        let conn = self.conn.lock().unwrap();
+        let row = conn
+            .query_row(
+                r#"
+                SELECT
+                    sir_id, grounding_score, claimed_count, grounded_count, ungrounded_claims,
+                    missing_callees, regeneration_attempts, retry_feedback, checked_at
+                FROM sir_grounding
+                WHERE sir_id = ?1
+                "#,
+                params![sir_id],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, f64>(1)?,
+                        row.get::<_, i64>(2)?,
+                        row.get::<_, i64>(3)?,
+                        row.get::<_, String>(4)?,
+                        row.get::<_, String>(5)?,
+                        row.get::<_, i64>(6)?,
+                        row.get::<_, Option<String>>(7)?,
+                        row.get::<_, i64>(8)?,
+                    ))
+                },
+            )
+            .optional()?;
+
+        let Some((
+            sir_id,
+            grounding_score,
+            claimed_count,
+            grounded_count,
+            ungrounded_claims,
+            missing_callees,
+            regeneration_attempts,
+            retry_feedback,
+            checked_at,
+        )) = row
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(SirGroundingRecord {
+            sir_id,
+            grounding_score,
+            claimed_count,
+            grounded_count,
+            ungrounded_claims: parse_string_list(&ungrounded_claims)?,
+            missing_callees: parse_string_list(&missing_callees)?,
+            regeneration_attempts,
+            retry_feedback,
+            checked_at,
+        }))
+    }
+}
@@ -183,6 +183,24 @@ impl SqliteStore {
         }
         Ok(records)
     }
+    pub fn list_all_test_intents(&self) -> Result<Vec<TestIntentRecord>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT
+                intent_id, file_path, test_name, intent_text, group_label,
+                language, symbol_id, created_at, updated_at
+            FROM test_intents
+            ORDER BY file_path ASC, test_name ASC, intent_id ASC
+            "#,
+        )?;
+        let rows = stmt.query_map([], test_intent_tuple_from_row)?;
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(test_intent_from_tuple(row?)?);
+        }
+        Ok(records)
+    }
     pub(crate) fn store_search_test_intents_lexical(
         &self,
         query: &str,
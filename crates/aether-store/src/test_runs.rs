@@ -0,0 +1,222 @@
+use super::*;
+
+/// One ingested test report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestRunRecord {
+    pub run_id: String,
+    /// `junit`, `nextest`, `pytest` or `jest`.
+    pub format: String,
+    pub source_path: String,
+    /// Workspace HEAD when the report was ingested, if known.
+    pub commit_hash: Option<String>,
+    pub recorded_at: i64,
+    pub total: i64,
+    pub passed: i64,
+    pub failed: i64,
+    pub skipped: i64,
+}
+
+/// The outcome of one test case within a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestResultRecord {
+    pub run_id: String,
+    /// Identity of the test across runs: `<file or suite>::<name>`.
+    pub test_key: String,
+    pub test_name: String,
+    pub file_path: Option<String>,
+    /// Test symbol the case was linked to, if any.
+    pub symbol_id: Option<String>,
+    /// `passed`, `failed` or `skipped`.
+    pub outcome: String,
+    pub duration_ms: Option<i64>,
+    pub message: Option<String>,
+}
+
+fn test_run_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<TestRunRecord> {
+    Ok(TestRunRecord {
+        run_id: row.get(0)?,
+        format: row.get(1)?,
+        source_path: row.get(2)?,
+        commit_hash: row.get(3)?,
+        recorded_at: row.get(4)?,
+        total: row.get(5)?,
+        passed: row.get(6)?,
+        failed: row.get(7)?,
+        skipped: row.get(8)?,
+    })
+}
+
+fn test_result_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<TestResultRecord> {
+    Ok(TestResultRecord {
+        run_id: row.get(0)?,
+        test_key: row.get(1)?,
+        test_name: row.get(2)?,
+        file_path: row.get(3)?,
+        symbol_id: row.get(4)?,
+        outcome: row.get(5)?,
+        duration_ms: row.get(6)?,
+        message: row.get(7)?,
+    })
+}
+
+impl SqliteStore {
+    /// Stores a run and its results, replacing any earlier copy of the run.
+    pub fn record_test_run(
+        &self,
+        run: &TestRunRecord,
+        results: &[TestResultRecord],
+    ) -> Result<(), StoreError> {
+        let run_id = run.run_id.trim();
+        if run_id.is_empty() {
+            return Ok(());
+        }
+
+        let conn = self.conn.lock().unwrap();
+        let tx = Transaction::new_unchecked(&conn, TransactionBehavior::Immediate)?;
+        tx.execute(
+            "DELETE FROM test_results WHERE run_id = ?1",
+            params![run_id],
+        )?;
+        tx.execute(
+            r#"
+            INSERT INTO test_runs (
+                run_id, format, source_path, commit_hash, recorded_at,
+                total, passed, failed, skipped
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            ON CONFLICT(run_id) DO UPDATE SET
+                format = excluded.format,
+                source_path = excluded.source_path,
+                commit_hash = excluded.commit_hash,
+                recorded_at = excluded.recorded_at,
+                total = excluded.total,
+                passed = excluded.passed,
+                failed = excluded.failed,
+                skipped = excluded.skipped
+            "#,
+            params![
+                run_id,
+                run.format.trim(),
+                normalize_path(run.source_path.trim()),
+                run.commit_hash.as_deref().map(str::trim),
+                run.recorded_at.max(0),
+                run.total.max(0),
+                run.passed.max(0),
+                run.failed.max(0),
+                run.skipped.max(0),
+            ],
+        )?;
+        {
+            let mut stmt = tx.prepare(
+                r#"
+                INSERT OR REPLACE INTO test_results (
+                    run_id, test_key, test_name, file_path, symbol_id,
+                    outcome, duration_ms, message
+                )
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                "#,
+            )?;
+            for result in results {
+                let test_key = result.test_key.trim();
+                if test_key.is_empty() {
+                    continue;
+                }
+                stmt.execute(params![
+                    run_id,
+                    test_key,
+                    result.test_name.trim(),
+                    result
+                        .file_path
+                        .as_deref()
+                        .map(|path| normalize_path(path.trim())),
+                    result.symbol_id.as_deref().map(str::trim),
+                    result.outcome.trim(),
+                    result.duration_ms.map(|duration| duration.max(0)),
+                    result.message.as_deref(),
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Most recent runs first.
+    pub fn list_test_runs(&self, limit: u32) -> Result<Vec<TestRunRecord>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT
+                run_id, format, source_path, commit_hash, recorded_at,
+                total, passed, failed, skipped
+            FROM test_runs
+            ORDER BY recorded_at DESC, run_id DESC
+            LIMIT ?1
+            "#,
+        )?;
+        let rows = stmt.query_map(params![limit.max(1) as i64], test_run_from_row)?;
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(row?);
+        }
+        Ok(records)
+    }
+
+    pub fn list_test_results_for_run(
+        &self,
+        run_id: &str,
+    ) -> Result<Vec<TestResultRecord>, StoreError> {
+        let run_id = run_id.trim();
+        if run_id.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT
+                run_id, test_key, test_name, file_path, symbol_id,
+                outcome, duration_ms, message
+            FROM test_results
+            WHERE run_id = ?1
+            ORDER BY test_key ASC
+            "#,
+        )?;
+        let rows = stmt.query_map(params![run_id], test_result_from_row)?;
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(row?);
+        }
+        Ok(records)
+    }
+
+    /// Results recorded in the `max_runs` most recent runs, oldest run first
+    /// for each test.
+    pub fn list_recent_test_results(
+        &self,
+        max_runs: u32,
+    ) -> Result<Vec<TestResultRecord>, StoreError> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            r#"
+            WITH recent AS (
+                SELECT run_id, recorded_at
+                FROM test_runs
+                ORDER BY recorded_at DESC, run_id DESC
+                LIMIT ?1
+            )
+            SELECT
+                r.run_id, r.test_key, r.test_name, r.file_path, r.symbol_id,
+                r.outcome, r.duration_ms, r.message
+            FROM test_results r
+            JOIN recent ON recent.run_id = r.run_id
+            ORDER BY r.test_key ASC, recent.recorded_at ASC, r.run_id ASC
+            "#,
+        )?;
+        let rows = stmt.query_map(params![max_runs.max(1) as i64], test_result_from_row)?;
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(row?);
+        }
+        Ok(records)
+    }
+}
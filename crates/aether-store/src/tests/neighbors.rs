@@ -1,3 +1,5 @@
+use aether_core::{EndpointCall, EndpointRoute};
+
 use super::*;
 
 fn test_symbol(id: &str, file_path: &str, qualified_name: &str) -> SymbolRecord {
@@ -248,3 +250,140 @@ fn reconcile_and_prune_cleans_stale_symbol_neighbors() {
             .is_empty()
     );
 }
+
+#[test]
+fn endpoint_edges_link_files_across_languages() {
+    let temp = tempdir().expect("tempdir");
+    let store = SqliteStore::open(temp.path()).expect("open store");
+
+    let handler = test_symbol("sym-handler", "src/routes.rs", "get_user");
+    let client = SymbolRecord {
+        language: "typescript".to_owned(),
+        ..test_symbol("sym-client", "web/api.ts", "loadUser")
+    };
+    store
+        .upsert_symbol(handler.clone())
+        .expect("upsert handler");
+    store.upsert_symbol(client.clone()).expect("upsert client");
+
+    let route = EndpointRoute {
+        file_path: handler.file_path.clone(),
+        method: "GET".to_owned(),
+        path: "/users/{}".to_owned(),
+        handler: handler.qualified_name.clone(),
+    };
+    let call = EndpointCall {
+        file_path: client.file_path.clone(),
+        source_id: client.id.clone(),
+        method: "GET".to_owned(),
+        path: "/users/42".to_owned(),
+    };
+    store
+        .replace_endpoints_for_file(&handler.file_path, std::slice::from_ref(&route), &[])
+        .expect("store route");
+    store
+        .replace_endpoints_for_file(&client.file_path, &[], std::slice::from_ref(&call))
+        .expect("store call");
+    assert_eq!(store.list_endpoint_routes().expect("routes"), vec![route]);
+    assert_eq!(store.list_endpoint_calls().expect("calls"), vec![call]);
+
+    store
+        .upsert_edges(&[calls_edge(
+            client.id.as_str(),
+            "render",
+            client.file_path.as_str(),
+        )])
+        .expect("upsert call edge");
+    let endpoint_edge = SymbolEdge {
+        source_id: client.id.clone(),
+        target_qualified_name: handler.qualified_name.clone(),
+        edge_kind: EdgeKind::Endpoint,
+        file_path: client.file_path.clone(),
+    };
+    store
+        .replace_endpoint_edges_for_file(&client.file_path, &[endpoint_edge])
+        .expect("replace endpoint edges");
+    store
+        .populate_symbol_neighbors(client.file_path.as_str())
+        .expect("populate neighbors");
+
+    assert_eq!(
+        store
+            .list_endpoint_linked_files(&handler.file_path)
+            .expect("linked from handler"),
+        vec![client.file_path.clone()]
+    );
+    assert_eq!(
+        store
+            .list_endpoint_linked_files(&client.file_path)
+            .expect("linked from client"),
+        vec![handler.file_path.clone()]
+    );
+    assert_eq!(
+        store
+            .get_symbol_neighbors(handler.id.as_str())
+            .expect("handler neighbors")[0]
+            .edge_type,
+        "endpoint_called_by"
+    );
+
+    store
+        .replace_endpoint_edges_for_file(&client.file_path, &[])
+        .expect("clear endpoint edges");
+    assert!(
+        store
+            .list_endpoint_linked_files(&handler.file_path)
+            .expect("linked after clear")
+            .is_empty()
+    );
+    assert_eq!(
+        store
+            .get_callers("render")
+            .expect("call edge survives")
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn list_located_edges_reports_both_endpoints_and_unresolved_targets() {
+    let temp = tempdir().expect("tempdir");
+    let store = SqliteStore::open(temp.path()).expect("open store");
+
+    let alpha = test_symbol("sym-alpha", "src/a.rs", "alpha");
+    let beta = test_symbol("sym-beta", "src/b.rs", "beta");
+    store.upsert_symbol(alpha.clone()).expect("upsert alpha");
+    store.upsert_symbol(beta.clone()).expect("upsert beta");
+    store
+        .upsert_edges(&[
+            calls_edge(alpha.id.as_str(), "beta", alpha.file_path.as_str()),
+            depends_edge("file::src/a.rs", "crate::store::Store", "src/a.rs"),
+        ])
+        .expect("upsert edges");
+
+    let edges = store
+        .list_located_edges(&[EdgeKind::Calls, EdgeKind::DependsOn])
+        .expect("list located edges");
+    assert_eq!(edges.len(), 2);
+    assert_eq!(
+        edges[0],
+        LocatedEdgeRecord {
+            source_id: "file::src/a.rs".to_owned(),
+            source_qualified_name: None,
+            source_file: "src/a.rs".to_owned(),
+            target_qualified_name: "crate::store::Store".to_owned(),
+            target_id: None,
+            target_file: None,
+            edge_kind: "depends_on".to_owned(),
+        }
+    );
+    assert_eq!(edges[1].source_qualified_name.as_deref(), Some("alpha"));
+    assert_eq!(edges[1].target_id.as_deref(), Some("sym-beta"));
+    assert_eq!(edges[1].target_file.as_deref(), Some("src/b.rs"));
+    assert!(
+        store
+            .list_located_edges(&[EdgeKind::TypeRef])
+            .expect("no type refs")
+            .is_empty()
+    );
+}
@@ -371,3 +371,147 @@ fn list_project_notes_for_file_ref_matches_exact_file_ref() {
     assert_eq!(matches.len(), 1);
     assert_eq!(matches[0].note_id, "note-a");
 }
+
+#[test]
+fn test_runs_keep_recent_results_in_run_order() {
+    let temp = tempdir().expect("tempdir");
+    let store = SqliteStore::open(temp.path()).expect("open store");
+
+    let run = |run_id: &str, recorded_at: i64| TestRunRecord {
+        run_id: run_id.to_owned(),
+        format: "junit".to_owned(),
+        source_path: "target/report.xml".to_owned(),
+        commit_hash: None,
+        recorded_at,
+        total: 1,
+        passed: 0,
+        failed: 0,
+        skipped: 0,
+    };
+    let result = |run_id: &str, outcome: &str| TestResultRecord {
+        run_id: run_id.to_owned(),
+        test_key: "tests/payment_test.rs::charges_card".to_owned(),
+        test_name: "charges_card".to_owned(),
+        file_path: Some("tests/payment_test.rs".to_owned()),
+        symbol_id: Some("sym-test".to_owned()),
+        outcome: outcome.to_owned(),
+        duration_ms: Some(12),
+        message: (outcome == "failed").then(|| "assertion failed".to_owned()),
+    };
+
+    for (run_id, recorded_at, outcome) in [
+        ("run-b", 200, "failed"),
+        ("run-a", 100, "passed"),
+        ("run-c", 300, "passed"),
+    ] {
+        store
+            .record_test_run(&run(run_id, recorded_at), &[result(run_id, outcome)])
+            .expect("record run");
+    }
+    // Re-recording a run replaces its results.
+    store
+        .record_test_run(&run("run-c", 300), &[result("run-c", "skipped")])
+        .expect("re-record run");
+
+    let runs = store.list_test_runs(10).expect("list runs");
+    assert_eq!(
+        runs.iter()
+            .map(|run| run.run_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["run-c", "run-b", "run-a"]
+    );
+
+    let recent = store.list_recent_test_results(2).expect("recent results");
+    assert_eq!(
+        recent
+            .iter()
+            .map(|result| (result.run_id.as_str(), result.outcome.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("run-b", "failed"), ("run-c", "skipped")]
+    );
+    assert_eq!(
+        store
+            .list_test_results_for_run("run-b")
+            .expect("run results")[0]
+            .message,
+        Some("assertion failed".to_owned())
+    );
+}
+
+#[test]
+fn profile_samples_sum_across_profiles_and_files() {
+    let temp = tempdir().expect("tempdir");
+    let store = SqliteStore::open(temp.path()).expect("open store");
+    store.upsert_symbol(symbol_record()).expect("upsert symbol");
+
+    let profile = |profile_id: &str, recorded_at: i64| ProfileRecord {
+        profile_id: profile_id.to_owned(),
+        format: "collapsed".to_owned(),
+        source_path: "perf.folded".to_owned(),
+        recorded_at,
+        total_samples: 10,
+        mapped_samples: 8,
+    };
+    let symbol = |symbol_id: &str, self_samples: i64, total_samples: i64| SymbolSampleCount {
+        symbol_id: symbol_id.to_owned(),
+        self_samples,
+        total_samples,
+    };
+    let edge = EdgeSampleCount {
+        caller_id: "sym-main".to_owned(),
+        callee_id: "sym-1".to_owned(),
+        samples: 5,
+    };
+
+    store
+        .record_profile(
+            &profile("profile-a", 100),
+            &[symbol("sym-1", 5, 5), symbol("sym-main", 3, 8)],
+            std::slice::from_ref(&edge),
+        )
+        .expect("record profile a");
+    store
+        .record_profile(
+            &profile("profile-b", 200),
+            &[symbol("sym-1", 2, 2)],
+            std::slice::from_ref(&edge),
+        )
+        .expect("record profile b");
+    // Re-recording a profile replaces its counts.
+    store
+        .record_profile(&profile("profile-b", 200), &[symbol("sym-1", 4, 4)], &[])
+        .expect("re-record profile b");
+
+    assert_eq!(
+        store
+            .list_profiles(10)
+            .expect("list profiles")
+            .iter()
+            .map(|profile| profile.profile_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["profile-b", "profile-a"]
+    );
+    assert_eq!(
+        store.list_symbol_sample_counts().expect("symbol counts"),
+        vec![symbol("sym-1", 9, 9), symbol("sym-main", 3, 8)]
+    );
+    assert_eq!(
+        store.list_edge_sample_counts().expect("edge counts"),
+        vec![edge]
+    );
+    assert_eq!(
+        store.list_file_sample_counts().expect("file counts"),
+        vec![FileSampleCount {
+            file_path: "src/lib.rs".to_owned(),
+            samples: 9,
+        }]
+    );
+
+    store.clear_profiles().expect("clear profiles");
+    assert!(
+        store
+            .list_edge_sample_counts()
+            .expect("edge counts")
+            .is_empty()
+    );
+}
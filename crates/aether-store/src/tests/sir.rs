@@ -616,3 +616,60 @@ fn sir_request_queue_round_trip_works() {
             .is_empty()
     );
 }
+
+#[test]
+fn sir_grounding_round_trip_overwrites_previous_check() {
+    let temp = tempdir().expect("tempdir");
+    let store = SqliteStore::open(temp.path()).expect("open store");
+    store.upsert_symbol(symbol_record()).expect("upsert symbol");
+    upsert_sir_state(
+        &store,
+        "sym-1",
+        "hash-1",
+        r#"{"intent":"Run","dependencies":["ghost"]}"#,
+        1_700_000_500,
+    );
+
+    let mut record = SirGroundingRecord {
+        sir_id: "sym-1".to_owned(),
+        grounding_score: 0.25,
+        claimed_count: 2,
+        grounded_count: 0,
+        ungrounded_claims: vec!["ghost".to_owned()],
+        missing_callees: vec!["demo::helper".to_owned()],
+        regeneration_attempts: 1,
+        retry_feedback: Some("  drop ghost  ".to_owned()),
+        checked_at: 1_700_000_600,
+    };
+    store
+        .upsert_sir_grounding(&record)
+        .expect("upsert grounding");
+
+    let loaded = store
+        .get_sir_grounding("sym-1")
+        .expect("load grounding")
+        .expect("grounding row exists");
+    assert_eq!(loaded.ungrounded_claims, vec!["ghost".to_owned()]);
+    assert_eq!(loaded.missing_callees, vec!["demo::helper".to_owned()]);
+    assert_eq!(loaded.retry_feedback.as_deref(), Some("drop ghost"));
+
+    record.grounding_score = 1.0;
+    record.ungrounded_claims.clear();
+    record.retry_feedback = None;
+    store
+        .upsert_sir_grounding(&record)
+        .expect("overwrite grounding");
+    let loaded = store
+        .get_sir_grounding("sym-1")
+        .expect("reload grounding")
+        .expect("grounding row exists");
+    assert_eq!(loaded.grounding_score, 1.0);
+    assert!(loaded.ungrounded_claims.is_empty());
+    assert_eq!(loaded.retry_feedback, None);
+    assert!(
+        store
+            .get_sir_grounding("sym-missing")
+            .expect("load")
+            .is_none()
+    );
+}
@@ -7,7 +7,7 @@ fn existing_symbol_and_sir_operations_still_work_after_migration_v6() {
     let store = SqliteStore::open(temp.path()).expect("open store");
 
     let schema = store.get_schema_version().expect("schema version");
-    assert_eq!(schema.version, 18);
+    assert_eq!(schema.version, 19);
 
     store
         .upsert_symbol(SymbolRecord {
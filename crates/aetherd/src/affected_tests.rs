@@ -0,0 +1,191 @@
+//! `aetherd affected-tests`: tests that exercise the symbols changed since a
+//! git ref, with runner filters and the changes no test covers.
+
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::Path;
+
+use aether_analysis::{AffectedTestsAnalyzer, AffectedTestsReport, AffectedTestsRequest};
+use aether_store::{SqliteStore, open_surreal_graph_store_readonly};
+use anyhow::{Context, Result};
+
+use crate::cli::{AffectedTestsArgs, AffectedTestsOutputFormat};
+
+pub fn run_affected_tests_command(workspace: &Path, args: AffectedTestsArgs) -> Result<()> {
+    let analyzer =
+        AffectedTestsAnalyzer::new(workspace).context("failed to initialize analyzer")?;
+    let store = SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+    // `tested_by` links are optional; the graph may be held by the daemon or
+    // not configured at all.
+    let graph = open_surreal_graph_store_readonly(workspace).ok();
+    let report = analyzer
+        .analyze(
+            &store,
+            graph.as_ref(),
+            &AffectedTestsRequest {
+                base_ref: args.base,
+                head_ref: args.head,
+                max_depth: args.depth,
+            },
+        )
+        .context("test impact analysis failed")?;
+
+    let rendered = match args.format {
+        AffectedTestsOutputFormat::Text => render_text(&report),
+        AffectedTestsOutputFormat::Json => serde_json::to_string_pretty(&report)
+            .context("failed to serialize affected-tests report")?,
+        AffectedTestsOutputFormat::Commands => report
+            .commands
+            .iter()
+            .map(|command| command.command.as_str())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    if !rendered.is_empty() {
+        writeln!(&mut stdout, "{}", rendered.trim_end())
+            .context("failed to write affected-tests output")?;
+    }
+
+    if args.fail_on_uncovered && report.uncovered_symbols > 0 {
+        crate::daemon_rpc::exit_command(1);
+    }
+    Ok(())
+}
+
+fn render_text(report: &AffectedTestsReport) -> String {
+    let head = match report.head_commit.as_deref() {
+        Some(commit) => format!("{} ({})", report.head_ref, short_commit(commit)),
+        None => report.head_ref.clone(),
+    };
+    let mut out = format!(
+        "{} ({}) -> {head}: {} changed file(s), {} changed symbol(s), {} affected test(s)\n",
+        report.base_ref,
+        short_commit(&report.base_commit),
+        report.changed_files,
+        report.changed_symbols.len(),
+        report.tests.len()
+    );
+
+    if !report.tests.is_empty() {
+        out.push_str("\nTests:\n");
+        for test in &report.tests {
+            let detail = match (test.depth, test.confidence) {
+                (Some(depth), _) if depth > 0 => {
+                    format!("{}, {depth} hop(s)", test.reason.as_str())
+                }
+                (_, Some(confidence)) => {
+                    format!("{}, confidence {confidence:.2}", test.reason.as_str())
+                }
+                _ => test.reason.as_str().to_owned(),
+            };
+            let _ = writeln!(
+                out,
+                "  [{}] {} ({}) — {detail}; via {}",
+                test.runner.as_str(),
+                test.selector,
+                test.file_path,
+                test.triggered_by.join(", ")
+            );
+        }
+    }
+
+    if report.uncovered_symbols > 0 {
+        out.push_str("\nChanged symbols with no covering test:\n");
+        for symbol in report
+            .changed_symbols
+            .iter()
+            .filter(|symbol| symbol.uncovered)
+        {
+            let _ = writeln!(
+                out,
+                "  {} {} {} ({})",
+                symbol.change.as_str(),
+                symbol.kind,
+                symbol.qualified_name,
+                symbol.file_path
+            );
+        }
+    }
+
+    if !report.commands.is_empty() {
+        out.push_str("\nRun:\n");
+        for command in &report.commands {
+            let _ = writeln!(out, "  {}", command.command);
+        }
+    }
+    for note in &report.notes {
+        let _ = writeln!(out, "note: {note}");
+    }
+    out
+}
+
+fn short_commit(commit: &str) -> &str {
+    commit.get(..12).unwrap_or(commit)
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_analysis::{
+        AffectedTest, ChangedSymbol, RunnerCommand, SymbolChangeType, TestRunner,
+        TestSelectionReason,
+    };
+
+    use super::*;
+
+    #[test]
+    fn render_text_lists_tests_uncovered_changes_and_commands() {
+        let changed = |name: &str, covering_tests, uncovered| ChangedSymbol {
+            symbol_id: name.to_owned(),
+            qualified_name: name.to_owned(),
+            file_path: "src/parser.rs".to_owned(),
+            language: "rust".to_owned(),
+            kind: "function".to_owned(),
+            change: SymbolChangeType::Modified,
+            covering_tests,
+            uncovered,
+        };
+        let report = AffectedTestsReport {
+            schema_version: "1.0".to_owned(),
+            base_ref: "origin/main".to_owned(),
+            base_commit: "0123456789abcdef0123".to_owned(),
+            head_ref: "working tree".to_owned(),
+            head_commit: None,
+            max_depth: 4,
+            changed_files: 1,
+            changed_symbols: vec![changed("parse", 1, false), changed("unused", 0, true)],
+            uncovered_symbols: 1,
+            tests: vec![AffectedTest {
+                file_path: "src/parser.rs".to_owned(),
+                test_name: "parses_input".to_owned(),
+                intent_text: "parses input".to_owned(),
+                group_label: None,
+                language: "rust".to_owned(),
+                runner: TestRunner::Cargo,
+                package: Some("parser".to_owned()),
+                selector: "tests::parses_input".to_owned(),
+                reason: TestSelectionReason::Graph,
+                depth: Some(1),
+                confidence: None,
+                triggered_by: vec!["parse".to_owned()],
+            }],
+            commands: vec![RunnerCommand {
+                runner: "cargo-test".to_owned(),
+                package: Some("parser".to_owned()),
+                command: "cargo test -p parser -- tests::parses_input".to_owned(),
+            }],
+            notes: Vec::new(),
+        };
+
+        assert_eq!(
+            render_text(&report),
+            "origin/main (0123456789ab) -> working tree: 1 changed file(s), 2 changed symbol(s), 1 affected test(s)\n\n\
+             Tests:\n\
+             \x20 [cargo] tests::parses_input (src/parser.rs) — graph, 1 hop(s); via parse\n\n\
+             Changed symbols with no covering test:\n\
+             \x20 modified function unused (src/parser.rs)\n\n\
+             Run:\n\
+             \x20 cargo test -p parser -- tests::parses_input\n"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! `aetherd arch-check`: layering rules from `.aether/architecture.toml`
+//! checked against the dependency graph.
+
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::Path;
+
+use aether_analysis::{
+    ArchitectureAnalyzer, ArchitectureCheckResult, ArchitectureDependencyRequest,
+    ArchitectureDependencyVerdict,
+};
+use aether_store::SqliteStore;
+use anyhow::{Context, Result};
+
+use crate::cli::ArchCheckArgs;
+
+pub fn run_arch_check_command(workspace: &Path, args: ArchCheckArgs) -> Result<()> {
+    let analyzer =
+        ArchitectureAnalyzer::new(workspace).context("failed to load architecture spec")?;
+    let store = SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+
+    let (rendered, passed) = match (args.from, args.to) {
+        (Some(source), Some(target)) => {
+            let verdict = analyzer
+                .check_dependency(&store, &ArchitectureDependencyRequest { source, target })
+                .context("architecture dependency check failed")?;
+            let rendered = if args.json {
+                serde_json::to_string_pretty(&verdict)
+                    .context("failed to serialize architecture verdict")?
+            } else {
+                render_verdict(&verdict)
+            };
+            (rendered, verdict.allowed)
+        }
+        _ => {
+            let result = analyzer
+                .check(&store)
+                .context("architecture check failed")?;
+            let rendered = if args.json {
+                serde_json::to_string_pretty(&result)
+                    .context("failed to serialize architecture check")?
+            } else {
+                render_check(&result)
+            };
+            (rendered, result.violations.is_empty())
+        }
+    };
+
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    writeln!(&mut stdout, "{}", rendered.trim_end())
+        .context("failed to write arch-check output")?;
+    if !passed {
+        crate::daemon_rpc::exit_command(1);
+    }
+    Ok(())
+}
+
+fn render_check(result: &ArchitectureCheckResult) -> String {
+    let mut out = format!(
+        "checked {} edge(s) against {} layer(s): {} violation(s)\n",
+        result.edges_checked,
+        result.layers.len(),
+        result.violations.len()
+    );
+    for violation in &result.violations {
+        let location = match violation.line {
+            Some(line) => format!("{}:{line}", violation.file),
+            None => violation.file.clone(),
+        };
+        let _ = writeln!(
+            out,
+            "{location}: {} {} -> {} ({})",
+            violation.edge_kind,
+            violation.source_symbol,
+            violation.target_symbol,
+            violation.message
+        );
+    }
+    out
+}
+
+fn render_verdict(verdict: &ArchitectureDependencyVerdict) -> String {
+    format!(
+        "{}: {}",
+        if verdict.allowed {
+            "allowed"
+        } else {
+            "violation"
+        },
+        verdict.message
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_analysis::{ArchitectureRule, ArchitectureViolation};
+
+    use super::*;
+
+    #[test]
+    fn render_check_lists_violations_with_locations() {
+        let result = ArchitectureCheckResult {
+            schema_version: "1.0".to_owned(),
+            spec_path: ".aether/architecture.toml".to_owned(),
+            layers: vec!["core".to_owned(), "store".to_owned()],
+            edges_checked: 12,
+            violations: vec![ArchitectureViolation {
+                source_layer: "core".to_owned(),
+                target_layer: "store".to_owned(),
+                source_symbol: "Symbol::load".to_owned(),
+                target_symbol: "SqliteStore".to_owned(),
+                edge_kind: "type_ref".to_owned(),
+                file: "crates/core/src/lib.rs".to_owned(),
+                line: Some(42),
+                target_file: Some("crates/store/src/lib.rs".to_owned()),
+                rule: ArchitectureRule::NotAllowed,
+                message: "layer 'core' may not depend on other layers (found 'store')".to_owned(),
+            }],
+        };
+
+        assert_eq!(
+            render_check(&result),
+            "checked 12 edge(s) against 2 layer(s): 1 violation(s)\n\
+             crates/core/src/lib.rs:42: type_ref Symbol::load -> SqliteStore \
+             (layer 'core' may not depend on other layers (found 'store'))\n"
+        );
+    }
+}
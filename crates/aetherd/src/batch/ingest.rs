@@ -520,15 +520,8 @@ fn symbol_from_record(record: &aether_store::SymbolRecord) -> Result<Symbol> {
 }
 
 fn parse_language(raw: &str) -> Result<Language> {
-    match raw.trim() {
-        "rust" => Ok(Language::Rust),
-        "typescript" => Ok(Language::TypeScript),
-        "tsx" => Ok(Language::Tsx),
-        "javascript" => Ok(Language::JavaScript),
-        "jsx" => Ok(Language::Jsx),
-        "python" => Ok(Language::Python),
-        other => Err(anyhow!("unsupported symbol language '{other}'")),
-    }
+    let name = raw.trim();
+    Language::from_name(name).ok_or_else(|| anyhow!("unsupported symbol language '{name}'"))
 }
 
 fn parse_symbol_kind(raw: &str) -> Result<SymbolKind> {
@@ -542,6 +535,12 @@ fn parse_symbol_kind(raw: &str) -> Result<SymbolKind> {
         "trait" => Ok(SymbolKind::Trait),
         "interface" => Ok(SymbolKind::Interface),
         "type_alias" => Ok(SymbolKind::TypeAlias),
+        "constant" => Ok(SymbolKind::Constant),
+        "static" => Ok(SymbolKind::Static),
+        "macro" => Ok(SymbolKind::Macro),
+        "module" => Ok(SymbolKind::Module),
+        "field" => Ok(SymbolKind::Field),
+        "enum_variant" => Ok(SymbolKind::EnumVariant),
         other => Err(anyhow!("unsupported symbol kind '{other}'")),
     }
 }
@@ -670,6 +669,7 @@ vector_backend = "sqlite"
             edge_cases: None,
             complexity: None,
             method_dependencies: None,
+            extensions: Default::default(),
         }
     }
 
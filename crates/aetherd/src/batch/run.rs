@@ -86,10 +86,11 @@ fn run_build_command(workspace: &Path, config: &AetherConfig, args: &BatchBuildA
         provider.as_ref(),
     )?;
     println!(
-        "Built {} chunk(s), wrote {} request(s), skipped {}, unresolved {}",
+        "Built {} chunk(s), wrote {} request(s), skipped {}, blocked {}, unresolved {}",
         summary.files.len(),
         summary.written,
         summary.skipped,
+        summary.blocked,
         summary.unresolved_symbols
     );
     Ok(())
@@ -165,11 +166,12 @@ fn run_full_batch_command(
             provider.as_ref(),
         )?;
         println!(
-            "Built {} chunk(s) for {}: wrote {}, skipped {}, unresolved {}",
+            "Built {} chunk(s) for {}: wrote {}, skipped {}, blocked {}, unresolved {}",
             build_summary.files.len(),
             pass.as_str(),
             build_summary.written,
             build_summary.skipped,
+            build_summary.blocked,
             build_summary.unresolved_symbols
         );
 
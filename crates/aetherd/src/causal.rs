@@ -89,7 +89,7 @@ fn symbol_leaf_name(qualified_name: &str) -> &str {
 }
 
 fn write_json_to_stdout(value: &serde_json::Value) -> Result<()> {
-    let mut out = std::io::stdout();
+    let mut out = crate::daemon_rpc::command_stdout();
     serde_json::to_writer_pretty(&mut out, value).context("failed to serialize JSON output")?;
     writeln!(&mut out).context("failed to write trailing newline")?;
     Ok(())
@@ -3,11 +3,16 @@ use std::path::PathBuf;
 use std::time::Duration;
 
 use aether_analysis::RiskLevel as CouplingRiskLevel;
+use aether_analysis::{
+    DEFAULT_AFFECTED_TESTS_MAX_DEPTH, DEFAULT_FLAKY_MIN_RUNS, DEFAULT_FLAKY_WINDOW_RUNS,
+    DEFAULT_OWNERSHIP_LIMIT, DEFAULT_TRACE_COMMIT_LIMIT, DEFAULT_TRACE_MAX_FRAMES,
+    DeadCodeConfidence, GraphExportFormat, ProfileFormat, TestReportFormat,
+};
 use aether_config::{InferenceProviderKind, OLLAMA_DEFAULT_ENDPOINT, VerifyMode};
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
 use crate::init_agent::AgentPlatform;
-use crate::search::{SearchMode, SearchOutputFormat};
+use crate::search::{SearchMode, SearchOutputFormat, SirFieldFilter};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum LogFormat {
@@ -203,7 +208,7 @@ pub struct MineCouplingArgs {
 
 #[derive(Debug, Clone, PartialEq, Eq, Args)]
 pub struct BlastRadiusArgs {
-    #[arg(help = "Target file path")]
+    #[arg(help = "Target file path; prefix with `ROOT:` for a file in another workspace root")]
     pub file: String,
 
     #[arg(
@@ -213,6 +218,13 @@ pub struct BlastRadiusArgs {
         help = "Minimum risk level to include: low, medium, high, critical"
     )]
     pub min_risk: CouplingRiskLevel,
+
+    #[arg(
+        long = "root",
+        value_name = "NAME",
+        help = "Only report coupled files from the named workspace root (repeatable)"
+    )]
+    pub roots: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Args)]
@@ -348,7 +360,9 @@ pub struct TraceCauseArgs {
 
 #[derive(Debug, Clone, PartialEq, Args)]
 pub struct HealthArgs {
-    #[arg(help = "Optional section filter: critical, cycles, orphans, bottlenecks, risk-hotspots")]
+    #[arg(
+        help = "Optional section filter: critical, cycles, orphans, bottlenecks, risk-hotspots, choke-points"
+    )]
     pub filter: Option<String>,
 
     #[arg(long, default_value = "10", help = "Maximum rows per selected section")]
@@ -727,7 +741,7 @@ pub struct PresetDeleteArgs {
 #[derive(Debug, Clone, PartialEq, Eq, Args)]
 pub struct ContextArgs {
     #[arg(
-        help = "Workspace-relative file targets",
+        help = "Workspace-relative file targets, or ROOT:path for a declared root",
         value_name = "FILE",
         required_unless_present_any = ["symbol", "overview", "branch"],
         conflicts_with_all = ["symbol", "overview"]
@@ -1037,6 +1051,8 @@ pub enum ContractCommand {
     Remove(ContractRemoveArgs),
     /// Force-run contract verification
     Check(ContractCheckArgs),
+    /// Apply `.aether/contracts.toml` policies and summarize their violations
+    Policies(ContractPoliciesArgs),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Parser)]
@@ -1052,6 +1068,15 @@ pub struct ContractAddArgs {
     /// Preserves clauses
     #[arg(long = "preserves", num_args = 1)]
     pub preserves_clauses: Vec<String>,
+    /// Structural: must call a target, e.g. "audit::log within 2 hops"
+    #[arg(long = "must-call", num_args = 1)]
+    pub must_call_clauses: Vec<String>,
+    /// Structural: must have no call path to a target, e.g. "std::process::exit"
+    #[arg(long = "must-not-reach", num_args = 1)]
+    pub must_not_reach_clauses: Vec<String>,
+    /// Structural: must be covered by at least N tests (default 1)
+    #[arg(long = "must-be-tested", num_args = 0..=1, default_missing_value = "1")]
+    pub must_be_tested: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Parser)]
@@ -1072,6 +1097,509 @@ pub struct ContractCheckArgs {
     pub symbol: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Parser)]
+pub struct ContractPoliciesArgs {
+    /// Emit the per-policy summary as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct LangArgs {
+    #[command(subcommand)]
+    pub command: LangCommand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum LangCommand {
+    /// Load configured grammars and validate their queries against sample files
+    Check(LangCheckArgs),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parser)]
+pub struct LangCheckArgs {
+    /// Only check this `[[languages]]` entry
+    #[arg(long)]
+    pub language: Option<String>,
+    /// Sample source files to parse with the loaded grammars
+    pub samples: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct ArchCheckArgs {
+    #[arg(
+        long,
+        requires = "to",
+        help = "Check one proposed dependency from this symbol, qualified name or file"
+    )]
+    pub from: Option<String>,
+
+    #[arg(
+        long,
+        requires = "from",
+        help = "Target symbol, qualified name, file or import path of the proposed dependency"
+    )]
+    pub to: Option<String>,
+
+    #[arg(long, help = "Print the result as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct DeadCodeArgs {
+    #[arg(
+        long,
+        default_value = "low",
+        value_parser = parse_dead_code_confidence,
+        help = "Lowest confidence to report: low, medium or high"
+    )]
+    pub min_confidence: DeadCodeConfidence,
+
+    #[arg(long, help = "Only report symbols under this workspace-relative path")]
+    pub path: Option<String>,
+
+    #[arg(long, default_value = "50", help = "Maximum symbols to list")]
+    pub limit: usize,
+
+    #[arg(
+        long,
+        help = "Do not treat the public API as an entry point (find exports nothing uses)"
+    )]
+    pub no_public_api: bool,
+
+    #[arg(long, help = "Print the report as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReleaseCheckOutputFormat {
+    #[default]
+    Markdown,
+    Json,
+}
+
+impl ReleaseCheckOutputFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Markdown => "markdown",
+            Self::Json => "json",
+        }
+    }
+}
+
+impl std::str::FromStr for ReleaseCheckOutputFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "json" => Ok(Self::Json),
+            other => Err(format!(
+                "invalid release-check output format '{other}', expected one of: markdown, json"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct ReleaseCheckArgs {
+    #[arg(long, help = "Git ref of the last release to compare against")]
+    pub base: String,
+
+    #[arg(long, help = "Git ref to check (defaults to the working tree)")]
+    pub head: Option<String>,
+
+    #[arg(
+        long,
+        default_value_t = 0.85,
+        help = "Minimum SIR similarity for exports whose signature did not change"
+    )]
+    pub threshold: f64,
+
+    #[arg(long, help = "Only report this package (repeatable)")]
+    pub package: Vec<String>,
+
+    #[arg(
+        long,
+        default_value = "markdown",
+        value_parser = parse_release_check_output_format,
+        help = "Output format: markdown or json"
+    )]
+    pub format: ReleaseCheckOutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AffectedTestsOutputFormat {
+    #[default]
+    Text,
+    Json,
+    Commands,
+}
+
+impl AffectedTestsOutputFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Commands => "commands",
+        }
+    }
+}
+
+impl std::str::FromStr for AffectedTestsOutputFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "commands" => Ok(Self::Commands),
+            other => Err(format!(
+                "invalid affected-tests output format '{other}', expected one of: text, json, commands"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct AffectedTestsArgs {
+    #[arg(long, help = "Git ref to compare against, e.g. origin/main")]
+    pub base: String,
+
+    #[arg(long, help = "Git ref with the changes (defaults to the working tree)")]
+    pub head: Option<String>,
+
+    #[arg(
+        long,
+        default_value_t = DEFAULT_AFFECTED_TESTS_MAX_DEPTH,
+        help = "Dependency hops walked back from each changed symbol"
+    )]
+    pub depth: usize,
+
+    #[arg(
+        long,
+        default_value = "text",
+        value_parser = parse_affected_tests_output_format,
+        help = "Output format: text, json, or commands (one runner command per line)"
+    )]
+    pub format: AffectedTestsOutputFormat,
+
+    #[arg(
+        long,
+        help = "Exit with status 1 when a changed symbol has no covering test"
+    )]
+    pub fail_on_uncovered: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct TestResultsArgs {
+    #[command(subcommand)]
+    pub command: TestResultsCommand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum TestResultsCommand {
+    /// Record a JUnit, nextest, pytest or jest report in the run history
+    Ingest(TestResultsIngestArgs),
+    /// Rank tests by how often they flip between passing and failing
+    Flaky(TestResultsFlakyArgs),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct TestResultsIngestArgs {
+    #[arg(help = "Report file; relative paths resolve against the workspace")]
+    pub report: PathBuf,
+
+    #[arg(
+        long,
+        value_parser = parse_test_report_format,
+        help = "Report format: junit, nextest, pytest or jest (detected when omitted)"
+    )]
+    pub format: Option<TestReportFormat>,
+
+    #[arg(long, help = "Print the ingested run as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct TestResultsFlakyArgs {
+    #[arg(
+        long,
+        default_value_t = DEFAULT_FLAKY_WINDOW_RUNS,
+        help = "Most recent runs to consider"
+    )]
+    pub runs: u32,
+
+    #[arg(
+        long,
+        default_value_t = DEFAULT_FLAKY_MIN_RUNS,
+        help = "Minimum non-skipped results before a test is scored"
+    )]
+    pub min_runs: usize,
+
+    #[arg(long, default_value = "20", help = "Maximum tests to list")]
+    pub limit: usize,
+
+    #[arg(long, help = "Print the report as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+#[command(group(
+    clap::ArgGroup::new("report")
+        .required(true)
+        .args(["junit", "nextest", "pytest", "jest"])
+))]
+pub struct TriageArgs {
+    #[arg(long, help = "JUnit XML report")]
+    pub junit: Option<PathBuf>,
+
+    #[arg(long, help = "cargo-nextest libtest JSON report")]
+    pub nextest: Option<PathBuf>,
+
+    #[arg(long, help = "pytest --json-report output")]
+    pub pytest: Option<PathBuf>,
+
+    #[arg(long, help = "jest --json output")]
+    pub jest: Option<PathBuf>,
+
+    #[arg(
+        long,
+        default_value = "20 commits",
+        help = "Lookback window (examples: '20 commits', '14d', 'since:a1b2c3d')"
+    )]
+    pub lookback: String,
+
+    #[arg(
+        long = "depth",
+        default_value_t = 5,
+        help = "Maximum upstream traversal depth (clamped to 1..10)"
+    )]
+    pub depth: u32,
+
+    #[arg(
+        long,
+        default_value_t = 5,
+        help = "Causal candidates traced per failing test (clamped to 1..50)"
+    )]
+    pub limit: u32,
+
+    #[arg(long, help = "Do not add the report to the run history")]
+    pub no_record: bool,
+
+    #[arg(long, help = "Print the report as JSON")]
+    pub json: bool,
+}
+
+impl TriageArgs {
+    /// The report passed on the command line and its format.
+    pub fn report(&self) -> Option<(TestReportFormat, &PathBuf)> {
+        [
+            (TestReportFormat::Junit, &self.junit),
+            (TestReportFormat::Nextest, &self.nextest),
+            (TestReportFormat::Pytest, &self.pytest),
+            (TestReportFormat::Jest, &self.jest),
+        ]
+        .into_iter()
+        .find_map(|(format, path)| path.as_ref().map(|path| (format, path)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct ProfileArgs {
+    #[command(subcommand)]
+    pub command: ProfileCommand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum ProfileCommand {
+    /// Map a collapsed-stack, pprof or speedscope profile onto indexed symbols
+    Ingest(ProfileIngestArgs),
+    /// List the symbols with the most samples across ingested profiles
+    Hot(ProfileHotArgs),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct ProfileIngestArgs {
+    #[arg(help = "Profile file; relative paths resolve against the workspace")]
+    pub profile: PathBuf,
+
+    #[arg(
+        long,
+        value_parser = parse_profile_format,
+        help = "Profile format: collapsed, pprof or speedscope (detected when omitted)"
+    )]
+    pub format: Option<ProfileFormat>,
+
+    #[arg(long, help = "Drop previously ingested profiles first")]
+    pub replace: bool,
+
+    #[arg(long, help = "Print the ingested profile as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct ProfileHotArgs {
+    #[arg(long, default_value = "20", help = "Maximum symbols to list")]
+    pub limit: usize,
+
+    #[arg(long, help = "Print the report as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct ExplainTraceArgs {
+    #[arg(help = "File holding the trace; reads stdin when omitted")]
+    pub trace: Option<PathBuf>,
+
+    #[arg(
+        long,
+        default_value_t = DEFAULT_TRACE_MAX_FRAMES,
+        help = "Resolved frames to explain, innermost first"
+    )]
+    pub max_frames: usize,
+
+    #[arg(
+        long = "commits",
+        default_value_t = DEFAULT_TRACE_COMMIT_LIMIT,
+        help = "Likely responsible commits to list"
+    )]
+    pub commit_limit: usize,
+
+    #[arg(long, help = "Print the explanation as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct OwnershipArgs {
+    #[arg(
+        long,
+        conflicts_with = "symbol",
+        help = "Report the symbols of this file"
+    )]
+    pub file: Option<String>,
+
+    #[arg(long, help = "Report one symbol, by id or qualified name")]
+    pub symbol: Option<String>,
+
+    #[arg(
+        long,
+        default_value_t = DEFAULT_OWNERSHIP_LIMIT,
+        help = "Communities to list without --file or --symbol, lowest bus factor first"
+    )]
+    pub limit: usize,
+
+    #[arg(long, help = "Print the ownership report as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct DocsArgs {
+    #[command(subcommand)]
+    pub command: DocsCommand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum DocsCommand {
+    /// Render SIR rollups, symbols, communities and contracts into an mdBook site
+    Build(DocsBuildArgs),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct DocsBuildArgs {
+    #[arg(
+        long,
+        default_value = "site",
+        help = "Output directory; relative paths resolve against the workspace"
+    )]
+    pub out: PathBuf,
+
+    #[arg(long, help = "Book title (defaults to the workspace directory name)")]
+    pub title: Option<String>,
+
+    #[arg(
+        long,
+        help = "Rewrite every page, even those unchanged since the last build"
+    )]
+    pub force: bool,
+
+    #[arg(long, help = "Print the build summary as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct GraphArgs {
+    #[command(subcommand)]
+    pub command: GraphCommand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum GraphCommand {
+    /// Export the dependency graph as DOT, GraphML, Mermaid or JSON Graph Format
+    Export(GraphExportArgs),
+    /// Find dependency paths with the graph query language
+    Query(GraphQueryArgs),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct GraphExportArgs {
+    #[arg(
+        long,
+        default_value = "dot",
+        value_parser = parse_graph_export_format,
+        help = "Output format: dot, graphml, mermaid or json"
+    )]
+    pub format: GraphExportFormat,
+
+    #[arg(
+        long,
+        help = "Write to this file instead of stdout; relative paths resolve against the workspace"
+    )]
+    pub out: Option<PathBuf>,
+
+    #[arg(long, help = "Keep only symbols in this community")]
+    pub community: Option<i64>,
+
+    #[arg(long, help = "Keep only symbols whose file matches this glob")]
+    pub path: Option<String>,
+
+    #[arg(
+        long = "edge-kind",
+        value_delimiter = ',',
+        value_name = "KIND",
+        help = "Edge kinds to keep: calls, depends_on, type_ref, implements, endpoint, references"
+    )]
+    pub edge_kinds: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Export the neighborhood of this symbol id or qualified name"
+    )]
+    pub root: Option<String>,
+
+    #[arg(
+        long,
+        requires = "root",
+        help = "Hops from --root, following edges in either direction (default 2)"
+    )]
+    pub depth: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct GraphQueryArgs {
+    #[arg(help = "Path query, e.g. \"from kind = function to name = db::execute\"")]
+    pub query: String,
+
+    #[arg(
+        long,
+        help = "Maximum paths to print, overriding the query's `limit` (clamped to 1..200)"
+    )]
+    pub limit: Option<usize>,
+
+    #[arg(long, help = "Print the result as JSON")]
+    pub json: bool,
+}
+
 #[derive(Debug, Clone, PartialEq, Subcommand)]
 pub enum Commands {
     /// Batch indexing operations
@@ -1144,6 +1672,30 @@ pub enum Commands {
     Seismograph(SeismographArgs),
     /// Manage and verify semantic contracts on symbols
     Contract(ContractArgs),
+    /// Inspect runtime-loaded language grammars
+    Lang(LangArgs),
+    /// Check declared architecture layers against the dependency graph
+    ArchCheck(ArchCheckArgs),
+    /// Compare the exported API surface against a release ref
+    ReleaseCheck(ReleaseCheckArgs),
+    /// List symbols unreachable from entry points
+    DeadCode(DeadCodeArgs),
+    /// Select the tests affected by changes since a git ref
+    AffectedTests(AffectedTestsArgs),
+    /// Ingest test reports and score flaky tests
+    TestResults(TestResultsArgs),
+    /// Rank the upstream changes most likely behind each failing test
+    Triage(TriageArgs),
+    /// Ingest runtime profiles and list the hottest symbols
+    Profile(ProfileArgs),
+    /// Map a panic backtrace or stack trace to symbols, SIRs and recent commits
+    ExplainTrace(ExplainTraceArgs),
+    /// Show code owners and bus factor per symbol and community
+    Ownership(OwnershipArgs),
+    /// Generate a static documentation site from SIRs and the dependency graph
+    Docs(DocsArgs),
+    /// Export the dependency graph for external tools
+    Graph(GraphArgs),
 }
 
 #[derive(Debug, Clone, Parser)]
@@ -1243,6 +1795,22 @@ pub struct Cli {
     )]
     pub output: SearchOutputFormat,
 
+    #[arg(
+        long = "sir-field",
+        value_name = "NAME=VALUE",
+        requires = "search",
+        help = "Only keep results whose custom SIR field matches (repeatable)"
+    )]
+    pub sir_field: Vec<SirFieldFilter>,
+
+    #[arg(
+        long = "root",
+        value_name = "NAME",
+        requires = "search",
+        help = "Only keep results from the named workspace root; the primary workspace uses its directory name (repeatable)"
+    )]
+    pub search_root: Vec<String>,
+
     #[arg(
         long,
         conflicts_with_all = ["search", "lsp", "index", "verify"],
@@ -1387,6 +1955,10 @@ fn parse_log_format(value: &str) -> Result<LogFormat, String> {
     value.parse()
 }
 
+fn parse_dead_code_confidence(value: &str) -> Result<DeadCodeConfidence, String> {
+    value.parse()
+}
+
 fn parse_coupling_risk_level(value: &str) -> Result<CouplingRiskLevel, String> {
     match value.trim() {
         "low" => Ok(CouplingRiskLevel::Low),
@@ -1411,6 +1983,26 @@ fn parse_refactor_prep_output_format(value: &str) -> Result<RefactorPrepOutputFo
     value.parse()
 }
 
+fn parse_release_check_output_format(value: &str) -> Result<ReleaseCheckOutputFormat, String> {
+    value.parse()
+}
+
+fn parse_affected_tests_output_format(value: &str) -> Result<AffectedTestsOutputFormat, String> {
+    value.parse()
+}
+
+fn parse_test_report_format(value: &str) -> Result<TestReportFormat, String> {
+    value.parse()
+}
+
+fn parse_profile_format(value: &str) -> Result<ProfileFormat, String> {
+    value.parse()
+}
+
+fn parse_graph_export_format(value: &str) -> Result<GraphExportFormat, String> {
+    value.parse()
+}
+
 fn parse_since_duration(value: &str) -> Result<Duration, String> {
     let trimmed = value.trim().to_ascii_lowercase();
     if trimmed.len() < 2 {
@@ -1443,14 +2035,17 @@ fn parse_since_duration(value: &str) -> Result<Duration, String> {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
     use std::time::Duration;
 
     use aether_config::OLLAMA_DEFAULT_ENDPOINT;
     use clap::Parser;
 
     use super::{
-        Cli, Commands, ContinuousCommand, PresetCommand, PresetShowArgs, SeismographCommand,
-        SeismographRunOnceArgs, SeismographStatusArgs, parse_since_duration,
+        Cli, Commands, ContinuousCommand, ContractArgs, ContractCommand, DEFAULT_OWNERSHIP_LIMIT,
+        DocsArgs, DocsCommand, GraphArgs, GraphCommand, GraphExportFormat, PresetCommand,
+        PresetShowArgs, ProfileArgs, ProfileCommand, ProfileFormat, SeismographCommand,
+        SeismographRunOnceArgs, SeismographStatusArgs, TestReportFormat, parse_since_duration,
     };
     use crate::init_agent::AgentPlatform;
 
@@ -2620,6 +3215,206 @@ mod tests {
         }
     }
 
+    #[test]
+    fn arch_check_requires_both_dependency_endpoints() {
+        let cli = Cli::try_parse_from([
+            "aetherd",
+            "arch-check",
+            "--from",
+            "crates/core/src/lib.rs",
+            "--to",
+            "aether_store::SqliteStore",
+        ])
+        .expect("arch-check should parse");
+        match cli.command {
+            Some(Commands::ArchCheck(args)) => {
+                assert_eq!(args.from.as_deref(), Some("crates/core/src/lib.rs"));
+                assert_eq!(args.to.as_deref(), Some("aether_store::SqliteStore"));
+                assert!(!args.json);
+            }
+            other => panic!("unexpected command: {other:?}"),
+        }
+
+        assert!(Cli::try_parse_from(["aetherd", "arch-check", "--from", "src/lib.rs"]).is_err());
+    }
+
+    #[test]
+    fn contract_add_parses_structural_clauses() {
+        let cli = Cli::try_parse_from([
+            "aetherd",
+            "contract",
+            "add",
+            "payments::charge",
+            "--must-call",
+            "audit::log within 2 hops",
+            "--must-not-reach",
+            "std::process::exit",
+            "--must-be-tested",
+        ])
+        .expect("contract add should parse");
+        let Some(Commands::Contract(ContractArgs {
+            command: ContractCommand::Add(args),
+        })) = cli.command
+        else {
+            panic!("unexpected command");
+        };
+        assert_eq!(args.must_call_clauses, vec!["audit::log within 2 hops"]);
+        assert_eq!(args.must_not_reach_clauses, vec!["std::process::exit"]);
+        assert_eq!(args.must_be_tested, Some(1));
+        assert!(args.must_clauses.is_empty());
+    }
+
+    #[test]
+    fn triage_requires_exactly_one_report() {
+        let cli = Cli::try_parse_from(["aetherd", "triage", "--junit", "target/report.xml"])
+            .expect("triage should parse");
+        match cli.command {
+            Some(Commands::Triage(args)) => {
+                assert_eq!(
+                    args.report(),
+                    Some((TestReportFormat::Junit, &PathBuf::from("target/report.xml")))
+                );
+                assert_eq!(args.lookback, "20 commits");
+                assert!(!args.no_record);
+            }
+            other => panic!("unexpected command: {other:?}"),
+        }
+
+        assert!(Cli::try_parse_from(["aetherd", "triage"]).is_err());
+        assert!(
+            Cli::try_parse_from(["aetherd", "triage", "--junit", "a.xml", "--jest", "b.json"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn profile_ingest_parses_format_override() {
+        let cli = Cli::try_parse_from([
+            "aetherd",
+            "profile",
+            "ingest",
+            "target/cpu.pb.gz",
+            "--format",
+            "pprof",
+            "--replace",
+        ])
+        .expect("profile ingest should parse");
+        let Some(Commands::Profile(ProfileArgs {
+            command: ProfileCommand::Ingest(args),
+        })) = cli.command
+        else {
+            panic!("unexpected command");
+        };
+        assert_eq!(args.profile, PathBuf::from("target/cpu.pb.gz"));
+        assert_eq!(args.format, Some(ProfileFormat::Pprof));
+        assert!(args.replace);
+
+        assert!(
+            Cli::try_parse_from(["aetherd", "profile", "ingest", "a.txt", "--format", "gprof"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn ownership_rejects_file_with_symbol() {
+        let cli = Cli::try_parse_from(["aetherd", "ownership", "--file", "src/lib.rs"])
+            .expect("ownership should parse");
+        let Some(Commands::Ownership(args)) = cli.command else {
+            panic!("unexpected command");
+        };
+        assert_eq!(args.file.as_deref(), Some("src/lib.rs"));
+        assert_eq!(args.limit, DEFAULT_OWNERSHIP_LIMIT);
+
+        assert!(
+            Cli::try_parse_from([
+                "aetherd",
+                "ownership",
+                "--file",
+                "src/lib.rs",
+                "--symbol",
+                "crate::run"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn docs_build_defaults_to_site_directory() {
+        let cli =
+            Cli::try_parse_from(["aetherd", "docs", "build"]).expect("docs build should parse");
+        let Some(Commands::Docs(DocsArgs {
+            command: DocsCommand::Build(args),
+        })) = cli.command
+        else {
+            panic!("unexpected command");
+        };
+        assert_eq!(args.out, PathBuf::from("site"));
+        assert!(!args.force);
+
+        let cli = Cli::try_parse_from(["aetherd", "docs", "build", "--out", "public/docs"])
+            .expect("docs build --out should parse");
+        let Some(Commands::Docs(DocsArgs {
+            command: DocsCommand::Build(args),
+        })) = cli.command
+        else {
+            panic!("unexpected command");
+        };
+        assert_eq!(args.out, PathBuf::from("public/docs"));
+    }
+
+    #[test]
+    fn graph_export_parses_filters() {
+        let cli = Cli::try_parse_from([
+            "aetherd",
+            "graph",
+            "export",
+            "--format",
+            "graphml",
+            "--edge-kind",
+            "calls,type_ref",
+            "--root",
+            "crate::run",
+            "--depth",
+            "3",
+        ])
+        .expect("graph export should parse");
+        let Some(Commands::Graph(GraphArgs {
+            command: GraphCommand::Export(args),
+        })) = cli.command
+        else {
+            panic!("unexpected command");
+        };
+        assert_eq!(args.format, GraphExportFormat::Graphml);
+        assert_eq!(args.edge_kinds, vec!["calls", "type_ref"]);
+        assert_eq!(args.root.as_deref(), Some("crate::run"));
+        assert_eq!(args.depth, Some(3));
+
+        assert!(Cli::try_parse_from(["aetherd", "graph", "export", "--depth", "3"]).is_err());
+    }
+
+    #[test]
+    fn graph_query_parses_query_and_limit() {
+        let cli = Cli::try_parse_from([
+            "aetherd",
+            "graph",
+            "query",
+            "from kind = function to name = db::execute",
+            "--limit",
+            "5",
+            "--json",
+        ])
+        .expect("graph query should parse");
+        let Some(Commands::Graph(GraphArgs {
+            command: GraphCommand::Query(args),
+        })) = cli.command
+        else {
+            panic!("unexpected command");
+        };
+        assert_eq!(args.query, "from kind = function to name = db::execute");
+        assert_eq!(args.limit, Some(5));
+        assert!(args.json);
+    }
+
     #[test]
     fn parse_since_duration_rejects_invalid_unit() {
         let err = parse_since_duration("7w").expect_err("expected error");
@@ -0,0 +1,334 @@
+//! PageRank and Louvain communities kept between continuous runs, so a cycle
+//! that only touched a few files updates them from the edge delta instead of
+//! recomputing over the whole graph. Incremental updates accumulate small
+//! errors (and local repair never splits a community), so a full recompute
+//! runs every `graph_full_recompute_every` updates or when too much changed.
+//!
+//! Only the continuous monitor and the seismograph read this state. Health
+//! scoring in `aether-analysis` still ranks the graph it loads from scratch.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aether_config::{ContinuousConfig, aether_dir};
+use aether_graph_algo::{
+    GraphAlgorithmEdge, incremental_page_rank_sync, louvain_repair_sync,
+    louvain_with_resolution_sync,
+};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+const GRAPH_STATE_VERSION: u32 = 1;
+const PAGERANK_DAMPING: f64 = 0.85;
+const PAGERANK_TOLERANCE: f64 = 1e-6;
+const COMMUNITY_RESOLUTION: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum GraphUpdateMode {
+    #[default]
+    Full,
+    Incremental,
+    Unchanged,
+}
+
+impl GraphUpdateMode {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Incremental => "incremental",
+            Self::Unchanged => "unchanged",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct GraphMetrics {
+    pub pagerank: HashMap<String, f64>,
+    pub communities: HashMap<String, usize>,
+    pub mode: GraphUpdateMode,
+    pub changed_edges: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct GraphState {
+    version: u32,
+    computed_at: i64,
+    updates_since_full: u32,
+    /// Sorted `(source, target, kind)`; parallel edges kept.
+    edges: Vec<(String, String, String)>,
+    /// Unnormalised PageRank, see `incremental_page_rank_sync`.
+    reach: BTreeMap<String, f64>,
+    communities: BTreeMap<String, usize>,
+}
+
+/// Brings the persisted graph state up to date with `edges` and returns the
+/// metrics for them.
+pub(crate) fn refresh_graph_metrics(
+    workspace: &Path,
+    continuous: &ContinuousConfig,
+    edges: &[GraphAlgorithmEdge],
+) -> Result<GraphMetrics> {
+    let mut current = edges
+        .iter()
+        .map(|edge| {
+            (
+                edge.source_id.clone(),
+                edge.target_id.clone(),
+                edge.edge_kind.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    current.sort();
+
+    let previous = load_graph_state(workspace);
+    if let Some(state) = previous.as_ref()
+        && state.edges == current
+    {
+        return Ok(metrics_from_state(state, GraphUpdateMode::Unchanged, 0));
+    }
+
+    let (removed, added) = previous
+        .as_ref()
+        .map(|state| edge_delta(&state.edges, &current))
+        .unwrap_or_default();
+    let changed_edges = removed.len() + added.len();
+    let full = match previous.as_ref() {
+        None => true,
+        Some(state) => {
+            state.updates_since_full.saturating_add(1) >= continuous.graph_full_recompute_every
+                || changed_edges as f64
+                    > continuous.graph_max_incremental_delta
+                        * state.edges.len().max(current.len()) as f64
+        }
+    };
+
+    let state = match previous {
+        Some(previous) if !full => {
+            let seeds = removed
+                .iter()
+                .chain(&added)
+                .flat_map(|(source, target, _)| [source.clone(), target.clone()])
+                .collect::<HashSet<_>>();
+            let previous_edges = algorithm_edges(&previous.edges);
+            let previous_reach = previous.reach.into_iter().collect::<HashMap<_, _>>();
+            let previous_communities = previous.communities.into_iter().collect::<HashMap<_, _>>();
+            let pagerank = incremental_page_rank_sync(
+                &previous_edges,
+                &previous_reach,
+                edges,
+                PAGERANK_DAMPING,
+                PAGERANK_TOLERANCE,
+            );
+            tracing::debug!(
+                changed_edges,
+                pushes = pagerank.pushes,
+                "incremental graph metrics update"
+            );
+            GraphState {
+                version: GRAPH_STATE_VERSION,
+                computed_at: crate::time::current_unix_timestamp_secs(),
+                updates_since_full: previous.updates_since_full + 1,
+                edges: current,
+                reach: pagerank.reach.into_iter().collect(),
+                communities: louvain_repair_sync(
+                    edges,
+                    &previous_communities,
+                    &seeds,
+                    COMMUNITY_RESOLUTION,
+                )
+                .into_iter()
+                .collect(),
+            }
+        }
+        _ => GraphState {
+            version: GRAPH_STATE_VERSION,
+            computed_at: crate::time::current_unix_timestamp_secs(),
+            updates_since_full: 0,
+            edges: current,
+            reach: incremental_page_rank_sync(
+                &[],
+                &HashMap::new(),
+                edges,
+                PAGERANK_DAMPING,
+                PAGERANK_TOLERANCE,
+            )
+            .reach
+            .into_iter()
+            .collect(),
+            communities: louvain_with_resolution_sync(edges, COMMUNITY_RESOLUTION)
+                .into_iter()
+                .collect(),
+        },
+    };
+    write_graph_state(workspace, &state)?;
+
+    let mode = if full {
+        GraphUpdateMode::Full
+    } else {
+        GraphUpdateMode::Incremental
+    };
+    Ok(metrics_from_state(&state, mode, changed_edges))
+}
+
+fn metrics_from_state(
+    state: &GraphState,
+    mode: GraphUpdateMode,
+    changed_edges: usize,
+) -> GraphMetrics {
+    let total = state.reach.values().sum::<f64>();
+    GraphMetrics {
+        pagerank: state
+            .reach
+            .iter()
+            .map(|(symbol_id, reach)| (symbol_id.clone(), reach / total))
+            .collect(),
+        communities: state
+            .communities
+            .iter()
+            .map(|(symbol_id, community)| (symbol_id.clone(), *community))
+            .collect(),
+        mode,
+        changed_edges,
+    }
+}
+
+type StateEdge = (String, String, String);
+
+/// Multiset difference of two sorted edge lists: `(removed, added)`.
+fn edge_delta(previous: &[StateEdge], current: &[StateEdge]) -> (Vec<StateEdge>, Vec<StateEdge>) {
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let (mut left, mut right) = (0, 0);
+    while left < previous.len() || right < current.len() {
+        match (previous.get(left), current.get(right)) {
+            (Some(old), Some(new)) if old == new => {
+                left += 1;
+                right += 1;
+            }
+            (Some(old), Some(new)) if old < new => {
+                removed.push(old.clone());
+                left += 1;
+            }
+            (Some(old), None) => {
+                removed.push(old.clone());
+                left += 1;
+            }
+            (_, Some(new)) => {
+                added.push(new.clone());
+                right += 1;
+            }
+            (None, None) => break,
+        }
+    }
+    (removed, added)
+}
+
+fn algorithm_edges(edges: &[StateEdge]) -> Vec<GraphAlgorithmEdge> {
+    edges
+        .iter()
+        .map(|(source_id, target_id, edge_kind)| GraphAlgorithmEdge {
+            source_id: source_id.clone(),
+            target_id: target_id.clone(),
+            edge_kind: edge_kind.clone(),
+        })
+        .collect()
+}
+
+fn graph_state_path(workspace: &Path) -> PathBuf {
+    aether_dir(workspace)
+        .join("continuous")
+        .join("graph_state.json")
+}
+
+/// A missing, unreadable or outdated state only costs a full recompute.
+fn load_graph_state(workspace: &Path) -> Option<GraphState> {
+    let path = graph_state_path(workspace);
+    let raw = fs::read_to_string(&path).ok()?;
+    match serde_json::from_str::<GraphState>(&raw) {
+        Ok(state) if state.version == GRAPH_STATE_VERSION => Some(state),
+        Ok(_) => None,
+        Err(err) => {
+            tracing::warn!(
+                path = %path.display(),
+                "discarding unreadable continuous graph state: {err}"
+            );
+            None
+        }
+    }
+}
+
+fn write_graph_state(workspace: &Path, state: &GraphState) -> Result<()> {
+    let path = graph_state_path(workspace);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let raw = serde_json::to_string(state)?;
+    fs::write(&path, raw).with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_config::ContinuousConfig;
+    use aether_graph_algo::GraphAlgorithmEdge;
+    use tempfile::tempdir;
+
+    use super::{GraphUpdateMode, refresh_graph_metrics};
+
+    fn edge(source: &str, target: &str) -> GraphAlgorithmEdge {
+        GraphAlgorithmEdge {
+            source_id: source.to_owned(),
+            target_id: target.to_owned(),
+            edge_kind: "calls".to_owned(),
+        }
+    }
+
+    #[test]
+    fn refresh_updates_incrementally_between_full_recomputes() {
+        let temp = tempdir().expect("tempdir");
+        let continuous = ContinuousConfig {
+            graph_full_recompute_every: 2,
+            graph_max_incremental_delta: 0.5,
+            ..ContinuousConfig::default()
+        };
+        let mut edges = vec![
+            edge("a", "b"),
+            edge("b", "c"),
+            edge("c", "a"),
+            edge("x", "y"),
+            edge("y", "z"),
+            edge("z", "x"),
+        ];
+
+        let first = refresh_graph_metrics(temp.path(), &continuous, &edges).expect("first");
+        assert_eq!(first.mode, GraphUpdateMode::Full);
+        assert_eq!(first.pagerank.len(), 6);
+        assert_eq!(first.communities["a"], first.communities["c"]);
+
+        let again = refresh_graph_metrics(temp.path(), &continuous, &edges).expect("again");
+        assert_eq!(again.mode, GraphUpdateMode::Unchanged);
+        assert_eq!(again.communities, first.communities);
+
+        edges.push(edge("c", "d"));
+        let grown = refresh_graph_metrics(temp.path(), &continuous, &edges).expect("grown");
+        assert_eq!(
+            (grown.mode, grown.changed_edges),
+            (GraphUpdateMode::Incremental, 1)
+        );
+        assert!(grown.pagerank["d"] > 0.0);
+        assert!((grown.pagerank.values().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        edges.push(edge("d", "e"));
+        let cadence = refresh_graph_metrics(temp.path(), &continuous, &edges).expect("cadence");
+        assert_eq!(cadence.mode, GraphUpdateMode::Full);
+
+        edges.truncate(2);
+        let rewired = refresh_graph_metrics(temp.path(), &continuous, &edges).expect("rewired");
+        assert_eq!(
+            (rewired.mode, rewired.changed_edges),
+            (GraphUpdateMode::Full, 6)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+mod graph_state;
 mod math;
 mod monitor;
 mod priority;
@@ -11,6 +12,7 @@ use anyhow::{Result, anyhow};
 
 use crate::cli::{BatchPass, ContinuousArgs, ContinuousCommand};
 
+pub(crate) use graph_state::refresh_graph_metrics;
 pub(crate) use math::cosine_distance_from_embeddings;
 pub(crate) use monitor::{ContinuousStatusSnapshot, load_status_snapshot, run_monitor_once};
 
@@ -129,6 +131,14 @@ fn print_status_snapshot(status: &ContinuousStatusSnapshot) {
     println!("Ingested results:        {}", status.ingested_results);
     println!("Fingerprint rows:        {}", status.fingerprint_rows);
     println!("Requeue pass:            {}", status.requeue_pass);
+    if let Some(mode) = status.graph_update {
+        println!(
+            "Graph update:            {} ({} edge change(s))",
+            mode.as_str(),
+            status.graph_changed_edges
+        );
+        println!("Communities:             {}", status.communities);
+    }
     if let Some(error) = status.last_error.as_deref() {
         println!("Last error:              {error}");
     }
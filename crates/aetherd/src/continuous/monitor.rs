@@ -6,7 +6,7 @@ use std::time::UNIX_EPOCH;
 
 use aether_config::{AetherConfig, GraphBackend, aether_dir};
 use aether_core::{GitContext, Symbol};
-use aether_graph_algo::{GraphAlgorithmEdge, page_rank_sync};
+use aether_graph_algo::GraphAlgorithmEdge;
 use aether_health::git_signals::compute_file_git_stats;
 use aether_store::{
     SirStateStore, SqliteStore, SurrealGraphStore, block_on_store_future,
@@ -17,6 +17,7 @@ use petgraph::Direction;
 use petgraph::graph::{DiGraph, NodeIndex};
 use serde::{Deserialize, Serialize};
 
+use super::graph_state::{GraphMetrics, GraphUpdateMode, refresh_graph_metrics};
 use super::priority::compute_priority;
 use super::staleness::{compute_staleness, effective_age, time_staleness};
 use super::{ensure_supported_schedule, parse_requeue_pass, resolve_continuous_config};
@@ -69,6 +70,12 @@ pub(crate) struct ContinuousStatusSnapshot {
     pub ingested_results: usize,
     pub fingerprint_rows: usize,
     pub requeue_pass: String,
+    #[serde(default)]
+    pub graph_update: Option<GraphUpdateMode>,
+    #[serde(default)]
+    pub graph_changed_edges: usize,
+    #[serde(default)]
+    pub communities: usize,
     pub last_error: Option<String>,
 }
 
@@ -134,7 +141,7 @@ fn run_monitor_once_inner(
     let mut candidate_ids = current_symbols.keys().cloned().collect::<Vec<_>>();
     candidate_ids.sort();
 
-    let scored_rows = score_symbols(
+    let (scored_rows, graph_metrics) = score_symbols(
         workspace,
         &store,
         &current_symbols,
@@ -276,6 +283,13 @@ fn run_monitor_once_inner(
         ingested_results,
         fingerprint_rows,
         requeue_pass: continuous.requeue_pass.clone(),
+        graph_update: Some(graph_metrics.mode),
+        graph_changed_edges: graph_metrics.changed_edges,
+        communities: graph_metrics
+            .communities
+            .values()
+            .collect::<HashSet<_>>()
+            .len(),
         last_error: None,
     };
     write_status_snapshot(workspace, &snapshot)?;
@@ -292,7 +306,7 @@ fn score_symbols(
     continuous: &aether_config::ContinuousConfig,
     runtime: &crate::batch::BatchRuntimeConfig,
     last_successful_at: Option<i64>,
-) -> Result<Vec<SymbolScoreRow>> {
+) -> Result<(Vec<SymbolScoreRow>, GraphMetrics)> {
     let graph_edges = store
         .list_graph_dependency_edges()
         .context("failed to load dependency edges for continuous monitor")?;
@@ -306,9 +320,9 @@ fn score_symbols(
             edge_kind: edge.edge_kind.clone(),
         })
         .collect::<Vec<_>>();
-    let pagerank_by_symbol = page_rank_sync(&pagerank_edges, 0.85, 25)
-        .into_iter()
-        .collect::<HashMap<_, _>>();
+    let graph_metrics = refresh_graph_metrics(workspace, continuous, &pagerank_edges)
+        .context("failed to refresh graph metrics for continuous monitor")?;
+    let pagerank_by_symbol = &graph_metrics.pagerank;
     let pr_max = pagerank_by_symbol.values().copied().fold(0.0_f64, f64::max);
 
     let churn_by_file = compute_git_churn_by_file(workspace, symbols_by_id);
@@ -442,7 +456,7 @@ fn score_symbols(
         });
     }
 
-    Ok(rows)
+    Ok((rows, graph_metrics))
 }
 
 fn compute_git_churn_by_file(
@@ -3,20 +3,39 @@
 /// Developers or agents declare behavioral expectations on symbols
 /// (must/must_not/preserves clauses), which are verified on every
 /// SIR regeneration using a two-stage cascade: embedding cosine
-/// pre-filter followed by LLM judge for ambiguous cases.
+/// pre-filter followed by LLM judge for ambiguous cases. Structural
+/// clauses (must_call/must_not_reach/must_be_tested) are graph predicates
+/// evaluated exactly against the graph store instead. Policies in
+/// `.aether/contracts.toml` apply clauses to every symbol a selector matches.
 mod judge;
+mod policy;
+mod structural;
 mod verify;
 
+use std::io::Write;
 use std::path::Path;
 
 use aether_config::AetherConfig;
 use aether_infer::{EmbeddingProviderOverrides, load_embedding_provider_from_config};
-use aether_store::{SirStateStore, SqliteStore};
+use aether_parse::SourceContract;
+use aether_store::{
+    DeclaredContract, SOURCE_CONTRACT_CREATOR, SirStateStore, SourceContractSync, SqliteStore,
+};
 use anyhow::{Context, Result, anyhow};
 
+pub use policy::{
+    CONTRACT_POLICY_PATH, ContractPolicySpec, PolicyStatus, policy_status,
+    refresh_policy_contracts, sync_contract_policies,
+};
+pub use structural::{
+    GraphEvidence, StructuralClause, StructuralEvidence, StructuralOutcome,
+    is_structural_clause_type,
+};
 pub use verify::{ClauseResult, ClauseStatus, ContractVerifier, VerificationResult};
 
-use crate::cli::{ContractAddArgs, ContractArgs, ContractCheckArgs, ContractCommand};
+use crate::cli::{
+    ContractAddArgs, ContractArgs, ContractCheckArgs, ContractCommand, ContractPoliciesArgs,
+};
 
 /// CLI dispatch for `aetherd contract` subcommands.
 pub fn run_contract_command(
@@ -29,6 +48,7 @@ pub fn run_contract_command(
         ContractCommand::List(list_args) => run_list(workspace, list_args.symbol.as_deref()),
         ContractCommand::Remove(remove_args) => run_remove(workspace, remove_args.contract_id),
         ContractCommand::Check(check_args) => run_check(workspace, config, &check_args),
+        ContractCommand::Policies(policies_args) => run_policies(workspace, &policies_args),
     }
 }
 
@@ -58,6 +78,9 @@ pub fn verify_symbol_contracts(
         }
     };
 
+    // The SIR just changed, so SIR-predicate policies may now select the symbol.
+    refresh_policy_contracts(store, workspace_root, &[symbol_id.to_owned()]);
+
     let active_contracts = store
         .list_active_contracts_for_symbol(symbol_id)
         .with_context(|| format!("failed to load contracts for {symbol_id}"))?;
@@ -86,8 +109,52 @@ pub fn verify_symbol_contracts(
     let mut passed = 0_usize;
     let mut failed = 0_usize;
     let mut ambiguous = 0_usize;
+    let mut graph_evidence = None;
 
     for contract in &active_contracts {
+        if is_structural_clause_type(&contract.clause_type) {
+            let evidence = graph_evidence.get_or_insert_with(|| {
+                GraphEvidence::open(store, workspace_root).map_err(|err| err.to_string())
+            });
+            let outcome = verify_structural_clause(
+                store,
+                symbol_id,
+                &contract.clause_type,
+                &contract.clause_text,
+                evidence.as_ref(),
+            );
+
+            apply_leaky_bucket(
+                store,
+                contract.id,
+                symbol_id,
+                sir_version,
+                &outcome.status,
+                contract.violation_streak,
+                contracts_config.streak_threshold,
+                &contract.clause_text,
+                "structural_fail",
+                Some(outcome.reason.as_str()),
+            )?;
+
+            match outcome.status {
+                ClauseStatus::Pass => passed += 1,
+                ClauseStatus::Fail => failed += 1,
+                ClauseStatus::Ambiguous => ambiguous += 1,
+            }
+
+            clause_results.push(ClauseResult {
+                contract_id: contract.id,
+                clause_text: contract.clause_text.clone(),
+                clause_type: contract.clause_type.clone(),
+                status: outcome.status,
+                similarity: None,
+                judge_reason: None,
+                structural_reason: Some(outcome.reason),
+            });
+            continue;
+        }
+
         let clause_embedding = contract
             .clause_embedding_json
             .as_deref()
@@ -130,6 +197,11 @@ pub fn verify_symbol_contracts(
             contract.violation_streak,
             contracts_config.streak_threshold,
             &contract.clause_text,
+            if judge_reason.is_some() {
+                "llm_judge_fail"
+            } else {
+                "embedding_fail"
+            },
             judge_reason.as_deref(),
         )?;
 
@@ -146,6 +218,7 @@ pub fn verify_symbol_contracts(
             status,
             similarity: Some(similarity),
             judge_reason,
+            structural_reason: None,
         });
     }
 
@@ -159,6 +232,80 @@ pub fn verify_symbol_contracts(
     })
 }
 
+/// Evaluate one structural clause against the graph. Graph or parse
+/// failures leave the clause ambiguous rather than failing verification.
+fn verify_structural_clause(
+    store: &SqliteStore,
+    symbol_id: &str,
+    clause_type: &str,
+    clause_text: &str,
+    evidence: Result<&GraphEvidence<'_>, &String>,
+) -> StructuralOutcome {
+    let ambiguous = |reason: String| StructuralOutcome {
+        status: ClauseStatus::Ambiguous,
+        reason,
+    };
+    let clause = match StructuralClause::parse(clause_type, clause_text) {
+        Ok(Some(clause)) => clause,
+        Ok(None) => return ambiguous(format!("'{clause_type}' is not a structural clause")),
+        Err(err) => return ambiguous(format!("invalid structural clause: {err}")),
+    };
+    let evidence = match evidence {
+        Ok(evidence) => evidence,
+        Err(err) => return ambiguous(format!("graph store unavailable: {err}")),
+    };
+    let symbol = match store.get_symbol_record(symbol_id) {
+        Ok(Some(symbol)) => symbol,
+        Ok(None) => return ambiguous(format!("symbol {symbol_id} is not indexed")),
+        Err(err) => return ambiguous(format!("failed to load symbol {symbol_id}: {err}")),
+    };
+    clause
+        .evaluate(&symbol, evidence)
+        .unwrap_or_else(|err| ambiguous(format!("graph query failed: {err}")))
+}
+
+/// Reconcile `@aether` clauses declared in one file's source with the store.
+///
+/// `symbol_ids` is every symbol the file defined before or after this index,
+/// so clauses on removed symbols are deactivated. Structural clauses are
+/// stored in canonical form; malformed ones are skipped with a warning.
+pub fn sync_source_contracts(
+    store: &SqliteStore,
+    file_path: &str,
+    symbol_ids: &[String],
+    declared: &[SourceContract],
+) -> Result<SourceContractSync> {
+    let declared = declared
+        .iter()
+        .filter_map(|contract| {
+            let clause_text =
+                match StructuralClause::parse(&contract.clause_type, &contract.clause_text) {
+                    Ok(Some(clause)) => clause.clause_text(),
+                    Ok(None) => contract.clause_text.clone(),
+                    Err(err) => {
+                        tracing::warn!(
+                            file_path,
+                            line = contract.line,
+                            error = %err,
+                            "skipping malformed @aether {} annotation",
+                            contract.clause_type
+                        );
+                        return None;
+                    }
+                };
+            Some(DeclaredContract {
+                symbol_id: contract.symbol_id.clone(),
+                clause_type: contract.clause_type.clone(),
+                clause_text,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    store
+        .sync_source_contracts(symbol_ids, &declared)
+        .with_context(|| format!("failed to sync source contracts for {file_path}"))
+}
+
 /// Apply leaky bucket streak logic and persist results.
 #[allow(clippy::too_many_arguments)]
 fn apply_leaky_bucket(
@@ -170,7 +317,8 @@ fn apply_leaky_bucket(
     current_streak: i64,
     streak_threshold: u32,
     clause_text: &str,
-    judge_reason: Option<&str>,
+    violation_type: &str,
+    reason: Option<&str>,
 ) -> Result<()> {
     match status {
         ClauseStatus::Pass => {
@@ -187,11 +335,6 @@ fn apply_leaky_bucket(
                 .context("failed to update contract streak")?;
 
             if new_streak >= i64::from(streak_threshold) {
-                let violation_type = if judge_reason.is_some() {
-                    "llm_judge_fail"
-                } else {
-                    "embedding_fail"
-                };
                 store
                     .insert_intent_violation(
                         contract_id,
@@ -199,7 +342,7 @@ fn apply_leaky_bucket(
                         sir_version,
                         violation_type,
                         None,
-                        judge_reason,
+                        reason,
                     )
                     .context("failed to insert intent violation")?;
                 tracing::warn!(
@@ -234,6 +377,7 @@ fn apply_leaky_bucket(
 // ── CLI command implementations ──────────────────────────────────
 
 fn run_add(workspace: &Path, config: &AetherConfig, args: &ContractAddArgs) -> Result<()> {
+    let mut out = crate::daemon_rpc::command_stdout();
     let store = SqliteStore::open(workspace).context("failed to open store")?;
     let symbol_id = resolve_symbol_id(&store, &args.symbol)?;
 
@@ -286,22 +430,56 @@ fn run_add(workspace: &Path, config: &AetherConfig, args: &ContractAddArgs) -> R
         inserted.push(("preserves", clause_text.as_str(), id));
     }
 
-    if inserted.is_empty() {
-        println!("No clauses specified. Use --must, --must-not, or --preserves.");
+    let structural = args
+        .must_call_clauses
+        .iter()
+        .map(|text| ("must_call", text.clone()))
+        .chain(
+            args.must_not_reach_clauses
+                .iter()
+                .map(|text| ("must_not_reach", text.clone())),
+        )
+        .chain(
+            args.must_be_tested
+                .map(|min_tests| ("must_be_tested", min_tests.to_string())),
+        );
+    let mut structural_texts = Vec::new();
+    for (clause_type, text) in structural {
+        let clause = StructuralClause::parse(clause_type, &text)
+            .map_err(|err| anyhow!("invalid {clause_type} clause: {err}"))?
+            .ok_or_else(|| anyhow!("{clause_type} is not a structural clause type"))?;
+        let clause_text = clause.clause_text();
+        // Structural clauses are evaluated against the graph, never embedded.
+        let id = store
+            .insert_intent_contract(&symbol_id, clause_type, &clause_text, None, "human")
+            .with_context(|| format!("failed to insert {clause_type} contract"))?;
+        structural_texts.push((clause_type, clause_text, id));
+    }
+
+    if inserted.is_empty() && structural_texts.is_empty() {
+        writeln!(
+            out,
+            "No clauses specified. Use --must, --must-not, --preserves, --must-call, \
+             --must-not-reach, or --must-be-tested."
+        )?;
         return Ok(());
     }
 
-    println!(
+    writeln!(
+        out,
         "Added {} contract clause(s) to symbol {symbol_id}:",
-        inserted.len()
-    );
+        inserted.len() + structural_texts.len()
+    )?;
     for (clause_type, text, id) in &inserted {
         let embedded = if embedding_provider.is_some() {
             " [embedded]"
         } else {
             ""
         };
-        println!("  #{id} {clause_type}: \"{text}\"{embedded}");
+        writeln!(out, "  #{id} {clause_type}: \"{text}\"{embedded}")?;
+    }
+    for (clause_type, text, id) in &structural_texts {
+        writeln!(out, "  #{id} {clause_type}: \"{text}\" [structural]")?;
     }
     let _ = config; // available for future judge config
     Ok(())
@@ -340,6 +518,7 @@ fn insert_clause_with_embedding(
 }
 
 fn run_list(workspace: &Path, symbol: Option<&str>) -> Result<()> {
+    let mut out = crate::daemon_rpc::command_stdout();
     let store = SqliteStore::open(workspace).context("failed to open store")?;
 
     let contracts = if let Some(symbol) = symbol {
@@ -354,7 +533,7 @@ fn run_list(workspace: &Path, symbol: Option<&str>) -> Result<()> {
     };
 
     if contracts.is_empty() {
-        println!("No active contracts found.");
+        writeln!(out, "No active contracts found.")?;
         return Ok(());
     }
 
@@ -362,10 +541,10 @@ fn run_list(workspace: &Path, symbol: Option<&str>) -> Result<()> {
     for contract in &contracts {
         if contract.symbol_id != current_symbol {
             if !current_symbol.is_empty() {
-                println!();
+                writeln!(out)?;
             }
             current_symbol.clone_from(&contract.symbol_id);
-            println!("Symbol: {current_symbol}");
+            writeln!(out, "Symbol: {current_symbol}")?;
         }
         let streak_indicator = if contract.violation_streak > 0 {
             format!(" [streak: {}]", contract.violation_streak)
@@ -377,16 +556,23 @@ fn run_list(workspace: &Path, symbol: Option<&str>) -> Result<()> {
         } else {
             ""
         };
-        println!(
-            "  #{} {}: \"{}\"{embedded}{streak_indicator}",
+        let source = match contract.policy_name() {
+            Some(policy) => format!(" [policy: {policy}]"),
+            None if contract.created_by == SOURCE_CONTRACT_CREATOR => " [source]".to_owned(),
+            None => String::new(),
+        };
+        writeln!(
+            out,
+            "  #{} {}: \"{}\"{embedded}{source}{streak_indicator}",
             contract.id, contract.clause_type, contract.clause_text
-        );
+        )?;
     }
-    println!("\n{} active contract(s) total.", contracts.len());
+    writeln!(out, "\n{} active contract(s) total.", contracts.len())?;
     Ok(())
 }
 
 fn run_remove(workspace: &Path, contract_id: i64) -> Result<()> {
+    let mut out = crate::daemon_rpc::command_stdout();
     let store = SqliteStore::open(workspace).context("failed to open store")?;
 
     let contract = store
@@ -395,22 +581,35 @@ fn run_remove(workspace: &Path, contract_id: i64) -> Result<()> {
         .ok_or_else(|| anyhow!("contract #{contract_id} not found"))?;
 
     if !contract.active {
-        println!("Contract #{contract_id} is already inactive.");
+        writeln!(out, "Contract #{contract_id} is already inactive.")?;
         return Ok(());
     }
+    if let Some(policy) = contract.policy_name() {
+        return Err(anyhow!(
+            "contract #{contract_id} comes from policy '{policy}'; edit {CONTRACT_POLICY_PATH} instead"
+        ));
+    }
+    if contract.created_by == SOURCE_CONTRACT_CREATOR {
+        return Err(anyhow!(
+            "contract #{contract_id} is declared in source; remove its @aether annotation instead"
+        ));
+    }
 
     store
         .deactivate_contract(contract_id)
         .context("failed to deactivate contract")?;
-    println!(
+    writeln!(
+        out,
         "Deactivated contract #{contract_id}: {} \"{}\"",
         contract.clause_type, contract.clause_text
-    );
+    )?;
     Ok(())
 }
 
 fn run_check(workspace: &Path, config: &AetherConfig, args: &ContractCheckArgs) -> Result<()> {
+    let mut out = crate::daemon_rpc::command_stdout();
     let store = SqliteStore::open(workspace).context("failed to open store")?;
+    sync_contract_policies(&store, workspace, None).context("failed to apply contract policies")?;
 
     let contracts = if let Some(symbol) = args.symbol.as_deref() {
         let symbol_id = resolve_symbol_id(&store, symbol)?;
@@ -424,7 +623,7 @@ fn run_check(workspace: &Path, config: &AetherConfig, args: &ContractCheckArgs)
     };
 
     if contracts.is_empty() {
-        println!("No active contracts to check.");
+        writeln!(out, "No active contracts to check.")?;
         return Ok(());
     }
 
@@ -458,23 +657,25 @@ fn run_check(workspace: &Path, config: &AetherConfig, args: &ContractCheckArgs)
         )?;
 
         if result.clauses_checked > 0 {
-            println!("Symbol: {symbol_id}");
+            writeln!(out, "Symbol: {symbol_id}")?;
             for clause in &result.clause_results {
                 let status_str = match clause.status {
                     ClauseStatus::Pass => "PASS",
                     ClauseStatus::Fail => "FAIL",
                     ClauseStatus::Ambiguous => "AMBIGUOUS",
                 };
-                let sim_str = clause
-                    .similarity
-                    .map(|s| format!(" (sim: {s:.3})"))
-                    .unwrap_or_default();
-                println!(
+                let sim_str = match (&clause.structural_reason, clause.similarity) {
+                    (Some(reason), _) => format!(" ({reason})"),
+                    (None, Some(s)) => format!(" (sim: {s:.3})"),
+                    (None, None) => String::new(),
+                };
+                writeln!(
+                    out,
                     "  [{status_str}] {} \"{}\"{sim_str}",
                     clause.clause_type, clause.clause_text
-                );
+                )?;
             }
-            println!();
+            writeln!(out)?;
         }
 
         total_checked += result.clauses_checked;
@@ -483,10 +684,63 @@ fn run_check(workspace: &Path, config: &AetherConfig, args: &ContractCheckArgs)
         total_ambiguous += result.ambiguous;
     }
 
-    println!(
+    writeln!(
+        out,
         "Checked {total_checked} clause(s): {total_passed} passed, \
          {total_failed} failed, {total_ambiguous} ambiguous"
-    );
+    )?;
+    Ok(())
+}
+
+fn run_policies(workspace: &Path, args: &ContractPoliciesArgs) -> Result<()> {
+    let mut out = crate::daemon_rpc::command_stdout();
+    let store = SqliteStore::open(workspace).context("failed to open store")?;
+    let spec = ContractPolicySpec::load(workspace)?;
+    let sync = sync_contract_policies(&store, workspace, None)
+        .context("failed to apply contract policies")?;
+    let status = policy_status(&store, &spec)?;
+
+    if args.json {
+        writeln!(out, "{}", serde_json::to_string_pretty(&status)?)?;
+        return Ok(());
+    }
+    if status.is_empty() {
+        writeln!(
+            out,
+            "No contract policies declared in {CONTRACT_POLICY_PATH}."
+        )?;
+        return Ok(());
+    }
+
+    writeln!(
+        out,
+        "Synced {} policy(ies): {} clause(s) added, {} retired.\n",
+        status.len(),
+        sync.added,
+        sync.deactivated
+    )?;
+    for policy in &status {
+        writeln!(
+            out,
+            "Policy {}: {} symbol(s), {} clause(s), {} failing, {} open violation(s)",
+            policy.name,
+            policy.symbol_count,
+            policy.clause_count,
+            policy.failing_clause_count,
+            policy.open_violation_count
+        )?;
+        for symbol in &policy.violating_symbols {
+            writeln!(
+                out,
+                "  {} ({} violation(s))",
+                symbol
+                    .qualified_name
+                    .as_deref()
+                    .unwrap_or(&symbol.symbol_id),
+                symbol.violations
+            )?;
+        }
+    }
     Ok(())
 }
 
@@ -552,6 +806,7 @@ mod tests {
             0,
             2,
             "clause",
+            "embedding_fail",
             None,
         )
         .unwrap();
@@ -581,6 +836,7 @@ mod tests {
             0,
             2,
             "clause",
+            "embedding_fail",
             None,
         )
         .unwrap();
@@ -594,6 +850,7 @@ mod tests {
             1,
             2,
             "clause",
+            "embedding_fail",
             None,
         )
         .unwrap();
@@ -623,6 +880,7 @@ mod tests {
             0,
             2,
             "clause",
+            "embedding_fail",
             None,
         )
         .unwrap();
@@ -645,6 +903,7 @@ mod tests {
             1,
             2,
             "clause",
+            "embedding_fail",
             None,
         )
         .unwrap();
@@ -658,6 +917,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn remove_refuses_source_declared_contract() {
+        let (store, dir) = open_test_store();
+        store
+            .sync_source_contracts(
+                &["sym_a".to_owned()],
+                &[DeclaredContract {
+                    symbol_id: "sym_a".to_owned(),
+                    clause_type: "must".to_owned(),
+                    clause_text: "reject zero amounts".to_owned(),
+                }],
+            )
+            .unwrap();
+        let contract = store.list_active_contracts_for_symbol("sym_a").unwrap()[0].clone();
+
+        let err = run_remove(dir.path(), contract.id).expect_err("source clause is protected");
+        assert!(err.to_string().contains("@aether annotation"));
+        assert!(
+            store
+                .get_intent_contract(contract.id)
+                .unwrap()
+                .unwrap()
+                .active
+        );
+    }
+
+    #[test]
+    fn sync_source_contracts_canonicalizes_and_skips_malformed_clauses() {
+        let (store, _dir) = open_test_store();
+        let declared = |clause_type: &str, clause_text: &str| SourceContract {
+            symbol_id: "sym_a".to_owned(),
+            clause_type: clause_type.to_owned(),
+            clause_text: clause_text.to_owned(),
+            line: 1,
+        };
+
+        let sync = sync_source_contracts(
+            &store,
+            "src/lib.rs",
+            &["sym_a".to_owned()],
+            &[
+                declared("must_be_tested", ""),
+                declared("must_call", "audit log"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(sync.added, 1);
+        let contracts = store.list_active_contracts_for_symbol("sym_a").unwrap();
+        assert_eq!(contracts[0].clause_text, "at least 1 test");
+        assert_eq!(contracts[0].created_by, SOURCE_CONTRACT_CREATOR);
+    }
+
     #[test]
     fn ambiguous_does_not_change_streak() {
         let (store, _dir) = open_test_store();
@@ -677,6 +988,7 @@ mod tests {
             1,
             2,
             "clause",
+            "embedding_fail",
             None,
         )
         .unwrap();
@@ -0,0 +1,606 @@
+//! Contract policies declared in `.aether/contracts.toml`: clauses applied to
+//! every symbol a selector matches, materialized as per-symbol contracts
+//! owned by the policy.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use aether_analysis::wildcard_match;
+use aether_store::{
+    ContractCreatorSummary, DeclaredContract, POLICY_CONTRACT_CREATOR_PREFIX, SirStateStore,
+    SourceContractSync, SqliteStore, SymbolRecord,
+};
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::structural::StructuralClause;
+
+pub const CONTRACT_POLICY_PATH: &str = ".aether/contracts.toml";
+
+/// Policies declared in `.aether/contracts.toml`:
+///
+/// ```toml
+/// [[policy]]
+/// name = "api-session"
+/// paths = ["src/api/handlers/*"]
+/// kinds = ["function", "method"]
+/// visibility = "public"
+/// must = ["verify the caller's session"]
+///
+/// [[policy]]
+/// name = "payments-audit"
+/// sir = { side_effects = "payments table" }
+/// must_call = ["audit::record within 2 hops"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContractPolicySpec {
+    #[serde(default, rename = "policy")]
+    pub policies: Vec<ContractPolicy>,
+}
+
+/// A symbol is selected when it satisfies every selector that is set.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContractPolicy {
+    pub name: String,
+    /// Globs over qualified names; `*` also spans `::`.
+    #[serde(default)]
+    pub symbols: Vec<String>,
+    /// Workspace-relative file globs; `*` also spans directories.
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Symbol kinds such as `function` or `method`.
+    #[serde(default)]
+    pub kinds: Vec<String>,
+    #[serde(default)]
+    pub visibility: PolicyVisibility,
+    /// SIR field to case-insensitive substring; `any` searches the whole SIR.
+    #[serde(default)]
+    pub sir: BTreeMap<String, String>,
+    #[serde(default)]
+    pub must: Vec<String>,
+    #[serde(default)]
+    pub must_not: Vec<String>,
+    #[serde(default)]
+    pub preserves: Vec<String>,
+    #[serde(default)]
+    pub must_call: Vec<String>,
+    #[serde(default)]
+    pub must_not_reach: Vec<String>,
+    #[serde(default)]
+    pub must_be_tested: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyVisibility {
+    #[default]
+    Any,
+    Public,
+    Private,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PolicyStatus {
+    pub name: String,
+    pub symbol_count: usize,
+    pub clause_count: usize,
+    pub failing_clause_count: usize,
+    pub open_violation_count: usize,
+    pub violating_symbols: Vec<PolicyViolatingSymbol>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PolicyViolatingSymbol {
+    pub symbol_id: String,
+    pub qualified_name: Option<String>,
+    pub violations: usize,
+}
+
+impl ContractPolicySpec {
+    /// Load and validate the workspace policy file; a missing file declares
+    /// no policies.
+    pub fn load(workspace: &Path) -> Result<Self> {
+        let path = workspace.join(CONTRACT_POLICY_PATH);
+        let raw = match fs::read_to_string(&path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()));
+            }
+        };
+        Self::parse(&raw).with_context(|| format!("invalid {CONTRACT_POLICY_PATH}"))
+    }
+
+    pub fn parse(raw: &str) -> Result<Self> {
+        let spec = toml::from_str::<Self>(raw)?;
+        let mut names = HashSet::new();
+        for policy in &spec.policies {
+            policy.validate()?;
+            if !names.insert(policy.name.as_str()) {
+                return Err(anyhow!(
+                    "policy '{}' is declared more than once",
+                    policy.name
+                ));
+            }
+        }
+        Ok(spec)
+    }
+}
+
+impl ContractPolicy {
+    pub fn created_by(&self) -> String {
+        format!("{POLICY_CONTRACT_CREATOR_PREFIX}{}", self.name)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let name = self.name.trim();
+        if name.is_empty() || name != self.name || name.contains(char::is_whitespace) {
+            return Err(anyhow!(
+                "policy name '{}' must be non-empty without whitespace",
+                self.name
+            ));
+        }
+        if self.symbols.is_empty()
+            && self.paths.is_empty()
+            && self.kinds.is_empty()
+            && self.visibility == PolicyVisibility::Any
+            && self.sir.is_empty()
+        {
+            return Err(anyhow!(
+                "policy '{name}' selects no symbols; set symbols, paths, kinds, visibility or sir"
+            ));
+        }
+        if self.clauses()?.is_empty() {
+            return Err(anyhow!("policy '{name}' declares no clauses"));
+        }
+        Ok(())
+    }
+
+    /// Clauses applied to each selected symbol, structural ones in canonical form.
+    pub fn clauses(&self) -> Result<Vec<(&'static str, String)>> {
+        let mut clauses = Vec::new();
+        for (clause_type, texts) in [
+            ("must", &self.must),
+            ("must_not", &self.must_not),
+            ("preserves", &self.preserves),
+        ] {
+            clauses.extend(
+                texts
+                    .iter()
+                    .map(|text| text.trim())
+                    .filter(|text| !text.is_empty())
+                    .map(|text| (clause_type, text.to_owned())),
+            );
+        }
+        let structural = self
+            .must_call
+            .iter()
+            .map(|text| ("must_call", text.clone()))
+            .chain(
+                self.must_not_reach
+                    .iter()
+                    .map(|text| ("must_not_reach", text.clone())),
+            )
+            .chain(
+                self.must_be_tested
+                    .map(|min_tests| ("must_be_tested", min_tests.to_string())),
+            );
+        for (clause_type, text) in structural {
+            let clause = StructuralClause::parse(clause_type, &text)
+                .map_err(|err| anyhow!("policy '{}': invalid {clause_type}: {err}", self.name))?
+                .ok_or_else(|| anyhow!("{clause_type} is not a structural clause type"))?;
+            clauses.push((clause_type, clause.clause_text()));
+        }
+        Ok(clauses)
+    }
+
+    /// Cheap selectors run first; visibility reads the source file and the
+    /// SIR predicate reads the SIR blob, so those are only consulted for
+    /// symbols that already passed the rest.
+    pub fn selects(&self, store: &SqliteStore, symbol: &SymbolRecord) -> Result<bool> {
+        if !self.kinds.is_empty()
+            && !self
+                .kinds
+                .iter()
+                .any(|kind| kind.trim().eq_ignore_ascii_case(&symbol.kind))
+        {
+            return Ok(false);
+        }
+        if !self.paths.is_empty()
+            && !self
+                .paths
+                .iter()
+                .any(|pattern| wildcard_match(pattern.trim(), &symbol.file_path))
+        {
+            return Ok(false);
+        }
+        if !self.symbols.is_empty()
+            && !self
+                .symbols
+                .iter()
+                .any(|pattern| wildcard_match(pattern.trim(), &symbol.qualified_name))
+        {
+            return Ok(false);
+        }
+        if self.visibility != PolicyVisibility::Any {
+            let is_public = store
+                .get_symbol_metadata(&symbol.id)?
+                .is_some_and(|metadata| metadata.is_public);
+            if is_public != (self.visibility == PolicyVisibility::Public) {
+                return Ok(false);
+            }
+        }
+        if !self.sir.is_empty() {
+            let Some(sir) = store
+                .read_sir_blob(&symbol.id)?
+                .and_then(|blob| serde_json::from_str::<Value>(&blob).ok())
+            else {
+                return Ok(false);
+            };
+            return Ok(sir_matches(&self.sir, &sir));
+        }
+        Ok(true)
+    }
+}
+
+/// Every predicate's needle must appear, case-insensitively, in the named
+/// SIR field (top-level or a workspace extension), or anywhere for `any`.
+pub fn sir_matches(predicates: &BTreeMap<String, String>, sir: &Value) -> bool {
+    predicates.iter().all(|(field, needle)| {
+        let needle = needle.trim().to_lowercase();
+        let value = if field == "any" {
+            Some(sir)
+        } else {
+            sir.get(field)
+                .or_else(|| sir.get("extensions").and_then(|ext| ext.get(field)))
+        };
+        value.is_some_and(|value| {
+            let mut text = String::new();
+            collect_text(value, &mut text);
+            text.to_lowercase().contains(&needle)
+        })
+    })
+}
+
+fn collect_text(value: &Value, out: &mut String) {
+    match value {
+        Value::String(text) => {
+            out.push_str(text);
+            out.push('\n');
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_text(item, out)),
+        Value::Object(fields) => fields.values().for_each(|item| collect_text(item, out)),
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// Materialize every policy over `symbol_ids` (all indexed symbols when
+/// `None`) and retire clauses of policies no longer declared.
+pub fn sync_contract_policies(
+    store: &SqliteStore,
+    workspace: &Path,
+    symbol_ids: Option<&[String]>,
+) -> Result<SourceContractSync> {
+    let spec = ContractPolicySpec::load(workspace)?;
+    let mut sync = SourceContractSync::default();
+
+    let declared_creators = spec
+        .policies
+        .iter()
+        .map(ContractPolicy::created_by)
+        .collect::<HashSet<_>>();
+    for created_by in store
+        .list_active_contract_creators(POLICY_CONTRACT_CREATOR_PREFIX)
+        .context("failed to list policy contracts")?
+    {
+        if !declared_creators.contains(&created_by) {
+            sync.deactivated += store
+                .deactivate_contracts_created_by(&created_by)
+                .with_context(|| format!("failed to retire contracts of {created_by}"))?;
+        }
+    }
+    if spec.policies.is_empty() {
+        return Ok(sync);
+    }
+
+    let all_symbol_ids;
+    let symbol_ids = match symbol_ids {
+        Some(symbol_ids) => symbol_ids,
+        None => {
+            all_symbol_ids = store
+                .list_all_symbol_ids()
+                .context("failed to list symbols")?;
+            &all_symbol_ids
+        }
+    };
+    let symbols = symbol_ids
+        .iter()
+        .map(|symbol_id| store.get_symbol_record(symbol_id))
+        .filter_map(Result::transpose)
+        .collect::<Result<Vec<_>, _>>()
+        .context("failed to load symbols")?;
+
+    for policy in &spec.policies {
+        let clauses = policy.clauses()?;
+        let mut declared = Vec::new();
+        for symbol in &symbols {
+            if !policy.selects(store, symbol)? {
+                continue;
+            }
+            declared.extend(
+                clauses
+                    .iter()
+                    .map(|(clause_type, clause_text)| DeclaredContract {
+                        symbol_id: symbol.id.clone(),
+                        clause_type: (*clause_type).to_owned(),
+                        clause_text: clause_text.clone(),
+                    }),
+            );
+        }
+        let policy_sync = store
+            .sync_declared_contracts(&policy.created_by(), symbol_ids, &declared)
+            .with_context(|| format!("failed to sync contracts of policy '{}'", policy.name))?;
+        sync.added += policy_sync.added;
+        sync.deactivated += policy_sync.deactivated;
+    }
+    Ok(sync)
+}
+
+/// Index and SIR hooks: re-select `symbol_ids` against the policies. A broken
+/// policy file must not stop indexing, so failures are only logged.
+pub fn refresh_policy_contracts(store: &SqliteStore, workspace: &Path, symbol_ids: &[String]) {
+    if symbol_ids.is_empty() {
+        return;
+    }
+    if let Err(err) = sync_contract_policies(store, workspace, Some(symbol_ids)) {
+        tracing::warn!(error = %err, "failed to apply contract policies");
+    }
+}
+
+/// Per-policy rollup, including declared policies that select nothing yet.
+pub fn policy_status(store: &SqliteStore, spec: &ContractPolicySpec) -> Result<Vec<PolicyStatus>> {
+    let mut summaries = store
+        .summarize_contracts_by_creator(POLICY_CONTRACT_CREATOR_PREFIX)
+        .context("failed to summarize policy contracts")?
+        .into_iter()
+        .map(|summary| (summary.created_by.clone(), summary))
+        .collect::<BTreeMap<_, _>>();
+
+    spec.policies
+        .iter()
+        .map(|policy| {
+            let summary =
+                summaries
+                    .remove(&policy.created_by())
+                    .unwrap_or_else(|| ContractCreatorSummary {
+                        created_by: policy.created_by(),
+                        ..ContractCreatorSummary::default()
+                    });
+            let violating_symbols = summary
+                .violating_symbols
+                .into_iter()
+                .map(|(symbol_id, violations)| {
+                    let qualified_name = store
+                        .get_symbol_record(&symbol_id)?
+                        .map(|record| record.qualified_name);
+                    Ok(PolicyViolatingSymbol {
+                        symbol_id,
+                        qualified_name,
+                        violations,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(PolicyStatus {
+                name: policy.name.clone(),
+                symbol_count: summary.symbol_count,
+                clause_count: summary.clause_count,
+                failing_clause_count: summary.failing_clause_count,
+                open_violation_count: summary.open_violation_count,
+                violating_symbols,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aether_store::SymbolCatalogStore;
+    use tempfile::tempdir;
+
+    fn upsert_symbol(store: &SqliteStore, id: &str, file_path: &str, kind: &str, name: &str) {
+        store
+            .upsert_symbol(SymbolRecord {
+                id: id.to_owned(),
+                file_path: file_path.to_owned(),
+                language: "rust".to_owned(),
+                kind: kind.to_owned(),
+                qualified_name: name.to_owned(),
+                signature_fingerprint: format!("sig-{id}"),
+                last_seen_at: 1_700_000_000,
+            })
+            .expect("upsert symbol");
+    }
+
+    #[test]
+    fn parse_rejects_policies_without_selectors_or_clauses() {
+        let spec = ContractPolicySpec::parse(
+            r#"
+            [[policy]]
+            name = "api-session"
+            paths = ["src/api/*"]
+            must = ["verify the caller's session"]
+            must_be_tested = 2
+            "#,
+        )
+        .expect("valid spec");
+        assert_eq!(
+            spec.policies[0].clauses().unwrap(),
+            vec![
+                ("must", "verify the caller's session".to_owned()),
+                ("must_be_tested", "at least 2 tests".to_owned()),
+            ]
+        );
+
+        let unselective = ContractPolicySpec::parse(
+            "[[policy]]\nname = \"everything\"\nmust = [\"log errors\"]\n",
+        )
+        .unwrap_err();
+        assert!(format!("{unselective:#}").contains("selects no symbols"));
+
+        let empty =
+            ContractPolicySpec::parse("[[policy]]\nname = \"idle\"\nkinds = [\"function\"]\n")
+                .unwrap_err();
+        assert!(format!("{empty:#}").contains("declares no clauses"));
+
+        let duplicate = ContractPolicySpec::parse(
+            "[[policy]]\nname = \"a\"\nkinds = [\"method\"]\nmust = [\"x\"]\n\
+             [[policy]]\nname = \"a\"\nkinds = [\"function\"]\nmust = [\"y\"]\n",
+        )
+        .unwrap_err();
+        assert!(format!("{duplicate:#}").contains("more than once"));
+    }
+
+    #[test]
+    fn sir_predicates_match_fields_extensions_and_any() {
+        let sir = serde_json::json!({
+            "intent": "Charge a card",
+            "side_effects": ["Writes a row to the Payments table"],
+            "extensions": { "data_classes": ["pci"] },
+        });
+        let predicates = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(field, needle)| ((*field).to_owned(), (*needle).to_owned()))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        assert!(sir_matches(
+            &predicates(&[("side_effects", "payments table")]),
+            &sir
+        ));
+        assert!(sir_matches(&predicates(&[("data_classes", "PCI")]), &sir));
+        assert!(sir_matches(&predicates(&[("any", "charge a card")]), &sir));
+        assert!(!sir_matches(
+            &predicates(&[("side_effects", "payments table"), ("intent", "refund")]),
+            &sir
+        ));
+        assert!(!sir_matches(
+            &predicates(&[("error_modes", "timeout")]),
+            &sir
+        ));
+    }
+
+    #[test]
+    fn sync_materializes_selected_symbols_and_retires_dropped_policies() {
+        let dir = tempdir().unwrap();
+        let workspace = dir.path();
+        fs::create_dir_all(workspace.join("src/api")).unwrap();
+        fs::write(
+            workspace.join("src/api/handlers.rs"),
+            "pub fn login() {}\nfn helper() {}\n",
+        )
+        .unwrap();
+        let store = SqliteStore::open(workspace).unwrap();
+        upsert_symbol(
+            &store,
+            "sym_login",
+            "src/api/handlers.rs",
+            "function",
+            "login",
+        );
+        upsert_symbol(
+            &store,
+            "sym_helper",
+            "src/api/handlers.rs",
+            "function",
+            "helper",
+        );
+        upsert_symbol(&store, "sym_pay", "src/payments.rs", "function", "charge");
+        store
+            .write_sir_blob(
+                "sym_pay",
+                r#"{"intent":"charge","side_effects":["insert into payments table"]}"#,
+            )
+            .unwrap();
+
+        fs::write(
+            workspace.join(CONTRACT_POLICY_PATH),
+            r#"
+            [[policy]]
+            name = "api-session"
+            paths = ["src/api/*"]
+            visibility = "public"
+            must = ["verify the caller's session"]
+
+            [[policy]]
+            name = "payments-audit"
+            sir = { side_effects = "payments table" }
+            must_call = ["audit::record"]
+            "#,
+        )
+        .unwrap();
+
+        let sync = sync_contract_policies(&store, workspace, None).unwrap();
+        assert_eq!(sync.added, 2);
+        let login = store.list_active_contracts_for_symbol("sym_login").unwrap();
+        assert_eq!(login.len(), 1);
+        assert_eq!(login[0].policy_name(), Some("api-session"));
+        assert!(
+            store
+                .list_active_contracts_for_symbol("sym_helper")
+                .unwrap()
+                .is_empty()
+        );
+        let pay = store.list_active_contracts_for_symbol("sym_pay").unwrap();
+        assert_eq!(pay[0].clause_text, "audit::record within 1 hop");
+
+        // A new symbol picked up by the index hook joins the policy.
+        upsert_symbol(
+            &store,
+            "sym_logout",
+            "src/api/handlers.rs",
+            "function",
+            "logout",
+        );
+        fs::write(
+            workspace.join("src/api/handlers.rs"),
+            "pub fn login() {}\nfn helper() {}\npub fn logout() {}\n",
+        )
+        .unwrap();
+        refresh_policy_contracts(&store, workspace, &["sym_logout".to_owned()]);
+        assert_eq!(
+            store
+                .list_active_contracts_for_symbol("sym_logout")
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let spec = ContractPolicySpec::load(workspace).unwrap();
+        let status = policy_status(&store, &spec).unwrap();
+        assert_eq!(status[0].name, "api-session");
+        assert_eq!(status[0].symbol_count, 2);
+        assert_eq!(status[1].clause_count, 1);
+
+        fs::write(
+            workspace.join(CONTRACT_POLICY_PATH),
+            "[[policy]]\nname = \"api-session\"\npaths = [\"src/api/*\"]\nvisibility = \"public\"\n\
+             must = [\"verify the caller's session\"]\n",
+        )
+        .unwrap();
+        let sync = sync_contract_policies(&store, workspace, None).unwrap();
+        assert_eq!(sync.added, 0);
+        assert_eq!(sync.deactivated, 1);
+        assert!(
+            store
+                .list_active_contracts_for_symbol("sym_pay")
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,514 @@
+/// Structural contract clauses verified exactly against the graph.
+///
+/// Unlike must/must_not/preserves, these clauses are graph predicates:
+/// `must_call` (reach a target within N call hops), `must_not_reach`
+/// (no call path to a target) and `must_be_tested` (at least N TESTED_BY
+/// edges). They never go through the embedding pre-filter or the judge.
+use std::sync::Arc;
+
+use aether_core::EdgeKind;
+use aether_store::{
+    GraphStore, SqliteStore, StoreError, SurrealGraphStore, SymbolRecord, block_on_store_future,
+    open_graph_store_readonly, open_surreal_graph_store_readonly,
+};
+
+use super::verify::ClauseStatus;
+
+pub const STRUCTURAL_CLAUSE_TYPES: [&str; 3] = ["must_call", "must_not_reach", "must_be_tested"];
+
+/// Hop limit for `must_not_reach` when the clause does not name one.
+pub const DEFAULT_REACH_DEPTH: u32 = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StructuralClause {
+    MustCall { target: String, within: u32 },
+    MustNotReach { target: String, within: u32 },
+    MustBeTested { min_tests: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuralOutcome {
+    pub status: ClauseStatus,
+    pub reason: String,
+}
+
+pub fn is_structural_clause_type(clause_type: &str) -> bool {
+    STRUCTURAL_CLAUSE_TYPES.contains(&clause_type.trim())
+}
+
+impl StructuralClause {
+    /// Parse a stored clause. Returns `Ok(None)` for non-structural clause types.
+    ///
+    /// Accepted texts: `audit::log`, `audit::log within 2 hops` and, for
+    /// `must_be_tested`, an empty text, `3` or `at least 3 tests`.
+    pub fn parse(clause_type: &str, clause_text: &str) -> Result<Option<Self>, String> {
+        let text = clause_text.trim();
+        let clause = match clause_type.trim() {
+            "must_call" => {
+                let (target, within) = parse_target(text)?;
+                Self::MustCall {
+                    target,
+                    within: within.unwrap_or(1),
+                }
+            }
+            "must_not_reach" => {
+                let (target, within) = parse_target(text)?;
+                Self::MustNotReach {
+                    target,
+                    within: within.unwrap_or(DEFAULT_REACH_DEPTH),
+                }
+            }
+            "must_be_tested" => Self::MustBeTested {
+                min_tests: parse_min_tests(text)?,
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(clause))
+    }
+
+    /// Canonical clause text, as stored and displayed.
+    pub fn clause_text(&self) -> String {
+        match self {
+            Self::MustCall { target, within } => format!("{target} within {}", hops(*within)),
+            Self::MustNotReach { target, within } if *within == DEFAULT_REACH_DEPTH => {
+                target.clone()
+            }
+            Self::MustNotReach { target, within } => format!("{target} within {}", hops(*within)),
+            Self::MustBeTested { min_tests: 1 } => "at least 1 test".to_owned(),
+            Self::MustBeTested { min_tests } => format!("at least {min_tests} tests"),
+        }
+    }
+
+    pub fn evaluate(
+        &self,
+        symbol: &SymbolRecord,
+        evidence: &dyn StructuralEvidence,
+    ) -> Result<StructuralOutcome, StoreError> {
+        let outcome = match self {
+            Self::MustCall { target, within } => {
+                match find_call(symbol, target, *within, evidence)? {
+                    Some(found) => StructuralOutcome {
+                        status: ClauseStatus::Pass,
+                        reason: format!("calls {target} at hop {} via {}", found.hop, found.via),
+                    },
+                    None => StructuralOutcome {
+                        status: ClauseStatus::Fail,
+                        reason: format!("no call to {target} within {}", hops(*within)),
+                    },
+                }
+            }
+            Self::MustNotReach { target, within } => {
+                match find_call(symbol, target, *within, evidence)? {
+                    Some(found) => StructuralOutcome {
+                        status: ClauseStatus::Fail,
+                        reason: format!("reaches {target} at hop {} via {}", found.hop, found.via),
+                    },
+                    None => StructuralOutcome {
+                        status: ClauseStatus::Pass,
+                        reason: format!("no call path to {target} within {}", hops(*within)),
+                    },
+                }
+            }
+            Self::MustBeTested { min_tests } => {
+                let count = evidence.tested_by_count(symbol.file_path.as_str())?;
+                let status = if count >= *min_tests as usize {
+                    ClauseStatus::Pass
+                } else {
+                    ClauseStatus::Fail
+                };
+                StructuralOutcome {
+                    status,
+                    reason: format!(
+                        "{} has {count} TESTED_BY edge(s), {min_tests} required",
+                        symbol.file_path
+                    ),
+                }
+            }
+        };
+        Ok(outcome)
+    }
+}
+
+/// Graph facts needed to evaluate structural clauses.
+pub trait StructuralEvidence {
+    /// Call targets of a symbol as written at the call site, resolved or not.
+    fn call_targets(&self, symbol_id: &str) -> Result<Vec<String>, StoreError>;
+    /// Symbols reachable through resolved calls, grouped by hop.
+    fn call_chain(&self, symbol_id: &str, depth: u32)
+    -> Result<Vec<Vec<SymbolRecord>>, StoreError>;
+    /// Number of test files linked to a file through TESTED_BY edges.
+    fn tested_by_count(&self, file_path: &str) -> Result<usize, StoreError>;
+}
+
+/// Evidence backed by the SQLite edge table, the configured graph store and
+/// the Surreal TESTED_BY relation.
+pub struct GraphEvidence<'a> {
+    store: &'a SqliteStore,
+    graph: Arc<dyn GraphStore>,
+    tested_by: Option<Arc<SurrealGraphStore>>,
+}
+
+impl<'a> GraphEvidence<'a> {
+    pub fn new(
+        store: &'a SqliteStore,
+        graph: Arc<dyn GraphStore>,
+        tested_by: Option<Arc<SurrealGraphStore>>,
+    ) -> Self {
+        Self {
+            store,
+            graph,
+            tested_by,
+        }
+    }
+
+    pub fn open(
+        store: &'a SqliteStore,
+        workspace_root: &std::path::Path,
+    ) -> Result<Self, StoreError> {
+        let tested_by = open_surreal_graph_store_readonly(workspace_root)
+            .ok()
+            .map(Arc::new);
+        let graph: Arc<dyn GraphStore> = match tested_by.clone() {
+            Some(surreal) => surreal,
+            None => Arc::from(open_graph_store_readonly(workspace_root)?),
+        };
+        Ok(Self::new(store, graph, tested_by))
+    }
+}
+
+impl StructuralEvidence for GraphEvidence<'_> {
+    fn call_targets(&self, symbol_id: &str) -> Result<Vec<String>, StoreError> {
+        Ok(self
+            .store
+            .list_symbol_edges_for_source_and_kinds(symbol_id, &[EdgeKind::Calls])?
+            .into_iter()
+            .map(|edge| edge.target_qualified_name)
+            .collect())
+    }
+
+    fn call_chain(
+        &self,
+        symbol_id: &str,
+        depth: u32,
+    ) -> Result<Vec<Vec<SymbolRecord>>, StoreError> {
+        block_on_store_future(self.graph.get_call_chain(symbol_id, depth))?
+    }
+
+    fn tested_by_count(&self, file_path: &str) -> Result<usize, StoreError> {
+        let Some(graph) = self.tested_by.as_ref() else {
+            return Err(StoreError::Graph(
+                "TESTED_BY edges require a surreal-compatible graph backend".to_owned(),
+            ));
+        };
+        let mut test_files =
+            block_on_store_future(graph.list_tested_by_for_target_file(file_path))??
+                .into_iter()
+                .map(|row| row.test_file)
+                .collect::<Vec<_>>();
+        test_files.sort();
+        test_files.dedup();
+        Ok(test_files.len())
+    }
+}
+
+struct CallMatch {
+    hop: u32,
+    via: String,
+}
+
+/// Breadth-first search for a call to `target`. Hop 1 is a direct call from
+/// `symbol`; hop N is a call made by a symbol reached in N-1 resolved calls.
+fn find_call(
+    symbol: &SymbolRecord,
+    target: &str,
+    within: u32,
+    evidence: &dyn StructuralEvidence,
+) -> Result<Option<CallMatch>, StoreError> {
+    if within == 0 {
+        return Ok(None);
+    }
+    let chain = if within > 1 {
+        evidence.call_chain(symbol.id.as_str(), within - 1)?
+    } else {
+        Vec::new()
+    };
+
+    let root = std::slice::from_ref(symbol);
+    for hop in 1..=within {
+        let callers = match hop {
+            1 => root,
+            _ => match chain.get(hop as usize - 2) {
+                Some(level) => level.as_slice(),
+                None => break,
+            },
+        };
+        for caller in callers {
+            let calls_target = evidence
+                .call_targets(caller.id.as_str())?
+                .iter()
+                .any(|callee| call_matches(target, callee));
+            if calls_target {
+                return Ok(Some(CallMatch {
+                    hop,
+                    via: caller.qualified_name.clone(),
+                }));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Whether a call site name or qualified name refers to the clause target.
+/// Either side may be a `::`-qualified suffix of the other, so `audit::log`
+/// matches `crate::audit::log` and `std::process::exit` matches
+/// `process::exit`, but a bare `exit` matches only a bare target.
+fn call_matches(target: &str, callee: &str) -> bool {
+    let target = normalize_path(target);
+    let callee = normalize_path(callee);
+    if target.is_empty() || callee.is_empty() {
+        return false;
+    }
+    target == callee
+        || callee.ends_with(format!("::{target}").as_str())
+        || (callee.contains("::") && target.ends_with(format!("::{callee}").as_str()))
+}
+
+fn normalize_path(value: &str) -> String {
+    value
+        .trim()
+        .replace('.', "::")
+        .trim_start_matches("::")
+        .to_owned()
+}
+
+fn parse_target(text: &str) -> Result<(String, Option<u32>), String> {
+    let (target, within) = match text.split_once(" within ") {
+        Some((target, rest)) => {
+            let count = rest
+                .trim()
+                .trim_end_matches("hops")
+                .trim_end_matches("hop")
+                .trim();
+            let within = count
+                .parse::<u32>()
+                .ok()
+                .filter(|value| *value > 0)
+                .ok_or_else(|| format!("invalid hop count '{}'", rest.trim()))?;
+            (target.trim(), Some(within))
+        }
+        None => (text, None),
+    };
+    if target.is_empty() || target.contains(char::is_whitespace) {
+        return Err(format!(
+            "expected '<qualified name> [within N hops]', got '{text}'"
+        ));
+    }
+    Ok((target.to_owned(), within))
+}
+
+fn parse_min_tests(text: &str) -> Result<u32, String> {
+    if text.is_empty() {
+        return Ok(1);
+    }
+    let count = text
+        .trim_start_matches("at least")
+        .trim()
+        .trim_end_matches("tests")
+        .trim_end_matches("test")
+        .trim();
+    count
+        .parse::<u32>()
+        .ok()
+        .filter(|value| *value > 0)
+        .ok_or_else(|| format!("expected 'at least N tests', got '{text}'"))
+}
+
+fn hops(count: u32) -> String {
+    if count == 1 {
+        "1 hop".to_owned()
+    } else {
+        format!("{count} hops")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct FakeEvidence {
+        calls: HashMap<String, Vec<String>>,
+        symbols: HashMap<String, SymbolRecord>,
+        tested_by: HashMap<String, usize>,
+    }
+
+    impl FakeEvidence {
+        fn add_symbol(&mut self, id: &str, qualified_name: &str, calls: &[&str]) {
+            self.symbols
+                .insert(id.to_owned(), symbol(id, qualified_name));
+            self.calls.insert(
+                id.to_owned(),
+                calls.iter().map(|call| (*call).to_owned()).collect(),
+            );
+        }
+    }
+
+    impl StructuralEvidence for FakeEvidence {
+        fn call_targets(&self, symbol_id: &str) -> Result<Vec<String>, StoreError> {
+            Ok(self.calls.get(symbol_id).cloned().unwrap_or_default())
+        }
+
+        fn call_chain(
+            &self,
+            symbol_id: &str,
+            depth: u32,
+        ) -> Result<Vec<Vec<SymbolRecord>>, StoreError> {
+            let mut levels = Vec::new();
+            let mut frontier = vec![symbol_id.to_owned()];
+            for _ in 0..depth {
+                let level = frontier
+                    .iter()
+                    .flat_map(|id| self.calls.get(id).cloned().unwrap_or_default())
+                    .filter_map(|callee| {
+                        self.symbols
+                            .values()
+                            .find(|record| record.qualified_name == callee)
+                            .cloned()
+                    })
+                    .collect::<Vec<_>>();
+                if level.is_empty() {
+                    break;
+                }
+                frontier = level.iter().map(|record| record.id.clone()).collect();
+                levels.push(level);
+            }
+            Ok(levels)
+        }
+
+        fn tested_by_count(&self, file_path: &str) -> Result<usize, StoreError> {
+            Ok(self.tested_by.get(file_path).copied().unwrap_or(0))
+        }
+    }
+
+    fn symbol(id: &str, qualified_name: &str) -> SymbolRecord {
+        SymbolRecord {
+            id: id.to_owned(),
+            file_path: "src/payments.rs".to_owned(),
+            language: "rust".to_owned(),
+            kind: "function".to_owned(),
+            qualified_name: qualified_name.to_owned(),
+            signature_fingerprint: String::new(),
+            last_seen_at: 0,
+        }
+    }
+
+    fn payment_graph() -> FakeEvidence {
+        let mut evidence = FakeEvidence::default();
+        evidence.add_symbol("charge", "payments::charge", &["payments::record"]);
+        evidence.add_symbol("record", "payments::record", &["audit::log"]);
+        evidence.add_symbol("refund", "payments::refund", &["payments::abort"]);
+        evidence.add_symbol("abort", "payments::abort", &["process::exit"]);
+        evidence
+    }
+
+    fn evaluate(
+        clause_type: &str,
+        text: &str,
+        symbol_id: &str,
+        evidence: &FakeEvidence,
+    ) -> StructuralOutcome {
+        let clause = StructuralClause::parse(clause_type, text)
+            .expect("valid clause")
+            .expect("structural clause");
+        clause
+            .evaluate(&evidence.symbols[symbol_id], evidence)
+            .expect("evaluate")
+    }
+
+    #[test]
+    fn parses_structural_clauses_to_canonical_text() {
+        let clause = StructuralClause::parse("must_call", " audit::log within 2 hops ")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            clause,
+            StructuralClause::MustCall {
+                target: "audit::log".to_owned(),
+                within: 2,
+            }
+        );
+        assert_eq!(clause.clause_text(), "audit::log within 2 hops");
+
+        let clause = StructuralClause::parse("must_not_reach", "std::process::exit")
+            .unwrap()
+            .unwrap();
+        assert_eq!(clause.clause_text(), "std::process::exit");
+
+        let clause = StructuralClause::parse("must_be_tested", "")
+            .unwrap()
+            .unwrap();
+        assert_eq!(clause, StructuralClause::MustBeTested { min_tests: 1 });
+        assert_eq!(clause.clause_text(), "at least 1 test");
+
+        assert_eq!(
+            StructuralClause::parse("must", "reject zero amounts"),
+            Ok(None)
+        );
+        assert!(StructuralClause::parse("must_call", "audit log").is_err());
+        assert!(StructuralClause::parse("must_call", "audit::log within zero hops").is_err());
+        assert!(StructuralClause::parse("must_be_tested", "some").is_err());
+    }
+
+    #[test]
+    fn must_call_respects_hop_limit() {
+        let evidence = payment_graph();
+
+        let outcome = evaluate("must_call", "audit::log within 2 hops", "charge", &evidence);
+        assert_eq!(outcome.status, ClauseStatus::Pass);
+        assert_eq!(
+            outcome.reason,
+            "calls audit::log at hop 2 via payments::record"
+        );
+
+        let outcome = evaluate("must_call", "audit::log", "charge", &evidence);
+        assert_eq!(outcome.status, ClauseStatus::Fail);
+        assert_eq!(outcome.reason, "no call to audit::log within 1 hop");
+    }
+
+    #[test]
+    fn must_not_reach_follows_calls_into_unresolved_targets() {
+        let evidence = payment_graph();
+
+        let outcome = evaluate("must_not_reach", "std::process::exit", "refund", &evidence);
+        assert_eq!(outcome.status, ClauseStatus::Fail);
+        assert_eq!(
+            outcome.reason,
+            "reaches std::process::exit at hop 2 via payments::abort"
+        );
+
+        let outcome = evaluate("must_not_reach", "std::process::exit", "charge", &evidence);
+        assert_eq!(outcome.status, ClauseStatus::Pass);
+
+        assert!(!call_matches("std::process::exit", "exit"));
+        assert!(call_matches("audit::log", "crate::audit::log"));
+        assert!(call_matches("audit.log", "audit::log"));
+    }
+
+    #[test]
+    fn must_be_tested_counts_tested_by_edges() {
+        let mut evidence = payment_graph();
+
+        let outcome = evaluate("must_be_tested", "at least 1 test", "charge", &evidence);
+        assert_eq!(outcome.status, ClauseStatus::Fail);
+
+        evidence.tested_by.insert("src/payments.rs".to_owned(), 2);
+        let outcome = evaluate("must_be_tested", "2", "charge", &evidence);
+        assert_eq!(outcome.status, ClauseStatus::Pass);
+        assert_eq!(
+            outcome.reason,
+            "src/payments.rs has 2 TESTED_BY edge(s), 2 required"
+        );
+    }
+}
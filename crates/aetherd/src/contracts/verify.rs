@@ -21,6 +21,8 @@ pub struct ClauseResult {
     pub status: ClauseStatus,
     pub similarity: Option<f64>,
     pub judge_reason: Option<String>,
+    /// Graph evidence for structural clauses
+    pub structural_reason: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -2,12 +2,15 @@ use std::io::Write;
 use std::path::Path;
 
 use aether_analysis::{BlastRadiusRequest, CouplingAnalyzer, MineCouplingRequest};
-use aether_config::{GraphBackend, load_workspace_config};
+use aether_config::{
+    GraphBackend, declared_workspace_roots, load_workspace_config, qualify_root_path,
+};
 use aether_store::{open_surreal_graph_store_readonly, open_surreal_graph_store_sync};
 use anyhow::{Context, Result};
 use serde_json::json;
 
 use crate::cli::{BlastRadiusArgs, CouplingReportArgs, MineCouplingArgs};
+use crate::workspace_roots::root_name_for_file_path;
 
 fn check_daemon_for_graph(workspace: &Path, command_name: &str) -> Result<()> {
     let config = load_workspace_config(workspace).context("failed to load workspace config")?;
@@ -41,19 +44,27 @@ pub fn run_mine_coupling_command(workspace: &Path, args: MineCouplingArgs) -> Re
 
 pub fn run_blast_radius_command(workspace: &Path, args: BlastRadiusArgs) -> Result<()> {
     check_daemon_for_graph(workspace, "blast-radius")?;
+    let config = load_workspace_config(workspace).context("failed to load workspace config")?;
+    let roots = declared_workspace_roots(&config);
     let analyzer = CouplingAnalyzer::new(workspace).context("failed to initialize analyzer")?;
     let graph = open_surreal_graph_store_sync(workspace)
         .context("failed to open configured surreal graph store")?;
-    let result = analyzer
+    let mut result = analyzer
         .blast_radius_with_graph(
             &graph,
             BlastRadiusRequest {
-                file_path: args.file,
+                file_path: qualify_root_path(&roots, args.file.as_str()),
                 min_risk: args.min_risk,
                 auto_mine: true,
             },
         )
         .context("blast radius query failed")?;
+    if !args.roots.is_empty() {
+        result.coupled_files.retain(|entry| {
+            let root = root_name_for_file_path(workspace, &roots, &entry.file);
+            args.roots.iter().any(|wanted| wanted == &root)
+        });
+    }
 
     let response =
         serde_json::to_value(result).context("failed to serialize blast radius output")?;
@@ -77,7 +88,7 @@ pub fn run_coupling_report_command(workspace: &Path, args: CouplingReportArgs) -
 }
 
 fn write_json_to_stdout(value: &serde_json::Value) -> Result<()> {
-    let mut out = std::io::stdout();
+    let mut out = crate::daemon_rpc::command_stdout();
     serde_json::to_writer_pretty(&mut out, value).context("failed to serialize JSON output")?;
     writeln!(&mut out).context("failed to write trailing newline")?;
     Ok(())
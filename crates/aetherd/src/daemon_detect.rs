@@ -28,7 +28,14 @@ impl DaemonInfo {
 /// Check if a daemon is running for the given workspace by probing the configured
 /// dashboard port. Returns `Some(DaemonInfo)` if either a same-workspace daemon
 /// responds or another process holds the graph lock, `None` otherwise.
+///
+/// Commands executing inside the daemon on behalf of an RPC client already own
+/// the stores, so they never see a running daemon.
 pub fn detect_running_daemon(config: &AetherConfig, workspace: &Path) -> Option<DaemonInfo> {
+    if crate::daemon_rpc::serving_rpc_request() {
+        return None;
+    }
+
     let port = config.dashboard.port;
     let addr = format!("127.0.0.1:{port}");
 
@@ -135,14 +142,16 @@ pub fn exit_daemon_detected(daemon: &DaemonInfo, command_name: &str) -> ! {
 /// Print a warning that graph data is unavailable due to daemon lock, then continue.
 pub fn warn_daemon_detected(daemon: &DaemonInfo, command_name: &str) {
     if daemon.has_http_api() {
-        eprintln!(
+        crate::daemon_rpc::command_eprintln!(
             "  Warning: AETHER daemon is running (PID {}, port {}). \
              Graph data unavailable for `{command_name}` \u{2014} results may be incomplete. \
              Use the dashboard at http://127.0.0.1:{}/dashboard/ for full data.",
-            daemon.pid, daemon.port, daemon.port
+            daemon.pid,
+            daemon.port,
+            daemon.port
         );
     } else {
-        eprintln!(
+        crate::daemon_rpc::command_eprintln!(
             "  Warning: another process holds the graph database lock. \
              Graph data unavailable for `{command_name}` \u{2014} results may be incomplete. \
              If no daemon is running, remove the stale lock with `rm -f .aether/graph/LOCK`.",
@@ -0,0 +1,724 @@
+//! Local RPC channel that lets CLI commands execute inside a running daemon.
+//!
+//! The daemon owns the graph and vector store locks, so CLI invocations that
+//! would otherwise collide with it forward their argv over a Unix socket at
+//! `.aether/daemon.sock`. The daemon re-parses the arguments, runs the command
+//! on the connection thread with stdout and stderr captured, and sends the
+//! rendered output back so the client prints exactly what a local run would
+//! print.
+
+use std::cell::RefCell;
+use std::ffi::OsString;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow, bail};
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::{Cli, Commands, ContractCommand};
+
+pub const DAEMON_SOCKET_FILE: &str = "daemon.sock";
+const RPC_PROTOCOL_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct RpcRequest {
+    version: u32,
+    workspace: String,
+    args: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct RpcResponse {
+    stdout: String,
+    #[serde(default)]
+    stderr: String,
+    #[serde(default)]
+    exit_code: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Output of a command that was executed inside the running daemon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaemonRpcOutput {
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: i32,
+    pub error: Option<String>,
+}
+
+pub fn daemon_socket_path(workspace: &Path) -> PathBuf {
+    workspace.join(".aether").join(DAEMON_SOCKET_FILE)
+}
+
+#[derive(Debug, Default)]
+struct CapturedCommand {
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+    exit_code: i32,
+}
+
+thread_local! {
+    static CAPTURED: RefCell<Option<CapturedCommand>> = const { RefCell::new(None) };
+}
+
+/// True while the current thread is executing a command on behalf of an RPC client.
+pub fn serving_rpc_request() -> bool {
+    CAPTURED.with(|captured| captured.borrow().is_some())
+}
+
+/// Stdout handle for command output that must reach the RPC client when the
+/// command runs inside the daemon.
+pub fn command_stdout() -> CommandStdout {
+    CommandStdout
+}
+
+/// Stderr handle for command diagnostics that must reach the RPC client when
+/// the command runs inside the daemon.
+pub fn command_stderr() -> CommandStderr {
+    CommandStderr
+}
+
+/// `eprintln!` through [`command_stderr`].
+macro_rules! command_eprintln {
+    ($($arg:tt)*) => {{
+        use ::std::io::Write as _;
+        let _ = writeln!($crate::daemon_rpc::command_stderr(), $($arg)*);
+    }};
+}
+pub(crate) use command_eprintln;
+
+/// Exit the process with `code`, or hand the code to the RPC client when the
+/// command runs inside the daemon so the daemon keeps running.
+pub fn exit_command(code: i32) {
+    let recorded = CAPTURED.with(|captured| {
+        captured
+            .borrow_mut()
+            .as_mut()
+            .map(|command| command.exit_code = code)
+    });
+    if recorded.is_none() {
+        std::process::exit(code);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CommandStdout;
+
+impl Write for CommandStdout {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match capture_output(buf, |command| &mut command.stdout) {
+            Some(()) => Ok(buf.len()),
+            None => io::stdout().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if serving_rpc_request() {
+            return Ok(());
+        }
+        io::stdout().flush()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CommandStderr;
+
+impl Write for CommandStderr {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match capture_output(buf, |command| &mut command.stderr) {
+            Some(()) => Ok(buf.len()),
+            None => io::stderr().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if serving_rpc_request() {
+            return Ok(());
+        }
+        io::stderr().flush()
+    }
+}
+
+fn capture_output(
+    buf: &[u8],
+    stream: impl FnOnce(&mut CapturedCommand) -> &mut Vec<u8>,
+) -> Option<()> {
+    CAPTURED.with(|captured| {
+        captured
+            .borrow_mut()
+            .as_mut()
+            .map(|command| stream(command).extend_from_slice(buf))
+    })
+}
+
+fn capture_command(run: impl FnOnce() -> Result<()>) -> (CapturedCommand, Result<()>) {
+    CAPTURED.with(|captured| *captured.borrow_mut() = Some(CapturedCommand::default()));
+    let result = run();
+    let captured = CAPTURED
+        .with(|captured| captured.borrow_mut().take())
+        .unwrap_or_default();
+    (captured, result)
+}
+
+/// Whether the parsed invocation is a read or safe write command the daemon serves.
+///
+/// Commands that write files relative to the caller's working directory stay
+/// local because the daemon cannot resolve those paths.
+pub fn is_daemon_routable(cli: &Cli) -> bool {
+    let Some(command) = cli.command.as_ref() else {
+        return cli.search.is_some();
+    };
+
+    match command {
+        Commands::Context(args) => args.output.is_none(),
+        Commands::SirContext(args) => args.output.is_none(),
+        Commands::Contract(args) => matches!(
+            args.command,
+            ContractCommand::Add(_)
+                | ContractCommand::List(_)
+                | ContractCommand::Remove(_)
+                | ContractCommand::Check(_)
+                | ContractCommand::Policies(_)
+        ),
+        Commands::TaskHistory(_)
+        | Commands::TaskRelevance(_)
+        | Commands::SirDiff(_)
+        | Commands::Remember(_)
+        | Commands::Recall(_)
+        | Commands::Ask(_)
+        | Commands::Notes(_)
+        | Commands::MineCoupling(_)
+        | Commands::BlastRadius(_)
+        | Commands::CouplingReport(_)
+        | Commands::TestIntents(_)
+        | Commands::DriftReport(_)
+        | Commands::DriftAck(_)
+        | Commands::Communities(_)
+        | Commands::TraceCause(_)
+        | Commands::Health(_)
+        | Commands::HealthScore(_)
+        | Commands::ArchCheck(_)
+        | Commands::ReleaseCheck(_)
+        | Commands::DeadCode(_)
+        | Commands::AffectedTests(_)
+        | Commands::TestResults(_)
+        | Commands::Triage(_)
+        | Commands::Profile(_)
+        | Commands::Ownership(_)
+        | Commands::Docs(_)
+        | Commands::Graph(_) => true,
+        _ => false,
+    }
+}
+
+/// Strip the program name and the optional leading `--` separator from argv.
+pub fn forwarded_args(args: impl IntoIterator<Item = OsString>) -> Vec<String> {
+    let mut args = args
+        .into_iter()
+        .skip(1)
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    if args.first().is_some_and(|arg| arg == "--") {
+        args.remove(0);
+    }
+    args
+}
+
+fn dispatch(workspace: &Path, cli: Cli) -> Result<()> {
+    use crate::affected_tests::run_affected_tests_command;
+    use crate::architecture::run_arch_check_command;
+    use crate::causal::run_trace_cause_command;
+    use crate::contracts::run_contract_command;
+    use crate::coupling::{
+        run_blast_radius_command, run_coupling_report_command, run_mine_coupling_command,
+    };
+    use crate::dead_code::run_dead_code_command;
+    use crate::docs::run_docs_command;
+    use crate::drift::{run_communities_command, run_drift_ack_command, run_drift_report_command};
+    use crate::graph::run_graph_command;
+    use crate::health::run_health_command;
+    use crate::health_score::run_health_score_command;
+    use crate::memory::{
+        run_ask_command, run_notes_command, run_recall_command, run_remember_command,
+    };
+    use crate::ownership::run_ownership_command;
+    use crate::profile::run_profile_command;
+    use crate::release_check::run_release_check_command;
+    use crate::search::{SearchFilters, run_search_once};
+    use crate::sir_context::{run_context_command, run_sir_context_command};
+    use crate::sir_diff::run_sir_diff_command;
+    use crate::task_context::{run_task_history_command, run_task_relevance_command};
+    use crate::test_intents::run_test_intents_command;
+    use crate::test_results::{run_test_results_command, run_triage_command};
+
+    if !is_daemon_routable(&cli) {
+        bail!("this command cannot be executed by the running daemon");
+    }
+
+    if let Some(query) = cli.search.as_deref() {
+        let mut out = command_stdout();
+        return run_search_once(
+            workspace,
+            query,
+            cli.search_limit.min(100),
+            cli.search_mode,
+            &SearchFilters {
+                sir_fields: cli.sir_field.clone(),
+                roots: cli.search_root.clone(),
+            },
+            cli.output,
+            &mut out,
+        );
+    }
+
+    let config = aether_config::load_workspace_config(workspace)
+        .context("failed to load workspace config")?;
+    match cli.command.expect("routable invocation has a subcommand") {
+        Commands::Context(args) => {
+            run_context_command(workspace, args).context("context command failed")
+        }
+        Commands::TaskHistory(args) => {
+            run_task_history_command(workspace, args).context("task-history command failed")
+        }
+        Commands::TaskRelevance(args) => {
+            run_task_relevance_command(workspace, args).context("task-relevance command failed")
+        }
+        Commands::SirContext(args) => {
+            run_sir_context_command(workspace, args).context("sir-context command failed")
+        }
+        Commands::SirDiff(args) => {
+            run_sir_diff_command(workspace, args).context("sir-diff command failed")
+        }
+        Commands::Remember(args) => {
+            run_remember_command(workspace, args).context("remember command failed")
+        }
+        Commands::Recall(args) => {
+            run_recall_command(workspace, args).context("recall command failed")
+        }
+        Commands::Ask(args) => run_ask_command(workspace, args).context("ask command failed"),
+        Commands::Notes(args) => run_notes_command(workspace, args).context("notes command failed"),
+        Commands::MineCoupling(args) => {
+            run_mine_coupling_command(workspace, args).context("mine-coupling command failed")
+        }
+        Commands::BlastRadius(args) => {
+            run_blast_radius_command(workspace, args).context("blast-radius command failed")
+        }
+        Commands::CouplingReport(args) => {
+            run_coupling_report_command(workspace, args).context("coupling-report command failed")
+        }
+        Commands::TestIntents(args) => {
+            run_test_intents_command(workspace, args).context("test-intents command failed")
+        }
+        Commands::DriftReport(args) => {
+            run_drift_report_command(workspace, args).context("drift-report command failed")
+        }
+        Commands::DriftAck(args) => {
+            run_drift_ack_command(workspace, args).context("drift-ack command failed")
+        }
+        Commands::Communities(args) => {
+            run_communities_command(workspace, args).context("communities command failed")
+        }
+        Commands::TraceCause(args) => {
+            run_trace_cause_command(workspace, args).context("trace-cause command failed")
+        }
+        Commands::Health(args) => {
+            run_health_command(workspace, args).context("health command failed")
+        }
+        Commands::HealthScore(args) => run_health_score_command(workspace, &config, args)
+            .context("health-score command failed"),
+        Commands::Contract(args) => {
+            run_contract_command(workspace, &config, args).context("contract command failed")
+        }
+        Commands::ArchCheck(args) => {
+            run_arch_check_command(workspace, args).context("arch-check command failed")
+        }
+        Commands::ReleaseCheck(args) => {
+            run_release_check_command(workspace, args).context("release-check command failed")
+        }
+        Commands::DeadCode(args) => {
+            run_dead_code_command(workspace, args).context("dead-code command failed")
+        }
+        Commands::AffectedTests(args) => {
+            run_affected_tests_command(workspace, args).context("affected-tests command failed")
+        }
+        Commands::TestResults(args) => {
+            run_test_results_command(workspace, args).context("test-results command failed")
+        }
+        Commands::Triage(args) => {
+            run_triage_command(workspace, args).context("triage command failed")
+        }
+        Commands::Profile(args) => {
+            run_profile_command(workspace, args).context("profile command failed")
+        }
+        Commands::Ownership(args) => {
+            run_ownership_command(workspace, args).context("ownership command failed")
+        }
+        Commands::Docs(args) => run_docs_command(workspace, args).context("docs command failed"),
+        Commands::Graph(args) => run_graph_command(workspace, args).context("graph command failed"),
+        _ => bail!("this command cannot be executed by the running daemon"),
+    }
+}
+
+fn execute_request(workspace: &Path, request: RpcRequest) -> RpcResponse {
+    let failure = |error: anyhow::Error| RpcResponse {
+        stdout: String::new(),
+        stderr: String::new(),
+        exit_code: 1,
+        error: Some(format!("{error:?}")),
+    };
+
+    if request.version != RPC_PROTOCOL_VERSION {
+        return failure(anyhow!(
+            "unsupported daemon RPC version {} (daemon speaks {RPC_PROTOCOL_VERSION})",
+            request.version
+        ));
+    }
+    if let Err(err) = ensure_same_workspace(workspace, request.workspace.as_str()) {
+        return failure(err);
+    }
+    let cli = match Cli::try_parse_from(std::iter::once("aetherd".to_owned()).chain(request.args)) {
+        Ok(cli) => cli,
+        Err(err) => return failure(anyhow!("{err}")),
+    };
+
+    let (captured, result) = capture_command(|| dispatch(workspace, cli));
+    RpcResponse {
+        stdout: String::from_utf8_lossy(&captured.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&captured.stderr).into_owned(),
+        exit_code: if result.is_err() {
+            1
+        } else {
+            captured.exit_code
+        },
+        error: result.err().map(|err| format!("{err:?}")),
+    }
+}
+
+fn ensure_same_workspace(workspace: &Path, remote_workspace: &str) -> Result<()> {
+    let local = workspace
+        .canonicalize()
+        .with_context(|| format!("failed to canonicalize workspace {}", workspace.display()))?;
+    let remote = Path::new(remote_workspace)
+        .canonicalize()
+        .with_context(|| format!("failed to canonicalize client workspace {remote_workspace}"))?;
+    if local != remote {
+        bail!(
+            "daemon is serving a different workspace: daemon={} client={}",
+            local.display(),
+            remote.display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+pub use unix::{DaemonRpcServer, spawn_daemon_rpc_server, try_run_in_daemon};
+
+#[cfg(not(unix))]
+pub use fallback::{DaemonRpcServer, spawn_daemon_rpc_server, try_run_in_daemon};
+
+#[cfg(unix)]
+mod unix {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::fs::PermissionsExt;
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::{Path, PathBuf};
+    use std::time::Duration;
+
+    use anyhow::{Context, Result, anyhow, bail};
+
+    use super::{
+        DaemonRpcOutput, RPC_PROTOCOL_VERSION, RpcRequest, RpcResponse, daemon_socket_path,
+        execute_request,
+    };
+
+    /// Listening socket owned by the daemon; the socket file is removed on drop.
+    #[derive(Debug)]
+    pub struct DaemonRpcServer {
+        socket_path: PathBuf,
+    }
+
+    impl DaemonRpcServer {
+        pub fn socket_path(&self) -> &Path {
+            &self.socket_path
+        }
+    }
+
+    impl Drop for DaemonRpcServer {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.socket_path);
+        }
+    }
+
+    /// Bind `.aether/daemon.sock` and serve CLI requests on background threads.
+    pub fn spawn_daemon_rpc_server(workspace: &Path) -> Result<DaemonRpcServer> {
+        let workspace = workspace
+            .canonicalize()
+            .with_context(|| format!("failed to canonicalize workspace {}", workspace.display()))?;
+        let socket_path = daemon_socket_path(&workspace);
+        if socket_path.exists() {
+            if UnixStream::connect(&socket_path).is_ok() {
+                bail!(
+                    "another daemon is already serving {}",
+                    socket_path.display()
+                );
+            }
+            std::fs::remove_file(&socket_path).with_context(|| {
+                format!("failed to remove stale socket {}", socket_path.display())
+            })?;
+        }
+        if let Some(parent) = socket_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+
+        let listener = UnixListener::bind(&socket_path)
+            .with_context(|| format!("failed to bind {}", socket_path.display()))?;
+        std::fs::set_permissions(&socket_path, std::fs::Permissions::from_mode(0o600))
+            .with_context(|| format!("failed to restrict {}", socket_path.display()))?;
+
+        std::thread::Builder::new()
+            .name("aether-rpc".to_owned())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    let stream = match stream {
+                        Ok(stream) => stream,
+                        Err(err) => {
+                            tracing::warn!(error = %err, "daemon RPC accept failed");
+                            continue;
+                        }
+                    };
+                    let workspace = workspace.clone();
+                    let spawned = std::thread::Builder::new()
+                        .name("aether-rpc-conn".to_owned())
+                        .spawn(move || {
+                            if let Err(err) = serve_connection(&workspace, stream) {
+                                tracing::warn!(error = %err, "daemon RPC request failed");
+                            }
+                        });
+                    if let Err(err) = spawned {
+                        tracing::warn!(error = %err, "failed to spawn daemon RPC worker");
+                    }
+                }
+            })
+            .context("failed to spawn daemon RPC listener thread")?;
+
+        Ok(DaemonRpcServer { socket_path })
+    }
+
+    fn serve_connection(workspace: &Path, stream: UnixStream) -> Result<()> {
+        let mut reader = BufReader::new(stream.try_clone().context("failed to clone stream")?);
+        let mut line = String::new();
+        reader
+            .read_line(&mut line)
+            .context("failed to read RPC request")?;
+        let request: RpcRequest =
+            serde_json::from_str(line.trim_end()).context("failed to parse RPC request")?;
+
+        tracing::debug!(args = ?request.args, "serving CLI command over daemon RPC");
+        let response = execute_request(workspace, request);
+        write_message(&stream, &response)
+    }
+
+    fn write_message<T: serde::Serialize>(mut stream: &UnixStream, message: &T) -> Result<()> {
+        serde_json::to_writer(&mut stream, message).context("failed to encode RPC message")?;
+        stream
+            .write_all(b"\n")
+            .context("failed to write RPC message")?;
+        stream.flush().context("failed to flush RPC message")
+    }
+
+    /// Run the invocation inside the workspace daemon if one is listening.
+    ///
+    /// Returns `Ok(None)` when no daemon socket accepts the connection, so the
+    /// caller can fall back to running the command locally.
+    pub fn try_run_in_daemon(
+        workspace: &Path,
+        args: Vec<String>,
+    ) -> Result<Option<DaemonRpcOutput>> {
+        let socket_path = daemon_socket_path(workspace);
+        let Ok(stream) = UnixStream::connect(&socket_path) else {
+            return Ok(None);
+        };
+        stream
+            .set_write_timeout(Some(Duration::from_secs(5)))
+            .context("failed to configure RPC socket")?;
+
+        let request = RpcRequest {
+            version: RPC_PROTOCOL_VERSION,
+            workspace: workspace.display().to_string(),
+            args,
+        };
+        write_message(&stream, &request)?;
+
+        let mut line = String::new();
+        BufReader::new(&stream)
+            .read_line(&mut line)
+            .context("failed to read daemon RPC response")?;
+        if line.trim().is_empty() {
+            return Err(anyhow!(
+                "daemon closed the RPC connection without a response"
+            ));
+        }
+        let response: RpcResponse =
+            serde_json::from_str(line.trim_end()).context("failed to parse daemon RPC response")?;
+
+        Ok(Some(DaemonRpcOutput {
+            stdout: response.stdout,
+            stderr: response.stderr,
+            exit_code: response.exit_code,
+            error: response.error,
+        }))
+    }
+}
+
+#[cfg(not(unix))]
+mod fallback {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use super::DaemonRpcOutput;
+
+    #[derive(Debug)]
+    pub struct DaemonRpcServer;
+
+    pub fn spawn_daemon_rpc_server(_workspace: &Path) -> Result<DaemonRpcServer> {
+        Ok(DaemonRpcServer)
+    }
+
+    pub fn try_run_in_daemon(
+        _workspace: &Path,
+        _args: Vec<String>,
+    ) -> Result<Option<DaemonRpcOutput>> {
+        Ok(None)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::io::Write;
+
+    use anyhow::Result;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn command_output_and_exit_code_are_captured_only_inside_rpc_scope() {
+        assert!(!serving_rpc_request());
+        let (captured, result) = capture_command(|| {
+            assert!(serving_rpc_request());
+            writeln!(command_stdout(), "captured line")?;
+            command_eprintln!("note: captured warning");
+            exit_command(2);
+            Ok(())
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(
+            String::from_utf8(captured.stdout).unwrap(),
+            "captured line\n"
+        );
+        assert_eq!(
+            String::from_utf8(captured.stderr).unwrap(),
+            "note: captured warning\n"
+        );
+        assert_eq!(captured.exit_code, 2);
+        assert!(!serving_rpc_request());
+    }
+
+    #[test]
+    fn routes_read_commands_but_not_daemon_or_file_writing_invocations() {
+        let parse = |args: &[&str]| {
+            Cli::try_parse_from(std::iter::once("aetherd").chain(args.iter().copied()))
+                .expect("cli parses")
+        };
+
+        assert!(is_daemon_routable(&parse(&["--search", "alpha"])));
+        assert!(is_daemon_routable(&parse(&["health"])));
+        assert!(is_daemon_routable(&parse(&["contract", "check"])));
+        assert!(is_daemon_routable(&parse(&["arch-check", "--json"])));
+        assert!(is_daemon_routable(&parse(&[
+            "release-check",
+            "--base",
+            "v1.0.0"
+        ])));
+        assert!(is_daemon_routable(&parse(&["dead-code", "--json"])));
+        assert!(is_daemon_routable(&parse(&[
+            "affected-tests",
+            "--base",
+            "origin/main"
+        ])));
+        assert!(is_daemon_routable(&parse(&[
+            "triage",
+            "--junit",
+            "report.xml"
+        ])));
+        assert!(is_daemon_routable(&parse(&["profile", "hot", "--json"])));
+        assert!(is_daemon_routable(&parse(&[
+            "ownership",
+            "--file",
+            "src/lib.rs"
+        ])));
+        assert!(is_daemon_routable(&parse(&[
+            "docs", "build", "--out", "site"
+        ])));
+        assert!(is_daemon_routable(&parse(&[
+            "graph", "export", "--format", "mermaid"
+        ])));
+        assert!(is_daemon_routable(&parse(&["context", "src/lib.rs"])));
+        assert!(!is_daemon_routable(&parse(&[
+            "context",
+            "src/lib.rs",
+            "--output",
+            "ctx.md"
+        ])));
+        assert!(!is_daemon_routable(&parse(&["--index-once"])));
+        assert!(!is_daemon_routable(&parse(&["status"])));
+    }
+
+    #[test]
+    fn forwarded_args_drop_program_name_and_separator() {
+        let args = forwarded_args(
+            ["aetherd", "--", "--workspace", ".", "health"]
+                .into_iter()
+                .map(OsString::from),
+        );
+        assert_eq!(args, vec!["--workspace", ".", "health"]);
+    }
+
+    #[test]
+    fn client_runs_command_inside_daemon_and_receives_output() -> Result<()> {
+        let workspace = TempDir::new()?;
+        let server = spawn_daemon_rpc_server(workspace.path())?;
+        assert!(server.socket_path().exists());
+
+        let output = try_run_in_daemon(
+            workspace.path(),
+            vec!["notes".to_owned(), "--limit".to_owned(), "5".to_owned()],
+        )?
+        .expect("daemon should answer");
+        assert_eq!(output.error, None);
+        assert_eq!(output.exit_code, 0);
+        let value: serde_json::Value = serde_json::from_str(&output.stdout)?;
+        assert!(value.is_array());
+
+        let rejected = try_run_in_daemon(workspace.path(), vec!["status".to_owned()])?
+            .expect("daemon should answer");
+        assert!(
+            rejected
+                .error
+                .as_deref()
+                .is_some_and(|error| error.contains("cannot be executed by the running daemon"))
+        );
+
+        let socket_path = server.socket_path().to_path_buf();
+        drop(server);
+        assert!(!socket_path.exists());
+        assert!(try_run_in_daemon(workspace.path(), vec!["notes".to_owned()])?.is_none());
+        Ok(())
+    }
+}
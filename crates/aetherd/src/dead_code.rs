@@ -0,0 +1,120 @@
+//! `aetherd dead-code`: symbols unreachable from mains, tests, route
+//! handlers, exported symbols and the public API.
+
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::Path;
+
+use aether_analysis::{DeadCodeAnalyzer, DeadCodeReport, DeadCodeRequest};
+use aether_store::SqliteStore;
+use anyhow::{Context, Result};
+
+use crate::cli::DeadCodeArgs;
+
+pub fn run_dead_code_command(workspace: &Path, args: DeadCodeArgs) -> Result<()> {
+    let analyzer = DeadCodeAnalyzer::new(workspace).context("failed to load dead-code config")?;
+    let store = SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+    let report = analyzer
+        .analyze(
+            &store,
+            &DeadCodeRequest {
+                min_confidence: args.min_confidence,
+                path_prefix: args.path,
+                limit: Some(args.limit),
+                public_api_roots: args.no_public_api.then_some(false),
+            },
+        )
+        .context("dead-code analysis failed")?;
+
+    let rendered = if args.json {
+        serde_json::to_string_pretty(&report).context("failed to serialize dead-code report")?
+    } else {
+        render_report(&report)
+    };
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    writeln!(&mut stdout, "{}", rendered.trim_end()).context("failed to write dead-code output")?;
+    Ok(())
+}
+
+fn render_report(report: &DeadCodeReport) -> String {
+    let roots = report
+        .roots
+        .iter()
+        .map(|(kind, count)| format!("{kind} {count}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let counts = ["high", "medium", "low"]
+        .iter()
+        .map(|level| {
+            format!(
+                "{} {level}",
+                report.confidence_counts.get(*level).copied().unwrap_or(0)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut out = format!(
+        "{} of {} symbol(s) reachable from roots ({})\nunreachable: {counts}\n",
+        report.reachable_symbols,
+        report.total_symbols,
+        if roots.is_empty() {
+            "none"
+        } else {
+            roots.as_str()
+        }
+    );
+    for entry in &report.unreachable {
+        let _ = writeln!(
+            out,
+            "[{}] {} {} ({}): {}",
+            entry.confidence.as_str(),
+            entry.kind,
+            entry.qualified_name,
+            entry.file_path,
+            entry.reasons.join("; ")
+        );
+    }
+    for note in &report.notes {
+        let _ = writeln!(out, "note: {note}");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use aether_analysis::{DeadCodeConfidence, UnreachableSymbol};
+
+    use super::*;
+
+    #[test]
+    fn render_report_lists_unreachable_symbols_with_reasons() {
+        let report = DeadCodeReport {
+            schema_version: "1.0".to_owned(),
+            edge_kinds: vec!["calls".to_owned()],
+            total_symbols: 12,
+            reachable_symbols: 10,
+            roots: BTreeMap::from([("main".to_owned(), 1), ("test".to_owned(), 3)]),
+            confidence_counts: BTreeMap::from([("high".to_owned(), 1), ("low".to_owned(), 1)]),
+            unreachable: vec![UnreachableSymbol {
+                symbol_id: "sym-1".to_owned(),
+                qualified_name: "legacy::convert".to_owned(),
+                file_path: "src/legacy.rs".to_owned(),
+                language: "rust".to_owned(),
+                kind: "function".to_owned(),
+                confidence: DeadCodeConfidence::High,
+                reasons: vec!["no path from any entry point".to_owned()],
+                dead_referrers: 0,
+            }],
+            notes: Vec::new(),
+        };
+
+        assert_eq!(
+            render_report(&report),
+            "10 of 12 symbol(s) reachable from roots (main 1, test 3)\n\
+             unreachable: 1 high, 0 medium, 1 low\n\
+             [high] function legacy::convert (src/legacy.rs): no path from any entry point\n"
+        );
+    }
+}
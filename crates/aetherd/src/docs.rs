@@ -0,0 +1,841 @@
+//! `aetherd docs build`: renders file and module SIR rollups, per-symbol SIR,
+//! dependency links, communities and contracts into an mdBook-compatible site.
+//!
+//! Pages are deterministic, so a rebuild only rewrites the pages whose content
+//! changed. A manifest of page hashes in the output directory lets the next
+//! build skip unchanged pages and remove pages for symbols that disappeared.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Write;
+use std::path::{Component, Path};
+
+use aether_sir::{FileSir, SirAnnotation, synthetic_file_sir_id, synthetic_module_sir_id};
+use aether_store::{
+    IntentContractRecord, SqliteStore, SymbolRecord, block_on_store_future,
+    open_surreal_graph_store_readonly,
+};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::cli::{DocsArgs, DocsBuildArgs, DocsCommand};
+
+const MANIFEST_FILE: &str = ".aether-docs.json";
+const MANIFEST_SCHEMA_VERSION: &str = "1.0";
+
+pub fn run_docs_command(workspace: &Path, args: DocsArgs) -> Result<()> {
+    match args.command {
+        DocsCommand::Build(args) => run_docs_build(workspace, args),
+    }
+}
+
+fn run_docs_build(workspace: &Path, args: DocsBuildArgs) -> Result<()> {
+    let out_dir = if args.out.is_absolute() {
+        args.out.clone()
+    } else {
+        workspace.join(&args.out)
+    };
+    let title = args.title.clone().unwrap_or_else(|| {
+        workspace
+            .canonicalize()
+            .ok()
+            .and_then(|path| {
+                path.file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+            })
+            .unwrap_or_else(|| "Workspace".to_owned())
+    });
+
+    let store = SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+    let site = collect_site(&store, workspace, title)?;
+    let pages = render_site(&site);
+    let mut summary = write_site(&out_dir, &pages, args.force)?;
+    summary.out_dir = args.out.display().to_string();
+    summary.notes = site.notes;
+
+    let rendered = if args.json {
+        serde_json::to_string_pretty(&summary).context("failed to serialize docs summary")?
+    } else {
+        render_summary(&summary)
+    };
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    writeln!(&mut stdout, "{}", rendered.trim_end()).context("failed to write docs output")?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+struct DocsSite {
+    title: String,
+    modules: Vec<ModuleDoc>,
+    symbols: BTreeMap<String, SymbolDoc>,
+    communities: BTreeMap<i64, Vec<String>>,
+    contracts: Vec<IntentContractRecord>,
+    notes: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct ModuleDoc {
+    path: String,
+    sir: Option<FileSir>,
+    files: Vec<FileDoc>,
+}
+
+#[derive(Debug, Clone)]
+struct FileDoc {
+    path: String,
+    sir: Option<FileSir>,
+    /// Symbol ids, ordered by qualified name.
+    symbols: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct SymbolDoc {
+    record: SymbolRecord,
+    sir: Option<SirAnnotation>,
+    /// `(edge_kind, target_id)` for every resolved outgoing edge.
+    dependencies: Vec<(String, String)>,
+    /// `(edge_kind, source_id)` for every resolved incoming edge.
+    callers: Vec<(String, String)>,
+    contracts: Vec<IntentContractRecord>,
+    community: Option<i64>,
+}
+
+fn collect_site(store: &SqliteStore, workspace: &Path, title: String) -> Result<DocsSite> {
+    let mut notes = Vec::new();
+    let records = store
+        .list_all_symbol_records()
+        .context("failed to list symbols")?;
+
+    let mut sir_ids = records
+        .iter()
+        .map(|record| record.id.clone())
+        .collect::<Vec<_>>();
+    let mut files = BTreeMap::<String, (String, Vec<&SymbolRecord>)>::new();
+    for record in &records {
+        files
+            .entry(record.file_path.clone())
+            .or_insert_with(|| (record.language.clone(), Vec::new()))
+            .1
+            .push(record);
+    }
+    let mut modules = BTreeMap::<String, Vec<String>>::new();
+    for (path, (language, _)) in &files {
+        sir_ids.push(synthetic_file_sir_id(language, path));
+        let module = module_of(path);
+        if !module.is_empty() {
+            sir_ids.push(synthetic_module_sir_id(language, &module));
+        }
+        modules.entry(module).or_default().push(path.clone());
+    }
+    let blobs = store
+        .list_sir_blobs_for_ids(&sir_ids)
+        .context("failed to read SIR")?;
+    let rollup = |id: String| {
+        blobs
+            .get(&id)
+            .and_then(|blob| serde_json::from_str::<FileSir>(blob).ok())
+    };
+
+    let mut symbols = records
+        .iter()
+        .map(|record| {
+            let sir = blobs
+                .get(&record.id)
+                .and_then(|blob| serde_json::from_str::<SirAnnotation>(blob).ok());
+            (
+                record.id.clone(),
+                SymbolDoc {
+                    record: record.clone(),
+                    sir,
+                    dependencies: Vec::new(),
+                    callers: Vec::new(),
+                    contracts: Vec::new(),
+                    community: None,
+                },
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    for edge in store
+        .list_graph_dependency_edges()
+        .context("failed to list dependency edges")?
+    {
+        if edge.source_symbol_id == edge.target_symbol_id
+            || !symbols.contains_key(&edge.target_symbol_id)
+        {
+            continue;
+        }
+        let Some(source) = symbols.get_mut(&edge.source_symbol_id) else {
+            continue;
+        };
+        source
+            .dependencies
+            .push((edge.edge_kind.clone(), edge.target_symbol_id.clone()));
+        if let Some(target) = symbols.get_mut(&edge.target_symbol_id) {
+            target.callers.push((edge.edge_kind, edge.source_symbol_id));
+        }
+    }
+
+    let contracts = store
+        .list_all_active_contracts()
+        .context("failed to list contracts")?;
+    for contract in &contracts {
+        if let Some(symbol) = symbols.get_mut(&contract.symbol_id) {
+            symbol.contracts.push(contract.clone());
+        }
+    }
+
+    let mut communities = BTreeMap::<i64, Vec<String>>::new();
+    match open_surreal_graph_store_readonly(workspace) {
+        Ok(graph) => match block_on_store_future(graph.list_louvain_communities()) {
+            Ok(Ok(assignments)) => {
+                for (symbol_id, community_id) in assignments {
+                    if let Some(symbol) = symbols.get_mut(&symbol_id) {
+                        symbol.community = Some(community_id);
+                        communities.entry(community_id).or_default().push(symbol_id);
+                    }
+                }
+            }
+            _ => notes.push("community detection failed; community map skipped".to_owned()),
+        },
+        Err(_) => notes.push(
+            "graph store unavailable (the daemon may hold its lock); community map skipped"
+                .to_owned(),
+        ),
+    }
+
+    let by_name = |ids: &mut Vec<String>| {
+        ids.sort_by(|left, right| {
+            let left_name = &symbols[left].record.qualified_name;
+            let right_name = &symbols[right].record.qualified_name;
+            left_name.cmp(right_name).then_with(|| left.cmp(right))
+        });
+    };
+    for members in communities.values_mut() {
+        by_name(members);
+    }
+
+    let modules = modules
+        .into_iter()
+        .map(|(module, paths)| {
+            let mut module_sir = None;
+            let files = paths
+                .into_iter()
+                .map(|path| {
+                    let (language, file_symbols) = &files[&path];
+                    if module_sir.is_none() && !module.is_empty() {
+                        module_sir = rollup(synthetic_module_sir_id(language, &module));
+                    }
+                    let mut ids = file_symbols
+                        .iter()
+                        .map(|record| record.id.clone())
+                        .collect::<Vec<_>>();
+                    by_name(&mut ids);
+                    FileDoc {
+                        sir: rollup(synthetic_file_sir_id(language, &path)),
+                        path,
+                        symbols: ids,
+                    }
+                })
+                .collect();
+            ModuleDoc {
+                path: module,
+                sir: module_sir,
+                files,
+            }
+        })
+        .collect();
+
+    for symbol in symbols.values_mut() {
+        symbol.dependencies.sort();
+        symbol.dependencies.dedup();
+        symbol.callers.sort();
+        symbol.callers.dedup();
+    }
+
+    Ok(DocsSite {
+        title,
+        modules,
+        symbols,
+        communities,
+        contracts,
+        notes,
+    })
+}
+
+/// Workspace-relative directory of a file; empty for files at the root.
+fn module_of(file_path: &str) -> String {
+    file_path
+        .rsplit_once('/')
+        .map(|(module, _)| module.to_owned())
+        .unwrap_or_default()
+}
+
+/// Flat page name for a path or id: `src/cart.rs` becomes `src__cart.rs`.
+fn slug(value: &str) -> String {
+    let mut slug = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '/' => slug.push_str("__"),
+            ch if ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | '_') => slug.push(ch),
+            _ => slug.push('_'),
+        }
+    }
+    if slug.is_empty() {
+        "root".to_owned()
+    } else {
+        slug
+    }
+}
+
+fn module_page(module: &str) -> String {
+    format!("modules/{}.md", slug(module))
+}
+
+fn file_page(file: &str) -> String {
+    format!("files/{}.md", slug(file))
+}
+
+fn symbol_page(symbol_id: &str) -> String {
+    format!("symbols/{}.md", slug(symbol_id))
+}
+
+fn module_title(module: &str) -> &str {
+    if module.is_empty() { "(root)" } else { module }
+}
+
+/// Link text inside `SUMMARY.md`, which mdBook reads as plain text.
+fn summary_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+}
+
+/// Renders every page keyed by its path relative to the output directory.
+fn render_site(site: &DocsSite) -> BTreeMap<String, String> {
+    let mut pages = BTreeMap::new();
+    pages.insert(
+        "book.toml".to_owned(),
+        format!(
+            "[book]\ntitle = {}\nsrc = \"src\"\n",
+            toml::Value::String(site.title.clone())
+        ),
+    );
+
+    let mut summary = String::from("# Summary\n\n[Overview](README.md)\n\n");
+    for module in &site.modules {
+        let _ = writeln!(
+            summary,
+            "- [{}]({})",
+            summary_text(module_title(&module.path)),
+            module_page(&module.path)
+        );
+        for file in &module.files {
+            let _ = writeln!(
+                summary,
+                "  - [{}]({})",
+                summary_text(&file.path),
+                file_page(&file.path)
+            );
+            for symbol_id in &file.symbols {
+                let _ = writeln!(
+                    summary,
+                    "    - [{}]({})",
+                    summary_text(&site.symbols[symbol_id].record.qualified_name),
+                    symbol_page(symbol_id)
+                );
+            }
+        }
+    }
+    summary.push_str("- [Communities](communities.md)\n- [Contracts](contracts.md)\n");
+    pages.insert("src/SUMMARY.md".to_owned(), summary);
+    pages.insert("src/README.md".to_owned(), render_overview(site));
+
+    for module in &site.modules {
+        pages.insert(
+            format!("src/{}", module_page(&module.path)),
+            render_module(module),
+        );
+        for file in &module.files {
+            pages.insert(
+                format!("src/{}", file_page(&file.path)),
+                render_file(site, file),
+            );
+        }
+    }
+    for (symbol_id, symbol) in &site.symbols {
+        pages.insert(
+            format!("src/{}", symbol_page(symbol_id)),
+            render_symbol(site, symbol),
+        );
+    }
+    pages.insert("src/communities.md".to_owned(), render_communities(site));
+    pages.insert("src/contracts.md".to_owned(), render_contracts(site));
+    pages
+}
+
+fn render_overview(site: &DocsSite) -> String {
+    let files = site
+        .modules
+        .iter()
+        .map(|module| module.files.len())
+        .sum::<usize>();
+    let with_sir = site
+        .symbols
+        .values()
+        .filter(|symbol| symbol.sir.is_some())
+        .count();
+    let mut out = format!("# {}\n\n", site.title);
+    let _ = writeln!(
+        out,
+        "- Modules: {}\n- Files: {files}\n- Symbols: {} ({with_sir} with SIR)\n\
+         - Communities: {}\n- Active contract clauses: {}",
+        site.modules.len(),
+        site.symbols.len(),
+        site.communities.len(),
+        site.contracts.len()
+    );
+    out.push_str("\n## Modules\n\n");
+    for module in &site.modules {
+        let intent = module
+            .sir
+            .as_ref()
+            .map(|sir| format!(" — {}", sir.intent))
+            .unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "- [{}]({}){intent}",
+            module_title(&module.path),
+            module_page(&module.path)
+        );
+    }
+    out
+}
+
+fn render_module(module: &ModuleDoc) -> String {
+    let mut out = format!("# {}\n\n", module_title(&module.path));
+    if let Some(sir) = &module.sir {
+        render_rollup(&mut out, sir);
+    }
+    out.push_str("## Files\n\n");
+    for file in &module.files {
+        let intent = file
+            .sir
+            .as_ref()
+            .map(|sir| format!(" — {}", sir.intent))
+            .unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "- [`{}`](../{}){intent}",
+            file.path,
+            file_page(&file.path)
+        );
+    }
+    out
+}
+
+fn render_file(site: &DocsSite, file: &FileDoc) -> String {
+    let mut out = format!(
+        "# `{}`\n\nModule: [{}](../{})\n\n",
+        file.path,
+        module_title(&module_of(&file.path)),
+        module_page(&module_of(&file.path))
+    );
+    match &file.sir {
+        Some(sir) => render_rollup(&mut out, sir),
+        None => out.push_str("_No file rollup has been generated yet._\n\n"),
+    }
+    out.push_str("## Symbols\n\n");
+    for symbol_id in &file.symbols {
+        let symbol = &site.symbols[symbol_id];
+        let intent = symbol
+            .sir
+            .as_ref()
+            .map(|sir| format!(" — {}", sir.intent))
+            .unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "- {} [`{}`](../{}){intent}",
+            symbol.record.kind,
+            symbol.record.qualified_name,
+            symbol_page(symbol_id)
+        );
+    }
+    out
+}
+
+fn render_rollup(out: &mut String, sir: &FileSir) {
+    let _ = writeln!(out, "{}\n", sir.intent);
+    render_list(out, "Exports", &sir.exports);
+    render_list(out, "Side effects", &sir.side_effects);
+    render_list(out, "Dependencies", &sir.dependencies);
+    render_list(out, "Error modes", &sir.error_modes);
+}
+
+fn render_symbol(site: &DocsSite, symbol: &SymbolDoc) -> String {
+    let record = &symbol.record;
+    let mut out = format!(
+        "# `{}`\n\n- Kind: {}\n- Language: {}\n- File: [`{}`](../{})\n",
+        record.qualified_name,
+        record.kind,
+        record.language,
+        record.file_path,
+        file_page(&record.file_path)
+    );
+    if let Some(community) = symbol.community {
+        let _ = writeln!(
+            out,
+            "- Community: [#{community}](../communities.md#community-{community})"
+        );
+    }
+    out.push('\n');
+
+    match &symbol.sir {
+        Some(sir) => {
+            let _ = writeln!(out, "## Intent\n\n{}\n", sir.intent);
+            if let Some(behavior) = sir.behavior.as_deref() {
+                let _ = writeln!(out, "## Behavior\n\n{behavior}\n");
+            }
+            render_list(&mut out, "Inputs", &sir.inputs);
+            render_list(&mut out, "Outputs", &sir.outputs);
+            render_list(&mut out, "Side effects", &sir.side_effects);
+            render_list(&mut out, "Error modes", &sir.error_modes);
+            if let Some(edge_cases) = sir.edge_cases.as_deref() {
+                let _ = writeln!(out, "## Edge cases\n\n{edge_cases}\n");
+            }
+        }
+        None => out.push_str("_No SIR has been generated for this symbol yet._\n\n"),
+    }
+
+    if !symbol.contracts.is_empty() {
+        out.push_str("## Contracts\n\n");
+        for contract in &symbol.contracts {
+            let _ = writeln!(
+                out,
+                "- **{}** {}",
+                contract.clause_type, contract.clause_text
+            );
+        }
+        out.push('\n');
+    }
+    render_links(&mut out, site, "Dependencies", &symbol.dependencies);
+    render_links(&mut out, site, "Callers", &symbol.callers);
+    out
+}
+
+fn render_list(out: &mut String, heading: &str, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "## {heading}\n");
+    for item in items {
+        let _ = writeln!(out, "- {item}");
+    }
+    out.push('\n');
+}
+
+fn render_links(out: &mut String, site: &DocsSite, heading: &str, links: &[(String, String)]) {
+    if links.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "## {heading}\n");
+    for (edge_kind, symbol_id) in links {
+        let _ = writeln!(
+            out,
+            "- {edge_kind} [`{}`]({}.md)",
+            site.symbols[symbol_id].record.qualified_name,
+            slug(symbol_id)
+        );
+    }
+    out.push('\n');
+}
+
+fn render_communities(site: &DocsSite) -> String {
+    let mut out = String::from("# Communities\n\n");
+    if site.communities.is_empty() {
+        out.push_str("_No community assignments are available._\n");
+        return out;
+    }
+    for (community, members) in &site.communities {
+        let _ = writeln!(
+            out,
+            "## Community {community}\n\n{} symbol(s)\n",
+            members.len()
+        );
+        for symbol_id in members {
+            let record = &site.symbols[symbol_id].record;
+            let _ = writeln!(
+                out,
+                "- [`{}`]({}) in `{}`",
+                record.qualified_name,
+                symbol_page(symbol_id),
+                record.file_path
+            );
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn render_contracts(site: &DocsSite) -> String {
+    let mut out = String::from("# Contracts\n\n");
+    if site.contracts.is_empty() {
+        out.push_str("_No active contracts._\n");
+        return out;
+    }
+    let mut current = None;
+    for contract in &site.contracts {
+        if current != Some(contract.symbol_id.as_str()) {
+            current = Some(contract.symbol_id.as_str());
+            match site.symbols.get(&contract.symbol_id) {
+                Some(symbol) => {
+                    let _ = writeln!(
+                        out,
+                        "\n## [`{}`]({})\n",
+                        symbol.record.qualified_name,
+                        symbol_page(&contract.symbol_id)
+                    );
+                }
+                None => {
+                    let _ = writeln!(out, "\n## `{}` (not indexed)\n", contract.symbol_id);
+                }
+            }
+        }
+        let _ = writeln!(
+            out,
+            "- **{}** {}",
+            contract.clause_type, contract.clause_text
+        );
+    }
+    out
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DocsManifest {
+    schema_version: String,
+    /// Page path relative to the output directory -> blake3 of its content.
+    pages: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+struct DocsBuildSummary {
+    out_dir: String,
+    pages: usize,
+    written: usize,
+    unchanged: usize,
+    removed: usize,
+    notes: Vec<String>,
+}
+
+/// Writes the pages that differ from the previous build and removes pages the
+/// previous build wrote that are no longer part of the site.
+fn write_site(
+    out_dir: &Path,
+    pages: &BTreeMap<String, String>,
+    force: bool,
+) -> Result<DocsBuildSummary> {
+    let manifest_path = out_dir.join(MANIFEST_FILE);
+    let previous = fs::read_to_string(&manifest_path)
+        .ok()
+        .and_then(|raw| serde_json::from_str::<DocsManifest>(&raw).ok())
+        .filter(|manifest| manifest.schema_version == MANIFEST_SCHEMA_VERSION)
+        .unwrap_or_default();
+
+    let mut summary = DocsBuildSummary {
+        pages: pages.len(),
+        ..DocsBuildSummary::default()
+    };
+    let mut hashes = BTreeMap::new();
+    for (relative, content) in pages {
+        let hash = blake3::hash(content.as_bytes()).to_hex().to_string();
+        let path = out_dir.join(relative);
+        if !force && previous.pages.get(relative) == Some(&hash) && path.is_file() {
+            summary.unchanged += 1;
+        } else {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("failed to create {}", parent.display()))?;
+            }
+            fs::write(&path, content)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            summary.written += 1;
+        }
+        hashes.insert(relative.clone(), hash);
+    }
+
+    for relative in previous.pages.keys() {
+        if pages.contains_key(relative) || !is_plain_relative(relative) {
+            continue;
+        }
+        let path = out_dir.join(relative);
+        if path.is_file() {
+            fs::remove_file(&path)
+                .with_context(|| format!("failed to remove {}", path.display()))?;
+            summary.removed += 1;
+        }
+    }
+
+    let manifest = DocsManifest {
+        schema_version: MANIFEST_SCHEMA_VERSION.to_owned(),
+        pages: hashes,
+    };
+    let raw = serde_json::to_string_pretty(&manifest).context("failed to serialize manifest")?;
+    fs::write(&manifest_path, raw)
+        .with_context(|| format!("failed to write {}", manifest_path.display()))?;
+    Ok(summary)
+}
+
+/// Guards removals against a hand-edited manifest pointing outside the site.
+fn is_plain_relative(relative: &str) -> bool {
+    Path::new(relative)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+}
+
+fn render_summary(summary: &DocsBuildSummary) -> String {
+    let mut out = format!(
+        "Built {} page(s) in {}: {} written, {} unchanged, {} removed\n",
+        summary.pages, summary.out_dir, summary.written, summary.unchanged, summary.removed
+    );
+    for note in &summary.notes {
+        let _ = writeln!(out, "note: {note}");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(id: &str, name: &str, file: &str) -> SymbolRecord {
+        SymbolRecord {
+            id: id.to_owned(),
+            file_path: file.to_owned(),
+            language: "rust".to_owned(),
+            kind: "function".to_owned(),
+            qualified_name: name.to_owned(),
+            signature_fingerprint: String::new(),
+            last_seen_at: 0,
+        }
+    }
+
+    fn sample_site() -> DocsSite {
+        let contract = IntentContractRecord {
+            id: 7,
+            symbol_id: "sym-total".to_owned(),
+            clause_type: "must".to_owned(),
+            clause_text: "never panic".to_owned(),
+            clause_embedding_json: None,
+            created_at: 0,
+            created_by: "dev".to_owned(),
+            active: true,
+            violation_streak: 0,
+        };
+        let mut symbols = BTreeMap::new();
+        symbols.insert(
+            "sym-total".to_owned(),
+            SymbolDoc {
+                record: symbol("sym-total", "Cart::total", "src/cart.rs"),
+                sir: Some(SirAnnotation {
+                    intent: "Sums the price of every line item".to_owned(),
+                    behavior: None,
+                    inputs: Vec::new(),
+                    outputs: Vec::new(),
+                    side_effects: Vec::new(),
+                    dependencies: Vec::new(),
+                    error_modes: vec!["panics on an item without a price".to_owned()],
+                    confidence: 0.9,
+                    edge_cases: None,
+                    complexity: None,
+                    method_dependencies: None,
+                    extensions: BTreeMap::new(),
+                }),
+                dependencies: vec![("calls".to_owned(), "sym-price".to_owned())],
+                callers: Vec::new(),
+                contracts: vec![contract.clone()],
+                community: Some(4),
+            },
+        );
+        symbols.insert(
+            "sym-price".to_owned(),
+            SymbolDoc {
+                record: symbol("sym-price", "Item::price", "src/cart.rs"),
+                sir: None,
+                dependencies: Vec::new(),
+                callers: vec![("calls".to_owned(), "sym-total".to_owned())],
+                contracts: Vec::new(),
+                community: Some(4),
+            },
+        );
+        DocsSite {
+            title: "shop".to_owned(),
+            modules: vec![ModuleDoc {
+                path: "src".to_owned(),
+                sir: None,
+                files: vec![FileDoc {
+                    path: "src/cart.rs".to_owned(),
+                    sir: None,
+                    symbols: vec!["sym-total".to_owned(), "sym-price".to_owned()],
+                }],
+            }],
+            symbols,
+            communities: BTreeMap::from([(
+                4,
+                vec!["sym-price".to_owned(), "sym-total".to_owned()],
+            )]),
+            contracts: vec![contract],
+            notes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn render_site_links_symbols_dependencies_and_contracts() {
+        let pages = render_site(&sample_site());
+
+        assert_eq!(
+            pages["src/symbols/sym-total.md"],
+            "# `Cart::total`\n\n\
+             - Kind: function\n\
+             - Language: rust\n\
+             - File: [`src/cart.rs`](../files/src__cart.rs.md)\n\
+             - Community: [#4](../communities.md#community-4)\n\n\
+             ## Intent\n\nSums the price of every line item\n\n\
+             ## Error modes\n\n- panics on an item without a price\n\n\
+             ## Contracts\n\n- **must** never panic\n\n\
+             ## Dependencies\n\n- calls [`Item::price`](sym-price.md)\n\n"
+        );
+        assert!(pages["src/SUMMARY.md"].contains(
+            "- [src](modules/src.md)\n  - [src/cart.rs](files/src__cart.rs.md)\n    \
+                 - [Cart::total](symbols/sym-total.md)\n"
+        ));
+        assert!(
+            pages["src/symbols/sym-price.md"].contains("## Callers\n\n- calls [`Cart::total`]")
+        );
+        assert_eq!(
+            pages["book.toml"],
+            "[book]\ntitle = \"shop\"\nsrc = \"src\"\n"
+        );
+    }
+
+    #[test]
+    fn write_site_skips_unchanged_pages_and_removes_stale_ones() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let mut pages = render_site(&sample_site());
+
+        let first = write_site(temp.path(), &pages, false).expect("first build");
+        assert_eq!(first.written, pages.len());
+
+        pages.remove("src/symbols/sym-price.md");
+        pages.insert("src/contracts.md".to_owned(), "# Contracts\n".to_owned());
+        let second = write_site(temp.path(), &pages, false).expect("second build");
+        assert_eq!(second.written, 1);
+        assert_eq!(second.unchanged, pages.len() - 1);
+        assert_eq!(second.removed, 1);
+        assert!(!temp.path().join("src/symbols/sym-price.md").exists());
+
+        let forced = write_site(temp.path(), &pages, true).expect("forced build");
+        assert_eq!(forced.written, pages.len());
+    }
+}
@@ -57,10 +57,10 @@ pub fn run_communities_command(workspace: &Path, args: CommunitiesArgs) -> Resul
     let config = load_workspace_config(workspace).context("failed to load workspace config")?;
     let output = load_communities_result(workspace, &config, &args)?;
     if let Some(port) = output.daemon_port {
-        eprintln!("{}", daemon_notice_message(port));
+        crate::daemon_rpc::command_eprintln!("{}", daemon_notice_message(port));
     }
 
-    let mut out = std::io::stdout();
+    let mut out = crate::daemon_rpc::command_stdout();
     write_communities_output(&mut out, &output.result, args.format)
 }
 
@@ -142,7 +142,7 @@ fn write_communities_output<W: Write>(
 }
 
 fn write_json_to_stdout(value: &serde_json::Value) -> Result<()> {
-    let mut out = std::io::stdout();
+    let mut out = crate::daemon_rpc::command_stdout();
     write_json(&mut out, value)
 }
 
@@ -0,0 +1,311 @@
+//! Links HTTP client call sites to route handlers with `endpoint` edges.
+//!
+//! Edges are owned by the client file, so reindexing a client rebuilds its own
+//! links while a changed route file relinks every client that called the old
+//! or new routes. The initial index only records each file's endpoint sites
+//! and links every client in one pass at the end.
+
+use std::collections::BTreeSet;
+
+use aether_core::{EdgeKind, EndpointCall, EndpointRoute, SymbolEdge, normalize_path};
+use aether_store::SqliteStore;
+use anyhow::{Context, Result};
+
+/// Endpoint edges from each call to every route it reaches.
+pub(crate) fn endpoint_edges_for_calls(
+    calls: &[EndpointCall],
+    routes: &[EndpointRoute],
+) -> Vec<SymbolEdge> {
+    let mut edges = calls
+        .iter()
+        .flat_map(|call| {
+            routes
+                .iter()
+                .filter(move |route| route.file_path != call.file_path && route.matches(call))
+                .map(move |route| SymbolEdge {
+                    source_id: call.source_id.clone(),
+                    target_qualified_name: route.handler.clone(),
+                    edge_kind: EdgeKind::Endpoint,
+                    file_path: call.file_path.clone(),
+                })
+        })
+        .collect::<Vec<_>>();
+    edges.sort_by(|left, right| {
+        left.file_path
+            .cmp(&right.file_path)
+            .then_with(|| left.source_id.cmp(&right.source_id))
+            .then_with(|| left.target_qualified_name.cmp(&right.target_qualified_name))
+    });
+    edges.dedup();
+    edges
+}
+
+/// Files containing a call that reaches any of `routes`.
+pub(crate) fn client_files_for_routes(
+    calls: &[EndpointCall],
+    routes: &[EndpointRoute],
+) -> BTreeSet<String> {
+    calls
+        .iter()
+        .filter(|call| routes.iter().any(|route| route.matches(call)))
+        .map(|call| call.file_path.clone())
+        .collect()
+}
+
+/// Records the endpoint sites extracted from `file_path` and rebuilds the
+/// endpoint edges they affect. Returns the other client files whose edges
+/// changed, which still need their neighbors and graph edges refreshed.
+pub(crate) fn update_endpoint_links(
+    store: &SqliteStore,
+    file_path: &str,
+    routes: &[EndpointRoute],
+    calls: &[EndpointCall],
+) -> Result<BTreeSet<String>> {
+    let file_path = normalize_path(file_path);
+    let previous_routes = store
+        .list_endpoint_routes_for_file(&file_path)
+        .with_context(|| format!("failed to load endpoint routes for {file_path}"))?;
+    store
+        .replace_endpoints_for_file(&file_path, routes, calls)
+        .with_context(|| format!("failed to store endpoint sites for {file_path}"))?;
+
+    let all_routes = store
+        .list_endpoint_routes()
+        .context("failed to list endpoint routes")?;
+    let all_calls = store
+        .list_endpoint_calls()
+        .context("failed to list endpoint calls")?;
+
+    let mut relinked = BTreeSet::new();
+    if previous_routes.as_slice() != routes {
+        relinked.extend(client_files_for_routes(&all_calls, &previous_routes));
+        relinked.extend(client_files_for_routes(&all_calls, routes));
+    }
+    relinked.insert(file_path.clone());
+
+    for client_file in &relinked {
+        let file_calls = all_calls
+            .iter()
+            .filter(|call| &call.file_path == client_file)
+            .cloned()
+            .collect::<Vec<_>>();
+        let edges = endpoint_edges_for_calls(&file_calls, &all_routes);
+        store
+            .replace_endpoint_edges_for_file(client_file, &edges)
+            .with_context(|| format!("failed to store endpoint edges for {client_file}"))?;
+    }
+
+    relinked.remove(&file_path);
+    Ok(relinked)
+}
+
+/// Rebuilds the endpoint edges of every client file from the recorded sites.
+/// Returns the client files, which need their neighbors and graph edges
+/// refreshed.
+pub(crate) fn relink_all_endpoint_clients(store: &SqliteStore) -> Result<BTreeSet<String>> {
+    let routes = store
+        .list_endpoint_routes()
+        .context("failed to list endpoint routes")?;
+    let calls = store
+        .list_endpoint_calls()
+        .context("failed to list endpoint calls")?;
+
+    let mut clients = BTreeSet::new();
+    for file_calls in calls.chunk_by(|left, right| left.file_path == right.file_path) {
+        let client_file = &file_calls[0].file_path;
+        let edges = endpoint_edges_for_calls(file_calls, &routes);
+        store
+            .replace_endpoint_edges_for_file(client_file, &edges)
+            .with_context(|| format!("failed to store endpoint edges for {client_file}"))?;
+        clients.insert(client_file.clone());
+    }
+    Ok(clients)
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_core::{Language, normalize_endpoint_path};
+    use aether_store::{SymbolCatalogStore, SymbolRecord};
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn route(file_path: &str, method: &str, path: &str, handler: &str) -> EndpointRoute {
+        EndpointRoute {
+            file_path: file_path.to_owned(),
+            method: method.to_owned(),
+            path: normalize_endpoint_path(path).expect("route path"),
+            handler: handler.to_owned(),
+        }
+    }
+
+    fn call(file_path: &str, source_id: &str, method: &str, path: &str) -> EndpointCall {
+        EndpointCall {
+            file_path: file_path.to_owned(),
+            source_id: source_id.to_owned(),
+            method: method.to_owned(),
+            path: normalize_endpoint_path(path).expect("call path"),
+        }
+    }
+
+    fn symbol(id: &str, file_path: &str, language: Language, qualified_name: &str) -> SymbolRecord {
+        SymbolRecord {
+            id: id.to_owned(),
+            file_path: file_path.to_owned(),
+            language: language.as_str().to_owned(),
+            kind: "function".to_owned(),
+            qualified_name: qualified_name.to_owned(),
+            signature_fingerprint: format!("sig-{id}"),
+            last_seen_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn calls_link_to_matching_routes_only() {
+        let routes = vec![
+            route("src/routes.rs", "GET", "/users/:id", "get_user"),
+            route("src/routes.rs", "POST", "/users", "create_user"),
+        ];
+        let calls = vec![
+            call("web/api.ts", "sym-load", "GET", "/api/users/42"),
+            call("web/api.ts", "sym-save", "PUT", "/users/42"),
+            call("scripts/sync.py", "sym-sync", "POST", "https://api/users"),
+        ];
+
+        let edges = endpoint_edges_for_calls(&calls, &routes);
+        let linked = edges
+            .iter()
+            .map(|edge| {
+                (
+                    edge.file_path.as_str(),
+                    edge.source_id.as_str(),
+                    edge.target_qualified_name.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            linked,
+            vec![
+                ("scripts/sync.py", "sym-sync", "create_user"),
+                ("web/api.ts", "sym-load", "get_user"),
+            ]
+        );
+        assert!(
+            edges
+                .iter()
+                .all(|edge| edge.edge_kind == EdgeKind::Endpoint)
+        );
+        assert_eq!(
+            client_files_for_routes(&calls, &routes[..1]),
+            BTreeSet::from(["web/api.ts".to_owned()])
+        );
+    }
+
+    #[test]
+    fn route_changes_relink_previously_indexed_clients() {
+        let temp = tempdir().expect("tempdir");
+        let store = SqliteStore::open(temp.path()).expect("open store");
+        store
+            .upsert_symbol(symbol(
+                "sym-handler",
+                "src/routes.rs",
+                Language::Rust,
+                "get_user",
+            ))
+            .expect("upsert handler");
+        store
+            .upsert_symbol(symbol(
+                "sym-client",
+                "web/api.ts",
+                Language::TypeScript,
+                "loadUser",
+            ))
+            .expect("upsert client");
+
+        let client_calls = [call("web/api.ts", "sym-client", "GET", "/users/7")];
+        let relinked = update_endpoint_links(&store, "web/api.ts", &[], &client_calls)
+            .expect("index client before routes");
+        assert!(relinked.is_empty());
+        assert!(
+            store
+                .list_endpoint_linked_files("web/api.ts")
+                .expect("linked files before routes")
+                .is_empty()
+        );
+
+        let routes = [route("src/routes.rs", "GET", "/users/{id}", "get_user")];
+        let relinked =
+            update_endpoint_links(&store, "src/routes.rs", &routes, &[]).expect("index routes");
+        assert_eq!(relinked, BTreeSet::from(["web/api.ts".to_owned()]));
+        assert_eq!(
+            store
+                .list_endpoint_linked_files("src/routes.rs")
+                .expect("linked files"),
+            vec!["web/api.ts".to_owned()]
+        );
+
+        let relinked =
+            update_endpoint_links(&store, "src/routes.rs", &[], &[]).expect("remove routes");
+        assert_eq!(relinked, BTreeSet::from(["web/api.ts".to_owned()]));
+        assert!(
+            store
+                .list_endpoint_linked_files("src/routes.rs")
+                .expect("linked files after removal")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn deferred_sites_are_linked_in_one_pass() {
+        let temp = tempdir().expect("tempdir");
+        let store = SqliteStore::open(temp.path()).expect("open store");
+        store
+            .upsert_symbol(symbol(
+                "sym-handler",
+                "src/routes.rs",
+                Language::Rust,
+                "get_user",
+            ))
+            .expect("upsert handler");
+
+        store
+            .replace_endpoints_for_file(
+                "web/api.ts",
+                &[],
+                &[call("web/api.ts", "sym-client", "GET", "/users/7")],
+            )
+            .expect("record client sites");
+        store
+            .replace_endpoints_for_file(
+                "web/admin.ts",
+                &[],
+                &[call("web/admin.ts", "sym-admin", "DELETE", "/users/7")],
+            )
+            .expect("record admin sites");
+        store
+            .replace_endpoints_for_file(
+                "src/routes.rs",
+                &[route("src/routes.rs", "GET", "/users/{id}", "get_user")],
+                &[],
+            )
+            .expect("record route sites");
+        assert!(
+            store
+                .list_endpoint_linked_files("src/routes.rs")
+                .expect("linked files before relink")
+                .is_empty()
+        );
+
+        let clients = relink_all_endpoint_clients(&store).expect("relink");
+        assert_eq!(
+            clients,
+            BTreeSet::from(["web/admin.ts".to_owned(), "web/api.ts".to_owned()])
+        );
+        assert_eq!(
+            store
+                .list_endpoint_linked_files("src/routes.rs")
+                .expect("linked files"),
+            vec!["web/api.ts".to_owned()]
+        );
+    }
+}
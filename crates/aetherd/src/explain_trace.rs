@@ -0,0 +1,256 @@
+//! `aetherd explain-trace`: maps a panic backtrace, Python traceback or
+//! JavaScript stack onto indexed symbols as an incident starting point.
+
+use std::fmt::Write as _;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use aether_analysis::{ExplainTraceReport, ExplainTraceRequest, StackTraceAnalyzer};
+use aether_store::SqliteStore;
+use anyhow::{Context, Result, bail};
+
+use crate::cli::ExplainTraceArgs;
+
+pub fn run_explain_trace_command(workspace: &Path, args: ExplainTraceArgs) -> Result<()> {
+    let trace = match args.trace.as_deref() {
+        Some(path) if path.as_os_str() != "-" => {
+            let path = if path.is_absolute() {
+                path.to_path_buf()
+            } else {
+                workspace.join(path)
+            };
+            std::fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?
+        }
+        _ => {
+            let mut trace = String::new();
+            std::io::stdin()
+                .read_to_string(&mut trace)
+                .context("failed to read trace from stdin")?;
+            trace
+        }
+    };
+    if trace.trim().is_empty() {
+        bail!("no trace given; pass a file or pipe the trace on stdin");
+    }
+
+    let store = SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+    let analyzer = StackTraceAnalyzer::new(workspace).context("failed to initialize analyzer")?;
+    let report = analyzer
+        .explain(
+            &store,
+            &ExplainTraceRequest {
+                trace,
+                max_frames: Some(args.max_frames),
+                commit_limit: Some(args.commit_limit),
+            },
+        )
+        .context("trace explanation failed")?;
+
+    let rendered = if args.json {
+        serde_json::to_string_pretty(&report).context("failed to serialize trace explanation")?
+    } else {
+        render_report(&report)
+    };
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    writeln!(&mut stdout, "{}", rendered.trim_end())
+        .context("failed to write explain-trace output")?;
+    Ok(())
+}
+
+fn render_report(report: &ExplainTraceReport) -> String {
+    let language = report
+        .language
+        .map(|language| language.as_str())
+        .unwrap_or("unrecognized");
+    let mut out = match report.message.as_deref() {
+        Some(message) => format!("{language} trace: {message}\n"),
+        None => format!("{language} trace\n"),
+    };
+    let _ = writeln!(
+        out,
+        "{}/{} frame(s) resolved to indexed symbols",
+        report.frames_resolved, report.frames_total
+    );
+
+    for frame in &report.chain {
+        let span = match (frame.start_line, frame.end_line) {
+            (Some(start), Some(end)) => format!(":{start}-{end}"),
+            _ => String::new(),
+        };
+        let location = frame
+            .location
+            .as_deref()
+            .map(|location| format!(" at {location}"))
+            .unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "\n  #{} {} ({}{span}){location}",
+            frame.position, frame.symbol_name, frame.file
+        );
+        if let Some(intent) = frame.intent.as_deref() {
+            let _ = writeln!(out, "     intent: {intent}");
+        }
+        if !frame.error_modes.is_empty() {
+            let _ = writeln!(out, "     error modes: {}", frame.error_modes.join("; "));
+        }
+        for change in &frame.sir_changes {
+            let mut parts = Vec::new();
+            if change.intent_changed {
+                parts.push("intent changed".to_owned());
+            }
+            parts.extend(
+                change
+                    .error_modes_added
+                    .iter()
+                    .map(|mode| format!("+error mode \"{mode}\"")),
+            );
+            parts.extend(
+                change
+                    .error_modes_removed
+                    .iter()
+                    .map(|mode| format!("-error mode \"{mode}\"")),
+            );
+            let commit = change
+                .commit
+                .as_deref()
+                .map(|commit| format!(" @ {}", short_id(commit)))
+                .unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "     SIR v{}{commit}: {}",
+                change.version,
+                parts.join(", ")
+            );
+        }
+        for violation in &frame.violations {
+            let reason = violation
+                .reason
+                .as_deref()
+                .map(|reason| format!(" — {reason}"))
+                .unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "     violates {} \"{}\"{reason}",
+                violation.clause_type, violation.clause_text
+            );
+        }
+        for commit in &frame.commits {
+            let _ = writeln!(
+                out,
+                "     commit {} {} {} — {} ({} line(s))",
+                short_id(&commit.commit),
+                commit.date,
+                commit.author,
+                commit.summary,
+                commit.lines
+            );
+        }
+    }
+
+    if !report.suspect_commits.is_empty() {
+        out.push_str("\nLikely responsible commits:\n");
+        for suspect in &report.suspect_commits {
+            let _ = writeln!(
+                out,
+                "  {}. {} {} {} — {} [score {:.2}]; touches {}",
+                suspect.rank,
+                short_id(&suspect.commit),
+                suspect.date,
+                suspect.author,
+                suspect.summary,
+                suspect.score,
+                suspect.symbols.join(", ")
+            );
+        }
+    }
+    for note in &report.notes {
+        let _ = writeln!(out, "note: {note}");
+    }
+    out
+}
+
+fn short_id(id: &str) -> &str {
+    id.get(..12).unwrap_or(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_analysis::{
+        ExplainedFrame, SuspectCommit, TraceCommit, TraceLanguage, TraceSirChange, TraceViolation,
+    };
+
+    use super::*;
+
+    #[test]
+    fn render_report_walks_the_chain_and_suspects() {
+        let report = ExplainTraceReport {
+            schema_version: "1.0".to_owned(),
+            language: Some(TraceLanguage::Rust),
+            message: Some("called `Option::unwrap()` on a `None` value".to_owned()),
+            frames_total: 5,
+            frames_resolved: 1,
+            chain: vec![ExplainedFrame {
+                position: 0,
+                function: String::new(),
+                location: Some("src/cart.rs:42".to_owned()),
+                symbol_id: "sym-total".to_owned(),
+                symbol_name: "Cart::total".to_owned(),
+                file: "src/cart.rs".to_owned(),
+                start_line: Some(38),
+                end_line: Some(47),
+                intent: Some("Sums the price of every line item".to_owned()),
+                error_modes: vec!["panics on an item without a price".to_owned()],
+                sir_changes: vec![TraceSirChange {
+                    version: 3,
+                    created_at: 0,
+                    commit: Some("fedcba9876543210".to_owned()),
+                    intent_changed: false,
+                    intent: "Sums the price of every line item".to_owned(),
+                    error_modes_added: vec!["panics on an item without a price".to_owned()],
+                    error_modes_removed: Vec::new(),
+                }],
+                violations: vec![TraceViolation {
+                    contract_id: 7,
+                    clause_type: "must".to_owned(),
+                    clause_text: "never panic".to_owned(),
+                    violation_type: "semantic".to_owned(),
+                    reason: Some("unwraps the price".to_owned()),
+                    confidence: Some(0.9),
+                    detected_at: 0,
+                }],
+                commits: vec![TraceCommit {
+                    commit: "fedcba9876543210".to_owned(),
+                    author: "dev".to_owned(),
+                    date: "2026-03-01".to_owned(),
+                    summary: "Allow free items".to_owned(),
+                    lines: 4,
+                }],
+            }],
+            suspect_commits: vec![SuspectCommit {
+                rank: 1,
+                score: 0.4,
+                commit: "fedcba9876543210".to_owned(),
+                author: "dev".to_owned(),
+                date: "2026-03-01".to_owned(),
+                summary: "Allow free items".to_owned(),
+                symbols: vec!["Cart::total".to_owned()],
+            }],
+            notes: Vec::new(),
+        };
+
+        assert_eq!(
+            render_report(&report),
+            "rust trace: called `Option::unwrap()` on a `None` value\n\
+             1/5 frame(s) resolved to indexed symbols\n\n\
+             \x20 #0 Cart::total (src/cart.rs:38-47) at src/cart.rs:42\n\
+             \x20    intent: Sums the price of every line item\n\
+             \x20    error modes: panics on an item without a price\n\
+             \x20    SIR v3 @ fedcba987654: +error mode \"panics on an item without a price\"\n\
+             \x20    violates must \"never panic\" — unwraps the price\n\
+             \x20    commit fedcba987654 2026-03-01 dev — Allow free items (4 line(s))\n\n\
+             Likely responsible commits:\n\
+             \x20 1. fedcba987654 2026-03-01 dev — Allow free items [score 0.40]; touches Cart::total\n"
+        );
+    }
+}
@@ -433,15 +433,7 @@ fn synthesize_symbol(record: &aether_store::SymbolRecord) -> Symbol {
 }
 
 fn parse_language(raw: &str) -> Language {
-    match raw.trim().to_ascii_lowercase().as_str() {
-        "rust" => Language::Rust,
-        "typescript" => Language::TypeScript,
-        "tsx" => Language::Tsx,
-        "javascript" => Language::JavaScript,
-        "jsx" => Language::Jsx,
-        "python" => Language::Python,
-        _ => Language::Rust,
-    }
+    Language::from_name(&raw.trim().to_ascii_lowercase()).unwrap_or(Language::Rust)
 }
 
 fn parse_kind(raw: &str) -> SymbolKind {
@@ -455,6 +447,12 @@ fn parse_kind(raw: &str) -> SymbolKind {
         "trait" => SymbolKind::Trait,
         "interface" => SymbolKind::Interface,
         "type_alias" => SymbolKind::TypeAlias,
+        "constant" => SymbolKind::Constant,
+        "static" => SymbolKind::Static,
+        "macro" => SymbolKind::Macro,
+        "module" => SymbolKind::Module,
+        "field" => SymbolKind::Field,
+        "enum_variant" => SymbolKind::EnumVariant,
         _ => SymbolKind::Function,
     }
 }
@@ -0,0 +1,202 @@
+//! `aetherd graph`: dependency graph export for external tools and path
+//! queries.
+
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::Path;
+
+use aether_analysis::{
+    GraphExport, GraphExportRequest, GraphExporter, GraphQueryResult, MAX_GRAPH_QUERY_LIMIT,
+    evaluate_graph_query, parse_graph_query,
+};
+use aether_store::{SqliteStore, open_graph_store_readonly, open_surreal_graph_store_readonly};
+use anyhow::{Context, Result};
+
+use crate::cli::{GraphArgs, GraphCommand, GraphExportArgs, GraphQueryArgs};
+
+pub fn run_graph_command(workspace: &Path, args: GraphArgs) -> Result<()> {
+    match args.command {
+        GraphCommand::Export(args) => run_graph_export(workspace, args),
+        GraphCommand::Query(args) => run_graph_query(workspace, args),
+    }
+}
+
+fn run_graph_export(workspace: &Path, args: GraphExportArgs) -> Result<()> {
+    let store = SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+    let graph = open_surreal_graph_store_readonly(workspace).ok();
+    let export = GraphExporter::new(workspace)
+        .export(
+            &store,
+            graph.as_ref(),
+            &GraphExportRequest {
+                community: args.community,
+                path_glob: args.path,
+                edge_kinds: args.edge_kinds,
+                root: args.root,
+                depth: args.depth,
+            },
+        )
+        .context("graph export failed")?;
+    let rendered = export.render(args.format);
+
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    match args.out {
+        Some(out) => {
+            let path = if out.is_absolute() {
+                out.clone()
+            } else {
+                workspace.join(&out)
+            };
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("failed to create {}", parent.display()))?;
+            }
+            std::fs::write(&path, &rendered)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            let summary = render_written(&export, &out.display().to_string());
+            writeln!(&mut stdout, "{}", summary.trim_end())
+                .context("failed to write graph output")?;
+        }
+        None => {
+            // Notes would corrupt the exported document, so they go to stderr.
+            for note in &export.notes {
+                crate::daemon_rpc::command_eprintln!("note: {note}");
+            }
+            writeln!(&mut stdout, "{}", rendered.trim_end())
+                .context("failed to write graph output")?;
+        }
+    }
+    Ok(())
+}
+
+fn run_graph_query(workspace: &Path, args: GraphQueryArgs) -> Result<()> {
+    let mut query = parse_graph_query(&args.query)?;
+    if let Some(limit) = args.limit {
+        query.limit = limit.clamp(1, MAX_GRAPH_QUERY_LIMIT);
+    }
+    let store = SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+    let graph = open_graph_store_readonly(workspace).context("failed to open graph store")?;
+    let result =
+        evaluate_graph_query(&store, graph.as_ref(), &query).context("graph query failed")?;
+
+    let rendered = if args.json {
+        serde_json::to_string_pretty(&result).context("failed to serialize graph query result")?
+    } else {
+        render_query_result(&result)
+    };
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    writeln!(&mut stdout, "{}", rendered.trim_end()).context("failed to write graph output")?;
+    Ok(())
+}
+
+fn render_written(export: &GraphExport, out: &str) -> String {
+    let mut summary = format!(
+        "Wrote {} node(s) and {} edge(s) to {out}\n",
+        export.nodes.len(),
+        export.edges.len()
+    );
+    for note in &export.notes {
+        let _ = writeln!(summary, "note: {note}");
+    }
+    summary
+}
+
+fn render_query_result(result: &GraphQueryResult) -> String {
+    let mut out = format!(
+        "{} path(s), showing {}\n",
+        result.total_paths,
+        result.paths.len()
+    );
+    for (position, path) in result.paths.iter().enumerate() {
+        let mut chain = path
+            .nodes
+            .first()
+            .map(|node| node.qualified_name.clone())
+            .unwrap_or_default();
+        for (kind, node) in path.edge_kinds.iter().zip(path.nodes.iter().skip(1)) {
+            let _ = write!(chain, " -[{kind}]-> {}", node.qualified_name);
+        }
+        let _ = writeln!(out, "\n{}. {chain}", position + 1);
+        for node in &path.nodes {
+            let _ = write!(out, "   {} ({})", node.qualified_name, node.file_path);
+            match node.intent.as_deref() {
+                Some(intent) => {
+                    let _ = writeln!(out, ": {intent}");
+                }
+                None => out.push('\n'),
+            }
+        }
+    }
+    for note in &result.notes {
+        let _ = writeln!(out, "note: {note}");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_analysis::{ExportedEdge, ExportedNode, GraphQueryPath, GraphQueryPathNode};
+
+    use super::*;
+
+    #[test]
+    fn render_written_counts_nodes_edges_and_notes() {
+        let node = |id: &str| ExportedNode {
+            id: id.to_owned(),
+            name: id.to_owned(),
+            kind: "function".to_owned(),
+            file: "src/lib.rs".to_owned(),
+            pagerank: 0.0,
+            community: None,
+            health_risk: None,
+            intent: None,
+        };
+        let export = GraphExport {
+            schema_version: "1.0".to_owned(),
+            nodes: vec![node("a"), node("b")],
+            edges: vec![ExportedEdge {
+                source: "a".to_owned(),
+                target: "b".to_owned(),
+                kind: "calls".to_owned(),
+            }],
+            notes: vec!["graph store unavailable; health risk left out".to_owned()],
+        };
+
+        assert_eq!(
+            render_written(&export, "docs/graph.dot"),
+            "Wrote 2 node(s) and 1 edge(s) to docs/graph.dot\n\
+             note: graph store unavailable; health risk left out\n"
+        );
+    }
+
+    #[test]
+    fn render_query_result_chains_edge_kinds_and_intents() {
+        let node = |name: &str, intent: Option<&str>| GraphQueryPathNode {
+            symbol_id: format!("sym-{name}"),
+            qualified_name: name.to_owned(),
+            kind: "function".to_owned(),
+            file_path: "src/lib.rs".to_owned(),
+            intent: intent.map(str::to_owned),
+        };
+        let result = GraphQueryResult {
+            schema_version: "1.0".to_owned(),
+            paths: vec![GraphQueryPath {
+                nodes: vec![
+                    node("http::get", None),
+                    node("db::execute", Some("Runs SQL")),
+                ],
+                edge_kinds: vec!["calls|type_ref".to_owned()],
+            }],
+            total_paths: 3,
+            notes: Vec::new(),
+        };
+
+        assert_eq!(
+            render_query_result(&result),
+            "3 path(s), showing 1\n\n\
+             1. http::get -[calls|type_ref]-> db::execute\n   \
+             http::get (src/lib.rs)\n   \
+             db::execute (src/lib.rs): Runs SQL\n"
+        );
+    }
+}
@@ -38,14 +38,15 @@ fn parse_health_filter(raw: Option<&str>) -> Result<Vec<HealthInclude>> {
         "orphans" => Ok(vec![HealthInclude::Orphans]),
         "bottlenecks" => Ok(vec![HealthInclude::Bottlenecks]),
         "risk-hotspots" | "risk_hotspots" => Ok(vec![HealthInclude::RiskHotspots]),
+        "choke-points" | "choke_points" => Ok(vec![HealthInclude::ChokePoints]),
         other => Err(anyhow!(
-            "invalid health filter '{other}', expected one of: critical, cycles, orphans, bottlenecks, risk-hotspots"
+            "invalid health filter '{other}', expected one of: critical, cycles, orphans, bottlenecks, risk-hotspots, choke-points"
         )),
     }
 }
 
 fn write_json_to_stdout(value: &serde_json::Value) -> Result<()> {
-    let mut out = std::io::stdout();
+    let mut out = crate::daemon_rpc::command_stdout();
     serde_json::to_writer_pretty(&mut out, value).context("failed to serialize JSON output")?;
     writeln!(&mut out).context("failed to write trailing newline")?;
     Ok(())
@@ -81,7 +81,9 @@ impl HealthCommandStores {
                 match runtime.block_on(SurrealGraphStore::open(workspace)) {
                     Ok(graph) => Some(graph),
                     Err(err) => {
-                        eprintln!("Warning: could not open graph store: {err}");
+                        crate::daemon_rpc::command_eprintln!(
+                            "Warning: could not open graph store: {err}"
+                        );
                         None
                     }
                 }
@@ -114,7 +116,7 @@ pub fn run_health_score_command(
     args: HealthScoreArgs,
 ) -> Result<()> {
     let execution = execute_health_score_command(workspace, config, args)?;
-    let mut stdout = std::io::stdout();
+    let mut stdout = crate::daemon_rpc::command_stdout();
     stdout
         .write_all(execution.rendered.as_bytes())
         .context("failed to write health-score output")?;
@@ -123,7 +125,7 @@ pub fn run_health_score_command(
     }
 
     if execution.exit_code != 0 {
-        std::process::exit(execution.exit_code);
+        crate::daemon_rpc::exit_command(execution.exit_code);
     }
 
     Ok(())
@@ -1359,6 +1361,12 @@ fn parse_symbol_kind(raw: &str) -> SymbolKind {
         "trait" => SymbolKind::Trait,
         "interface" => SymbolKind::Interface,
         "type_alias" => SymbolKind::TypeAlias,
+        "constant" => SymbolKind::Constant,
+        "static" => SymbolKind::Static,
+        "macro" => SymbolKind::Macro,
+        "module" => SymbolKind::Module,
+        "field" => SymbolKind::Field,
+        "enum_variant" => SymbolKind::EnumVariant,
         _ => SymbolKind::Function,
     }
 }
@@ -1776,6 +1784,7 @@ model = "qwen3-embeddings-4B"
                 ("gamma".to_owned(), vec!["SymbolRecord".to_owned()]),
                 ("delta".to_owned(), vec!["SymbolRecord".to_owned()]),
             ])),
+            extensions: Default::default(),
         };
         store
             .write_sir_blob(
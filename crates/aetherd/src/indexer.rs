@@ -7,13 +7,14 @@ use std::time::{Duration, Instant};
 
 use aether_analysis::TestIntentAnalyzer;
 use aether_config::{
-    InferenceProviderKind, WatcherConfig, ensure_workspace_config, gemini_thinking_fingerprint,
+    InferenceProviderKind, WatcherConfig, WorkspaceRootConfig, declared_workspace_roots,
+    ensure_workspace_config, gemini_thinking_fingerprint, load_workspace_config,
 };
 use aether_core::{GitContext, Symbol, SymbolChangeEvent, content_hash, normalize_path};
 use aether_graph_algo::{GraphAlgorithmEdge, page_rank_sync};
 use aether_infer::ProviderOverrides;
 use aether_infer::sir_prompt::SirEnrichmentContext;
-use aether_parse::{SymbolExtractor, TestIntent, language_for_path};
+use aether_parse::{ExtractedFile, SymbolExtractor, TestIntent, language_for_path};
 use aether_sir::{FileSir, SirAnnotation, synthetic_file_sir_id};
 #[cfg(test)]
 use aether_store::SirHistoryStore;
@@ -25,13 +26,14 @@ use aether_store::{
 use anyhow::{Context, Result};
 use gix::bstr::ByteSlice;
 use gix::traverse::commit::simple::CommitTimeOrder;
-use ignore::WalkBuilder;
 use notify::{Config, Event, RecommendedWatcher, RecursiveMode, Watcher};
 
 use crate::batch::hash::compute_prompt_hash;
 use crate::batch::write_fingerprint_row;
 use crate::continuous::cosine_distance_from_embeddings;
+use crate::endpoints::{relink_all_endpoint_clients, update_endpoint_links};
 use crate::observer::{DebounceQueue, ObserverState, is_ignored_path};
+use crate::parse_pool::{parse_in_parallel, parse_worker_count};
 use crate::priority_queue::{
     SirPriorityQueue, compute_priority_score, kind_priority_score, size_inverse_score,
 };
@@ -40,6 +42,7 @@ use crate::sir_pipeline::{
     SIR_GENERATION_PASS_REGENERATED, SIR_GENERATION_PASS_SCAN, SIR_GENERATION_PASS_TRIAGE,
     SirPipeline, build_job,
 };
+use crate::workspace_roots::{ResolvedRootLink, resolve_root_links, root_link_edges};
 
 const REQUEST_POLL_BATCH: usize = 128;
 const WORKER_IDLE_SLEEP_MS: u64 = 200;
@@ -634,9 +637,7 @@ fn run_full_index_once_inner(config: &IndexerConfig, skip_teardown: bool) -> Res
     let mut stdout = std::io::stdout();
     let (initial_events, symbols_by_id, symbol_count) = collect_initial_snapshot(&observer);
 
-    for event in &initial_events {
-        structural.process_event(&store, event)?;
-    }
+    structural.process_initial_events(&store, &initial_events)?;
 
     let reconciliation_plan = plan_symbol_reconciliation(&store, &symbols_by_id)?;
     let _ = execute_symbol_reconciliation(
@@ -1217,6 +1218,10 @@ fn lookup_caller_contracts(
             .map(|s| s.qualified_name)
             .unwrap_or_else(|| edge.source_id.clone());
         for contract in contracts {
+            // Structural clauses describe the caller's own call graph.
+            if crate::contracts::is_structural_clause_type(&contract.clause_type) {
+                continue;
+            }
             result.push((
                 caller_name.clone(),
                 contract.clause_type,
@@ -1293,12 +1298,12 @@ fn run_initial_index_once_inner(config: &IndexerConfig, skip_teardown: bool) ->
 
     let (observer, store) = initialize_observer_and_store(config)?;
     let mut structural = StructuralIndexer::new(config.workspace.clone())?;
-    let mut symbol_count = 0usize;
-
-    for event in observer.initial_symbol_events() {
-        symbol_count += event.added.len() + event.updated.len();
-        structural.process_event(store.as_ref(), &event)?;
-    }
+    let initial_events = observer.initial_symbol_events();
+    let symbol_count = initial_events
+        .iter()
+        .map(|event| event.added.len() + event.updated.len())
+        .sum::<usize>();
+    structural.process_initial_events(store.as_ref(), &initial_events)?;
 
     tracing::info!(
         symbol_count,
@@ -1326,9 +1331,7 @@ pub(crate) fn run_structural_index_once(
     let store = SqliteStore::open(workspace).context("failed to initialize local store")?;
     let mut structural = StructuralIndexer::new(workspace.to_path_buf())?;
     let (initial_events, symbols_by_id, symbol_count) = collect_initial_snapshot(&observer);
-    for event in &initial_events {
-        structural.process_event(&store, event)?;
-    }
+    structural.process_initial_events(&store, &initial_events)?;
     Ok((store, symbols_by_id, symbol_count))
 }
 
@@ -1349,14 +1352,15 @@ pub fn run_indexing_loop(config: IndexerConfig) -> Result<()> {
     let mut initial_symbols = Vec::<Symbol>::new();
     let mut symbol_count = 0usize;
 
-    for event in observer.initial_symbol_events() {
+    let initial_events = observer.initial_symbol_events();
+    for event in &initial_events {
         symbol_count += event.added.len() + event.updated.len();
-        collect_changed_symbols(&event, &mut initial_symbols);
+        collect_changed_symbols(event, &mut initial_symbols);
         for symbol in event.added.iter().chain(event.updated.iter()) {
             initial_symbol_index.insert(symbol.id.clone(), symbol.clone());
         }
-        structural.process_event(store.as_ref(), &event)?;
     }
+    structural.process_initial_events(store.as_ref(), &initial_events)?;
     tracing::info!(
         symbol_count,
         "Structural index complete: {} symbols indexed, lexical search + graph queries available",
@@ -1414,10 +1418,10 @@ pub fn run_indexing_loop(config: IndexerConfig) -> Result<()> {
     .context("failed to initialize file watcher")?;
     let git_watch_dir = resolve_git_watch_dir(&config.workspace);
 
-    for entry in WalkBuilder::new(&config.workspace)
-        .hidden(true)
-        .git_ignore(true)
-        .build()
+    for entry in observer
+        .root_walkers()
+        .iter_mut()
+        .flat_map(|walk| walk.hidden(true).git_ignore(true).build())
         .filter_map(|entry| entry.ok())
         .filter(|entry| entry.file_type().map(|kind| kind.is_dir()).unwrap_or(false))
         .filter(|entry| !is_ignored_path(entry.path()))
@@ -1617,7 +1621,7 @@ fn process_git_trigger(
             let git_paths = if !watcher_runtime.watcher.git_trigger_changed_files_only
                 || previous_head.sha.is_none()
             {
-                collect_full_reindex_paths(config.workspace.as_path(), observer)
+                collect_full_reindex_paths(observer)
             } else if let (Some(old_sha), Some(new_sha)) =
                 (previous_head.sha.as_deref(), current_head.sha.as_deref())
             {
@@ -1630,11 +1634,11 @@ fn process_git_trigger(
                             error = %err,
                             "failed to diff git heads; falling back to full reindex"
                         );
-                        collect_full_reindex_paths(config.workspace.as_path(), observer)
+                        collect_full_reindex_paths(observer)
                     }
                 }
             } else {
-                collect_full_reindex_paths(config.workspace.as_path(), observer)
+                collect_full_reindex_paths(observer)
             };
             paths.extend(git_paths);
         }
@@ -1907,12 +1911,16 @@ fn normalize_git_rename_path(path: &str) -> String {
     value.to_owned()
 }
 
-fn collect_full_reindex_paths(workspace: &Path, observer: &ObserverState) -> Vec<PathBuf> {
+fn collect_full_reindex_paths(observer: &ObserverState) -> Vec<PathBuf> {
     let mut paths = observer
         .tracked_paths()
         .into_iter()
         .collect::<BTreeSet<_>>();
-    for entry in WalkBuilder::new(workspace).standard_filters(true).build() {
+    for entry in observer
+        .root_walkers()
+        .iter_mut()
+        .flat_map(|walk| walk.standard_filters(true).build())
+    {
         let Ok(entry) = entry else {
             continue;
         };
@@ -2402,8 +2410,39 @@ fn infer_symbol_is_public(source: &str, symbol: &Symbol) -> bool {
     })
 }
 
+/// Files extracted per batch by [`StructuralIndexer::process_initial_events`],
+/// bounding how many extracted files are held in memory at once.
+const INITIAL_EXTRACT_BATCH_SIZE: usize = 256;
+
+/// Reads and extracts a file for edge indexing, or `None` when it was deleted.
+fn extract_event_file(
+    extractor: &mut SymbolExtractor,
+    workspace_root: &Path,
+    file_path: &str,
+) -> Result<Option<ExtractedFile>> {
+    let full_path = workspace_root.join(file_path);
+    let source = match fs::read_to_string(&full_path) {
+        Ok(source) => source,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => {
+            return Err(err).with_context(|| {
+                format!(
+                    "failed to read source for edge extraction {}",
+                    full_path.display()
+                )
+            });
+        }
+    };
+    extractor
+        .extract_with_edges_from_path(Path::new(file_path), &source)
+        .map(Some)
+        .with_context(|| format!("failed to extract edges from {file_path}"))
+}
+
 struct StructuralIndexer {
     workspace_root: PathBuf,
+    roots: Vec<WorkspaceRootConfig>,
+    root_links: Vec<ResolvedRootLink>,
     extractor: SymbolExtractor,
     test_intent_analyzer: TestIntentAnalyzer,
     graph_runtime: tokio::runtime::Runtime,
@@ -2425,9 +2464,12 @@ impl StructuralIndexer {
             .block_on(open_graph_store(&workspace_root))
             .context("failed to open configured graph store")?;
         let surreal_graph_store = open_surreal_graph_store_sync(&workspace_root).ok();
+        let workspace_config = load_workspace_config(&workspace_root).unwrap_or_default();
 
         Ok(Self {
             workspace_root,
+            roots: declared_workspace_roots(&workspace_config),
+            root_links: resolve_root_links(&workspace_config),
             extractor,
             test_intent_analyzer,
             graph_runtime,
@@ -2437,6 +2479,43 @@ impl StructuralIndexer {
     }
 
     fn process_event(&mut self, store: &SqliteStore, event: &SymbolChangeEvent) -> Result<()> {
+        let extracted =
+            extract_event_file(&mut self.extractor, &self.workspace_root, &event.file_path);
+        self.apply_event(store, event, extracted, false)
+    }
+
+    /// Processes the initial snapshot, extracting each batch of files on the
+    /// parse worker pool and applying the results in event order. Endpoint
+    /// links are built once after every file's sites are recorded.
+    fn process_initial_events(
+        &mut self,
+        store: &SqliteStore,
+        events: &[SymbolChangeEvent],
+    ) -> Result<()> {
+        for batch in events.chunks(INITIAL_EXTRACT_BATCH_SIZE) {
+            let workspace_root = self.workspace_root.as_path();
+            let extracted = parse_in_parallel(
+                batch,
+                parse_worker_count(batch.len()),
+                |extractor, event| extract_event_file(extractor, workspace_root, &event.file_path),
+            )?;
+            for (event, extracted) in batch.iter().zip(extracted) {
+                self.apply_event(store, event, extracted, true)?;
+            }
+        }
+        let clients = relink_all_endpoint_clients(store)?;
+        self.refresh_relinked_clients(store, &clients)
+    }
+
+    /// With `defer_endpoint_links` the file's endpoint sites are recorded but
+    /// no endpoint edges are rebuilt; the caller relinks afterwards.
+    fn apply_event(
+        &mut self,
+        store: &SqliteStore,
+        event: &SymbolChangeEvent,
+        extracted: Result<Option<ExtractedFile>>,
+        defer_endpoint_links: bool,
+    ) -> Result<()> {
         for symbol in &event.removed {
             store
                 .mark_removed(&symbol.id)
@@ -2454,17 +2533,37 @@ impl StructuralIndexer {
             .delete_edges_for_file(&event.file_path)
             .with_context(|| format!("failed to delete edges for file {}", event.file_path))?;
 
-        let full_path = self.workspace_root.join(&event.file_path);
-        let source = fs::read_to_string(&full_path);
-        match source {
-            Ok(source) => {
-                let extracted = self
-                    .extractor
-                    .extract_with_edges_from_path(Path::new(&event.file_path), &source)
-                    .with_context(|| format!("failed to extract edges from {}", event.file_path))?;
-                store.upsert_edges(&extracted.edges).with_context(|| {
+        let relinked_files = match extracted? {
+            Some(extracted) => {
+                let mut edges = extracted.edges;
+                edges.extend(root_link_edges(
+                    &self.root_links,
+                    &self.roots,
+                    &event.file_path,
+                    &extracted.symbols,
+                ));
+                store.upsert_edges(&edges).with_context(|| {
                     format!("failed to upsert edges for file {}", event.file_path)
                 })?;
+                let relinked_files = if defer_endpoint_links {
+                    store
+                        .replace_endpoints_for_file(
+                            &event.file_path,
+                            &extracted.endpoint_routes,
+                            &extracted.endpoint_calls,
+                        )
+                        .with_context(|| {
+                            format!("failed to store endpoint sites for {}", event.file_path)
+                        })?;
+                    BTreeSet::new()
+                } else {
+                    update_endpoint_links(
+                        store,
+                        &event.file_path,
+                        &extracted.endpoint_routes,
+                        &extracted.endpoint_calls,
+                    )?
+                };
                 if let Err(err) = store.populate_symbol_neighbors(event.file_path.as_str()) {
                     tracing::warn!(
                         file_path = %event.file_path,
@@ -2483,23 +2582,59 @@ impl StructuralIndexer {
                     .with_context(|| {
                         format!("failed to upsert test intents for file {}", event.file_path)
                     })?;
+                let contract_scope = extracted
+                    .symbols
+                    .iter()
+                    .chain(&event.removed)
+                    .map(|symbol| symbol.id.clone())
+                    .collect::<Vec<_>>();
+                crate::contracts::sync_source_contracts(
+                    store,
+                    &event.file_path,
+                    &contract_scope,
+                    &extracted.source_contracts,
+                )?;
+                crate::contracts::refresh_policy_contracts(
+                    store,
+                    &self.workspace_root,
+                    &contract_scope,
+                );
+                relinked_files
             }
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            None => {
                 store
                     .replace_test_intents_for_file(event.file_path.as_str(), &[])
                     .with_context(|| {
                         format!("failed to clear test intents for file {}", event.file_path)
                     })?;
+                let contract_scope = event
+                    .removed
+                    .iter()
+                    .map(|symbol| symbol.id.clone())
+                    .collect::<Vec<_>>();
+                crate::contracts::sync_source_contracts(
+                    store,
+                    &event.file_path,
+                    &contract_scope,
+                    &[],
+                )?;
+                crate::contracts::refresh_policy_contracts(
+                    store,
+                    &self.workspace_root,
+                    &contract_scope,
+                );
+                if defer_endpoint_links {
+                    store
+                        .replace_endpoints_for_file(&event.file_path, &[], &[])
+                        .with_context(|| {
+                            format!("failed to clear endpoint sites for {}", event.file_path)
+                        })?;
+                    BTreeSet::new()
+                } else {
+                    update_endpoint_links(store, &event.file_path, &[], &[])?
+                }
             }
-            Err(err) => {
-                return Err(err).with_context(|| {
-                    format!(
-                        "failed to read source for edge extraction {}",
-                        full_path.display()
-                    )
-                });
-            }
-        }
+        };
 
         if let Some(graph) = self.surreal_graph_store.as_ref() {
             let _ = self
@@ -2526,6 +2661,27 @@ impl StructuralIndexer {
             );
         }
 
+        // Clients in other files whose endpoint edges moved with this file's routes.
+        self.refresh_relinked_clients(store, &relinked_files)
+    }
+
+    fn refresh_relinked_clients(
+        &self,
+        store: &SqliteStore,
+        client_files: &BTreeSet<String>,
+    ) -> Result<()> {
+        for client_file in client_files {
+            if let Err(err) = store.populate_symbol_neighbors(client_file) {
+                tracing::warn!(
+                    file_path = %client_file,
+                    error = %err,
+                    "failed to populate symbol_neighbors for relinked endpoint client"
+                );
+            }
+            self.graph_runtime
+                .block_on(store.sync_graph_for_file(self.graph_store.as_ref(), client_file))
+                .with_context(|| format!("failed to sync graph edges for {client_file}"))?;
+        }
         Ok(())
     }
 
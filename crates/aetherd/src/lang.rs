@@ -0,0 +1,218 @@
+//! Runtime-loaded tree-sitter grammars (`[[languages]]`) and `aetherd lang check`.
+
+use std::path::{Path, PathBuf};
+
+use aether_config::{AetherConfig, LanguagePluginConfig, declared_language_plugins};
+use aether_core::normalize_path;
+use aether_parse::{
+    LanguagePlugin, LanguagePluginSpec, LanguageRegistry, SymbolExtractor, install_language_plugins,
+};
+use anyhow::{Context, Result, anyhow};
+
+use crate::cli::{LangArgs, LangCheckArgs, LangCommand};
+
+/// Resolves a `[[languages]]` entry's paths against the workspace.
+pub fn language_plugin_spec(
+    workspace: &Path,
+    language: &LanguagePluginConfig,
+) -> LanguagePluginSpec {
+    LanguagePluginSpec {
+        name: language.name.clone(),
+        extensions: language.plugin_extensions(),
+        grammar_path: workspace.join(language.grammar.trim()),
+        language_symbol: language.language_symbol(),
+        queries_dir: workspace.join(language.queries.trim()),
+    }
+}
+
+/// Loads the configured grammars and makes them available to every parser
+/// built afterwards. Grammars that fail to load are skipped with a warning.
+pub fn install_configured_language_plugins(workspace: &Path, config: &AetherConfig) {
+    let mut plugins = Vec::new();
+    for language in declared_language_plugins(config) {
+        match LanguagePlugin::load(&language_plugin_spec(workspace, &language)) {
+            Ok(plugin) => {
+                tracing::info!(
+                    language = %language.name,
+                    extensions = ?plugin.extensions(),
+                    "loaded language plugin"
+                );
+                plugins.push(plugin);
+            }
+            Err(err) => {
+                tracing::warn!(language = %language.name, error = %format!("{err:#}"), "skipping language plugin that failed to load");
+            }
+        }
+    }
+    install_language_plugins(plugins);
+}
+
+pub fn run_lang_command(workspace: &Path, config: &AetherConfig, args: LangArgs) -> Result<()> {
+    match args.command {
+        LangCommand::Check(check_args) => run_check(workspace, config, &check_args),
+    }
+}
+
+fn run_check(workspace: &Path, config: &AetherConfig, args: &LangCheckArgs) -> Result<()> {
+    let languages = declared_language_plugins(config)
+        .into_iter()
+        .filter(|language| {
+            args.language
+                .as_deref()
+                .is_none_or(|name| language.name == name)
+        })
+        .collect::<Vec<_>>();
+    if languages.is_empty() {
+        return Err(match args.language.as_deref() {
+            Some(name) => anyhow!("no loadable [[languages]] entry named '{name}'"),
+            None => anyhow!("no loadable [[languages]] entries are configured"),
+        });
+    }
+
+    let mut failures = 0usize;
+    let mut registry = LanguageRegistry::new();
+    for language in &languages {
+        let spec = language_plugin_spec(workspace, language);
+        match LanguagePlugin::load(&spec) {
+            Ok(plugin) => {
+                println!(
+                    "{}: grammar ok (ABI {}), queries ok ({} extension(s): {}{})",
+                    language.name,
+                    plugin.abi_version(),
+                    plugin.extensions().len(),
+                    plugin.extensions().join(", "),
+                    if plugin.has_test_intent_query() {
+                        ", with test intents"
+                    } else {
+                        ""
+                    }
+                );
+                registry.register(plugin.config());
+            }
+            Err(err) => {
+                failures += 1;
+                println!("{}: FAILED: {err:#}", language.name);
+            }
+        }
+    }
+
+    if !args.samples.is_empty() {
+        let mut extractor =
+            SymbolExtractor::with_registry(registry).context("failed to initialize parser")?;
+        for sample in &args.samples {
+            if let Err(err) = check_sample(&mut extractor, workspace, sample) {
+                failures += 1;
+                println!("  {}: FAILED: {err:#}", sample.display());
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(anyhow!("{failures} language check(s) failed"));
+    }
+    Ok(())
+}
+
+/// Parses one sample with the loaded plugins. Samples must parse without
+/// syntax errors and yield at least one symbol.
+fn check_sample(extractor: &mut SymbolExtractor, workspace: &Path, sample: &Path) -> Result<()> {
+    let path = if sample.is_absolute() || sample.exists() {
+        sample.to_path_buf()
+    } else {
+        workspace.join(sample)
+    };
+    let source = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read sample {}", path.display()))?;
+    let file_path = PathBuf::from(normalize_path(&sample.to_string_lossy()));
+
+    let language = extractor
+        .path_language(&file_path)
+        .ok_or_else(|| anyhow!("no checked language handles this file extension"))?;
+    let extracted = extractor.extract_with_edges_from_path(&file_path, &source)?;
+    let snapshot = extractor.parse_snapshot(language, &file_path.to_string_lossy(), source)?;
+    let errors = snapshot.error_positions();
+
+    println!(
+        "  {}: {} symbol(s), {} edge(s), {} test intent(s), {} syntax error(s)",
+        sample.display(),
+        extracted.symbols.len(),
+        extracted.edges.len(),
+        extracted.test_intents.len(),
+        errors.len()
+    );
+    if let Some(first) = errors.first() {
+        return Err(anyhow!(
+            "syntax error at {}:{}; the grammar may not match this file",
+            first.line,
+            first.column
+        ));
+    }
+    if extracted.symbols.is_empty() {
+        return Err(anyhow!("symbols.scm matched nothing"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kotlin(grammar: &str, queries: &str) -> LanguagePluginConfig {
+        LanguagePluginConfig {
+            name: "kotlin".to_owned(),
+            extensions: vec![".kt".to_owned()],
+            grammar: grammar.to_owned(),
+            symbol: None,
+            queries: queries.to_owned(),
+        }
+    }
+
+    #[test]
+    fn plugin_paths_resolve_against_the_workspace() {
+        let spec = language_plugin_spec(
+            Path::new("/repo"),
+            &kotlin("grammars/libtree-sitter-kotlin.so", "/opt/queries/kotlin"),
+        );
+        assert_eq!(
+            spec.grammar_path,
+            Path::new("/repo/grammars/libtree-sitter-kotlin.so")
+        );
+        assert_eq!(spec.queries_dir, Path::new("/opt/queries/kotlin"));
+        assert_eq!(spec.extensions, vec!["kt".to_owned()]);
+        assert_eq!(spec.language_symbol, "tree_sitter_kotlin");
+    }
+
+    #[test]
+    fn check_fails_when_the_grammar_cannot_be_loaded() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let config = AetherConfig {
+            languages: vec![kotlin(
+                "missing/libtree-sitter-kotlin.so",
+                "missing/queries",
+            )],
+            ..AetherConfig::default()
+        };
+
+        let err = run_check(
+            temp.path(),
+            &config,
+            &LangCheckArgs {
+                language: None,
+                samples: Vec::new(),
+            },
+        )
+        .expect_err("missing grammar");
+        assert!(err.to_string().contains("1 language check(s) failed"));
+
+        let err = run_check(
+            temp.path(),
+            &config,
+            &LangCheckArgs {
+                language: Some("ruby".to_owned()),
+                samples: Vec::new(),
+            },
+        )
+        .expect_err("unknown language");
+        assert!(err.to_string().contains("'ruby'"));
+    }
+}
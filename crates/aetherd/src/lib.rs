@@ -1,3 +1,5 @@
+pub mod affected_tests;
+pub mod architecture;
 pub mod audit_report;
 pub mod batch;
 pub mod calibrate;
@@ -11,19 +13,30 @@ pub mod contracts;
 pub mod coupling;
 pub mod daemon_client;
 pub mod daemon_detect;
+pub mod daemon_rpc;
+pub mod dead_code;
+pub mod docs;
 pub mod drift;
+pub mod endpoints;
 pub mod enhance;
 pub mod enhance_templates;
+pub mod explain_trace;
 pub mod fsck;
+pub mod graph;
 pub mod health;
 pub mod health_score;
 pub mod indexer;
 pub mod init_agent;
+pub mod lang;
 pub mod memory;
 pub mod observer;
+pub mod ownership;
+pub mod parse_pool;
 pub mod priority_queue;
+pub mod profile;
 pub mod quality;
 pub mod refactor_prep;
+pub mod release_check;
 pub mod search;
 pub mod seismograph;
 pub mod setup_local;
@@ -36,6 +49,8 @@ pub mod sir_quality;
 pub mod task_context;
 pub mod templates;
 pub mod test_intents;
+pub mod test_results;
 pub(crate) mod time;
 pub mod verification;
 pub mod verify_intent;
+pub mod workspace_roots;
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::io::IsTerminal;
+use std::io::{IsTerminal, Write};
 use std::path::Path;
 
 use aether_config::{
@@ -12,18 +12,21 @@ use aether_infer::sir_prompt::SirEnrichmentContext;
 use aether_infer::{download_candle_embedding_model, download_candle_reranker_model};
 use aether_sir::SirAnnotation;
 use aether_store::{AuditFindingFilters, AuditStore, SirStateStore, SqliteStore};
+use aetherd::affected_tests::run_affected_tests_command;
+use aetherd::architecture::run_arch_check_command;
 use aetherd::audit_report::render_audit_report;
 use aetherd::batch::run_batch_command;
 use aetherd::calibrate::run_calibration_once;
 use aetherd::causal::run_trace_cause_command;
 use aetherd::cli::{
-    AskArgs, AuditReportArgs, BatchArgs, BlastRadiusArgs, Cli, Commands, CommunitiesArgs,
-    ComputeQualityArgs, ContextArgs, ContinuousArgs, ContractArgs, CouplingReportArgs,
-    DriftAckArgs, DriftReportArgs, EnhanceArgs, FsckArgs, HealthArgs, HealthScoreArgs,
-    InitAgentArgs, LogFormat, MineCouplingArgs, NotesArgs, PresetArgs, RecallArgs,
-    RefactorPrepArgs, RegenerateArgs, RememberArgs, SeismographArgs, SetupLocalArgs,
-    SirContextArgs, SirDiffArgs, SirInjectArgs, TaskHistoryArgs, TaskRelevanceArgs,
-    TestIntentsArgs, TraceCauseArgs, VerifyIntentArgs, parse_cli,
+    AffectedTestsArgs, ArchCheckArgs, AskArgs, AuditReportArgs, BatchArgs, BlastRadiusArgs, Cli,
+    Commands, CommunitiesArgs, ComputeQualityArgs, ContextArgs, ContinuousArgs, ContractArgs,
+    CouplingReportArgs, DeadCodeArgs, DocsArgs, DriftAckArgs, DriftReportArgs, EnhanceArgs,
+    ExplainTraceArgs, FsckArgs, GraphArgs, HealthArgs, HealthScoreArgs, InitAgentArgs, LangArgs,
+    LogFormat, MineCouplingArgs, NotesArgs, OwnershipArgs, PresetArgs, ProfileArgs, RecallArgs,
+    RefactorPrepArgs, RegenerateArgs, ReleaseCheckArgs, RememberArgs, SeismographArgs,
+    SetupLocalArgs, SirContextArgs, SirDiffArgs, SirInjectArgs, TaskHistoryArgs, TaskRelevanceArgs,
+    TestIntentsArgs, TestResultsArgs, TraceCauseArgs, TriageArgs, VerifyIntentArgs, parse_cli,
 };
 use aetherd::context_presets::run_preset_command;
 use aetherd::continuous::run_continuous_command;
@@ -31,9 +34,16 @@ use aetherd::contracts::run_contract_command;
 use aetherd::coupling::{
     run_blast_radius_command, run_coupling_report_command, run_mine_coupling_command,
 };
+use aetherd::daemon_rpc::{
+    DaemonRpcOutput, forwarded_args, is_daemon_routable, spawn_daemon_rpc_server, try_run_in_daemon,
+};
+use aetherd::dead_code::run_dead_code_command;
+use aetherd::docs::run_docs_command;
 use aetherd::drift::{run_communities_command, run_drift_ack_command, run_drift_report_command};
 use aetherd::enhance::run_enhance_command;
+use aetherd::explain_trace::run_explain_trace_command;
 use aetherd::fsck::run_fsck;
+use aetherd::graph::run_graph_command;
 use aetherd::health::run_health_command;
 use aetherd::health_score::run_health_score_command;
 use aetherd::indexer::{
@@ -41,12 +51,16 @@ use aetherd::indexer::{
     run_initial_index_once_for_cli,
 };
 use aetherd::init_agent::{InitAgentOptions, run_init_agent};
+use aetherd::lang::{install_configured_language_plugins, run_lang_command};
 use aetherd::memory::{
     run_ask_command, run_notes_command, run_recall_command, run_remember_command,
 };
 use aetherd::observer::ObserverState;
+use aetherd::ownership::run_ownership_command;
+use aetherd::profile::run_profile_command;
 use aetherd::refactor_prep::run_refactor_prep_command;
-use aetherd::search::run_search_once;
+use aetherd::release_check::run_release_check_command;
+use aetherd::search::{SearchFilters, run_search_once};
 use aetherd::seismograph::run_seismograph_command;
 use aetherd::setup_local::{SetupLocalOptions, run_setup_local};
 use aetherd::sir_context::{run_context_command, run_sir_context_command};
@@ -56,6 +70,7 @@ use aetherd::sir_pipeline::{SIR_GENERATION_PASS_REGENERATED, SirDeepPromptSpec,
 use aetherd::sir_quality::run_compute_quality_command;
 use aetherd::task_context::{run_task_history_command, run_task_relevance_command};
 use aetherd::test_intents::run_test_intents_command;
+use aetherd::test_results::{run_test_results_command, run_triage_command};
 use aetherd::verification::{VerificationRequest, run_verification};
 use aetherd::verify_intent::run_verify_intent_command;
 use anyhow::{Context, Result, anyhow};
@@ -81,6 +96,14 @@ fn run(cli: Cli) -> Result<()> {
     let config = load_config_for_command(&workspace, command.as_ref())?;
     init_tracing_subscriber(cli.log_format, &config.general.log_level)?;
 
+    if is_daemon_routable(&cli)
+        && let Some(output) = try_run_in_daemon(&workspace, forwarded_args(std::env::args_os()))
+            .context("failed to run command in the running daemon")?
+    {
+        return finish_daemon_rpc(output);
+    }
+    install_configured_language_plugins(&workspace, &config);
+
     if let Some(command) = command {
         return run_subcommand(&workspace, &config, command);
     }
@@ -146,6 +169,10 @@ fn run(cli: Cli) -> Result<()> {
             query,
             cli.search_limit.min(100),
             cli.search_mode,
+            &SearchFilters {
+                sir_fields: cli.sir_field.clone(),
+                roots: cli.search_root.clone(),
+            },
             cli.output,
             &mut out,
         );
@@ -271,6 +298,18 @@ fn run(cli: Cli) -> Result<()> {
         }
     }
 
+    let _rpc_server = if cli.index_once {
+        None
+    } else {
+        match spawn_daemon_rpc_server(&workspace) {
+            Ok(server) => Some(server),
+            Err(err) => {
+                tracing::warn!(error = %err, "daemon RPC socket unavailable; CLI commands will not be routed to this daemon");
+                None
+            }
+        }
+    };
+
     let indexer_config = IndexerConfig {
         workspace: workspace.clone(),
         debounce_ms: cli.debounce_ms,
@@ -324,6 +363,30 @@ fn run(cli: Cli) -> Result<()> {
     run_indexing_loop(indexer_config)
 }
 
+fn finish_daemon_rpc(output: DaemonRpcOutput) -> Result<()> {
+    tracing::debug!("command executed by the running daemon");
+    // Diagnostics come first: commands emit their warnings and notes before
+    // the output they qualify.
+    let mut stderr = std::io::stderr();
+    stderr
+        .write_all(output.stderr.as_bytes())
+        .and_then(|()| stderr.flush())
+        .context("failed to write daemon command diagnostics")?;
+    let mut stdout = std::io::stdout();
+    stdout
+        .write_all(output.stdout.as_bytes())
+        .and_then(|()| stdout.flush())
+        .context("failed to write daemon command output")?;
+
+    if let Some(error) = output.error {
+        return Err(anyhow!(error));
+    }
+    if output.exit_code != 0 {
+        std::process::exit(output.exit_code);
+    }
+    Ok(())
+}
+
 fn run_subcommand(workspace: &Path, config: &AetherConfig, command: Commands) -> Result<()> {
     match command {
         Commands::Batch(args) => run_batch_subcommand(workspace, config, args),
@@ -361,6 +424,18 @@ fn run_subcommand(workspace: &Path, config: &AetherConfig, command: Commands) ->
         Commands::Fsck(args) => run_fsck_subcommand(workspace, args),
         Commands::Seismograph(args) => run_seismograph_subcommand(workspace, config, args),
         Commands::Contract(args) => run_contract_subcommand(workspace, config, args),
+        Commands::Lang(args) => run_lang_subcommand(workspace, config, args),
+        Commands::ArchCheck(args) => run_arch_check_subcommand(workspace, args),
+        Commands::ReleaseCheck(args) => run_release_check_subcommand(workspace, args),
+        Commands::DeadCode(args) => run_dead_code_subcommand(workspace, args),
+        Commands::AffectedTests(args) => run_affected_tests_subcommand(workspace, args),
+        Commands::TestResults(args) => run_test_results_subcommand(workspace, args),
+        Commands::Triage(args) => run_triage_subcommand(workspace, args),
+        Commands::Profile(args) => run_profile_subcommand(workspace, args),
+        Commands::ExplainTrace(args) => run_explain_trace_subcommand(workspace, args),
+        Commands::Ownership(args) => run_ownership_subcommand(workspace, args),
+        Commands::Docs(args) => run_docs_subcommand(workspace, args),
+        Commands::Graph(args) => run_graph_subcommand(workspace, args),
     }
 }
 
@@ -916,6 +991,54 @@ fn run_contract_subcommand(
     run_contract_command(workspace, config, args).context("contract command failed")
 }
 
+fn run_lang_subcommand(workspace: &Path, config: &AetherConfig, args: LangArgs) -> Result<()> {
+    run_lang_command(workspace, config, args).context("lang command failed")
+}
+
+fn run_arch_check_subcommand(workspace: &Path, args: ArchCheckArgs) -> Result<()> {
+    run_arch_check_command(workspace, args).context("arch-check command failed")
+}
+
+fn run_release_check_subcommand(workspace: &Path, args: ReleaseCheckArgs) -> Result<()> {
+    run_release_check_command(workspace, args).context("release-check command failed")
+}
+
+fn run_dead_code_subcommand(workspace: &Path, args: DeadCodeArgs) -> Result<()> {
+    run_dead_code_command(workspace, args).context("dead-code command failed")
+}
+
+fn run_affected_tests_subcommand(workspace: &Path, args: AffectedTestsArgs) -> Result<()> {
+    run_affected_tests_command(workspace, args).context("affected-tests command failed")
+}
+
+fn run_test_results_subcommand(workspace: &Path, args: TestResultsArgs) -> Result<()> {
+    run_test_results_command(workspace, args).context("test-results command failed")
+}
+
+fn run_triage_subcommand(workspace: &Path, args: TriageArgs) -> Result<()> {
+    run_triage_command(workspace, args).context("triage command failed")
+}
+
+fn run_profile_subcommand(workspace: &Path, args: ProfileArgs) -> Result<()> {
+    run_profile_command(workspace, args).context("profile command failed")
+}
+
+fn run_explain_trace_subcommand(workspace: &Path, args: ExplainTraceArgs) -> Result<()> {
+    run_explain_trace_command(workspace, args).context("explain-trace command failed")
+}
+
+fn run_ownership_subcommand(workspace: &Path, args: OwnershipArgs) -> Result<()> {
+    run_ownership_command(workspace, args).context("ownership command failed")
+}
+
+fn run_docs_subcommand(workspace: &Path, args: DocsArgs) -> Result<()> {
+    run_docs_command(workspace, args).context("docs command failed")
+}
+
+fn run_graph_subcommand(workspace: &Path, args: GraphArgs) -> Result<()> {
+    run_graph_command(workspace, args).context("graph command failed")
+}
+
 fn init_tracing_subscriber(log_format: LogFormat, configured_log_level: &str) -> Result<()> {
     let init_result = match log_format {
         LogFormat::Human => tracing_subscriber::fmt()
@@ -59,16 +59,18 @@ pub fn run_remember_command(workspace: &Path, args: RememberArgs) -> Result<()>
                                 updated_at: Some(remember.note.updated_at),
                             }))
                         {
-                            eprintln!("warning: failed to persist note embedding: {err}");
+                            crate::daemon_rpc::command_eprintln!(
+                                "warning: failed to persist note embedding: {err}"
+                            );
                         }
                     }
                     Ok(_) => {
-                        eprintln!(
+                        crate::daemon_rpc::command_eprintln!(
                             "warning: embedding provider returned an empty vector; note stored without semantic index"
                         );
                     }
                     Err(err) => {
-                        eprintln!(
+                        crate::daemon_rpc::command_eprintln!(
                             "warning: embedding generation failed; note stored without semantic index: {err}"
                         );
                     }
@@ -76,7 +78,7 @@ pub fn run_remember_command(workspace: &Path, args: RememberArgs) -> Result<()>
             }
             Ok(None) => {}
             Err(err) => {
-                eprintln!(
+                crate::daemon_rpc::command_eprintln!(
                     "warning: failed to load embedding provider; note stored without semantic index: {err}"
                 );
             }
@@ -199,12 +201,12 @@ pub fn run_ask_command(workspace: &Path, args: AskArgs) -> Result<()> {
                     });
                 }
                 Ok(_) => {
-                    eprintln!(
+                    crate::daemon_rpc::command_eprintln!(
                         "warning: embedding provider returned empty vector; running lexical-only ask"
                     );
                 }
                 Err(err) => {
-                    eprintln!(
+                    crate::daemon_rpc::command_eprintln!(
                         "warning: embedding generation failed for ask query; running lexical-only: {err}"
                     );
                 }
@@ -212,7 +214,7 @@ pub fn run_ask_command(workspace: &Path, args: AskArgs) -> Result<()> {
         }
         Ok(None) => {}
         Err(err) => {
-            eprintln!(
+            crate::daemon_rpc::command_eprintln!(
                 "warning: failed to load embedding provider for ask query; running lexical-only: {err}"
             );
         }
@@ -303,7 +305,7 @@ fn current_unix_timestamp_millis() -> i64 {
 }
 
 fn write_json_to_stdout(value: &serde_json::Value) -> Result<()> {
-    let mut out = std::io::stdout();
+    let mut out = crate::daemon_rpc::command_stdout();
     serde_json::to_writer_pretty(&mut out, value).context("failed to serialize JSON output")?;
     writeln!(&mut out).context("failed to write trailing newline")?;
     Ok(())
@@ -4,11 +4,14 @@ use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
-use aether_core::{Language, Symbol, SymbolChangeEvent, diff_symbols, normalize_path};
-use aether_parse::{SymbolExtractor, language_for_path};
+use aether_core::{Language, SymbolChangeEvent, diff_symbols, normalize_path};
+use aether_parse::{SymbolExtractor, SyntaxSnapshot, language_for_path};
 use anyhow::{Context, Result};
 use ignore::WalkBuilder;
 
+use crate::parse_pool::{parse_in_parallel, parse_worker_count};
+use crate::workspace_roots::{ObservedRoot, load_workspace_roots};
+
 const MAX_PARSE_FILE_SIZE: u64 = 2 * 1024 * 1024;
 const IGNORED_PATH_MARKERS: &[&str] = &[
     ".git",
@@ -32,31 +35,57 @@ const IGNORED_PATH_MARKERS: &[&str] = &[
     "vendor",
 ];
 
-#[derive(Debug, Clone)]
-struct FileSnapshot {
+/// A file found by the initial walk, parsed on a worker thread.
+struct SeedFile {
+    relative: PathBuf,
+    full_path: PathBuf,
+    display_path: String,
     language: Language,
-    symbols: Vec<Symbol>,
 }
 
 pub struct ObserverState {
     workspace: PathBuf,
+    roots: Vec<ObservedRoot>,
     extractor: SymbolExtractor,
-    snapshots: HashMap<PathBuf, FileSnapshot>,
+    /// Retained syntax per file, so the next change reparses incrementally.
+    snapshots: HashMap<PathBuf, SyntaxSnapshot>,
 }
 
 impl ObserverState {
     pub fn new(workspace: PathBuf) -> Result<Self> {
+        let roots = load_workspace_roots(&workspace);
         Ok(Self {
             workspace,
+            roots,
             extractor: SymbolExtractor::new()?,
             snapshots: HashMap::new(),
         })
     }
 
+    /// Additional `[[roots]]` directories observed alongside the workspace.
+    pub fn roots(&self) -> &[ObservedRoot] {
+        &self.roots
+    }
+
+    /// One walker for the workspace and one per observed root, with the
+    /// root's `exclude` globs applied so excluded trees are never traversed.
+    pub fn root_walkers(&self) -> Vec<WalkBuilder> {
+        let mut walks = vec![WalkBuilder::new(&self.workspace)];
+        for root in &self.roots {
+            let mut walk = WalkBuilder::new(&root.path);
+            walk.overrides(root.excludes());
+            walks.push(walk);
+        }
+        walks
+    }
+
     pub fn seed_from_disk(&mut self) -> Result<()> {
-        for entry in WalkBuilder::new(&self.workspace)
-            .standard_filters(true)
-            .build()
+        let mut walks = self.root_walkers();
+
+        let mut files = Vec::new();
+        for entry in walks
+            .iter_mut()
+            .flat_map(|walk| walk.standard_filters(true).build())
         {
             let Ok(entry) = entry else {
                 continue;
@@ -78,23 +107,41 @@ impl ObserverState {
                 continue;
             };
 
-            let relative = relative_workspace_path(&self.workspace, full_path);
-            let display_path = normalize_path(&relative.to_string_lossy());
-
-            let source = match fs::read_to_string(full_path) {
-                Ok(source) => source,
-                Err(_) => continue,
+            let Some(relative) = self.stored_path(full_path) else {
+                continue;
             };
+            let display_path = normalize_path(&relative.to_string_lossy());
+            files.push(SeedFile {
+                relative,
+                full_path: full_path.to_path_buf(),
+                display_path,
+                language,
+            });
+        }
 
-            let symbols = self
-                .extractor
-                .extract_from_source(language, &display_path, &source)
-                .with_context(|| {
-                    format!("failed to extract symbols from {}", full_path.display())
-                })?;
-
-            self.snapshots
-                .insert(relative, FileSnapshot { language, symbols });
+        let parsed = parse_in_parallel(
+            &files,
+            parse_worker_count(files.len()),
+            |extractor, file| {
+                let Ok(source) = fs::read_to_string(&file.full_path) else {
+                    return Ok(None);
+                };
+                extractor
+                    .parse_snapshot(file.language, &file.display_path, source)
+                    .map(Some)
+                    .with_context(|| {
+                        format!(
+                            "failed to extract symbols from {}",
+                            file.full_path.display()
+                        )
+                    })
+            },
+        )?;
+
+        for (file, syntax) in files.into_iter().zip(parsed) {
+            if let Some(syntax) = syntax? {
+                self.snapshots.insert(file.relative, syntax);
+            }
         }
 
         Ok(())
@@ -105,18 +152,22 @@ impl ObserverState {
             return Ok(None);
         }
 
-        let relative = relative_workspace_path(&self.workspace, path);
+        let Some(relative) = self.stored_path(path) else {
+            return Ok(None);
+        };
         let display_path = normalize_path(&relative.to_string_lossy());
 
-        let previous = self.snapshots.get(&relative).cloned();
-        let language =
-            language_for_path(&relative).or_else(|| previous.as_ref().map(|snap| snap.language));
+        let language = language_for_path(&relative).or_else(|| {
+            self.snapshots
+                .get(&relative)
+                .map(|snapshot| snapshot.language())
+        });
 
         let Some(language) = language else {
             return Ok(None);
         };
 
-        let current_symbols = if path.exists() && path.is_file() {
+        let source = if path.exists() && path.is_file() {
             if let Some(metadata) = match fs::metadata(path) {
                 Ok(metadata) => Some(metadata),
                 Err(err) if err.kind() == ErrorKind::NotFound => None,
@@ -139,13 +190,8 @@ impl ObserverState {
             }
 
             match fs::read_to_string(path) {
-                Ok(source) => self
-                    .extractor
-                    .extract_from_source(language, &display_path, &source)
-                    .with_context(|| {
-                        format!("failed to extract symbols from {}", path.display())
-                    })?,
-                Err(err) if err.kind() == ErrorKind::NotFound => Vec::new(),
+                Ok(source) => Some(source),
+                Err(err) if err.kind() == ErrorKind::NotFound => None,
                 Err(err) => {
                     return Err(err).with_context(|| {
                         format!("failed to read changed file {}", path.display())
@@ -153,26 +199,36 @@ impl ObserverState {
                 }
             }
         } else {
-            Vec::new()
+            None
         };
 
+        let previous = self.snapshots.remove(&relative);
         let previous_symbols = previous
             .as_ref()
-            .map(|snapshot| snapshot.symbols.as_slice())
-            .unwrap_or(&[]);
+            .map(|snapshot| snapshot.symbols().to_vec())
+            .unwrap_or_default();
+
+        let current = match source {
+            Some(source) => {
+                let syntax = match previous {
+                    Some(previous) if previous.language() == language => {
+                        self.extractor.reparse_snapshot(previous, source)
+                    }
+                    _ => self
+                        .extractor
+                        .parse_snapshot(language, &display_path, source),
+                }
+                .with_context(|| format!("failed to extract symbols from {}", path.display()))?;
+                Some(syntax)
+            }
+            None => None,
+        };
+        let current_symbols = current.as_ref().map(SyntaxSnapshot::symbols).unwrap_or(&[]);
 
-        let event = diff_symbols(&display_path, language, previous_symbols, &current_symbols);
+        let event = diff_symbols(&display_path, language, &previous_symbols, current_symbols);
 
-        if current_symbols.is_empty() {
-            self.snapshots.remove(&relative);
-        } else {
-            self.snapshots.insert(
-                relative,
-                FileSnapshot {
-                    language,
-                    symbols: current_symbols,
-                },
-            );
+        if let Some(syntax) = current.filter(|syntax| !syntax.symbols().is_empty()) {
+            self.snapshots.insert(relative, syntax);
         }
 
         if event.is_empty() {
@@ -186,16 +242,16 @@ impl ObserverState {
         let mut events = Vec::new();
 
         for (relative_path, snapshot) in &self.snapshots {
-            if snapshot.symbols.is_empty() {
+            if snapshot.symbols().is_empty() {
                 continue;
             }
 
-            let mut added = snapshot.symbols.clone();
+            let mut added = snapshot.symbols().to_vec();
             added.sort_by(|a, b| a.id.cmp(&b.id));
 
             events.push(SymbolChangeEvent {
                 file_path: normalize_path(&relative_path.to_string_lossy()),
-                language: snapshot.language,
+                language: snapshot.language(),
                 added,
                 removed: Vec::new(),
                 updated: Vec::new(),
@@ -210,11 +266,30 @@ impl ObserverState {
         let mut paths = self
             .snapshots
             .keys()
-            .map(|relative| self.workspace.join(relative))
+            .map(|relative| self.absolute_path(relative))
             .collect::<Vec<_>>();
         paths.sort();
         paths
     }
+
+    /// Stored file path for a changed path, or `None` when a root excludes it.
+    fn stored_path(&self, path: &Path) -> Option<PathBuf> {
+        if path.is_absolute()
+            && let Some(root) = self.roots.iter().find(|root| path.starts_with(&root.path))
+        {
+            return root.stored_path(path);
+        }
+        Some(relative_workspace_path(&self.workspace, path))
+    }
+
+    fn absolute_path(&self, relative: &Path) -> PathBuf {
+        for root in &self.roots {
+            if let Ok(rest) = relative.strip_prefix(&root.prefix) {
+                return root.path.join(rest);
+            }
+        }
+        self.workspace.join(relative)
+    }
 }
 
 #[derive(Debug, Default)]
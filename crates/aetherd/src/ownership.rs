@@ -0,0 +1,164 @@
+//! `aetherd ownership`: CODEOWNERS and recency-weighted blame ownership with
+//! bus factor, per symbol and per community.
+
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::Path;
+
+use aether_analysis::{AuthorShare, OwnershipAnalyzer, OwnershipReport, OwnershipRequest};
+use aether_store::{SqliteStore, open_surreal_graph_store_readonly};
+use anyhow::{Context, Result};
+
+use crate::cli::OwnershipArgs;
+
+pub fn run_ownership_command(workspace: &Path, args: OwnershipArgs) -> Result<()> {
+    let analyzer = OwnershipAnalyzer::new(workspace).context("failed to load CODEOWNERS")?;
+    let store = SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+    let graph = open_surreal_graph_store_readonly(workspace).ok();
+    let report = analyzer
+        .report(
+            &store,
+            graph.as_ref(),
+            &OwnershipRequest {
+                file: args.file,
+                symbol: args.symbol,
+                limit: Some(args.limit),
+            },
+        )
+        .context("ownership analysis failed")?;
+
+    let rendered = if args.json {
+        serde_json::to_string_pretty(&report).context("failed to serialize ownership report")?
+    } else {
+        render_report(&report)
+    };
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    writeln!(&mut stdout, "{}", rendered.trim_end()).context("failed to write ownership output")?;
+    Ok(())
+}
+
+fn render_report(report: &OwnershipReport) -> String {
+    let mut out = match report.codeowners_file.as_deref() {
+        Some(file) => format!("CODEOWNERS: {file}\n"),
+        None => "CODEOWNERS: none\n".to_owned(),
+    };
+
+    if !report.symbols.is_empty() {
+        out.push_str("\nSymbols:\n");
+        for symbol in &report.symbols {
+            let span = match (symbol.start_line, symbol.end_line) {
+                (Some(start), Some(end)) => format!(":{start}-{end}"),
+                _ => String::new(),
+            };
+            let _ = writeln!(
+                out,
+                "  {} ({}{span}) bus factor {}",
+                symbol.qualified_name,
+                symbol.file_path,
+                render_bus_factor(symbol.bus_factor)
+            );
+            render_owners(&mut out, &symbol.codeowners, &symbol.authors);
+        }
+    }
+
+    if !report.communities.is_empty() {
+        out.push_str("\nCommunities:\n");
+        for community in &report.communities {
+            let _ = writeln!(
+                out,
+                "  #{} ({} symbol(s) in {} file(s)) bus factor {}",
+                community.community_id,
+                community.symbol_count,
+                community.file_count,
+                render_bus_factor(community.bus_factor)
+            );
+            render_owners(&mut out, &community.codeowners, &community.authors);
+        }
+    }
+
+    for note in &report.notes {
+        let _ = writeln!(out, "note: {note}");
+    }
+    out
+}
+
+fn render_owners(out: &mut String, codeowners: &[String], authors: &[AuthorShare]) {
+    if !codeowners.is_empty() {
+        let _ = writeln!(out, "     codeowners: {}", codeowners.join(" "));
+    }
+    if !authors.is_empty() {
+        let authors = authors
+            .iter()
+            .map(|author| format!("{} {:.0}%", author.author, author.share * 100.0))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let _ = writeln!(out, "     authors: {authors}");
+    }
+}
+
+fn render_bus_factor(bus_factor: usize) -> String {
+    if bus_factor == 0 {
+        "unknown".to_owned()
+    } else {
+        bus_factor.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_analysis::{CommunityOwnership, SymbolOwnership};
+
+    use super::*;
+
+    #[test]
+    fn render_report_lists_owners_and_bus_factor() {
+        let report = OwnershipReport {
+            schema_version: "1.0".to_owned(),
+            codeowners_file: Some(".github/CODEOWNERS".to_owned()),
+            symbols: vec![SymbolOwnership {
+                symbol_id: "sym-total".to_owned(),
+                qualified_name: "Cart::total".to_owned(),
+                file_path: "src/cart.rs".to_owned(),
+                start_line: Some(38),
+                end_line: Some(47),
+                codeowners: vec!["@org/checkout".to_owned()],
+                authors: vec![
+                    AuthorShare {
+                        author: "ana".to_owned(),
+                        lines: 8,
+                        weight: 6.0,
+                        share: 0.75,
+                    },
+                    AuthorShare {
+                        author: "bo".to_owned(),
+                        lines: 2,
+                        weight: 2.0,
+                        share: 0.25,
+                    },
+                ],
+                bus_factor: 1,
+            }],
+            communities: vec![CommunityOwnership {
+                community_id: 4,
+                symbol_count: 12,
+                file_count: 3,
+                codeowners: Vec::new(),
+                authors: Vec::new(),
+                bus_factor: 0,
+            }],
+            notes: vec!["graph store unavailable; community ownership skipped".to_owned()],
+        };
+
+        assert_eq!(
+            render_report(&report),
+            "CODEOWNERS: .github/CODEOWNERS\n\n\
+             Symbols:\n\
+             \x20 Cart::total (src/cart.rs:38-47) bus factor 1\n\
+             \x20    codeowners: @org/checkout\n\
+             \x20    authors: ana 75%, bo 25%\n\n\
+             Communities:\n\
+             \x20 #4 (12 symbol(s) in 3 file(s)) bus factor unknown\n\
+             note: graph store unavailable; community ownership skipped\n"
+        );
+    }
+}
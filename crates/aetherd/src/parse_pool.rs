@@ -0,0 +1,100 @@
+//! Bounded worker pool for the parse/extract stage of the initial index.
+//!
+//! tree-sitter parsers are not shareable across threads, so every worker owns
+//! its own [`SymbolExtractor`]. Results come back in input order regardless of
+//! which worker finished first.
+
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use aether_parse::SymbolExtractor;
+use anyhow::{Context, Result};
+
+/// Upper bound on parse workers, whatever the machine's parallelism.
+pub(crate) const MAX_PARSE_WORKERS: usize = 8;
+
+/// Worker count for `jobs` parse jobs on this machine.
+pub(crate) fn parse_worker_count(jobs: usize) -> usize {
+    std::thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(MAX_PARSE_WORKERS)
+        .min(jobs)
+        .max(1)
+}
+
+/// Runs `parse` over `items` on up to `workers` threads and returns the
+/// results in the order of `items`.
+pub(crate) fn parse_in_parallel<T, R, F>(items: &[T], workers: usize, parse: F) -> Result<Vec<R>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&mut SymbolExtractor, &T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let outputs = std::thread::scope(|scope| {
+        let handles = (0..workers.clamp(1, items.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| -> Result<Vec<(usize, R)>> {
+                    let mut extractor =
+                        SymbolExtractor::new().context("failed to initialize parser")?;
+                    let mut parsed = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            return Ok(parsed);
+                        };
+                        parsed.push((index, parse(&mut extractor, item)));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Err(anyhow::anyhow!("parse worker panicked")))
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+
+    let mut indexed = outputs.into_iter().flatten().collect::<Vec<_>>();
+    indexed.sort_by_key(|(index, _)| *index);
+    Ok(indexed.into_iter().map(|(_, result)| result).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_core::Language;
+
+    use super::*;
+
+    #[test]
+    fn results_keep_input_order_across_workers() {
+        let sources = (0..40)
+            .map(|index| format!("fn item_{index}() -> u32 {{ {index} }}\n"))
+            .collect::<Vec<_>>();
+
+        let names = parse_in_parallel(&sources, 4, |extractor, source| {
+            extractor
+                .extract_from_source(Language::Rust, "src/lib.rs", source)
+                .expect("extract")
+                .into_iter()
+                .map(|symbol| symbol.name)
+                .collect::<Vec<_>>()
+        })
+        .expect("parse in parallel");
+
+        let expected = (0..40)
+            .map(|index| vec![format!("item_{index}")])
+            .collect::<Vec<_>>();
+        assert_eq!(names, expected);
+    }
+
+    #[test]
+    fn worker_count_is_bounded_by_jobs_and_limit() {
+        assert_eq!(parse_worker_count(0), 1);
+        assert_eq!(parse_worker_count(1), 1);
+        assert!(parse_worker_count(1_000) <= MAX_PARSE_WORKERS);
+    }
+}
@@ -136,7 +136,7 @@ pub fn kind_priority_score(kind: &str, is_public: bool) -> f64 {
     }
     if matches!(
         kind.as_str(),
-        "const" | "static" | "type" | "type_alias" | "variable"
+        "const" | "constant" | "static" | "macro" | "type" | "type_alias" | "variable"
     ) {
         return 0.5;
     }
@@ -204,6 +204,7 @@ mod tests {
         assert_eq!(kind_priority_score("function", true), 1.0);
         assert_eq!(kind_priority_score("function", false), 0.7);
         assert_eq!(kind_priority_score("type_alias", false), 0.5);
+        assert_eq!(kind_priority_score("constant", false), 0.5);
         assert_eq!(kind_priority_score("enum", false), 0.3);
     }
 }
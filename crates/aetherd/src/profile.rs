@@ -0,0 +1,155 @@
+//! `aetherd profile`: runtime profile ingestion and hot-symbol listing.
+
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use aether_analysis::{
+    HotSymbol, HotSymbolsReport, IngestProfileRequest, IngestedProfile, ProfileAnalyzer,
+};
+use aether_store::SqliteStore;
+use anyhow::{Context, Result};
+
+use crate::cli::{ProfileArgs, ProfileCommand};
+
+pub fn run_profile_command(workspace: &Path, args: ProfileArgs) -> Result<()> {
+    let analyzer = ProfileAnalyzer::new(workspace).context("failed to initialize analyzer")?;
+    let rendered = match args.command {
+        ProfileCommand::Ingest(args) => {
+            let store = SqliteStore::open(workspace).context("failed to open local store")?;
+            let ingested = analyzer
+                .ingest(
+                    &store,
+                    &IngestProfileRequest {
+                        path: resolve_profile_path(workspace, &args.profile),
+                        format: args.format,
+                        replace: args.replace,
+                    },
+                )
+                .context("failed to ingest profile")?;
+            if args.json {
+                serde_json::to_string_pretty(&ingested).context("failed to serialize profile")?
+            } else {
+                render_ingested(&ingested)
+            }
+        }
+        ProfileCommand::Hot(args) => {
+            let store =
+                SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+            let report = analyzer
+                .hot_symbols(&store, args.limit)
+                .context("hot-symbol query failed")?;
+            if args.json {
+                serde_json::to_string_pretty(&report)
+                    .context("failed to serialize hot-symbol report")?
+            } else {
+                render_hot(&report)
+            }
+        }
+    };
+
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    writeln!(&mut stdout, "{}", rendered.trim_end()).context("failed to write profile output")?;
+    Ok(())
+}
+
+/// Relative profile paths resolve against the workspace so the command reads
+/// the same file when it runs inside the daemon.
+fn resolve_profile_path(workspace: &Path, profile: &Path) -> PathBuf {
+    if profile.is_absolute() {
+        profile.to_path_buf()
+    } else {
+        workspace.join(profile)
+    }
+}
+
+fn render_ingested(ingested: &IngestedProfile) -> String {
+    let mut out = format!(
+        "Ingested {} profile from {}: {} sample(s), {} mapped; {}/{} frame(s) resolved to {} symbol(s), {} call edge(s)\n",
+        ingested.profile.format,
+        ingested.profile.source_path,
+        ingested.profile.total_samples,
+        ingested.profile.mapped_samples,
+        ingested.frames_mapped,
+        ingested.frames,
+        ingested.symbols_sampled,
+        ingested.edges_sampled
+    );
+    render_symbols(&mut out, "Hottest", &ingested.hottest);
+    for note in &ingested.notes {
+        let _ = writeln!(out, "note: {note}");
+    }
+    out
+}
+
+fn render_hot(report: &HotSymbolsReport) -> String {
+    let samples = report
+        .profiles
+        .iter()
+        .map(|profile| profile.total_samples)
+        .sum::<i64>();
+    let mut out = format!(
+        "{} profile(s), {} sample(s)\n",
+        report.profiles.len(),
+        samples
+    );
+    render_symbols(&mut out, "Hot symbols", &report.symbols);
+    out
+}
+
+fn render_symbols(out: &mut String, heading: &str, symbols: &[HotSymbol]) {
+    if symbols.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "\n{heading}:");
+    for symbol in symbols {
+        let _ = writeln!(
+            out,
+            "  {:>8} total {:>8} self  {} ({})",
+            symbol.total_samples, symbol.self_samples, symbol.symbol_name, symbol.file
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_store::ProfileRecord;
+
+    use super::*;
+
+    #[test]
+    fn render_ingested_lists_hottest_symbols() {
+        let ingested = IngestedProfile {
+            schema_version: "1.0".to_owned(),
+            profile: ProfileRecord {
+                profile_id: "0123456789abcdef".to_owned(),
+                format: "collapsed".to_owned(),
+                source_path: "target/cpu.folded".to_owned(),
+                recorded_at: 0,
+                total_samples: 120,
+                mapped_samples: 100,
+            },
+            frames: 6,
+            frames_mapped: 4,
+            symbols_sampled: 2,
+            edges_sampled: 1,
+            hottest: vec![HotSymbol {
+                symbol_id: "sym-parse".to_owned(),
+                symbol_name: "Parser::parse".to_owned(),
+                file: "src/parser.rs".to_owned(),
+                self_samples: 70,
+                total_samples: 100,
+            }],
+            notes: vec!["2 frame(s) did not match an indexed symbol".to_owned()],
+        };
+
+        assert_eq!(
+            render_ingested(&ingested),
+            "Ingested collapsed profile from target/cpu.folded: 120 sample(s), 100 mapped; \
+             4/6 frame(s) resolved to 2 symbol(s), 1 call edge(s)\n\n\
+             Hottest:\n\
+             \x20      100 total       70 self  Parser::parse (src/parser.rs)\n\
+             note: 2 frame(s) did not match an indexed symbol\n"
+        );
+    }
+}
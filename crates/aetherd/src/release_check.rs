@@ -0,0 +1,196 @@
+//! `aetherd release-check`: exported API surface per package compared
+//! between two git refs, rendered as markdown or JSON.
+
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::Path;
+
+use aether_analysis::{ApiChangeKind, ReleaseCheckReport, ReleaseCheckRequest, check_release};
+use aether_store::SqliteStore;
+use anyhow::{Context, Result};
+
+use crate::cli::{ReleaseCheckArgs, ReleaseCheckOutputFormat};
+
+pub fn run_release_check_command(workspace: &Path, args: ReleaseCheckArgs) -> Result<()> {
+    let store = SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+    let report = check_release(
+        workspace,
+        &store,
+        &ReleaseCheckRequest {
+            base_ref: args.base,
+            head_ref: args.head,
+            threshold: args.threshold,
+            packages: args.package,
+        },
+    )
+    .context("release check failed")?;
+
+    let rendered = match args.format {
+        ReleaseCheckOutputFormat::Markdown => render_markdown(&report),
+        ReleaseCheckOutputFormat::Json => serde_json::to_string_pretty(&report)
+            .context("failed to serialize release check report")?,
+    };
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    writeln!(&mut stdout, "{}", rendered.trim_end())
+        .context("failed to write release-check output")?;
+
+    let silent_changes = report
+        .changes
+        .iter()
+        .any(|change| change.kind == ApiChangeKind::SilentBehaviorChange);
+    if report.breaking || silent_changes {
+        crate::daemon_rpc::exit_command(1);
+    }
+    Ok(())
+}
+
+fn render_markdown(report: &ReleaseCheckReport) -> String {
+    let head = match report.head_commit.as_deref() {
+        Some(commit) => format!("{} ({})", report.head_ref, short_commit(commit)),
+        None => report.head_ref.clone(),
+    };
+    let mut out = format!(
+        "# Release check: {} ({}) -> {head}\n\n",
+        report.base_ref,
+        short_commit(&report.base_commit)
+    );
+    let verdict = if report.breaking {
+        "**Breaking changes detected.**"
+    } else {
+        "No breaking API changes."
+    };
+    let _ = writeln!(out, "{verdict}\n");
+
+    if !report.packages.is_empty() {
+        out.push_str(
+            "| package | exports | added | removed | signature changed | silent behavior |\n\
+             |---|---|---|---|---|---|\n",
+        );
+        for package in &report.packages {
+            let _ = writeln!(
+                out,
+                "| {} | {} -> {} | {} | {} | {} | {} |",
+                package.package,
+                package.exported_before,
+                package.exported_after,
+                package.added,
+                package.removed,
+                package.signature_changed,
+                package.silent_behavior_changes
+            );
+        }
+        out.push('\n');
+    }
+
+    for (kind, heading) in [
+        (ApiChangeKind::Removed, "Removed"),
+        (ApiChangeKind::SignatureChanged, "Signature changed"),
+        (
+            ApiChangeKind::SilentBehaviorChange,
+            "Silent behavior changes",
+        ),
+        (ApiChangeKind::Added, "Added"),
+    ] {
+        let changes = report
+            .changes
+            .iter()
+            .filter(|change| change.kind == kind)
+            .collect::<Vec<_>>();
+        if changes.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "## {heading}\n");
+        for change in changes {
+            let _ = write!(
+                out,
+                "- `{}` {} `{}` ({})",
+                change.package, change.symbol_kind, change.qualified_name, change.file_path
+            );
+            if let Some(similarity) = change.similarity {
+                let _ = write!(
+                    out,
+                    " — SIR similarity {similarity:.2} < {:.2}",
+                    report.threshold
+                );
+            }
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+
+    if !report.notes.is_empty() {
+        out.push_str("## Notes\n\n");
+        for note in &report.notes {
+            let _ = writeln!(out, "- {note}");
+        }
+    }
+    out
+}
+
+fn short_commit(commit: &str) -> &str {
+    commit.get(..12).unwrap_or(commit)
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_analysis::{ApiChange, PackageApiSummary};
+
+    use super::*;
+
+    #[test]
+    fn render_markdown_groups_changes_by_kind() {
+        let change = |kind, name: &str, similarity| ApiChange {
+            kind,
+            package: "aether-core".to_owned(),
+            qualified_name: name.to_owned(),
+            symbol_kind: "function".to_owned(),
+            language: "rust".to_owned(),
+            file_path: "src/lib.rs".to_owned(),
+            before_signature: None,
+            after_signature: None,
+            similarity,
+            method: None,
+        };
+        let report = ReleaseCheckReport {
+            schema_version: "1.0".to_owned(),
+            base_ref: "v1.0.0".to_owned(),
+            base_commit: "0123456789abcdef0123".to_owned(),
+            head_ref: "working tree".to_owned(),
+            head_commit: None,
+            threshold: 0.85,
+            breaking: true,
+            packages: vec![PackageApiSummary {
+                package: "aether-core".to_owned(),
+                exported_before: 2,
+                exported_after: 2,
+                added: 1,
+                removed: 1,
+                signature_changed: 0,
+                silent_behavior_changes: 1,
+            }],
+            changes: vec![
+                change(ApiChangeKind::Removed, "open", None),
+                change(ApiChangeKind::SilentBehaviorChange, "close", Some(0.4)),
+                change(ApiChangeKind::Added, "sync", None),
+            ],
+            unchecked_behavior: 0,
+            used_embeddings: false,
+            notes: Vec::new(),
+        };
+
+        assert_eq!(
+            render_markdown(&report),
+            "# Release check: v1.0.0 (0123456789ab) -> working tree\n\n\
+             **Breaking changes detected.**\n\n\
+             | package | exports | added | removed | signature changed | silent behavior |\n\
+             |---|---|---|---|---|---|\n\
+             | aether-core | 2 -> 2 | 1 | 1 | 0 | 1 |\n\n\
+             ## Removed\n\n\
+             - `aether-core` function `open` (src/lib.rs)\n\n\
+             ## Silent behavior changes\n\n\
+             - `aether-core` function `close` (src/lib.rs) — SIR similarity 0.40 < 0.85\n\n\
+             ## Added\n\n\
+             - `aether-core` function `sync` (src/lib.rs)\n\n"
+        );
+    }
+}
@@ -5,7 +5,7 @@ use std::sync::OnceLock;
 
 use aether_config::{
     AetherConfig, SearchCalibratedThresholdsConfig, SearchRerankerKind, SearchThresholdsConfig,
-    ensure_workspace_config,
+    declared_workspace_roots, ensure_workspace_config,
 };
 use aether_core::{
     SEARCH_FALLBACK_EMBEDDING_EMPTY_QUERY_VECTOR, SEARCH_FALLBACK_EMBEDDINGS_DISABLED,
@@ -17,6 +17,7 @@ use aether_infer::{
     RerankerProvider, RerankerProviderOverrides, load_embedding_provider_from_config,
     load_reranker_provider_from_config,
 };
+use aether_sir::{SirAnnotation, is_valid_extension_name, sir_extension_matches};
 use aether_store::{
     SirStateStore, SqliteStore, SymbolCatalogStore, SymbolSearchResult, ThresholdCalibrationRecord,
     ThresholdStore, open_vector_store,
@@ -24,6 +25,8 @@ use aether_store::{
 use anyhow::{Context, Result};
 use serde_json::{Value, json};
 
+use crate::workspace_roots::root_name_for_file_path;
+
 pub use aether_core::SearchMode;
 
 fn reranker_runtime() -> &'static tokio::runtime::Runtime {
@@ -78,15 +81,72 @@ pub struct SearchResultRow {
 
 pub type SearchExecution = SearchEnvelope<SearchResultRow>;
 
+/// `NAME=VALUE` filter matched against a custom SIR extension field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SirFieldFilter {
+    pub name: String,
+    pub value: String,
+}
+
+impl std::str::FromStr for SirFieldFilter {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let Some((name, expected)) = value.split_once('=') else {
+            return Err(format!(
+                "invalid SIR field filter '{value}', expected NAME=VALUE"
+            ));
+        };
+        let name = name.trim();
+        if !is_valid_extension_name(name) {
+            return Err(format!("invalid SIR field name '{name}'"));
+        }
+        Ok(Self {
+            name: name.to_owned(),
+            value: expected.trim().to_owned(),
+        })
+    }
+}
+
+/// Post-retrieval filters applied by `--sir-field` and `--root`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchFilters {
+    pub sir_fields: Vec<SirFieldFilter>,
+    /// Root names to keep; the primary workspace is named after its directory.
+    pub roots: Vec<String>,
+}
+
+impl SearchFilters {
+    pub fn is_empty(&self) -> bool {
+        self.sir_fields.is_empty() && self.roots.is_empty()
+    }
+}
+
 pub fn run_search_once(
     workspace: &Path,
     query: &str,
     limit: u32,
     mode: SearchMode,
+    filters: &SearchFilters,
     output_format: SearchOutputFormat,
     out: &mut dyn Write,
 ) -> Result<()> {
-    let execution = execute_search(workspace, query, limit, mode)?;
+    let mut execution = if filters.is_empty() {
+        execute_search(workspace, query, limit, mode)?
+    } else {
+        // Over-fetch so filtering does not starve the requested result count.
+        let mut execution = execute_search(workspace, query, 100, mode)?;
+        if !filters.roots.is_empty() {
+            execution.matches = filter_by_roots(workspace, execution.matches, &filters.roots)?;
+        }
+        if !filters.sir_fields.is_empty() {
+            let store = SqliteStore::open(workspace).context("failed to initialize local store")?;
+            execution.matches =
+                filter_by_sir_fields(&store, execution.matches, &filters.sir_fields)?;
+        }
+        execution
+    };
+    execution.matches.truncate(limit.clamp(1, 100) as usize);
     if let Some(reason) = &execution.fallback_reason {
         tracing::warn!(fallback_reason = %reason, "AETHER search fallback");
     }
@@ -100,6 +160,49 @@ pub fn run_search_once(
     Ok(())
 }
 
+fn filter_by_roots(
+    workspace: &Path,
+    matches: Vec<SearchResultRow>,
+    roots: &[String],
+) -> Result<Vec<SearchResultRow>> {
+    let config =
+        ensure_workspace_config(workspace).context("failed to load workspace config for search")?;
+    let declared = declared_workspace_roots(&config);
+    Ok(matches
+        .into_iter()
+        .filter(|row| {
+            let root = root_name_for_file_path(workspace, &declared, &row.file_path);
+            roots.iter().any(|wanted| wanted == &root)
+        })
+        .collect())
+}
+
+fn filter_by_sir_fields(
+    store: &SqliteStore,
+    matches: Vec<SearchResultRow>,
+    filters: &[SirFieldFilter],
+) -> Result<Vec<SearchResultRow>> {
+    let mut kept = Vec::with_capacity(matches.len());
+    for row in matches {
+        let Some(blob) = store
+            .read_sir_blob(&row.symbol_id)
+            .with_context(|| format!("failed to read SIR for {}", row.symbol_id))?
+        else {
+            continue;
+        };
+        let Ok(sir) = serde_json::from_str::<SirAnnotation>(&blob) else {
+            continue;
+        };
+        if filters
+            .iter()
+            .all(|filter| sir_extension_matches(&sir, &filter.name, &filter.value))
+        {
+            kept.push(row);
+        }
+    }
+    Ok(kept)
+}
+
 pub fn execute_search(
     workspace: &Path,
     query: &str,
@@ -823,6 +926,7 @@ mod tests {
                 edge_cases: None,
                 complexity: None,
                 method_dependencies: None,
+                extensions: Default::default(),
             })
         }
     }
@@ -908,6 +1012,7 @@ mod tests {
             "alpha",
             20,
             SearchMode::Lexical,
+            &SearchFilters::default(),
             SearchOutputFormat::Table,
             &mut out,
         )
@@ -918,6 +1023,126 @@ mod tests {
         assert!(rendered.contains("demo::alpha"));
     }
 
+    #[test]
+    fn run_search_once_filters_by_sir_extension_fields() {
+        let temp = tempdir().expect("tempdir");
+        let workspace = temp.path();
+        let store = SqliteStore::open(workspace).expect("open store");
+
+        for (id, name, team) in [
+            ("sym-1", "demo::alpha_one", "billing"),
+            ("sym-2", "demo::alpha_two", "search"),
+        ] {
+            store
+                .upsert_symbol(SymbolRecord {
+                    id: id.to_owned(),
+                    file_path: "src/lib.rs".to_owned(),
+                    language: "rust".to_owned(),
+                    kind: "function".to_owned(),
+                    qualified_name: name.to_owned(),
+                    signature_fingerprint: format!("sig-{id}"),
+                    last_seen_at: 1_700_000_000,
+                })
+                .expect("upsert symbol");
+            let sir = SirAnnotation {
+                intent: format!("Intent for {name}"),
+                behavior: None,
+                inputs: Vec::new(),
+                outputs: Vec::new(),
+                side_effects: Vec::new(),
+                dependencies: Vec::new(),
+                error_modes: Vec::new(),
+                confidence: 0.9,
+                edge_cases: None,
+                complexity: None,
+                method_dependencies: None,
+                extensions: [("owner_team".to_owned(), json!(team))]
+                    .into_iter()
+                    .collect(),
+            };
+            store
+                .write_sir_blob(id, &serde_json::to_string(&sir).expect("serialize sir"))
+                .expect("write sir");
+        }
+
+        let filter: SirFieldFilter = "owner_team=billing".parse().expect("parse filter");
+        let mut out = Vec::new();
+        run_search_once(
+            workspace,
+            "alpha",
+            20,
+            SearchMode::Lexical,
+            &SearchFilters {
+                sir_fields: vec![filter],
+                roots: Vec::new(),
+            },
+            SearchOutputFormat::Table,
+            &mut out,
+        )
+        .expect("run search");
+
+        let rendered = String::from_utf8(out).expect("utf8 output");
+        assert!(rendered.contains("demo::alpha_one"));
+        assert!(!rendered.contains("demo::alpha_two"));
+        assert!("owner_team".parse::<SirFieldFilter>().is_err());
+    }
+
+    #[test]
+    fn run_search_once_filters_by_workspace_root() {
+        let temp = tempdir().expect("tempdir");
+        let workspace = temp.path().join("backend");
+        fs::create_dir_all(workspace.join(".aether")).expect("create workspace");
+        fs::write(
+            workspace.join(".aether/config.toml"),
+            "[[roots]]\nname = \"sdk\"\npath = \"../sdk\"\n",
+        )
+        .expect("write config");
+        let store = SqliteStore::open(&workspace).expect("open store");
+
+        for (id, name, file_path) in [
+            ("sym-1", "demo::alpha_backend", "src/lib.rs"),
+            ("sym-2", "ApiClient.alphaSdk", "../sdk/src/client.ts"),
+        ] {
+            store
+                .upsert_symbol(SymbolRecord {
+                    id: id.to_owned(),
+                    file_path: file_path.to_owned(),
+                    language: "rust".to_owned(),
+                    kind: "function".to_owned(),
+                    qualified_name: name.to_owned(),
+                    signature_fingerprint: format!("sig-{id}"),
+                    last_seen_at: 1_700_000_000,
+                })
+                .expect("upsert symbol");
+        }
+
+        let search = |root: &str| {
+            let mut out = Vec::new();
+            run_search_once(
+                &workspace,
+                "alpha",
+                20,
+                SearchMode::Lexical,
+                &SearchFilters {
+                    sir_fields: Vec::new(),
+                    roots: vec![root.to_owned()],
+                },
+                SearchOutputFormat::Table,
+                &mut out,
+            )
+            .expect("run search");
+            String::from_utf8(out).expect("utf8 output")
+        };
+
+        let sdk = search("sdk");
+        assert!(sdk.contains("ApiClient.alphaSdk"));
+        assert!(!sdk.contains("demo::alpha_backend"));
+
+        let backend = search("backend");
+        assert!(backend.contains("demo::alpha_backend"));
+        assert!(!backend.contains("ApiClient.alphaSdk"));
+    }
+
     #[test]
     fn write_search_results_json_outputs_stable_shape() {
         let mut out = Vec::new();
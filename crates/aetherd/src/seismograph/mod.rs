@@ -4,7 +4,7 @@ mod epicenter;
 mod velocity;
 
 use aether_config::{AetherConfig, SeismographConfig};
-use aether_graph_algo::{GraphAlgorithmEdge, page_rank_sync};
+use aether_graph_algo::GraphAlgorithmEdge;
 use aether_store::{
     CascadeRecord, CommunityStabilityRecord as StoreCommunityStabilityRecord, DriftStore,
     SeismographMetricRecord, SqliteStore,
@@ -88,9 +88,15 @@ pub fn run_seismograph_analysis(
         })
         .collect();
 
-    let pagerank_map: HashMap<String, f64> = page_rank_sync(&pagerank_edges, 0.85, 25)
-        .into_iter()
-        .collect();
+    // Shares the continuous monitor's persisted graph state, so right after
+    // a continuous run this is a lookup rather than a recompute.
+    let graph_metrics = crate::continuous::refresh_graph_metrics(
+        workspace,
+        &crate::continuous::resolve_continuous_config(config),
+        &pagerank_edges,
+    )
+    .context("failed to refresh graph metrics")?;
+    let pagerank_map = &graph_metrics.pagerank;
 
     // 4. Compute semantic velocity
     let prev_velocity = store
@@ -101,7 +107,7 @@ pub fn run_seismograph_analysis(
 
     let vel = velocity::compute_semantic_velocity(
         &batch_records,
-        &pagerank_map,
+        pagerank_map,
         seismo_config.noise_floor,
         seismo_config.ema_alpha,
         prev_velocity,
@@ -119,15 +125,25 @@ pub fn run_seismograph_analysis(
         .list_latest_community_snapshot()
         .context("failed to load community snapshot")?;
 
-    let community_map: HashMap<String, String> = community_snapshot
-        .iter()
-        .map(|cs| (cs.symbol_id.clone(), cs.community_id.to_string()))
-        .collect();
+    // Drift analysis writes the community snapshot; until it has run, fall
+    // back to the communities kept alongside PageRank.
+    let community_map: HashMap<String, String> = if community_snapshot.is_empty() {
+        graph_metrics
+            .communities
+            .iter()
+            .map(|(symbol_id, community)| (symbol_id.clone(), community.to_string()))
+            .collect()
+    } else {
+        community_snapshot
+            .iter()
+            .map(|cs| (cs.symbol_id.clone(), cs.community_id.to_string()))
+            .collect()
+    };
 
     let community_results = community::compute_community_stability(
         &window_records,
         &community_map,
-        &pagerank_map,
+        pagerank_map,
         seismo_config.noise_floor,
     );
 
@@ -177,7 +193,7 @@ pub fn run_seismograph_analysis(
         predict_aftershocks(
             &store,
             &batch_records,
-            &pagerank_map,
+            pagerank_map,
             &dep_map,
             &seismo_config,
         )?
@@ -308,9 +324,13 @@ pub fn train_aftershock_model(workspace: &Path, config: &AetherConfig) -> Result
             edge_kind: edge.edge_kind.clone(),
         })
         .collect();
-    let pagerank_map: HashMap<String, f64> = page_rank_sync(&pagerank_edges, 0.85, 25)
-        .into_iter()
-        .collect();
+    let pagerank_map = crate::continuous::refresh_graph_metrics(
+        workspace,
+        &crate::continuous::resolve_continuous_config(config),
+        &pagerank_edges,
+    )
+    .context("failed to refresh graph metrics")?
+    .pagerank;
 
     // Build max delta_sem per symbol in window
     let mut max_delta: HashMap<&str, f64> = HashMap::new();
@@ -184,6 +184,7 @@ async fn run_sir_smoke_test(provider: &dyn InferenceProvider) -> Result<SirAnnot
         kind: "function".to_owned(),
         is_public: true,
         line_count: SETUP_SMOKE_TEST_SNIPPET.lines().count(),
+        extension_fields: Vec::new(),
     };
     let sir = provider
         .generate_sir(SETUP_SMOKE_TEST_SNIPPET, &context)
@@ -264,6 +265,7 @@ mod tests {
             edge_cases: None,
             complexity: None,
             method_dependencies: None,
+            extensions: Default::default(),
         };
         validate_smoke_test_sir(&valid).expect("valid sir");
 
@@ -279,6 +281,7 @@ mod tests {
             edge_cases: None,
             complexity: None,
             method_dependencies: None,
+            extensions: Default::default(),
         };
         let err = validate_smoke_test_sir(&invalid).expect_err("expected invalid sir");
         assert!(
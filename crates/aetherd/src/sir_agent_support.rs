@@ -237,15 +237,8 @@ fn byte_offset_for_position(source: &str, position: Position) -> Option<usize> {
 }
 
 fn parse_language(raw: &str) -> Result<Language> {
-    match raw.trim() {
-        "rust" => Ok(Language::Rust),
-        "typescript" => Ok(Language::TypeScript),
-        "tsx" => Ok(Language::Tsx),
-        "javascript" => Ok(Language::JavaScript),
-        "jsx" => Ok(Language::Jsx),
-        "python" => Ok(Language::Python),
-        other => Err(anyhow!("unsupported symbol language '{other}'")),
-    }
+    let name = raw.trim();
+    Language::from_name(name).ok_or_else(|| anyhow!("unsupported symbol language '{name}'"))
 }
 
 fn parse_symbol_kind(raw: &str) -> Result<SymbolKind> {
@@ -259,6 +252,12 @@ fn parse_symbol_kind(raw: &str) -> Result<SymbolKind> {
         "trait" => Ok(SymbolKind::Trait),
         "interface" => Ok(SymbolKind::Interface),
         "type_alias" => Ok(SymbolKind::TypeAlias),
+        "constant" => Ok(SymbolKind::Constant),
+        "static" => Ok(SymbolKind::Static),
+        "macro" => Ok(SymbolKind::Macro),
+        "module" => Ok(SymbolKind::Module),
+        "field" => Ok(SymbolKind::Field),
+        "enum_variant" => Ok(SymbolKind::EnumVariant),
         other => Err(anyhow!("unsupported symbol kind '{other}'")),
     }
 }
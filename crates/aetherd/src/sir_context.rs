@@ -491,7 +491,7 @@ pub fn run_context_command(workspace: &Path, args: ContextArgs) -> Result<()> {
         fs::write(&path, rendered)
             .with_context(|| format!("failed to write output file {}", path.display()))?;
     } else {
-        let mut out = std::io::stdout();
+        let mut out = crate::daemon_rpc::command_stdout();
         out.write_all(rendered.as_bytes())
             .context("failed to write context output")?;
         if !rendered.ends_with('\n') {
@@ -573,7 +573,7 @@ pub fn run_sir_context_command(workspace: &Path, args: SirContextArgs) -> Result
         return Ok(());
     }
 
-    let mut out = std::io::stdout();
+    let mut out = crate::daemon_rpc::command_stdout();
     out.write_all(rendered.as_bytes())
         .context("failed to write sir-context output")?;
     if !rendered.ends_with('\n') {
@@ -623,10 +623,13 @@ fn context_targets(workspace: &Path, args: &ContextArgs) -> Result<Vec<ContextTa
         return Err(anyhow!("missing file target, --symbol, or --overview"));
     }
 
+    let roots = aether_config::load_workspace_config(workspace)
+        .map(|config| aether_config::declared_workspace_roots(&config))
+        .unwrap_or_default();
     let mut targets = Vec::new();
     let mut seen = HashSet::new();
     for value in &args.targets {
-        let normalized = normalize_workspace_relative_path(workspace, value)?;
+        let normalized = normalize_context_target_path(workspace, &roots, value)?;
         if seen.insert(normalized.clone()) {
             targets.push(ContextTarget::File { path: normalized });
         }
@@ -2140,6 +2143,22 @@ fn task_relevance_score(task: Option<&str>, text: &str) -> usize {
         .sum()
 }
 
+/// Normalizes a context file target, accepting `ROOT:path` for files in a
+/// declared workspace root.
+fn normalize_context_target_path(
+    workspace: &Path,
+    roots: &[aether_config::WorkspaceRootConfig],
+    value: &str,
+) -> Result<String> {
+    if let Some((name, rest)) = value.trim().split_once(':')
+        && let Some(root) = roots.iter().find(|root| root.name == name)
+    {
+        let relative = normalize_workspace_relative_path(workspace, rest)?;
+        return Ok(format!("{}/{relative}", root.path_prefix()));
+    }
+    normalize_workspace_relative_path(workspace, value)
+}
+
 fn normalize_workspace_relative_path(workspace: &Path, value: &str) -> Result<String> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
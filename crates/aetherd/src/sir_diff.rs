@@ -11,7 +11,7 @@ use crate::sir_agent_support::{format_relative_age, load_fresh_symbol_source, re
 
 pub fn run_sir_diff_command(workspace: &Path, args: SirDiffArgs) -> Result<()> {
     let rendered = execute_sir_diff_command(workspace, args)?;
-    let mut out = std::io::stdout();
+    let mut out = crate::daemon_rpc::command_stdout();
     out.write_all(rendered.as_bytes())
         .context("failed to write sir-diff output")?;
     if !rendered.ends_with('\n') {
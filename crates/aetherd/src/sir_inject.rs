@@ -246,6 +246,7 @@ fn empty_sir(confidence: f32) -> SirAnnotation {
         edge_cases: None,
         complexity: None,
         method_dependencies: None,
+        extensions: Default::default(),
     }
 }
 
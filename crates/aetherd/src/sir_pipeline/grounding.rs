@@ -0,0 +1,283 @@
+use std::collections::BTreeSet;
+
+use aether_config::SirQualityConfig;
+use aether_core::{EdgeKind, Symbol, file_source_id};
+use aether_sir::SirAnnotation;
+use aether_store::{SqliteStore, SymbolRelationStore};
+use anyhow::{Context, Result};
+
+/// Workspace policy for cross-checking SIR claims against the static graph.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct GroundingPolicy {
+    pub(crate) enabled: bool,
+    pub(crate) min_score: f64,
+    pub(crate) max_regenerations: u32,
+}
+
+impl GroundingPolicy {
+    pub(crate) fn from_config(config: &SirQualityConfig) -> Self {
+        Self {
+            enabled: config.grounding_check,
+            min_score: config.grounding_min_score,
+            max_regenerations: config.grounding_max_regenerations,
+        }
+    }
+}
+
+impl Default for GroundingPolicy {
+    fn default() -> Self {
+        Self::from_config(&SirQualityConfig::default())
+    }
+}
+
+/// Static facts the parser extracted for one symbol.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct GraphFacts {
+    /// Targets of the symbol's call and type-reference edges plus its file's imports.
+    pub(crate) edge_targets: Vec<String>,
+    /// Call targets that resolve to indexed workspace symbols.
+    pub(crate) workspace_callees: Vec<String>,
+}
+
+impl GraphFacts {
+    pub(crate) fn load(store: &SqliteStore, symbol: &Symbol) -> Result<Self> {
+        let mut edge_targets = store
+            .list_symbol_edges_for_source_and_kinds(
+                symbol.id.as_str(),
+                &[EdgeKind::Calls, EdgeKind::TypeRef],
+            )
+            .with_context(|| format!("failed to load graph edges for {}", symbol.id))?
+            .into_iter()
+            .map(|edge| edge.target_qualified_name)
+            .collect::<Vec<_>>();
+        edge_targets.extend(
+            store
+                .get_dependencies(file_source_id(symbol.file_path.as_str()).as_str())
+                .with_context(|| format!("failed to load imports for {}", symbol.file_path))?
+                .into_iter()
+                .map(|edge| edge.target_qualified_name),
+        );
+
+        let workspace_callees = store
+            .get_symbol_neighbors_by_type(symbol.id.as_str(), EdgeKind::Calls.as_str())
+            .with_context(|| format!("failed to load resolved callees for {}", symbol.id))?
+            .into_iter()
+            .map(|neighbor| neighbor.neighbor_name)
+            .filter(|name| name != &symbol.qualified_name)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        Ok(Self {
+            edge_targets,
+            workspace_callees,
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.edge_targets.is_empty() && self.workspace_callees.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct GroundingReport {
+    pub(crate) score: f64,
+    pub(crate) claimed_count: usize,
+    pub(crate) grounded_count: usize,
+    pub(crate) ungrounded_claims: Vec<String>,
+    pub(crate) missing_callees: Vec<String>,
+}
+
+/// Scores how well the SIR's dependency claims agree with the static graph.
+///
+/// The score averages claim precision (claims backed by an edge) and callee
+/// coverage (resolved workspace callees the SIR mentions). Returns `None` when
+/// the parser produced no facts for the symbol, so nothing can be checked.
+pub(crate) fn evaluate_grounding(
+    sir: &SirAnnotation,
+    facts: &GraphFacts,
+) -> Option<GroundingReport> {
+    if facts.is_empty() {
+        return None;
+    }
+
+    let known_segments = facts
+        .edge_targets
+        .iter()
+        .chain(facts.workspace_callees.iter())
+        .flat_map(|target| path_segments(target))
+        .collect::<BTreeSet<_>>();
+
+    let mut claimed_count = 0usize;
+    let mut ungrounded_claims = Vec::new();
+    for claim in &sir.dependencies {
+        let Some(path) = claim_path(claim) else {
+            continue;
+        };
+        claimed_count += 1;
+        let grounded = path
+            .last()
+            .is_some_and(|leaf| known_segments.contains(leaf))
+            || (path.len() == 1 && known_segments.contains(&path[0]));
+        if !grounded {
+            ungrounded_claims.push(claim.trim().to_owned());
+        }
+    }
+    let grounded_count = claimed_count - ungrounded_claims.len();
+
+    let mentioned = sir
+        .dependencies
+        .iter()
+        .chain(sir.side_effects.iter())
+        .flat_map(|text| path_segments(text))
+        .collect::<BTreeSet<_>>();
+    let missing_callees = facts
+        .workspace_callees
+        .iter()
+        .filter(|callee| {
+            path_segments(callee)
+                .last()
+                .is_some_and(|leaf| !mentioned.contains(leaf))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let precision = ratio(grounded_count, claimed_count);
+    let coverage = ratio(
+        facts.workspace_callees.len() - missing_callees.len(),
+        facts.workspace_callees.len(),
+    );
+
+    Some(GroundingReport {
+        score: (precision + coverage) / 2.0,
+        claimed_count,
+        grounded_count,
+        ungrounded_claims,
+        missing_callees,
+    })
+}
+
+/// Discrepancy summary appended to the regeneration prompt.
+pub(crate) fn grounding_feedback(report: &GroundingReport) -> String {
+    let mut feedback = format!(
+        "the SIR does not match the static call graph (grounding score {:.2})",
+        report.score
+    );
+    if !report.ungrounded_claims.is_empty() {
+        feedback.push_str(&format!(
+            "; dependencies with no supporting call, type reference or import: {}",
+            report.ungrounded_claims.join(", ")
+        ));
+    }
+    if !report.missing_callees.is_empty() {
+        feedback.push_str(&format!(
+            "; called workspace symbols missing from dependencies: {}",
+            report.missing_callees.join(", ")
+        ));
+    }
+    feedback.push_str("; list only dependencies the code actually uses");
+    feedback
+}
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        1.0
+    } else {
+        part as f64 / total as f64
+    }
+}
+
+/// First identifier path in a free-form claim, e.g. `SqliteStore::open` in
+/// "SqliteStore::open for persistence".
+fn claim_path(claim: &str) -> Option<Vec<String>> {
+    claim
+        .split_whitespace()
+        .map(path_segments)
+        .find(|segments| !segments.is_empty())
+}
+
+fn path_segments(text: &str) -> Vec<String> {
+    text.split(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+        .map(|segment| segment.trim_start_matches("r#"))
+        .filter(|segment| segment.len() > 1)
+        .filter(|segment| !segment.chars().all(|ch| ch.is_ascii_digit()))
+        .map(str::to_ascii_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sir_with(dependencies: &[&str], side_effects: &[&str]) -> SirAnnotation {
+        SirAnnotation {
+            intent: "Loads a record".to_owned(),
+            behavior: None,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            side_effects: side_effects
+                .iter()
+                .map(|value| (*value).to_owned())
+                .collect(),
+            dependencies: dependencies
+                .iter()
+                .map(|value| (*value).to_owned())
+                .collect(),
+            error_modes: Vec::new(),
+            confidence: 0.8,
+            edge_cases: None,
+            complexity: None,
+            method_dependencies: None,
+            extensions: Default::default(),
+        }
+    }
+
+    fn facts() -> GraphFacts {
+        GraphFacts {
+            edge_targets: vec![
+                "SqliteStore::open".to_owned(),
+                "serde_json::from_str".to_owned(),
+                "parse_record".to_owned(),
+            ],
+            workspace_callees: vec!["demo::parse_record".to_owned()],
+        }
+    }
+
+    #[test]
+    fn grounded_claims_and_covered_callees_score_full() {
+        let sir = sir_with(
+            &["SqliteStore::open", "serde_json", "parse_record helper"],
+            &[],
+        );
+        let report = evaluate_grounding(&sir, &facts()).expect("report");
+
+        assert_eq!(report.claimed_count, 3);
+        assert_eq!(report.grounded_count, 3);
+        assert!(report.ungrounded_claims.is_empty());
+        assert!(report.missing_callees.is_empty());
+        assert_eq!(report.score, 1.0);
+    }
+
+    #[test]
+    fn hallucinated_claims_and_missing_callees_lower_score() {
+        let sir = sir_with(&["reqwest::Client", "SqliteStore"], &["writes to disk"]);
+        let report = evaluate_grounding(&sir, &facts()).expect("report");
+
+        assert_eq!(report.ungrounded_claims, vec!["reqwest::Client".to_owned()]);
+        assert_eq!(
+            report.missing_callees,
+            vec!["demo::parse_record".to_owned()]
+        );
+        assert_eq!(report.score, 0.25);
+
+        let feedback = grounding_feedback(&report);
+        assert!(feedback.contains("reqwest::Client"));
+        assert!(feedback.contains("demo::parse_record"));
+    }
+
+    #[test]
+    fn symbols_without_graph_facts_are_not_checked() {
+        let sir = sir_with(&["anything"], &[]);
+        assert!(evaluate_grounding(&sir, &GraphFacts::default()).is_none());
+    }
+}
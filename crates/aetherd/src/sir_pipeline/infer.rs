@@ -93,6 +93,7 @@ pub(crate) fn build_job(
         kind: symbol.kind.as_str().to_owned(),
         is_public: infer_symbol_text_is_public(&symbol_text),
         line_count: symbol_text.lines().count(),
+        extension_fields: Vec::new(),
     };
 
     Ok(SirJob {
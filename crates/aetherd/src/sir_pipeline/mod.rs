@@ -8,12 +8,12 @@ use std::time::UNIX_EPOCH;
 use aether_analysis::TestIntentAnalyzer;
 use aether_config::{
     ContractsConfig, InferenceProviderKind, SIR_QUALITY_FLOOR_CONFIDENCE, SIR_QUALITY_FLOOR_WINDOW,
-    ensure_workspace_config, load_workspace_config,
+    declared_sir_extensions, ensure_workspace_config, load_workspace_config,
 };
 use aether_core::{EdgeKind, GitContext, Language, Symbol, SymbolChangeEvent, content_hash};
 use aether_infer::{
     EmbeddingProvider, EmbeddingProviderOverrides, EmbeddingPurpose, InferenceProvider,
-    ProviderOverrides, Qwen3LocalProvider, load_embedding_provider_from_config,
+    ProviderOverrides, Qwen3LocalProvider, build_retry_prompt, load_embedding_provider_from_config,
     load_provider_from_env_or_mock,
     sir_prompt::{self, SirEnrichmentContext},
 };
@@ -21,22 +21,24 @@ use aether_infer::{
 use aether_infer::{InferError, SirContext};
 use aether_parse::SymbolExtractor;
 use aether_sir::{
-    FileSir, SirAnnotation, canonicalize_file_sir_json, canonicalize_sir_json, file_sir_hash,
-    sir_hash, synthetic_file_sir_id, validate_sir,
+    FileSir, SirAnnotation, SirExtensionField, canonicalize_file_sir_json, canonicalize_sir_json,
+    file_sir_hash, retain_declared_extensions, sir_hash, synthetic_file_sir_id, validate_sir,
+    validate_sir_extensions,
 };
 #[cfg(test)]
 use aether_store::SymbolRecord;
 use aether_store::{
-    BatchCompleteResult, IntentOperation, SirHistoryStore, SirMetaRecord, SirStateStore,
-    SqliteStore, SymbolCatalogStore, SymbolEmbeddingRecord, SymbolRelationStore, TestIntentStore,
-    VectorEmbeddingMetaRecord, VectorStore, WriteIntent, WriteIntentStatus, open_graph_store,
-    open_surreal_graph_store_sync, open_vector_store,
+    BatchCompleteResult, IntentOperation, SirGroundingRecord, SirHistoryStore, SirMetaRecord,
+    SirStateStore, SqliteStore, SymbolCatalogStore, SymbolEmbeddingRecord, SymbolRelationStore,
+    TestIntentStore, VectorEmbeddingMetaRecord, VectorStore, WriteIntent, WriteIntentStatus,
+    open_graph_store, open_surreal_graph_store_sync, open_vector_store,
 };
 use anyhow::{Context, Result, anyhow};
 use tokio::runtime::Runtime;
 use tokio::sync::Semaphore;
 use tokio::task::JoinSet;
 
+use self::grounding::{GraphFacts, GroundingPolicy, evaluate_grounding, grounding_feedback};
 pub(crate) use self::infer::build_job;
 use self::infer::{GeneratedSir, SirGenerationOutcome, SirJob, generate_sir_jobs};
 pub(crate) use self::persist::UpsertSirIntentPayload;
@@ -47,6 +49,7 @@ use self::rollup::{
 };
 use crate::quality::SirQualityMonitor;
 
+mod grounding;
 mod infer;
 mod persist;
 mod rollup;
@@ -129,6 +132,8 @@ pub struct SirPipeline {
     skip_surreal_sync: bool,
     skip_local_edges: bool,
     contracts_config: Option<ContractsConfig>,
+    sir_extensions: Vec<SirExtensionField>,
+    grounding: GroundingPolicy,
 }
 
 struct PreparedCandidateJobs {
@@ -295,9 +300,16 @@ impl SirPipeline {
         let vector_store = runtime
             .block_on(open_vector_store(&workspace_root))
             .context("failed to initialize vector store")?;
-        let contracts_config = load_workspace_config(&workspace_root)
-            .ok()
-            .and_then(|c| c.contracts);
+        let workspace_config = load_workspace_config(&workspace_root).ok();
+        let sir_extensions = workspace_config
+            .as_ref()
+            .map(declared_sir_extensions)
+            .unwrap_or_default();
+        let grounding = workspace_config
+            .as_ref()
+            .map(|config| GroundingPolicy::from_config(&config.sir_quality))
+            .unwrap_or_default();
+        let contracts_config = workspace_config.and_then(|c| c.contracts);
 
         Ok(Self {
             workspace_root,
@@ -320,6 +332,8 @@ impl SirPipeline {
             skip_surreal_sync: false,
             skip_local_edges: false,
             contracts_config,
+            sir_extensions,
+            grounding,
         })
     }
 
@@ -550,30 +564,25 @@ impl SirPipeline {
             let language = item.symbol.language;
             touched_files.entry(file_path.clone()).or_insert(language);
 
-            match build_job(
-                &self.workspace_root,
-                item.symbol,
-                Some(item.priority_score),
-                None,
-            ) {
-                Ok(mut job) => {
-                    let prompt = if item.use_cot {
-                        sir_prompt::build_enriched_sir_prompt_with_cot(
-                            &job.symbol_text,
-                            &job.context,
-                            &item.enrichment,
-                        )
-                    } else {
-                        sir_prompt::build_enriched_sir_prompt(
-                            &job.symbol_text,
-                            &job.context,
-                            &item.enrichment,
-                        )
-                    };
-                    job.custom_prompt = Some(prompt);
-                    job.deep_mode = item.use_cot;
-                    jobs.push(job);
-                }
+            let use_cot = item.use_cot;
+            let enrichment = &item.enrichment;
+            match self.build_job_with_prompt(store, item.symbol, Some(item.priority_score), |job| {
+                let prompt = if use_cot {
+                    sir_prompt::build_enriched_sir_prompt_with_cot(
+                        &job.symbol_text,
+                        &job.context,
+                        enrichment,
+                    )
+                } else {
+                    sir_prompt::build_enriched_sir_prompt(
+                        &job.symbol_text,
+                        &job.context,
+                        enrichment,
+                    )
+                };
+                Some((prompt, use_cot))
+            }) {
+                Ok(job) => jobs.push(job),
                 Err(err) => {
                     stats.failure_count += 1;
                     tracing::warn!(
@@ -744,7 +753,7 @@ impl SirPipeline {
                     .copied()
                     .unwrap_or(0.0),
             );
-            match build_job(&self.workspace_root, symbol, priority_score, None) {
+            match self.build_job(store, symbol, priority_score) {
                 Ok(job) => jobs.push(job),
                 Err(err) => {
                     stats.failure_count += 1;
@@ -947,16 +956,12 @@ impl SirPipeline {
                 continue;
             }
 
-            match build_job(&self.workspace_root, symbol, priority_score, None) {
-                Ok(mut job) => {
-                    if let Some(prompt_overrides) = prompt_overrides
-                        && let Some(override_spec) = prompt_overrides.get(job.symbol.id.as_str())
-                    {
-                        job.custom_prompt = Some(override_spec.prompt.clone());
-                        job.deep_mode = override_spec.deep_mode;
-                    }
-                    jobs.push(job);
-                }
+            let override_spec = prompt_overrides
+                .and_then(|prompt_overrides| prompt_overrides.get(symbol.id.as_str()));
+            match self.build_job_with_prompt(store, symbol, priority_score, |_| {
+                override_spec.map(|spec| (spec.prompt.clone(), spec.deep_mode))
+            }) {
+                Ok(job) => jobs.push(job),
                 Err(err) => {
                     tracing::warn!(
                         file_path = %file_path,
@@ -988,6 +993,131 @@ impl SirPipeline {
         }
     }
 
+    /// Builds a SIR job whose context carries the workspace extension schema.
+    fn build_job(
+        &self,
+        store: &SqliteStore,
+        symbol: Symbol,
+        priority_score: Option<f64>,
+    ) -> Result<SirJob> {
+        self.build_job_with_prompt(store, symbol, priority_score, |_| None)
+    }
+
+    /// Like `build_job`, with `prompt` choosing a custom prompt and deep mode
+    /// for the built job.
+    ///
+    /// Symbols queued for regeneration by the grounding check get a retry
+    /// prompt that wraps whichever prompt was chosen and feeds the recorded
+    /// graph discrepancy back to the model.
+    fn build_job_with_prompt(
+        &self,
+        store: &SqliteStore,
+        symbol: Symbol,
+        priority_score: Option<f64>,
+        prompt: impl FnOnce(&SirJob) -> Option<(String, bool)>,
+    ) -> Result<SirJob> {
+        let mut job = build_job(&self.workspace_root, symbol, priority_score, None)?;
+        job.context.extension_fields = self.sir_extensions.clone();
+        if let Some((custom_prompt, deep_mode)) = prompt(&job) {
+            job.custom_prompt = Some(custom_prompt);
+            job.deep_mode = deep_mode;
+        }
+
+        if self.grounding.enabled
+            && let Some(feedback) = store
+                .get_sir_grounding(job.symbol.id.as_str())
+                .with_context(|| format!("failed to read SIR grounding for {}", job.symbol.id))?
+                .and_then(|record| record.retry_feedback)
+        {
+            let previous_output = store
+                .read_sir_blob(job.symbol.id.as_str())
+                .with_context(|| format!("failed to read SIR blob for {}", job.symbol.id))?
+                .unwrap_or_default();
+            let original_prompt = job.custom_prompt.take().unwrap_or_else(|| {
+                sir_prompt::build_sir_prompt_for_kind(job.symbol_text.as_str(), &job.context)
+            });
+            job.custom_prompt = Some(build_retry_prompt(
+                original_prompt.as_str(),
+                feedback.as_str(),
+                previous_output.as_str(),
+            ));
+        }
+
+        Ok(job)
+    }
+
+    /// Records how well a persisted SIR agrees with the static graph and queues
+    /// poorly grounded SIRs for regeneration.
+    fn check_grounding(
+        &self,
+        store: &SqliteStore,
+        symbol: &Symbol,
+        sir: &SirAnnotation,
+    ) -> Result<()> {
+        if !self.grounding.enabled {
+            return Ok(());
+        }
+
+        let facts = GraphFacts::load(store, symbol)?;
+        let Some(report) = evaluate_grounding(sir, &facts) else {
+            return Ok(());
+        };
+
+        // Attempts only accumulate while a regeneration cycle is in flight.
+        let mut regeneration_attempts = store
+            .get_sir_grounding(symbol.id.as_str())
+            .with_context(|| format!("failed to read SIR grounding for {}", symbol.id))?
+            .filter(|previous| previous.retry_feedback.is_some())
+            .map_or(0, |previous| previous.regeneration_attempts);
+        let retry_feedback = (report.score < self.grounding.min_score
+            && regeneration_attempts < i64::from(self.grounding.max_regenerations))
+        .then(|| grounding_feedback(&report));
+        if retry_feedback.is_some() {
+            regeneration_attempts += 1;
+        }
+
+        store
+            .upsert_sir_grounding(&SirGroundingRecord {
+                sir_id: symbol.id.clone(),
+                grounding_score: report.score,
+                claimed_count: report.claimed_count as i64,
+                grounded_count: report.grounded_count as i64,
+                ungrounded_claims: report.ungrounded_claims.clone(),
+                missing_callees: report.missing_callees.clone(),
+                regeneration_attempts,
+                retry_feedback: retry_feedback.clone(),
+                checked_at: unix_timestamp_secs(),
+            })
+            .with_context(|| format!("failed to persist SIR grounding for {}", symbol.id))?;
+
+        if retry_feedback.is_none() {
+            return Ok(());
+        }
+
+        if let Some(meta) = store
+            .get_sir_meta(symbol.id.as_str())
+            .with_context(|| format!("failed to read SIR metadata for {}", symbol.id))?
+        {
+            store
+                .upsert_sir_meta(SirMetaRecord {
+                    sir_status: SIR_STATUS_STALE.to_owned(),
+                    ..meta
+                })
+                .with_context(|| format!("failed to mark {} for regeneration", symbol.id))?;
+        }
+        store
+            .enqueue_sir_request(symbol.id.as_str())
+            .with_context(|| format!("failed to queue regeneration for {}", symbol.id))?;
+        tracing::info!(
+            symbol_id = %symbol.id,
+            grounding_score = report.score,
+            ungrounded_claims = report.ungrounded_claims.len(),
+            missing_callees = report.missing_callees.len(),
+            "queued ungrounded SIR for regeneration"
+        );
+        Ok(())
+    }
+
     fn prepare_sir_for_persistence(
         &self,
         store: &SqliteStore,
@@ -995,6 +1125,13 @@ impl SirPipeline {
         sir: &SirAnnotation,
     ) -> Result<(SirAnnotation, String, String)> {
         let mut sir = sir.clone();
+        retain_declared_extensions(&mut sir, &self.sir_extensions);
+        validate_sir_extensions(&sir, &self.sir_extensions).with_context(|| {
+            format!(
+                "SIR for {} does not match the workspace extension schema",
+                symbol.qualified_name
+            )
+        })?;
         self.inject_method_dependencies(store, symbol, &mut sir)?;
         let canonical_json = canonicalize_sir_json(&sir);
         let sir_hash_value = sir_hash(&sir);
@@ -1317,6 +1454,14 @@ impl SirPipeline {
             return Ok(None);
         }
 
+        if let Err(err) = self.check_grounding(store, &generated.symbol, &payload.sir) {
+            tracing::warn!(
+                symbol_id = %generated.symbol.id,
+                error = %err,
+                "failed to check SIR grounding"
+            );
+        }
+
         let embedding_needed =
             match self.check_embedding_needed(&generated.symbol.id, &sir_hash_value, None) {
                 Ok(needed) => needed,
@@ -1848,7 +1993,8 @@ impl SirPipeline {
             let Some(spec) = deep_specs.get(symbol.id.as_str()) else {
                 continue;
             };
-            let job = build_job(&self.workspace_root, symbol.clone(), priority_score, None)
+            let job = self
+                .build_job(store, symbol.clone(), priority_score)
                 .with_context(|| {
                     format!("failed to build deep SIR job for {}", symbol.qualified_name)
                 })?;
@@ -2288,7 +2434,7 @@ impl SirPipeline {
         payload: &UpsertSirIntentPayload,
         write_intent_id: Option<&str>,
     ) -> Result<(String, String)> {
-        let (_, canonical_json, sir_hash_value) =
+        let (sir, canonical_json, sir_hash_value) =
             self.prepare_sir_for_persistence(store, &payload.symbol, &payload.sir)?;
         let attempted_at = unix_timestamp_secs();
         // Higher-quality passes still need to promote metadata even when the
@@ -2314,6 +2460,14 @@ impl SirPipeline {
             write_intent_id,
         )?;
 
+        if let Err(err) = self.check_grounding(store, &payload.symbol, &sir) {
+            tracing::warn!(
+                symbol_id = %payload.symbol.id,
+                error = %err,
+                "failed to check SIR grounding"
+            );
+        }
+
         Ok((canonical_json, sir_hash_value))
     }
 
@@ -2396,12 +2550,40 @@ impl SirPipeline {
                 .with_context(|| {
                     format!("failed to upsert test intents for file {}", event.file_path)
                 })?;
+            let contract_scope = extracted
+                .symbols
+                .iter()
+                .chain(&event.removed)
+                .map(|symbol| symbol.id.clone())
+                .collect::<Vec<_>>();
+            crate::contracts::sync_source_contracts(
+                store,
+                &event.file_path,
+                &contract_scope,
+                &extracted.source_contracts,
+            )?;
+            crate::contracts::refresh_policy_contracts(
+                store,
+                &self.workspace_root,
+                &contract_scope,
+            );
         } else {
             store
                 .replace_test_intents_for_file(event.file_path.as_str(), &[])
                 .with_context(|| {
                     format!("failed to clear test intents for file {}", event.file_path)
                 })?;
+            let contract_scope = event
+                .removed
+                .iter()
+                .map(|symbol| symbol.id.clone())
+                .collect::<Vec<_>>();
+            crate::contracts::sync_source_contracts(store, &event.file_path, &contract_scope, &[])?;
+            crate::contracts::refresh_policy_contracts(
+                store,
+                &self.workspace_root,
+                &contract_scope,
+            );
         }
 
         if let Ok(graph) = open_surreal_graph_store_sync(&self.workspace_root) {
@@ -189,6 +189,7 @@ fn build_file_rollup_context(
         kind: "file".to_owned(),
         is_public: true,
         line_count: summary_input.lines().count(),
+        extension_fields: Vec::new(),
     }
 }
 
@@ -248,6 +248,7 @@ vector_backend = "sqlite"
             edge_cases: None,
             complexity: None,
             method_dependencies: None,
+            extensions: Default::default(),
         }
     }
 
@@ -483,6 +484,7 @@ vector_backend = "sqlite"
                     "stale".to_owned(),
                     vec!["stale".to_owned()],
                 )])),
+                extensions: Default::default(),
             },
             provider_name: "test_provider".to_owned(),
             model_name: "test_model".to_owned(),
@@ -540,6 +542,113 @@ vector_backend = "sqlite"
         assert_eq!(intent.status, WriteIntentStatus::VectorDone);
     }
 
+    #[test]
+    fn ungrounded_sir_is_queued_for_regeneration_with_retry_feedback() {
+        let temp = tempdir().expect("tempdir");
+        let workspace = temp.path();
+        write_embeddings_only_config(workspace);
+        let source = "pub fn run() {\n    helper();\n}\n";
+        fs::create_dir_all(workspace.join("src")).expect("create src");
+        fs::write(workspace.join("src/lib.rs"), source).expect("write source");
+
+        let store = SqliteStore::open(workspace).expect("open store");
+        for symbol in [
+            demo_symbol("sym-run", "demo::run"),
+            demo_symbol("sym-helper", "demo::helper"),
+        ] {
+            store.upsert_symbol(symbol).expect("upsert symbol");
+        }
+        store
+            .upsert_edges(&[SymbolEdge {
+                source_id: "sym-run".to_owned(),
+                target_qualified_name: "demo::helper".to_owned(),
+                edge_kind: EdgeKind::Calls,
+                file_path: "src/lib.rs".to_owned(),
+            }])
+            .expect("upsert edges");
+        store
+            .populate_symbol_neighbors("src/lib.rs")
+            .expect("populate neighbors");
+
+        let pipeline = build_write_pipeline(workspace, Arc::new(PanicInferenceProvider));
+        let symbol = demo_type_symbol(
+            "sym-run",
+            "run",
+            "demo::run",
+            "src/lib.rs",
+            SymbolKind::Function,
+            source,
+        );
+        let generated = infer::GeneratedSir {
+            symbol: symbol.clone(),
+            sir: SirAnnotation {
+                dependencies: vec!["reqwest::Client".to_owned()],
+                ..demo_sir()
+            },
+            provider_name: "test_provider".to_owned(),
+            model_name: "test_model".to_owned(),
+            reasoning_trace: None,
+        };
+
+        let mut out = Vec::new();
+        pipeline
+            .commit_successful_generation(
+                &store,
+                generated,
+                SIR_GENERATION_PASS_SCAN,
+                None,
+                false,
+                &mut out,
+            )
+            .expect("commit successful generation")
+            .expect("intent id");
+
+        let grounding = store
+            .get_sir_grounding("sym-run")
+            .expect("read grounding")
+            .expect("grounding should be recorded");
+        assert_eq!(grounding.grounding_score, 0.0);
+        assert_eq!(
+            grounding.ungrounded_claims,
+            vec!["reqwest::Client".to_owned()]
+        );
+        assert_eq!(grounding.missing_callees, vec!["demo::helper".to_owned()]);
+        assert_eq!(grounding.regeneration_attempts, 1);
+        assert_eq!(
+            store
+                .get_sir_meta("sym-run")
+                .expect("read meta")
+                .expect("meta exists")
+                .sir_status,
+            SIR_STATUS_STALE
+        );
+        assert_eq!(
+            store
+                .list_sir_request_symbol_ids(10)
+                .expect("list requests"),
+            vec!["sym-run".to_owned()]
+        );
+
+        let job = pipeline
+            .build_job(&store, symbol.clone(), None)
+            .expect("build retry job");
+        let prompt = job.custom_prompt.expect("retry prompt should be set");
+        assert!(prompt.contains("Your previous response was invalid"));
+        assert!(prompt.contains("reqwest::Client"));
+        assert!(prompt.contains("demo::helper"));
+
+        let job = pipeline
+            .build_job_with_prompt(&store, symbol, None, |_| {
+                Some(("ENRICHED PROMPT".to_owned(), true))
+            })
+            .expect("build enriched retry job");
+        let prompt = job.custom_prompt.expect("retry prompt should be set");
+        assert!(prompt.starts_with("ENRICHED PROMPT"));
+        assert!(prompt.contains("Your previous response was invalid"));
+        assert!(prompt.contains("demo::helper"));
+        assert!(job.deep_mode);
+    }
+
     #[test]
     fn new_embeddings_only_errors_when_provider_is_not_configured() {
         let temp = tempdir().expect("tempdir");
@@ -1006,6 +1115,7 @@ impl Store {
                 edge_cases: None,
                 complexity: None,
                 method_dependencies: None,
+                extensions: Default::default(),
             },
         });
         let pipeline = build_write_pipeline(workspace, provider).with_skip_surreal_sync(true);
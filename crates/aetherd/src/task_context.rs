@@ -5,6 +5,7 @@ use std::io::Write;
 use std::path::Path;
 use std::sync::OnceLock;
 
+use aether_analysis::{EdgeWeights, execution_edge_weights};
 use aether_core::normalize_path;
 use aether_infer::{EmbeddingProviderOverrides, load_embedding_provider_from_config};
 use aether_store::{
@@ -16,6 +17,7 @@ use anyhow::{Context, Result, anyhow};
 use gix::bstr::ByteSlice;
 use petgraph::Direction;
 use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
 
 use crate::cli::{TaskHistoryArgs, TaskRelevanceArgs};
 use crate::observer::is_ignored_path;
@@ -247,6 +249,26 @@ pub fn personalized_pagerank(
     seed_symbol_ids: &[String],
     alpha: f64,
     iterations: usize,
+) -> HashMap<String, f64> {
+    personalized_pagerank_weighted(
+        graph,
+        node_map,
+        seed_symbol_ids,
+        alpha,
+        iterations,
+        &EdgeWeights::new(),
+    )
+}
+
+/// Personalized PageRank where rank leaves a node along its outgoing edges in
+/// proportion to their weight; edges without a weight count as 1.0.
+pub fn personalized_pagerank_weighted(
+    graph: &DiGraph<String, ()>,
+    node_map: &HashMap<String, NodeIndex>,
+    seed_symbol_ids: &[String],
+    alpha: f64,
+    iterations: usize,
+    weights: &EdgeWeights,
 ) -> HashMap<String, f64> {
     if graph.node_count() == 0 || seed_symbol_ids.is_empty() {
         return HashMap::new();
@@ -267,18 +289,17 @@ pub fn personalized_pagerank(
         seed_vector.insert(node, seed_weight);
     }
 
-    let out_degree = nodes
-        .iter()
-        .map(|node| {
-            (
-                *node,
-                graph
-                    .neighbors_directed(*node, Direction::Outgoing)
-                    .count()
-                    .max(1),
-            )
-        })
-        .collect::<HashMap<_, _>>();
+    let mut edge_weights = vec![1.0f64; graph.edge_count()];
+    let mut out_weight = HashMap::<NodeIndex, f64>::new();
+    for edge in graph.edge_references() {
+        let weight = weights
+            .get(&(graph[edge.source()].clone(), graph[edge.target()].clone()))
+            .copied()
+            .filter(|weight| weight.is_finite() && *weight > 0.0)
+            .unwrap_or(1.0);
+        edge_weights[edge.id().index()] = weight;
+        *out_weight.entry(edge.source()).or_insert(0.0) += weight;
+    }
 
     let mut rank = nodes
         .iter()
@@ -301,10 +322,11 @@ pub fn personalized_pagerank(
         let mut next = HashMap::<NodeIndex, f64>::new();
         for node in &nodes {
             let incoming_sum = graph
-                .neighbors_directed(*node, Direction::Incoming)
-                .map(|incoming| {
-                    rank.get(&incoming).copied().unwrap_or(0.0)
-                        / out_degree.get(&incoming).copied().unwrap_or(1) as f64
+                .edges_directed(*node, Direction::Incoming)
+                .map(|edge| {
+                    rank.get(&edge.source()).copied().unwrap_or(0.0)
+                        * edge_weights[edge.id().index()]
+                        / out_weight.get(&edge.source()).copied().unwrap_or(1.0)
                 })
                 .sum::<f64>();
             let seed_mass = seed_vector.get(node).copied().unwrap_or(0.0);
@@ -330,6 +352,21 @@ pub fn personalized_pagerank(
         .collect()
 }
 
+/// Sampled call-edge weights when `[profiles].execution_weighted` is set;
+/// empty otherwise, which leaves the ranking structural.
+fn execution_weights(workspace: &Path, store: &SqliteStore) -> EdgeWeights {
+    let enabled = aether_config::load_workspace_config(workspace)
+        .map(|config| config.profiles.execution_weighted)
+        .unwrap_or(false);
+    if !enabled {
+        return EdgeWeights::new();
+    }
+    store
+        .list_edge_sample_counts()
+        .map(|counts| execution_edge_weights(&counts))
+        .unwrap_or_default()
+}
+
 pub fn branch_diff_to_symbols(
     workspace: &Path,
     store: &SqliteStore,
@@ -484,12 +521,13 @@ pub(crate) fn resolve_task_symbols_with_context(
         }
     }
 
-    let ppr = personalized_pagerank(
+    let ppr = personalized_pagerank_weighted(
         &graph,
         &node_map,
         seed_symbol_ids.as_slice(),
         DEFAULT_PPR_ALPHA,
         DEFAULT_PPR_ITERATIONS,
+        &execution_weights(workspace, store),
     );
 
     let clamped_beta = beta.clamp(0.0, 1.0);
@@ -524,7 +562,7 @@ pub fn run_task_history_command(workspace: &Path, args: TaskHistoryArgs) -> Resu
     let history = store
         .list_recent_task_history(args.limit)
         .context("failed to list recent task history")?;
-    let mut out = std::io::stdout();
+    let mut out = crate::daemon_rpc::command_stdout();
     write_task_history(&history, &mut out)
 }
 
@@ -549,7 +587,7 @@ pub fn run_task_relevance_command(workspace: &Path, args: TaskRelevanceArgs) ->
         .get_symbol_search_results_batch(top_ids.as_slice())
         .context("failed to resolve ranked symbols for task relevance output")?;
 
-    let mut out = std::io::stdout();
+    let mut out = crate::daemon_rpc::command_stdout();
     write_task_relevance(
         &resolution.notices,
         &resolution.ranked_symbols,
@@ -28,7 +28,7 @@ pub struct TemplateContext {
     pub mcp_binary_hint: String,
 }
 
-pub(crate) const TOOL_DESCRIPTIONS: [(&str, &str); 40] = [
+pub(crate) const TOOL_DESCRIPTIONS: [(&str, &str); 46] = [
     ("aether_status", "Get AETHER local store status"),
     (
         "aether_symbol_lookup",
@@ -121,6 +121,14 @@ pub(crate) const TOOL_DESCRIPTIONS: [(&str, &str); 40] = [
         "aether_blast_radius",
         "Analyze coupled files and risk levels for blast-radius impact",
     ),
+    (
+        "aether_graph_query",
+        "Find dependency paths between symbols with from/to predicates, edge kinds, hop bounds and through/avoid constraints",
+    ),
+    (
+        "aether_ownership",
+        "Show CODEOWNERS owners, recency-weighted blame authors and bus factor for a file, symbol or community",
+    ),
     (
         "aether_test_intents",
         "Query extracted behavioral test intents for a file or symbol",
@@ -129,6 +137,18 @@ pub(crate) const TOOL_DESCRIPTIONS: [(&str, &str); 40] = [
         "aether_drift_report",
         "Run semantic drift analysis with boundary and structural anomaly detection",
     ),
+    (
+        "aether_arch_check",
+        "Check architecture layer rules, or whether a proposed dependency is allowed",
+    ),
+    (
+        "aether_dead_code",
+        "List symbols unreachable from entry points, with confidence levels",
+    ),
+    (
+        "aether_affected_tests",
+        "Select the tests affected by changes since a git ref, with runner filters",
+    ),
     (
         "aether_health",
         "Get codebase health metrics including critical symbols, bottlenecks, dependency cycles, orphaned code, and risk hotspots.",
@@ -153,6 +173,10 @@ pub(crate) const TOOL_DESCRIPTIONS: [(&str, &str); 40] = [
         "aether_trace_cause",
         "Trace likely upstream semantic causes of a downstream breakage",
     ),
+    (
+        "aether_explain_trace",
+        "Explain a panic backtrace or stack trace frame by frame with SIRs, contract violations and likely responsible commits",
+    ),
     (
         "aether_acknowledge_drift",
         "Acknowledge drift findings and create a project note",
@@ -338,7 +362,7 @@ mod tests {
 
     #[test]
     fn tool_descriptions_include_enhance_prompt_tool() {
-        assert_eq!(TOOL_DESCRIPTIONS.len(), 40);
+        assert_eq!(TOOL_DESCRIPTIONS.len(), 46);
         assert!(
             TOOL_DESCRIPTIONS
                 .iter()
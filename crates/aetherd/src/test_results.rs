@@ -0,0 +1,332 @@
+//! `aetherd test-results` and `aetherd triage`: test report ingestion,
+//! flaky-test scoring, and causal triage of failing tests.
+
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use aether_analysis::{
+    FlakyTestsReport, FlakyTestsRequest, IngestTestReportRequest, IngestedTestRun,
+    TestResultsAnalyzer, TriageReport, TriageRequest,
+};
+use aether_store::{SqliteStore, open_surreal_graph_store_readonly};
+use anyhow::{Context, Result, anyhow};
+
+use crate::cli::{TestResultsArgs, TestResultsCommand, TriageArgs};
+
+pub fn run_test_results_command(workspace: &Path, args: TestResultsArgs) -> Result<()> {
+    let analyzer = TestResultsAnalyzer::new(workspace).context("failed to initialize analyzer")?;
+    let rendered = match args.command {
+        TestResultsCommand::Ingest(args) => {
+            let store = SqliteStore::open(workspace).context("failed to open local store")?;
+            let run = analyzer
+                .ingest(
+                    &store,
+                    &IngestTestReportRequest {
+                        path: resolve_report_path(workspace, &args.report),
+                        format: args.format,
+                        record: true,
+                    },
+                )
+                .context("failed to ingest test report")?;
+            if args.json {
+                serde_json::to_string_pretty(&run).context("failed to serialize test run")?
+            } else {
+                render_run(&run)
+            }
+        }
+        TestResultsCommand::Flaky(args) => {
+            let store =
+                SqliteStore::open_readonly(workspace).context("failed to open local store")?;
+            let mut report = analyzer
+                .flaky_tests(
+                    &store,
+                    &FlakyTestsRequest {
+                        window_runs: args.runs,
+                        min_runs: args.min_runs,
+                    },
+                )
+                .context("flaky-test analysis failed")?;
+            report.tests.truncate(args.limit);
+            if args.json {
+                serde_json::to_string_pretty(&report)
+                    .context("failed to serialize flaky-test report")?
+            } else {
+                render_flaky(&report)
+            }
+        }
+    };
+
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    writeln!(&mut stdout, "{}", rendered.trim_end())
+        .context("failed to write test-results output")?;
+    Ok(())
+}
+
+pub fn run_triage_command(workspace: &Path, args: TriageArgs) -> Result<()> {
+    let config = aether_config::load_workspace_config(workspace)
+        .context("failed to load workspace config")?;
+    if matches!(
+        config.storage.graph_backend,
+        aether_config::GraphBackend::Surreal | aether_config::GraphBackend::Cozo
+    ) && let Some(daemon) = crate::daemon_detect::detect_running_daemon(&config, workspace)
+    {
+        crate::daemon_detect::exit_daemon_detected(&daemon, "triage");
+    }
+    let (format, report) = args
+        .report()
+        .ok_or_else(|| anyhow!("provide one of --junit, --nextest, --pytest or --jest"))?;
+
+    let analyzer = TestResultsAnalyzer::new(workspace).context("failed to initialize analyzer")?;
+    let store = SqliteStore::open(workspace).context("failed to open local store")?;
+    let run = analyzer
+        .ingest(
+            &store,
+            &IngestTestReportRequest {
+                path: resolve_report_path(workspace, report),
+                format: Some(format),
+                record: !args.no_record,
+            },
+        )
+        .context("failed to ingest test report")?;
+    let graph = open_surreal_graph_store_readonly(workspace)
+        .context("failed to open configured surreal graph store")?;
+    let report = analyzer
+        .triage(
+            &store,
+            &graph,
+            &run,
+            &TriageRequest {
+                lookback: Some(args.lookback),
+                max_depth: Some(args.depth),
+                limit: Some(args.limit),
+                flaky: FlakyTestsRequest::default(),
+            },
+        )
+        .context("failure triage failed")?;
+
+    let rendered = if args.json {
+        serde_json::to_string_pretty(&report).context("failed to serialize triage report")?
+    } else {
+        render_triage(&run, &report)
+    };
+    let mut stdout = crate::daemon_rpc::command_stdout();
+    writeln!(&mut stdout, "{}", rendered.trim_end()).context("failed to write triage output")?;
+    Ok(())
+}
+
+/// Relative report paths resolve against the workspace so the command reads
+/// the same file when it runs inside the daemon.
+fn resolve_report_path(workspace: &Path, report: &Path) -> PathBuf {
+    if report.is_absolute() {
+        report.to_path_buf()
+    } else {
+        workspace.join(report)
+    }
+}
+
+fn render_run(run: &IngestedTestRun) -> String {
+    let mut out = format!(
+        "{} {} run {} from {}: {} test(s), {} passed, {} failed, {} skipped; {} linked to test symbols\n",
+        if run.recorded { "Recorded" } else { "Read" },
+        run.run.format,
+        short_id(&run.run.run_id),
+        run.run.source_path,
+        run.run.total,
+        run.run.passed,
+        run.run.failed,
+        run.run.skipped,
+        run.linked
+    );
+    let failures = run
+        .results
+        .iter()
+        .filter(|result| result.outcome == "failed")
+        .collect::<Vec<_>>();
+    if !failures.is_empty() {
+        out.push_str("\nFailed:\n");
+        for result in failures {
+            let _ = writeln!(
+                out,
+                "  {}{}",
+                result.test_key,
+                first_line(result.message.as_deref())
+            );
+        }
+    }
+    for note in &run.notes {
+        let _ = writeln!(out, "note: {note}");
+    }
+    out
+}
+
+fn render_flaky(report: &FlakyTestsReport) -> String {
+    let mut out = format!(
+        "{} run(s), {} test(s) scored, {} flaky\n",
+        report.runs_considered,
+        report.tests_scored,
+        report.tests.len()
+    );
+    if !report.tests.is_empty() {
+        out.push('\n');
+        for test in &report.tests {
+            let _ = writeln!(
+                out,
+                "  {:.2}  {} — {}/{} failed, {} flip(s), {} inconsistent commit(s), last {}",
+                test.flakiness,
+                test.test_key,
+                test.failed,
+                test.runs,
+                test.flips,
+                test.inconsistent_commits,
+                test.last_outcome.as_str()
+            );
+        }
+    }
+    out
+}
+
+fn render_triage(run: &IngestedTestRun, report: &TriageReport) -> String {
+    let mut out = format!(
+        "Run {} ({}, {}): {} failing test(s) of {}\n",
+        short_id(&report.run_id),
+        run.run.format,
+        run.run.source_path,
+        report.failed,
+        run.run.total
+    );
+
+    if !report.suspects.is_empty() {
+        out.push_str("\nLikely causes:\n");
+        for suspect in &report.suspects {
+            let _ = writeln!(
+                out,
+                "  {}. {} ({}) @ {} by {} — score {:.2}; fails {}",
+                suspect.rank,
+                suspect.symbol_name,
+                suspect.file,
+                short_id(&suspect.commit),
+                suspect.author,
+                suspect.score,
+                suspect.failing_tests.join(", ")
+            );
+        }
+    }
+
+    if !report.failures.is_empty() {
+        out.push_str("\nFailures:\n");
+        for failure in &report.failures {
+            let flakiness = failure
+                .flakiness
+                .map(|score| format!(" [flakiness {score:.2}]"))
+                .unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "  {}{flakiness}{}",
+                failure.test_key,
+                first_line(failure.message.as_deref())
+            );
+            for entry in &failure.causal_chain {
+                let _ = writeln!(
+                    out,
+                    "    -> {} ({}) {:.2}, {} hop(s)",
+                    entry.symbol_name, entry.file, entry.causal_score, entry.depth
+                );
+            }
+            for note in &failure.notes {
+                let _ = writeln!(out, "    note: {note}");
+            }
+        }
+    }
+    for note in run.notes.iter().chain(&report.notes) {
+        let _ = writeln!(out, "note: {note}");
+    }
+    out
+}
+
+fn first_line(message: Option<&str>) -> String {
+    message
+        .and_then(|message| message.lines().map(str::trim).find(|line| !line.is_empty()))
+        .map(|line| format!(" — {line}"))
+        .unwrap_or_default()
+}
+
+fn short_id(id: &str) -> &str {
+    id.get(..12).unwrap_or(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use aether_analysis::{FailureTriage, TriageSuspect};
+    use aether_store::{TestResultRecord, TestRunRecord};
+
+    use super::*;
+
+    #[test]
+    fn render_triage_lists_suspects_and_failures() {
+        let run = IngestedTestRun {
+            schema_version: "1.0".to_owned(),
+            run: TestRunRecord {
+                run_id: "0123456789abcdef".to_owned(),
+                format: "junit".to_owned(),
+                source_path: "target/report.xml".to_owned(),
+                commit_hash: None,
+                recorded_at: 0,
+                total: 3,
+                passed: 2,
+                failed: 1,
+                skipped: 0,
+            },
+            linked: 1,
+            results: vec![TestResultRecord {
+                run_id: "0123456789abcdef".to_owned(),
+                test_key: "tests/api.rs::charges".to_owned(),
+                test_name: "charges".to_owned(),
+                file_path: Some("tests/api.rs".to_owned()),
+                symbol_id: Some("sym-test".to_owned()),
+                outcome: "failed".to_owned(),
+                duration_ms: Some(4),
+                message: Some("\nassertion failed: total == 4\nat tests/api.rs:9".to_owned()),
+            }],
+            recorded: true,
+            notes: Vec::new(),
+        };
+        let report = TriageReport {
+            schema_version: "1.0".to_owned(),
+            run_id: run.run.run_id.clone(),
+            failed: 1,
+            suspects: vec![TriageSuspect {
+                rank: 1,
+                score: 0.5,
+                symbol_id: "sym-total".to_owned(),
+                symbol_name: "total".to_owned(),
+                file: "src/cart.rs".to_owned(),
+                commit: "fedcba9876543210".to_owned(),
+                author: "dev".to_owned(),
+                date: "2026-01-01".to_owned(),
+                failing_tests: vec!["tests/api.rs::charges".to_owned()],
+            }],
+            failures: vec![FailureTriage {
+                test_key: "tests/api.rs::charges".to_owned(),
+                test_name: "charges".to_owned(),
+                file_path: Some("tests/api.rs".to_owned()),
+                symbol_id: Some("sym-test".to_owned()),
+                message: run.results[0].message.clone(),
+                flakiness: Some(0.25),
+                causal_chain: Vec::new(),
+                notes: vec!["no upstream semantic changes in the lookback".to_owned()],
+            }],
+            notes: Vec::new(),
+        };
+
+        assert_eq!(
+            render_triage(&run, &report),
+            "Run 0123456789ab (junit, target/report.xml): 1 failing test(s) of 3\n\n\
+             Likely causes:\n\
+             \x20 1. total (src/cart.rs) @ fedcba987654 by dev — score 0.50; fails tests/api.rs::charges\n\n\
+             Failures:\n\
+             \x20 tests/api.rs::charges [flakiness 0.25] — assertion failed: total == 4\n\
+             \x20   note: no upstream semantic changes in the lookback\n"
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Resolution of `[[roots]]` and `[[root_links]]` for multi-repository workspaces.
+
+use std::path::{Path, PathBuf};
+
+use aether_config::{
+    AetherConfig, WorkspaceRootConfig, declared_workspace_roots, load_workspace_config,
+    root_for_file_path,
+};
+use aether_core::{EdgeKind, Symbol, SymbolEdge, normalize_path};
+use ignore::overrides::{Override, OverrideBuilder};
+
+/// A declared root resolved to a directory on disk.
+#[derive(Debug, Clone)]
+pub struct ObservedRoot {
+    pub name: String,
+    /// Canonical root directory.
+    pub path: PathBuf,
+    /// Prefix that root-relative paths are joined onto to form stored file paths.
+    pub prefix: PathBuf,
+    excludes: Override,
+}
+
+impl ObservedRoot {
+    /// Stored file path for an absolute path under this root, unless excluded.
+    pub fn stored_path(&self, path: &Path) -> Option<PathBuf> {
+        let relative = path.strip_prefix(&self.path).ok()?;
+        if self.excludes.matched(relative, false).is_ignore() {
+            return None;
+        }
+        Some(self.prefix.join(relative))
+    }
+
+    pub fn excludes(&self) -> Override {
+        self.excludes.clone()
+    }
+}
+
+/// Resolves the workspace's declared roots, skipping (with a warning) roots
+/// that do not exist or live inside the primary workspace, which already
+/// indexes them.
+pub fn resolve_workspace_roots(workspace: &Path, config: &AetherConfig) -> Vec<ObservedRoot> {
+    let canonical_workspace = workspace
+        .canonicalize()
+        .unwrap_or_else(|_| workspace.to_path_buf());
+    let mut resolved = Vec::new();
+    for root in declared_workspace_roots(config) {
+        let prefix = PathBuf::from(root.path_prefix());
+        let path = match workspace.join(&prefix).canonicalize() {
+            Ok(path) => path,
+            Err(err) => {
+                tracing::warn!(root = %root.name, path = %root.path, error = %err, "skipping workspace root that cannot be resolved");
+                continue;
+            }
+        };
+        if path.starts_with(&canonical_workspace) {
+            tracing::warn!(
+                root = %root.name,
+                path = %path.display(),
+                "skipping workspace root nested inside the primary workspace"
+            );
+            continue;
+        }
+
+        let mut excludes = OverrideBuilder::new(&path);
+        for pattern in &root.exclude {
+            let pattern = pattern.trim();
+            // `dir/**` only matches what is inside `dir`; matching the
+            // directory too lets walkers skip it without listing it. A glob
+            // with no inner `/` would match at any depth, so anchor it.
+            let directory = pattern
+                .strip_suffix("/**")
+                .filter(|directory| !directory.is_empty())
+                .map(|directory| {
+                    if directory.trim_start_matches('/').contains('/') {
+                        directory.to_owned()
+                    } else {
+                        format!("/{}", directory.trim_start_matches('/'))
+                    }
+                });
+            for glob in std::iter::once(pattern.to_owned()).chain(directory) {
+                if let Err(err) = excludes.add(&format!("!{glob}")) {
+                    tracing::warn!(root = %root.name, pattern = %pattern, error = %err, "ignoring invalid root exclude pattern");
+                }
+            }
+        }
+        let excludes = excludes.build().unwrap_or_else(|_| Override::empty());
+
+        resolved.push(ObservedRoot {
+            name: root.name,
+            path,
+            prefix,
+            excludes,
+        });
+    }
+    // Most specific roots first, so nested roots win over their parents.
+    resolved.sort_by(|left, right| right.path.cmp(&left.path));
+    resolved
+}
+
+/// Loads and resolves the roots declared in the workspace config.
+pub fn load_workspace_roots(workspace: &Path) -> Vec<ObservedRoot> {
+    match load_workspace_config(workspace) {
+        Ok(config) => resolve_workspace_roots(workspace, &config),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Display name of the root a stored file path belongs to. Files in the
+/// primary workspace use the workspace directory name.
+pub fn root_name_for_file_path(
+    workspace: &Path,
+    roots: &[WorkspaceRootConfig],
+    file_path: &str,
+) -> String {
+    match root_for_file_path(roots, file_path) {
+        Some(root) => root.name.clone(),
+        None => primary_root_name(workspace),
+    }
+}
+
+pub fn primary_root_name(workspace: &Path) -> String {
+    workspace
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| ".".to_owned())
+}
+
+/// A `[[root_links]]` entry whose source root is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ResolvedRootLink {
+    /// Stored path prefix of the source root; `None` for the primary workspace.
+    from_prefix: Option<String>,
+    from_symbol: String,
+    to_symbol: String,
+}
+
+pub(crate) fn resolve_root_links(config: &AetherConfig) -> Vec<ResolvedRootLink> {
+    let roots = declared_workspace_roots(config);
+    config
+        .root_links
+        .iter()
+        .filter(|link| !link.from_symbol.is_empty() && !link.to_symbol.is_empty())
+        .filter_map(|link| {
+            let from_prefix = match link.from_root.as_deref() {
+                Some(name) => Some(roots.iter().find(|root| root.name == name)?.path_prefix()),
+                None => None,
+            };
+            Some(ResolvedRootLink {
+                from_prefix,
+                from_symbol: link.from_symbol.clone(),
+                to_symbol: link.to_symbol.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Call edges contributed by root links for the symbols extracted from one file.
+pub(crate) fn root_link_edges(
+    links: &[ResolvedRootLink],
+    roots: &[WorkspaceRootConfig],
+    file_path: &str,
+    symbols: &[Symbol],
+) -> Vec<SymbolEdge> {
+    if links.is_empty() {
+        return Vec::new();
+    }
+    let file_path = normalize_path(file_path);
+    let file_prefix = root_for_file_path(roots, &file_path).map(WorkspaceRootConfig::path_prefix);
+    let file_path = file_path.as_str();
+
+    links
+        .iter()
+        .filter(|link| link.from_prefix == file_prefix)
+        .flat_map(|link| {
+            symbols
+                .iter()
+                .filter(move |symbol| symbol.qualified_name == link.from_symbol)
+                .map(move |symbol| SymbolEdge {
+                    source_id: symbol.id.clone(),
+                    target_qualified_name: link.to_symbol.clone(),
+                    edge_kind: EdgeKind::Calls,
+                    file_path: file_path.to_owned(),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aether_config::{RootLinkConfig, WorkspaceRootConfig};
+    use aether_core::{Language, Position, SourceRange, SymbolKind};
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn root(name: &str, path: &str, exclude: &[&str]) -> WorkspaceRootConfig {
+        WorkspaceRootConfig {
+            name: name.to_owned(),
+            path: path.to_owned(),
+            exclude: exclude.iter().map(|value| (*value).to_owned()).collect(),
+        }
+    }
+
+    #[test]
+    fn sibling_roots_resolve_and_nested_roots_are_skipped() {
+        let temp = tempdir().expect("tempdir");
+        let workspace = temp.path().join("backend");
+        fs::create_dir_all(workspace.join("vendored")).expect("workspace dirs");
+        fs::create_dir_all(temp.path().join("sdk/generated")).expect("sdk dirs");
+
+        let config = AetherConfig {
+            roots: vec![
+                root("sdk", "../sdk", &["generated/**"]),
+                root("vendored", "vendored", &[]),
+                root("missing", "../missing", &[]),
+            ],
+            ..AetherConfig::default()
+        };
+        let roots = resolve_workspace_roots(&workspace, &config);
+
+        assert_eq!(roots.len(), 1);
+        let sdk = &roots[0];
+        assert_eq!(sdk.name, "sdk");
+        assert_eq!(
+            sdk.stored_path(&sdk.path.join("src/client.ts")),
+            Some(PathBuf::from("../sdk/src/client.ts"))
+        );
+        assert_eq!(sdk.stored_path(&sdk.path.join("generated/api.ts")), None);
+        assert_eq!(primary_root_name(&workspace), "backend");
+
+        fs::create_dir_all(sdk.path.join("generated/nested")).expect("nested dirs");
+        fs::create_dir_all(sdk.path.join("src")).expect("src dir");
+        fs::write(sdk.path.join("generated/nested/api.ts"), "").expect("generated file");
+        fs::write(sdk.path.join("src/client.ts"), "").expect("source file");
+        let walked = ignore::WalkBuilder::new(&sdk.path)
+            .overrides(sdk.excludes())
+            .build()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path().strip_prefix(&sdk.path).unwrap().to_path_buf())
+            .collect::<Vec<_>>();
+        assert!(walked.contains(&PathBuf::from("src/client.ts")));
+        assert!(walked.iter().all(|path| !path.starts_with("generated")));
+    }
+
+    #[test]
+    fn root_links_add_call_edges_only_for_symbols_in_the_source_root() {
+        let config = AetherConfig {
+            roots: vec![root("sdk", "../sdk", &[])],
+            root_links: vec![RootLinkConfig {
+                from_root: Some("sdk".to_owned()),
+                from_symbol: "ApiClient.getUser".to_owned(),
+                to_symbol: "handlers::get_user".to_owned(),
+            }],
+            ..AetherConfig::default()
+        };
+        let links = resolve_root_links(&config);
+        let roots = declared_workspace_roots(&config);
+        let symbol = Symbol {
+            id: "sym-get-user".to_owned(),
+            language: Language::TypeScript,
+            file_path: "../sdk/src/client.ts".to_owned(),
+            kind: SymbolKind::Method,
+            name: "getUser".to_owned(),
+            qualified_name: "ApiClient.getUser".to_owned(),
+            signature_fingerprint: "sig".to_owned(),
+            content_hash: "hash".to_owned(),
+            range: SourceRange {
+                start: Position { line: 1, column: 1 },
+                end: Position { line: 3, column: 2 },
+                start_byte: None,
+                end_byte: None,
+            },
+        };
+
+        let symbols = [symbol];
+        let edges = root_link_edges(&links, &roots, "../sdk/src/client.ts", &symbols);
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].source_id, "sym-get-user");
+        assert_eq!(edges[0].target_qualified_name, "handlers::get_user");
+        assert_eq!(edges[0].edge_kind, EdgeKind::Calls);
+
+        assert!(root_link_edges(&links, &roots, "src/client.ts", &symbols).is_empty());
+    }
+}
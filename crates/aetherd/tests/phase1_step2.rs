@@ -45,6 +45,7 @@ impl InferenceProvider for HashingMockProvider {
             edge_cases: None,
             complexity: None,
             method_dependencies: None,
+            extensions: Default::default(),
         })
     }
 }
@@ -90,6 +91,7 @@ impl InferenceProvider for RollupUnionMockProvider {
             edge_cases: None,
             complexity: None,
             method_dependencies: None,
+            extensions: Default::default(),
         })
     }
 }
@@ -146,6 +148,7 @@ fn prompt_tracking_sir(context: &SirContext) -> SirAnnotation {
         edge_cases: None,
         complexity: None,
         method_dependencies: None,
+        extensions: Default::default(),
     }
 }
 
@@ -33,6 +33,7 @@ impl InferenceProvider for TestProvider {
             edge_cases: None,
             complexity: None,
             method_dependencies: None,
+            extensions: Default::default(),
         })
     }
 }